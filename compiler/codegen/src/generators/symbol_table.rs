@@ -122,6 +122,13 @@ pub fn generate(
     builder.set_linkage(process_signal_global, Linkage::External);
     builder.set_alignment(process_signal_global, 8);
 
+    // Generate flag telling the runtime whether this executable was built with `--bin`, in
+    // which case all of its command-line arguments belong to `main/1`, rather than the runtime
+    let escript_init = builder.build_constant_uint(i8_type, options.bin.is_some() as u64);
+    let escript_global = builder.build_global(i8_type, "__LUMEN_ESCRIPT", Some(escript_init));
+    builder.set_linkage(escript_global, Linkage::External);
+    builder.set_alignment(escript_global, 1);
+
     // We have to build a shim for the Rust libstd `lang_start_internal`
     // function to start the Rust runtime. Since that symbol is internal,
     // we locate the mangled symbol name at build time and build a shim
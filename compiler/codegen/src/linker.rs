@@ -2,6 +2,7 @@ pub(crate) mod archive;
 mod command;
 pub(crate) mod link;
 mod rpath;
+mod source_map;
 
 use std::ffi::{OsStr, OsString};
 use std::fs::{self, File};
@@ -538,6 +539,12 @@ impl<'a> Linker for GccLinker<'a> {
                 .override_export_symbols
                 .is_none()
         {
+            // NIF libraries loaded by `erlang:load_nif/2` resolve the `enif_*` functions against
+            // the executable, which ELF executables only allow when their symbols are exported.
+            if !self.options.target.options.is_like_osx {
+                self.linker_arg("--export-dynamic");
+            }
+
             return;
         }
 
@@ -12,14 +12,14 @@ use std::str;
 
 use anyhow::*;
 use cc::windows_registry;
-use log::{info, warn};
+use log::{debug, info, warn};
 use tempfile::Builder as TempFileBuilder;
 use thiserror::private::PathAsDisplay;
 
 use liblumen_core::util::thread_local::ThreadLocalCell;
 use liblumen_session::filesearch;
 use liblumen_session::search_paths::PathKind;
-use liblumen_session::{CFGuard, DebugInfo, Options, ProjectType, Sanitizer};
+use liblumen_session::{CFGuard, DebugInfo, Options, ProjectType, Sanitizer, Strip};
 use liblumen_target::crt_objects::{CrtObjects, CrtObjectsFallback};
 use liblumen_target::{
     LinkOutputKind, LinkerFlavor, LldFlavor, PanicStrategy, RelocModel, RelroLevel,
@@ -30,6 +30,7 @@ use liblumen_util::time::time;
 
 use crate::linker::command::Command;
 use crate::linker::rpath::{self, RPathConfig};
+use crate::linker::source_map;
 use crate::linker::Linker;
 use crate::meta::{CodegenResults, LibSource};
 
@@ -507,6 +508,19 @@ fn use_system_linker(
         }
     }
 
+    // Browsers don't understand DWARF, so when targeting wasm we also emit a source
+    // map, so that devtools can show the Erlang sources when stepping through the module
+    if options.target.arch == "wasm32"
+        && options.debug_info != DebugInfo::None
+        && options.debugging_opts.strip == Strip::None
+    {
+        match source_map::emit(output_file) {
+            Ok(true) => (),
+            Ok(false) => debug!("no line table found, skipping source map generation"),
+            Err(e) => diagnostics.warn(format!("failed to generate source map: {:#}", e)),
+        }
+    }
+
     Ok(())
 }
 
@@ -954,6 +968,14 @@ pub fn add_local_native_libraries(
             "libpanic_unwind.rlib",
             "lumen_rt_minimal",
             "libliblumen_otp.rlib",
+            "liblumen_nif.rlib",
+        ],
+        // WASI hosts are not browsers, so use the runtime that only needs WASI imports
+        "wasm32" if !no_std && options.target.target_os == "wasi" => vec![
+            "libpanic_abort.rlib",
+            "lumen_rt_minimal",
+            "libliblumen_otp.rlib",
+            "liblumen_nif.rlib",
         ],
         "wasm32" if !no_std => vec!["libpanic_abort.rlib", "lumen_web"],
         _ => vec!["libpanic_unwind.rlib"],
@@ -0,0 +1,347 @@
+//! Generates source maps for WebAssembly modules from their DWARF line tables.
+//!
+//! Browser devtools don't understand DWARF, but they do understand source maps, in
+//! which the generated "column" is the byte offset of an instruction in the `.wasm`
+//! file, so once a module has been linked, we translate its line table into one, and
+//! point the module at it via a `sourceMappingURL` section.
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context};
+
+use gimli::{ColumnType, EndianSlice, LittleEndian, SectionId};
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const CUSTOM_SECTION_ID: u8 = 0;
+const CODE_SECTION_ID: u8 = 10;
+const SOURCE_MAPPING_URL: &str = "sourceMappingURL";
+const BASE64_CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Writes a source map for the linked module at `wasm_file` to `<wasm_file>.map`, and
+/// adds a `sourceMappingURL` section referencing it to the module.
+///
+/// The contents of the Erlang sources are embedded in the map, so that devtools can
+/// display them without the sources being served alongside the module.
+///
+/// Returns `false` if the module has no line table to generate a map from.
+pub fn emit(wasm_file: &Path) -> anyhow::Result<bool> {
+    let mut wasm =
+        fs::read(wasm_file).with_context(|| format!("could not read {}", wasm_file.display()))?;
+
+    let map = {
+        let module = WasmModule::parse(&wasm)?;
+        let code_offset = match module.code_offset {
+            None => return Ok(false),
+            Some(offset) => offset,
+        };
+        let mappings = read_line_table(&module)?;
+        if mappings.is_empty() {
+            return Ok(false);
+        }
+        SourceMap::new(mappings, code_offset as u64)
+    };
+
+    let mut map_file = wasm_file.as_os_str().to_owned();
+    map_file.push(".map");
+    let map_file = PathBuf::from(map_file);
+    fs::write(&map_file, map.to_json())
+        .with_context(|| format!("could not write {}", map_file.display()))?;
+
+    // The map is written next to the module, so it can be referenced by name
+    let url = map_file.file_name().unwrap().to_string_lossy();
+    append_custom_section(&mut wasm, SOURCE_MAPPING_URL, url.as_bytes());
+    fs::write(wasm_file, wasm)
+        .with_context(|| format!("could not write {}", wasm_file.display()))?;
+
+    Ok(true)
+}
+
+/// The parts of a WebAssembly module we need to build a source map
+struct WasmModule<'a> {
+    /// The offset of the code section contents, which DWARF addresses are relative to
+    code_offset: Option<usize>,
+    custom_sections: HashMap<&'a str, &'a [u8]>,
+}
+impl<'a> WasmModule<'a> {
+    fn parse(bytes: &'a [u8]) -> anyhow::Result<Self> {
+        if bytes.len() < 8 || &bytes[0..4] != WASM_MAGIC {
+            bail!("not a webassembly module");
+        }
+
+        let mut module = Self {
+            code_offset: None,
+            custom_sections: HashMap::new(),
+        };
+        let mut pos = 8;
+        while pos < bytes.len() {
+            let id = bytes[pos];
+            pos += 1;
+            let size = read_leb128(bytes, &mut pos)? as usize;
+            let start = pos;
+            let end = start
+                .checked_add(size)
+                .filter(|end| *end <= bytes.len())
+                .ok_or_else(|| anyhow!("section extends past the end of the module"))?;
+
+            match id {
+                CUSTOM_SECTION_ID => {
+                    let name_len = read_leb128(bytes, &mut pos)? as usize;
+                    let name_end = pos + name_len;
+                    if name_end > end {
+                        bail!("custom section name extends past the end of the section");
+                    }
+                    let name = std::str::from_utf8(&bytes[pos..name_end])?;
+                    module.custom_sections.insert(name, &bytes[name_end..end]);
+                }
+                CODE_SECTION_ID => module.code_offset = Some(start),
+                _ => (),
+            }
+
+            pos = end;
+        }
+
+        Ok(module)
+    }
+
+    fn section(&self, id: SectionId) -> &'a [u8] {
+        self.custom_sections.get(id.name()).copied().unwrap_or(&[])
+    }
+}
+
+/// A line table entry
+struct Mapping {
+    address: u64,
+    file: PathBuf,
+    line: u64,
+    column: u64,
+}
+
+fn read_line_table(module: &WasmModule) -> anyhow::Result<Vec<Mapping>> {
+    let load = |id: SectionId| -> Result<_, gimli::Error> {
+        Ok(EndianSlice::new(module.section(id), LittleEndian))
+    };
+    let no_sup =
+        |_: SectionId| -> Result<_, gimli::Error> { Ok(EndianSlice::new(&[], LittleEndian)) };
+    let dwarf = gimli::Dwarf::load(load, no_sup)?;
+
+    let mut mappings = Vec::new();
+    let mut units = dwarf.units();
+    while let Some(header) = units.next()? {
+        let unit = dwarf.unit(header)?;
+        let program = match unit.line_program.clone() {
+            None => continue,
+            Some(program) => program,
+        };
+
+        let comp_dir = unit
+            .comp_dir
+            .map(|dir| PathBuf::from(dir.to_string_lossy().as_ref()));
+        let mut rows = program.rows();
+        while let Some((header, row)) = rows.next_row()? {
+            if row.end_sequence() {
+                continue;
+            }
+            let line = match row.line() {
+                None => continue,
+                Some(line) => u64::from(line),
+            };
+            let file = match row.file(header) {
+                None => continue,
+                Some(file) => file,
+            };
+
+            // Joining an absolute path replaces what came before it, which is what we
+            // want if either the directory or file name are absolute
+            let mut path = comp_dir.clone().unwrap_or_default();
+            if let Some(dir) = file.directory(header) {
+                path.push(dwarf.attr_string(&unit, dir)?.to_string_lossy().as_ref());
+            }
+            path.push(
+                dwarf
+                    .attr_string(&unit, file.path_name())?
+                    .to_string_lossy()
+                    .as_ref(),
+            );
+
+            // Source maps use zero-based lines and columns, DWARF uses one-based
+            let column = match row.column() {
+                ColumnType::LeftEdge => 0,
+                ColumnType::Column(column) => u64::from(column) - 1,
+            };
+            mappings.push(Mapping {
+                address: row.address(),
+                file: path,
+                line: line - 1,
+                column,
+            });
+        }
+    }
+
+    mappings.sort_by_key(|m| m.address);
+    Ok(mappings)
+}
+
+/// A source map in the format described by the [Source Map Revision 3 Proposal][spec]
+///
+/// [spec]: https://sourcemaps.info/spec.html
+struct SourceMap {
+    sources: Vec<PathBuf>,
+    mappings: String,
+}
+impl SourceMap {
+    fn new(entries: Vec<Mapping>, code_offset: u64) -> Self {
+        let mut sources = Vec::new();
+        let mut source_ids = HashMap::new();
+        let mut mappings = String::new();
+
+        // Each field of a segment is relative to the same field of the previous one
+        let mut last = (0i64, 0i64, 0i64, 0i64);
+        for entry in entries.into_iter() {
+            let Mapping {
+                address,
+                file,
+                line,
+                column,
+            } = entry;
+            let next_id = sources.len();
+            let source = *source_ids.entry(file.clone()).or_insert_with(|| {
+                sources.push(file);
+                next_id
+            });
+            let segment = (
+                (code_offset + address) as i64,
+                source as i64,
+                line as i64,
+                column as i64,
+            );
+            // Consecutive instructions from the same location only need one segment
+            if !mappings.is_empty() && (segment.1, segment.2, segment.3) == (last.1, last.2, last.3)
+            {
+                continue;
+            }
+
+            if !mappings.is_empty() {
+                mappings.push(',');
+            }
+            encode_vlq(&mut mappings, segment.0 - last.0);
+            encode_vlq(&mut mappings, segment.1 - last.1);
+            encode_vlq(&mut mappings, segment.2 - last.2);
+            encode_vlq(&mut mappings, segment.3 - last.3);
+            last = segment;
+        }
+
+        Self { sources, mappings }
+    }
+
+    fn to_json(&self) -> String {
+        let cwd = env::current_dir().ok();
+        let mut sources = Vec::with_capacity(self.sources.len());
+        let mut contents = Vec::with_capacity(self.sources.len());
+        for source in self.sources.iter() {
+            // Paths under the current directory are made relative, which is easier
+            // to navigate in devtools, and doesn't leak details of the build machine
+            let relative = cwd
+                .as_ref()
+                .and_then(|cwd| source.strip_prefix(cwd).ok())
+                .unwrap_or(source.as_path());
+            sources.push(json_string(&relative.to_string_lossy()));
+            contents.push(match fs::read_to_string(source) {
+                Ok(content) => json_string(&content),
+                Err(_) => "null".to_string(),
+            });
+        }
+
+        format!(
+            "{{\"version\":3,\"sources\":[{}],\"sourcesContent\":[{}],\"names\":[],\"mappings\":\"{}\"}}",
+            sources.join(","),
+            contents.join(","),
+            self.mappings
+        )
+    }
+}
+
+fn encode_vlq(out: &mut String, value: i64) {
+    // The sign is stored in the least significant bit
+    let mut vlq = if value < 0 {
+        ((-value as u64) << 1) | 1
+    } else {
+        (value as u64) << 1
+    };
+    loop {
+        let mut digit = vlq & 0x1f;
+        vlq >>= 5;
+        if vlq > 0 {
+            digit |= 0x20;
+        }
+        out.push(BASE64_CHARS[digit as usize] as char);
+        if vlq == 0 {
+            break;
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(&mut escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn read_leb128(bytes: &[u8], pos: &mut usize) -> anyhow::Result<u32> {
+    let mut result = 0u32;
+    let mut shift = 0;
+    loop {
+        let byte = *bytes
+            .get(*pos)
+            .ok_or_else(|| anyhow!("unexpected end of module"))?;
+        *pos += 1;
+        if shift >= 32 {
+            bail!("invalid leb128 integer");
+        }
+        result |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+fn write_leb128(out: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        out.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+fn append_custom_section(wasm: &mut Vec<u8>, name: &str, contents: &[u8]) {
+    let mut payload = Vec::with_capacity(name.len() + contents.len() + 10);
+    write_leb128(&mut payload, name.len());
+    payload.extend_from_slice(name.as_bytes());
+    write_leb128(&mut payload, contents.len());
+    payload.extend_from_slice(contents);
+
+    wasm.push(CUSTOM_SECTION_ID);
+    write_leb128(wasm, payload.len());
+    wasm.extend_from_slice(&payload);
+}
@@ -0,0 +1,125 @@
+#include "llvm/ADT/Optional.h"
+#include "llvm/Support/Casting.h"
+#include "mlir/Dialect/LLVMIR/LLVMDialect.h"
+#include "mlir/Dialect/StandardOps/IR/Ops.h"
+
+#include "lumen/EIR/Builder/Passes.h"
+#include "lumen/EIR/IR/EIRAttributes.h"
+#include "lumen/EIR/IR/EIRDialect.h"
+#include "lumen/EIR/IR/EIROps.h"
+#include "lumen/EIR/IR/EIRTypes.h"
+
+using ::mlir::DialectRegistry;
+using ::mlir::OpBuilder;
+using ::mlir::Operation;
+using ::mlir::OperationPass;
+using ::mlir::PassWrapper;
+using ::mlir::Type;
+using ::mlir::TypeAttr;
+using ::mlir::Value;
+
+using ::llvm::dyn_cast_or_null;
+using ::llvm::isa;
+using ::llvm::Optional;
+using ::llvm::StringRef;
+
+namespace {
+
+using namespace ::lumen::eir;
+
+Optional<int64_t> foldLength(Operation *);
+Optional<int64_t> foldTupleSize(Operation *);
+
+/// Evaluates calls to size-like BIFs (i.e. `length/1` and `tuple_size/1`)
+/// whose argument is a literal, replacing the call with the constant result.
+///
+/// Arithmetic and comparisons are folded by the ops themselves, but these
+/// builtins are ordinary calls until lowering, so they need to be handled
+/// here, before sparse conditional constant propagation runs.
+struct FoldConstantBuiltinsPass
+    : public PassWrapper<FoldConstantBuiltinsPass, OperationPass<FuncOp>> {
+    void getDependentDialects(DialectRegistry &registry) const override {
+        registry.insert<mlir::StandardOpsDialect, mlir::LLVM::LLVMDialect,
+                        lumen::eir::eirDialect>();
+    }
+
+    void runOnOperation() override {
+        FuncOp op = getOperation();
+        if (op.isExternal()) return;
+
+        OpBuilder builder(op.getParentOfType<ModuleOp>());
+        op.walk([&](CallOp call) {
+            if (call.getNumOperands() != 1 || call.getNumResults() != 1)
+                return;
+
+            Operation *arg = getLiteralDefiningOp(call.getOperand(0));
+            if (!arg) return;
+
+            StringRef callee = call.getCallee();
+            Optional<int64_t> result;
+            if (callee == "erlang:length/1")
+                result = foldLength(arg);
+            else if (callee == "erlang:tuple_size/1")
+                result = foldTupleSize(arg);
+
+            // Either not a builtin we know how to fold, or the argument was
+            // of the wrong type, in which case we leave the call in place so
+            // that the error is raised at runtime
+            if (!result.hasValue()) return;
+
+            builder.setInsertionPoint(call);
+            Value folded =
+                builder.create<ConstantIntOp>(call.getLoc(), result.getValue());
+            Type resultType = call.getResult(0).getType();
+            if (folded.getType() != resultType)
+                folded = builder.create<CastOp>(call.getLoc(), folded,
+                                                resultType);
+            call.getResult(0).replaceAllUsesWith(folded);
+            call.erase();
+        });
+    }
+
+   private:
+    /// Looks through casts to find the constant op which produced `value`
+    static Operation *getLiteralDefiningOp(Value value) {
+        Operation *definingOp = value.getDefiningOp();
+        while (auto castOp = dyn_cast_or_null<CastOp>(definingOp))
+            definingOp = castOp.input().getDefiningOp();
+        return definingOp;
+    }
+};
+
+Optional<int64_t> foldLength(Operation *op) {
+    if (isa<ConstantNilOp>(op)) return 0;
+
+    auto listOp = dyn_cast_or_null<ConstantListOp>(op);
+    if (!listOp) return llvm::None;
+
+    // Constant lists with more than one element store their tail as the last
+    // element, so we can only fold when that tail is nil, i.e. the list is
+    // proper; improper lists must raise badarg at runtime
+    auto elements = listOp.getValue().cast<SeqAttr>().getValue();
+    auto numElements = elements.size();
+    if (numElements < 2) return numElements;
+
+    auto tail = elements.back().dyn_cast_or_null<TypeAttr>();
+    if (!tail || !tail.getValue().isa<NilType>()) return llvm::None;
+
+    return numElements - 1;
+}
+
+Optional<int64_t> foldTupleSize(Operation *op) {
+    auto tupleOp = dyn_cast_or_null<ConstantTupleOp>(op);
+    if (!tupleOp) return llvm::None;
+
+    return tupleOp.getValue().cast<SeqAttr>().size();
+}
+}  // namespace
+
+namespace lumen {
+namespace eir {
+std::unique_ptr<mlir::Pass> createFoldConstantBuiltinsPass() {
+    return std::make_unique<FoldConstantBuiltinsPass>();
+}
+}  // namespace eir
+}  // namespace lumen
@@ -9,6 +9,7 @@
 #include "mlir/Pass/PassManager.h"
 #include "mlir/Pass/PassRegistry.h"
 
+#include "lumen/EIR/Builder/Passes.h"
 #include "lumen/EIR/Conversion/ConvertEIRToLLVM.h"
 #include "lumen/EIR/IR/EIROps.h"
 #include "lumen/llvm/Target.h"
@@ -85,6 +86,24 @@ extern "C" MLIRPassManagerRef MLIRCreatePassManager(
     // TODO: Hook driver into instrumentation
     // pm.addInstrumentation(...);
 
+    // Optimize EIR while we still have the high-level semantics of the ops
+    // available: inline small functions, then fold constant expressions and
+    // propagate them across blocks
+    if (optLevel > CodeGenOptLevel::None) {
+        // Inline small functions at -O2 and above, unless optimizing for size
+        if (optLevel >= CodeGenOptLevel::Default && sizeLevel == 0) {
+            pm->addPass(mlir::createInlinerPass());
+        }
+
+        OpPassManager &eirPM = pm->nest<::lumen::eir::FuncOp>();
+        eirPM.addPass(::lumen::eir::createFoldConstantBuiltinsPass());
+        eirPM.addPass(mlir::createCanonicalizerPass());
+        // Sparse conditional constant propagation
+        eirPM.addPass(mlir::createSCCPPass());
+        // Clean up anything made dead or foldable by propagation
+        eirPM.addPass(mlir::createCanonicalizerPass());
+    }
+
     // Convert EIR to LLVM dialect
     pm->addPass(::lumen::eir::createConvertEIRToLLVMPass(targetMachine));
 
@@ -94,11 +113,6 @@ extern "C" MLIRPassManagerRef MLIRCreatePassManager(
 
     // Add optimizations if enabled
     if (optLevel > CodeGenOptLevel::None) {
-        // When optimizing for size, avoid aggressive inlining
-        if (sizeLevel == 0) {
-            // pm->addPass(mlir::createInlinerPass());
-        }
-
         // Canonicalize generated LLVM dialect, and perform optimizations
         // OpPassManager &optPM = pm->nest<::mlir::LLVM::LLVMFuncOp>();
         // optPM.addPass(mlir::createCanonicalizerPass());
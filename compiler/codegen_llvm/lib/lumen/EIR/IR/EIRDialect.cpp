@@ -13,10 +13,103 @@
 
 using namespace lumen::eir;
 
+using ::llvm::cast;
+using ::llvm::dyn_cast_or_null;
+using ::llvm::isa;
 using ::llvm::SmallString;
 using ::mlir::Attribute;
+using ::mlir::BlockAndValueMapping;
 using ::mlir::DialectAsmParser;
 using ::mlir::DialectAsmPrinter;
+using ::mlir::DialectInlinerInterface;
+using ::mlir::Location;
+using ::mlir::Region;
+
+//===----------------------------------------------------------------------===//
+// Inlining
+//===----------------------------------------------------------------------===//
+
+namespace {
+/// The maximum number of operations a function body may contain to be
+/// considered for inlining, unless inlining is forced via `alwaysinline`.
+///
+/// This corresponds to the default `inline_size` used by erlc
+static constexpr unsigned INLINE_SIZE_BUDGET = 24;
+
+/// Returns true if the body of the given function fits in the inlining budget
+static bool isWithinInlineBudget(FuncOp callee) {
+    unsigned size = 0;
+    auto result = callee.walk([&](Operation *op) {
+        if (++size > INLINE_SIZE_BUDGET) return mlir::WalkResult::interrupt();
+        return mlir::WalkResult::advance();
+    });
+    return !result.wasInterrupted();
+}
+
+struct EirInlinerInterface : public DialectInlinerInterface {
+    using DialectInlinerInterface::DialectInlinerInterface;
+
+    /// Only direct calls to small, or explicitly inlinable, functions are
+    /// inlined. Calls with an exception edge (i.e. `eir.invoke`) are never
+    /// inlined, as the landing pad would be lost.
+    bool isLegalToInline(Operation *call, Operation *callable,
+                         bool wouldBeCloned) const final {
+        if (!isa<CallOp>(call)) return false;
+
+        auto callee = dyn_cast_or_null<FuncOp>(callable);
+        if (!callee || callee.isExternal()) return false;
+        if (callee.getAttr("noinline")) return false;
+        if (callee.getAttr("alwaysinline")) return true;
+
+        return isWithinInlineBudget(callee);
+    }
+
+    bool isLegalToInline(Region *dest, Region *src, bool wouldBeCloned,
+                         BlockAndValueMapping &valueMapping) const final {
+        return true;
+    }
+
+    bool isLegalToInline(Operation *op, Region *dest, bool wouldBeCloned,
+                         BlockAndValueMapping &valueMapping) const final {
+        // A musttail call is only valid in tail position of a function with a
+        // compatible signature, neither of which holds once inlined
+        if (auto callOp = dyn_cast_or_null<CallOp>(op))
+            return !callOp.getMustTailAttr();
+        return true;
+    }
+
+    /// Called when a multi-block function is inlined, in which case returns
+    /// are replaced with a branch to the block following the call.
+    void handleTerminator(Operation *op, Block *newDest) const final {
+        auto returnOp = dyn_cast_or_null<ReturnOp>(op);
+        if (!returnOp) return;
+
+        OpBuilder builder(op);
+        builder.create<BranchOp>(op->getLoc(), newDest,
+                                 returnOp.getOperands());
+        op->erase();
+    }
+
+    /// Called when a single-block function is inlined, in which case the
+    /// results of the call are replaced with the returned values.
+    void handleTerminator(Operation *op,
+                          ArrayRef<Value> valuesToReplace) const final {
+        auto returnOp = cast<ReturnOp>(op);
+        assert(returnOp.getNumOperands() == valuesToReplace.size());
+
+        for (auto it : llvm::zip(returnOp.getOperands(), valuesToReplace))
+            std::get<1>(it).replaceAllUsesWith(std::get<0>(it));
+    }
+
+    /// Reconciles the types of call arguments/results which differ from the
+    /// signature of the inlined function, e.g. concrete types vs `!eir.term`
+    Operation *materializeCallConversion(OpBuilder &builder, Value input,
+                                         Type resultType,
+                                         Location conversionLoc) const final {
+        return builder.create<CastOp>(conversionLoc, input, resultType);
+    }
+};
+}  // namespace
 
 /// Create an instance of the EIR dialect, owned by the context.
 ///
@@ -37,6 +130,8 @@ void eirDialect::initialize() {
         RefType, PtrType, TraceRefType, ReceiveRefType>();
 
     addAttributes<AtomAttr, APIntAttr, APFloatAttr, BinaryAttr, SeqAttr>();
+
+    addInterfaces<EirInlinerInterface>();
 }
 
 Operation *eirDialect::materializeConstant(mlir::OpBuilder &builder,
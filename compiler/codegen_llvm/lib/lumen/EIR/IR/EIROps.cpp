@@ -2022,6 +2022,176 @@ void CmpEqOp::getCanonicalizationPatterns(OwningRewritePatternList &results,
     results.insert<CanonicalizeEqualityComparison>(context);
 }
 
+//===----------------------------------------------------------------------===//
+// cmp.lt, cmp.lte, cmp.gt, cmp.gte
+//===----------------------------------------------------------------------===//
+
+// Extracts the name of an atom-like constant, used for term ordering
+static Optional<StringRef> getAtomNameForOrdering(Attribute attr) {
+    if (auto atom = attr.dyn_cast_or_null<AtomAttr>()) {
+        auto name = atom.getStringValue();
+        // Without a name, we can't determine the order
+        if (name.empty()) return llvm::None;
+        return name;
+    }
+    if (auto boolAttr = attr.dyn_cast_or_null<BoolAttr>())
+        return StringRef(boolAttr.getValue() ? "true" : "false");
+    return llvm::None;
+}
+
+static Optional<APInt> getConstantInteger(Attribute attr) {
+    if (auto intAttr = attr.dyn_cast_or_null<APIntAttr>())
+        return intAttr.getValue();
+    if (auto intAttr = attr.dyn_cast_or_null<IntegerAttr>())
+        return intAttr.getValue();
+    return llvm::None;
+}
+
+// Extracts a numeric constant as a float, used for mixed int/float comparisons
+static Optional<APFloat> getNumberAsFloat(Attribute attr) {
+    auto &semantics = llvm::APFloatBase::IEEEdouble();
+    if (auto flt = attr.dyn_cast_or_null<APFloatAttr>()) return flt.getValue();
+    if (auto flt = attr.dyn_cast_or_null<mlir::FloatAttr>())
+        return flt.getValue();
+
+    auto i = getConstantInteger(attr);
+    if (!i.hasValue()) return llvm::None;
+
+    APFloat result(semantics, APInt::getNullValue(64));
+    auto status = result.convertFromAPInt(i.getValue(), /*signed=*/true,
+                                          APFloat::rmNearestTiesToEven);
+    if (status != APFloat::opStatus::opOK) return llvm::None;
+    return result;
+}
+
+// Compares two constant operands according to the Erlang term order,
+// returning a negative, zero, or positive value if `lhs` is less than,
+// equal to, or greater than `rhs`. Only numbers and atoms are supported,
+// if the order can't be determined statically, returns None.
+static Optional<int> compareConstantTerms(ArrayRef<Attribute> operands) {
+    assert(operands.size() == 2 && "binary op takes two operands");
+
+    Attribute lhs = operands[0];
+    Attribute rhs = operands[1];
+
+    if (!lhs || !rhs) return llvm::None;
+
+    // Integers are compared exactly
+    auto lhsInt = getConstantInteger(lhs);
+    auto rhsInt = getConstantInteger(rhs);
+    if (lhsInt.hasValue() && rhsInt.hasValue()) {
+        unsigned defaultBitWidth = 64;
+        auto bitWidth =
+            std::max({defaultBitWidth, lhsInt.getValue().getBitWidth(),
+                      rhsInt.getValue().getBitWidth()});
+        APInt l = normalizeAPInt(lhsInt.getValue(), bitWidth);
+        APInt r = normalizeAPInt(rhsInt.getValue(), bitWidth);
+        if (l.slt(r)) return -1;
+        if (l.sgt(r)) return 1;
+        return 0;
+    }
+
+    auto lhsNum = getNumberAsFloat(lhs);
+    auto rhsNum = getNumberAsFloat(rhs);
+    auto lhsAtom = getAtomNameForOrdering(lhs);
+    auto rhsAtom = getAtomNameForOrdering(rhs);
+
+    // Mixed integers and floats are compared arithmetically
+    if (lhsNum.hasValue() && rhsNum.hasValue()) {
+        APFloat l = lhsNum.getValue();
+        APFloat r = rhsNum.getValue();
+        if (l.isNaN() || r.isNaN()) return llvm::None;
+        switch (l.compare(r)) {
+            case APFloat::cmpLessThan:
+                return -1;
+            case APFloat::cmpGreaterThan:
+                return 1;
+            case APFloat::cmpEqual:
+                return 0;
+            default:
+                return llvm::None;
+        }
+    }
+
+    // Atoms are ordered by name
+    if (lhsAtom.hasValue() && rhsAtom.hasValue())
+        return lhsAtom.getValue().compare(rhsAtom.getValue());
+
+    // Numbers are always ordered before atoms
+    if (lhsNum.hasValue() && rhsAtom.hasValue()) return -1;
+    if (lhsAtom.hasValue() && rhsNum.hasValue()) return 1;
+
+    return llvm::None;
+}
+
+OpFoldResult CmpLtOp::fold(ArrayRef<Attribute> operands) {
+    auto result = compareConstantTerms(operands);
+    if (!result.hasValue()) return nullptr;
+
+    return BoolAttr::get(result.getValue() < 0, getContext());
+}
+
+OpFoldResult CmpLteOp::fold(ArrayRef<Attribute> operands) {
+    auto result = compareConstantTerms(operands);
+    if (!result.hasValue()) return nullptr;
+
+    return BoolAttr::get(result.getValue() <= 0, getContext());
+}
+
+OpFoldResult CmpGtOp::fold(ArrayRef<Attribute> operands) {
+    auto result = compareConstantTerms(operands);
+    if (!result.hasValue()) return nullptr;
+
+    return BoolAttr::get(result.getValue() > 0, getContext());
+}
+
+OpFoldResult CmpGteOp::fold(ArrayRef<Attribute> operands) {
+    auto result = compareConstantTerms(operands);
+    if (!result.hasValue()) return nullptr;
+
+    return BoolAttr::get(result.getValue() >= 0, getContext());
+}
+
+//===----------------------------------------------------------------------===//
+// logical.and, logical.or
+//===----------------------------------------------------------------------===//
+
+static Optional<bool> getConstantBool(Attribute attr) {
+    if (!attr) return llvm::None;
+
+    if (auto boolAttr = attr.dyn_cast_or_null<BoolAttr>())
+        return boolAttr.getValue();
+
+    if (auto atomAttr = attr.dyn_cast_or_null<AtomAttr>()) {
+        auto id = atomAttr.getValue().getLimitedValue();
+        if (id == 0 || id == 1) return id == 1;
+    }
+
+    return llvm::None;
+}
+
+// `and` and `or` are strict in both operands, so we can only fold when both
+// are known booleans, otherwise the badarg must be raised at runtime
+OpFoldResult LogicalAndOp::fold(ArrayRef<Attribute> operands) {
+    assert(operands.size() == 2 && "binary op takes two operands");
+
+    auto lhs = getConstantBool(operands[0]);
+    auto rhs = getConstantBool(operands[1]);
+    if (!lhs.hasValue() || !rhs.hasValue()) return nullptr;
+
+    return BoolAttr::get(lhs.getValue() && rhs.getValue(), getContext());
+}
+
+OpFoldResult LogicalOrOp::fold(ArrayRef<Attribute> operands) {
+    assert(operands.size() == 2 && "binary op takes two operands");
+
+    auto lhs = getConstantBool(operands[0]);
+    auto rhs = getConstantBool(operands[1]);
+    if (!lhs.hasValue() || !rhs.hasValue()) return nullptr;
+
+    return BoolAttr::get(lhs.getValue() || rhs.getValue(), getContext());
+}
+
 //===----------------------------------------------------------------------===//
 // eir.math.*
 //===----------------------------------------------------------------------===//
@@ -5,7 +5,7 @@ use clap::{App, AppSettings, Arg, ArgMatches};
 
 use liblumen_session::{CodegenOptions, DebuggingOptions, OptionGroup, OutputType};
 use liblumen_target::Target;
-use liblumen_util::diagnostics::ColorArg;
+use liblumen_util::diagnostics::{ColorArg, ErrorFormat};
 
 /// Parses the provided arguments
 pub fn parse<'a>(args: impl Iterator<Item = OsString>) -> clap::Result<ArgMatches<'a>> {
@@ -32,6 +32,9 @@ pub fn parser<'a, 'b>() -> App<'a, 'b> {
         )
         .subcommand(print_command())
         .subcommand(compile_command())
+        .subcommand(build_command())
+        .subcommand(explain_command())
+        .subcommand(lsp_command())
 }
 
 pub fn print_print_help() {
@@ -76,10 +79,27 @@ fn print_command<'a, 'b>() -> App<'a, 'b> {
         )
 }
 
+fn explain_command<'a, 'b>() -> App<'a, 'b> {
+    App::new("explain")
+        .about("Prints an extended description of a diagnostic code, with examples")
+        .arg(
+            Arg::with_name("code")
+                .index(1)
+                .required(true)
+                .help("The code shown with the diagnostic, e.g. L0001")
+                .value_name("CODE"),
+        )
+}
+
+fn lsp_command<'a, 'b>() -> App<'a, 'b> {
+    App::new("lsp").about(
+        "Runs a Language Server for Erlang sources over standard input and output, for editors",
+    )
+}
+
 fn compile_command<'a, 'b>() -> App<'a, 'b> {
-    let target = self::target_arg();
-    App::new("compile")
-        .about("Compiles Erlang sources to an executable or shared library")
+    let app = App::new("compile")
+        .about("Compiles Erlang and Elixir sources to an executable or shared library")
         .setting(AppSettings::DeriveDisplayOrder)
         .arg(
             Arg::with_name("inputs")
@@ -87,13 +107,39 @@ fn compile_command<'a, 'b>() -> App<'a, 'b> {
                 .help(
                     "Path(s) to the source file(s) or director(y|ies) to compile.\n\
                      You may also use `-` as a file name to read a file from stdin.\n\
-                     If not provided, the compiler will use the current directory as input.",
+                     If not provided, the compiler will use the current directory as input.\n\
+                     Elixir (.ex) sources are compiled with `elixir`, which must be installed.",
                 )
                 .next_line_help(true)
                 .multiple(true)
                 .value_name("PATHS"),
-        )
+        );
+    compile_options(app)
+}
+
+fn build_command<'a, 'b>() -> App<'a, 'b> {
+    let app = App::new("build")
+        .about("Compiles a rebar3 or Mix project, along with its dependencies")
+        .setting(AppSettings::DeriveDisplayOrder)
         .arg(
+            Arg::with_name("project")
+                .index(1)
+                .help(
+                    "The directory containing the project's rebar.config or mix.exs.\n\
+                     If not provided, the compiler will use the current directory.\n\
+                     Dependencies must already have been fetched by rebar3 or mix.\n\
+                     Options given on the command line take precedence over the project's.",
+                )
+                .next_line_help(true)
+                .value_name("DIR"),
+        );
+    compile_options(app)
+}
+
+/// Adds the options shared by commands which compile sources
+fn compile_options<'a, 'b>(app: App<'a, 'b>) -> App<'a, 'b> {
+    let target = self::target_arg();
+    app.arg(
             Arg::with_name("raw")
                 .last(true)
                 .help(
@@ -111,6 +157,32 @@ fn compile_command<'a, 'b>() -> App<'a, 'b> {
                 .takes_value(true)
                 .value_name("NAME"),
         )
+        .arg(
+            Arg::with_name("bin")
+                .help(
+                    "Build a standalone executable from FILE, whose module must export main/1.\n\
+                     The executable calls main/1 with its command-line arguments as a list of binaries.",
+                )
+                .next_line_help(true)
+                .long("bin")
+                .takes_value(true)
+                .value_name("FILE"),
+        )
+        .arg(
+            Arg::with_name("app")
+                .help(
+                    "Build an OTP application from its resource file, which is either an .app.src\n\
+                     file alongside its sources, or an .app file in ebin/ with its sources in src/.\n\
+                     May be given more than once; the executable starts each application's `mod`\n\
+                     callback, after those of the applications it depends on.",
+                )
+                .next_line_help(true)
+                .long("app")
+                .takes_value(true)
+                .value_name("FILE")
+                .multiple(true)
+                .number_of_values(1),
+        )
         .arg(
             Arg::with_name("output")
                 .help("Write output to FILE")
@@ -124,6 +196,24 @@ fn compile_command<'a, 'b>() -> App<'a, 'b> {
                 .long("output-dir")
                 .value_name("DIR"),
         )
+        .arg(
+            Arg::with_name("cache-dir")
+                .help(
+                    "Reuse object files from previous builds stored in DIR.\n\
+                     Modules whose sources, includes and compiler flags are unchanged are not recompiled.",
+                )
+                .next_line_help(true)
+                .long("cache-dir")
+                .value_name("DIR"),
+        )
+        .arg(
+            Arg::with_name("jobs")
+                .help("Compile up to N modules in parallel (default is the number of CPUs)")
+                .short("j")
+                .long("jobs")
+                .takes_value(true)
+                .value_name("N"),
+        )
         .arg(
             Arg::with_name("debug")
                 .help("Generate source level debug information (same as -C debuginfo=2)")
@@ -174,6 +264,14 @@ fn compile_command<'a, 'b>() -> App<'a, 'b> {
                 .case_insensitive(true)
                 .default_value("auto"),
         )
+        .arg(
+            Arg::with_name("error-format")
+                .help("How to format diagnostics, `json` writes one JSON object per line")
+                .next_line_help(true)
+                .long("error-format")
+                .possible_values(ErrorFormat::VARIANTS)
+                .default_value("human"),
+        )
         .arg(
             Arg::with_name("source-map-prefix")
                 .help("Remap source paths in all output (i.e. FROM/foo => TO/foo)")
@@ -203,6 +301,29 @@ fn compile_command<'a, 'b>() -> App<'a, 'b> {
                 .long("no-warn")
                 .conflicts_with("warnings-as-errors"),
         )
+        .arg(
+            Arg::with_name("warnings")
+                .help(
+                    "Control warnings like erlc: -Werror treats them as errors, -W0 disables them,\n\
+                     and -W1 (the default) reports them",
+                )
+                .next_line_help(true)
+                .short("W")
+                .takes_value(true)
+                .value_name("LEVEL")
+                .possible_values(&["0", "1", "error"])
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("analyze")
+                .help(
+                    "Warn about code which can never succeed, such as calls to functions with the \
+                     wrong arity,\nor arithmetic on atoms, by analyzing all modules together",
+                )
+                .next_line_help(true)
+                .long("analyze"),
+        )
         .arg(
             Arg::with_name("verbose")
                 .help("Set verbosity level")
@@ -248,6 +369,29 @@ fn compile_command<'a, 'b>() -> App<'a, 'b> {
                 .multiple(true)
                 .number_of_values(1),
         )
+        .arg(
+            Arg::with_name("code-path-prepend")
+                .help(
+                    "Add a directory to the beginning of the Erlang code path.\n\
+                     The code path is searched by -include_lib, in addition to \
+                     the applications in the library directories in ERL_LIBS",
+                )
+                .next_line_help(true)
+                .long("pa")
+                .value_name("DIR")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
+        .arg(
+            Arg::with_name("code-path-append")
+                .help("Add a directory to the end of the Erlang code path")
+                .long("pz")
+                .value_name("DIR")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1),
+        )
         .arg(
             Arg::with_name("emit")
                 .help(OutputType::help())
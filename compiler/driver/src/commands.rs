@@ -1,4 +1,7 @@
+pub(crate) mod build;
 pub(crate) mod compile;
+pub(crate) mod explain;
+pub(crate) mod lsp;
 pub(crate) mod print;
 
 use std::sync::Arc;
@@ -18,6 +21,7 @@ pub(super) fn create_diagnostics_handler(
     let config = DiagnosticsConfig {
         warnings_as_errors: options.warnings_as_errors,
         no_warn: options.no_warn,
+        error_format: options.error_format,
         display: DisplayConfig::default(),
     };
     Arc::new(DiagnosticsHandler::new(config, codemap, emitter))
@@ -0,0 +1,55 @@
+//! Builds a rebar3 or Mix project, by translating its configuration into the equivalent
+//! `compile` options.
+mod project;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use clap::ArgMatches;
+
+use log::debug;
+
+use liblumen_session::{CodegenOptions, DebuggingOptions, Options};
+use liblumen_util::diagnostics::{Emitter, FileName};
+
+use self::project::Project;
+
+pub fn handle_command<'a>(
+    c_opts: CodegenOptions,
+    z_opts: DebuggingOptions,
+    matches: &ArgMatches<'a>,
+    cwd: PathBuf,
+    emitter: Option<Arc<dyn Emitter>>,
+) -> anyhow::Result<()> {
+    let root = match matches.value_of_os("project") {
+        None => cwd.clone(),
+        Some(dir) => cwd.join(dir),
+    };
+    let project = Project::load(&root)?;
+    debug!("loaded project from {}: {:?}", root.display(), project);
+
+    let mut options = Options::new(c_opts, z_opts, cwd, &matches)?;
+
+    // Options given on the command line take precedence over those of the project
+    if !matches.is_present("name") && options.bin.is_none() && options.apps.is_empty() {
+        options.project_name = project.name;
+    }
+    let mut input_files = options.input_files.take().unwrap_or_default();
+    for dir in project.src_dirs.into_iter() {
+        let dir: FileName = dir.into();
+        if !input_files.contains(&dir) {
+            input_files.push(dir);
+        }
+    }
+    options.input_files = Some(input_files);
+    options.include_path.extend(project.include_dirs);
+    options.code_path.extend(project.code_path);
+    for (name, value) in project.defines.into_iter() {
+        options.defines.entry(name).or_insert(value);
+    }
+    if !options.no_warn {
+        options.warnings_as_errors |= project.warnings_as_errors;
+    }
+
+    super::compile::compile(options, emitter)
+}
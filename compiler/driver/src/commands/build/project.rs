@@ -0,0 +1,336 @@
+//! Reads the layout and compiler options of a rebar3 or Mix project.
+//!
+//! rebar3 projects are described by `rebar.config`, which we read directly, with
+//! dependencies found where `rebar3 get-deps` puts them. A `mix.exs` has to be evaluated,
+//! so for Mix projects we ask `mix` to export the parts of its configuration we need, in
+//! the same term format, which requires Elixir to be installed and dependencies fetched.
+use std::collections::{HashSet, VecDeque};
+use std::env;
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::term::{self, Term};
+
+const REBAR_CONFIG: &str = "rebar.config";
+const MIX_EXS: &str = "mix.exs";
+
+/// The environment variable that overrides the `mix` executable used to read `mix.exs`
+pub const MIX: &str = "MIX";
+
+/// Writes the configuration of the current Mix project to the file given as the first
+/// argument, as a list of `{Key, Value}` tuples in the same format as `rebar.config`.
+///
+/// The dependency paths come from the lock, so they include transitive dependencies.
+const MIX_SCRIPT: &str = r#"
+[path] = System.argv()
+config = Mix.Project.config()
+paths = fn paths -> Enum.map(paths, &to_charlist/1) end
+
+manifest = [
+  name: to_charlist(config[:app]),
+  src_dirs: paths.(config[:erlc_paths] ++ config[:elixirc_paths]),
+  include_dirs: paths.([config[:erlc_include_path]]),
+  erl_opts: config[:erlc_options],
+  deps: paths.(Map.values(Mix.Project.deps_paths()))
+]
+
+File.write!(path, :io_lib.format("~p.~n", [manifest]))
+"#;
+
+/// Everything needed to compile a project and its dependencies in one invocation
+#[derive(Debug)]
+pub struct Project {
+    pub name: String,
+    /// The directories containing the sources of the project and its dependencies
+    pub src_dirs: Vec<PathBuf>,
+    pub include_dirs: Vec<PathBuf>,
+    /// The `ebin` directories of dependencies, so that `-include_lib` can find them
+    pub code_path: Vec<PathBuf>,
+    /// Macros defined by the project's `erl_opts`
+    ///
+    /// The options of dependencies only contribute to the include path, as all modules
+    /// share the same set of macros.
+    pub defines: Vec<(String, Option<String>)>,
+    pub warnings_as_errors: bool,
+}
+impl Project {
+    /// Loads the project in `root` from its `rebar.config` or `mix.exs`
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        if root.join(REBAR_CONFIG).is_file() {
+            Self::from_rebar(root)
+        } else if root.join(MIX_EXS).is_file() {
+            Self::from_mix(root)
+        } else {
+            bail!(
+                "could not find {} or {} in {}",
+                REBAR_CONFIG,
+                MIX_EXS,
+                root.display()
+            )
+        }
+    }
+
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            src_dirs: Vec::new(),
+            include_dirs: Vec::new(),
+            code_path: Vec::new(),
+            defines: Vec::new(),
+            warnings_as_errors: false,
+        }
+    }
+
+    fn from_rebar(root: &Path) -> anyhow::Result<Self> {
+        let mut project = Self::new(dir_name(root)?);
+
+        // Umbrella projects keep their applications in `apps`, all of which we build
+        let mut apps = vec![root.to_path_buf()];
+        if let Ok(entries) = fs::read_dir(root.join("apps")) {
+            let mut app_dirs = entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>();
+            // `read_dir` order is platform-dependent, so sort to keep builds reproducible
+            app_dirs.sort();
+            apps.extend(app_dirs);
+        }
+
+        // Dependencies, including transitive ones, are fetched to `_build/default/lib`,
+        // unless they are checked out locally in `_checkouts`
+        let search_dirs = [root.join("_checkouts"), root.join("_build/default/lib")];
+        let mut seen = HashSet::new();
+        let mut pending = apps
+            .into_iter()
+            .map(|dir| (dir, true))
+            .collect::<VecDeque<_>>();
+        while let Some((app_dir, is_project)) = pending.pop_front() {
+            let config = read_rebar_config(&app_dir)?;
+            project.add_application(&app_dir, &config, &["src"], is_project)?;
+
+            for dependency in rebar_dependencies(&config)? {
+                if !seen.insert(dependency.clone()) {
+                    continue;
+                }
+                let dependency_dir = search_dirs
+                    .iter()
+                    .map(|dir| dir.join(&dependency))
+                    .find(|dir| dir.is_dir())
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "dependency `{}` has not been fetched, run `rebar3 get-deps` first",
+                            dependency
+                        )
+                    })?;
+                project.code_path.push(dependency_dir.join("ebin"));
+                pending.push_back((dependency_dir, false));
+            }
+        }
+
+        Ok(project)
+    }
+
+    fn from_mix(root: &Path) -> anyhow::Result<Self> {
+        let manifest = export_mix_manifest(root)?;
+        let name = lookup(&manifest, "name")
+            .and_then(Term::as_string)
+            .ok_or_else(|| anyhow!("expected the Mix project to define `app`"))?;
+        let mut project = Self::new(name);
+
+        project.add_application(root, &manifest, &[], true)?;
+        if let Some(dirs) = lookup(&manifest, "include_dirs") {
+            for dir in strings(dirs, "include_dirs")? {
+                let dir = root.join(dir);
+                if !project.include_dirs.contains(&dir) {
+                    project.include_dirs.push(dir);
+                }
+            }
+        }
+
+        if let Some(dependencies) = lookup(&manifest, "deps") {
+            for dependency_dir in strings(dependencies, "deps")? {
+                let dependency_dir = root.join(dependency_dir);
+                // Mix can depend on rebar3 projects, whose layout we already understand,
+                // otherwise we assume the conventional layout of a Mix project
+                let config = read_rebar_config(&dependency_dir)?;
+                project.add_application(&dependency_dir, &config, &["src", "lib"], false)?;
+                project.code_path.push(dependency_dir.join("ebin"));
+            }
+        }
+
+        Ok(project)
+    }
+
+    /// Adds the sources and include directories of the application in `app_dir`, which
+    /// is configured by `config`, a list of `{Key, Value}` tuples
+    ///
+    /// Sources are found in the directories listed by `src_dirs`, or `default_src_dirs`
+    fn add_application(
+        &mut self,
+        app_dir: &Path,
+        config: &[Term],
+        default_src_dirs: &[&str],
+        is_project: bool,
+    ) -> anyhow::Result<()> {
+        let erl_opts = match lookup(config, "erl_opts") {
+            None => &[],
+            Some(opts) => opts
+                .as_list()
+                .ok_or_else(|| anyhow!("expected `erl_opts` to be a list"))?,
+        };
+
+        // rebar3 reads `src_dirs` from the top-level of the config, rebar2 from `erl_opts`
+        let src_dirs = match lookup(config, "src_dirs").or_else(|| lookup(erl_opts, "src_dirs")) {
+            Some(dirs) => strings(dirs, "src_dirs")?,
+            None => default_src_dirs.iter().map(|dir| dir.to_string()).collect(),
+        };
+        for dir in src_dirs {
+            let dir = app_dir.join(dir);
+            if dir.is_dir() && !self.src_dirs.contains(&dir) {
+                self.src_dirs.push(dir);
+            }
+        }
+
+        let include_dir = app_dir.join("include");
+        if include_dir.is_dir() {
+            self.include_dirs.push(include_dir);
+        }
+
+        for opt in erl_opts.iter() {
+            match (opt.as_atom(), opt.as_tuple()) {
+                (Some("warnings_as_errors"), _) if is_project => self.warnings_as_errors = true,
+                (_, Some([key, dir])) if key.as_atom() == Some("i") => {
+                    let dir = dir
+                        .as_string()
+                        .ok_or_else(|| anyhow!("expected {{i, Dir}} to name a directory"))?;
+                    self.include_dirs.push(app_dir.join(dir));
+                }
+                (_, Some([key, name])) if is_project && key.as_atom() == Some("d") => {
+                    self.defines.push((macro_name(name)?, None));
+                }
+                (_, Some([key, name, value])) if is_project && key.as_atom() == Some("d") => {
+                    self.defines
+                        .push((macro_name(name)?, Some(value.to_string())));
+                }
+                // Everything else, e.g. debug_info, either has a flag of its own, or
+                // does not apply to Lumen
+                _ => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn read_rebar_config(app_dir: &Path) -> anyhow::Result<Vec<Term>> {
+    let path = app_dir.join(REBAR_CONFIG);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let source =
+        fs::read_to_string(&path).with_context(|| format!("could not read {}", path.display()))?;
+    term::consult(&source).with_context(|| format!("invalid {}", path.display()))
+}
+
+/// Returns the names of the dependencies listed in `deps`, which may be given as `Name`,
+/// `{Name, Version}`, `{Name, Source}` or `{Name, Version, Source}`
+fn rebar_dependencies(config: &[Term]) -> anyhow::Result<Vec<String>> {
+    let deps = match lookup(config, "deps") {
+        None => return Ok(Vec::new()),
+        Some(deps) => deps
+            .as_list()
+            .ok_or_else(|| anyhow!("expected `deps` to be a list"))?,
+    };
+    deps.iter()
+        .map(|dep| {
+            dep.as_atom()
+                .or_else(|| {
+                    dep.as_tuple()
+                        .and_then(|t| t.first())
+                        .and_then(Term::as_atom)
+                })
+                .map(|name| name.to_string())
+                .ok_or_else(|| anyhow!("invalid dependency in `deps`: {}", dep))
+        })
+        .collect()
+}
+
+fn export_mix_manifest(root: &Path) -> anyhow::Result<Vec<Term>> {
+    let mix = env::var_os(MIX).unwrap_or_else(|| OsString::from("mix"));
+    let manifest_path = env::temp_dir().join(format!("lumen-mix-{}.config", process::id()));
+    let output = Command::new(&mix)
+        .current_dir(root)
+        .args(&[
+            "run",
+            "--no-compile",
+            "--no-deps-check",
+            "--no-archives-check",
+            "--no-start",
+            "-e",
+            MIX_SCRIPT,
+        ])
+        .arg(&manifest_path)
+        .output()
+        .with_context(|| {
+            format!(
+                "could not run {} to read {}; Elixir must be installed to build Mix projects",
+                mix.to_string_lossy(),
+                root.join(MIX_EXS).display()
+            )
+        })?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "{}",
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ))
+        .with_context(|| format!("could not read {}", root.join(MIX_EXS).display()));
+    }
+
+    let source = fs::read_to_string(&manifest_path);
+    fs::remove_file(&manifest_path).ok();
+    let source = source.with_context(|| {
+        format!(
+            "{} did not export the project configuration",
+            mix.to_string_lossy()
+        )
+    })?;
+    match term::parse(&source)? {
+        Term::List(manifest) => Ok(manifest),
+        _ => bail!("expected the exported Mix project configuration to be a list"),
+    }
+}
+
+/// Returns the value of the first `{Key, Value}` tuple in `config` with the given key
+fn lookup<'a>(config: &'a [Term], key: &str) -> Option<&'a Term> {
+    config.iter().find_map(|entry| match entry.as_tuple() {
+        Some([k, value]) if k.as_atom() == Some(key) => Some(value),
+        _ => None,
+    })
+}
+
+fn strings(term: &Term, key: &str) -> anyhow::Result<Vec<String>> {
+    term.as_list()
+        .and_then(|elements| elements.iter().map(Term::as_string).collect())
+        .ok_or_else(|| anyhow!("expected `{}` to be a list of strings", key))
+}
+
+fn macro_name(name: &Term) -> anyhow::Result<String> {
+    name.as_atom()
+        .map(|name| name.to_string())
+        .ok_or_else(|| anyhow!("expected macro name to be an atom, got {}", name))
+}
+
+fn dir_name(dir: &Path) -> anyhow::Result<String> {
+    let dir = dir
+        .canonicalize()
+        .with_context(|| format!("could not read {}", dir.display()))?;
+    dir.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.to_string())
+        .ok_or_else(|| anyhow!("could not determine project name from {}", dir.display()))
+}
@@ -1,4 +1,5 @@
 use std::ops::Deref;
+use std::panic;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::thread;
@@ -15,12 +16,14 @@ use liblumen_codegen::linker::{self, LinkerInfo};
 use liblumen_codegen::meta::{CodegenResults, ProjectInfo};
 use liblumen_session::{CodegenOptions, DebuggingOptions, Options};
 use liblumen_util::diagnostics::{CodeMap, Emitter};
+use liblumen_util::threading;
 use liblumen_util::time::HumanDuration;
 
 use crate::commands::*;
+use crate::compiler::analysis;
+use crate::compiler::cache::ArtifactCache;
 use crate::compiler::prelude::{Compiler as CompilerQueryGroup, *};
 use crate::compiler::Compiler;
-use crate::task;
 
 const NUM_GENERATED_MODULES: usize = 3;
 
@@ -33,16 +36,34 @@ pub fn handle_command<'a>(
 ) -> anyhow::Result<()> {
     // Extract options from provided arguments
     let options = Options::new(c_opts, z_opts, cwd, &matches)?;
+
+    compile(options, emitter)
+}
+
+/// Compiles the inputs described by `options`, and links them if requested
+pub(super) fn compile(options: Options, emitter: Option<Arc<dyn Emitter>>) -> anyhow::Result<()> {
     // Construct empty code map for use in compilation
     let codemap = Arc::new(CodeMap::new());
     // Set up diagnostics
     let diagnostics = create_diagnostics_handler(&options, codemap.clone(), emitter);
 
+    // Open the artifact cache, if enabled
+    //
+    // NOTE: This must happen before anything is interned, see `ArtifactCache::restore_symbols`
+    let artifact_cache = match options.cache_dir.as_ref() {
+        None => None,
+        Some(dir) => {
+            let cache = ArtifactCache::open(dir)?;
+            cache.restore_symbols()?;
+            Some(cache)
+        }
+    };
+
     // Initialize codegen backend
     codegen::init(&options)?;
 
     // Build query database
-    let mut db = Compiler::new(codemap, diagnostics);
+    let mut db = Compiler::new(codemap, diagnostics, artifact_cache);
 
     // The core of the query system is the initial set of options provided to the compiler
     //
@@ -58,24 +79,6 @@ pub fn handle_command<'a>(
     }
 
     let start = Instant::now();
-    let mut tasks = inputs
-        .iter()
-        .cloned()
-        .map(|input| {
-            debug!("spawning worker for {:?}", input);
-            let snapshot = db.snapshot();
-            task::spawn(async move {
-                let result = snapshot.compile(input);
-                if result.is_err() {
-                    let diagnostics = snapshot.diagnostics();
-                    let input_info = snapshot.lookup_intern_input(input);
-                    diagnostics.failed("Failed", format!("{}", input_info.source_name()));
-                }
-                result
-            })
-        })
-        .collect::<Vec<_>>();
-
     let options = db.options();
     let mut codegen_results = CodegenResults {
         project_name: options.project_name.clone(),
@@ -85,13 +88,60 @@ pub fn handle_command<'a>(
         project_info: ProjectInfo::new(&options),
     };
 
-    debug!("awaiting results from workers ({} units)", num_inputs);
+    let jobs = options.jobs.unwrap_or_else(num_cpus::get);
+    let compiled = threading::spawn_thread_pool(jobs, |pool| {
+        // Each module reports diagnostics to its own buffer, which is printed once it
+        // has been compiled, so that output is grouped by module and appears in input
+        // order, no matter which worker finishes first
+        let tasks = inputs
+            .iter()
+            .cloned()
+            .map(|input| {
+                debug!("queueing {:?}", input);
+                let (diagnostics, captured) = db.diagnostics().capture();
+                let snapshot = db.snapshot_with_diagnostics(Arc::new(diagnostics));
+                let task = pool.spawn(move || {
+                    let result = snapshot.compile(input);
+                    if result.is_err() {
+                        let diagnostics = snapshot.diagnostics();
+                        let input_info = snapshot.lookup_intern_input(input);
+                        diagnostics.failed("Failed", format!("{}", input_info.source_name()));
+                    }
+                    result
+                });
+                (task, captured)
+            })
+            .collect::<Vec<_>>();
+
+        debug!(
+            "awaiting results from {} workers ({} units)",
+            pool.size(),
+            num_inputs
+        );
+
+        tasks
+            .into_iter()
+            .filter_map(|(task, captured)| {
+                let result = task.join();
+                captured.flush().unwrap();
+                match result {
+                    Ok(compiled) => compiled.ok(),
+                    Err(err) => panic::resume_unwind(err),
+                }
+            })
+            .collect::<Vec<_>>()
+    });
+    codegen_results.modules.extend(compiled);
 
     let diagnostics = db.diagnostics();
-    for task in tasks.drain(..) {
-        if let Ok(compiled) = task::join(task).unwrap() {
-            codegen_results.modules.push(compiled);
-        }
+
+    // Look for code that can never succeed, which needs all modules at once
+    if options.analyze && !diagnostics.has_errors() {
+        let modules = inputs
+            .iter()
+            .filter_map(|input| db.input_eir(*input).ok())
+            .collect::<Vec<_>>();
+        analysis::check(&modules, diagnostics);
     }
 
     // Do not proceed to linking if there were compilation errors
@@ -107,6 +157,15 @@ pub fn handle_command<'a>(
     let target_machine = db.get_target_machine(thread_id);
     let atoms = db.take_atoms();
     let symbols = db.take_symbols();
+
+    // Record the symbol ids referenced by this build, so they can be restored by the next
+    if let Some(cache) = db.artifact_cache() {
+        let used_atoms = atoms.iter().map(|atom| atom.as_usize());
+        let used_symbols = symbols
+            .iter()
+            .flat_map(|symbol| vec![symbol.module, symbol.function]);
+        cache.save_symbols(used_atoms.chain(used_symbols))?;
+    }
     codegen::generators::run(
         &options,
         &mut codegen_results,
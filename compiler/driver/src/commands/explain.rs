@@ -0,0 +1,19 @@
+use clap::ArgMatches;
+
+use anyhow::anyhow;
+
+use liblumen_util::diagnostics::codes;
+
+/// The main entry point for the 'explain' command
+pub fn handle_command<'a>(matches: &ArgMatches<'a>) -> anyhow::Result<()> {
+    let code = matches.value_of("code").unwrap();
+    let code = codes::lookup(code).ok_or_else(|| {
+        anyhow!(
+            "{} is not a valid diagnostic code, they look like {}",
+            code,
+            codes::CODES[0].code
+        )
+    })?;
+    print!("{}", code.explanation);
+    Ok(())
+}
@@ -0,0 +1,462 @@
+//! A minimal Language Server for Erlang sources, speaking JSON-RPC over standard input and output.
+//!
+//! Open documents are kept in sync incrementally and reparsed on every change to publish the
+//! parser's diagnostics.  Document symbols and go-to-definition within a document come from a
+//! lightweight scan of the source in `symbols`, so that they keep working while the source doesn't
+//! parse.
+mod document;
+mod symbols;
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, BufRead, Read, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+
+use clap::ArgMatches;
+
+use log::warn;
+
+use libeir_frontend::erlang::ErlangFrontend;
+use libeir_frontend::{AnyFrontend, DynFrontend};
+use libeir_syntax_erl::ParseConfig;
+
+use liblumen_session::code_path;
+use liblumen_util::diagnostics::{CodeMap, Files, LabelStyle, Severity};
+use liblumen_util::json::Json;
+
+use self::document::Document;
+
+const METHOD_NOT_FOUND: f64 = -32601.0;
+const INTERNAL_ERROR: f64 = -32603.0;
+
+// `SymbolKind.Function`
+const FUNCTION_SYMBOL_KIND: usize = 12;
+
+/// The main entry point for the 'lsp' command
+pub fn handle_command<'a>(_matches: &ArgMatches<'a>, cwd: PathBuf) -> anyhow::Result<()> {
+    let stdin = io::stdin();
+    let stdout = io::stdout();
+    let mut server = Server::new(cwd);
+
+    server.run(&mut stdin.lock(), &mut stdout.lock())
+}
+
+struct Server {
+    cwd: PathBuf,
+    code_paths: VecDeque<PathBuf>,
+    documents: HashMap<String, Document>,
+    shutdown: bool,
+}
+
+impl Server {
+    fn new(cwd: PathBuf) -> Self {
+        Self {
+            cwd,
+            code_paths: code_path::from_env().into(),
+            documents: HashMap::new(),
+            shutdown: false,
+        }
+    }
+
+    fn run(&mut self, reader: &mut impl BufRead, writer: &mut impl Write) -> anyhow::Result<()> {
+        while let Some(message) = read_message(reader)? {
+            let method = match message.get("method").and_then(Json::as_str) {
+                Some(method) => method.to_string(),
+                // Responses to requests from the server, which it doesn't send
+                None => continue,
+            };
+            let params = message.get("params").cloned().unwrap_or(Json::Null);
+
+            if method == "exit" {
+                return if self.shutdown {
+                    Ok(())
+                } else {
+                    Err(anyhow!(
+                        "exit notification received before shutdown request"
+                    ))
+                };
+            }
+
+            match message.get("id") {
+                Some(id) => {
+                    let response = match self.request(&method, &params) {
+                        Some(Ok(result)) => Json::object(vec![
+                            ("jsonrpc", "2.0".into()),
+                            ("id", id.clone()),
+                            ("result", result),
+                        ]),
+                        Some(Err(error)) => {
+                            error_response(id, INTERNAL_ERROR, format!("{:#}", error))
+                        }
+                        None => error_response(
+                            id,
+                            METHOD_NOT_FOUND,
+                            format!("method not found: {}", method),
+                        ),
+                    };
+
+                    write_message(writer, &response)?;
+                }
+                None => match self.notification(&method, &params) {
+                    Ok(notifications) => {
+                        for notification in notifications {
+                            write_message(writer, &notification)?;
+                        }
+                    }
+                    Err(error) => warn!("{} notification failed: {:#}", method, error),
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `None` if the method is not supported
+    fn request(&mut self, method: &str, params: &Json) -> Option<anyhow::Result<Json>> {
+        let result = match method {
+            "initialize" => Ok(Json::object(vec![
+                (
+                    "capabilities",
+                    Json::object(vec![
+                        (
+                            "textDocumentSync",
+                            Json::object(vec![
+                                ("openClose", true.into()),
+                                // `TextDocumentSyncKind.Incremental`
+                                ("change", 2.into()),
+                            ]),
+                        ),
+                        ("definitionProvider", true.into()),
+                        ("documentSymbolProvider", true.into()),
+                    ]),
+                ),
+                (
+                    "serverInfo",
+                    Json::object(vec![
+                        ("name", "lumen".into()),
+                        ("version", crate::LUMEN_RELEASE.into()),
+                    ]),
+                ),
+            ])),
+            "shutdown" => {
+                self.shutdown = true;
+                Ok(Json::Null)
+            }
+            "textDocument/documentSymbol" => self.document_symbol(params),
+            "textDocument/definition" => self.definition(params),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Returns the notifications to send in response
+    fn notification(&mut self, method: &str, params: &Json) -> anyhow::Result<Vec<Json>> {
+        match method {
+            "textDocument/didOpen" => {
+                let text_document = params
+                    .get("textDocument")
+                    .ok_or_else(|| anyhow!("missing textDocument"))?;
+                let uri = uri(text_document)?;
+                let text = text_document
+                    .get("text")
+                    .and_then(Json::as_str)
+                    .ok_or_else(|| anyhow!("missing textDocument.text"))?;
+                let document = Document {
+                    uri: uri.to_string(),
+                    version: text_document.get("version").and_then(Json::as_usize),
+                    text: text.to_string(),
+                };
+                let notification = self.publish_diagnostics(&document);
+                self.documents.insert(uri.to_string(), document);
+
+                Ok(vec![notification])
+            }
+            "textDocument/didChange" => {
+                let text_document = params
+                    .get("textDocument")
+                    .ok_or_else(|| anyhow!("missing textDocument"))?;
+                let uri = uri(text_document)?;
+                let changes = params
+                    .get("contentChanges")
+                    .and_then(Json::as_array)
+                    .ok_or_else(|| anyhow!("missing contentChanges"))?;
+                let document = self
+                    .documents
+                    .get_mut(uri)
+                    .ok_or_else(|| anyhow!("document ({}) is not open", uri))?;
+
+                // Changes are applied in order, each to the result of the previous one
+                for change in changes {
+                    document.apply_change(change)?;
+                }
+                document.version = text_document.get("version").and_then(Json::as_usize);
+
+                let document = &self.documents[uri];
+
+                Ok(vec![self.publish_diagnostics(document)])
+            }
+            "textDocument/didClose" => {
+                let text_document = params
+                    .get("textDocument")
+                    .ok_or_else(|| anyhow!("missing textDocument"))?;
+                let uri = uri(text_document)?;
+                self.documents.remove(uri);
+
+                // Clear the diagnostics of the closed document
+                Ok(vec![notification(
+                    "textDocument/publishDiagnostics",
+                    Json::object(vec![
+                        ("uri", uri.into()),
+                        ("diagnostics", Json::Array(vec![])),
+                    ]),
+                )])
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn document_symbol(&self, params: &Json) -> anyhow::Result<Json> {
+        let document = self.document(params)?;
+
+        let symbols = symbols::functions(&document.text)
+            .into_iter()
+            .map(|function| {
+                Json::object(vec![
+                    (
+                        "name",
+                        format!("{}/{}", function.name, function.arity).into(),
+                    ),
+                    ("kind", FUNCTION_SYMBOL_KIND.into()),
+                    ("range", document.range(&function.range)),
+                    ("selectionRange", document.range(&function.selection_range)),
+                ])
+            })
+            .collect();
+
+        Ok(Json::Array(symbols))
+    }
+
+    fn definition(&self, params: &Json) -> anyhow::Result<Json> {
+        let document = self.document(params)?;
+        let offset = params
+            .get("position")
+            .and_then(|position| document.offset(position))
+            .ok_or_else(|| anyhow!("missing or invalid position"))?;
+
+        let reference = match symbols::reference_at(&document.text, offset) {
+            Some(reference) => reference,
+            None => return Ok(Json::Null),
+        };
+        let functions = symbols::functions(&document.text);
+        let function = functions.iter().find(|function| {
+            function.name == reference.name
+                && reference
+                    .arity
+                    .map(|arity| function.arity == arity)
+                    .unwrap_or(true)
+        });
+
+        Ok(match function {
+            Some(function) => Json::object(vec![
+                ("uri", document.uri.as_str().into()),
+                ("range", document.range(&function.selection_range)),
+            ]),
+            None => Json::Null,
+        })
+    }
+
+    fn document(&self, params: &Json) -> anyhow::Result<&Document> {
+        let text_document = params
+            .get("textDocument")
+            .ok_or_else(|| anyhow!("missing textDocument"))?;
+        let uri = uri(text_document)?;
+
+        self.documents
+            .get(uri)
+            .ok_or_else(|| anyhow!("document ({}) is not open", uri))
+    }
+
+    /// Parses the document and returns the `textDocument/publishDiagnostics` notification for the
+    /// parser's diagnostics
+    fn publish_diagnostics(&self, document: &Document) -> Json {
+        let codemap = Arc::new(CodeMap::new());
+        let mut parse_config = ParseConfig::new();
+        // Like `erlc`, includes are searched for relative to the document and the current
+        // directory
+        if let Some(dir) = path(&document.uri).and_then(|path| path.parent().map(PathBuf::from)) {
+            parse_config.include_paths.push_back(dir);
+        }
+        parse_config.include_paths.push_back(self.cwd.clone());
+        parse_config.code_paths = self.code_paths.clone();
+
+        let frontend: AnyFrontend = ErlangFrontend::new(parse_config, codemap.clone()).into();
+        let (_, diagnostics) = frontend.parse_string_dyn(&document.text);
+
+        let diagnostics = diagnostics
+            .iter()
+            .map(|diagnostic| {
+                // Only labels in this document can be shown at their range; diagnostics for
+                // included files are shown at the start of the document
+                let range = diagnostic
+                    .labels
+                    .iter()
+                    .filter(|label| label.style == LabelStyle::Primary)
+                    .find(|label| {
+                        codemap
+                            .source(label.file_id)
+                            .map(|source| source.as_ref() == document.text)
+                            .unwrap_or(false)
+                    })
+                    .map(|label| label.range.clone())
+                    .unwrap_or(0..0);
+
+                let mut message = diagnostic.message.clone();
+                for note in diagnostic.notes.iter() {
+                    message.push('\n');
+                    message.push_str(note);
+                }
+
+                let mut pairs = vec![
+                    ("range", document.range(&range)),
+                    ("severity", severity(diagnostic.severity).into()),
+                    ("source", "lumen".into()),
+                    ("message", message.into()),
+                ];
+                if let Some(ref code) = diagnostic.code {
+                    pairs.push(("code", code.as_str().into()));
+                }
+
+                Json::object(pairs)
+            })
+            .collect();
+
+        let mut params = vec![("uri", document.uri.as_str().into())];
+        if let Some(version) = document.version {
+            params.push(("version", version.into()));
+        }
+        params.push(("diagnostics", Json::Array(diagnostics)));
+
+        notification("textDocument/publishDiagnostics", Json::object(params))
+    }
+}
+
+/// `DiagnosticSeverity`
+fn severity(severity: Severity) -> usize {
+    match severity {
+        Severity::Bug | Severity::Error => 1,
+        Severity::Warning => 2,
+        Severity::Note => 3,
+        Severity::Help => 4,
+    }
+}
+
+fn uri(text_document: &Json) -> anyhow::Result<&str> {
+    text_document
+        .get("uri")
+        .and_then(Json::as_str)
+        .ok_or_else(|| anyhow!("missing textDocument.uri"))
+}
+
+/// Converts a `file://` URI to a path, decoding percent-encoded bytes
+fn path(uri: &str) -> Option<PathBuf> {
+    let encoded = if uri.starts_with("file://") {
+        &uri["file://".len()..]
+    } else {
+        return None;
+    };
+    let encoded_bytes = encoded.as_bytes();
+    let mut bytes = Vec::with_capacity(encoded_bytes.len());
+    let mut index = 0;
+
+    while index < encoded_bytes.len() {
+        match encoded_bytes[index] {
+            b'%' if index + 2 < encoded_bytes.len() => {
+                let hex = std::str::from_utf8(&encoded_bytes[index + 1..index + 3]).ok()?;
+                bytes.push(u8::from_str_radix(hex, 16).ok()?);
+                index += 3;
+            }
+            byte => {
+                bytes.push(byte);
+                index += 1;
+            }
+        }
+    }
+
+    String::from_utf8(bytes).ok().map(PathBuf::from)
+}
+
+fn notification(method: &str, params: Json) -> Json {
+    Json::object(vec![
+        ("jsonrpc", "2.0".into()),
+        ("method", method.into()),
+        ("params", params),
+    ])
+}
+
+fn error_response(id: &Json, code: f64, message: String) -> Json {
+    Json::object(vec![
+        ("jsonrpc", "2.0".into()),
+        ("id", id.clone()),
+        (
+            "error",
+            Json::object(vec![
+                ("code", Json::Number(code)),
+                ("message", message.into()),
+            ]),
+        ),
+    ])
+}
+
+/// Reads a message with its `Content-Length` header.  Returns `None` at the end of input.
+fn read_message(reader: &mut impl BufRead) -> anyhow::Result<Option<Json>> {
+    let mut content_length = None;
+
+    loop {
+        let mut line = String::new();
+
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+
+        if line.is_empty() {
+            break;
+        }
+
+        let mut parts = line.splitn(2, ':');
+        let name = parts.next().unwrap().trim();
+
+        if name.eq_ignore_ascii_case("Content-Length") {
+            let value = parts.next().unwrap_or("").trim();
+            content_length = Some(
+                value
+                    .parse::<usize>()
+                    .with_context(|| format!("invalid Content-Length ({})", value))?,
+            );
+        }
+    }
+
+    let content_length = content_length.ok_or_else(|| anyhow!("missing Content-Length header"))?;
+    let mut content = vec![0; content_length];
+    reader.read_exact(&mut content)?;
+    let content = String::from_utf8(content).context("message is not UTF-8")?;
+
+    Json::parse(&content).map(Some)
+}
+
+fn write_message(writer: &mut impl Write, message: &Json) -> anyhow::Result<()> {
+    let content = message.to_string();
+    write!(
+        writer,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    writer.flush()?;
+
+    Ok(())
+}
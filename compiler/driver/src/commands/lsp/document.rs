@@ -0,0 +1,91 @@
+use std::ops::Range;
+
+use liblumen_util::json::Json;
+
+/// An open document, kept in sync with the editor's buffer
+pub struct Document {
+    pub uri: String,
+    pub version: Option<usize>,
+    pub text: String,
+}
+
+impl Document {
+    /// Applies a `TextDocumentContentChangeEvent`: without a range, the change replaces the whole
+    /// text, otherwise only the range is replaced.
+    pub fn apply_change(&mut self, change: &Json) -> anyhow::Result<()> {
+        let text = change
+            .get("text")
+            .and_then(Json::as_str)
+            .ok_or_else(|| anyhow::anyhow!("content change is missing text"))?;
+
+        match change.get("range") {
+            Some(range) => {
+                let range = self
+                    .range_to_offsets(range)
+                    .ok_or_else(|| anyhow::anyhow!("content change has an invalid range"))?;
+                self.text.replace_range(range, text);
+            }
+            None => self.text = text.to_string(),
+        }
+
+        Ok(())
+    }
+
+    /// Converts an LSP `Position`, where `character` counts UTF-16 code units, to a byte offset.
+    /// Positions past the end of a line are clamped to the end of the line, as the protocol
+    /// requires.
+    pub fn offset(&self, position: &Json) -> Option<usize> {
+        let line = position.get("line")?.as_usize()?;
+        let character = position.get("character")?.as_usize()?;
+
+        let line_start = if line == 0 {
+            0
+        } else {
+            self.text
+                .match_indices('\n')
+                .nth(line - 1)
+                .map(|(index, _)| index + 1)?
+        };
+
+        let mut utf16_offset = 0;
+
+        for (index, c) in self.text[line_start..].char_indices() {
+            if utf16_offset >= character || c == '\n' {
+                return Some(line_start + index);
+            }
+
+            utf16_offset += c.len_utf16();
+        }
+
+        Some(self.text.len())
+    }
+
+    /// Converts a byte offset to an LSP `Position`
+    pub fn position(&self, offset: usize) -> Json {
+        let offset = offset.min(self.text.len());
+        let before = &self.text[..offset];
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let character: usize = before[line_start..].chars().map(char::len_utf16).sum();
+
+        Json::object(vec![("line", line.into()), ("character", character.into())])
+    }
+
+    pub fn range(&self, range: &Range<usize>) -> Json {
+        Json::object(vec![
+            ("start", self.position(range.start)),
+            ("end", self.position(range.end)),
+        ])
+    }
+
+    fn range_to_offsets(&self, range: &Json) -> Option<Range<usize>> {
+        let start = self.offset(range.get("start")?)?;
+        let end = self.offset(range.get("end")?)?;
+
+        if start <= end {
+            Some(start..end)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! A lightweight scan of Erlang source for function definitions and references.
+//!
+//! Unlike the parser, this works on source that doesn't parse yet, which is most of the time while
+//! it is being edited, and it keeps the byte ranges of names, which the EIR doesn't.
+
+use std::ops::Range;
+
+/// A function defined in the document
+#[derive(Clone, Debug, PartialEq)]
+pub struct Function {
+    pub name: String,
+    pub arity: usize,
+    /// From the first clause head to the end of the last clause
+    pub range: Range<usize>,
+    /// The name in the first clause head
+    pub selection_range: Range<usize>,
+}
+
+/// A reference to a local function, such as a call or `fun name/arity`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reference {
+    pub name: String,
+    /// `None` when the arity can't be determined, such as for a bare atom
+    pub arity: Option<usize>,
+}
+
+pub fn functions(source: &str) -> Vec<Function> {
+    let tokens = tokenize(source);
+    let mut functions: Vec<Function> = Vec::new();
+    let mut form_start = true;
+    let mut index = 0;
+
+    while index < tokens.len() {
+        let token = &tokens[index];
+
+        if form_start {
+            form_start = false;
+
+            if let (Kind::Atom(name), Some(Kind::Open('('))) =
+                (&token.kind, tokens.get(index + 1).map(|token| &token.kind))
+            {
+                let arity = arity(&tokens, index + 1);
+                let form_end = form_end(&tokens, index, source.len());
+
+                match functions.last_mut() {
+                    // Clauses of the same function are usually in one form, but a clause ended by
+                    // `.` by mistake is reported by the compiler, not here
+                    Some(last) if &last.name == name && last.arity == arity => {
+                        last.range.end = form_end;
+                    }
+                    _ => functions.push(Function {
+                        name: name.clone(),
+                        arity,
+                        range: token.range.start..form_end,
+                        selection_range: token.range.clone(),
+                    }),
+                }
+            }
+        }
+
+        if token.kind == Kind::Dot {
+            form_start = true;
+        }
+
+        index += 1;
+    }
+
+    functions
+}
+
+/// Finds the local function referenced by the atom at `offset`
+pub fn reference_at(source: &str, offset: usize) -> Option<Reference> {
+    let tokens = tokenize(source);
+    // The cursor can be between two tokens, such as at the end of the name in `name(`, so prefer
+    // the atom
+    let (index, name) = tokens
+        .iter()
+        .enumerate()
+        .find_map(|(index, token)| match token.kind {
+            Kind::Atom(ref name) if token.range.start <= offset && offset <= token.range.end => {
+                Some((index, name.clone()))
+            }
+            _ => None,
+        })?;
+
+    // Remote calls, `module:name(...)`, are not in this document
+    if 0 < index && tokens[index - 1].kind == Kind::Colon {
+        return None;
+    }
+    if let Some(Kind::Colon) = tokens.get(index + 1).map(|token| &token.kind) {
+        return None;
+    }
+
+    let arity = match tokens.get(index + 1).map(|token| &token.kind) {
+        Some(Kind::Open('(')) => Some(arity(&tokens, index + 1)),
+        // `fun name/1` and `-export([name/1])`
+        Some(Kind::Slash) => match tokens.get(index + 2).map(|token| &token.kind) {
+            Some(Kind::Integer(arity)) => Some(*arity),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    Some(Reference { name, arity })
+}
+
+// Private
+
+#[derive(Clone, Debug, PartialEq)]
+enum Kind {
+    Atom(String),
+    Integer(usize),
+    Open(char),
+    Close(char),
+    /// Keywords that are closed by `end`
+    Begin,
+    End,
+    Comma,
+    Colon,
+    Slash,
+    /// The `.` that ends a form
+    Dot,
+    Other,
+}
+
+#[derive(Clone, Debug)]
+struct Token {
+    kind: Kind,
+    range: Range<usize>,
+}
+
+const BLOCK_KEYWORDS: &[&str] = &["begin", "case", "if", "receive", "try"];
+
+/// Counts the arguments between the `(` at `open` and its matching `)`
+fn arity(tokens: &[Token], open: usize) -> usize {
+    let mut depth = 1;
+    let mut commas = 0;
+    let mut empty = true;
+
+    for token in &tokens[open + 1..] {
+        match token.kind {
+            Kind::Open(_) | Kind::Begin => depth += 1,
+            Kind::Close(_) | Kind::End => {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+            Kind::Comma if depth == 1 => commas += 1,
+            Kind::Dot => break,
+            _ => (),
+        }
+
+        empty = false;
+    }
+
+    if empty {
+        0
+    } else {
+        commas + 1
+    }
+}
+
+/// Returns the end of the form containing the token at `index`
+fn form_end(tokens: &[Token], index: usize, source_len: usize) -> usize {
+    tokens[index..]
+        .iter()
+        .find(|token| token.kind == Kind::Dot)
+        .map(|token| token.range.end)
+        .unwrap_or(source_len)
+}
+
+fn tokenize(source: &str) -> Vec<Token> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let start = index;
+        let byte = bytes[index];
+
+        let kind = match byte {
+            b'%' => {
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    index += 1;
+                }
+                continue;
+            }
+            _ if byte.is_ascii_whitespace() => {
+                index += 1;
+                continue;
+            }
+            b'a'..=b'z' => {
+                index = skip_name(bytes, index);
+                let name = &source[start..index];
+
+                if BLOCK_KEYWORDS.contains(&name) {
+                    Kind::Begin
+                } else if name == "fun" {
+                    // `fun (...) -> ... end` is a block, but `fun name/1` and `fun m:f/1` are not
+                    match next_non_whitespace(bytes, index) {
+                        Some(b'(') => Kind::Begin,
+                        _ => Kind::Other,
+                    }
+                } else if name == "end" {
+                    Kind::End
+                } else {
+                    Kind::Atom(name.to_string())
+                }
+            }
+            b'A'..=b'Z' | b'_' => {
+                index = skip_name(bytes, index);
+                Kind::Other
+            }
+            b'0'..=b'9' => {
+                while index < bytes.len()
+                    && (bytes[index].is_ascii_alphanumeric()
+                        || bytes[index] == b'#'
+                        || bytes[index] == b'_')
+                {
+                    index += 1;
+                }
+                // Floats
+                if index + 1 < bytes.len()
+                    && bytes[index] == b'.'
+                    && bytes[index + 1].is_ascii_digit()
+                {
+                    index += 1;
+                    while index < bytes.len()
+                        && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_')
+                    {
+                        index += 1;
+                    }
+                    Kind::Other
+                } else {
+                    match source[start..index].parse() {
+                        Ok(integer) => Kind::Integer(integer),
+                        Err(_) => Kind::Other,
+                    }
+                }
+            }
+            b'\'' => {
+                index = skip_quoted(bytes, index, b'\'');
+                // Unterminated atoms don't have a closing quote
+                let end = if index - start >= 2 && bytes[index - 1] == b'\'' {
+                    index - 1
+                } else {
+                    index
+                };
+                Kind::Atom(source[start + 1..end].to_string())
+            }
+            b'"' => {
+                index = skip_quoted(bytes, index, b'"');
+                Kind::Other
+            }
+            b'$' => {
+                // Character literals, including `$\n` and `$"`
+                index += 1;
+                if index < bytes.len() && bytes[index] == b'\\' {
+                    index += 1;
+                }
+                if index < bytes.len() {
+                    index += utf8_len(bytes[index]);
+                }
+                Kind::Other
+            }
+            b'(' | b'[' | b'{' => {
+                index += 1;
+                Kind::Open(byte as char)
+            }
+            b')' | b']' | b'}' => {
+                index += 1;
+                Kind::Close(byte as char)
+            }
+            b'<' if bytes.get(index + 1) == Some(&b'<') => {
+                index += 2;
+                Kind::Open('<')
+            }
+            b'>' if bytes.get(index + 1) == Some(&b'>') => {
+                index += 2;
+                Kind::Close('>')
+            }
+            b',' => {
+                index += 1;
+                Kind::Comma
+            }
+            b':' if bytes.get(index + 1) == Some(&b':') => {
+                // Type annotations, `::`
+                index += 2;
+                Kind::Other
+            }
+            b':' => {
+                index += 1;
+                Kind::Colon
+            }
+            b'/' => {
+                index += 1;
+                Kind::Slash
+            }
+            b'.' => {
+                index += 1;
+                // A form ends with `.` followed by whitespace, a comment, or the end of input
+                match bytes.get(index) {
+                    None | Some(b'%') => Kind::Dot,
+                    Some(next) if next.is_ascii_whitespace() => Kind::Dot,
+                    _ => Kind::Other,
+                }
+            }
+            _ => {
+                index += utf8_len(byte);
+                Kind::Other
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            range: start..index,
+        });
+    }
+
+    tokens
+}
+
+fn skip_name(bytes: &[u8], mut index: usize) -> usize {
+    while index < bytes.len()
+        && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_' || bytes[index] == b'@')
+    {
+        index += 1;
+    }
+
+    index
+}
+
+fn skip_quoted(bytes: &[u8], mut index: usize, quote: u8) -> usize {
+    // Skip the opening quote
+    index += 1;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            byte if byte == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+fn next_non_whitespace(bytes: &[u8], index: usize) -> Option<u8> {
+    bytes[index..]
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .cloned()
+}
+
+fn utf8_len(first_byte: u8) -> usize {
+    match first_byte {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xFF => 4,
+        // Continuation bytes shouldn't start a character, so only skip one
+        _ => 1,
+    }
+}
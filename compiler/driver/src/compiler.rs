@@ -1,7 +1,9 @@
+pub(crate) mod analysis;
+pub(crate) mod cache;
 mod queries;
 mod query_groups;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 
@@ -22,6 +24,7 @@ use crate::interner::{InternedInput, Interner, InternerStorage};
 use crate::output::CompilerOutput;
 use crate::parser::{Parser, ParserStorage};
 
+use self::cache::{ArtifactCache, ModuleMetadata};
 use self::query_groups::{CompilerExt, CompilerStorage};
 
 pub(crate) mod prelude {
@@ -40,9 +43,15 @@ pub struct Compiler {
     codemap: Arc<CodeMap>,
     atoms: Arc<Mutex<HashSet<Symbol>>>,
     symbols: Arc<Mutex<HashSet<FunctionSymbol>>>,
+    artifact_cache: Option<Arc<ArtifactCache>>,
+    module_metadata: Arc<Mutex<HashMap<InternedInput, ModuleMetadata>>>,
 }
 impl Compiler {
-    pub fn new(codemap: Arc<CodeMap>, diagnostics: Arc<DiagnosticsHandler>) -> Self {
+    pub fn new(
+        codemap: Arc<CodeMap>,
+        diagnostics: Arc<DiagnosticsHandler>,
+        artifact_cache: Option<ArtifactCache>,
+    ) -> Self {
         let mut atoms = HashSet::default();
         atoms.insert(Symbol::intern("false"));
         atoms.insert(Symbol::intern("true"));
@@ -52,8 +61,26 @@ impl Compiler {
             codemap,
             atoms: Arc::new(Mutex::new(atoms)),
             symbols: Arc::new(Mutex::new(HashSet::default())),
+            artifact_cache: artifact_cache.map(Arc::new),
+            module_metadata: Arc::new(Mutex::new(HashMap::default())),
         }
     }
+
+    /// Like `snapshot`, but reports diagnostics via the given handler rather than our own
+    pub fn snapshot_with_diagnostics(
+        &self,
+        diagnostics: Arc<DiagnosticsHandler>,
+    ) -> Snapshot<Self> {
+        Snapshot::new(Self {
+            runtime: self.runtime.snapshot(self),
+            diagnostics,
+            codemap: self.codemap.clone(),
+            atoms: self.atoms.clone(),
+            symbols: self.symbols.clone(),
+            artifact_cache: self.artifact_cache.clone(),
+            module_metadata: self.module_metadata.clone(),
+        })
+    }
 }
 impl salsa::Database for Compiler {
     fn salsa_runtime(&self) -> &salsa::Runtime<Self> {
@@ -66,13 +93,7 @@ impl salsa::Database for Compiler {
 }
 impl salsa::ParallelDatabase for Compiler {
     fn snapshot(&self) -> Snapshot<Self> {
-        Snapshot::new(Self {
-            runtime: self.runtime.snapshot(self),
-            diagnostics: self.diagnostics.clone(),
-            codemap: self.codemap.clone(),
-            atoms: self.atoms.clone(),
-            symbols: self.symbols.clone(),
-        })
+        self.snapshot_with_diagnostics(self.diagnostics.clone())
     }
 }
 
@@ -180,4 +201,16 @@ impl CompilerExt for Compiler {
             locked.insert(*i);
         }
     }
+
+    fn artifact_cache(&self) -> Option<&Arc<ArtifactCache>> {
+        self.artifact_cache.as_ref()
+    }
+
+    fn add_module_metadata(&self, input: InternedInput, metadata: ModuleMetadata) {
+        self.module_metadata.lock().insert(input, metadata);
+    }
+
+    fn take_module_metadata(&self, input: InternedInput) -> Option<ModuleMetadata> {
+        self.module_metadata.lock().remove(&input)
+    }
 }
@@ -0,0 +1,300 @@
+//! A lightweight success typing analysis over the EIR of all modules in a build.
+//!
+//! Like Dialyzer, this only reports code which can never succeed, so a correct program
+//! never produces a warning, but it is far less thorough. It knows which functions the
+//! modules being compiled define, which of those return the same atom from every clause,
+//! and that the arithmetic BIFs only accept numbers, which is enough to catch calls with
+//! the wrong arity, and arithmetic on literals or results which can't be numbers.
+use std::collections::{HashMap, HashSet};
+
+use libeir_intern::Symbol;
+use libeir_ir as ir;
+use libeir_ir::{AtomTerm, AtomicTerm, ConstKind, FunctionEntry};
+
+use liblumen_session::IRModule;
+use liblumen_util::diagnostics::codes;
+use liblumen_util::diagnostics::{
+    Diagnostic, DiagnosticCode, DiagnosticsHandler, Label, SourceSpan,
+};
+
+/// The arithmetic BIFs in the `erlang` module, which raise `badarith` unless all of their
+/// arguments are numbers
+const ARITHMETIC: &[(&str, usize)] = &[
+    ("+", 1),
+    ("-", 1),
+    ("bnot", 1),
+    ("+", 2),
+    ("-", 2),
+    ("*", 2),
+    ("/", 2),
+    ("div", 2),
+    ("rem", 2),
+    ("band", 2),
+    ("bor", 2),
+    ("bxor", 2),
+    ("bsl", 2),
+    ("bsr", 2),
+];
+
+/// A function, by module, name and arity
+type Mfa = (Symbol, Symbol, usize);
+
+/// Analyzes `modules` together, reporting warnings via `diagnostics`
+pub fn check(modules: &[IRModule], diagnostics: &DiagnosticsHandler) {
+    let mut known = KnownFunctions::default();
+    let mut functions = Vec::new();
+    for module in modules.iter() {
+        known.modules.insert(module.name().name);
+        for definition in module.function_iter() {
+            let function = definition.function();
+            let ident = function.ident();
+            known
+                .arities
+                .entry((ident.module.name, ident.name.name))
+                .or_default()
+                .push(ident.arity);
+
+            // Closures are lowered to functions of their own, each with an entry here
+            let analysis = libeir_lowerutils::analyze(function);
+            let root = analysis.func_tree.root_fun;
+            let root_entry = analysis
+                .func_tree
+                .functions
+                .iter()
+                .find(|(entry_block, _)| **entry_block == root);
+            if let Some((_, root_entry)) = root_entry {
+                if let Some(atom) = returned_atom(function, root_entry) {
+                    let mfa = (ident.module.name, ident.name.name, ident.arity);
+                    known.returns_atom.insert(mfa, atom);
+                }
+            }
+            functions.push((function, analysis));
+        }
+    }
+
+    for (function, analysis) in functions.iter() {
+        let mut checker = FunctionChecker::new(function, &known);
+        for (_, entry) in analysis.func_tree.functions.iter() {
+            checker.check(entry);
+        }
+        for warning in checker.warnings.into_iter() {
+            diagnostics.emit_warning(warning);
+        }
+    }
+}
+
+/// What we know about the functions defined by the modules in the build
+#[derive(Default)]
+struct KnownFunctions {
+    modules: HashSet<Symbol>,
+    /// The arities at which each module/function pair is defined
+    arities: HashMap<(Symbol, Symbol), Vec<usize>>,
+    /// Functions which return the same atom from every clause, e.g. `ok`
+    returns_atom: HashMap<Mfa, Symbol>,
+}
+
+struct FunctionChecker<'a> {
+    function: &'a ir::Function,
+    known: &'a KnownFunctions,
+    warnings: Vec<Diagnostic>,
+    /// Lowering duplicates blocks, e.g. per clause, so we only report each problem once
+    reported: Vec<(String, Option<SourceSpan>)>,
+}
+impl<'a> FunctionChecker<'a> {
+    fn new(function: &'a ir::Function, known: &'a KnownFunctions) -> Self {
+        Self {
+            function,
+            known,
+            warnings: Vec::new(),
+            reported: Vec::new(),
+        }
+    }
+
+    fn check(&mut self, entry: &FunctionEntry) {
+        let f = self.function;
+
+        // The result of a call is the argument of its success continuation, so we need to
+        // know which call each continuation belongs to, in order to know what it receives
+        let mut results = HashMap::new();
+        for block in entry.scope.iter().copied() {
+            if let Some(ir::OpKind::Call(ir::CallKind::Function)) = f.block_kind(block) {
+                let reads = f.block_reads(block);
+                if let (Some(callee), Some(ok)) =
+                    (static_callee(f, reads[0]), f.value_block(reads[1]))
+                {
+                    results.insert(ok, callee);
+                }
+            }
+        }
+
+        for block in entry.scope.iter().copied() {
+            if let Some(ir::OpKind::Call(ir::CallKind::Function)) = f.block_kind(block) {
+                let reads = f.block_reads(block);
+                if let Some(callee) = static_callee(f, reads[0]) {
+                    self.check_call(block, callee, &reads[3..], &results);
+                }
+            }
+        }
+    }
+
+    fn check_call(
+        &mut self,
+        block: ir::Block,
+        callee: Mfa,
+        args: &[ir::Value],
+        results: &HashMap<ir::Block, Mfa>,
+    ) {
+        let (module, name, arity) = callee;
+
+        if self.known.modules.contains(&module) {
+            let arities = self.known.arities.get(&(module, name));
+            let defined = arities.map(|a| a.contains(&arity)).unwrap_or(false);
+            if !defined {
+                let note = arities.map(|arities| {
+                    let arities = arities
+                        .iter()
+                        .map(|arity| format!("{}:{}/{}", module, name, arity))
+                        .collect::<Vec<_>>();
+                    format!("{} is defined", arities.join(" and "))
+                });
+                self.warn(
+                    block,
+                    codes::UNDEFINED_FUNCTION,
+                    format!(
+                        "call to undefined function {}:{}/{} will always fail",
+                        module, name, arity
+                    ),
+                    note,
+                );
+            }
+            return;
+        }
+
+        let is_arithmetic = module.as_str().get() == "erlang"
+            && ARITHMETIC
+                .iter()
+                .any(|(op, op_arity)| name.as_str().get() == *op && arity == *op_arity);
+        if !is_arithmetic {
+            return;
+        }
+
+        for arg in args.iter().copied() {
+            if let Some(ty) = self.literal_type(arg) {
+                self.warn(
+                    block,
+                    codes::ARITHMETIC_ON_LITERAL,
+                    format!(
+                        "erlang:{}/{} on {} will always fail with badarith",
+                        name, arity, ty
+                    ),
+                    None,
+                );
+            } else if let ir::ValueKind::Argument(result_of, 0) = self.function.value_kind(arg) {
+                let producer = match results.get(&result_of) {
+                    Some(producer) => *producer,
+                    None => continue,
+                };
+                if let Some(atom) = self.known.returns_atom.get(&producer) {
+                    let (m, f, a) = producer;
+                    self.warn(
+                        block,
+                        codes::ARITHMETIC_ON_ATOM_RESULT,
+                        format!(
+                            "erlang:{}/{} on the result of {}:{}/{} will always fail with badarith",
+                            name, arity, m, f, a
+                        ),
+                        Some(format!("{}:{}/{} always returns '{}'", m, f, a, atom)),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Returns a description of the type of `value`, if it is a literal which is not a number
+    fn literal_type(&self, value: ir::Value) -> Option<&'static str> {
+        let f = self.function;
+        let constant = f.value_const(value)?;
+        match f.const_kind(constant) {
+            ConstKind::Atomic(AtomicTerm::Atom(_)) => Some("an atom"),
+            ConstKind::Atomic(AtomicTerm::Binary(_)) => Some("a binary"),
+            ConstKind::Atomic(AtomicTerm::Nil) | ConstKind::ListCell { .. } => Some("a list"),
+            ConstKind::Tuple { .. } => Some("a tuple"),
+            ConstKind::Map { .. } => Some("a map"),
+            _ => None,
+        }
+    }
+
+    fn warn(
+        &mut self,
+        block: ir::Block,
+        code: DiagnosticCode,
+        message: String,
+        note: Option<String>,
+    ) {
+        let span = self.function.block_locations(block).first().copied();
+        let key = (message, span);
+        if self.reported.contains(&key) {
+            return;
+        }
+        let (message, span) = key.clone();
+        self.reported.push(key);
+
+        let mut warning = Diagnostic::warning()
+            .with_code(code.code)
+            .with_message(message);
+        if let Some(span) = span {
+            warning = warning.with_labels(vec![Label::primary(span.source_id(), span)]);
+        }
+        if let Some(note) = note {
+            warning = warning.with_notes(vec![note]);
+        }
+        self.warnings.push(warning);
+    }
+}
+
+/// Returns the function called by `callee`, if it is known statically
+fn static_callee(f: &ir::Function, callee: ir::Value) -> Option<Mfa> {
+    let primop = f.value_primop(callee)?;
+    if *f.primop_kind(primop) != ir::PrimOpKind::CaptureFunction {
+        return None;
+    }
+    let reads = f.primop_reads(primop);
+    let module = constant_atom(f, reads[0])?;
+    let name = constant_atom(f, reads[1])?;
+    let arity = match f.const_kind(f.value_const(reads[2])?) {
+        ConstKind::Atomic(AtomicTerm::Int(ir::IntTerm(arity))) => *arity as usize,
+        _ => return None,
+    };
+    Some((module, name, arity))
+}
+
+/// Returns the atom returned by every clause of the function described by `entry`
+///
+/// Anything else that might return, such as a tail call, means we don't know.
+fn returned_atom(f: &ir::Function, entry: &FunctionEntry) -> Option<Symbol> {
+    let ret = entry.ret?;
+    let mut returned = None;
+    for block in entry.scope.iter().copied() {
+        let reads = f.block_reads(block);
+        if !reads.contains(&ret) {
+            continue;
+        }
+        match f.block_kind(block) {
+            Some(ir::OpKind::Call(ir::CallKind::ControlFlow)) if reads[0] == ret => {
+                let atom = reads.get(1).and_then(|value| constant_atom(f, *value))?;
+                if *returned.get_or_insert(atom) != atom {
+                    return None;
+                }
+            }
+            _ => return None,
+        }
+    }
+    returned
+}
+
+fn constant_atom(f: &ir::Function, value: ir::Value) -> Option<Symbol> {
+    match f.const_kind(f.value_const(value)?) {
+        ConstKind::Atomic(AtomicTerm::Atom(AtomTerm(atom))) => Some(*atom),
+        _ => None,
+    }
+}
@@ -0,0 +1,286 @@
+//! An on-disk cache of compiled object files, used to avoid recompiling
+//! modules which have not changed since a previous build.
+//!
+//! Each module is keyed by a fingerprint of its source, its EIR after
+//! preprocessing and lowering (which captures the contents of any included
+//! files), and the compiler flags which affect code generation.
+//!
+//! Generated code refers to atoms by their interned symbol id, so an object
+//! file is only reusable if those ids are the same in the current session. To
+//! guarantee this, the cache records the interner contents at the end of each
+//! build, and replays them before anything else is interned in the next one.
+//! Every symbol restored at the start of a build is written back at the end of
+//! it, even if the build no longer uses it, so that entries for modules which
+//! were not part of this build stay valid for later ones.
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::fs::{self, File};
+use std::hash::{Hash, Hasher};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{anyhow, Context};
+
+use log::debug;
+
+use libeir_intern::Symbol;
+
+use liblumen_core::symbols::FunctionSymbol;
+use liblumen_session::{Input, Options, OutputType};
+
+const VERSION_FILE: &'static str = "VERSION";
+const SYMBOLS_FILE: &'static str = "symbols";
+
+/// A content hash identifying a module and the configuration it was compiled with
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Fingerprint(u64);
+impl Fingerprint {
+    pub fn new(options: &Options, input: &Input, module: &libeir_ir::Module) -> io::Result<Self> {
+        let mut hasher = DefaultHasher::new();
+        options_fingerprint(options).hash(&mut hasher);
+        match input {
+            Input::File(ref path) => fs::read(path)?.hash(&mut hasher),
+            Input::Str { ref input, .. } => input.hash(&mut hasher),
+        }
+        module.to_text_standard().hash(&mut hasher);
+        Ok(Self(hasher.finish()))
+    }
+}
+
+/// The atoms and function symbols defined by a module, which must be registered
+/// with the atom and symbol tables when linking its object file
+#[derive(Default)]
+pub struct ModuleMetadata {
+    pub atoms: HashSet<Symbol>,
+    pub symbols: HashSet<FunctionSymbol>,
+}
+
+/// The artifacts of a previous compilation of a module
+pub struct CachedModule {
+    pub object: PathBuf,
+    pub metadata: ModuleMetadata,
+}
+
+pub struct ArtifactCache {
+    dir: PathBuf,
+    /// The number of symbols replayed by `restore_symbols`
+    restored: AtomicUsize,
+}
+impl ArtifactCache {
+    /// Opens the cache in `dir`, creating it if necessary
+    ///
+    /// If the cache was written by a different version of the compiler, it is cleared.
+    pub fn open(dir: &Path) -> anyhow::Result<Self> {
+        let version = format!("{} {}", crate::LUMEN_RELEASE, crate::LUMEN_COMMIT_HASH);
+        let version_path = dir.join(VERSION_FILE);
+        let cache = Self {
+            dir: dir.to_path_buf(),
+            restored: AtomicUsize::new(0),
+        };
+
+        match fs::read_to_string(&version_path) {
+            Ok(ref cached_version) if cached_version == &version => return Ok(cache),
+            Ok(_) => {
+                debug!("artifact cache was created by another compiler version, clearing it");
+                cache.clear()?;
+            }
+            Err(_) => (),
+        }
+
+        fs::create_dir_all(dir)
+            .with_context(|| format!("could not create cache directory {}", dir.display()))?;
+        fs::write(&version_path, version)?;
+        Ok(cache)
+    }
+
+    /// Replays the symbols interned by the previous build, so that the symbol ids
+    /// referenced by cached object files are valid in this session.
+    ///
+    /// This must be called before anything else is interned. If the ids cannot be
+    /// reproduced, the cache is cleared, as none of its contents are usable.
+    pub fn restore_symbols(&self) -> anyhow::Result<()> {
+        let path = self.dir.join(SYMBOLS_FILE);
+        let file = match File::open(&path) {
+            Ok(file) => file,
+            Err(_) => return Ok(()),
+        };
+
+        let mut reader = BufReader::new(file);
+        let mut id = 0;
+        while let Some(name) = read_symbol(&mut reader)? {
+            if Symbol::intern(&name).as_usize() != id {
+                debug!("unable to restore symbol ids, clearing artifact cache");
+                self.clear()?;
+                return Self::open(&self.dir).map(|_| ());
+            }
+            id += 1;
+        }
+        self.restored.store(id, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Records the interned symbols which may be referenced by cached object files
+    ///
+    /// This includes every symbol restored at the start of this build, as entries
+    /// cached by earlier builds may refer to them even if this build did not.
+    pub fn save_symbols<I>(&self, used: I) -> anyhow::Result<()>
+    where
+        I: Iterator<Item = usize>,
+    {
+        let restored = self.restored.load(Ordering::SeqCst);
+        let len = used.map(|id| id + 1).max().unwrap_or(0).max(restored);
+        if len == 0 {
+            return Ok(());
+        }
+
+        let mut file = File::create(self.dir.join(SYMBOLS_FILE))?;
+        for id in 0..len {
+            let symbol = Symbol::new(id as u32);
+            write_symbol(&mut file, symbol.as_str().get())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the cached artifacts for the given fingerprint, if present
+    ///
+    /// An entry which refers to symbols that were not restored for this build,
+    /// e.g. because the build which stored it failed before saving its symbols,
+    /// is treated as missing, since its symbol ids may mean something else now.
+    pub fn lookup(&self, fingerprint: Fingerprint) -> Option<CachedModule> {
+        let object = self.object_path(fingerprint);
+        if !object.exists() {
+            return None;
+        }
+
+        let restored = self.restored.load(Ordering::SeqCst);
+        let parse_id = |part: Option<&str>| -> Option<usize> {
+            part?.parse().ok().filter(|id| *id < restored)
+        };
+
+        let mut metadata = ModuleMetadata::default();
+        let manifest = File::open(self.manifest_path(fingerprint)).ok()?;
+        for line in BufReader::new(manifest).lines() {
+            let line = line.ok()?;
+            let mut parts = line.split(' ');
+            match parts.next()? {
+                "atom" => {
+                    let id = parse_id(parts.next())?;
+                    metadata.atoms.insert(Symbol::new(id as u32));
+                }
+                "symbol" => {
+                    metadata.symbols.insert(FunctionSymbol {
+                        module: parse_id(parts.next())?,
+                        function: parse_id(parts.next())?,
+                        arity: parts.next()?.parse().ok()?,
+                        ptr: std::ptr::null(),
+                    });
+                }
+                _ => return None,
+            }
+        }
+
+        Some(CachedModule { object, metadata })
+    }
+
+    /// Stores the object file and metadata produced by compiling a module
+    pub fn store(
+        &self,
+        fingerprint: Fingerprint,
+        object: &Path,
+        metadata: &ModuleMetadata,
+    ) -> anyhow::Result<()> {
+        let mut manifest = File::create(self.manifest_path(fingerprint))?;
+        for atom in metadata.atoms.iter() {
+            writeln!(manifest, "atom {}", atom.as_usize())?;
+        }
+        for symbol in metadata.symbols.iter() {
+            writeln!(
+                manifest,
+                "symbol {} {} {}",
+                symbol.module, symbol.function, symbol.arity
+            )?;
+        }
+        // The object is copied last, as its presence marks the entry as complete
+        fs::copy(object, self.object_path(fingerprint))?;
+        Ok(())
+    }
+
+    /// Removes all cache entries
+    ///
+    /// Only files created by the cache are removed, in case the directory is shared
+    fn clear(&self) -> anyhow::Result<()> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(()),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            let is_entry = match path.extension().and_then(|ext| ext.to_str()) {
+                Some("o") | Some("manifest") => true,
+                _ => path.ends_with(SYMBOLS_FILE) || path.ends_with(VERSION_FILE),
+            };
+            if is_entry {
+                fs::remove_file(&path)
+                    .with_context(|| format!("could not remove {}", path.display()))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn object_path(&self, fingerprint: Fingerprint) -> PathBuf {
+        self.dir.join(format!("{:016x}.o", fingerprint.0))
+    }
+
+    fn manifest_path(&self, fingerprint: Fingerprint) -> PathBuf {
+        self.dir.join(format!("{:016x}.manifest", fingerprint.0))
+    }
+}
+
+/// Returns true if the requested outputs can be satisfied from the cache
+///
+/// Only object files are cached, so if any intermediate output was requested, we
+/// need to run the full pipeline.
+pub fn is_cacheable(options: &Options) -> bool {
+    options
+        .output_types
+        .keys()
+        .all(|ty| *ty == OutputType::Object || *ty == OutputType::Link)
+}
+
+/// The subset of options which affect the generated code for a module
+fn options_fingerprint(options: &Options) -> String {
+    let defines = options.defines.iter().collect::<BTreeMap<_, _>>();
+    format!(
+        "{} {:?} {:?} {:?} {:?} {:?} {:?} {:?}",
+        options.target.triple(),
+        options.opt_level,
+        options.debug_info,
+        options.debug_assertions,
+        options.codegen_opts,
+        defines,
+        options.include_path,
+        options.code_path,
+    )
+}
+
+// Symbols may contain any character, so each is written length-prefixed
+fn write_symbol<W: Write>(writer: &mut W, name: &str) -> io::Result<()> {
+    write!(writer, "{}:{}\n", name.len(), name)
+}
+
+fn read_symbol<R: BufRead>(reader: &mut R) -> anyhow::Result<Option<String>> {
+    let mut len = Vec::new();
+    if reader.read_until(b':', &mut len)? == 0 {
+        return Ok(None);
+    }
+    len.pop();
+    let len: usize = std::str::from_utf8(&len)?
+        .parse()
+        .map_err(|_| anyhow!("invalid symbol entry in artifact cache"))?;
+    // Read the name along with its trailing newline
+    let mut name = vec![0; len + 1];
+    reader.read_exact(&mut name)?;
+    name.pop();
+    Ok(Some(String::from_utf8(name)?))
+}
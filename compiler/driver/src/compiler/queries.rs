@@ -1,8 +1,10 @@
+use std::fs::File;
+use std::io;
 use std::ops::Deref;
 use std::sync::Arc;
 use std::thread::{self, ThreadId};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, Context};
 
 use log::debug;
 
@@ -12,6 +14,7 @@ use liblumen_llvm::{self as llvm, target::TargetMachineConfig};
 use liblumen_mlir as mlir;
 use liblumen_session::{Input, InputType, OutputType};
 
+use super::cache::{self, Fingerprint, ModuleMetadata};
 use super::prelude::*;
 
 /// Create context for LLVM
@@ -120,6 +123,15 @@ where
         Ok(generated_module) => {
             db.add_atoms(generated_module.atoms.iter());
             db.add_symbols(generated_module.symbols.iter());
+            if db.artifact_cache().is_some() {
+                db.add_module_metadata(
+                    input,
+                    ModuleMetadata {
+                        atoms: generated_module.atoms.clone(),
+                        symbols: generated_module.symbols.clone(),
+                    },
+                );
+            }
             db.maybe_emit_file_with_opts(&options, input, &generated_module.module)?;
             Ok(Arc::new(generated_module.module))
         }
@@ -136,7 +148,11 @@ where
     C: Compiler,
 {
     match db.input_type(input) {
-        InputType::Erlang | InputType::AbstractErlang | InputType::EIR => {
+        InputType::Erlang
+        | InputType::Elixir
+        | InputType::AbstractErlang
+        | InputType::Beam
+        | InputType::EIR => {
             debug!("input {:?} is erlang", input);
             db.generate_mlir(thread_id, input)
         }
@@ -146,7 +162,7 @@ where
         }
         InputType::Unknown(None) => {
             debug!("unknown input type for {:?} on {:?}", input, thread_id);
-            db.report_error("invalid input, expected .erl or .mlir");
+            db.report_error("invalid input, expected .erl, .ex, .beam or .mlir");
             Err(ErrorReported)
         }
         InputType::Unknown(Some(ref ext)) => {
@@ -155,7 +171,7 @@ where
                 ext, input, thread_id
             );
             db.report_error(format!(
-                "invalid input extension ({}), expected .erl or .mlir",
+                "invalid input extension ({}), expected .erl, .ex, .beam or .mlir",
                 ext
             ));
             Err(ErrorReported)
@@ -302,6 +318,30 @@ where
     let source_name = input_info.source_name();
     let diagnostics = db.diagnostics();
 
+    // Reuse the object file from a previous build if nothing has changed
+    let fingerprint = get_fingerprint(db, input)?;
+    if let Some(cached) = fingerprint.and_then(|f| db.artifact_cache().unwrap().lookup(f)) {
+        debug!("using cached object file for {:?}", input);
+        db.add_atoms(cached.metadata.atoms.iter());
+        db.add_symbols(cached.metadata.symbols.iter());
+        let obj_path = db.maybe_emit_file_with_callback_and_opts(
+            &options,
+            input,
+            OutputType::Object,
+            |outfile| {
+                let mut object = File::open(&cached.object)?;
+                io::copy(&mut object, outfile)?;
+                Ok(())
+            },
+        )?;
+        diagnostics.success("Fresh", format!("{}", &source_name));
+        return Ok(Arc::new(CompiledModule::new(
+            input_info.file_stem().to_string_lossy().into_owned(),
+            obj_path,
+            None,
+        )));
+    }
+
     diagnostics.success("Compiling", format!("{}", &source_name));
     debug!(
         "compiling {:?} ({:?}) on thread {:?}",
@@ -331,6 +371,18 @@ where
         },
     )?;
 
+    // Store the object file so that later builds can reuse it
+    if let (Some(fingerprint), Some(obj_path)) = (fingerprint, obj_path.as_ref()) {
+        let cache = db.artifact_cache().unwrap();
+        let metadata = db.take_module_metadata(input).unwrap_or_default();
+        if let Err(err) = cache.store(fingerprint, obj_path, &metadata) {
+            diagnostics.warn(format!(
+                "unable to cache object file for {}: {:#}",
+                &source_name, err
+            ));
+        }
+    }
+
     // Gather compiled module metadata
     let bc_path = options
         .output_types
@@ -348,6 +400,29 @@ where
     Ok(compiled)
 }
 
+/// Computes the fingerprint of the given input for the artifact cache
+///
+/// Returns `None` if caching is disabled, or not possible for this input
+fn get_fingerprint<C>(db: &C, input: InternedInput) -> QueryResult<Option<Fingerprint>>
+where
+    C: Compiler,
+{
+    let options = db.options();
+    if db.artifact_cache().is_none() || !cache::is_cacheable(&options) {
+        return Ok(None);
+    }
+    // MLIR inputs have no EIR to fingerprint
+    if db.input_type(input) == InputType::MLIR {
+        return Ok(None);
+    }
+
+    let module = db.input_eir(input)?;
+    let input_info = db.lookup_intern_input(input);
+    let fingerprint = Fingerprint::new(&options, &input_info, &module)
+        .with_context(|| format!("unable to fingerprint {}", input_info.source_name()));
+    db.to_query_result(fingerprint).map(Some)
+}
+
 fn get_input_source_name<C>(db: &C, input: InternedInput) -> Option<String>
 where
     C: Compiler,
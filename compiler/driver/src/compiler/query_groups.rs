@@ -7,6 +7,7 @@ use liblumen_core::symbols::FunctionSymbol;
 use liblumen_llvm as llvm;
 use liblumen_mlir as mlir;
 
+use crate::compiler::cache::{ArtifactCache, ModuleMetadata};
 use crate::compiler::queries;
 use crate::diagnostics::QueryResult;
 use crate::interner::InternedInput;
@@ -78,4 +79,7 @@ pub trait CompilerExt: CompilerOutput {
     fn add_symbols<'a, I>(&self, symbols: I)
     where
         I: Iterator<Item = &'a FunctionSymbol>;
+    fn artifact_cache(&self) -> Option<&Arc<ArtifactCache>>;
+    fn add_module_metadata(&self, input: InternedInput, metadata: ModuleMetadata);
+    fn take_module_metadata(&self, input: InternedInput) -> Option<ModuleMetadata>;
 }
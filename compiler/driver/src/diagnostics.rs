@@ -20,7 +20,7 @@ pub trait CompilerDiagnostics {
         match err {
             Ok(val) => Ok(val),
             Err(err) => {
-                self.report_error(format!("{}", err));
+                self.report_error(format!("{:#}", err));
                 Err(ErrorReported)
             }
         }
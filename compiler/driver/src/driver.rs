@@ -40,6 +40,19 @@ pub fn run_compiler_with_emitter(
             cwd,
             emitter,
         ),
+        ("build", subcommand_matches) => commands::build::handle_command(
+            c_opts,
+            z_opts,
+            subcommand_matches.unwrap(),
+            cwd,
+            emitter,
+        ),
+        ("explain", subcommand_matches) => {
+            commands::explain::handle_command(subcommand_matches.unwrap())
+        }
+        ("lsp", subcommand_matches) => {
+            commands::lsp::handle_command(subcommand_matches.unwrap(), cwd)
+        }
         (subcommand, _) => Err(anyhow!(format!("Unrecognized subcommand '{}'", subcommand))),
     }
 }
@@ -6,7 +6,7 @@ mod driver;
 mod interner;
 mod output;
 mod parser;
-pub(crate) mod task;
+mod term;
 
 pub use self::driver::{run_compiler, run_compiler_with_emitter};
 
@@ -1,3 +1,7 @@
+mod app;
+mod beam;
+mod boot;
+mod elixir;
 mod queries;
 
 use std::path::PathBuf;
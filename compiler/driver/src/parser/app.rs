@@ -0,0 +1,161 @@
+//! Reads OTP application resource files, i.e. `.app` files and their `.app.src` templates
+//!
+//! A resource file contains a single term of the form `{application, Name, Properties}`,
+//! of which we only need a few of the properties to boot an application.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context};
+
+use crate::term::{self, Term};
+
+/// Applications which are provided by the runtime, rather than built from source
+const RUNTIME_APPLICATIONS: &[&str] = &["kernel", "stdlib"];
+
+/// The parts of an application resource file needed to boot the application
+#[derive(Debug)]
+pub struct Application {
+    pub name: String,
+    pub path: PathBuf,
+    /// The applications which must be started before this one
+    pub applications: Vec<String>,
+    /// The application callback module, and its start arguments as Erlang source,
+    /// or `None` for library applications, which have nothing to start
+    pub start: Option<(String, String)>,
+}
+impl Application {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let source = fs::read_to_string(path)
+            .with_context(|| format!("could not read {}", path.display()))?;
+        let term = term::parse(&source)
+            .with_context(|| format!("invalid application resource file {}", path.display()))?;
+        Self::from_term(path, term)
+            .with_context(|| format!("invalid application resource file {}", path.display()))
+    }
+
+    fn from_term(path: &Path, term: Term) -> anyhow::Result<Self> {
+        let (name, properties) = match term {
+            Term::Tuple(mut elements) if elements.len() == 3 => {
+                let properties = elements.pop().unwrap();
+                let name = elements.pop().unwrap();
+                match (&elements[0], name, properties) {
+                    (Term::Atom(tag), Term::Atom(name), Term::List(properties))
+                        if tag == "application" =>
+                    {
+                        (name, properties)
+                    }
+                    _ => bail!("expected {{application, Name, Properties}}"),
+                }
+            }
+            _ => bail!("expected {{application, Name, Properties}}"),
+        };
+
+        let mut app = Self {
+            name,
+            path: path.to_path_buf(),
+            applications: Vec::new(),
+            start: None,
+        };
+        for property in properties.into_iter() {
+            let (key, value) = match property {
+                Term::Tuple(mut elements) if elements.len() == 2 => {
+                    let value = elements.pop().unwrap();
+                    match elements.pop().unwrap() {
+                        Term::Atom(key) => (key, value),
+                        _ => bail!("expected application properties to be {{Key, Value}} tuples"),
+                    }
+                }
+                _ => bail!("expected application properties to be {{Key, Value}} tuples"),
+            };
+
+            match (key.as_str(), value) {
+                ("applications", Term::List(apps)) => {
+                    for dependency in apps.into_iter() {
+                        match dependency {
+                            Term::Atom(dependency) => app.applications.push(dependency),
+                            _ => bail!("expected `applications` to be a list of atoms"),
+                        }
+                    }
+                }
+                ("applications", _) => bail!("expected `applications` to be a list of atoms"),
+                ("mod", Term::Tuple(mut elements)) if elements.len() == 2 => {
+                    let args = elements.pop().unwrap();
+                    match elements.pop().unwrap() {
+                        Term::Atom(module) => app.start = Some((module, args.to_string())),
+                        _ => bail!("expected `mod` to be a {{Module, StartArgs}} tuple"),
+                    }
+                }
+                ("mod", _) => bail!("expected `mod` to be a {{Module, StartArgs}} tuple"),
+                // Everything else is either informational, or not yet supported by the runtime
+                _ => (),
+            }
+        }
+
+        Ok(app)
+    }
+}
+
+/// Returns `apps` in the order they must be started, such that each application is
+/// started after the applications it depends on
+///
+/// Otherwise, applications are started in the order given.
+pub fn start_order(apps: &[Application]) -> anyhow::Result<Vec<&Application>> {
+    let mut by_name = HashMap::new();
+    for app in apps.iter() {
+        if let Some(other) = by_name.insert(app.name.as_str(), app) {
+            bail!(
+                "application `{}` is defined by both {} and {}",
+                app.name,
+                other.path.display(),
+                app.path.display()
+            );
+        }
+    }
+
+    let mut order = Vec::with_capacity(apps.len());
+    let mut visiting = Vec::new();
+    for app in apps.iter() {
+        visit(app, &by_name, &mut visiting, &mut order)?;
+    }
+    Ok(order)
+}
+
+fn visit<'a>(
+    app: &'a Application,
+    by_name: &HashMap<&str, &'a Application>,
+    visiting: &mut Vec<&'a str>,
+    order: &mut Vec<&'a Application>,
+) -> anyhow::Result<()> {
+    if order.iter().any(|started| started.name == app.name) {
+        return Ok(());
+    }
+    if visiting.contains(&app.name.as_str()) {
+        let mut cycle = visiting
+            .iter()
+            .skip_while(|name| **name != app.name)
+            .copied()
+            .collect::<Vec<_>>();
+        cycle.push(&app.name);
+        bail!("circular application dependency: {}", cycle.join(" -> "));
+    }
+
+    visiting.push(&app.name);
+    for dependency in app.applications.iter() {
+        if RUNTIME_APPLICATIONS.contains(&dependency.as_str()) {
+            continue;
+        }
+        let dependency = by_name.get(dependency.as_str()).ok_or_else(|| {
+            anyhow!(
+                "application `{}` depends on `{}`, which was not given with --app",
+                app.name,
+                dependency
+            )
+        })?;
+        visit(dependency, by_name, visiting, order)?;
+    }
+    visiting.pop();
+
+    order.push(app);
+    Ok(())
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod test;
+
+use std::fs;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Context};
+use flate2::read::GzDecoder;
+
+use liblumen_beam::beam::reader::RawBeamFile;
+use liblumen_beam::serialization::etf::{Atom, List, Term, Tuple};
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+
+/// Reads the abstract code from the `Dbgi` or `Abst` chunk of the `.beam` file at `path` and
+/// returns its forms in the same format as `erlc +dabstr`, so that they can be lowered by the same
+/// frontend as `.abstr` files.
+///
+/// The `.beam` file must have been compiled with `debug_info` by the Erlang compiler.  Modules
+/// compiled by Elixir store Elixir's own debug info, which only Elixir can convert to abstract
+/// code, so compile their `.ex` sources instead.
+pub fn to_abstract_erlang(path: &Path) -> anyhow::Result<String> {
+    let bytes = fs::read(path).with_context(|| format!("could not read {}", path.display()))?;
+
+    bytes_to_abstract_erlang(&bytes).with_context(|| {
+        format!(
+            "could not load abstract code from {}; it must be compiled with debug_info",
+            path.display()
+        )
+    })
+}
+
+fn bytes_to_abstract_erlang(bytes: &[u8]) -> anyhow::Result<String> {
+    let forms = forms(bytes)?;
+
+    Ok(forms.iter().map(|form| format!("{}.\n", form)).collect())
+}
+
+fn forms(bytes: &[u8]) -> anyhow::Result<Vec<Term>> {
+    // `.beam` files can be compressed with `compile`'s `compressed` option
+    if bytes.starts_with(GZIP_MAGIC) {
+        let mut decompressed = Vec::new();
+        GzDecoder::new(bytes)
+            .read_to_end(&mut decompressed)
+            .context("compressed .beam file could not be inflated")?;
+
+        return forms(&decompressed);
+    }
+
+    let beam = RawBeamFile::from_reader(Cursor::new(bytes))
+        .map_err(|error| anyhow!("not a valid .beam file: {}", error))?;
+
+    if let Some(debug_info) = beam.get_chunk(b"Dbgi") {
+        debug_info_forms(decode(&debug_info.data)?)
+    } else if let Some(abstract_code) = beam.get_chunk(b"Abst") {
+        if abstract_code.data.is_empty() {
+            bail!("Abst chunk is empty")
+        }
+
+        raw_abstract_v1_forms(decode(&abstract_code.data)?)
+    } else {
+        bail!("has neither a Dbgi nor an Abst chunk")
+    }
+}
+
+fn decode(data: &[u8]) -> anyhow::Result<Term> {
+    Term::decode(Cursor::new(data)).map_err(|error| anyhow!("{}", error))
+}
+
+/// `{debug_info_v1, Backend, Data}`, where only the `erl_abstract_code` backend's
+/// `{Forms, CompileOptions}` data can be used without running its backend.
+fn debug_info_forms(debug_info: Term) -> anyhow::Result<Vec<Term>> {
+    match debug_info {
+        Term::Tuple(Tuple { mut elements })
+            if elements.len() == 3 && is_atom(&elements[0], "debug_info_v1") =>
+        {
+            let data = elements.pop().unwrap();
+            let backend = elements.pop().unwrap();
+
+            if !is_atom(&backend, "erl_abstract_code") {
+                bail!(
+                    "debug info from backend ({}) is not supported, only erl_abstract_code",
+                    backend
+                )
+            }
+
+            match data {
+                Term::Tuple(Tuple {
+                    elements: mut data_elements,
+                }) if data_elements.len() == 2 => {
+                    data_elements.truncate(1);
+                    let abstract_code = data_elements.pop().unwrap();
+
+                    if is_atom(&abstract_code, "none") {
+                        bail!("debug info does not include abstract code")
+                    }
+
+                    proper_forms(abstract_code)
+                }
+                _ => Err(anyhow!(
+                    "erl_abstract_code data is not a 2-tuple of forms and compile options"
+                )),
+            }
+        }
+        _ => Err(anyhow!("Dbgi chunk is not a debug_info_v1 tuple")),
+    }
+}
+
+/// `{raw_abstract_v1, Forms}` from the `Abst` chunk written by older compilers.
+fn raw_abstract_v1_forms(abstract_code: Term) -> anyhow::Result<Vec<Term>> {
+    match abstract_code {
+        Term::Tuple(Tuple { mut elements })
+            if elements.len() == 2 && is_atom(&elements[0], "raw_abstract_v1") =>
+        {
+            proper_forms(elements.pop().unwrap())
+        }
+        _ => Err(anyhow!("Abst chunk is not a raw_abstract_v1 tuple")),
+    }
+}
+
+fn proper_forms(forms: Term) -> anyhow::Result<Vec<Term>> {
+    match forms {
+        Term::List(List { elements }) => Ok(elements),
+        _ => Err(anyhow!("forms are not a proper list")),
+    }
+}
+
+fn is_atom(term: &Term, name: &str) -> bool {
+    match term {
+        Term::Atom(Atom { name: atom_name }) => atom_name == name,
+        _ => false,
+    }
+}
@@ -0,0 +1,151 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use liblumen_beam::beam::chunk::RawChunk;
+use liblumen_beam::beam::reader::RawBeamFile;
+
+use super::*;
+
+#[test]
+fn with_abst_chunk_returns_forms() {
+    let abstract_erlang = to_abstract_erlang(&test_file("reader/test.beam")).unwrap();
+
+    assert!(abstract_erlang
+        .starts_with("{'attribute',1,'file',{[116,101,115,116,46,101,114,108],1}}.\n"));
+    assert!(abstract_erlang.contains("\n{'attribute',2,'module','test'}.\n"));
+    assert!(abstract_erlang.contains("\n{'attribute',4,'export',[{'hello',1}]}.\n"));
+    assert!(abstract_erlang.ends_with("\n{'eof',11}.\n"));
+}
+
+#[test]
+fn with_gzip_compressed_beam_returns_same_forms_as_uncompressed() {
+    let path = test_file("ast/test.beam");
+    let bytes = fs::read(&path).unwrap();
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&bytes).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    assert_eq!(
+        to_abstract_erlang(&path).unwrap(),
+        bytes_to_abstract_erlang(&compressed).unwrap()
+    );
+}
+
+#[test]
+fn with_dbgi_chunk_without_abstract_code_errors() {
+    assert_error_contains(
+        to_abstract_erlang(&test_file("simple.beam")),
+        "debug info does not include abstract code",
+    );
+}
+
+#[test]
+fn with_dbgi_chunk_from_elixir_errors() {
+    assert_error_contains(
+        to_abstract_erlang(&test_file("reader/Elixir.Unicode.beam")),
+        "is not supported, only erl_abstract_code",
+    );
+}
+
+#[test]
+fn with_empty_abst_chunk_errors() {
+    assert_error_contains(
+        bytes_to_abstract_erlang(&beam(vec![raw_chunk(b"Abst", Vec::new())])),
+        "Abst chunk is empty",
+    );
+}
+
+#[test]
+fn without_dbgi_or_abst_chunk_errors() {
+    assert_error_contains(
+        bytes_to_abstract_erlang(&beam(vec![raw_chunk(b"Atom", vec![0, 0, 0, 0])])),
+        "has neither a Dbgi nor an Abst chunk",
+    );
+}
+
+#[test]
+fn with_truncated_beam_errors() {
+    let bytes = fs::read(test_file("reader/test.beam")).unwrap();
+
+    // In the header, in a chunk header, in a chunk, and in the `Abst` chunk
+    for len in &[6, 16, 40, bytes.len() - 40] {
+        assert_error_contains(
+            bytes_to_abstract_erlang(&bytes[..*len]),
+            "not a valid .beam file",
+        );
+    }
+}
+
+#[test]
+fn with_wrong_magic_number_errors() {
+    let mut bytes = fs::read(test_file("reader/test.beam")).unwrap();
+    bytes[0..4].copy_from_slice(b"FOR2");
+
+    assert_error_contains(bytes_to_abstract_erlang(&bytes), "magic number");
+}
+
+#[test]
+fn with_chunk_size_past_end_errors() {
+    let mut bytes = fs::read(test_file("reader/test.beam")).unwrap();
+    // Size of the first (`Atom`) chunk
+    bytes[16..20].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xF0]);
+
+    assert_error_contains(bytes_to_abstract_erlang(&bytes), "not a valid .beam file");
+}
+
+#[test]
+fn with_malformed_abst_chunk_errors() {
+    // Not the external term format version
+    assert!(bytes_to_abstract_erlang(&beam(vec![raw_chunk(b"Abst", vec![0xFF; 8])])).is_err());
+
+    // `{raw_abstract_v1, [_, _]}` cut off after the list header
+    let mut truncated_forms = vec![131, 104, 2, 100, 0, 15];
+    truncated_forms.extend_from_slice(b"raw_abstract_v1");
+    truncated_forms.extend_from_slice(&[108, 0, 0, 0, 2]);
+    assert!(bytes_to_abstract_erlang(&beam(vec![raw_chunk(b"Abst", truncated_forms)])).is_err());
+
+    // `[]` instead of `{raw_abstract_v1, Forms}`
+    assert_error_contains(
+        bytes_to_abstract_erlang(&beam(vec![raw_chunk(b"Abst", vec![131, 106])])),
+        "Abst chunk is not a raw_abstract_v1 tuple",
+    );
+}
+
+fn assert_error_contains<T: std::fmt::Debug>(result: anyhow::Result<T>, expected: &str) {
+    // `{:#}` includes the causes, as the context added by `to_abstract_erlang` is outermost
+    let message = format!("{:#}", result.unwrap_err());
+
+    assert!(
+        message.contains(expected),
+        "{:?} does not contain {:?}",
+        message,
+        expected
+    );
+}
+
+fn beam(chunks: Vec<RawChunk>) -> Vec<u8> {
+    let mut beam = RawBeamFile::new();
+
+    for chunk in chunks {
+        beam.push_chunk(chunk);
+    }
+
+    let mut bytes = Vec::new();
+    beam.to_writer(&mut bytes).unwrap();
+
+    bytes
+}
+
+fn raw_chunk(id: &[u8; 4], data: Vec<u8>) -> RawChunk {
+    RawChunk { id: *id, data }
+}
+
+fn test_file(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../liblumen_beam/tests/testdata")
+        .join(name)
+}
@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use anyhow::anyhow;
+
+use liblumen_session::{Input, Options};
+
+use super::app::{self, Application};
+
+/// The name of the generated source, which determines the name of its outputs
+const NAME: &'static str = "init.erl";
+
+/// Generates the `init` module which boots an executable built with `--bin` or `--app`
+///
+/// The runtime boots the system by spawning `init:start/0`, which we generate to start
+/// each application given with `--app`, after those it depends on, by calling the
+/// `start/2` callback of its `mod`.
+///
+/// With `--bin`, this then calls `main/1` in the module defined by that file, with the
+/// command-line arguments, minus the program name, as binaries. Like an escript, the
+/// module is expected to have the same name as its file. Otherwise, `init` waits
+/// forever once the applications are started, as their supervision trees are linked
+/// to it.
+///
+/// Returns `None` if neither option was given, in which case the user provides `init`.
+pub fn init_module(options: &Options) -> anyhow::Result<Option<Input>> {
+    if options.bin.is_none() && options.apps.is_empty() {
+        return Ok(None);
+    }
+
+    let apps = options
+        .apps
+        .iter()
+        .map(|path| Application::load(path))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut body = Vec::new();
+    for app in app::start_order(&apps)? {
+        // Library applications have no processes to start
+        if let Some((ref module, ref args)) = app.start {
+            body.push(format!(
+                "ok = start_application({}, {}, {})",
+                quote_atom(&app.name),
+                quote_atom(module),
+                args
+            ));
+        }
+    }
+    match options.bin.as_ref() {
+        Some(path) => {
+            body.push(
+                "Args = case init:get_plain_arguments() of\n        \
+                     [_Program | Rest] -> Rest;\n        \
+                     [] -> []\n    \
+                 end"
+                .to_owned(),
+            );
+            body.push(format!("{}:main(Args)", quote_atom(main_module(path)?)));
+        }
+        None => body.push("receive after infinity -> ok end".to_owned()),
+    }
+
+    let source = format!(
+        "-module(init).\n\
+         -export([start/0]).\n\
+         \n\
+         start() ->\n    \
+             {}.\n\
+         \n\
+         start_application(Name, Module, Args) ->\n    \
+             case Module:start(normal, Args) of\n        \
+                 {{ok, _Pid}} -> ok;\n        \
+                 {{ok, _Pid, _State}} -> ok;\n        \
+                 {{error, Reason}} -> erlang:error({{application_start_failure, Name, Reason}});\n        \
+                 Other -> erlang:error({{application_start_failure, Name, {{bad_return, Other}}}})\n    \
+             end.\n",
+        body.join(",\n    ")
+    );
+
+    Ok(Some(Input::new(NAME, source)))
+}
+
+fn main_module(path: &Path) -> anyhow::Result<&str> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .ok_or_else(|| anyhow!("invalid main module path: {}", path.display()))
+}
+
+fn quote_atom(name: &str) -> String {
+    format!("'{}'", name.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -0,0 +1,66 @@
+use std::env;
+use std::ffi::OsString;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{anyhow, Context};
+
+/// The environment variable that overrides the `elixir` executable used to compile `.ex` files
+pub const ELIXIR: &str = "ELIXIR";
+
+/// Compiles the only module in the file given as the first argument and prints its Erlang Abstract
+/// Format forms in the same format as `erlc +dabstr`, so that they can be lowered by the same
+/// frontend as `.abstr` files.
+///
+/// `beam_lib` asks Elixir's debug info backend for the `abstract_code` chunk, so this works
+/// without the module being compiled with any special options.
+const SCRIPT: &str = r#"
+[path] = System.argv()
+
+case Code.compile_file(path) do
+  [{_module, beam}] ->
+    {:ok, {_, [abstract_code: {:raw_abstract_v1, forms}]}} =
+      :beam_lib.chunks(beam, [:abstract_code])
+
+    Enum.each(forms, &IO.write(:io_lib.format("~p.~n", [&1])))
+
+  modules ->
+    IO.puts(:stderr, "expected 1 module, but #{length(modules)} were defined")
+    System.halt(1)
+end
+"#;
+
+/// Compiles the Elixir source at `path` to Erlang Abstract Format using the `elixir` executable on
+/// the `PATH`, or the one named by the `ELIXIR` environment variable.
+pub fn to_abstract_erlang(path: &Path) -> anyhow::Result<String> {
+    let elixir = env::var_os(ELIXIR).unwrap_or_else(|| OsString::from("elixir"));
+    let output = Command::new(&elixir)
+        .arg("-e")
+        .arg(SCRIPT)
+        .arg("--")
+        .arg(path)
+        .output()
+        .with_context(|| {
+            format!(
+                "could not run {} to compile {}; Elixir must be installed to compile .ex files",
+                elixir.to_string_lossy(),
+                path.display()
+            )
+        })?;
+
+    if output.status.success() {
+        String::from_utf8(output.stdout).with_context(|| {
+            format!(
+                "abstract format for {} from {} is not UTF-8",
+                path.display(),
+                elixir.to_string_lossy()
+            )
+        })
+    } else {
+        Err(anyhow!(
+            "{}",
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        ))
+        .with_context(|| format!("could not compile {} with Elixir", path.display()))
+    }
+}
@@ -80,6 +80,11 @@ where
         }
     }
 
+    // Executables built with `--bin` or `--app` get a generated `init` module which boots them
+    if let Some(input) = db.to_query_result(super::boot::init_module(&options))? {
+        interned_input_vec.push(db.intern_input(input));
+    }
+
     Ok(Arc::new(interned_input_vec.into()))
 }
 
@@ -100,7 +105,7 @@ where
     parse_config.warnings_as_errors = options.warnings_as_errors;
     parse_config.no_warn = options.no_warn;
     parse_config.include_paths = options.include_path.clone();
-    parse_config.code_paths = Default::default();
+    parse_config.code_paths = options.code_path.clone();
     parse_config
 }
 
@@ -113,9 +118,14 @@ where
     use libeir_frontend::erlang::ErlangFrontend;
 
     let codemap = db.codemap().clone();
-    let frontend: AnyFrontend = match db.input_type(input) {
+    let input_type = db.input_type(input);
+    let frontend: AnyFrontend = match input_type {
         InputType::Erlang => ErlangFrontend::new(db.parse_config(), codemap).into(),
-        InputType::AbstractErlang => AbstrErlangFrontend::new(codemap).into(),
+        // Elixir is compiled to Erlang Abstract Format by `elixir` itself and `.beam` files
+        // contain it in their debug info
+        InputType::Elixir | InputType::AbstractErlang | InputType::Beam => {
+            AbstrErlangFrontend::new(codemap).into()
+        }
         InputType::EIR => EirFrontend::new(codemap).into(),
         ty => {
             db.report_error(format!("invalid input type: {}", ty));
@@ -124,7 +134,24 @@ where
     };
 
     let (result, diags) = match db.lookup_intern_input(input) {
+        Input::File(ref path) if input_type == InputType::Elixir => {
+            let source = db.to_query_result(super::elixir::to_abstract_erlang(path))?;
+            frontend.parse_string_dyn(&source)
+        }
+        Input::File(ref path) if input_type == InputType::Beam => {
+            let source = db.to_query_result(super::beam::to_abstract_erlang(path))?;
+            frontend.parse_string_dyn(&source)
+        }
         Input::File(ref path) => frontend.parse_file_dyn(path),
+        Input::Str { ref name, .. }
+            if input_type == InputType::Elixir || input_type == InputType::Beam =>
+        {
+            db.report_error(format!(
+                "invalid input ({}), .{} inputs must be read from a file",
+                name, input_type
+            ));
+            return Err(ErrorReported);
+        }
         Input::Str { ref input, .. } => frontend.parse_string_dyn(input),
     };
 
@@ -0,0 +1,386 @@
+//! Reads Erlang terms from the files used to configure OTP applications and projects,
+//! e.g. `.app` files and `rebar.config`, in the same format as `file:consult/1`.
+//!
+//! Only the subset of term syntax such files use in practice is supported: atoms, numbers,
+//! characters, strings, binaries, tuples, lists and maps.
+use std::fmt::{self, Write};
+
+use anyhow::anyhow;
+
+/// Parses a single term, terminated by a `.`
+pub(crate) fn parse(source: &str) -> anyhow::Result<Term> {
+    let mut parser = TermParser::new(source);
+    let term = parser.term()?;
+    parser.expect('.')?;
+    parser.expect_end()?;
+    Ok(term)
+}
+
+/// Parses a sequence of terms, each terminated by a `.`
+pub(crate) fn consult(source: &str) -> anyhow::Result<Vec<Term>> {
+    let mut parser = TermParser::new(source);
+    let mut terms = Vec::new();
+    loop {
+        parser.skip_whitespace();
+        if parser.at_end() {
+            return Ok(terms);
+        }
+        terms.push(parser.term()?);
+        parser.expect('.')?;
+    }
+}
+
+/// An Erlang term
+#[derive(Debug)]
+pub(crate) enum Term {
+    Atom(String),
+    /// The source of an integer or float literal, which we have no need to evaluate
+    Number(String),
+    Str(String),
+    Binary(Vec<Term>),
+    Tuple(Vec<Term>),
+    List(Vec<Term>),
+    Map(Vec<(Term, Term)>),
+}
+impl Term {
+    pub(crate) fn as_atom(&self) -> Option<&str> {
+        match self {
+            Term::Atom(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Returns the contents of a string, or of a binary made up of strings
+    pub(crate) fn as_string(&self) -> Option<String> {
+        match self {
+            Term::Str(s) => Some(s.clone()),
+            Term::Binary(elements) => elements.iter().map(|e| e.as_string()).collect(),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a tuple
+    pub(crate) fn as_tuple(&self) -> Option<&[Term]> {
+        match self {
+            Term::Tuple(elements) => Some(elements),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of a list
+    pub(crate) fn as_list(&self) -> Option<&[Term]> {
+        match self {
+            Term::List(elements) => Some(elements),
+            _ => None,
+        }
+    }
+}
+impl fmt::Display for Term {
+    /// Formats the term as Erlang source
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn write_seq<T: fmt::Display>(f: &mut fmt::Formatter, terms: &[T]) -> fmt::Result {
+            for (i, term) in terms.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{}", term)?;
+            }
+            Ok(())
+        }
+
+        match self {
+            Term::Atom(name) => write_quoted(f, name, '\''),
+            Term::Number(number) => f.write_str(number),
+            Term::Str(s) => write_quoted(f, s, '"'),
+            Term::Binary(elements) => {
+                f.write_str("<<")?;
+                write_seq(f, elements)?;
+                f.write_str(">>")
+            }
+            Term::Tuple(elements) => {
+                f.write_char('{')?;
+                write_seq(f, elements)?;
+                f.write_char('}')
+            }
+            Term::List(elements) => {
+                f.write_char('[')?;
+                write_seq(f, elements)?;
+                f.write_char(']')
+            }
+            Term::Map(pairs) => {
+                f.write_str("#{")?;
+                for (i, (key, value)) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{} => {}", key, value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+fn write_quoted(f: &mut fmt::Formatter, s: &str, quote: char) -> fmt::Result {
+    f.write_char(quote)?;
+    for c in s.chars() {
+        match c {
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\t' => f.write_str("\\t")?,
+            c if c == quote => {
+                f.write_char('\\')?;
+                f.write_char(c)?;
+            }
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char(quote)
+}
+
+struct TermParser<'a> {
+    source: &'a str,
+    pos: usize,
+}
+impl<'a> TermParser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    fn at_end(&self) -> bool {
+        self.pos == self.source.len()
+    }
+
+    fn expect_end(&mut self) -> anyhow::Result<()> {
+        self.skip_whitespace();
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.error("expected end of file"))
+        }
+    }
+
+    fn term(&mut self) -> anyhow::Result<Term> {
+        self.skip_whitespace();
+        let c = self
+            .peek()
+            .ok_or_else(|| self.error("unexpected end of file"))?;
+        match c {
+            '{' => {
+                self.bump();
+                self.sequence('}').map(Term::Tuple)
+            }
+            '[' => {
+                self.bump();
+                self.sequence(']').map(Term::List)
+            }
+            '#' => {
+                self.bump();
+                self.expect('{')?;
+                self.map()
+            }
+            '<' => {
+                self.bump();
+                self.expect('<')?;
+                self.binary()
+            }
+            '"' => {
+                // Adjacent string literals are concatenated
+                let mut s = self.string('"')?;
+                loop {
+                    self.skip_whitespace();
+                    if self.peek() != Some('"') {
+                        break;
+                    }
+                    s.push_str(&self.string('"')?);
+                }
+                Ok(Term::Str(s))
+            }
+            '\'' => self.string('\'').map(Term::Atom),
+            '$' => {
+                self.bump();
+                let c = match self.bump() {
+                    Some('\\') => self.escape()?,
+                    Some(c) => c,
+                    None => return Err(self.error("unexpected end of file")),
+                };
+                Ok(Term::Number((c as u32).to_string()))
+            }
+            '-' | '0'..='9' => self.number(),
+            c if c.is_ascii_lowercase() => {
+                let start = self.pos;
+                self.bump_while(|c| c.is_ascii_alphanumeric() || c == '_' || c == '@');
+                Ok(Term::Atom(self.source[start..self.pos].to_owned()))
+            }
+            c => Err(self.error(format!("unexpected character `{}`", c))),
+        }
+    }
+
+    /// Parses comma-separated terms up to and including `close`
+    fn sequence(&mut self, close: char) -> anyhow::Result<Vec<Term>> {
+        let mut terms = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(close) {
+            self.bump();
+            return Ok(terms);
+        }
+        loop {
+            terms.push(self.term()?);
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => continue,
+                Some(c) if c == close => return Ok(terms),
+                _ => return Err(self.error(format!("expected `,` or `{}`", close))),
+            }
+        }
+    }
+
+    fn map(&mut self) -> anyhow::Result<Term> {
+        let mut pairs = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.bump();
+            return Ok(Term::Map(pairs));
+        }
+        loop {
+            let key = self.term()?;
+            self.expect('=')?;
+            self.expect('>')?;
+            let value = self.term()?;
+            pairs.push((key, value));
+            self.skip_whitespace();
+            match self.bump() {
+                Some(',') => continue,
+                Some('}') => return Ok(Term::Map(pairs)),
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+    }
+
+    fn binary(&mut self) -> anyhow::Result<Term> {
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+        if self.source[self.pos..].starts_with(">>") {
+            self.pos += 2;
+            return Ok(Term::Binary(elements));
+        }
+        loop {
+            let element = self.term()?;
+            match element {
+                Term::Str(_) | Term::Number(_) => elements.push(element),
+                _ => return Err(self.error("expected a string or integer in binary")),
+            }
+            self.skip_whitespace();
+            if self.source[self.pos..].starts_with(">>") {
+                self.pos += 2;
+                return Ok(Term::Binary(elements));
+            }
+            self.expect(',')?;
+        }
+    }
+
+    fn string(&mut self, quote: char) -> anyhow::Result<String> {
+        self.expect(quote)?;
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                Some('\\') => s.push(self.escape()?),
+                Some(c) if c == quote => return Ok(s),
+                Some(c) => s.push(c),
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+    }
+
+    /// Parses the remainder of an escape sequence, following the `\`
+    fn escape(&mut self) -> anyhow::Result<char> {
+        match self.bump() {
+            Some('n') => Ok('\n'),
+            Some('r') => Ok('\r'),
+            Some('t') => Ok('\t'),
+            Some('s') => Ok(' '),
+            Some('e') => Ok('\x1b'),
+            Some('0') => Ok('\0'),
+            Some(c) => Ok(c),
+            None => Err(self.error("unterminated escape sequence")),
+        }
+    }
+
+    fn number(&mut self) -> anyhow::Result<Term> {
+        let start = self.pos;
+        if self.peek() == Some('-') {
+            self.bump();
+        }
+        if !self.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
+            return Err(self.error("expected a number"));
+        }
+        self.bump_while(|c| c.is_ascii_digit() || c == '_');
+        if self.peek() == Some('#') {
+            // An integer with an explicit base, e.g. 16#ff
+            self.bump();
+            self.bump_while(|c| c.is_ascii_alphanumeric());
+        } else if self.source[self.pos..].starts_with('.')
+            && self.source[self.pos + 1..]
+                .chars()
+                .next()
+                .map(|c| c.is_ascii_digit())
+                .unwrap_or(false)
+        {
+            self.bump();
+            self.bump_while(|c| c.is_ascii_digit() || c == '_');
+            if let Some('e') | Some('E') = self.peek() {
+                self.bump();
+                if let Some('-') | Some('+') = self.peek() {
+                    self.bump();
+                }
+                self.bump_while(|c| c.is_ascii_digit());
+            }
+        }
+        Ok(Term::Number(self.source[start..self.pos].to_owned()))
+    }
+
+    fn expect(&mut self, expected: char) -> anyhow::Result<()> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.bump();
+                Ok(())
+            }
+            _ => Err(self.error(format!("expected `{}`", expected))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            self.bump_while(char::is_whitespace);
+            if self.peek() != Some('%') {
+                return;
+            }
+            self.bump_while(|c| c != '\n');
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn bump_while(&mut self, predicate: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn error(&self, message: impl Into<String>) -> anyhow::Error {
+        let line = self.source[..self.pos].matches('\n').count() + 1;
+        anyhow!("line {}: {}", line, message.into())
+    }
+}
@@ -3,6 +3,7 @@
 
 #include "mlir/Target/LLVMIR.h"
 #include "mlir/Conversion/StandardToLLVM/ConvertStandardToLLVMPass.h"
+#include "mlir/Dialect/LLVMIR/LLVMDialect.h"
 #include "mlir/ExecutionEngine/ExecutionEngine.h"
 #include "mlir/ExecutionEngine/OptUtils.h"
 #include "mlir/IR/MLIRContext.h"
@@ -14,19 +15,26 @@
 
 #include "llvm-c/Core.h"
 #include "llvm-c/TargetMachine.h"
+#include "llvm/ADT/SmallString.h"
 #include "llvm/ADT/StringRef.h"
 #include "llvm/ADT/Triple.h"
 #include "llvm/Support/CBindingWrapping.h"
+#include "llvm/Support/FileSystem.h"
+#include "llvm/IR/DebugInfo.h"
+#include "llvm/IR/DebugInfoMetadata.h"
 #include "llvm/IR/Module.h"
 #include "llvm/IR/LLVMContext.h"
 #include "llvm/Target/TargetMachine.h"
 
+using ::mlir::Location;
 using ::mlir::MLIRContext;
 using ::mlir::ModuleOp;
 using ::mlir::OpPassManager;
 using ::mlir::OwningModuleRef;
 using ::mlir::PassManager;
+using ::mlir::UnknownLoc;
 using ::llvm::LLVMContext;
+using ::llvm::SmallString;
 using ::llvm::StringRef;
 using ::llvm::TargetMachine;
 using ::llvm::Triple;
@@ -64,11 +72,45 @@ extern "C" LowerResult MLIRLowerModule(MLIRContextRef context,
 }
 
 
+// MLIR only emits a subprogram for functions in which every call has a known
+// location, so a single call introduced during lowering without one would
+// otherwise cost us the debug info for the entire function. To avoid that, any
+// operation without a location is attributed to the function it belongs to.
+static void inheritFunctionLocations(ModuleOp mod) {
+  mod.walk([](mlir::LLVM::LLVMFuncOp func) {
+    Location funcLoc = func.getLoc();
+    if (funcLoc.isa<UnknownLoc>())
+      return;
+
+    func.walk([&](mlir::Operation *op) {
+      if (op->getLoc().isa<UnknownLoc>())
+        op->setLoc(funcLoc);
+    });
+  });
+}
+
+// The compile unit created during translation names the module rather than
+// the source file, and is rooted at `/`, so debuggers are unable to find the
+// Erlang sources, whose paths are relative to the directory we were invoked in.
+static void fixupCompileUnits(llvm::Module &llvmMod, StringRef sourceName) {
+  LLVMContext &ctx = llvmMod.getContext();
+  SmallString<128> compDir;
+  if (llvm::sys::fs::current_path(compDir))
+    compDir = "/";
+
+  for (llvm::DICompileUnit *cu : llvmMod.debug_compile_units()) {
+    // Operand 0 is the file, operand 1 the producer
+    cu->replaceOperandWith(0, llvm::DIFile::get(ctx, sourceName, compDir));
+    cu->replaceOperandWith(1, llvm::MDString::get(ctx, "lumen"));
+  }
+}
+
 extern "C" LowerResult MLIRLowerToLLVMIR(MLIRModuleRef m,
                                          LLVMContextRef context,
                                          LLVMTargetMachineRef tm,
                                          const char *sourceName,
-                                         unsigned sourceNameLen) {
+                                         unsigned sourceNameLen,
+                                         bool emitDebugInfo) {
   LLVMContext *ctx = unwrap(context);
   ModuleOp *mod = unwrap(m);
   StringRef srcName(sourceName, sourceNameLen);
@@ -79,12 +121,22 @@ extern "C" LowerResult MLIRLowerToLLVMIR(MLIRModuleRef m,
     modName = StringRef("unknown");
 
   OwningModuleRef ownedMod(*mod);
+  inheritFunctionLocations(*ownedMod);
+
   auto llvmModPtr = mlir::translateModuleToLLVMIR(*ownedMod, *ctx, modName);
   if (!llvmModPtr) {
     MLIRModuleRef ptr = wrap(new ModuleOp(ownedMod.release()));
     return {.module = (void *)(ptr), .success = false};
   }
 
+  // Debug info is derived from the locations in the module, which we always
+  // keep for diagnostics. Like BEAM, which always knows the line of each call
+  // in a stacktrace, the line tables are kept for backtraces even if debug
+  // info wasn't requested, but everything else is removed.
+  fixupCompileUnits(*llvmModPtr, srcName);
+  if (!emitDebugInfo)
+    llvm::stripNonLineTableDebugInfo(*llvmModPtr);
+
   TargetMachine *targetMachine = unwrap(tm);
   Triple triple = targetMachine->getTargetTriple();
   llvmModPtr->setDataLayout(targetMachine->createDataLayout());
@@ -10,7 +10,7 @@ use liblumen_llvm as llvm;
 use liblumen_llvm::enums::{CodeGenOptLevel, CodeGenOptSize};
 use liblumen_llvm::target::{TargetMachine, TargetMachineRef};
 use liblumen_llvm::utils::{MemoryBuffer, MemoryBufferRef};
-use liblumen_session::{Options, OutputType};
+use liblumen_session::{DebugInfo, Options, OutputType};
 use liblumen_util::diagnostics::DiagnosticsHandler;
 
 use crate::{diagnostics, Dialect, Module, ModuleRef};
@@ -50,6 +50,7 @@ pub struct Context {
     target_machine: TargetMachineRef,
     opt: CodeGenOptLevel,
     size: CodeGenOptSize,
+    debug_info: DebugInfo,
     context_options: ContextOptions,
 }
 unsafe impl Send for Context {}
@@ -95,6 +96,7 @@ impl Context {
             target_machine,
             opt,
             size,
+            debug_info: options.debug_info,
             context_options,
         }
     }
@@ -107,6 +109,10 @@ impl Context {
         self.size
     }
 
+    pub fn debug_info(&self) -> DebugInfo {
+        self.debug_info
+    }
+
     pub fn pass_manager_ref(&self) -> PassManagerRef {
         self.pass_manager
     }
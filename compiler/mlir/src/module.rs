@@ -10,7 +10,7 @@ use anyhow::anyhow;
 use liblumen_llvm as llvm;
 use liblumen_llvm::target::TargetMachineRef;
 use liblumen_llvm::utils::{LLVMString, MemoryBufferRef};
-use liblumen_session::{Emit, OutputType};
+use liblumen_session::{DebugInfo, Emit, OutputType};
 use liblumen_util as util;
 
 use crate::context::PassManagerRef;
@@ -101,6 +101,7 @@ impl Module {
         source_name: Option<String>,
     ) -> anyhow::Result<Result<llvm::module::Module, ()>> {
         let target_machine = context.target_machine_ref();
+        let emit_debug_info = context.debug_info() != DebugInfo::None;
 
         let source_name_bytes = source_name
             .as_ref()
@@ -116,6 +117,7 @@ impl Module {
                 target_machine,
                 source_name_ptr as *const libc::c_char,
                 source_name_len as libc::c_uint,
+                emit_debug_info,
             )
         };
         if result.module.is_null() {
@@ -198,6 +200,7 @@ extern "C" {
         target_machine: TargetMachineRef,
         source_name: *const libc::c_char,
         source_name_len: libc::c_uint,
+        emit_debug_info: bool,
     ) -> ffi::ToLLVMIRResult;
 
     #[cfg(not(windows))]
@@ -0,0 +1,48 @@
+//! The Erlang code path, which `-include_lib("app/include/file.hrl")` searches to find the
+//! directory of an installed application, the same way `code:lib_dir/1` does on the BEAM.
+//!
+//! Each entry is the `ebin` directory of an application, so the application's directory, and
+//! therefore its `include` directory, is the parent of the entry.
+use std::env;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable listing library directories that contain applications, with the same
+/// meaning as for `erl`.
+pub const ERL_LIBS: &str = "ERL_LIBS";
+
+/// Returns the code path entries for the applications in the library directories listed in the
+/// `ERL_LIBS` environment variable.
+pub fn from_env() -> Vec<PathBuf> {
+    match env::var_os(ERL_LIBS) {
+        Some(erl_libs) => from_erl_libs(&erl_libs),
+        None => Vec::new(),
+    }
+}
+
+/// Returns the code path entries for the applications in `erl_libs`, a list of library
+/// directories separated the same way as `PATH`.
+pub fn from_erl_libs(erl_libs: &OsStr) -> Vec<PathBuf> {
+    env::split_paths(erl_libs)
+        .flat_map(|lib_dir| application_ebin_dirs(&lib_dir))
+        .collect()
+}
+
+/// Applications are included even if they have not been built yet and so have no `ebin`
+/// directory, as only their `include` directory is needed to compile against them.
+fn application_ebin_dirs(lib_dir: &Path) -> Vec<PathBuf> {
+    let mut ebin_dirs: Vec<PathBuf> = match fs::read_dir(lib_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .map(|application_dir| application_dir.join("ebin"))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    // `read_dir` order is platform-dependent, so sort to make resolution deterministic
+    ebin_dirs.sort();
+
+    ebin_dirs
+}
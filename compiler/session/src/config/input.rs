@@ -8,7 +8,9 @@ use liblumen_util::diagnostics::FileName;
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum InputType {
     Erlang,
+    Elixir,
     AbstractErlang,
+    Beam,
     EIR,
     MLIR,
     Unknown(Option<String>),
@@ -16,7 +18,9 @@ pub enum InputType {
 impl InputType {
     const TYPES: &'static [InputType] = &[
         InputType::Erlang,
+        InputType::Elixir,
         InputType::AbstractErlang,
+        InputType::Beam,
         InputType::EIR,
         InputType::MLIR,
     ];
@@ -28,9 +32,12 @@ impl InputType {
         match path.extension().and_then(|s| s.to_str()) {
             None => false,
             Some("erl") => true,
+            Some("ex") => true,
             Some("eir") => true,
             Some("abstr") => true,
             Some("mlir") => true,
+            // `.beam` files are only compiled when given explicitly, as directories usually
+            // contain the `.beam` files built from the sources in the same directory
             Some(_) => false,
         }
     }
@@ -47,7 +54,9 @@ impl fmt::Display for InputType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::Erlang => f.write_str("erl"),
+            Self::Elixir => f.write_str("ex"),
             Self::AbstractErlang => f.write_str("abstr"),
+            Self::Beam => f.write_str("beam"),
             Self::EIR => f.write_str("eir"),
             Self::MLIR => f.write_str("mlir"),
             Self::Unknown(None) => f.write_str("unknown (no extension)"),
@@ -81,7 +90,9 @@ impl Input {
         match self {
             Input::File(ref file) => match file.extension().and_then(|ext| ext.to_str()) {
                 Some("erl") => InputType::Erlang,
+                Some("ex") => InputType::Elixir,
                 Some("abstr") => InputType::AbstractErlang,
+                Some("beam") => InputType::Beam,
                 Some("eir") => InputType::EIR,
                 Some("mlir") => InputType::MLIR,
                 Some(t) => InputType::Unknown(Some(t.to_string())),
@@ -90,8 +101,12 @@ impl Input {
             Input::Str { ref name, .. } => {
                 if name.ends_with(".erl") {
                     InputType::Erlang
+                } else if name.ends_with(".ex") {
+                    InputType::Elixir
                 } else if name.ends_with(".abstr") {
                     InputType::AbstractErlang
+                } else if name.ends_with(".beam") {
+                    InputType::Beam
                 } else if name.ends_with(".eir") {
                     InputType::EIR
                 } else if name.ends_with(".mlir") {
@@ -20,11 +20,12 @@ use clap::ArgMatches;
 
 use liblumen_target::spec::{CodeModel, PanicStrategy, RelocModel, TlsModel};
 use liblumen_target::{self as target, Target};
-use liblumen_util::diagnostics::{ColorArg, ColorChoice, FileName};
+use liblumen_util::diagnostics::{ColorArg, ColorChoice, ErrorFormat, FileName};
 use liblumen_util::error::{HelpRequested, Verbosity};
 use liblumen_util::fs::NativeLibraryKind;
 
 use super::*;
+use crate::code_path;
 use crate::filesearch;
 use crate::search_paths::SearchPath;
 
@@ -48,8 +49,11 @@ pub struct Options {
     pub project_type: ProjectType,
     pub output_types: OutputTypes,
     pub color: ColorChoice,
+    pub error_format: ErrorFormat,
     pub warnings_as_errors: bool,
     pub no_warn: bool,
+    /// Whether to run the success typing analysis over all modules after compiling them
+    pub analyze: bool,
     pub verbosity: Verbosity,
 
     pub host: Target,
@@ -68,12 +72,23 @@ pub struct Options {
 
     pub current_dir: PathBuf,
     pub input_files: Option<Vec<FileName>>,
+    /// The source file defining `main/1`, when building a standalone executable with `--bin`
+    pub bin: Option<PathBuf>,
+    /// The application resource files (`.app` or `.app.src`) of the applications to boot
+    pub apps: Vec<PathBuf>,
     pub output_file: Option<PathBuf>,
     pub output_dir: Option<PathBuf>,
+    /// The directory in which compiled artifacts are cached between builds, if enabled
+    pub cache_dir: Option<PathBuf>,
+    /// The number of modules to compile in parallel, or `None` to use one per CPU
+    pub jobs: Option<usize>,
     // Remap source path prefixes in all output (messages, object files, debug, etc.).
     pub source_path_prefix: Vec<(PathBuf, PathBuf)>,
     pub search_paths: Vec<SearchPath>,
     pub include_path: VecDeque<PathBuf>,
+    /// The `ebin` directories searched by `-include_lib`, from `--pa`, `ERL_LIBS`, and `--pz`, in
+    /// that order
+    pub code_path: VecDeque<PathBuf>,
     pub link_libraries: Vec<(String, Option<String>, Option<NativeLibraryKind>)>,
     pub defines: HashMap<String, Option<String>>,
 
@@ -120,19 +135,88 @@ impl Options {
             }
         };
 
+        // `--bin` builds the given file along with any other inputs
+        let bin = args.value_of_os("bin").map(PathBuf::from);
+        let input_files = match bin.as_ref() {
+            None => input_files,
+            Some(path) => {
+                let bin_file: FileName = path.clone().into();
+                let mut files = input_files.unwrap_or_default();
+                if !files.contains(&bin_file) {
+                    files.push(bin_file);
+                }
+                Some(files)
+            }
+        };
+
+        // `--app` builds the sources of each application along with any other inputs
+        let apps: Vec<PathBuf> = args
+            .values_of_os("app")
+            .map(|apps| apps.map(PathBuf::from).collect())
+            .unwrap_or_default();
+        let input_files = if apps.is_empty() {
+            input_files
+        } else {
+            let mut files = input_files.unwrap_or_default();
+            for app in apps.iter() {
+                let src_dir = app_source_dir(app).ok_or_else(|| {
+                    str_to_clap_err(
+                        "app",
+                        "expected the path to an .app file in ebin/, or an .app.src file",
+                    )
+                })?;
+                let src_dir: FileName = src_dir.into();
+                if !files.contains(&src_dir) {
+                    files.push(src_dir);
+                }
+            }
+            Some(files)
+        };
+
         if let Some(extra_args) = args.values_of("raw") {
             for arg in extra_args {
                 codegen_opts.llvm_args.push(arg.to_string());
             }
         }
 
-        let project_name = detect_project_name(args, cwd.as_path(), input_files.as_deref());
+        let project_name = match bin.as_ref() {
+            // The executable is named after its main module, like an escript
+            Some(path) if !args.is_present("name") => path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .ok_or_else(|| {
+                    str_to_clap_err("bin", "expected the path to an Erlang source file")
+                })?
+                .to_owned(),
+            // A release is named after its first application
+            None if !apps.is_empty() && !args.is_present("name") => app_name(&apps[0])
+                .ok_or_else(|| {
+                    str_to_clap_err("app", "expected the path to an .app or .app.src file")
+                })?
+                .to_owned(),
+            _ => detect_project_name(args, cwd.as_path(), input_files.as_deref()),
+        };
         let project_type_opt: Option<ProjectType> =
             ParseOption::parse_option(&option!("project-type"), &args)?;
         let project_type = project_type_opt.unwrap_or(ProjectType::Executable);
+        if bin.is_some() && project_type != ProjectType::Executable {
+            return Err(str_to_clap_err(
+                "bin",
+                "--bin can only be used when building an executable",
+            )
+            .into());
+        }
+        if !apps.is_empty() && project_type != ProjectType::Executable {
+            return Err(str_to_clap_err(
+                "app",
+                "--app can only be used when building an executable",
+            )
+            .into());
+        }
         let output_types = OutputTypes::parse_option(&option!("emit"), &args)?;
 
         let color_arg = ColorArg::parse_option(&option!("color"), &args)?;
+        let error_format = ErrorFormat::parse_option(&option!("error-format"), &args)?;
 
         let maybe_sysroot: Option<PathBuf> = ParseOption::parse_option(&option!("sysroot"), &args)?;
         let sysroot = match &maybe_sysroot {
@@ -210,6 +294,18 @@ impl Options {
 
         let output_file = args.value_of_os("output").map(PathBuf::from);
         let output_dir = args.value_of_os("output-dir").map(PathBuf::from);
+        let cache_dir = args.value_of_os("cache-dir").map(PathBuf::from);
+        let jobs = match args.value_of("jobs") {
+            None => None,
+            Some(value) => match value.parse::<usize>() {
+                Ok(n) if n > 0 => Some(n),
+                _ => {
+                    return Err(
+                        str_to_clap_err("jobs", "expected a positive number of jobs").into(),
+                    )
+                }
+            },
+        };
         if let Some(values) = args.values_of("define") {
             for value in values {
                 let define = self::parse_key_value(value)?;
@@ -219,8 +315,22 @@ impl Options {
                 );
             }
         }
-        let warnings_as_errors = args.is_present("warnings-as-errors");
-        let no_warn = args.is_present("no-warn");
+        // `-W` follows erlc, where `-Werror` treats warnings as errors and `-W0` disables them
+        let warning_levels = args
+            .values_of("warnings")
+            .map(|values| values.collect::<Vec<_>>())
+            .unwrap_or_default();
+        let warnings_as_errors =
+            args.is_present("warnings-as-errors") || warning_levels.contains(&"error");
+        let no_warn = args.is_present("no-warn") || warning_levels.contains(&"0");
+        if warnings_as_errors && no_warn {
+            return Err(str_to_clap_err(
+                "warnings",
+                "warnings cannot be both disabled and treated as errors",
+            )
+            .into());
+        }
+        let analyze = args.is_present("analyze");
         let verbosity = Verbosity::from_level(args.occurrences_of("verbose") as isize);
         let mut include_path = VecDeque::new();
         if let Some(values) = args.values_of_os("include-paths") {
@@ -228,14 +338,28 @@ impl Options {
                 include_path.push_front(PathBuf::from(value));
             }
         }
+        let mut code_path: VecDeque<PathBuf> = code_path::from_env().into();
+        if let Some(values) = args.values_of_os("code-path-prepend") {
+            // Reversed, so that the directories keep their order at the front of the code path
+            for value in values.rev() {
+                code_path.push_front(PathBuf::from(value));
+            }
+        }
+        if let Some(values) = args.values_of_os("code-path-append") {
+            for value in values {
+                code_path.push_back(PathBuf::from(value));
+            }
+        }
 
         Ok(Self {
             project_name,
             project_type,
             output_types,
             color: color_arg.into(),
+            error_format,
             warnings_as_errors,
             no_warn,
+            analyze,
             verbosity,
             host,
             target,
@@ -250,11 +374,16 @@ impl Options {
             debugging_opts,
             current_dir: cwd,
             input_files,
+            bin,
+            apps,
             output_file,
             output_dir,
+            cache_dir,
+            jobs,
             source_path_prefix,
             search_paths,
             include_path,
+            code_path,
             link_libraries,
             defines,
             cli_forced_thinlto_off: false,
@@ -311,8 +440,10 @@ impl Options {
             project_type: ProjectType::Executable,
             output_types: OutputTypes::default(),
             color: ColorChoice::Auto,
+            error_format: ErrorFormat::Human,
             warnings_as_errors: false,
             no_warn: false,
+            analyze: false,
             verbosity: Verbosity::from_level(0),
             host,
             target,
@@ -327,11 +458,16 @@ impl Options {
             debugging_opts,
             current_dir: cwd,
             input_files: None,
+            bin: None,
+            apps: Vec::new(),
             output_file: None,
             output_dir: None,
+            cache_dir: None,
+            jobs: None,
             source_path_prefix: vec![],
             search_paths: Default::default(),
             include_path: Default::default(),
+            code_path: Default::default(),
             link_libraries: Default::default(),
             defines,
             cli_forced_thinlto_off: false,
@@ -491,6 +627,35 @@ fn detect_project_name<'a>(
     cwd.file_name().unwrap().to_str().unwrap().to_owned()
 }
 
+/// Returns the name of the application described by the resource file at `path`
+fn app_name(path: &Path) -> Option<&str> {
+    let file_name = path.file_name()?.to_str()?;
+    file_name
+        .strip_suffix(".app.src")
+        .or_else(|| file_name.strip_suffix(".app"))
+        .filter(|name| !name.is_empty())
+}
+
+/// Returns the directory containing the sources of the application described by the
+/// resource file at `path`
+///
+/// Following OTP conventions, an `.app.src` file lives alongside the sources, whereas an
+/// `.app` file lives in `ebin`, with the sources in the sibling `src` directory.
+fn app_source_dir(path: &Path) -> Option<PathBuf> {
+    let file_name = path.file_name()?.to_str()?;
+    let dir = match path.parent()? {
+        dir if dir.as_os_str().is_empty() => Path::new("."),
+        dir => dir,
+    };
+    if file_name.ends_with(".app.src") {
+        Some(dir.to_path_buf())
+    } else if file_name.ends_with(".app") {
+        Some(dir.join("..").join("src"))
+    } else {
+        None
+    }
+}
+
 /// Generate a default project configuration for the current session
 fn default_configuration(target: &Target) -> HashMap<String, Option<String>> {
     let end = target.target_endian.clone();
@@ -9,7 +9,7 @@ use liblumen_target::spec::{
     CodeModel, LinkerFlavor, MergeFunctions, PanicStrategy, RelocModel, RelroLevel, Target,
     TargetError, TlsModel,
 };
-use liblumen_util::diagnostics::ColorArg;
+use liblumen_util::diagnostics::{ColorArg, ErrorFormat};
 
 use super::OptionInfo;
 
@@ -190,6 +190,14 @@ impl ParseOption for ColorArg {
         choice.parse().map_err(|e| invalid_value(info, e))
     }
 }
+impl ParseOption for ErrorFormat {
+    fn parse_option<'a>(info: &OptionInfo, matches: &ArgMatches<'a>) -> clap::Result<Self> {
+        match matches.value_of(info.name) {
+            None => Ok(Self::default()),
+            Some(s) => s.parse().map_err(|e| invalid_value(info, e)),
+        }
+    }
+}
 
 pub(in crate::config) fn invalid_value(info: &OptionInfo, description: &str) -> clap::Error {
     clap::Error {
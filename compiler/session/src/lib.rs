@@ -1,3 +1,4 @@
+pub mod code_path;
 mod config;
 pub mod filesearch;
 pub mod search_paths;
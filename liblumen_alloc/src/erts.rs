@@ -4,6 +4,7 @@ pub mod fragment;
 pub mod message;
 mod module_function_arity;
 pub mod node;
+pub mod persistent_term;
 pub mod process;
 pub mod scheduler;
 pub mod string;
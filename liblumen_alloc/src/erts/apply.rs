@@ -2,11 +2,15 @@ use core::ffi::c_void;
 use core::mem;
 use core::slice;
 
+use alloc::sync::Arc;
+
 use hashbrown::{HashMap, HashSet};
+use lazy_static::lazy_static;
 
 use once_cell::sync::OnceCell;
 
 use liblumen_arena::DroplessArena;
+use liblumen_core::locks::RwLock;
 use liblumen_core::symbols::FunctionSymbol;
 #[cfg(all(unix, target_arch = "x86_64"))]
 use liblumen_core::sys::dynamic_call;
@@ -15,7 +19,7 @@ use liblumen_core::sys::dynamic_call::DynamicCallee;
 use crate::erts::term::prelude::Atom;
 #[cfg(all(unix, target_arch = "x86_64"))]
 use crate::erts::term::prelude::{Encoded, Term};
-use crate::erts::ModuleFunctionArity;
+use crate::erts::{Arity, ModuleFunctionArity};
 
 /// Dynamically invokes the function mapped to the given symbol.
 ///
@@ -55,17 +59,13 @@ pub unsafe fn apply_callee(callee: DynamicCallee, args: &[Term]) -> Term {
 }
 
 pub fn find_symbol(mfa: &ModuleFunctionArity) -> Option<DynamicCallee> {
-    let symbols = SYMBOLS.get().unwrap_or_else(|| {
-        panic!(
-            "InitializeLumenDispatchTable not called before trying to get {:?}",
-            mfa
-        )
-    });
-    if let Some(f) = symbols.get_function(mfa) {
-        Some(unsafe { mem::transmute::<*const c_void, DynamicCallee>(f) })
-    } else {
-        None
-    }
+    let function = match current_version(mfa.module) {
+        // The code server has loaded a version of the module, so only it is called
+        Some(version) => version.get_function(mfa),
+        None => symbol_table(mfa).get_function(mfa),
+    };
+
+    function.map(|f| unsafe { mem::transmute::<*const c_void, DynamicCallee>(f) })
 }
 
 pub fn dump_symbols() {
@@ -74,6 +74,10 @@ pub fn dump_symbols() {
 }
 
 pub fn module_loaded(module: Atom) -> bool {
+    if current_version(module).is_some() {
+        return true;
+    }
+
     let symbols = SYMBOLS.get().unwrap_or_else(|| {
         panic!(
             "InitializeLumenDispatchTable not called before trying to check module ({}) loaded",
@@ -84,9 +88,142 @@ pub fn module_loaded(module: Atom) -> bool {
     symbols.contains_module(module)
 }
 
+/// The functions of the current versions of all modules, such as for matching the patterns of
+/// `erlang:trace_pattern/2`.  Empty if the symbol table hasn't been initialized.
+pub fn module_function_arities() -> Vec<ModuleFunctionArity> {
+    let versions_by_module = VERSIONS_BY_MODULE.read();
+
+    let mut module_function_arity_vec: Vec<ModuleFunctionArity> = match SYMBOLS.get() {
+        Some(symbols) => symbols
+            .functions
+            .keys()
+            .filter(|mfa| !versions_by_module.contains_key(&mfa.module))
+            .map(|mfa| **mfa)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    for (module, versions) in versions_by_module.iter() {
+        match &versions.current {
+            Some(Version::Static) => {
+                if let Some(symbols) = SYMBOLS.get() {
+                    module_function_arity_vec.extend(
+                        symbols
+                            .functions
+                            .keys()
+                            .filter(|mfa| mfa.module == *module)
+                            .map(|mfa| **mfa),
+                    )
+                }
+            }
+            Some(Version::Loaded(loaded_code)) => {
+                module_function_arity_vec.extend(loaded_code.functions.keys().map(
+                    |(function, arity)| ModuleFunctionArity {
+                        module: *module,
+                        function: *function,
+                        arity: *arity,
+                    },
+                ))
+            }
+            None => (),
+        }
+    }
+
+    module_function_arity_vec
+}
+
+/// Makes `functions` the current version of `module`, so that `find_symbol` returns them instead
+/// of the functions of the previous version.  The previous current version, which may be the one
+/// compiled into the executable, becomes the old version: processes already running it keep
+/// doing so until it is purged.
+///
+/// Returns `false` without loading if `module` still has an old version.
+pub fn load_module(module: Atom, functions: HashMap<(Atom, Arity), *const c_void>) -> bool {
+    let mut versions_by_module = VERSIONS_BY_MODULE.write();
+    let versions = versions_by_module
+        .entry(module)
+        .or_insert_with(|| Versions {
+            current: if SYMBOLS
+                .get()
+                .map_or(false, |symbols| symbols.contains_module(module))
+            {
+                Some(Version::Static)
+            } else {
+                None
+            },
+            old: None,
+        });
+
+    if versions.old.is_some() {
+        return false;
+    }
+
+    versions.old = versions.current.take();
+    versions.current = Some(Version::Loaded(Arc::new(LoadedCode { functions })));
+
+    true
+}
+
+pub fn has_old_code(module: Atom) -> bool {
+    VERSIONS_BY_MODULE
+        .read()
+        .get(&module)
+        .map_or(false, |versions| versions.old.is_some())
+}
+
+/// Whether `function`, as called for `mfa`, is the old version of `mfa.module`.
+pub fn is_old_code(mfa: &ModuleFunctionArity, function: *const c_void) -> bool {
+    match VERSIONS_BY_MODULE.read().get(&mfa.module) {
+        Some(Versions {
+            old: Some(old),
+            current,
+        }) => {
+            old.get_function(mfa) == Some(function)
+                && current
+                    .as_ref()
+                    .and_then(|current| current.get_function(mfa))
+                    != Some(function)
+        }
+        _ => false,
+    }
+}
+
+/// Forgets the old version of `module`, so that it can be replaced by the next load.  The caller
+/// is responsible for making sure no process is running the old version anymore.
+///
+/// Returns `false` if `module` has no old version.
+pub fn purge_module(module: Atom) -> bool {
+    match VERSIONS_BY_MODULE.write().get_mut(&module) {
+        Some(versions) => versions.old.take().is_some(),
+        None => false,
+    }
+}
+
+fn current_version(module: Atom) -> Option<Version> {
+    VERSIONS_BY_MODULE
+        .read()
+        .get(&module)
+        .and_then(|versions| versions.current.clone())
+}
+
+fn symbol_table(mfa: &ModuleFunctionArity) -> &'static SymbolTable {
+    SYMBOLS.get().unwrap_or_else(|| {
+        panic!(
+            "InitializeLumenDispatchTable not called before trying to get {:?}",
+            mfa
+        )
+    })
+}
+
 /// The symbol table used by the runtime system
 static SYMBOLS: OnceCell<SymbolTable> = OnceCell::new();
 
+lazy_static! {
+    /// The versions of the modules the code server has loaded since startup.  Modules that are
+    /// not here only have the version in `SYMBOLS`.
+    static ref VERSIONS_BY_MODULE: RwLock<HashMap<Atom, Versions>> = Default::default();
+}
+
 /// Performs one-time initialization of the atom table at program start, using the
 /// array of constant atom values present in the compiled program.
 ///
@@ -196,3 +333,39 @@ impl SymbolTable {
 // These are safe to implement because the items in the symbol table are static
 unsafe impl Sync for SymbolTable {}
 unsafe impl Send for SymbolTable {}
+
+/// The functions of a module version loaded after startup, keyed by name and arity
+struct LoadedCode {
+    functions: HashMap<(Atom, Arity), *const c_void>,
+}
+
+// These are safe to implement because loaded code is never unmapped
+unsafe impl Sync for LoadedCode {}
+unsafe impl Send for LoadedCode {}
+
+#[derive(Clone)]
+enum Version {
+    /// The version compiled into the executable, whose functions are in `SYMBOLS`
+    Static,
+    Loaded(Arc<LoadedCode>),
+}
+
+impl Version {
+    fn get_function(&self, mfa: &ModuleFunctionArity) -> Option<*const c_void> {
+        match self {
+            Version::Static => symbol_table(mfa).get_function(mfa),
+            Version::Loaded(loaded_code) => loaded_code
+                .functions
+                .get(&(mfa.function, mfa.arity))
+                .copied(),
+        }
+    }
+}
+
+/// Like the BEAM, each module has at most two versions: the current version, which new calls use,
+/// and the old version, which processes that were running it when the current version was loaded
+/// may still be running.
+struct Versions {
+    current: Option<Version>,
+    old: Option<Version>,
+}
@@ -99,11 +99,27 @@ impl From<InternalException> for Exception {
     fn from(err: InternalException) -> Self {
         match err {
             InternalException::System(err) => Self::System(err),
+            InternalException::Internal(source) if is_system_limit(&source) => {
+                Self::Runtime(system_limit(Trace::capture(), Some(source)))
+            }
             InternalException::Internal(source) => Self::Runtime(badarg!(Trace::capture(), source)),
         }
     }
 }
 
+// Atoms are created from untrusted input, such as by `list_to_atom/1`, so exhausting the atom table
+// raises `system_limit`, as in BEAM, instead of the `badarg` used for other internal errors
+fn is_system_limit(error: &anyhow::Error) -> bool {
+    error
+        .chain()
+        .any(|cause| match cause.downcast_ref::<AtomError>() {
+            Some(AtomError::TooManyAtoms(_)) => true,
+            _ => cause
+                .downcast_ref::<ArcError>()
+                .map_or(false, |arc_error| is_system_limit(arc_error)),
+        })
+}
+
 /// Used to represent errors which occur when expecting a
 /// particular exception when converting from a more abstract type
 #[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
@@ -121,3 +137,40 @@ where
         Self(PhantomData, PhantomData)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::Context;
+
+    use crate::atom;
+
+    #[test]
+    fn too_many_atoms_is_system_limit() {
+        let result: anyhow::Result<Atom> = Err(AtomError::TooManyAtoms(8192))
+            .context("string (\"too_many\") cannot be converted to atom");
+        let exception: Exception = result.unwrap_err().into();
+
+        match exception {
+            Exception::Runtime(runtime_exception) => {
+                assert_eq!(runtime_exception.reason(), atom!("system_limit"))
+            }
+            Exception::System(_) => panic!("expected a runtime exception"),
+        }
+    }
+
+    #[test]
+    fn other_atom_errors_are_badarg() {
+        let result: anyhow::Result<Atom> =
+            Err(AtomError::NonExistent).context("string (\"missing\") cannot be converted to atom");
+        let exception: Exception = result.unwrap_err().into();
+
+        match exception {
+            Exception::Runtime(runtime_exception) => {
+                assert_eq!(runtime_exception.reason(), atom!("badarg"))
+            }
+            Exception::System(_) => panic!("expected a runtime exception"),
+        }
+    }
+}
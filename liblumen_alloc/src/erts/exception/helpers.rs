@@ -16,6 +16,11 @@ pub fn badarith(trace: Arc<Trace>, source: Option<ArcError>) -> RuntimeException
     self::error(atom("badarith"), None, trace, source)
 }
 
+#[inline]
+pub fn system_limit(trace: Arc<Trace>, source: Option<ArcError>) -> RuntimeException {
+    self::error(atom("system_limit"), None, trace, source)
+}
+
 pub fn badarity(
     process: &Process,
     fun: Term,
@@ -48,9 +53,11 @@ pub fn badmap(process: &Process, map: Term, trace: Arc<Trace>, source: ArcError)
     Exception::Runtime(self::error(reason, None, trace, Some(source)))
 }
 
+/// `undef` is an `error`, not an `exit`, so that it can be caught with `error:undef`.  The
+/// arguments of the undefined call belong in the top frame of `trace`.
 #[inline]
 pub fn undef(trace: Arc<Trace>, source: Option<ArcError>) -> Exception {
-    Exception::Runtime(self::exit(atom("undef"), trace, source))
+    Exception::Runtime(self::error(atom("undef"), None, trace, source))
 }
 
 #[inline]
@@ -302,6 +302,16 @@ impl HeapFragment {
         Self::new_improper_list_from_iter(slice.iter().copied(), tail)
     }
 
+    pub fn new_map(map: Map) -> AllocResult<(Boxed<Map>, NonNull<Self>)> {
+        let layout = Layout::for_value(&map);
+        let mut non_null_heap_fragment = Self::new(layout)?;
+        let heap_fragment = unsafe { non_null_heap_fragment.as_mut() };
+
+        heap_fragment
+            .map(map)
+            .map(|boxed_map| (boxed_map, non_null_heap_fragment))
+    }
+
     pub fn new_map_from_hash_map(
         hash_map: HashMap<Term, Term>,
     ) -> AllocResult<(Boxed<Map>, NonNull<Self>)> {
@@ -0,0 +1,300 @@
+//! Node-global storage for `persistent_term`
+//!
+//! Each key and value is copied once into its own `HeapFragment`, which is then registered as a
+//! literal area.  Reads return the stored term itself, so it is shared by all processes without
+//! copying, and the garbage collector skips pointers into literal areas instead of moving what they
+//! point to onto the process heap.
+//!
+//! Any process may still reference a term after it is replaced or erased, and there is no literal
+//! area collector to find those references, so the fragments of replaced and erased terms are
+//! retired rather than freed: they remain literal areas for the life of the node.
+//!
+//! The garbage collector checks every boxed pointer it sweeps with `contains`, so `contains` reads
+//! a per-thread copy of the literal areas, which is only refreshed, under the lock, after an area
+//! is added.
+
+use core::cell::RefCell;
+use core::mem;
+use core::ptr::NonNull;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc::vec::Vec;
+
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::RwLock;
+
+use crate::borrow::CloneToProcess;
+use crate::erts::exception::AllocResult;
+use crate::erts::process::alloc::Heap;
+use crate::erts::term::prelude::*;
+use crate::erts::HeapFragment;
+
+lazy_static! {
+    static ref ENTRY_BY_KEY: RwLock<EntryByKey> = Default::default();
+    /// The start and end address of each literal area, sorted by start address
+    static ref LITERAL_AREAS: RwLock<Vec<(usize, usize)>> = Default::default();
+}
+
+/// Incremented each time a literal area is added, so that threads know their copy is stale
+static LITERAL_AREAS_VERSION: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// This thread's copy of `LITERAL_AREAS` and the `LITERAL_AREAS_VERSION` it was copied at
+    static LITERAL_AREAS_COPY: RefCell<(usize, Vec<(usize, usize)>)> = RefCell::new((0, Vec::new()));
+}
+
+/// `persistent_term:info/0`
+pub struct Info {
+    /// The number of persistent terms
+    pub count: usize,
+    /// The number of bytes used by the keys and values of the persistent terms
+    pub memory: usize,
+}
+
+/// Returns `true` if `ptr` points into the literal area of a current or retired persistent term.
+///
+/// Such pointers must never be followed by the garbage collector of any process.
+pub fn contains<T: ?Sized>(ptr: *const T) -> bool {
+    let address = ptr as *const () as usize;
+    let version = LITERAL_AREAS_VERSION.load(Ordering::Acquire);
+
+    LITERAL_AREAS_COPY.with(|literal_areas_copy| {
+        let mut literal_areas_copy = literal_areas_copy.borrow_mut();
+
+        // Areas are never removed, so a copy at the current version has all of them
+        if literal_areas_copy.0 != version {
+            *literal_areas_copy = (version, LITERAL_AREAS.read().clone());
+        }
+
+        let literal_areas = &literal_areas_copy.1;
+
+        match literal_areas.binary_search_by_key(&address, |(start, _)| *start) {
+            Ok(_) => true,
+            Err(0) => false,
+            Err(index) => address < literal_areas[index - 1].1,
+        }
+    })
+}
+
+/// `persistent_term:erase/1`
+///
+/// Returns `true` if there was a persistent term for `key`.
+pub fn erase(key: Term) -> bool {
+    ENTRY_BY_KEY.write().0.remove(&key).is_some()
+}
+
+/// `persistent_term:get/1,2`
+///
+/// The returned term is shared and must not be mutated.
+pub fn get(key: Term) -> Option<Term> {
+    ENTRY_BY_KEY
+        .read()
+        .0
+        .get(&key)
+        .map(|entry| entry.value.term)
+}
+
+/// `persistent_term:info/0`
+pub fn info() -> Info {
+    let entry_by_key = ENTRY_BY_KEY.read();
+
+    Info {
+        count: entry_by_key.0.len(),
+        memory: entry_by_key
+            .0
+            .values()
+            .map(|entry| entry.key.size_in_words() + entry.value.size_in_words())
+            .sum::<usize>()
+            * mem::size_of::<Term>(),
+    }
+}
+
+/// `persistent_term:put/2`
+///
+/// Putting a value exactly equal (`=:=`) to the current value of `key` is a no-op, so that
+/// repeated puts of the same configuration do not retire literal areas.
+pub fn put(key: Term, value: Term) -> AllocResult<()> {
+    if let Some(current_value) = get(key) {
+        if current_value
+            .decode()
+            .unwrap()
+            .exact_eq(&value.decode().unwrap())
+        {
+            return Ok(());
+        }
+    }
+
+    let key = Literal::new(key)?;
+    let value = Literal::new(value)?;
+
+    ENTRY_BY_KEY
+        .write()
+        .0
+        .insert(key.term, Entry { key, value });
+
+    Ok(())
+}
+
+// Private
+
+#[derive(Default)]
+struct EntryByKey(HashMap<Term, Entry>);
+
+// Entries are only accessed while holding the `RwLock` and are never mutated
+unsafe impl Send for EntryByKey {}
+unsafe impl Sync for EntryByKey {}
+
+struct Entry {
+    key: Literal,
+    value: Literal,
+}
+
+/// A term copied into a literal area, or an immediate, which needs no area
+struct Literal {
+    term: Term,
+    heap_fragment: Option<NonNull<HeapFragment>>,
+}
+
+impl Literal {
+    fn new(term: Term) -> AllocResult<Self> {
+        if term.is_immediate() {
+            Ok(Self {
+                term,
+                heap_fragment: None,
+            })
+        } else {
+            let (term, heap_fragment) = term.clone_to_fragment()?;
+            let heap = unsafe { heap_fragment.as_ref() };
+
+            let start = heap.heap_start() as usize;
+            let mut literal_areas = LITERAL_AREAS.write();
+            let index = literal_areas
+                .binary_search_by_key(&start, |(start, _)| *start)
+                .unwrap_err();
+            literal_areas.insert(index, (start, heap.heap_end() as usize));
+            LITERAL_AREAS_VERSION.fetch_add(1, Ordering::Release);
+
+            Ok(Self {
+                term,
+                heap_fragment: Some(heap_fragment),
+            })
+        }
+    }
+
+    fn size_in_words(&self) -> usize {
+        match self.heap_fragment {
+            Some(heap_fragment) => unsafe { heap_fragment.as_ref() }.heap_used(),
+            None => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::process::alloc::TermAlloc;
+    use crate::erts::testing::RegionHeap;
+    use crate::{atom, fixnum};
+
+    #[test]
+    fn put_copies_boxed_terms_into_literal_area() {
+        let mut heap = RegionHeap::default();
+        let key = atom!("put_copies_boxed_terms_into_literal_area");
+        let value: Term = heap
+            .tuple_from_slice(&[fixnum!(1), fixnum!(2)])
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        put(key, value).unwrap();
+
+        let stored = get(key).unwrap();
+        let stored_ptr: *const Term = stored.dyn_cast();
+        let value_ptr: *const Term = value.dyn_cast();
+
+        assert_eq!(stored, value);
+        assert!(contains(stored_ptr));
+        assert!(!heap.contains(stored_ptr));
+        assert!(!contains(value_ptr));
+    }
+
+    #[test]
+    fn put_with_exactly_equal_value_does_not_replace_value() {
+        let mut heap = RegionHeap::default();
+        let key = atom!("put_with_exactly_equal_value_does_not_replace_value");
+        let value: Term = heap
+            .tuple_from_slice(&[fixnum!(1)])
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        put(key, value).unwrap();
+        let first_ptr: *const Term = get(key).unwrap().dyn_cast();
+
+        put(key, value).unwrap();
+        let second_ptr: *const Term = get(key).unwrap().dyn_cast();
+
+        assert_eq!(first_ptr, second_ptr);
+    }
+
+    #[test]
+    fn erase_removes_key_but_keeps_literal_area() {
+        let mut heap = RegionHeap::default();
+        let key = atom!("erase_removes_key_but_keeps_literal_area");
+        let value: Term = heap
+            .list_from_slice(&[fixnum!(1)])
+            .unwrap()
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        put(key, value).unwrap();
+
+        let stored_ptr: *const Term = get(key).unwrap().dyn_cast();
+
+        assert!(erase(key));
+        assert_eq!(get(key), None);
+        assert!(!erase(key));
+        assert!(contains(stored_ptr));
+    }
+
+    #[test]
+    fn contains_sees_literal_areas_added_by_other_threads() {
+        let mut heap = RegionHeap::default();
+        let value: Term = heap
+            .tuple_from_slice(&[fixnum!(1)])
+            .unwrap()
+            .encode()
+            .unwrap();
+        let value_ptr: *const Term = value.dyn_cast();
+
+        // Copies the literal areas for this thread
+        assert!(!contains(value_ptr));
+
+        let stored_address = std::thread::spawn(move || {
+            let key = atom!("contains_sees_literal_areas_added_by_other_threads");
+
+            put(key, value).unwrap();
+
+            let stored_ptr: *const Term = get(key).unwrap().dyn_cast();
+
+            stored_ptr as usize
+        })
+        .join()
+        .unwrap();
+
+        assert!(contains(stored_address as *const Term));
+    }
+
+    #[test]
+    fn put_with_immediate_value_does_not_need_literal_area() {
+        let key = atom!("put_with_immediate_value_does_not_need_literal_area");
+
+        put(key, fixnum!(1)).unwrap();
+
+        assert_eq!(get(key), Some(fixnum!(1)));
+    }
+}
@@ -1,4 +1,5 @@
 pub mod alloc;
+mod exit_signal;
 pub mod ffi;
 mod flags;
 mod frame;
@@ -6,13 +7,16 @@ mod frame;
 mod frame_with_arguments;
 mod frames;
 pub mod gc;
+pub mod gc_defaults;
 mod heap;
 mod mailbox;
+mod max_heap_size;
 mod monitor;
 pub mod priority;
 pub mod trace;
 
 use core::cell::RefCell;
+use core::cmp;
 use core::convert::TryInto;
 use core::ffi::c_void;
 use core::fmt::{self, Debug};
@@ -23,6 +27,7 @@ use core::ptr::{self, NonNull};
 use core::str::Chars;
 use core::sync::atomic::{AtomicU16, AtomicU64, AtomicUsize, Ordering};
 
+use ::alloc::collections::VecDeque;
 use ::alloc::sync::Arc;
 
 use std::panic::catch_unwind;
@@ -51,6 +56,7 @@ use super::*;
 use self::alloc::VirtualAllocator;
 use self::alloc::{Heap, HeapAlloc, TermAlloc};
 use self::alloc::{StackAlloc, StackPrimitives};
+pub use self::exit_signal::{ExitSignal, ExitSignalKind};
 use self::ffi::{set_process_signal, ProcessSignal};
 pub use self::frame::{Frame, Native};
 pub use self::frame_with_arguments::FrameWithArguments;
@@ -58,8 +64,9 @@ pub use self::frames::{Frames, StackTrace};
 use self::gc::{GcError, RootSet};
 
 pub use self::flags::*;
-pub use self::heap::ProcessHeap;
+pub use self::heap::{GarbageCollectionInfo, ProcessHeap};
 pub use self::mailbox::*;
+pub use self::max_heap_size::MaxHeapSize;
 pub use self::monitor::Monitor;
 pub use self::priority::Priority;
 use crate::erts::process::ffi::process_error;
@@ -108,7 +115,7 @@ pub struct Process {
     /// Minimum size of the heap that this process will start with
     min_heap_size: usize,
     /// The maximum size of the heap allowed for this process
-    max_heap_size: usize,
+    max_heap_size: MaxHeapSize,
     /// Minimum virtual heap size for this process
     min_vheap_size: usize,
     /// The percentage of used to unused space at which a collection is triggered
@@ -130,6 +137,10 @@ pub struct Process {
     /// exceeds `MAX_REDUCTIONS_PER_RUN`.
     run_reductions: AtomicU16,
     pub total_reductions: AtomicU64,
+    /// The number of garbage collections, both minor and full sweep, that have completed
+    pub total_garbage_collections: AtomicU64,
+    /// The number of words freed by all garbage collections
+    pub total_words_reclaimed: AtomicU64,
     pub frames: Mutex<Frames>,
     pub status: RwLock<Status>,
     pub registered_name: RwLock<Option<Atom>>,
@@ -142,6 +153,8 @@ pub struct Process {
     /// Maps monitor references to the PID of the process being monitored by this process.
     pub monitored_pid_by_reference: DashMap<Reference, Pid>,
     pub mailbox: Mutex<RefCell<Mailbox>>,
+    /// Exit signals waiting for the scheduler to deliver them before the process next runs
+    exit_signals: Mutex<VecDeque<ExitSignal>>,
     pub registers: CalleeSavedRegisters,
     pub stack: Mutex<alloc::Stack>,
     // process heap, cache line aligned to avoid false sharing with rest of struct
@@ -173,16 +186,17 @@ impl Process {
         Self {
             flags: AtomicProcessFlags::new(ProcessFlags::Default),
             min_heap_size: heap_size,
-            max_heap_size: 0,
+            max_heap_size: gc_defaults::max_heap_size(),
             min_vheap_size: 0,
             gc_threshold: 0.75,
-            max_gen_gcs: 65535,
+            max_gen_gcs: gc_defaults::fullsweep_after(),
             off_heap,
             off_heap_size: AtomicUsize::new(0),
             dictionary: Default::default(),
             pid,
             status: Default::default(),
             mailbox: Default::default(),
+            exit_signals: Default::default(),
             heap: Mutex::new(heap),
             stack: Default::default(),
             registers: Default::default(),
@@ -194,6 +208,8 @@ impl Process {
             initial_module_function_arity,
             run_reductions: Default::default(),
             total_reductions: Default::default(),
+            total_garbage_collections: Default::default(),
+            total_words_reclaimed: Default::default(),
             registered_name: Default::default(),
             linked_pid_set: Default::default(),
             monitor_by_reference: Default::default(),
@@ -451,6 +467,28 @@ impl Process {
         self.heap.lock().stack_used()
     }
 
+    /// Size of the young generation heap, in words, including the stack
+    pub fn heap_size(&self) -> usize {
+        self.heap.lock().heap_size()
+    }
+
+    /// Sizes of the heap generations and off-heap fragments, in words
+    pub fn garbage_collection_info(&self) -> GarbageCollectionInfo {
+        let garbage_collection_info = self.heap.lock().garbage_collection_info();
+
+        GarbageCollectionInfo {
+            mbuf_size: self.off_heap_size(),
+            ..garbage_collection_info
+        }
+    }
+
+    /// Size of all heap generations and off-heap fragments, in words
+    pub fn total_heap_size(&self) -> usize {
+        let heap_size = self.heap.lock().total_heap_size();
+
+        heap_size + self.off_heap_size()
+    }
+
     // Links
 
     pub fn link(&self, other: &Process) {
@@ -543,6 +581,17 @@ impl Process {
         self.send_message(Message::Process(message::Process { data }));
     }
 
+    /// Queues `exit_signal` to be delivered by the scheduler before the process next runs, so
+    /// that the status of a running process is only ever changed by the process itself.
+    pub fn send_exit_signal(&self, exit_signal: ExitSignal) {
+        self.exit_signals.lock().push_back(exit_signal);
+    }
+
+    /// Takes the exit signals waiting to be delivered, in the order they were sent.
+    pub fn take_exit_signals(&self) -> VecDeque<ExitSignal> {
+        mem::take(&mut *self.exit_signals.lock())
+    }
+
     /// Returns `true` if the process should stop waiting and be rescheduled as runnable.
     pub fn send_from_other(&self, data: Term) {
         match self.heap.try_lock() {
@@ -762,6 +811,15 @@ impl Process {
         optional_cons_to_term(optional_cons)
     }
 
+    /// Unlike `map_from_hash_map`, the entries of `map` are not copied, so `map` must be made
+    /// from terms on this process's heap, such as by `Map::put` on one of its maps.
+    pub fn map(&self, map: Map) -> Term {
+        self.acquire_heap()
+            .map(map.clone())
+            .unwrap_or_else(|_| self.attach_fragment_or_panic(HeapFragment::new_map(map)))
+            .into()
+    }
+
     pub fn map_from_hash_map(&self, hash_map: HashMap<Term, Term>) -> Term {
         self.acquire_heap()
             .map_from_hash_map(hash_map.clone())
@@ -889,6 +947,15 @@ impl Process {
         self.list_from_slice(&entry_vec).into()
     }
 
+    /// Returns all key/value pairs from process dictionary without allocating them on the heap, so
+    /// that they can be copied to another process.
+    pub fn get_entry_vec(&self) -> Vec<(Term, Term)> {
+        self.dictionary
+            .iter()
+            .map(|entry| (*entry.key(), *entry.value()))
+            .collect()
+    }
+
     /// Returns list of all keys from the process dictionary.
     pub fn get_keys(&self) -> Term {
         let entry_vec: Vec<Term> = self.dictionary.iter().map(|entry| *entry.key()).collect();
@@ -897,14 +964,17 @@ impl Process {
     }
 
     /// Returns list of all keys from the process dictionary that have `value`.
+    ///
+    /// Values are compared with `=:=`, so `1` and `1.0` are distinct, as in `erts`.
     pub fn get_keys_from_value(&self, value: Term) -> Term {
+        let value = value.decode().unwrap();
         let key_vec: Vec<Term> = self
             .dictionary
             .iter()
             .filter_map(|entry| {
                 let entry_key = entry.key();
-                let entry_value = entry.value();
-                if entry_value == &value {
+                let entry_value = entry.value().decode().unwrap();
+                if entry_value.exact_eq(&value) {
                     Some(*entry_key)
                 } else {
                     None
@@ -955,6 +1025,57 @@ impl Process {
         heap.should_collect(self.gc_threshold)
     }
 
+    /// Minimum size of the heap, in words
+    #[inline]
+    pub fn min_heap_size(&self) -> usize {
+        self.min_heap_size
+    }
+
+    /// Minimum size of the virtual binary heap, in words
+    #[inline]
+    pub fn min_vheap_size(&self) -> usize {
+        self.min_vheap_size
+    }
+
+    /// Sets the minimum size of the virtual binary heap, in words.  Only valid before the process
+    /// is scheduled.
+    #[inline]
+    pub fn set_min_vheap_size(&mut self, min_vheap_size: usize) {
+        self.min_vheap_size = min_vheap_size;
+    }
+
+    /// Maximum size of the heap and what happens when it would be exceeded
+    #[inline]
+    pub fn max_heap_size(&self) -> MaxHeapSize {
+        self.max_heap_size
+    }
+
+    /// Sets the maximum size of the heap and what happens when it would be exceeded.  Only valid
+    /// before the process is scheduled.
+    #[inline]
+    pub fn set_max_heap_size(&mut self, max_heap_size: MaxHeapSize) {
+        self.max_heap_size = max_heap_size;
+    }
+
+    /// The number of minor collections that can occur before a full sweep is forced
+    #[inline]
+    pub fn fullsweep_after(&self) -> usize {
+        self.max_gen_gcs
+    }
+
+    /// Sets the number of minor collections that can occur before a full sweep is forced.  Only
+    /// valid before the process is scheduled.
+    #[inline]
+    pub fn set_fullsweep_after(&mut self, fullsweep_after: usize) {
+        self.max_gen_gcs = fullsweep_after;
+    }
+
+    /// The number of minor collections performed since the last full sweep
+    #[inline]
+    pub fn minor_gcs(&self) -> usize {
+        self.heap.lock().gen_gc_count
+    }
+
     #[inline(always)]
     fn off_heap_size(&self) -> usize {
         self.off_heap_size.load(Ordering::Acquire)
@@ -1007,12 +1128,49 @@ impl Process {
         roots: impl Into<RootSet>,
     ) -> Result<usize, GcError> {
         let mut heap = self.heap.lock();
+        // Hold the mailbox for the whole collection, so that a sender can't move the messages
+        // added to the root set
+        let mailbox_guard = self.mailbox.lock();
+        let mut mailbox = mailbox_guard.borrow_mut();
         // The roots passed in here are pointers to the native stack, all other roots
         // we are able to pick up from the current process context
         let mut rootset = roots.into();
         self.base_root_set(&mut rootset);
+        mailbox.root_set(&mut rootset);
+
+        let words_before = self.words_used(&heap);
         // Initialize the collector with the given root set
-        heap.garbage_collect(self, need, rootset)
+        let result = heap.garbage_collect(self, need, rootset);
+
+        if result.is_ok() {
+            let words_reclaimed = words_before.saturating_sub(self.words_used(&heap));
+
+            self.total_garbage_collections
+                .fetch_add(1, Ordering::SeqCst);
+            self.total_words_reclaimed
+                .fetch_add(words_reclaimed as u64, Ordering::SeqCst);
+        }
+
+        result
+    }
+
+    /// Words used on both heap generations and in off-heap fragments
+    fn words_used(&self, heap: &ProcessHeap) -> usize {
+        let garbage_collection_info = heap.garbage_collection_info();
+
+        garbage_collection_info.heap_size
+            + garbage_collection_info.old_heap_size
+            + self.off_heap_size()
+    }
+
+    /// Requests a full sweep garbage collection, such as for `erlang:garbage_collect/0,1` and
+    /// `erlang:hibernate/3`, which also shrinks the heap to fit the live data.
+    ///
+    /// Only the stack map knows which terms on the native stack are live, so the collection is
+    /// done the next time the process collects through `__lumen_builtin_gc.run`, which walks it.
+    pub fn force_garbage_collection(&self) {
+        self.flags
+            .set(ProcessFlags::ForceGC | ProcessFlags::NeedFullSweep);
     }
 
     /// Cleans up any linked HeapFragments which should have had any live
@@ -1071,6 +1229,34 @@ impl Process {
         MAX_REDUCTIONS_PER_RUN <= self.run_reductions.load(Ordering::SeqCst)
     }
 
+    /// Consumes `reductions` for work done by a native function in the current run.
+    ///
+    /// Native functions whose work grows with their arguments, such as walking a list, should
+    /// consume reductions as they go and, when told to yield, queue a frame for a label that
+    /// resumes the remaining work and return `Term::NONE`, so that the scheduler can run other
+    /// processes before the label is called.
+    ///
+    /// A process that is not running, such as when a native function is called directly in tests,
+    /// has no scheduler to yield to, so it is never told to yield.
+    pub fn consume_reductions(&self, reductions: usize) -> Yield {
+        let reductions = cmp::min(reductions, MAX_REDUCTIONS_PER_RUN as usize) as Reductions;
+        // Saturate, so that a process that is not running and never resets its run reductions
+        // does not wrap around
+        let run_reductions = self
+            .run_reductions
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |run_reductions| {
+                Some(run_reductions.saturating_add(reductions))
+            })
+            .unwrap()
+            .saturating_add(reductions);
+
+        if MAX_REDUCTIONS_PER_RUN <= run_reductions && *self.status.read() == Status::Running {
+            Yield::Now
+        } else {
+            Yield::Continue
+        }
+    }
+
     pub fn runnable<F>(&self, before_runnable: F)
     where
         F: FnOnce(),
@@ -1118,6 +1304,38 @@ impl Process {
         }
     }
 
+    /// Discards the frames that called the current frame, along with the terms they left on the
+    /// stack, for `erlang:hibernate/3`, so that when the current frame's continuation returns,
+    /// the process ends instead of returning to them.
+    pub fn discard_callers(&self) {
+        let mut frames = self.frames.lock();
+        let arity = frames
+            .current()
+            .unwrap_or_else(|| panic!("Process ({}) has no current frame", self))
+            .native()
+            .arity() as usize;
+        frames.discard_callers();
+        drop(frames);
+
+        let mut heap = self.heap.lock();
+        let stack_size = heap.stack_size();
+
+        if arity < stack_size {
+            let arguments: Vec<Term> = (1..=arity)
+                .map(|one_based_index| heap.stack_slot(one_based_index).unwrap())
+                .collect();
+            heap.stack_popn(stack_size);
+
+            // Popping freed the space, so there is room to push the arguments back
+            for argument in arguments.into_iter().rev() {
+                unsafe {
+                    let stack0 = heap.alloca(1).unwrap().as_ptr();
+                    ptr::write(stack0, argument);
+                }
+            }
+        }
+    }
+
     /// Puts the process in the waiting status
     pub fn wait(&self) {
         *self.status.write() = Status::Waiting;
@@ -1452,6 +1670,17 @@ enum CalledCurrentNative {
     SystemException,
 }
 
+/// Whether a native function should yield after `Process::consume_reductions`
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Yield {
+    /// The run still has reductions left, so the native function can continue
+    Continue,
+    /// The run has used all of its reductions, so the native function should queue a frame to
+    /// resume its work and return `Term::NONE`
+    Now,
+}
+
 pub enum Ran {
     Waiting,
     Reduced,
@@ -8,7 +8,7 @@ use liblumen_core::sys::sysconf::MIN_ALIGN;
 use liblumen_core::util::pointer::{distance_absolute, in_area};
 
 use crate::erts::exception::AllocResult;
-use crate::erts::term::prelude::Term;
+use crate::erts::term::prelude::{Boxed, ProcBin, Term};
 
 /// The core trait for allocating on a heap
 pub trait HeapAlloc {
@@ -105,6 +105,14 @@ pub trait Heap: HeapAlloc {
         self.contains(ptr)
     }
 
+    /// Links a `ProcBin` that was just cloned on to this heap into its virtual binary heap, so
+    /// that the reference the clone holds is released when it is collected
+    ///
+    /// Defaults to doing nothing, for heaps such as heap fragments that don't have a virtual
+    /// binary heap; their binaries are linked when they are swept on to a process heap.
+    #[inline]
+    fn virtual_alloc_procbin(&mut self, _bin: Boxed<ProcBin>) {}
+
     #[cfg(debug_assertions)]
     #[inline]
     fn sanity_check(&self) {
@@ -177,4 +185,9 @@ where
     fn is_owner<U: ?Sized>(&self, ptr: *const U) -> bool {
         self.deref().is_owner(ptr)
     }
+
+    #[inline]
+    fn virtual_alloc_procbin(&mut self, bin: Boxed<ProcBin>) {
+        self.deref_mut().virtual_alloc_procbin(bin)
+    }
 }
@@ -200,6 +200,11 @@ where
         self.young.sanity_check();
         self.old.sanity_check();
     }
+
+    #[inline]
+    fn virtual_alloc_procbin(&mut self, bin: Boxed<ProcBin>) {
+        self.virtual_alloc(bin)
+    }
 }
 impl<A, B> HeapAlloc for SemispaceHeap<A, B>
 where
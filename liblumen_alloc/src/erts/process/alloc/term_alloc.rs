@@ -269,6 +269,20 @@ pub trait TermAlloc: Heap {
         Ok(ptr)
     }
 
+    /// Moves `map` onto the heap without copying its entries, so they must already be on this
+    /// heap, as they are for a map returned by `Map::put` on a map on this heap.
+    fn map(&mut self, map: Map) -> AllocResult<Boxed<Map>>
+    where
+        Self: Sized,
+    {
+        let map_ptr = unsafe { self.alloc_layout(Layout::for_value(&map))?.as_ptr() as *mut Map };
+
+        unsafe {
+            map_ptr.write(map);
+            Ok(Boxed::new_unchecked(map_ptr))
+        }
+    }
+
     /// Constructs a map and associated with the given process.
     fn map_from_slice(&mut self, slice: &[(Term, Term)]) -> AllocResult<Boxed<Map>>
     where
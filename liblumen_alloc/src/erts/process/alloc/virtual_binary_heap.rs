@@ -28,10 +28,12 @@ impl VirtualAllocator<ProcBin> for VirtualBinaryHeap {
     fn virtual_free(&mut self, ptr: Boxed<ProcBin>) {
         let raw = ptr.as_ptr();
         debug_assert!(self.virtual_contains(raw));
+        let bin_size = ptr.as_ref().full_byte_len();
         unsafe {
             self.unlink_raw(raw);
             ptr::drop_in_place(raw);
         }
+        self.used -= bin_size;
     }
 
     fn virtual_pop(&mut self, ptr: Boxed<ProcBin>) -> ProcBin {
@@ -84,6 +86,7 @@ impl VirtualAllocator<ProcBin> for VirtualBinaryHeap {
             let ptr = cursor.remove().unwrap();
             ptr::drop_in_place(UnsafeRef::into_raw(ptr));
         }
+        self.used = 0;
     }
 }
 impl VirtualHeap<ProcBin> for VirtualBinaryHeap {
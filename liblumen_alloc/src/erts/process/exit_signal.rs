@@ -0,0 +1,77 @@
+use core::ptr::{self, NonNull};
+
+use alloc::sync::Arc;
+
+use crate::borrow::CloneToProcess;
+use crate::erts::exception::{AllocResult, ArcError};
+use crate::erts::fragment::HeapFragment;
+use crate::erts::term::prelude::*;
+
+use super::trace::Trace;
+use super::Process;
+
+/// Why an exit signal was sent, which determines whether a `kill` reason can be trapped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitSignalKind {
+    /// Sent by `erlang:exit/2`.  A `kill` reason can't be trapped and exits the receiver with
+    /// `killed`.
+    Exit,
+    /// Sent because a linked process exited.  A `kill` reason is trapped like any other.
+    Link,
+}
+
+/// An exit signal waiting in a process's signal queue until its scheduler delivers it.
+///
+/// The reason is copied into a heap fragment owned by the signal, so that the sender's heap can
+/// be collected or freed before the signal is delivered.  Delivering the signal moves the heap
+/// fragment to the receiving process.
+pub struct ExitSignal {
+    pub kind: ExitSignalKind,
+    pub from: Pid,
+    pub trace: Arc<Trace>,
+    pub source: Option<ArcError>,
+    reason: Term,
+    heap_fragment: Option<NonNull<HeapFragment>>,
+}
+
+impl ExitSignal {
+    pub fn new(
+        kind: ExitSignalKind,
+        from: Pid,
+        reason: Term,
+        trace: Arc<Trace>,
+        source: Option<ArcError>,
+    ) -> AllocResult<Self> {
+        let (reason, heap_fragment) = reason.clone_to_fragment()?;
+
+        Ok(Self {
+            kind,
+            from,
+            trace,
+            source,
+            reason,
+            heap_fragment: Some(heap_fragment),
+        })
+    }
+
+    /// Moves the reason's heap fragment to `process`, so that the reason can be used in
+    /// `process`.
+    pub fn attach_reason(mut self, process: &Process) -> Term {
+        let mut heap_fragment = self.heap_fragment.take().unwrap();
+        process.attach_fragment(unsafe { heap_fragment.as_mut() });
+
+        self.reason
+    }
+}
+
+impl Drop for ExitSignal {
+    fn drop(&mut self) {
+        // The signal was dropped without being delivered, such as when the receiver exited first
+        if let Some(heap_fragment) = self.heap_fragment.take() {
+            unsafe { ptr::drop_in_place(heap_fragment.as_ptr()) };
+        }
+    }
+}
+
+// The heap fragment is only accessed by whichever thread owns the signal
+unsafe impl Send for ExitSignal {}
@@ -23,6 +23,11 @@ impl Frames {
         self.stack.top()
     }
 
+    /// Iterates the frames that are currently executing, from the top of the stack
+    pub fn iter(&self) -> impl Iterator<Item = &Frame> {
+        self.stack.iter()
+    }
+
     pub fn push(&mut self, frame: Frame) {
         self.stack.push(frame);
     }
@@ -35,6 +40,12 @@ impl Frames {
         self.stack.popn(n)
     }
 
+    /// Discards the frames between the current frame and the bottom frame, which ends the process,
+    /// so that the current frame's continuation can never return to them.
+    pub fn discard_callers(&mut self) {
+        self.stack.retain_top_and_bottom()
+    }
+
     /// Queue a future `frame` to run once `Frame` at top of `stack` returns.
     pub fn queue(&mut self, frame_with_arguments: FrameWithArguments) {
         self.queue.push(frame_with_arguments);
@@ -10,10 +10,24 @@ use crate::process::Frame;
 pub struct Stack(VecDeque<Frame>);
 
 impl Stack {
+    /// Iterates the frames from the top of the stack
+    pub fn iter(&self) -> impl Iterator<Item = &Frame> {
+        self.0.iter()
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
 
+    /// Removes every frame except the top and the bottom frames
+    pub fn retain_top_and_bottom(&mut self) {
+        if 2 < self.len() {
+            let bottom = self.0.pop_back().unwrap();
+            self.0.truncate(1);
+            self.0.push_back(bottom);
+        }
+    }
+
     pub fn pop(&mut self) -> Option<Frame> {
         self.0.pop_front()
     }
@@ -2,6 +2,7 @@ use core::alloc::Layout;
 use core::ptr::NonNull;
 
 use crate::erts::exception::AllocResult;
+use crate::erts::persistent_term;
 use crate::erts::process::alloc::*;
 use crate::erts::term::prelude::*;
 
@@ -373,8 +374,14 @@ where
             return 0;
         }
 
-        // Check if this is a move marker
         let box_ptr: *mut Term = (*pos).dyn_cast();
+
+        // Skip pointers into `persistent_term` literal areas, which are shared by all processes
+        if persistent_term::contains(box_ptr) {
+            return 0;
+        }
+
+        // Check if this is a move marker
         let unboxed = &*box_ptr;
         if unboxed.is_boxed() {
             // Overwrite the move marker with the forwarding address
@@ -421,8 +428,14 @@ where
             return 0;
         }
 
-        // Check if this is a move marker
         let ptr: Boxed<Cons> = (*pos).dyn_cast();
+
+        // Skip pointers into `persistent_term` literal areas
+        if persistent_term::contains(ptr.as_ptr()) {
+            return 0;
+        }
+
+        // Check if this is a move marker
         let cons = ptr.as_ref();
         if cons.is_move_marker() {
             // Overwrite the move marker with the forwarding address
@@ -101,6 +101,11 @@ impl Heap for OldHeap {
     fn heap_end(&self) -> *mut Term {
         self.end
     }
+
+    #[inline]
+    fn virtual_alloc_procbin(&mut self, bin: Boxed<ProcBin>) {
+        self.virtual_alloc(bin)
+    }
 }
 impl HeapAlloc for OldHeap {
     #[inline]
@@ -4,7 +4,9 @@ use core::mem;
 use core::ops::Deref;
 use core::ptr::NonNull;
 
-use crate::erts::process::alloc::TermAlloc;
+use crate::erts::persistent_term;
+use crate::erts::process::alloc::{Heap, TermAlloc};
+use crate::erts::process::gc::GcError;
 use crate::erts::process::test::process;
 use crate::erts::term::closure::*;
 use crate::erts::term::prelude::*;
@@ -48,6 +50,87 @@ fn gc_fullsweep_after_tenuring_test() {
     tenuring_gc_test(process, true);
 }
 
+// This test ensures that minor collections are counted until a full sweep resets the count
+#[test]
+fn gc_minor_gcs_reset_by_fullsweep_test() {
+    let process = process();
+    assert_eq!(process.minor_gcs(), 0);
+
+    let mut roots = [];
+    process.garbage_collect(0, &mut roots[..]).unwrap();
+    assert_eq!(process.minor_gcs(), 1);
+
+    let mut roots = [];
+    process.garbage_collect(0, &mut roots[..]).unwrap();
+    assert_eq!(process.minor_gcs(), 2);
+
+    process.set_flags(ProcessFlags::NeedFullSweep);
+    let mut roots = [];
+    process.garbage_collect(0, &mut roots[..]).unwrap();
+    assert_eq!(process.minor_gcs(), 0);
+}
+
+// This test ensures that a collection that would grow the heap past `max_heap_size` fails when
+// the process should be killed
+#[test]
+fn gc_max_heap_size_exceeded_with_kill_test() {
+    let mut process = process();
+    process.set_max_heap_size(MaxHeapSize {
+        size: 1,
+        kill: true,
+        error_logger: false,
+    });
+
+    let mut roots = [];
+    assert_eq!(
+        process.garbage_collect(0, &mut roots[..]),
+        Err(GcError::MaxHeapSizeExceeded)
+    );
+}
+
+// This test ensures that the heap is allowed to grow past `max_heap_size` when the process should
+// not be killed
+#[test]
+fn gc_max_heap_size_exceeded_without_kill_test() {
+    let mut process = process();
+    process.set_max_heap_size(MaxHeapSize {
+        size: 1,
+        kill: false,
+        error_logger: false,
+    });
+
+    let mut roots = [];
+    assert!(process.garbage_collect(0, &mut roots[..]).is_ok());
+    // The minor collection was promoted to a full sweep
+    assert_eq!(process.minor_gcs(), 0);
+}
+
+// This test ensures that terms in `persistent_term` literal areas are shared instead of being
+// moved onto the process heap, even when referenced from terms that are moved
+#[test]
+fn gc_skips_persistent_terms_test() {
+    let process = process();
+    let key = atom!("gc_skips_persistent_terms_test");
+    let value = process.tuple_from_slice(&[atom!("config"), fixnum!(1)]);
+    persistent_term::put(key, value).unwrap();
+
+    let persistent = persistent_term::get(key).unwrap();
+    let persistent_ptr: *const Term = persistent.dyn_cast();
+    let list = process.list_from_slice(&[persistent]);
+
+    let mut roots = [persistent, list];
+    process.garbage_collect(0, &mut roots[..]).unwrap();
+    let [persistent_root, list_root] = roots;
+
+    let persistent_root_ptr: *const Term = persistent_root.dyn_cast();
+    assert_eq!(persistent_root_ptr, persistent_ptr);
+
+    let cons: Boxed<Cons> = list_root.try_into().unwrap();
+    let head_ptr: *const Term = cons.head.dyn_cast();
+    assert_eq!(head_ptr, persistent_ptr);
+    assert!(!process.acquire_heap().contains(head_ptr));
+}
+
 fn simple_gc_test(process: Process) {
     // Allocate an `{:ok, "hello world"}` tuple
     // First, the `ok` atom, an immediate, is super easy
@@ -388,6 +388,11 @@ impl Heap for YoungHeap {
     fn sanity_check(&self) {
         self.overrun_check();
     }
+
+    #[inline]
+    fn virtual_alloc_procbin(&mut self, bin: Boxed<ProcBin>) {
+        self.virtual_alloc(bin)
+    }
 }
 impl HeapAlloc for YoungHeap {
     #[inline]
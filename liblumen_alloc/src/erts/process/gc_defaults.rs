@@ -0,0 +1,58 @@
+//! Runtime-wide defaults for the garbage collection options of new processes
+//!
+//! These are set with `erlang:system_flag/2` and only apply to processes spawned afterwards.
+//! `erlang:spawn_opt` options override them for a single process.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::RwLock;
+
+use super::alloc;
+use super::MaxHeapSize;
+
+/// The number of minor collections before a full sweep is forced, the same as the BEAM
+const DEFAULT_FULLSWEEP_AFTER: usize = 65535;
+
+static FULLSWEEP_AFTER: AtomicUsize = AtomicUsize::new(DEFAULT_FULLSWEEP_AFTER);
+
+lazy_static! {
+    static ref MIN_HEAP_SIZE: AtomicUsize = AtomicUsize::new(alloc::default_heap_size());
+    static ref MAX_HEAP_SIZE: RwLock<MaxHeapSize> = Default::default();
+}
+
+/// The number of minor collections that can occur before a full sweep is forced
+pub fn fullsweep_after() -> usize {
+    FULLSWEEP_AFTER.load(Ordering::Acquire)
+}
+
+/// Returns the previous default
+pub fn set_fullsweep_after(fullsweep_after: usize) -> usize {
+    FULLSWEEP_AFTER.swap(fullsweep_after, Ordering::AcqRel)
+}
+
+/// Minimum size of the heap, in words.  Heaps grow from here through the same Fibonacci-like
+/// sizes as the BEAM.
+pub fn min_heap_size() -> usize {
+    MIN_HEAP_SIZE.load(Ordering::Acquire)
+}
+
+/// Rounds `min_heap_size` up to the next heap size, like the BEAM, and returns the previous
+/// default
+pub fn set_min_heap_size(min_heap_size: usize) -> usize {
+    MIN_HEAP_SIZE.swap(alloc::next_heap_size(min_heap_size), Ordering::AcqRel)
+}
+
+pub fn max_heap_size() -> MaxHeapSize {
+    *MAX_HEAP_SIZE.read()
+}
+
+/// Returns the previous default
+pub fn set_max_heap_size(max_heap_size: MaxHeapSize) -> MaxHeapSize {
+    let mut guard = MAX_HEAP_SIZE.write();
+    let previous = *guard;
+    *guard = max_heap_size;
+
+    previous
+}
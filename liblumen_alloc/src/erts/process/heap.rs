@@ -1,7 +1,7 @@
 use core::alloc::Layout;
 use core::ptr::NonNull;
 
-use log::trace;
+use log::{error, trace};
 
 use liblumen_core::util::pointer::distance_absolute;
 
@@ -12,6 +12,23 @@ use super::alloc::{self, *};
 use super::gc::{self, *};
 use super::{Process, ProcessFlags};
 
+/// Sizes of a process's heap, in words, as reported by
+/// `process_info(Pid, garbage_collection_info)`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GarbageCollectionInfo {
+    /// Size of the young generation heap, including the stack
+    pub heap_block_size: usize,
+    /// Used space in the young generation heap
+    pub heap_size: usize,
+    /// Size of the old generation heap, or `0` if there isn't one
+    pub old_heap_block_size: usize,
+    /// Used space in the old generation heap
+    pub old_heap_size: usize,
+    pub stack_size: usize,
+    /// Size of the heap fragments attached to the process
+    pub mbuf_size: usize,
+}
+
 /// This struct contains the actual semi-space heap that stack/heap allocations
 /// are delegated to, and provides coordination for garbage collection of the
 /// heap given the current process context.
@@ -40,6 +57,27 @@ impl ProcessHeap {
         self.heap.should_collect(gc_threshold)
     }
 
+    /// Returns the size of both generations of the heap, in words
+    #[inline]
+    pub fn total_heap_size(&self) -> usize {
+        self.heap.young_generation().heap_size() + self.heap.old_generation().heap_size()
+    }
+
+    /// Returns the allocated and used sizes of both generations of the heap
+    pub fn garbage_collection_info(&self) -> GarbageCollectionInfo {
+        let young = self.heap.young_generation();
+        let old = self.heap.old_generation();
+
+        GarbageCollectionInfo {
+            heap_block_size: young.heap_size(),
+            heap_size: young.heap_used(),
+            old_heap_block_size: old.heap_size(),
+            old_heap_size: old.heap_used(),
+            stack_size: young.stack_used(),
+            mbuf_size: 0,
+        }
+    }
+
     #[cfg(test)]
     pub(super) fn heap(&self) -> &SemispaceProcessHeap {
         &self.heap
@@ -117,15 +155,13 @@ impl ProcessHeap {
             };
 
         // Verify that our projected heap size is not going to blow the max heap size, if set
-        // NOTE: When this happens, we will be left with no choice but to kill the process
-        if process.max_heap_size > 0 && process.max_heap_size < new_heap_size {
-            return Err(GcError::MaxHeapSizeExceeded);
-        }
+        // NOTE: When this happens and `kill` is set, the caller must kill the process
+        check_max_heap_size(process, new_heap_size)?;
 
-        // Unset heap_grow and need_fullsweep flags, because we are doing both
+        // Unset heap_grow, need_fullsweep and force_gc flags, because we are doing all of them
         process
             .flags
-            .clear(ProcessFlags::GrowHeap | ProcessFlags::NeedFullSweep);
+            .clear(ProcessFlags::GrowHeap | ProcessFlags::NeedFullSweep | ProcessFlags::ForceGC);
 
         // Allocate target heap (new young generation)
         let ptr = alloc::heap(new_heap_size).map_err(|alloc| GcError::Alloc(alloc))?;
@@ -248,7 +284,7 @@ impl ProcessHeap {
         // the max heap size, if one was configured.
         //
         // If a max heap size is set, make sure we're not going to exceed it
-        if process.max_heap_size > 0 {
+        if process.max_heap_size.size > 0 {
             // First, check if we have exceeded the max heap size
             let mut heap_size = size_before;
             // In this estimate, our stack size includes unused area between stack and heap
@@ -268,9 +304,10 @@ impl ProcessHeap {
             let baseline_size = stack_size + size_before + needed;
             heap_size += alloc::next_heap_size(baseline_size);
 
-            // When this error type is returned, a full sweep will be triggered
-            if heap_size > process.max_heap_size {
-                return Err(GcError::MaxHeapSizeExceeded);
+            // A full sweep may free enough to stay under the max heap size, and it decides what
+            // happens when it can't
+            if process.max_heap_size.is_exceeded_by(heap_size) {
+                return self.collect_full(process, needed, roots);
             }
         }
 
@@ -447,6 +484,11 @@ impl Heap for ProcessHeap {
     fn is_owner<T: ?Sized>(&self, ptr: *const T) -> bool {
         self.heap.contains(ptr)
     }
+
+    #[inline]
+    fn virtual_alloc_procbin(&mut self, bin: Boxed<ProcBin>) {
+        self.virtual_alloc(bin)
+    }
 }
 impl VirtualHeap<ProcBin> for ProcessHeap {
     #[inline]
@@ -547,3 +589,29 @@ impl StackPrimitives for ProcessHeap {
         self.heap.stack_popn(n);
     }
 }
+
+/// Returns `Err(GcError::MaxHeapSizeExceeded)` if growing the heap to `heap_size` words exceeds
+/// the max heap size and the process should be killed.  When the process shouldn't be killed, the
+/// heap is allowed to grow.
+fn check_max_heap_size(process: &Process, heap_size: usize) -> Result<(), GcError> {
+    let max_heap_size = process.max_heap_size;
+
+    if max_heap_size.is_exceeded_by(heap_size) {
+        if max_heap_size.error_logger {
+            error!(
+                "Process ({}) maximum heap size reached (max heap size: {} words, total heap size: \
+                 {} words, kill: {})",
+                process.pid(),
+                max_heap_size.size,
+                heap_size,
+                max_heap_size.kill
+            );
+        }
+
+        if max_heap_size.kill {
+            return Err(GcError::MaxHeapSizeExceeded);
+        }
+    }
+
+    Ok(())
+}
@@ -1,12 +1,16 @@
 use core::default::Default;
+use core::ptr;
 
 use alloc::collections::vec_deque::Iter;
 use alloc::collections::VecDeque;
 
+use intrusive_collections::UnsafeRef;
+
 use crate::borrow::CloneToProcess;
 use crate::erts::exception::AllocResult;
 use crate::erts::message::{self, Message};
 use crate::erts::process::ffi::{set_process_signal, ProcessSignal};
+use crate::erts::process::gc::RootSet;
 use crate::erts::process::Process;
 use crate::erts::term::prelude::Term;
 
@@ -14,47 +18,77 @@ use crate::erts::term::prelude::Term;
 pub struct Mailbox {
     messages: VecDeque<Message>,
     seen: isize,
-
-    cursor: usize,
 }
 
 impl Mailbox {
     // Start receive implementation for the eir interpreter / minimal runtime
+    //
+    // The position of the receive is kept by the caller, so that a receive that is abandoned
+    // (such as when a clause body raises) doesn't leave the mailbox mid-scan for the next receive.
 
-    pub fn recv_start(&self) {
-        debug_assert!(self.cursor == 0);
-    }
     /// Important to remember that this might return a term in a heap
     /// fragment, and that it needs to be copied over to the process
     /// heap before the message is removed from the mailbox.
-    pub fn recv_peek(&self) -> Option<Term> {
-        match self.messages.get(self.cursor) {
+    pub fn recv_peek(&self, cursor: usize) -> Option<Term> {
+        match self.messages.get(cursor) {
             None => None,
             Some(Message::Process(message::Process { data })) => Some(*data),
             Some(Message::HeapFragment(message::HeapFragment { data, .. })) => Some(*data),
         }
     }
 
-    pub fn recv_last_off_heap(&self) -> bool {
-        match &self.messages[self.cursor - 1] {
+    pub fn recv_off_heap(&self, cursor: usize) -> bool {
+        match &self.messages[cursor] {
             Message::Process(_) => false,
             Message::HeapFragment(_) => true,
         }
     }
-    pub fn recv_increment(&mut self) {
-        self.cursor += 1;
+
+    /// Copies the message at `cursor` out of its heap fragment into the `process` heap and frees
+    /// the fragment, so that the terms bound from the message don't outlive their storage.
+    ///
+    /// Returns `None` if the heap is locked or is too small, in which case the message stays in
+    /// its fragment, which remains attached to `process` until the next garbage collection.
+    pub fn recv_move_to_heap(&mut self, cursor: usize, process: &Process) -> Option<Term> {
+        let message = &mut self.messages[cursor];
+
+        let heap_data = match message {
+            Message::Process(message::Process { data }) => return Some(*data),
+            Message::HeapFragment(message::HeapFragment {
+                unsafe_ref_heap_fragment,
+                data,
+            }) => {
+                let heap_data = data.clone_to_heap(&mut process.try_acquire_heap()?).ok()?;
+
+                let mut off_heap = process.off_heap.lock();
+                let off_heap_unsafe_ref_heap_fragment = unsafe {
+                    let mut cursor =
+                        off_heap.cursor_mut_from_ptr(unsafe_ref_heap_fragment.as_ref());
+                    cursor
+                        .remove()
+                        .expect("HeapFragment was not in process's off_heap")
+                };
+                drop(off_heap);
+
+                unsafe {
+                    ptr::drop_in_place(UnsafeRef::into_raw(off_heap_unsafe_ref_heap_fragment))
+                };
+
+                heap_data
+            }
+        };
+
+        *message = Message::Process(message::Process { data: heap_data });
+
+        Some(heap_data)
     }
-    pub fn recv_received(&mut self) {
-        let message = self.messages.remove(self.cursor - 1).unwrap();
+
+    pub fn recv_received(&mut self, cursor: usize) {
+        let message = self.messages.remove(cursor).unwrap();
 
         if let Message::HeapFragment(_) = message {
             set_process_signal(ProcessSignal::GarbageCollect);
         }
-
-        self.cursor = 0;
-    }
-    pub fn recv_timeout(&mut self) {
-        self.cursor = 0;
     }
 
     // End receive implementation for the eir interpreter / minimal interpreter
@@ -73,6 +107,16 @@ impl Mailbox {
         }
     }
 
+    /// Inserts the messages that are stored on the process heap into the given root set, so that
+    /// garbage collection keeps them and updates them when they move.
+    pub fn root_set(&mut self, rootset: &mut RootSet) {
+        for message in self.messages.iter_mut() {
+            if let Message::Process(message::Process { data }) = message {
+                rootset.push(data as *mut Term);
+            }
+        }
+    }
+
     pub fn iter(&self) -> Iter<Message> {
         self.messages.iter()
     }
@@ -184,7 +228,6 @@ impl Default for Mailbox {
         Mailbox {
             messages: Default::default(),
             seen: -1,
-            cursor: 0,
         }
     }
 }
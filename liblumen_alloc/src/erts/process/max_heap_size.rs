@@ -0,0 +1,33 @@
+/// The `max_heap_size` process flag
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaxHeapSize {
+    /// Maximum size of the heap, in words, or `0` if the heap size is unbounded
+    pub size: usize,
+    /// Whether the process is killed when its heap would grow past `size`.  Otherwise, the heap
+    /// is allowed to grow.
+    pub kill: bool,
+    /// Whether an error is logged when the heap would grow past `size`
+    pub error_logger: bool,
+}
+
+impl MaxHeapSize {
+    /// The keys of the `max_heap_size` map of `process_flag/2`, `process_info/2`, and `spawn_opt`
+    pub const SIZE_KEY: &'static str = "size";
+    pub const KILL_KEY: &'static str = "kill";
+    pub const ERROR_LOGGER_KEY: &'static str = "error_logger";
+
+    /// Returns `true` if a heap of `heap_size` words is larger than allowed
+    pub fn is_exceeded_by(&self, heap_size: usize) -> bool {
+        0 < self.size && self.size < heap_size
+    }
+}
+
+impl Default for MaxHeapSize {
+    fn default() -> Self {
+        Self {
+            size: 0,
+            kill: true,
+            error_logger: true,
+        }
+    }
+}
@@ -49,6 +49,52 @@ mod traps_exit {
     }
 }
 
+mod consume_reductions {
+    use super::*;
+
+    use crate::erts::process::{Status, Yield, MAX_REDUCTIONS_PER_RUN};
+
+    #[test]
+    fn running_process_with_reductions_left_continues() {
+        let process = process();
+        *process.status.write() = Status::Running;
+
+        assert_eq!(
+            process.consume_reductions(MAX_REDUCTIONS_PER_RUN as usize - 1),
+            Yield::Continue
+        );
+        assert!(!process.is_reduced());
+    }
+
+    #[test]
+    fn running_process_without_reductions_left_yields() {
+        let process = process();
+        *process.status.write() = Status::Running;
+
+        assert_eq!(
+            process.consume_reductions(MAX_REDUCTIONS_PER_RUN as usize - 1),
+            Yield::Continue
+        );
+        assert_eq!(process.consume_reductions(1), Yield::Now);
+        assert!(process.is_reduced());
+    }
+
+    #[test]
+    fn process_that_is_not_running_never_yields() {
+        let process = process();
+
+        assert_eq!(
+            process.consume_reductions(usize::max_value()),
+            Yield::Continue
+        );
+        assert_eq!(
+            process.consume_reductions(usize::max_value()),
+            Yield::Continue
+        );
+        assert!(process.is_reduced());
+    }
+}
+
 mod integer {
     use super::*;
 
@@ -66,6 +112,152 @@ mod integer {
     }
 }
 
+mod discard_callers {
+    use super::*;
+
+    use crate::erts::process::{Frame, Native};
+    use crate::erts::term::prelude::*;
+
+    #[test]
+    fn keeps_current_and_bottom_frames_and_current_arguments() {
+        let process = process();
+        let bottom = Frame::new(module_function_arity("bottom", 0), Native::Zero(native_0));
+        let caller = Frame::new(module_function_arity("caller", 1), Native::One(native_1));
+        let current = Frame::new(module_function_arity("current", 1), Native::One(native_1));
+
+        let mut frames = process.frames.lock();
+        frames.push(bottom);
+        frames.push(caller);
+        frames.push(current);
+        drop(frames);
+
+        let caller_argument = process.integer(1);
+        process.stack_push(caller_argument).unwrap();
+        let current_argument = process.integer(2);
+        process.stack_push(current_argument).unwrap();
+
+        process.discard_callers();
+
+        let function_names: Vec<&str> = process
+            .frames
+            .lock()
+            .iter()
+            .map(|frame| frame.module_function_arity().function.name())
+            .collect();
+        assert_eq!(function_names, vec!["current", "bottom"]);
+
+        assert_eq!(process.stack_top(), Some(current_argument));
+        assert_eq!(process.stack_peek(2), None);
+    }
+
+    fn module_function_arity(function: &str, arity: Arity) -> ModuleFunctionArity {
+        ModuleFunctionArity {
+            module: atom_from_str!("discard_callers"),
+            function: Atom::try_from_str(function).unwrap(),
+            arity,
+        }
+    }
+
+    extern "C" fn native_0() -> Term {
+        Term::NONE
+    }
+
+    extern "C" fn native_1(_: Term) -> Term {
+        Term::NONE
+    }
+}
+
+mod force_garbage_collection {
+    use super::*;
+
+    use crate::erts::process::gc::RootSet;
+    use crate::erts::process::ProcessFlags;
+    use crate::erts::term::prelude::*;
+
+    #[test]
+    fn next_garbage_collection_is_full_sweep_and_keeps_messages_on_heap() {
+        let process = process();
+        let elements = [
+            process.integer(1),
+            atom_from_str!("message").encode().unwrap(),
+        ];
+        let message = process.tuple_from_slice(&elements);
+        process.send_from_self(message);
+
+        process.force_garbage_collection();
+
+        assert!(process.should_collect());
+
+        process.garbage_collect(0, RootSet::empty()).unwrap();
+
+        assert!(!process.are_flags_set(ProcessFlags::ForceGC));
+        assert!(!process.are_flags_set(ProcessFlags::NeedFullSweep));
+
+        let mailbox_guard = process.mailbox.lock();
+        let mailbox = mailbox_guard.borrow();
+        let collected_message = mailbox.recv_peek(0).unwrap();
+
+        assert_eq!(collected_message, process.tuple_from_slice(&elements));
+    }
+}
+
+mod recv_move_to_heap {
+    use super::*;
+
+    use crate::erts::term::prelude::*;
+
+    #[test]
+    fn with_heap_fragment_message_moves_message_to_heap_and_frees_heap_fragment() {
+        let process = process();
+        let sender = super::process();
+        let elements = [
+            sender.integer(1),
+            atom_from_str!("message").encode().unwrap(),
+        ];
+        let data = sender.tuple_from_slice(&elements);
+
+        {
+            // Holding the heap lock forces the message into a heap fragment
+            let _heap = process.acquire_heap();
+            process.send_from_other(data);
+        }
+
+        assert_eq!(process.off_heap.lock().iter().count(), 1);
+
+        let mailbox_guard = process.mailbox.lock();
+        let mut mailbox = mailbox_guard.borrow_mut();
+
+        assert!(mailbox.recv_off_heap(0));
+
+        let moved = mailbox.recv_move_to_heap(0, &process).unwrap();
+
+        assert_eq!(moved, data);
+        assert!(!mailbox.recv_off_heap(0));
+        assert_eq!(mailbox.recv_peek(0), Some(moved));
+        assert_eq!(process.off_heap.lock().iter().count(), 0);
+    }
+
+    #[test]
+    fn with_heap_locked_leaves_message_in_heap_fragment() {
+        let process = process();
+        let data = atom_from_str!("message").encode().unwrap();
+        let boxed_data = process.tuple_from_slice(&[data]);
+
+        {
+            let _heap = process.acquire_heap();
+            process.send_from_other(boxed_data);
+        }
+
+        let mailbox_guard = process.mailbox.lock();
+        let mut mailbox = mailbox_guard.borrow_mut();
+        let _heap = process.acquire_heap();
+
+        assert_eq!(mailbox.recv_move_to_heap(0, &process), None);
+        assert!(mailbox.recv_off_heap(0));
+        assert_eq!(process.off_heap.lock().iter().count(), 1);
+    }
+}
+
 pub(super) fn process() -> Process {
     let init = atom_from_str!("init");
     let initial_module_function_arity = ModuleFunctionArity {
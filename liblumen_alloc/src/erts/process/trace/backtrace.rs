@@ -1,3 +1,4 @@
+use std::cmp;
 use std::fmt;
 use std::iter::FusedIterator;
 use std::mem;
@@ -24,12 +25,17 @@ pub struct Trace {
     top: ThreadLocalCell<Option<Term>>,
 }
 impl Trace {
+    /// The maximum number of Erlang frames in the stacktrace term
     const MAX_FRAMES: usize = 10;
+    /// The maximum number of native frames captured.  This is larger than `MAX_FRAMES` because
+    /// the runtime's own frames, such as for raising the exception, are on the stack too, but
+    /// are not included in the stacktrace term.
+    const MAX_NATIVE_FRAMES: usize = 64;
 
     #[inline]
     fn new() -> Arc<Self> {
         Arc::new(Self {
-            frames: ThreadLocalCell::new(Vec::with_capacity(Self::MAX_FRAMES)),
+            frames: ThreadLocalCell::new(Vec::with_capacity(Self::MAX_NATIVE_FRAMES)),
             fragment: ThreadLocalCell::new(None),
             term: ThreadLocalCell::new(None),
             top: ThreadLocalCell::new(None),
@@ -57,7 +63,7 @@ impl Trace {
             trace.push_frame(frame);
             //}
 
-            depth < Self::MAX_FRAMES
+            depth < Self::MAX_NATIVE_FRAMES
         });
 
         trace_arc
@@ -88,7 +94,13 @@ impl Trace {
     }
 
     #[inline]
-    pub fn print(&self, process: &Process, kind: Term, reason: Term, source: Option<ArcError>) -> std::io::Result<()> {
+    pub fn print(
+        &self,
+        process: &Process,
+        kind: Term,
+        reason: Term,
+        source: Option<ArcError>,
+    ) -> std::io::Result<()> {
         format::print(self, process, kind, reason, source)
     }
 
@@ -266,7 +278,9 @@ impl Trace {
         if let Some(fragment) = self.fragment.as_ref() {
             Ok(Some(fragment.clone()))
         } else {
-            if let Some(layout) = utils::calculate_fragment_layout(self.frames.len(), extra) {
+            let num_frames = cmp::min(self.frames.len(), Self::MAX_FRAMES);
+
+            if let Some(layout) = utils::calculate_fragment_layout(num_frames, extra) {
                 let heap_ptr = HeapFragment::new(layout)?;
                 unsafe {
                     self.fragment.set(Some(heap_ptr.clone()));
@@ -294,19 +308,24 @@ impl Trace {
         let heap = unsafe { heap_ptr.as_mut() };
 
         // If top was set, we have an extra frame to append
-        let mut erlang_frames = if self.top.is_some() {
-            Vec::with_capacity(1 + self.frames.len())
+        let max_erlang_frames = if self.top.is_some() {
+            1 + Self::MAX_FRAMES
         } else {
-            Vec::with_capacity(self.frames.len())
+            Self::MAX_FRAMES
         };
+        let mut erlang_frames = Vec::with_capacity(max_erlang_frames);
 
         // If top was set, add it as the most recent frame on the stack
         if let Some(top) = self.top.as_ref() {
             erlang_frames.push(*top);
         }
 
-        // Add all of the "real" stack frames
+        // Add the "real" stack frames, skipping the native frames that aren't Erlang functions
         for frame in &self.frames[..] {
+            if erlang_frames.len() == max_erlang_frames {
+                break;
+            }
+
             if let Some(symbol) = frame.symbolicate() {
                 if let Some(ref mfa) = symbol.module_function_arity() {
                     let erlang_frame =
@@ -228,23 +228,28 @@ pub fn format_locations<A>(
 where
     A: TermAlloc,
 {
-    // Each location is a pair of: {file, "<path>"}, {line, <line>}
-    let file_atom = Atom::str_to_term("file");
-    let line_atom = Atom::str_to_term("line");
-    let file = if let Some(f) = filename {
+    // Each location is a pair of: {file, "<path>"}, {line, <line>}, but like `erl_error`, only
+    // the locations that are known are included
+    let mut locations = Vec::with_capacity(2);
+
+    if let Some(f) = filename {
+        let file_atom = Atom::str_to_term("file");
         let filename_list = match f.to_string_lossy() {
             Cow::Borrowed(s) => to_trimmed_charlist(heap, s),
             Cow::Owned(ref s) => to_trimmed_charlist(heap, s),
-        };
-        heap.tuple_from_slice(&[file_atom, filename_list.unwrap_or(Term::NIL)])?
-    } else {
-        heap.tuple_from_slice(&[file_atom, Term::NIL])?
-    };
-    let file = file.into();
-    let line_int: SmallInteger = line.unwrap_or_default().try_into().unwrap();
-    let line = heap.tuple_from_slice(&[line_atom, line_int.into()])?.into();
+        }?;
+        let file = heap.tuple_from_slice(&[file_atom, filename_list])?;
+        locations.push(file.into());
+    }
 
-    Ok(heap.list_from_slice(&[file, line])?.into())
+    if let Some(l) = line {
+        let line_atom = Atom::str_to_term("line");
+        let line_int: SmallInteger = l.try_into().unwrap();
+        let line = heap.tuple_from_slice(&[line_atom, line_int.into()])?;
+        locations.push(line.into());
+    }
+
+    Ok(heap.list_from_slice(&locations)?.into())
 }
 
 pub fn to_trimmed_charlist<A, S>(heap: &mut A, filename: S) -> AllocResult<Term>
@@ -255,7 +260,12 @@ where
     let f = filename.as_ref();
     let len = f.len();
     if len > MAX_FILENAME_LEN {
-        let begin = len - MAX_FILENAME_LEN;
+        // Keep the end of the path, which is more useful than the start, without splitting a
+        // multi-byte character
+        let mut begin = len - MAX_FILENAME_LEN;
+        while !f.is_char_boundary(begin) {
+            begin += 1;
+        }
         let trimmed = &f[begin..];
         heap.charlist_from_str(trimmed).map(|t| t.into())
     } else {
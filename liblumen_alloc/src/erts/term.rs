@@ -6,8 +6,10 @@ pub mod closure;
 pub mod convert;
 mod encoding;
 mod float;
+pub mod hash;
 pub mod index;
 mod integer;
+mod iodata;
 pub mod list;
 mod map;
 pub(super) mod pid;
@@ -15,6 +17,7 @@ mod port;
 pub(super) mod reference;
 mod release;
 mod resource;
+pub mod term_ordering;
 mod tuple;
 mod typed_term;
 
@@ -41,6 +44,7 @@ pub mod prelude {
     pub use super::closure::Closure;
     pub use super::float::Float;
     pub use super::integer::{BigInteger, Integer, SmallInteger};
+    pub use super::iodata::{IodataChunk, IodataError, IodataIter};
     pub use super::list::{
         Cons, HeaplessListBuilder, ImproperList, ImproperListError, List, ListBuilder,
         MaybeImproper,
@@ -6,6 +6,7 @@ use core::mem;
 use core::ptr;
 use core::slice;
 use core::str::{self, Utf8Error};
+use core::sync::atomic::{AtomicUsize, Ordering};
 
 use std::os::raw::c_uint;
 
@@ -31,6 +32,38 @@ lazy_static! {
     static ref ATOMS: RwLock<AtomTable> = Default::default();
 }
 
+/// The maximum number of atoms the atom table may hold, which is `MAX_ATOMS` unless lowered with
+/// `set_limit`
+static LIMIT: AtomicUsize = AtomicUsize::new(MAX_ATOMS);
+
+/// Returns the number of atoms in the atom table
+pub fn count() -> usize {
+    ATOMS.read().names.len()
+}
+
+/// Returns the maximum number of atoms the atom table may hold
+pub fn limit() -> usize {
+    LIMIT.load(Ordering::Relaxed)
+}
+
+/// Sets the maximum number of atoms the atom table may hold, like `+t` for `erl`.  Creating an atom
+/// beyond the limit fails with `AtomError::TooManyAtoms`, so that atoms created from untrusted
+/// input can't exhaust memory, as atoms are never freed.
+///
+/// Returns `Err` if `limit` exceeds `MAX_ATOMS` or is less than the number of existing atoms
+pub fn set_limit(limit: usize) -> Result<(), AtomError> {
+    // Hold the write lock so that no atoms can be created between the check and the store
+    let table = ATOMS.write();
+
+    if limit < table.next_id || MAX_ATOMS < limit {
+        return Err(AtomError::InvalidLimit(limit, table.next_id));
+    }
+
+    LIMIT.store(limit, Ordering::Relaxed);
+
+    Ok(())
+}
+
 /// Performs one-time initialization of the atom table at program start, using the
 /// array of constant atom values present in the compiled program.
 ///
@@ -318,8 +351,15 @@ impl TryFrom<TypedTerm> for Atom {
 /// Produced by operations which create atoms
 #[derive(Error, Debug)]
 pub enum AtomError {
-    #[error("exceeded system limit: maximum number of atoms ({})", MAX_ATOMS)]
-    TooManyAtoms,
+    #[error("exceeded system limit: maximum number of atoms ({})", .0)]
+    TooManyAtoms(usize),
+    #[error(
+        "invalid atom limit ({}), must be at least the number of existing atoms ({}) and at most {}",
+        .0,
+        .1,
+        MAX_ATOMS
+    )]
+    InvalidLimit(usize, usize),
     #[error("invalid atom, length is {}, maximum length is {}", .0, MAX_ATOM_LENGTH)]
     InvalidLength(usize),
     #[error("tried to convert to an atom that doesn't exist")]
@@ -423,10 +463,11 @@ impl AtomTable {
     // `mut reference`.
     unsafe fn insert(&mut self, name: &str) -> Result<usize, AtomError> {
         let id = self.next_id;
-        self.next_id += 1;
-        if id > MAX_ATOMS {
-            return Err(AtomError::TooManyAtoms);
+        let limit = limit();
+        if id >= limit {
+            return Err(AtomError::TooManyAtoms(limit));
         }
+        self.next_id += 1;
 
         let size = name.len();
 
@@ -467,3 +508,38 @@ unsafe impl Sync for AtomTable {}
 #[derive(Debug, Error)]
 #[error("atom ({0}) is not supported")]
 pub struct TryAtomFromTermError(pub &'static str);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_limit_above_max_atoms_errors() {
+        match set_limit(MAX_ATOMS + 1) {
+            Err(AtomError::InvalidLimit(limit, _)) => assert_eq!(limit, MAX_ATOMS + 1),
+            result => panic!("set_limit(MAX_ATOMS + 1) returned {:?}", result),
+        }
+        assert_eq!(limit(), MAX_ATOMS);
+    }
+
+    #[test]
+    fn set_limit_to_max_atoms_succeeds() {
+        assert_eq!(set_limit(MAX_ATOMS), Ok(()));
+        assert_eq!(limit(), MAX_ATOMS);
+    }
+
+    #[test]
+    fn set_limit_below_count_errors() {
+        // Other tests may create atoms concurrently, so the count can only grow after this
+        let next_id = ATOMS.read().next_id;
+
+        match set_limit(next_id - 1) {
+            Err(AtomError::InvalidLimit(limit, error_next_id)) => {
+                assert_eq!(limit, next_id - 1);
+                assert!(next_id <= error_next_id);
+            }
+            result => panic!("set_limit({}) returned {:?}", next_id - 1, result),
+        }
+        assert_eq!(limit(), MAX_ATOMS);
+    }
+}
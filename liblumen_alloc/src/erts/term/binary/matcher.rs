@@ -1,3 +1,15 @@
+use core::char;
+use core::convert::TryInto;
+use core::str;
+
+use alloc::vec::Vec;
+
+use num_bigint::{BigInt, Sign};
+use num_traits::One;
+
+use liblumen_core::sys::Endianness;
+
+use crate::erts::process::Process;
 use crate::erts::term::prelude::*;
 
 #[repr(C)]
@@ -27,18 +39,381 @@ impl BinaryMatchResult {
     }
 }
 
-pub fn match_raw<B>(bin: B, _unit: u8, size: Option<usize>) -> Result<BinaryMatchResult, ()>
-where
-    B: Bitstring + MaybePartialByte,
-{
-    let size = size.unwrap_or(0);
-    let total_bits = bin.total_bit_len();
+/// Matches `size * unit` bits of `bin` as a sub-binary, or all remaining bits when `size` is
+/// `Term::NONE`
+pub fn match_raw(process: &Process, bin: Term, unit: u8, size: Term) -> BinaryMatchResult {
+    try_match_raw(process, bin, unit, size).unwrap_or_else(BinaryMatchResult::failed)
+}
+
+/// Matches `size * unit` bits of `bin` as an integer, defaulting to 8 bits when `size` is
+/// `Term::NONE`
+pub fn match_integer(
+    process: &Process,
+    bin: Term,
+    signed: bool,
+    endianness: Endianness,
+    unit: u8,
+    size: Term,
+) -> BinaryMatchResult {
+    try_match_integer(process, bin, signed, endianness, unit, size)
+        .unwrap_or_else(BinaryMatchResult::failed)
+}
+
+/// Matches `size * unit` bits of `bin` as a 32- or 64-bit float, defaulting to 64 bits when
+/// `size` is `Term::NONE`
+pub fn match_float(
+    process: &Process,
+    bin: Term,
+    endianness: Endianness,
+    unit: u8,
+    size: Term,
+) -> BinaryMatchResult {
+    try_match_float(process, bin, endianness, unit, size).unwrap_or_else(BinaryMatchResult::failed)
+}
+
+/// Matches a UTF-8 encoded code point at the start of `bin`
+pub fn match_utf8(process: &Process, bin: Term, size: Term) -> BinaryMatchResult {
+    try_match_utf8(process, bin, size).unwrap_or_else(BinaryMatchResult::failed)
+}
+
+/// Matches a UTF-16 encoded code point, including surrogate pairs, at the start of `bin`
+pub fn match_utf16(
+    process: &Process,
+    bin: Term,
+    endianness: Endianness,
+    size: Term,
+) -> BinaryMatchResult {
+    try_match_utf16(process, bin, endianness, size).unwrap_or_else(BinaryMatchResult::failed)
+}
+
+/// Matches a UTF-32 encoded code point at the start of `bin`
+pub fn match_utf32(
+    process: &Process,
+    bin: Term,
+    endianness: Endianness,
+    size: Term,
+) -> BinaryMatchResult {
+    try_match_utf32(process, bin, endianness, size).unwrap_or_else(BinaryMatchResult::failed)
+}
+
+fn try_match_raw(process: &Process, bin: Term, unit: u8, size: Term) -> Option<BinaryMatchResult> {
+    let remaining = Remaining::new(bin)?;
+    let bit_len = size_in_bits(size, unit, remaining.bit_len)?;
+
+    // Without a size, the segment is the rest of the binary, which must be a whole number of units,
+    // such as whole bytes for `binary`
+    if size.is_none() && bit_len % (unit as usize) != 0 {
+        return None;
+    }
+
+    let value = remaining.subbinary(process, 0, bit_len)?;
+
+    remaining.success(process, value, bit_len)
+}
+
+fn try_match_integer(
+    process: &Process,
+    bin: Term,
+    signed: bool,
+    endianness: Endianness,
+    unit: u8,
+    size: Term,
+) -> Option<BinaryMatchResult> {
+    let remaining = Remaining::new(bin)?;
+    let bit_len = size_in_bits(size, unit, 8)?;
+
+    if remaining.bit_len < bit_len {
+        return None;
+    }
+
+    let unsigned = match resolve(endianness) {
+        Endianness::Little => {
+            // Little-endian segments are split into whole bytes from the start, least significant
+            // first, with any trailing partial byte holding the most significant bits
+            let full_byte_len = bit_len / 8;
+            let mut bytes: Vec<u8> = (0..full_byte_len)
+                .map(|index| remaining.bits(index * 8, 8) as u8)
+                .collect();
+            let partial_byte_bit_len = bit_len % 8;
+
+            if 0 < partial_byte_bit_len {
+                bytes.push(remaining.bits(full_byte_len * 8, partial_byte_bit_len) as u8);
+            }
+
+            BigInt::from_bytes_le(Sign::Plus, &bytes)
+        }
+        _ => {
+            // Left-pad to whole bytes so the bits can be read as a big-endian magnitude
+            let padding = (8 - (bit_len % 8)) % 8;
+            let bytes: Vec<u8> = (0..((bit_len + padding) / 8))
+                .map(|index| {
+                    (0..8).fold(0_u8, |acc, bit_index| {
+                        let padded_index = index * 8 + bit_index;
+                        let bit = if padded_index < padding {
+                            0
+                        } else {
+                            remaining.bit(padded_index - padding)
+                        };
+
+                        (acc << 1) | bit
+                    })
+                })
+                .collect();
+
+            BigInt::from_bytes_be(Sign::Plus, &bytes)
+        }
+    };
+
+    let value = if signed && 0 < bit_len && (BigInt::one() << (bit_len - 1)) <= unsigned {
+        unsigned - (BigInt::one() << bit_len)
+    } else {
+        unsigned
+    };
+
+    remaining.success(process, process.integer(value), bit_len)
+}
+
+fn try_match_float(
+    process: &Process,
+    bin: Term,
+    endianness: Endianness,
+    unit: u8,
+    size: Term,
+) -> Option<BinaryMatchResult> {
+    let remaining = Remaining::new(bin)?;
+    let bit_len = size_in_bits(size, unit, 64)?;
+
+    let value = match bit_len {
+        32 => f32::from_bits(remaining.uint(0, 4, endianness)? as u32) as f64,
+        64 => f64::from_bits(remaining.uint(0, 8, endianness)?),
+        _ => return None,
+    };
+
+    // NaN and infinities cannot be represented as terms, so they fail to match like in BEAM
+    if !value.is_finite() {
+        return None;
+    }
+
+    remaining.success(process, process.float(value), bit_len)
+}
+
+fn try_match_utf8(process: &Process, bin: Term, size: Term) -> Option<BinaryMatchResult> {
+    if !size.is_none() {
+        return None;
+    }
+
+    let remaining = Remaining::new(bin)?;
+    let leading = remaining.uint(0, 1, Endianness::Big)? as u8;
+    let byte_len = match leading {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        0xF0..=0xF7 => 4,
+        _ => return None,
+    };
+
+    if remaining.bit_len < byte_len * 8 {
+        return None;
+    }
+
+    let bytes: Vec<u8> = (0..byte_len)
+        .map(|index| remaining.bits(index * 8, 8) as u8)
+        .collect();
+    // `from_utf8` rejects overlong encodings, surrogates, and code points past `0x10FFFF`
+    let c = str::from_utf8(&bytes).ok()?.chars().next()?;
+
+    remaining.success(process, process.integer(c), byte_len * 8)
+}
 
-    if size == 0 && total_bits == 0 {
-        // TODO: t = bin_to_term
-        let t = Term::NONE;
-        return Ok(BinaryMatchResult::success(Term::NONE, t));
+fn try_match_utf16(
+    process: &Process,
+    bin: Term,
+    endianness: Endianness,
+    size: Term,
+) -> Option<BinaryMatchResult> {
+    if !size.is_none() {
+        return None;
     }
 
-    unimplemented!()
+    let remaining = Remaining::new(bin)?;
+    let high = remaining.uint(0, 2, endianness)? as u16;
+    let (c, bit_len) = match high {
+        0xD800..=0xDBFF => {
+            let low = remaining.uint(16, 2, endianness)? as u16;
+            let c = char::decode_utf16([high, low].iter().cloned())
+                .next()?
+                .ok()?;
+
+            (c, 32)
+        }
+        _ => (char::from_u32(high as u32)?, 16),
+    };
+
+    remaining.success(process, process.integer(c), bit_len)
+}
+
+fn try_match_utf32(
+    process: &Process,
+    bin: Term,
+    endianness: Endianness,
+    size: Term,
+) -> Option<BinaryMatchResult> {
+    if !size.is_none() {
+        return None;
+    }
+
+    let remaining = Remaining::new(bin)?;
+    let c = char::from_u32(remaining.uint(0, 4, endianness)? as u32)?;
+
+    remaining.success(process, process.integer(c), 32)
+}
+
+/// Converts the `size` operand of a match into a number of bits, using `default` when the
+/// segment has no size
+fn size_in_bits(size: Term, unit: u8, default: usize) -> Option<usize> {
+    if size.is_none() {
+        Some(default)
+    } else {
+        let size: SmallInteger = size.decode().ok()?.try_into().ok()?;
+        let size: usize = size.try_into().ok()?;
+
+        size.checked_mul(unit as usize)
+    }
+}
+
+fn resolve(endianness: Endianness) -> Endianness {
+    match endianness {
+        Endianness::Native if cfg!(target_endian = "little") => Endianness::Little,
+        Endianness::Native => Endianness::Big,
+        endianness => endianness,
+    }
+}
+
+/// The bits of a binary that have not been matched yet, addressed relative to the bytes of the
+/// original binary so that sub-binaries can share it
+struct Remaining {
+    original: Term,
+    base: *const u8,
+    // Offset in bits of the first unmatched bit from `base`
+    bit_offset: usize,
+    // Number of unmatched bits
+    bit_len: usize,
+}
+impl Remaining {
+    fn new(bin: Term) -> Option<Self> {
+        match bin.decode().ok()? {
+            TypedTerm::HeapBinary(bin_ptr) => Self::whole(bin, bin_ptr.full_byte_len()),
+            TypedTerm::ProcBin(bin_ptr) => Self::whole(bin, bin_ptr.full_byte_len()),
+            TypedTerm::BinaryLiteral(bin_ptr) => Self::whole(bin, bin_ptr.full_byte_len()),
+            TypedTerm::SubBinary(bin_ptr) => {
+                let original = bin_ptr.original();
+
+                Some(Self {
+                    original,
+                    base: base(original)?,
+                    bit_offset: bin_ptr.byte_offset() * 8 + (bin_ptr.bit_offset() as usize),
+                    bit_len: bin_ptr.total_bit_len(),
+                })
+            }
+            TypedTerm::MatchContext(match_ctx_ptr) => {
+                let buffer = &match_ctx_ptr.as_ref().buffer;
+                // The offsets of a buffer started on a sub-binary are already relative to the
+                // sub-binary's original
+                let original = match buffer.original.decode().ok()? {
+                    TypedTerm::SubBinary(bin_ptr) => bin_ptr.original(),
+                    _ => buffer.original,
+                };
+
+                Some(Self {
+                    original,
+                    base: base(original)?,
+                    bit_offset: buffer.bit_offset,
+                    bit_len: buffer.bit_len - buffer.bit_offset,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn whole(original: Term, full_byte_len: usize) -> Option<Self> {
+        Some(Self {
+            original,
+            base: base(original)?,
+            bit_offset: 0,
+            bit_len: full_byte_len * 8,
+        })
+    }
+
+    fn bit(&self, index: usize) -> u8 {
+        let absolute_index = self.bit_offset + index;
+        let byte = unsafe { *self.base.add(absolute_index / 8) };
+
+        (byte >> (7 - (absolute_index % 8))) & 1
+    }
+
+    /// Reads `len` (at most 64) bits starting at `index` as a big-endian unsigned integer
+    fn bits(&self, index: usize, len: usize) -> u64 {
+        (index..(index + len)).fold(0, |acc, bit_index| {
+            (acc << 1) | (self.bit(bit_index) as u64)
+        })
+    }
+
+    /// Reads `byte_len` (at most 8) bytes starting at bit `index` as an unsigned integer
+    fn uint(&self, index: usize, byte_len: usize, endianness: Endianness) -> Option<u64> {
+        if self.bit_len < index + byte_len * 8 {
+            return None;
+        }
+
+        let big = self.bits(index, byte_len * 8);
+
+        let uint = match resolve(endianness) {
+            Endianness::Little => {
+                let shift = 64 - byte_len * 8;
+
+                big.swap_bytes() >> shift
+            }
+            _ => big,
+        };
+
+        Some(uint)
+    }
+
+    /// Creates a sub-binary of `bit_len` bits starting at bit `index`
+    fn subbinary(&self, process: &Process, index: usize, bit_len: usize) -> Option<Term> {
+        if self.bit_len < index + bit_len {
+            return None;
+        }
+
+        let offset = self.bit_offset + index;
+
+        Some(process.subbinary_from_original(
+            self.original,
+            offset / 8,
+            (offset % 8) as u8,
+            bit_len / 8,
+            (bit_len % 8) as u8,
+        ))
+    }
+
+    fn success(
+        &self,
+        process: &Process,
+        value: Term,
+        matched_bit_len: usize,
+    ) -> Option<BinaryMatchResult> {
+        let rest = self.subbinary(process, matched_bit_len, self.bit_len - matched_bit_len)?;
+
+        Some(BinaryMatchResult::success(value, rest))
+    }
+}
+
+fn base(original: Term) -> Option<*const u8> {
+    let ptr = match original.decode().ok()? {
+        TypedTerm::HeapBinary(bin_ptr) => unsafe { bin_ptr.as_byte_ptr() },
+        TypedTerm::ProcBin(bin_ptr) => unsafe { bin_ptr.as_byte_ptr() },
+        TypedTerm::BinaryLiteral(bin_ptr) => unsafe { bin_ptr.as_byte_ptr() },
+        _ => return None,
+    };
+
+    Some(ptr)
 }
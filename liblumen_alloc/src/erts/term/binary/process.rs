@@ -12,7 +12,7 @@ use liblumen_core::offset_of;
 
 use crate::borrow::CloneToProcess;
 use crate::erts::exception::AllocResult;
-use crate::erts::process::alloc::TermAlloc;
+use crate::erts::process::alloc::{Heap, TermAlloc};
 use crate::erts::process::Process;
 use crate::erts::string::Encoding;
 use crate::erts::term::prelude::*;
@@ -162,17 +162,14 @@ impl ProcBin {
         unsafe { self.inner.as_ref() }
     }
 
-    // Non-inlined part of `drop`.
+    // Non-inlined part of `drop`, called once the last reference has been released.
     #[inline(never)]
     unsafe fn drop_slow(&self) {
         use liblumen_core::sys::alloc as sys_alloc;
 
-        if self.inner().refc.fetch_sub(1, atomic::Ordering::Release) == 1 {
-            atomic::fence(atomic::Ordering::Acquire);
-            let inner = self.inner.as_ref();
-            let layout = Layout::for_value(&inner);
-            sys_alloc::free(inner as *const _ as *mut u8, layout);
-        }
+        let inner = self.inner.as_ref();
+        let layout = Layout::for_value(inner);
+        sys_alloc::free(inner as *const _ as *mut u8, layout);
     }
 
     #[inline]
@@ -219,14 +216,8 @@ impl Clone for ProcBin {
 impl CloneToProcess for ProcBin {
     fn clone_to_process(&self, process: &Process) -> Term {
         let mut heap = process.acquire_heap();
-        let boxed = self.clone_to_heap(&mut heap).unwrap();
-        let ptr: *mut Self = boxed.dyn_cast();
-        self.inner().refc.fetch_add(1, atomic::Ordering::AcqRel);
-        // Reify a reference to the newly written clone, and push it
-        // on to the process virtual heap
-        let clone = unsafe { &*ptr };
-        process.virtual_alloc(clone);
-        boxed
+
+        self.clone_to_heap(&mut heap).unwrap()
     }
 
     fn clone_to_heap<A>(&self, heap: &mut A) -> AllocResult<Term>
@@ -237,15 +228,12 @@ impl CloneToProcess for ProcBin {
             // Allocate space for the header
             let layout = Layout::new::<Self>();
             let ptr = heap.alloc_layout(layout)?.as_ptr() as *mut Self;
-            // Write the binary header with an empty link
-            ptr::write(
-                ptr,
-                Self {
-                    header: self.header.clone(),
-                    inner: self.inner,
-                    link: LinkedListLink::new(),
-                },
-            );
+            // Write the binary header with an empty link, taking a new reference to the data so
+            // that the clone can be released independently of `self`
+            ptr::write(ptr, self.clone());
+            // Link the clone to the heap's virtual binary heap, if it has one, so that its
+            // reference is released when it is collected
+            heap.virtual_alloc_procbin(Boxed::new_unchecked(ptr));
             // Reify result term
             Ok(ptr.into())
         }
@@ -437,6 +437,17 @@ impl Closure {
         self.native.map(|nn| nn.as_ptr() as usize)
     }
 
+    /// The native address used to compare closures.
+    ///
+    /// External funs are identified by `module:function/arity` alone, so one made before its
+    /// module was loaded equals one made after.
+    fn compared_native_address(&self) -> Option<usize> {
+        match self.definition {
+            Definition::Export { .. } => None,
+            Definition::Anonymous { .. } => self.native_address(),
+        }
+    }
+
     /// Returns the length of the closure environment in terms.
     #[inline]
     pub fn env_len(&self) -> usize {
@@ -560,7 +571,7 @@ impl Hash for Closure {
         self.module.hash(state);
         self.arity.hash(state);
         self.definition.hash(state);
-        self.native_address().hash(state);
+        self.compared_native_address().hash(state);
         self.env_slice().hash(state);
     }
 }
@@ -571,7 +582,10 @@ impl Ord for Closure {
             .cmp(&other.module)
             .then_with(|| self.arity.cmp(&other.arity))
             .then_with(|| self.definition.cmp(&other.definition))
-            .then_with(|| self.native_address().cmp(&other.native_address()))
+            .then_with(|| {
+                self.compared_native_address()
+                    .cmp(&other.compared_native_address())
+            })
             .then_with(|| self.env_slice().cmp(other.env_slice()))
     }
 }
@@ -581,7 +595,7 @@ impl PartialEq for Closure {
         (self.module == other.module)
             && (self.arity == other.arity)
             && (self.definition == other.definition)
-            && (self.native_address() == other.native_address())
+            && (self.compared_native_address() == other.compared_native_address())
             && (self.env_slice() == other.env_slice())
     }
 }
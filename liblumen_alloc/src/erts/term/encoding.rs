@@ -9,7 +9,6 @@ use alloc::sync::Arc;
 
 use std::backtrace::Backtrace;
 
-use hashbrown::HashMap;
 use thiserror::Error;
 
 use liblumen_term::{Encoding as TermEncoding, Tag};
@@ -20,6 +19,7 @@ use crate::erts::fragment::HeapFragment;
 use crate::erts::process::alloc::{Heap, TermAlloc};
 
 use super::arch::{Repr, Word};
+use super::map::Entries;
 use super::prelude::*;
 
 /// Represents the various conditions under which encoding can fail
@@ -244,7 +244,7 @@ impl<T: ?Sized> Debug for Header<T> {
 }
 const_assert_eq!(mem::size_of::<Header<usize>>(), mem::size_of::<usize>());
 impl Header<Map> {
-    pub fn from_map(map: &HashMap<Term, Term>) -> Self {
+    pub(in crate::erts::term) fn from_map(map: &Entries) -> Self {
         let header_layout = Layout::new::<Self>();
         let value_layout = Layout::for_value(map);
         let (layout, _value_offset) = header_layout.extend(value_layout).unwrap();
@@ -18,9 +18,8 @@ use core::fmt::{self, Display};
 use core::hash::{self, Hash};
 use core::ops::*;
 
-use num_bigint::{BigInt, Sign};
-
 use super::prelude::*;
+use super::term_ordering::{big_integer_float_cmp, small_integer_float_cmp};
 
 impl Float {
     pub const INTEGRAL_MIN: f64 = -9007199254740992.0;
@@ -112,63 +111,13 @@ impl PartialOrd<f64> for Float {
 impl PartialOrd<SmallInteger> for Float {
     #[inline]
     fn partial_cmp(&self, other: &SmallInteger) -> Option<Ordering> {
-        use core::num::FpCategory;
-
-        let value = self.value();
-        let is_negative = value.is_sign_negative();
-        match value.classify() {
-            FpCategory::Nan => None,
-            FpCategory::Subnormal => {
-                // The float is less precise, so convert to isize
-                let f = value as isize;
-                Some(f.cmp(&other.0))
-            }
-            FpCategory::Infinite if is_negative => Some(Ordering::Less),
-            FpCategory::Infinite => Some(Ordering::Greater),
-            FpCategory::Zero => Some(0.cmp(&other.0)),
-            FpCategory::Normal => {
-                // Float is higher precision
-                let i = other.0 as f64;
-                value.partial_cmp(&i)
-            }
-        }
+        Some(small_integer_float_cmp(other.0, self.value()).reverse())
     }
 }
 impl PartialOrd<BigInteger> for Float {
     #[inline]
     fn partial_cmp(&self, other: &BigInteger) -> Option<Ordering> {
-        use core::num::FpCategory;
-        use num_traits::Zero;
-
-        let value = self.value();
-        let is_negative = value.is_sign_negative();
-        match value.classify() {
-            FpCategory::Nan => None,
-            FpCategory::Subnormal => {
-                // The float is less precise, so convert to isize
-                let f = BigInt::from(value as isize);
-                Some(f.cmp(&other.value))
-            }
-            FpCategory::Infinite if is_negative => Some(Ordering::Less),
-            FpCategory::Infinite => Some(Ordering::Greater),
-            FpCategory::Zero => {
-                let f: BigInt = Zero::zero();
-                Some(f.cmp(&other.value))
-            }
-            FpCategory::Normal => {
-                use num_traits::ToPrimitive;
-                // Float is higher precision, try and convert to it,
-                // if we fail, then the bigint is larger in either direction,
-                // which we must determine based on its sign
-                if let Some(i) = other.value.to_isize() {
-                    return value.partial_cmp(&(i as f64));
-                }
-                if let Sign::Minus = other.value.sign() {
-                    return Some(Ordering::Greater);
-                }
-                Some(Ordering::Less)
-            }
-        }
+        Some(big_integer_float_cmp(&other.value, self.value()).reverse())
     }
 }
 
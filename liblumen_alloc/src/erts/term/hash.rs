@@ -0,0 +1,365 @@
+//! Hashing of terms by value, shared by the map HAMT, `erlang:phash2/1,2`, and ETS.
+//!
+//! The hash only depends on the value of a term, not where it is stored or how it is
+//! represented, so a binary hashes the same as a `HeapBin`, `ProcBin`, or `SubBinary` and a map
+//! hashes the same whether its entries are a flat vector or a HAMT.  Integers and floats hash
+//! differently even when they are `==`, as map keys and ETS keys are compared exactly.
+//!
+//! Hashes are stable for the life of the node and atoms hash by name, so terms without pids,
+//! ports, references, or funs hash the same on every node.  They are **NOT** the same as the
+//! values of `phash2` on BEAM.
+
+use core::hash::{Hash, Hasher};
+
+use num_traits::ToPrimitive;
+
+use super::prelude::*;
+
+/// FNV-1a, which unlike the default `hashbrown` hasher is the same for every map, so that tries
+/// built separately place equal keys at the same position.
+pub struct TermHasher(u64);
+
+impl TermHasher {
+    /// Writes `term` by value, recursing into its elements.
+    pub fn write_term(&mut self, term: Term) {
+        match term.decode().unwrap() {
+            TypedTerm::SmallInteger(small_integer) => {
+                let small_integer_isize: isize = small_integer.into();
+
+                self.write_integer_i64(small_integer_isize as i64);
+            }
+            TypedTerm::BigInteger(big_integer) => {
+                let big_int = &big_integer.as_ref().value;
+
+                match big_int.to_i64() {
+                    Some(big_int_i64) => self.write_integer_i64(big_int_i64),
+                    None => {
+                        self.write_u8(INTEGER);
+                        self.write(&big_int.to_signed_bytes_le());
+                    }
+                }
+            }
+            TypedTerm::Float(float) => {
+                let value = float.value();
+                // `0.0` and `-0.0` are exactly equal
+                let normalized = if value == 0.0 { 0.0 } else { value };
+
+                self.write_u8(FLOAT);
+                self.write_u64(normalized.to_bits());
+            }
+            TypedTerm::Atom(atom) => self.write_atom(atom),
+            TypedTerm::Reference(reference) => {
+                self.write_u8(REFERENCE);
+                reference.as_ref().hash(self);
+            }
+            TypedTerm::ExternalReference(external_reference) => {
+                self.write_u8(REFERENCE);
+                external_reference.as_ref().hash(self);
+            }
+            TypedTerm::ResourceReference(resource) => {
+                self.write_u8(REFERENCE);
+                resource.as_ref().hash(self);
+            }
+            TypedTerm::Closure(closure) => {
+                self.write_u8(FUNCTION);
+                self.write_atom(closure.module());
+                self.write_u8(closure.arity());
+                closure.definition().hash(self);
+
+                for element in closure.env_slice() {
+                    self.write_term(*element);
+                }
+            }
+            TypedTerm::Port(port) => {
+                self.write_u8(PORT);
+                port.hash(self);
+            }
+            TypedTerm::ExternalPort(external_port) => {
+                self.write_u8(PORT);
+                external_port.as_ref().hash(self);
+            }
+            TypedTerm::Pid(pid) => {
+                self.write_u8(PID);
+                pid.hash(self);
+            }
+            TypedTerm::ExternalPid(external_pid) => {
+                self.write_u8(PID);
+                external_pid.as_ref().hash(self);
+            }
+            TypedTerm::Tuple(tuple) => {
+                self.write_u8(TUPLE);
+                self.write_usize(tuple.len());
+
+                for element in tuple.iter() {
+                    self.write_term(*element);
+                }
+            }
+            TypedTerm::Map(map) => {
+                // Entries are combined with a commutative sum, so that the order they are iterated
+                // in, which depends on the representation, doesn't matter
+                let entries_hash = map.iter().fold(0_u64, |acc, (key, value)| {
+                    let mut entry_hasher = TermHasher::default();
+                    entry_hasher.write_term(*key);
+                    entry_hasher.write_term(*value);
+
+                    acc.wrapping_add(entry_hasher.finish())
+                });
+
+                self.write_u8(MAP);
+                self.write_usize(map.len());
+                self.write_u64(entries_hash);
+            }
+            TypedTerm::Nil => self.write_u8(NIL),
+            TypedTerm::List(cons) => {
+                // Iterate instead of recursing on the tail, so that long lists don't overflow the
+                // stack
+                let mut tail = Term::NIL;
+                let mut option_cons = Some(cons);
+
+                while let Some(cons) = option_cons {
+                    self.write_u8(LIST);
+                    self.write_term(cons.head);
+
+                    match cons.tail.decode().unwrap() {
+                        TypedTerm::List(tail_cons) => option_cons = Some(tail_cons),
+                        _ => {
+                            tail = cons.tail;
+                            option_cons = None;
+                        }
+                    }
+                }
+
+                self.write_term(tail);
+            }
+            TypedTerm::HeapBinary(heap_binary) => self.write_aligned_binary(heap_binary.as_bytes()),
+            TypedTerm::ProcBin(process_binary) => {
+                self.write_aligned_binary(process_binary.as_bytes())
+            }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                self.write_aligned_binary(binary_literal.as_bytes())
+            }
+            TypedTerm::SubBinary(subbinary) => {
+                self.write_u8(BITSTRING);
+                self.write_usize(subbinary.full_byte_len());
+
+                for byte in subbinary.full_byte_iter() {
+                    self.write_u8(byte);
+                }
+
+                self.write_u8(subbinary.partial_byte_bit_len());
+
+                for bit in subbinary.partial_byte_bit_iter() {
+                    self.write_u8(bit);
+                }
+            }
+            TypedTerm::MatchContext(match_context) => {
+                self.write_u8(BITSTRING);
+                self.write_usize(match_context.full_byte_len());
+
+                for byte in match_context.full_byte_iter() {
+                    self.write_u8(byte);
+                }
+
+                self.write_u8(match_context.partial_byte_bit_len());
+
+                for bit in match_context.partial_byte_bit_iter() {
+                    self.write_u8(bit);
+                }
+            }
+        }
+    }
+
+    fn write_atom(&mut self, atom: Atom) {
+        self.write_u8(ATOM);
+        self.write(atom.name().as_bytes());
+        // Terminate the name, so that consecutive atoms can't run together
+        self.write_u8(0xff);
+    }
+
+    fn write_integer_i64(&mut self, integer: i64) {
+        self.write_u8(INTEGER);
+        self.write(&integer.to_le_bytes());
+    }
+
+    fn write_aligned_binary(&mut self, bytes: &[u8]) {
+        self.write_u8(BITSTRING);
+        self.write_usize(bytes.len());
+        self.write(bytes);
+        // No partial byte
+        self.write_u8(0);
+    }
+}
+
+impl Default for TermHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for TermHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    // The default writes native-endian bytes
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    // Lengths are written as `u64`, so that they hash the same on 32-bit targets, like `wasm32`
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// The 64-bit hash of `term`, for hash tables.
+pub fn hash(term: Term) -> u64 {
+    let mut hasher = TermHasher::default();
+    hasher.write_term(term);
+
+    hasher.finish()
+}
+
+/// The 32-bit hash of `term` used by `erlang:phash2/1,2`.
+pub fn phash2(term: Term) -> u32 {
+    let hash = hash(term);
+
+    // Fold, so that the high bits, which FNV-1a mixes best, aren't thrown away
+    ((hash >> 32) ^ hash) as u32
+}
+
+// Private
+
+// Each type writes a different tag first, so that terms of different types with the same
+// bytes don't always collide.
+const INTEGER: u8 = 0;
+const FLOAT: u8 = 1;
+const ATOM: u8 = 2;
+const REFERENCE: u8 = 3;
+const FUNCTION: u8 = 4;
+const PORT: u8 = 5;
+const PID: u8 = 6;
+const TUPLE: u8 = 7;
+const MAP: u8 = 8;
+const NIL: u8 = 9;
+const LIST: u8 = 10;
+const BITSTRING: u8 = 11;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::alloc::Layout;
+
+    use liblumen_core::sys::sysconf::MIN_ALIGN;
+
+    use crate::erts::process::alloc::TermAlloc;
+    use crate::erts::testing::RegionHeap;
+
+    #[test]
+    fn binaries_hash_the_same_regardless_of_representation() {
+        let mut heap = heap();
+        let bytes = [0, 1, 2, 3, 4, 5, 6, 7];
+
+        let heap_binary: Term = heap.heapbin_from_bytes(&bytes).unwrap().into();
+        let process_binary: Term = heap.procbin_from_bytes(&bytes).unwrap().into();
+
+        let original: Term = heap
+            .heapbin_from_bytes(&[0xff, 0, 1, 2, 3, 4, 5, 6, 7])
+            .unwrap()
+            .into();
+        let subbinary: Term = heap
+            .subbinary_from_original(original, 1, 0, bytes.len(), 0)
+            .unwrap()
+            .into();
+
+        assert_eq!(hash(heap_binary), hash(process_binary));
+        assert_eq!(hash(heap_binary), hash(subbinary));
+    }
+
+    #[test]
+    fn bitstrings_hash_differently_from_binaries_with_the_same_bytes() {
+        let mut heap = heap();
+
+        let original: Term = heap.heapbin_from_bytes(&[1, 0b1000_0000]).unwrap().into();
+        let binary: Term = heap
+            .subbinary_from_original(original, 0, 0, 1, 0)
+            .unwrap()
+            .into();
+        let bitstring: Term = heap
+            .subbinary_from_original(original, 0, 0, 1, 1)
+            .unwrap()
+            .into();
+
+        assert_ne!(hash(binary), hash(bitstring));
+    }
+
+    #[test]
+    fn maps_hash_the_same_regardless_of_insertion_order() {
+        let mut heap = heap();
+        let entries: Vec<(Term, Term)> = (0..40)
+            .map(|i| (heap.integer(i).unwrap(), Atom::str_to_term("value")))
+            .collect();
+        let mut reversed_entries = entries.clone();
+        reversed_entries.reverse();
+
+        let map: Term = heap.map_from_slice(&entries).unwrap().into();
+        let reversed_map: Term = heap.map_from_slice(&reversed_entries).unwrap().into();
+
+        assert_eq!(hash(map), hash(reversed_map));
+    }
+
+    #[test]
+    fn integers_and_floats_hash_differently() {
+        let mut heap = heap();
+
+        let integer = heap.integer(1).unwrap();
+        let float: Term = heap.float(1.0).unwrap().into();
+
+        assert_ne!(hash(integer), hash(float));
+    }
+
+    #[test]
+    fn zero_and_negative_zero_hash_the_same() {
+        let mut heap = heap();
+
+        let zero: Term = heap.float(0.0).unwrap().into();
+        let negative_zero: Term = heap.float(-0.0).unwrap().into();
+
+        assert_eq!(hash(zero), hash(negative_zero));
+    }
+
+    #[test]
+    fn improper_lists_hash_differently_from_proper_lists() {
+        let mut heap = heap();
+        let one = heap.integer(1).unwrap();
+        let two = heap.integer(2).unwrap();
+
+        let proper: Term = heap.list_from_slice(&[one, two]).unwrap().unwrap().into();
+        let improper: Term = heap.cons(one, two).unwrap().into();
+
+        assert_ne!(hash(proper), hash(improper));
+    }
+
+    #[test]
+    fn lengths_hash_the_same_as_u64() {
+        let mut usize_hasher = TermHasher::default();
+        usize_hasher.write_usize(3);
+
+        let mut u64_hasher = TermHasher::default();
+        u64_hasher.write(&3_u64.to_le_bytes());
+
+        assert_eq!(usize_hasher.finish(), u64_hasher.finish());
+    }
+
+    fn heap() -> RegionHeap {
+        RegionHeap::new(Layout::from_size_align(64 * 1024, MIN_ALIGN).unwrap())
+    }
+}
@@ -5,6 +5,7 @@ pub use big::*;
 pub use small::*;
 
 use num_bigint::{BigInt, Sign};
+use num_traits::ToPrimitive;
 use thiserror::Error;
 
 use core::cmp::Ordering;
@@ -127,9 +128,13 @@ impl From<BigInt> for Integer {
     }
 }
 impl From<BigInteger> for Integer {
+    /// Demotes `i` to a `SmallInteger` if it fits, so that arithmetic on big integers can return
+    /// small integers
     #[inline]
     fn from(i: BigInteger) -> Self {
-        Self::Big(i)
+        let big_int: BigInt = i.into();
+
+        big_int.into()
     }
 }
 impl From<SmallInteger> for Integer {
@@ -377,22 +382,204 @@ impl Shr<usize> for Integer {
 impl Shl<Integer> for Integer {
     type Output = Integer;
 
+    /// Like `bsl`, a negative shift is a right shift
     fn shl(self, rhs: Integer) -> Self {
-        let rhs: usize = match rhs {
-            Self::Small(r) => r.try_into().expect("overflow caused by large shift"),
-            Self::Big(ref r) => r.try_into().expect("overflow caused by large shift"),
-        };
-        self.map(|n| n.shl(rhs), |n| n.shl(rhs))
+        match shift(rhs) {
+            (Sign::Minus, Some(shift)) => self.shr(shift),
+            (Sign::Minus, None) => self.shr(usize::max_value()),
+            (_, Some(shift)) => self.shl(shift),
+            (_, None) => panic!("overflow caused by large shift"),
+        }
     }
 }
 impl Shr<Integer> for Integer {
     type Output = Integer;
 
+    /// Like `bsr`, a negative shift is a left shift
     fn shr(self, rhs: Integer) -> Self {
-        let rhs: usize = match rhs {
-            Self::Small(r) => r.try_into().expect("overflow caused by large shift"),
-            Self::Big(ref r) => r.try_into().expect("overflow caused by large shift"),
-        };
-        self.map(|n| n.shr(rhs), |n| n.shr(rhs))
+        match shift(rhs) {
+            (Sign::Minus, Some(shift)) => self.shl(shift),
+            (Sign::Minus, None) => panic!("overflow caused by large shift"),
+            (_, Some(shift)) => self.shr(shift),
+            (_, None) => self.shr(usize::max_value()),
+        }
+    }
+}
+
+/// The sign and magnitude of `shift`, if the magnitude fits in a `usize`
+fn shift(shift: Integer) -> (Sign, Option<usize>) {
+    let big_int: BigInt = match shift {
+        Integer::Small(small_integer) => small_integer.into(),
+        Integer::Big(big_integer) => big_integer.into(),
+    };
+
+    (big_int.sign(), big_int.magnitude().to_usize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_promotes_past_max_value_and_sub_demotes() {
+        let max = small(SmallInteger::MAX_VALUE);
+
+        let sum = max.clone() + small(1);
+        assert!(is_big(&sum));
+
+        let difference = sum - small(1);
+        assert!(!is_big(&difference));
+        assert_eq!(difference, max);
+    }
+
+    #[test]
+    fn sub_promotes_past_min_value_and_add_demotes() {
+        let min = small(SmallInteger::MIN_VALUE);
+
+        let difference = min.clone() - small(1);
+        assert!(is_big(&difference));
+
+        let sum = difference + small(1);
+        assert!(!is_big(&sum));
+        assert_eq!(sum, min);
+    }
+
+    #[test]
+    fn mul_promotes_and_div_demotes() {
+        let factor = small(1 << 32);
+
+        let product = factor.clone() * factor.clone();
+        assert!(is_big(&product));
+
+        let quotient = product / factor.clone();
+        assert!(!is_big(&quotient));
+        assert_eq!(quotient, factor);
+    }
+
+    #[test]
+    fn rem_of_big_demotes() {
+        // 2^64 = 2 (mod 7)
+        let dividend = big() + small(6);
+
+        let remainder = dividend % small(7);
+        assert!(!is_big(&remainder));
+        assert_eq!(remainder, small(1));
+    }
+
+    #[test]
+    fn band_of_big_and_small_demotes() {
+        let result = (big() + small(0b1010)) & small(0b0110);
+
+        assert!(!is_big(&result));
+        assert_eq!(result, small(0b0010));
+    }
+
+    #[test]
+    fn band_of_negative_big_sign_extends_small() {
+        let result = (-big()) & small(-1);
+
+        assert!(is_big(&result));
+        assert_eq!(result, -big());
+    }
+
+    #[test]
+    fn bor_of_small_and_big_promotes() {
+        let result = small(1) | big();
+
+        assert!(is_big(&result));
+        assert_eq!(result, big() + small(1));
+    }
+
+    #[test]
+    fn bxor_of_equal_bigs_demotes() {
+        let result = big() ^ big();
+
+        assert!(!is_big(&result));
+        assert_eq!(result, small(0));
+    }
+
+    #[test]
+    fn bxor_of_negative_big_sign_extends_small() {
+        let result = (-big()) ^ small(-1);
+
+        assert_eq!(result, big() - small(1));
+    }
+
+    #[test]
+    fn not_of_small_is_small() {
+        assert_eq!(
+            !small(SmallInteger::MAX_VALUE),
+            small(SmallInteger::MIN_VALUE)
+        );
+        assert_eq!(
+            !small(SmallInteger::MIN_VALUE),
+            small(SmallInteger::MAX_VALUE)
+        );
+        assert_eq!(!small(0), small(-1));
+    }
+
+    #[test]
+    fn shl_promotes_when_bits_are_shifted_out() {
+        let result = small(3) << 62_usize;
+
+        assert!(is_big(&result));
+        assert_eq!(result, (big() * small(3)) >> 2_usize);
+    }
+
+    #[test]
+    fn shl_of_negative_promotes() {
+        let result = small(-1) << 64_usize;
+
+        assert!(is_big(&result));
+        assert_eq!(result, -big());
+    }
+
+    #[test]
+    fn shl_within_range_is_small() {
+        assert_eq!(small(1) << 3_usize, small(8));
+        assert_eq!(small(0) << 128_usize, small(0));
+    }
+
+    #[test]
+    fn shr_of_big_demotes() {
+        let result = big() >> 64_usize;
+
+        assert!(!is_big(&result));
+        assert_eq!(result, small(1));
+    }
+
+    #[test]
+    fn shr_past_all_bits_leaves_sign() {
+        assert_eq!(small(5) >> 128_usize, small(0));
+        assert_eq!(small(-5) >> 128_usize, small(-1));
+    }
+
+    #[test]
+    fn negative_shl_shifts_right() {
+        assert_eq!(small(8) << small(-3), small(1));
+        assert_eq!(big() << small(-64), small(1));
+    }
+
+    #[test]
+    fn negative_shr_shifts_left() {
+        assert_eq!(small(1) >> small(-64), big());
+    }
+
+    /// `1 bsl 64`, which is always big
+    fn big() -> Integer {
+        let big_int: BigInt = BigInt::from(1) << 64;
+
+        big_int.into()
+    }
+
+    fn is_big(integer: &Integer) -> bool {
+        match integer {
+            Integer::Big(_) => true,
+            Integer::Small(_) => false,
+        }
+    }
+
+    fn small(i: isize) -> Integer {
+        Integer::Small(SmallInteger::new(i).unwrap())
     }
 }
@@ -13,6 +13,7 @@ use crate::borrow::CloneToProcess;
 use crate::erts::exception::AllocResult;
 use crate::erts::process::alloc::TermAlloc;
 use crate::erts::term::prelude::*;
+use crate::erts::term::term_ordering::big_integer_float_cmp;
 
 use super::*;
 
@@ -334,93 +335,9 @@ impl PartialOrd<SmallInteger> for BigInteger {
     }
 }
 impl PartialOrd<Float> for BigInteger {
+    #[inline]
     fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
-        use Ordering::*;
-        use Sign::*;
-
-        let self_big_int = &self.value;
-        let other_f64 = other.value();
-
-        let ordering = match self_big_int.sign() {
-            Minus => {
-                if other_f64 < 0.0 {
-                    // fits in small integer so the big other_f64 must be lesser
-                    if (SmallInteger::MIN_VALUE as f64) <= other_f64 {
-                        Less
-                    // big_int can't fit in float, so it must be less than any float
-                    } else if (std::f64::MAX_EXP as usize) < self_big_int.bits() {
-                        Less
-                    // > A float is more precise than an integer until all
-                    // > significant figures of the float are to the left of the
-                    // > decimal point.
-                    } else if Float::INTEGRAL_MIN <= other_f64 {
-                        let self_f64: f64 = self.into();
-
-                        f64_cmp_f64(self_f64, other_f64)
-                    } else {
-                        let other_integral_f64 = other_f64.trunc();
-                        let other_big_int = unsafe { integral_f64_to_big_int(other_integral_f64) };
-
-                        match self_big_int.cmp(&other_big_int) {
-                            Equal => {
-                                let float_fract = other_f64 - other_integral_f64;
-
-                                if float_fract == 0.0 {
-                                    Equal
-                                } else {
-                                    // BigInt Is -N while float is -N.M
-                                    Greater
-                                }
-                            }
-                            ordering => ordering,
-                        }
-                    }
-                } else {
-                    Less
-                }
-            }
-            // BigInt does not have a zero because zero is a SmallInteger
-            NoSign => unreachable!(),
-            Plus => {
-                if 0.0 < other_f64 {
-                    // fits in small integer, so the big integer must be greater
-                    if other_f64 <= (SmallInteger::MAX_VALUE as f64) {
-                        Greater
-                    // big_int can't fit in float, so it must be greater than any float
-                    } else if (std::f64::MAX_EXP as usize) < self_big_int.bits() {
-                        Greater
-                    // > A float is more precise than an integer until all
-                    // > significant figures of the float are to the left of the
-                    // > decimal point.
-                    } else if other_f64 <= Float::INTEGRAL_MAX {
-                        let self_f64: f64 = self.into();
-
-                        f64_cmp_f64(self_f64, other_f64)
-                    } else {
-                        let other_integral_f64 = other_f64.trunc();
-                        let other_big_int = unsafe { integral_f64_to_big_int(other_integral_f64) };
-
-                        match self_big_int.cmp(&other_big_int) {
-                            Equal => {
-                                let other_fract = other_f64 - other_integral_f64;
-
-                                if other_fract == 0.0 {
-                                    Equal
-                                } else {
-                                    // BigInt is N while float is N.M
-                                    Less
-                                }
-                            }
-                            ordering => ordering,
-                        }
-                    }
-                } else {
-                    Greater
-                }
-            }
-        };
-
-        Some(ordering)
+        Some(big_integer_float_cmp(&self.value, other.value()))
     }
 }
 impl PartialOrd<usize> for BigInteger {
@@ -438,7 +355,11 @@ impl PartialOrd<isize> for BigInteger {
 impl PartialOrd<f64> for BigInteger {
     #[inline]
     fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
-        self.value.partial_cmp(&(*other as usize).into())
+        if other.is_nan() {
+            None
+        } else {
+            Some(big_integer_float_cmp(&self.value, *other))
+        }
     }
 }
 impl<T> PartialOrd<Boxed<T>> for BigInteger
@@ -574,15 +495,9 @@ impl BitAnd for BigInteger {
     type Output = BigInteger;
     #[inline]
     fn bitand(self, rhs: BigInteger) -> Self::Output {
-        let lhs = self.value.to_signed_bytes_le();
-        let rhs = rhs.value.to_signed_bytes_le();
-        let bytes = lhs
-            .iter()
-            .zip(rhs.iter())
-            .map(|(l, r)| l.bitand(r))
-            .collect::<Vec<u8>>();
-        let result = BigInt::from_signed_bytes_le(bytes.as_slice());
-        BigInteger::new(result)
+        // Like Erlang, `BigInt` treats negative operands as two's complement, sign-extending the
+        // shorter operand
+        BigInteger::new(self.value.bitand(rhs.value))
     }
 }
 impl BitAnd for &BigInteger {
@@ -639,15 +554,7 @@ impl BitXor for BigInteger {
     type Output = BigInteger;
     #[inline]
     fn bitxor(self, rhs: BigInteger) -> Self::Output {
-        let lhs = self.value.to_signed_bytes_le();
-        let rhs = rhs.value.to_signed_bytes_le();
-        let bytes = lhs
-            .iter()
-            .zip(rhs.iter())
-            .map(|(l, r)| l.bitxor(r))
-            .collect::<Vec<u8>>();
-        let result = BigInt::from_signed_bytes_le(bytes.as_slice());
-        BigInteger::new(result)
+        BigInteger::new(self.value.bitxor(rhs.value))
     }
 }
 impl BitXor for &BigInteger {
@@ -715,30 +622,3 @@ impl Shr<usize> for &BigInteger {
         BigInteger::new(self.value.clone().shr(rhs))
     }
 }
-
-fn f64_cmp_f64(left: f64, right: f64) -> Ordering {
-    match left.partial_cmp(&right) {
-        Some(ordering) => ordering,
-        // Erlang doesn't support the floats that can't be compared
-        None => unreachable!(),
-    }
-}
-
-unsafe fn integral_f64_to_big_int(integral: f64) -> BigInt {
-    let (mantissa, exponent, sign) = num_traits::Float::integer_decode(integral);
-    let mantissa_big_int: BigInt = mantissa.into();
-
-    let scaled = if exponent < 0 {
-        let right_shift = (-exponent) as usize;
-
-        mantissa_big_int >> right_shift
-    } else if exponent == 0 {
-        mantissa_big_int
-    } else {
-        let left_shift = exponent as usize;
-
-        mantissa_big_int << left_shift
-    };
-
-    sign * scaled
-}
@@ -7,9 +7,12 @@ use num_bigint::BigInt;
 
 use crate::erts::term::arch::{MAX_SMALLINT_VALUE, MIN_SMALLINT_VALUE};
 use crate::erts::term::prelude::*;
+use crate::erts::term::term_ordering::small_integer_float_cmp;
 
 use super::*;
 
+const ISIZE_BITS: usize = core::mem::size_of::<isize>() * 8;
+
 /// A small type, slightly less than 64/32-bit wide, as 4 bits are used for tags
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(transparent)]
@@ -271,13 +274,9 @@ impl Not for SmallInteger {
 
     #[inline]
     fn not(self) -> Self::Output {
-        // Take the bitwise complement and mask the high bits (the max
-        // value has the same bit representation as the desired mask)
-        let complement = !self.0 & Self::MAX_VALUE;
-        if complement > Self::MAX_VALUE || complement < Self::MIN_VALUE {
-            return Integer::Big(BigInteger::new(complement.into()));
-        }
-        Integer::Small(unsafe { SmallInteger::new_unchecked(complement) })
+        // The range of small integers is symmetric around -1/2 like `isize`, so the complement of
+        // a small integer is always small
+        Integer::Small(Self(!self.0))
     }
 }
 impl Shl<usize> for SmallInteger {
@@ -285,20 +284,30 @@ impl Shl<usize> for SmallInteger {
 
     #[inline]
     fn shl(self, rhs: usize) -> Self::Output {
-        match rhs.try_into() {
-            Err(_) => {
+        let shifted = if rhs < ISIZE_BITS {
+            let val = self.0 << rhs;
+
+            // Shifting back only gives the original value if no bits were shifted out
+            if (val >> rhs) == self.0 {
+                Some(val)
+            } else {
+                None
+            }
+        } else if self.0 == 0 {
+            Some(0)
+        } else {
+            None
+        };
+
+        match shifted {
+            Some(val) if Self::MIN_VALUE <= val && val <= Self::MAX_VALUE => {
+                Integer::Small(Self(val))
+            }
+            _ => {
                 let lhs: BigInt = self.into();
-                Integer::Big(BigInteger::new(lhs.shl(rhs)))
+
+                Integer::from(lhs.shl(rhs))
             }
-            Ok(shift) => match (self.0).checked_shl(shift) {
-                None => {
-                    let lhs: BigInt = self.into();
-                    Integer::Big(BigInteger::new(lhs.shl(shift as usize)))
-                }
-                Some(val) if val > Self::MAX_VALUE => Integer::Big(BigInteger::new(val.into())),
-                Some(val) if val < Self::MIN_VALUE => Integer::Big(BigInteger::new(val.into())),
-                Some(val) => Integer::Small(Self(val)),
-            },
         }
     }
 }
@@ -307,21 +316,17 @@ impl Shr<usize> for SmallInteger {
 
     #[inline]
     fn shr(self, rhs: usize) -> Self::Output {
-        match rhs.try_into() {
-            Err(_) => {
-                let lhs: BigInt = self.into();
-                Integer::Big(BigInteger::new(lhs.shr(rhs)))
-            }
-            Ok(shift) => match (self.0).checked_shr(shift) {
-                None => {
-                    let lhs: BigInt = self.into();
-                    Integer::Big(BigInteger::new(lhs.shl(shift as usize)))
-                }
-                Some(val) if val > Self::MAX_VALUE => Integer::Big(BigInteger::new(val.into())),
-                Some(val) if val < Self::MIN_VALUE => Integer::Big(BigInteger::new(val.into())),
-                Some(val) => Integer::Small(Self(val)),
-            },
-        }
+        // Shifting right can only make the magnitude smaller, so the result is always small.  Like
+        // `bsr`, shifting all the bits out leaves the sign.
+        let val = if rhs < ISIZE_BITS {
+            self.0 >> rhs
+        } else if self.0 < 0 {
+            -1
+        } else {
+            0
+        };
+
+        Integer::Small(Self(val))
     }
 }
 
@@ -381,7 +386,7 @@ where
 impl PartialOrd<Float> for SmallInteger {
     #[inline]
     fn partial_cmp(&self, other: &Float) -> Option<Ordering> {
-        (self.0 as f64).partial_cmp(&other.value())
+        Some(small_integer_float_cmp(self.0, other.value()))
     }
 }
 impl PartialOrd<BigInteger> for SmallInteger {
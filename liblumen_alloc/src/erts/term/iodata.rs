@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+
+use core::convert::TryInto;
+
+use thiserror::Error;
+
+use crate::erts::term::prelude::*;
+
+/// A byte or binary in iodata
+///
+/// > iodata() = iolist() | binary()
+/// > iolist() = maybe_improper_list(byte() | binary() | iolist(), binary() | [])
+pub enum IodataChunk {
+    Byte(u8),
+    BinaryLiteral(Boxed<BinaryLiteral>),
+    HeapBinary(Boxed<HeapBin>),
+    MatchContext(Boxed<MatchContext>),
+    ProcBin(Boxed<ProcBin>),
+    SubBinary(Boxed<SubBinary>),
+}
+impl IodataChunk {
+    /// The number of bytes in this chunk
+    pub fn len(&self) -> usize {
+        match self {
+            Self::Byte(_) => 1,
+            Self::BinaryLiteral(binary_literal) => binary_literal.full_byte_len(),
+            Self::HeapBinary(heap_binary) => heap_binary.full_byte_len(),
+            Self::MatchContext(match_context) => match_context.full_byte_len(),
+            Self::ProcBin(proc_bin) => proc_bin.full_byte_len(),
+            Self::SubBinary(subbinary) => subbinary.full_byte_len(),
+        }
+    }
+
+    /// Appends the bytes of this chunk to `bytes`
+    pub fn extend_vec(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Self::Byte(byte) => bytes.push(*byte),
+            Self::BinaryLiteral(binary_literal) => {
+                bytes.extend_from_slice(binary_literal.as_bytes())
+            }
+            Self::HeapBinary(heap_binary) => bytes.extend_from_slice(heap_binary.as_bytes()),
+            Self::MatchContext(match_context) => {
+                if match_context.is_aligned() {
+                    bytes.extend_from_slice(unsafe { match_context.as_bytes_unchecked() });
+                } else {
+                    bytes.extend(match_context.full_byte_iter());
+                }
+            }
+            Self::ProcBin(proc_bin) => bytes.extend_from_slice(proc_bin.as_bytes()),
+            Self::SubBinary(subbinary) => {
+                if subbinary.is_aligned() {
+                    bytes.extend_from_slice(unsafe { subbinary.as_bytes_unchecked() });
+                } else {
+                    bytes.extend(subbinary.full_byte_iter());
+                }
+            }
+        }
+    }
+}
+
+/// Why a term is not iodata
+#[derive(Debug, Error, Clone, Copy)]
+pub enum IodataError {
+    /// The element is not a byte, binary or nested iolist
+    #[error("element ({0}) is not a byte, binary, or nested iolist")]
+    Element(Term),
+    /// The element is a bitstring, but its bits do not make up whole bytes
+    #[error("element ({0}) is a bitstring, but not a binary")]
+    NotABinary(Term),
+    /// The tail of an improper list is a byte, which only heads may be
+    #[error("tail ({0}) cannot be a byte")]
+    ByteTail(Term),
+}
+
+/// Iterates over the bytes and binaries in iodata, in order
+///
+/// Nested lists are traversed with a stack on the heap, rather than by recursion, so that
+/// arbitrarily deep nesting, such as the left-nested lists built up by repeatedly consing onto
+/// the head of an accumulator, cannot overflow the Rust stack.
+pub struct IodataIter {
+    stack: Vec<Term>,
+}
+impl IodataIter {
+    pub fn new(iodata: Term) -> Self {
+        Self {
+            stack: vec![iodata],
+        }
+    }
+
+    /// The total number of bytes in the iodata, as returned by `erlang:iolist_size/1`
+    pub fn size(self) -> Result<usize, IodataError> {
+        self.map(|result| result.map(|chunk| chunk.len())).sum()
+    }
+
+    /// The bytes of the iodata, as in the binary returned by `erlang:iolist_to_binary/1`
+    pub fn bytes(self) -> Result<Vec<u8>, IodataError> {
+        let mut bytes = Vec::new();
+
+        for result in self {
+            result?.extend_vec(&mut bytes);
+        }
+
+        Ok(bytes)
+    }
+
+    /// Stops iteration after returning `error`
+    fn error(&mut self, error: IodataError) -> Option<Result<IodataChunk, IodataError>> {
+        self.stack.clear();
+
+        Some(Err(error))
+    }
+}
+impl Iterator for IodataIter {
+    type Item = Result<IodataChunk, IodataError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(top) = self.stack.pop() {
+            let chunk = match top.decode() {
+                Ok(TypedTerm::SmallInteger(small_integer)) => match small_integer.try_into() {
+                    Ok(byte) => IodataChunk::Byte(byte),
+                    Err(_) => return self.error(IodataError::Element(top)),
+                },
+                Ok(TypedTerm::Nil) => continue,
+                Ok(TypedTerm::List(cons)) => {
+                    // `byte()` is allowed for heads, but not for the tail of an improper list
+                    let tail = cons.tail;
+                    let result_u8: Result<u8, _> = tail.try_into();
+
+                    if result_u8.is_ok() {
+                        return self.error(IodataError::ByteTail(tail));
+                    }
+
+                    self.stack.push(tail);
+                    self.stack.push(cons.head);
+
+                    continue;
+                }
+                Ok(TypedTerm::BinaryLiteral(binary_literal)) => {
+                    IodataChunk::BinaryLiteral(binary_literal)
+                }
+                Ok(TypedTerm::HeapBinary(heap_binary)) => IodataChunk::HeapBinary(heap_binary),
+                Ok(TypedTerm::MatchContext(match_context)) => {
+                    if match_context.is_binary() {
+                        IodataChunk::MatchContext(match_context)
+                    } else {
+                        return self.error(IodataError::NotABinary(top));
+                    }
+                }
+                Ok(TypedTerm::ProcBin(proc_bin)) => IodataChunk::ProcBin(proc_bin),
+                Ok(TypedTerm::SubBinary(subbinary)) => {
+                    if subbinary.is_binary() {
+                        IodataChunk::SubBinary(subbinary)
+                    } else {
+                        return self.error(IodataError::NotABinary(top));
+                    }
+                }
+                _ => return self.error(IodataError::Element(top)),
+            };
+
+            return Some(Ok(chunk));
+        }
+
+        None
+    }
+}
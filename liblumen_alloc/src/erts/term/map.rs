@@ -1,3 +1,5 @@
+mod hamt;
+
 use core::alloc::Layout;
 use core::cmp;
 use core::convert::{TryFrom, TryInto};
@@ -5,6 +7,7 @@ use core::fmt::{self, Debug, Display, Write};
 use core::hash::{Hash, Hasher};
 use core::mem;
 use core::ptr;
+use core::slice;
 
 use alloc::vec::Vec;
 
@@ -15,30 +18,35 @@ use crate::erts::exception::{AllocResult, InternalResult};
 use crate::erts::process::alloc::TermAlloc;
 
 use super::prelude::*;
+use super::term_ordering::map_key_cmp;
+
+use self::hamt::Hamt;
+
+/// Maps with more keys than this are stored in a `Hamt` instead of a flat, sorted entry vector,
+/// like `MAP_SMALL_MAP_LIMIT` in BEAM.
+const FLAT_MAX_LEN: usize = 32;
 
 #[derive(Clone)]
 #[repr(C)]
 pub struct Map {
     header: Header<Map>,
-    value: HashMap<Term, Term>,
+    value: Entries,
 }
 
 impl Map {
     pub(in crate::erts) fn from_hash_map(value: HashMap<Term, Term>) -> Self {
-        Self {
-            header: Header::from_map(&value),
-            value,
-        }
+        Self::from_entries(Entries::from_iter(value.len(), value.into_iter()))
     }
 
     pub(in crate::erts) fn from_slice(slice: &[(Term, Term)]) -> Self {
-        let mut value: HashMap<Term, Term> = HashMap::with_capacity(slice.len());
+        Self::from_entries(Entries::from_iter(slice.len(), slice.iter().copied()))
+    }
 
-        for (entry_key, entry_value) in slice {
-            value.insert(*entry_key, *entry_value);
+    fn from_entries(value: Entries) -> Self {
+        Self {
+            header: Header::from_map(&value),
+            value,
         }
-
-        Self::from_hash_map(value)
     }
 
     pub fn from_list(list: Term) -> InternalResult<HashMap<Term, Term>> {
@@ -83,94 +91,241 @@ impl Map {
     }
 
     pub fn get(&self, key: Term) -> Option<Term> {
-        self.value.get(&key).copied()
+        self.value.get(key)
     }
 
-    pub fn take(&self, key: Term) -> Option<(Term, HashMap<Term, Term>)> {
-        if self.is_key(key) {
-            let mut map = self.value.clone();
-            let value = map.remove(&key).unwrap();
+    /// The value of `key` and the map without it.  Like `remove`, the returned map shares the
+    /// unchanged parts of a large map with `self`.
+    pub fn take(&self, key: Term) -> Option<(Term, Map)> {
+        let mut value = self.value.clone();
 
-            Some((value, map))
-        } else {
-            None
-        }
+        value
+            .remove(key)
+            .map(|removed| (removed, Self::from_entries(value)))
     }
 
     pub fn is_key(&self, key: Term) -> bool {
-        self.value.contains_key(&key)
+        self.get(key).is_some()
     }
 
     pub fn keys(&self) -> Vec<Term> {
-        self.value.keys().into_iter().copied().collect()
+        self.iter().map(|(key, _)| *key).collect()
     }
 
     pub fn values(&self) -> Vec<Term> {
-        self.value.values().into_iter().copied().collect()
+        self.iter().map(|(_, value)| *value).collect()
     }
 
     pub fn len(&self) -> usize {
         self.value.len()
     }
 
-    pub fn remove(&self, key: Term) -> Option<HashMap<Term, Term>> {
-        if self.is_key(key) {
-            let mut map = self.value.clone();
-            map.remove(&key);
-            Some(map)
-        } else {
-            None
-        }
+    /// `None` if `key` is not present, so that the caller can return the original map.
+    pub fn remove(&self, key: Term) -> Option<Map> {
+        self.take(key).map(|(_, map)| map)
     }
 
-    pub fn update(&self, key: Term, value: Term) -> Option<HashMap<Term, Term>> {
+    /// `None` if `key` is not present, as only existing keys can be updated.
+    pub fn update(&self, key: Term, value: Term) -> Option<Map> {
         if self.is_key(key) {
-            let mut map = self.value.clone();
-            map.insert(key, value);
-            Some(map)
+            Some(self.insert(key, value))
         } else {
             None
         }
     }
 
-    pub fn put(&self, key: Term, value: Term) -> Option<HashMap<Term, Term>> {
-        if self.get(key).map_or(false, |val| val == value) {
+    /// `None` if `key` already has `value`, so that the caller can return the original map.
+    pub fn put(&self, key: Term, value: Term) -> Option<Map> {
+        if self
+            .get(key)
+            .map_or(false, |val| are_exactly_equal(val, value))
+        {
             None
         } else {
-            let mut map = self.value.clone();
-            map.insert(key, value);
-            Some(map)
+            Some(self.insert(key, value))
         }
     }
 
-    pub fn iter(&self) -> hashbrown::hash_map::Iter<Term, Term> {
-        self.value.iter()
+    /// Iterates entries in key order for maps with up to 32 keys and in hash order otherwise.
+    pub fn iter(&self) -> Iter {
+        match &self.value {
+            Entries::Flat(entry_vec) => Iter::Flat(entry_vec.iter()),
+            Entries::Hamt(hamt) => Iter::Hamt(hamt.iter()),
+        }
     }
 
-    pub fn iter_mut(&mut self) -> hashbrown::hash_map::IterMut<Term, Term> {
-        self.value.iter_mut()
+    pub fn to_hash_map(&self) -> HashMap<Term, Term> {
+        let mut hash_map = HashMap::with_capacity(self.len());
+
+        for (key, value) in self.iter() {
+            hash_map.insert(*key, *value);
+        }
+
+        hash_map
     }
 
     // Private
 
+    /// Large maps are `Hamt`s with copy-on-write nodes, so only the nodes on the path to `key`
+    /// are copied and the returned map shares the rest with `self`.
+    fn insert(&self, key: Term, value: Term) -> Map {
+        let mut entries = self.value.clone();
+        entries.insert(key, value);
+
+        Self::from_entries(entries)
+    }
+
     fn sorted_keys(&self) -> Vec<Term> {
-        let mut key_vec: Vec<Term> = Vec::new();
-        key_vec.extend(self.value.keys());
-        key_vec.sort_unstable_by(|key1, key2| key1.cmp(&key2));
+        let mut key_vec = self.keys();
+        key_vec.sort_unstable_by(|key1, key2| map_key_cmp(*key1, *key2));
 
         key_vec
     }
 }
 
-impl AsRef<HashMap<Term, Term>> for Boxed<Map> {
-    fn as_ref(&self) -> &HashMap<Term, Term> {
-        &self.as_ref().value
+/// Keys and values are compared like `=:=`, so that `1` and `1.0` are different keys.
+fn are_exactly_equal(left: Term, right: Term) -> bool {
+    left.decode().unwrap().exact_eq(&right.decode().unwrap())
+}
+
+pub enum Iter<'a> {
+    Flat(slice::Iter<'a, (Term, Term)>),
+    Hamt(hamt::Iter<'a>),
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a Term, &'a Term);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Iter::Flat(iter) => iter.next().map(|(key, value)| (key, value)),
+            Iter::Hamt(iter) => iter.next(),
+        }
     }
 }
 
-impl AsRef<HashMap<Term, Term>> for Map {
-    fn as_ref(&self) -> &HashMap<Term, Term> {
-        &self.value
+/// The entries of a `Map`, in the representation that suits its size.
+#[derive(Clone, Debug)]
+pub(in crate::erts::term) enum Entries {
+    /// Entries sorted by key, searched linearly
+    Flat(Vec<(Term, Term)>),
+    Hamt(Hamt),
+}
+
+impl Entries {
+    fn from_iter<I>(len: usize, iter: I) -> Self
+    where
+        I: Iterator<Item = (Term, Term)>,
+    {
+        if len <= FLAT_MAX_LEN {
+            let mut entry_vec: Vec<(Term, Term)> = Vec::with_capacity(len);
+
+            for (key, value) in iter {
+                match entry_vec
+                    .iter_mut()
+                    .find(|(entry_key, _)| are_exactly_equal(*entry_key, key))
+                {
+                    Some((_, entry_value)) => *entry_value = value,
+                    None => entry_vec.push((key, value)),
+                }
+            }
+
+            entry_vec.sort_by(|(key1, _), (key2, _)| map_key_cmp(*key1, *key2));
+
+            Entries::Flat(entry_vec)
+        } else {
+            let mut hamt = Hamt::new();
+
+            for (key, value) in iter {
+                hamt.insert(key, value);
+            }
+
+            // Duplicate keys in `iter` can leave few enough entries for the flat representation
+            if hamt.len() <= FLAT_MAX_LEN {
+                let len = hamt.len();
+                let entry_vec: Vec<(Term, Term)> =
+                    hamt.iter().map(|(key, value)| (*key, *value)).collect();
+
+                Self::from_iter(len, entry_vec.into_iter())
+            } else {
+                Entries::Hamt(hamt)
+            }
+        }
+    }
+
+    fn get(&self, key: Term) -> Option<Term> {
+        match self {
+            Entries::Flat(entry_vec) => entry_vec
+                .iter()
+                .find(|(entry_key, _)| are_exactly_equal(*entry_key, key))
+                .map(|(_, entry_value)| *entry_value),
+            Entries::Hamt(hamt) => hamt.get(key),
+        }
+    }
+
+    fn insert(&mut self, key: Term, value: Term) {
+        match self {
+            Entries::Flat(entry_vec) => {
+                match entry_vec
+                    .iter_mut()
+                    .find(|(entry_key, _)| are_exactly_equal(*entry_key, key))
+                {
+                    Some((_, entry_value)) => *entry_value = value,
+                    None => {
+                        let index = entry_vec
+                            .iter()
+                            .position(|(entry_key, _)| {
+                                map_key_cmp(key, *entry_key) == cmp::Ordering::Less
+                            })
+                            .unwrap_or_else(|| entry_vec.len());
+                        entry_vec.insert(index, (key, value));
+                    }
+                }
+
+                if FLAT_MAX_LEN < entry_vec.len() {
+                    let mut hamt = Hamt::new();
+
+                    for (entry_key, entry_value) in entry_vec.iter() {
+                        hamt.insert(*entry_key, *entry_value);
+                    }
+
+                    *self = Entries::Hamt(hamt);
+                }
+            }
+            Entries::Hamt(hamt) => {
+                hamt.insert(key, value);
+            }
+        }
+    }
+
+    fn remove(&mut self, key: Term) -> Option<Term> {
+        match self {
+            Entries::Flat(entry_vec) => entry_vec
+                .iter()
+                .position(|(entry_key, _)| are_exactly_equal(*entry_key, key))
+                .map(|index| entry_vec.remove(index).1),
+            Entries::Hamt(hamt) => {
+                let removed = hamt.remove(key)?;
+
+                // Keep maps with few enough keys in key order, as `from_iter` would
+                if hamt.len() <= FLAT_MAX_LEN {
+                    let len = hamt.len();
+                    let entry_vec: Vec<(Term, Term)> =
+                        hamt.iter().map(|(key, value)| (*key, *value)).collect();
+
+                    *self = Self::from_iter(len, entry_vec.into_iter());
+                }
+
+                Some(removed)
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Entries::Flat(entry_vec) => entry_vec.len(),
+            Entries::Hamt(hamt) => hamt.len(),
+        }
     }
 }
 
@@ -182,15 +337,16 @@ impl crate::borrow::CloneToProcess for Map {
         let layout = Layout::for_value(self);
         let ptr = unsafe { heap.alloc_layout(layout)?.as_ptr() };
 
-        let self_value = &self.value;
-        let mut heap_value = HashMap::with_capacity(self_value.len());
+        let mut heap_entry_vec = Vec::with_capacity(self.len());
 
-        for (entry_key, entry_value) in self_value {
+        for (entry_key, entry_value) in self.iter() {
             let heap_entry_key = entry_key.clone_to_heap(heap)?;
             let heap_entry_value = entry_value.clone_to_heap(heap)?;
-            heap_value.insert(heap_entry_key, heap_entry_value);
+            heap_entry_vec.push((heap_entry_key, heap_entry_value));
         }
 
+        let heap_value = Entries::from_iter(heap_entry_vec.len(), heap_entry_vec.into_iter());
+
         // Clone to ensure `value` remains valid if caller is dropped
         let heap_self = Self {
             header: self.header.clone(),
@@ -244,7 +400,7 @@ impl Eq for Map {}
 impl Hash for Map {
     fn hash<H: Hasher>(&self, state: &mut H) {
         for key in self.sorted_keys() {
-            let value = self.value[&key];
+            let value = self.get(key).unwrap();
 
             key.hash(state);
             value.hash(state);
@@ -254,7 +410,10 @@ impl Hash for Map {
 
 impl PartialEq for Map {
     fn eq(&self, other: &Map) -> bool {
-        self.value.eq(&other.value)
+        (self.len() == other.len())
+            && self
+                .iter()
+                .all(|(key, value)| other.get(*key) == Some(*value))
     }
 }
 impl<T> PartialEq<Boxed<T>> for Map
@@ -292,18 +451,19 @@ impl Ord for Map {
                 let self_key_vec = self.sorted_keys();
                 let other_key_vec = other.sorted_keys();
 
-                match self_key_vec.cmp(&other_key_vec) {
+                let key_ordering = self_key_vec
+                    .iter()
+                    .zip(other_key_vec.iter())
+                    .map(|(self_key, other_key)| map_key_cmp(*self_key, *other_key))
+                    .find(|ordering| *ordering != cmp::Ordering::Equal)
+                    .unwrap_or(cmp::Ordering::Equal);
+
+                match key_ordering {
                     cmp::Ordering::Equal => {
-                        let self_value = &self.value;
-                        let other_value = &other.value;
                         let mut final_ordering = cmp::Ordering::Equal;
 
                         for key in self_key_vec {
-                            match self_value
-                                .get(&key)
-                                .unwrap()
-                                .cmp(other_value.get(&key).unwrap())
-                            {
+                            match self.get(key).unwrap().cmp(&other.get(key).unwrap()) {
                                 cmp::Ordering::Equal => continue,
                                 ordering => {
                                     final_ordering = ordering;
@@ -333,3 +493,181 @@ impl TryFrom<TypedTerm> for Boxed<Map> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::testing::RegionHeap;
+
+    #[test]
+    fn put_on_large_map_only_copies_path_to_key() {
+        let original = large_map();
+        let put = original.put(fixnum!(0), fixnum!(-1)).unwrap();
+
+        assert_eq!(original.get(fixnum!(0)), Some(fixnum!(0)));
+        assert_eq!(put.get(fixnum!(0)), Some(fixnum!(-1)));
+        assert_eq!(put.len(), LARGE_LEN);
+
+        let (nodes, shared) = hamt(&put).root_nodes_shared_with(hamt(&original));
+
+        assert!(0 < shared);
+        assert!(nodes - 1 <= shared);
+    }
+
+    #[test]
+    fn remove_on_large_map_only_copies_path_to_key() {
+        let original = large_map();
+        let removed = original.remove(fixnum!(0)).unwrap();
+
+        assert_eq!(original.get(fixnum!(0)), Some(fixnum!(0)));
+        assert_eq!(removed.get(fixnum!(0)), None);
+        assert_eq!(removed.len(), LARGE_LEN - 1);
+
+        let (nodes, shared) = hamt(&removed).root_nodes_shared_with(hamt(&original));
+
+        assert!(0 < shared);
+        assert!(nodes - 1 <= shared);
+    }
+
+    #[test]
+    fn put_past_flat_max_len_keeps_every_entry() {
+        let mut map = Map::from_slice(&[]);
+
+        for i in 0..(FLAT_MAX_LEN + 1) as isize {
+            map = map.put(fixnum!(i), fixnum!(i)).unwrap();
+
+            assert_eq!(map.len(), (i + 1) as usize);
+        }
+
+        assert!(matches!(map.value, Entries::Hamt(_)));
+
+        for i in 0..(FLAT_MAX_LEN + 1) as isize {
+            assert_eq!(map.get(fixnum!(i)), Some(fixnum!(i)));
+        }
+    }
+
+    #[test]
+    fn put_on_flat_map_keeps_keys_in_order() {
+        let map = Map::from_slice(&[(fixnum!(3), fixnum!(3)), (fixnum!(1), fixnum!(1))])
+            .put(fixnum!(2), fixnum!(2))
+            .unwrap();
+
+        assert_eq!(map.keys(), vec![fixnum!(1), fixnum!(2), fixnum!(3)]);
+    }
+
+    #[test]
+    fn remove_to_flat_max_len_keeps_keys_in_order() {
+        let entry_vec: Vec<(Term, Term)> = (0..(FLAT_MAX_LEN + 1) as isize)
+            .rev()
+            .map(|i| (fixnum!(i), fixnum!(i)))
+            .collect();
+        let map = Map::from_slice(&entry_vec)
+            .remove(fixnum!(FLAT_MAX_LEN as isize))
+            .unwrap();
+        let expected_key_vec: Vec<Term> = (0..FLAT_MAX_LEN as isize).map(|i| fixnum!(i)).collect();
+
+        assert_eq!(map.keys(), expected_key_vec);
+    }
+
+    #[test]
+    fn put_with_same_value_returns_none() {
+        let map = Map::from_slice(&[(atom!("key"), fixnum!(1))]);
+
+        assert!(map.put(atom!("key"), fixnum!(1)).is_none());
+    }
+
+    #[test]
+    fn put_with_float_key_equal_to_integer_key_adds_key() {
+        let mut heap = RegionHeap::default();
+        let float_key = heap.float(1.0).unwrap().encode().unwrap();
+        let map = Map::from_slice(&[(fixnum!(1), atom!("a"))])
+            .put(float_key, atom!("b"))
+            .unwrap();
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(fixnum!(1)), Some(atom!("a")));
+        assert_eq!(map.get(float_key), Some(atom!("b")));
+    }
+
+    #[test]
+    fn put_on_large_map_with_float_key_equal_to_integer_key_adds_key() {
+        let mut heap = RegionHeap::default();
+        let float_key = heap.float(1.0).unwrap().encode().unwrap();
+        let map = large_map().put(float_key, atom!("b")).unwrap();
+
+        assert_eq!(map.len(), LARGE_LEN + 1);
+        assert_eq!(map.get(fixnum!(1)), Some(fixnum!(1)));
+        assert_eq!(map.get(float_key), Some(atom!("b")));
+    }
+
+    #[test]
+    fn remove_with_float_key_equal_to_integer_key_returns_none() {
+        let mut heap = RegionHeap::default();
+        let float_key = heap.float(1.0).unwrap().encode().unwrap();
+        let map = Map::from_slice(&[(fixnum!(1), atom!("a"))]);
+
+        assert!(map.remove(float_key).is_none());
+        assert!(!map.is_key(float_key));
+    }
+
+    #[test]
+    fn from_slice_with_float_key_equal_to_integer_key_keeps_both() {
+        let mut heap = RegionHeap::default();
+        let float_key = heap.float(1.0).unwrap().encode().unwrap();
+        let map = Map::from_slice(&[(float_key, atom!("b")), (fixnum!(1), atom!("a"))]);
+
+        assert_eq!(map.keys(), vec![fixnum!(1), float_key]);
+    }
+
+    #[test]
+    fn put_with_float_value_equal_to_integer_value_returns_map() {
+        let mut heap = RegionHeap::default();
+        let float_value = heap.float(1.0).unwrap().encode().unwrap();
+        let map = Map::from_slice(&[(atom!("key"), fixnum!(1))]);
+
+        assert_eq!(
+            map.put(atom!("key"), float_value)
+                .unwrap()
+                .get(atom!("key")),
+            Some(float_value)
+        );
+    }
+
+    #[test]
+    fn update_without_key_returns_none() {
+        let map = large_map();
+
+        assert!(map.update(fixnum!(-1), fixnum!(-1)).is_none());
+        assert_eq!(
+            map.update(fixnum!(1), fixnum!(-1)).unwrap().get(fixnum!(1)),
+            Some(fixnum!(-1))
+        );
+    }
+
+    #[test]
+    fn take_returns_value_and_map_without_key() {
+        let (value, map) = large_map().take(fixnum!(1)).unwrap();
+
+        assert_eq!(value, fixnum!(1));
+        assert_eq!(map.len(), LARGE_LEN - 1);
+        assert!(!map.is_key(fixnum!(1)));
+    }
+
+    const LARGE_LEN: usize = 1000;
+
+    fn large_map() -> Map {
+        let entry_vec: Vec<(Term, Term)> = (0..LARGE_LEN as isize)
+            .map(|i| (fixnum!(i), fixnum!(i)))
+            .collect();
+
+        Map::from_slice(&entry_vec)
+    }
+
+    fn hamt(map: &Map) -> &Hamt {
+        match &map.value {
+            Entries::Hamt(hamt) => hamt,
+            Entries::Flat(_) => panic!("{} is not large enough to be a Hamt", map),
+        }
+    }
+}
@@ -0,0 +1,526 @@
+//! A hash array mapped trie (HAMT) holding the entries of maps that have outgrown a flat, sorted
+//! entry vector, mirroring the `hashmap` representation used by BEAM for maps with more than
+//! 32 keys.
+//!
+//! Nodes are reference-counted and copied on write, so cloning a `Hamt` is O(1) and a later
+//! `insert` or `remove` on either copy only copies the nodes along the path to the changed key.
+use core::fmt::{self, Debug};
+use core::slice;
+
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::erts::term::hash::hash;
+use crate::erts::term::prelude::Term;
+
+use super::are_exactly_equal;
+
+/// Number of hash bits consumed at each level of the trie
+const BITS_PER_LEVEL: u32 = 5;
+const LEVEL_MASK: u64 = (1 << BITS_PER_LEVEL) - 1;
+
+#[derive(Clone)]
+pub struct Hamt {
+    root: Arc<Node>,
+    len: usize,
+}
+
+impl Hamt {
+    pub fn new() -> Self {
+        Self {
+            root: Arc::new(Node::empty()),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn get(&self, key: Term) -> Option<Term> {
+        let hash = hash(key);
+        let mut node: &Node = &self.root;
+        let mut shift = 0;
+
+        loop {
+            match node {
+                Node::Branch { bitmap, children } => {
+                    let bit = bit(hash, shift);
+
+                    if bitmap & bit == 0 {
+                        return None;
+                    }
+
+                    match &children[position(*bitmap, bit)] {
+                        Child::Leaf(leaf) => {
+                            return if leaf.hash == hash && are_exactly_equal(leaf.key, key) {
+                                Some(leaf.value)
+                            } else {
+                                None
+                            };
+                        }
+                        Child::Node(child) => {
+                            node = &**child;
+                            shift += BITS_PER_LEVEL;
+                        }
+                    }
+                }
+                Node::Collision {
+                    hash: collision_hash,
+                    entries,
+                } => {
+                    return if *collision_hash == hash {
+                        entries
+                            .iter()
+                            .find(|(entry_key, _)| are_exactly_equal(*entry_key, key))
+                            .map(|(_, entry_value)| *entry_value)
+                    } else {
+                        None
+                    };
+                }
+            }
+        }
+    }
+
+    /// Inserts `value` for `key`, returning the previous value if `key` was already present.
+    pub fn insert(&mut self, key: Term, value: Term) -> Option<Term> {
+        let leaf = Leaf {
+            hash: hash(key),
+            key,
+            value,
+        };
+        let previous = Arc::make_mut(&mut self.root).insert(0, leaf);
+
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    /// Removes `key`, returning its value if it was present.
+    pub fn remove(&mut self, key: Term) -> Option<Term> {
+        let hash = hash(key);
+
+        // Avoid copying the root when `key` is not present and this trie shares it
+        self.get(key)?;
+
+        let (removed, replacement) = self.root.remove(0, hash, key)?;
+
+        self.root = match replacement {
+            Replacement::Empty => Arc::new(Node::empty()),
+            Replacement::Leaf(leaf) => {
+                let mut root = Node::empty();
+                root.insert(0, leaf);
+
+                Arc::new(root)
+            }
+            Replacement::Node(node) => node,
+        };
+        self.len -= 1;
+
+        Some(removed)
+    }
+
+    /// The number of nodes directly under the root and how many of them are shared with
+    /// `other`, to check that changes only copy the path to the changed key.
+    #[cfg(test)]
+    pub(super) fn root_nodes_shared_with(&self, other: &Hamt) -> (usize, usize) {
+        let mut nodes = 0;
+        let mut shared = 0;
+
+        if let (
+            Node::Branch { bitmap, children },
+            Node::Branch {
+                bitmap: other_bitmap,
+                children: other_children,
+            },
+        ) = (&*self.root, &*other.root)
+        {
+            if bitmap == other_bitmap {
+                for (child, other_child) in children.iter().zip(other_children.iter()) {
+                    if let Child::Node(node) = child {
+                        nodes += 1;
+
+                        if let Child::Node(other_node) = other_child {
+                            if Arc::ptr_eq(node, other_node) {
+                                shared += 1;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        (nodes, shared)
+    }
+
+    pub fn iter(&self) -> Iter {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            collision: [].iter(),
+        };
+        iter.descend(&self.root);
+
+        iter
+    }
+}
+
+impl Debug for Hamt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl Default for Hamt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Iter<'a> {
+    stack: Vec<slice::Iter<'a, Child>>,
+    collision: slice::Iter<'a, (Term, Term)>,
+}
+
+impl<'a> Iter<'a> {
+    fn descend(&mut self, node: &'a Node) {
+        match node {
+            Node::Branch { children, .. } => self.stack.push(children.iter()),
+            Node::Collision { entries, .. } => self.collision = entries.iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (&'a Term, &'a Term);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, value)) = self.collision.next() {
+                return Some((key, value));
+            }
+
+            match self.stack.last_mut()?.next() {
+                Some(Child::Leaf(leaf)) => return Some((&leaf.key, &leaf.value)),
+                Some(Child::Node(node)) => self.descend(node),
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+// Private
+
+#[derive(Clone)]
+enum Node {
+    Branch {
+        bitmap: u32,
+        children: Vec<Child>,
+    },
+    /// Keys whose full hashes are equal
+    Collision {
+        hash: u64,
+        entries: Vec<(Term, Term)>,
+    },
+}
+
+impl Node {
+    fn empty() -> Self {
+        Node::Branch {
+            bitmap: 0,
+            children: Vec::new(),
+        }
+    }
+
+    /// Builds the smallest branch at `shift` that distinguishes `first` from `second`, which
+    /// must have different hashes.
+    fn branch(shift: u32, first: Child, first_hash: u64, second: Leaf) -> Self {
+        let first_bit = bit(first_hash, shift);
+        let second_bit = bit(second.hash, shift);
+
+        if first_bit == second_bit {
+            let child = Node::branch(shift + BITS_PER_LEVEL, first, first_hash, second);
+
+            Node::Branch {
+                bitmap: first_bit,
+                children: vec![Child::Node(Arc::new(child))],
+            }
+        } else {
+            let second = Child::Leaf(second);
+            let children = if first_bit < second_bit {
+                vec![first, second]
+            } else {
+                vec![second, first]
+            };
+
+            Node::Branch {
+                bitmap: first_bit | second_bit,
+                children,
+            }
+        }
+    }
+
+    fn insert(&mut self, shift: u32, leaf: Leaf) -> Option<Term> {
+        match self {
+            Node::Branch { bitmap, children } => {
+                let bit = bit(leaf.hash, shift);
+                let position = position(*bitmap, bit);
+
+                if *bitmap & bit == 0 {
+                    *bitmap |= bit;
+                    children.insert(position, Child::Leaf(leaf));
+
+                    return None;
+                }
+
+                match &mut children[position] {
+                    Child::Leaf(existing) if are_exactly_equal(existing.key, leaf.key) => {
+                        Some(core::mem::replace(&mut existing.value, leaf.value))
+                    }
+                    Child::Leaf(existing) if existing.hash == leaf.hash => {
+                        let collision = Node::Collision {
+                            hash: leaf.hash,
+                            entries: vec![(existing.key, existing.value), (leaf.key, leaf.value)],
+                        };
+                        children[position] = Child::Node(Arc::new(collision));
+
+                        None
+                    }
+                    Child::Leaf(existing) => {
+                        let existing_hash = existing.hash;
+                        let existing = Child::Leaf(existing.clone());
+                        let branch =
+                            Node::branch(shift + BITS_PER_LEVEL, existing, existing_hash, leaf);
+                        children[position] = Child::Node(Arc::new(branch));
+
+                        None
+                    }
+                    Child::Node(node) => Arc::make_mut(node).insert(shift + BITS_PER_LEVEL, leaf),
+                }
+            }
+            Node::Collision { hash, entries } if *hash == leaf.hash => {
+                match entries
+                    .iter_mut()
+                    .find(|(key, _)| are_exactly_equal(*key, leaf.key))
+                {
+                    Some((_, value)) => Some(core::mem::replace(value, leaf.value)),
+                    None => {
+                        entries.push((leaf.key, leaf.value));
+
+                        None
+                    }
+                }
+            }
+            Node::Collision { hash, .. } => {
+                // A key with a different hash reached this collision node because the hashes
+                // share a prefix, so push the collision node down a level
+                let collision_hash = *hash;
+                let collision = Child::Node(Arc::new(self.clone()));
+                *self = Node::branch(shift, collision, collision_hash, leaf);
+
+                None
+            }
+        }
+    }
+
+    /// Returns the removed value and what should replace this node in its parent, or `None` if
+    /// `key` is not present.
+    fn remove(&self, shift: u32, hash: u64, key: Term) -> Option<(Term, Replacement)> {
+        match self {
+            Node::Branch { bitmap, children } => {
+                let bit = bit(hash, shift);
+
+                if bitmap & bit == 0 {
+                    return None;
+                }
+
+                let position = position(*bitmap, bit);
+
+                let (removed, replacement) = match &children[position] {
+                    Child::Leaf(leaf) if are_exactly_equal(leaf.key, key) => {
+                        (leaf.value, Replacement::Empty)
+                    }
+                    Child::Leaf(_) => return None,
+                    Child::Node(node) => node.remove(shift + BITS_PER_LEVEL, hash, key)?,
+                };
+
+                let mut bitmap = *bitmap;
+                let mut children = children.clone();
+
+                match replacement {
+                    Replacement::Empty => {
+                        bitmap &= !bit;
+                        children.remove(position);
+                    }
+                    Replacement::Leaf(leaf) => children[position] = Child::Leaf(leaf),
+                    Replacement::Node(node) => children[position] = Child::Node(node),
+                }
+
+                let replacement = match children.len() {
+                    0 => Replacement::Empty,
+                    // Hoist a lone leaf so that lookups don't walk through single-child branches
+                    1 if shift > 0 && children[0].is_leaf() => match children.pop().unwrap() {
+                        Child::Leaf(leaf) => Replacement::Leaf(leaf),
+                        Child::Node(_) => unreachable!(),
+                    },
+                    _ => Replacement::Node(Arc::new(Node::Branch { bitmap, children })),
+                };
+
+                Some((removed, replacement))
+            }
+            Node::Collision {
+                hash: collision_hash,
+                entries,
+            } => {
+                if *collision_hash != hash {
+                    return None;
+                }
+
+                let index = entries
+                    .iter()
+                    .position(|(entry_key, _)| are_exactly_equal(*entry_key, key))?;
+                let removed = entries[index].1;
+                let mut entries = entries.clone();
+                entries.remove(index);
+
+                let replacement = if entries.len() == 1 {
+                    let (key, value) = entries[0];
+
+                    Replacement::Leaf(Leaf { hash, key, value })
+                } else {
+                    Replacement::Node(Arc::new(Node::Collision { hash, entries }))
+                };
+
+                Some((removed, replacement))
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Child {
+    Leaf(Leaf),
+    Node(Arc<Node>),
+}
+
+impl Child {
+    fn is_leaf(&self) -> bool {
+        match self {
+            Child::Leaf(_) => true,
+            Child::Node(_) => false,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Leaf {
+    hash: u64,
+    key: Term,
+    value: Term,
+}
+
+enum Replacement {
+    Empty,
+    Leaf(Leaf),
+    Node(Arc<Node>),
+}
+
+fn bit(hash: u64, shift: u32) -> u32 {
+    1 << ((hash >> shift) & LEVEL_MASK)
+}
+
+fn position(bitmap: u32, bit: u32) -> usize {
+    (bitmap & (bit - 1)).count_ones() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::erts::term::prelude::*;
+
+    #[test]
+    fn insert_then_get_returns_value() {
+        let mut hamt = Hamt::new();
+
+        for i in 0..1000 {
+            assert_eq!(hamt.insert(fixnum!(i), fixnum!(i * 2)), None);
+        }
+
+        assert_eq!(hamt.len(), 1000);
+
+        for i in 0..1000 {
+            assert_eq!(hamt.get(fixnum!(i)), Some(fixnum!(i * 2)));
+        }
+
+        assert_eq!(hamt.get(fixnum!(1000)), None);
+    }
+
+    #[test]
+    fn insert_existing_key_replaces_value() {
+        let mut hamt = Hamt::new();
+        hamt.insert(atom!("key"), fixnum!(1));
+
+        assert_eq!(hamt.insert(atom!("key"), fixnum!(2)), Some(fixnum!(1)));
+        assert_eq!(hamt.len(), 1);
+        assert_eq!(hamt.get(atom!("key")), Some(fixnum!(2)));
+    }
+
+    #[test]
+    fn remove_leaves_other_keys() {
+        let mut hamt = Hamt::new();
+
+        for i in 0..100 {
+            hamt.insert(fixnum!(i), fixnum!(i));
+        }
+
+        for i in (0..100).step_by(2) {
+            assert_eq!(hamt.remove(fixnum!(i)), Some(fixnum!(i)));
+        }
+
+        assert_eq!(hamt.len(), 50);
+        assert_eq!(hamt.remove(fixnum!(0)), None);
+
+        for i in 0..100 {
+            let expected = if i % 2 == 0 { None } else { Some(fixnum!(i)) };
+
+            assert_eq!(hamt.get(fixnum!(i)), expected);
+        }
+    }
+
+    #[test]
+    fn clone_is_unaffected_by_changes_to_original() {
+        let mut original = Hamt::new();
+
+        for i in 0..100 {
+            original.insert(fixnum!(i), fixnum!(i));
+        }
+
+        let clone = original.clone();
+        original.insert(fixnum!(0), fixnum!(-1));
+        original.remove(fixnum!(1));
+
+        assert_eq!(clone.len(), 100);
+        assert_eq!(clone.get(fixnum!(0)), Some(fixnum!(0)));
+        assert_eq!(clone.get(fixnum!(1)), Some(fixnum!(1)));
+    }
+
+    #[test]
+    fn iter_visits_every_entry_once() {
+        let mut hamt = Hamt::new();
+
+        for i in 0..500 {
+            hamt.insert(fixnum!(i), fixnum!(i));
+        }
+
+        let mut keys: Vec<Term> = hamt.iter().map(|(key, _)| *key).collect();
+        keys.sort();
+
+        let expected: Vec<Term> = (0..500).map(|i| fixnum!(i)).collect();
+
+        assert_eq!(keys, expected);
+    }
+}
@@ -3,6 +3,9 @@ use core::cmp;
 use core::convert::TryFrom;
 use core::fmt::{self, Debug, Display};
 use core::hash::{Hash, Hasher};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use alloc::sync::Arc;
 
 use crate::borrow::CloneToProcess;
 use crate::erts::exception::AllocResult;
@@ -15,6 +18,18 @@ use super::prelude::*;
 #[repr(transparent)]
 pub struct Port(usize);
 impl Port {
+    /// Generates the next `Port`.
+    ///
+    /// `Port`s are not reused for the lifetime of the VM.
+    pub fn next() -> Port {
+        Self(COUNTER.fetch_add(1, Ordering::SeqCst))
+    }
+
+    /// Same as `next`, but directly encodes to `Term`
+    pub fn next_term() -> Term {
+        Self::next().encode().unwrap()
+    }
+
     /// Given a the raw pid value (as a usize), reifies it into a `Port`
     #[inline]
     pub unsafe fn from_raw(port: usize) -> Self {
@@ -28,8 +43,8 @@ impl Port {
 }
 
 impl Display for Port {
-    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
-        unimplemented!()
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#Port<0.{}>", self.0)
     }
 }
 
@@ -84,11 +99,16 @@ impl TryFrom<TypedTerm> for Port {
 #[repr(C)]
 pub struct ExternalPort {
     header: Header<ExternalPort>,
-    node: Node,
+    arc_node: Arc<Node>,
     next: *mut u8,
     port: Port,
 }
 impl_static_header!(ExternalPort, Term::HEADER_EXTERN_PORT);
+impl ExternalPort {
+    pub fn arc_node(&self) -> Arc<Node> {
+        self.arc_node.clone()
+    }
+}
 impl CloneToProcess for ExternalPort {
     fn clone_to_heap<A>(&self, _heap: &mut A) -> AllocResult<Term>
     where
@@ -110,7 +130,7 @@ impl Display for ExternalPort {
 
 impl Hash for ExternalPort {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.node.hash(state);
+        self.arc_node.hash(state);
         self.port.hash(state);
     }
 }
@@ -118,7 +138,7 @@ impl Hash for ExternalPort {
 impl PartialEq for ExternalPort {
     #[inline]
     fn eq(&self, other: &ExternalPort) -> bool {
-        self.node == other.node && self.port == other.port
+        self.arc_node == other.arc_node && self.port == other.port
     }
 }
 impl<T> PartialEq<Boxed<T>> for ExternalPort
@@ -135,7 +155,7 @@ impl PartialOrd for ExternalPort {
     #[inline]
     fn partial_cmp(&self, other: &ExternalPort) -> Option<cmp::Ordering> {
         use cmp::Ordering;
-        match self.node.partial_cmp(&other.node) {
+        match self.arc_node.partial_cmp(&other.arc_node) {
             Some(Ordering::Equal) => self.port.partial_cmp(&other.port),
             result => result,
         }
@@ -161,3 +181,5 @@ impl TryFrom<TypedTerm> for Boxed<ExternalPort> {
         }
     }
 }
+
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
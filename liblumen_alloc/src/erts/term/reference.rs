@@ -155,6 +155,11 @@ pub struct ExternalReference {
     reference: Reference,
 }
 impl_static_header!(ExternalReference, Term::HEADER_EXTERN_REF);
+impl ExternalReference {
+    pub fn arc_node(&self) -> Arc<Node> {
+        self.arc_node.clone()
+    }
+}
 impl CloneToProcess for ExternalReference {
     #[inline]
     fn clone_to_heap<A>(&self, _heap: &mut A) -> AllocResult<Term>
@@ -159,6 +159,20 @@ where
     }
 }
 
+impl Eq for Resource {}
+impl Ord for Resource {
+    /// Resources have no natural order, so they are ordered by the address of the shared value,
+    /// which doesn't change while any reference to it exists.
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+impl PartialOrd for Resource {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Clone for Resource {
     #[inline]
     fn clone(&self) -> Self {
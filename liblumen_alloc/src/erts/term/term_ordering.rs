@@ -0,0 +1,523 @@
+//! All terms in Erlang and Elixir are completely ordered.
+//!
+//! number < atom < reference < function < port < pid < tuple < map < nil < list < bitstring
+//!
+//! > When comparing two numbers of different types (a number being either an integer or a float), a
+//! > conversion to the type with greater precision will always occur, unless the comparison
+//! > operator used is either === or !==. A float will be considered more precise than an integer,
+//! > unless the float is greater/less than +/-9007199254740992.0 respectively, at which point all
+//! > the significant figures of the float are to the left of the decimal point. This behavior
+//! > exists so that the comparison of large numbers remains transitive.
+//! >
+//! > The collection types are compared using the following rules:
+//! >
+//! > * Tuples are compared by size, then element by element.
+//! > * Maps are compared by size, then by keys in ascending term order, then by values in key
+//! >   order.   In the specific case of maps' key ordering, integers are always considered to be
+//! >   less than floats.
+//! > * Lists are compared element by element.
+//! > * Bitstrings are compared byte by byte, incomplete bytes are compared bit by bit.
+//! > -- https://hexdocs.pm/elixir/operators.html#term-ordering
+//!
+//! `Ord` for `Term` and `TypedTerm` use [cmp], so the comparison BIFs, `lists:sort/1`, and the
+//! ordering of map keys all share it.
+
+use core::cmp::Ordering::{self, *};
+
+use num_bigint::BigInt;
+use num_traits::{FromPrimitive, ToPrimitive};
+
+use super::prelude::*;
+
+/// The rank of each type of term in the total order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TypeOrder {
+    Number,
+    Atom,
+    Reference,
+    Function,
+    Port,
+    Pid,
+    Tuple,
+    Map,
+    Nil,
+    List,
+    Bitstring,
+}
+
+impl From<&TypedTerm> for TypeOrder {
+    fn from(typed_term: &TypedTerm) -> Self {
+        match typed_term {
+            TypedTerm::SmallInteger(_) | TypedTerm::BigInteger(_) | TypedTerm::Float(_) => {
+                TypeOrder::Number
+            }
+            TypedTerm::Atom(_) => TypeOrder::Atom,
+            TypedTerm::Reference(_)
+            | TypedTerm::ResourceReference(_)
+            | TypedTerm::ExternalReference(_) => TypeOrder::Reference,
+            TypedTerm::Closure(_) => TypeOrder::Function,
+            TypedTerm::Port(_) | TypedTerm::ExternalPort(_) => TypeOrder::Port,
+            TypedTerm::Pid(_) | TypedTerm::ExternalPid(_) => TypeOrder::Pid,
+            TypedTerm::Tuple(_) => TypeOrder::Tuple,
+            TypedTerm::Map(_) => TypeOrder::Map,
+            TypedTerm::Nil => TypeOrder::Nil,
+            TypedTerm::List(_) => TypeOrder::List,
+            TypedTerm::HeapBinary(_)
+            | TypedTerm::ProcBin(_)
+            | TypedTerm::BinaryLiteral(_)
+            | TypedTerm::SubBinary(_)
+            | TypedTerm::MatchContext(_) => TypeOrder::Bitstring,
+        }
+    }
+}
+
+/// Compares `lhs` and `rhs` in the total order of terms.
+///
+/// Integers and floats are compared by value, so `1` and `1.0` are `Equal`.  Use
+/// [exact_eq](liblumen_core::cmp::ExactEq) to tell them apart.
+pub fn cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    match TypeOrder::from(lhs).cmp(&TypeOrder::from(rhs)) {
+        Equal => same_type_cmp(lhs, rhs),
+        ordering => ordering,
+    }
+}
+
+/// Compares map keys, which, unlike [cmp], orders all integers before all floats, so that `1` and
+/// `1.0` are different keys.
+pub fn map_key_cmp(lhs: Term, rhs: Term) -> Ordering {
+    match (lhs.is_integer(), rhs.is_integer()) {
+        (true, false) if rhs.is_float() => Less,
+        (false, true) if lhs.is_float() => Greater,
+        _ => lhs.cmp(&rhs),
+    }
+}
+
+// Private
+
+fn same_type_cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    match (lhs, rhs) {
+        (TypedTerm::Atom(lhs), TypedTerm::Atom(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Closure(lhs), TypedTerm::Closure(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Tuple(lhs), TypedTerm::Tuple(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Map(lhs), TypedTerm::Map(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Nil, TypedTerm::Nil) => Equal,
+        (TypedTerm::List(lhs), TypedTerm::List(rhs)) => lhs.as_ref().cmp(rhs.as_ref()),
+        _ => match TypeOrder::from(lhs) {
+            TypeOrder::Number => number_cmp(lhs, rhs),
+            TypeOrder::Reference => reference_cmp(lhs, rhs),
+            TypeOrder::Port => port_cmp(lhs, rhs),
+            TypeOrder::Pid => pid_cmp(lhs, rhs),
+            TypeOrder::Bitstring => bitstring_cmp(lhs, rhs),
+            type_order => unreachable!(
+                "{:?} ({:?}) and ({:?}) should have been compared directly",
+                type_order, lhs, rhs
+            ),
+        },
+    }
+}
+
+fn number_cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    match (lhs, rhs) {
+        (TypedTerm::SmallInteger(lhs), TypedTerm::SmallInteger(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::SmallInteger(lhs), TypedTerm::BigInteger(rhs)) => {
+            let lhs_big_int: BigInt = (*lhs).into();
+
+            lhs_big_int.cmp(&rhs.value)
+        }
+        (TypedTerm::SmallInteger(lhs), TypedTerm::Float(rhs)) => {
+            let lhs_isize: isize = (*lhs).into();
+
+            small_integer_float_cmp(lhs_isize, rhs.value())
+        }
+        (TypedTerm::BigInteger(lhs), TypedTerm::SmallInteger(rhs)) => {
+            let rhs_big_int: BigInt = (*rhs).into();
+
+            lhs.value.cmp(&rhs_big_int)
+        }
+        (TypedTerm::BigInteger(lhs), TypedTerm::BigInteger(rhs)) => lhs.value.cmp(&rhs.value),
+        (TypedTerm::BigInteger(lhs), TypedTerm::Float(rhs)) => {
+            big_integer_float_cmp(&lhs.value, rhs.value())
+        }
+        (TypedTerm::Float(lhs), TypedTerm::SmallInteger(rhs)) => {
+            let rhs_isize: isize = (*rhs).into();
+
+            small_integer_float_cmp(rhs_isize, lhs.value()).reverse()
+        }
+        (TypedTerm::Float(lhs), TypedTerm::BigInteger(rhs)) => {
+            big_integer_float_cmp(&rhs.value, lhs.value()).reverse()
+        }
+        (TypedTerm::Float(lhs), TypedTerm::Float(rhs)) => float_cmp(lhs.value(), rhs.value()),
+        _ => unreachable!("({:?}) and ({:?}) are not both numbers", lhs, rhs),
+    }
+}
+
+pub(in crate::erts::term) fn small_integer_float_cmp(integer: isize, float: f64) -> Ordering {
+    if Float::INTEGRAL_MIN < float && float < Float::INTEGRAL_MAX {
+        // Converting `integer` may round it, but only when it's too big to be equal to `float`, and
+        // rounding never moves it past `float`
+        float_cmp(integer as f64, float)
+    } else {
+        big_integer_float_cmp(&integer.into(), float)
+    }
+}
+
+pub(in crate::erts::term) fn big_integer_float_cmp(integer: &BigInt, float: f64) -> Ordering {
+    if Float::INTEGRAL_MIN < float && float < Float::INTEGRAL_MAX {
+        match integer.to_f64() {
+            // Same as for small integers, rounding can't change the order
+            Some(integer_f64) => float_cmp(integer_f64, float),
+            // Too big for any float, so farther from zero than `float`
+            None => integer.sign().cmp(&num_bigint::Sign::NoSign),
+        }
+    } else {
+        // All the significant figures of `float` are to the left of the decimal point, so it
+        // converts to an integer exactly
+        match BigInt::from_f64(float) {
+            Some(float_big_int) => integer.cmp(&float_big_int),
+            None if float.is_sign_negative() => Greater,
+            None => Less,
+        }
+    }
+}
+
+fn float_cmp(lhs: f64, rhs: f64) -> Ordering {
+    // Erlang doesn't support the floats that can't be compared
+    lhs.partial_cmp(&rhs).unwrap()
+}
+
+/// Local references are ordered before resources, which are ordered before external references.
+fn reference_cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    match (lhs, rhs) {
+        (TypedTerm::Reference(lhs), TypedTerm::Reference(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Reference(_), _) => Less,
+        (TypedTerm::ResourceReference(_), TypedTerm::Reference(_)) => Greater,
+        (TypedTerm::ResourceReference(lhs), TypedTerm::ResourceReference(rhs)) => {
+            lhs.as_ref().cmp(rhs.as_ref())
+        }
+        (TypedTerm::ResourceReference(_), _) => Less,
+        (TypedTerm::ExternalReference(lhs), TypedTerm::ExternalReference(rhs)) => {
+            lhs.as_ref().partial_cmp(rhs.as_ref()).unwrap()
+        }
+        (TypedTerm::ExternalReference(_), _) => Greater,
+        _ => unreachable!("({:?}) and ({:?}) are not both references", lhs, rhs),
+    }
+}
+
+/// Local ports are ordered before external ports.
+fn port_cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    match (lhs, rhs) {
+        (TypedTerm::Port(lhs), TypedTerm::Port(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Port(_), TypedTerm::ExternalPort(_)) => Less,
+        (TypedTerm::ExternalPort(_), TypedTerm::Port(_)) => Greater,
+        (TypedTerm::ExternalPort(lhs), TypedTerm::ExternalPort(rhs)) => {
+            lhs.as_ref().partial_cmp(rhs.as_ref()).unwrap()
+        }
+        _ => unreachable!("({:?}) and ({:?}) are not both ports", lhs, rhs),
+    }
+}
+
+/// Local pids are ordered before external pids.
+fn pid_cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    match (lhs, rhs) {
+        (TypedTerm::Pid(lhs), TypedTerm::Pid(rhs)) => lhs.cmp(rhs),
+        (TypedTerm::Pid(_), TypedTerm::ExternalPid(_)) => Less,
+        (TypedTerm::ExternalPid(_), TypedTerm::Pid(_)) => Greater,
+        (TypedTerm::ExternalPid(lhs), TypedTerm::ExternalPid(rhs)) => {
+            lhs.as_ref().cmp(rhs.as_ref())
+        }
+        _ => unreachable!("({:?}) and ({:?}) are not both pids", lhs, rhs),
+    }
+}
+
+fn bitstring_cmp(lhs: &TypedTerm, rhs: &TypedTerm) -> Ordering {
+    let option_ordering = match (lhs, rhs) {
+        (TypedTerm::HeapBinary(lhs), TypedTerm::HeapBinary(rhs)) => {
+            Some(lhs.as_ref().cmp(rhs.as_ref()))
+        }
+        (TypedTerm::HeapBinary(lhs), TypedTerm::ProcBin(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::HeapBinary(lhs), TypedTerm::BinaryLiteral(rhs)) => {
+            lhs.as_ref().partial_cmp(rhs)
+        }
+        (TypedTerm::HeapBinary(lhs), TypedTerm::SubBinary(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::HeapBinary(lhs), TypedTerm::MatchContext(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::ProcBin(lhs), TypedTerm::HeapBinary(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::ProcBin(lhs), TypedTerm::ProcBin(rhs)) => Some(lhs.as_ref().cmp(rhs.as_ref())),
+        (TypedTerm::ProcBin(lhs), TypedTerm::BinaryLiteral(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::ProcBin(lhs), TypedTerm::SubBinary(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::ProcBin(lhs), TypedTerm::MatchContext(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::BinaryLiteral(lhs), TypedTerm::HeapBinary(rhs)) => {
+            lhs.as_ref().partial_cmp(rhs)
+        }
+        (TypedTerm::BinaryLiteral(lhs), TypedTerm::ProcBin(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::BinaryLiteral(lhs), TypedTerm::BinaryLiteral(rhs)) => {
+            Some(lhs.as_ref().cmp(rhs.as_ref()))
+        }
+        (TypedTerm::BinaryLiteral(lhs), TypedTerm::SubBinary(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::BinaryLiteral(lhs), TypedTerm::MatchContext(rhs)) => {
+            lhs.as_ref().partial_cmp(rhs)
+        }
+        (TypedTerm::SubBinary(lhs), TypedTerm::HeapBinary(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::SubBinary(lhs), TypedTerm::ProcBin(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::SubBinary(lhs), TypedTerm::BinaryLiteral(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::SubBinary(lhs), TypedTerm::SubBinary(rhs)) => {
+            Some(lhs.as_ref().cmp(rhs.as_ref()))
+        }
+        (TypedTerm::SubBinary(lhs), TypedTerm::MatchContext(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::MatchContext(lhs), TypedTerm::HeapBinary(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::MatchContext(lhs), TypedTerm::ProcBin(rhs)) => lhs.as_ref().partial_cmp(rhs),
+        (TypedTerm::MatchContext(lhs), TypedTerm::BinaryLiteral(rhs)) => {
+            lhs.as_ref().partial_cmp(rhs)
+        }
+        // `MatchContext` only implements the comparison with `SubBinary` in that direction
+        (TypedTerm::MatchContext(lhs), TypedTerm::SubBinary(rhs)) => rhs
+            .as_ref()
+            .partial_cmp(lhs)
+            .map(|ordering| ordering.reverse()),
+        (TypedTerm::MatchContext(lhs), TypedTerm::MatchContext(rhs)) => {
+            Some(lhs.as_ref().cmp(rhs.as_ref()))
+        }
+        _ => unreachable!("({:?}) and ({:?}) are not both bitstrings", lhs, rhs),
+    };
+
+    option_ordering.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::alloc::Layout;
+
+    use liblumen_core::sys::sysconf::MIN_ALIGN;
+
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use crate::erts::process::alloc::TermAlloc;
+    use crate::erts::testing::RegionHeap;
+
+    proptest! {
+        #[test]
+        fn cmp_matches_oracle(lhs in model(), rhs in model()) {
+            let mut heap = heap();
+            let lhs_term = lhs.to_term(&mut heap);
+            let rhs_term = rhs.to_term(&mut heap);
+
+            prop_assert_eq!(lhs_term.cmp(&rhs_term), oracle_cmp(&lhs, &rhs));
+        }
+
+        #[test]
+        fn cmp_is_antisymmetric(lhs in model(), rhs in model()) {
+            let mut heap = heap();
+            let lhs_term = lhs.to_term(&mut heap);
+            let rhs_term = rhs.to_term(&mut heap);
+
+            prop_assert_eq!(lhs_term.cmp(&rhs_term), rhs_term.cmp(&lhs_term).reverse());
+        }
+
+        #[test]
+        fn number_cmp_is_transitive(
+            first in number_near_integral_limit(),
+            second in number_near_integral_limit(),
+            third in number_near_integral_limit()
+        ) {
+            let mut heap = heap();
+            let mut terms = vec![
+                first.to_term(&mut heap),
+                second.to_term(&mut heap),
+                third.to_term(&mut heap)
+            ];
+
+            terms.sort();
+
+            prop_assert!(terms[0] <= terms[1]);
+            prop_assert!(terms[1] <= terms[2]);
+            prop_assert!(terms[0] <= terms[2]);
+        }
+
+        #[test]
+        fn map_key_cmp_orders_integers_before_floats(integer in any::<i32>(), float in any::<i32>()) {
+            let mut heap = heap();
+            let integer_term = heap.integer(integer).unwrap();
+            let float_term: Term = heap.float(float as f64).unwrap().into();
+
+            prop_assert_eq!(map_key_cmp(integer_term, float_term), Less);
+            prop_assert_eq!(map_key_cmp(float_term, integer_term), Greater);
+        }
+    }
+
+    #[test]
+    fn integer_and_float_with_same_value_are_equal() {
+        let mut heap = heap();
+        let values: Vec<BigInt> = vec![
+            0.into(),
+            (-1).into(),
+            BigInt::from(Float::INTEGRAL_MAX as i64),
+            BigInt::from(Float::INTEGRAL_MIN as i64),
+            BigInt::from(1) << 64,
+            -(BigInt::from(1) << 100),
+        ];
+
+        for value in values {
+            let float = value.to_f64().unwrap();
+            let integer_term = heap.integer(value).unwrap();
+            let float_term: Term = heap.float(float).unwrap().into();
+
+            assert_eq!(integer_term.cmp(&float_term), Equal);
+            assert_eq!(float_term.cmp(&integer_term), Equal);
+        }
+    }
+
+    #[test]
+    fn integer_past_integral_limit_is_greater_than_nearest_float() {
+        let mut heap = heap();
+        let float = Float::INTEGRAL_MAX;
+        // `INTEGRAL_MAX + 1` rounds to `INTEGRAL_MAX` as a float
+        let integer_term = heap.integer(BigInt::from(float as i64) + 1).unwrap();
+        let float_term: Term = heap.float(float).unwrap().into();
+
+        assert_eq!(integer_term.cmp(&float_term), Greater);
+    }
+
+    /// A term that can be compared without `term_ordering`.
+    #[derive(Clone, Debug)]
+    enum Model {
+        Integer(BigInt),
+        Float(f64),
+        Atom(String),
+        Tuple(Vec<Model>),
+        Nil,
+        List(Vec<Model>),
+        Binary(Vec<u8>),
+    }
+
+    impl Model {
+        fn type_order(&self) -> usize {
+            match self {
+                Model::Integer(_) | Model::Float(_) => 0,
+                Model::Atom(_) => 1,
+                Model::Tuple(_) => 6,
+                Model::Nil => 8,
+                Model::List(_) => 9,
+                Model::Binary(_) => 10,
+            }
+        }
+
+        fn to_term(&self, heap: &mut RegionHeap) -> Term {
+            match self {
+                Model::Integer(integer) => heap.integer(integer.clone()).unwrap(),
+                Model::Float(float) => heap.float(*float).unwrap().into(),
+                Model::Atom(name) => Atom::str_to_term(name),
+                Model::Tuple(elements) => {
+                    let element_terms: Vec<Term> = elements
+                        .iter()
+                        .map(|element| element.to_term(heap))
+                        .collect();
+
+                    heap.tuple_from_slice(&element_terms).unwrap().into()
+                }
+                Model::Nil => Term::NIL,
+                Model::List(elements) => {
+                    let element_terms: Vec<Term> = elements
+                        .iter()
+                        .map(|element| element.to_term(heap))
+                        .collect();
+
+                    heap.list_from_slice(&element_terms)
+                        .unwrap()
+                        .unwrap()
+                        .into()
+                }
+                Model::Binary(bytes) => heap.binary_from_bytes(bytes).unwrap(),
+            }
+        }
+    }
+
+    fn oracle_cmp(lhs: &Model, rhs: &Model) -> Ordering {
+        match (lhs, rhs) {
+            (Model::Integer(lhs), Model::Integer(rhs)) => lhs.cmp(rhs),
+            (Model::Integer(lhs), Model::Float(rhs)) => oracle_integer_float_cmp(lhs, *rhs),
+            (Model::Float(lhs), Model::Integer(rhs)) => {
+                oracle_integer_float_cmp(rhs, *lhs).reverse()
+            }
+            (Model::Float(lhs), Model::Float(rhs)) => lhs.partial_cmp(rhs).unwrap(),
+            (Model::Atom(lhs), Model::Atom(rhs)) => lhs.cmp(rhs),
+            (Model::Tuple(lhs), Model::Tuple(rhs)) => lhs
+                .len()
+                .cmp(&rhs.len())
+                .then_with(|| oracle_elements_cmp(lhs, rhs)),
+            (Model::Nil, Model::Nil) => Equal,
+            (Model::List(lhs), Model::List(rhs)) => oracle_elements_cmp(lhs, rhs),
+            (Model::Binary(lhs), Model::Binary(rhs)) => lhs.cmp(rhs),
+            _ => lhs.type_order().cmp(&rhs.type_order()),
+        }
+    }
+
+    /// Compares exactly by scaling both to integers, so unlike `big_integer_float_cmp` it never
+    /// rounds.
+    fn oracle_integer_float_cmp(integer: &BigInt, float: f64) -> Ordering {
+        let (mantissa, exponent, sign) = num_traits::Float::integer_decode(float);
+        let mantissa = BigInt::from(sign) * BigInt::from(mantissa);
+
+        if exponent < 0 {
+            (integer << ((-exponent) as usize)).cmp(&mantissa)
+        } else {
+            integer.cmp(&(mantissa << (exponent as usize)))
+        }
+    }
+
+    /// Element by element, then shorter first.
+    fn oracle_elements_cmp(lhs: &[Model], rhs: &[Model]) -> Ordering {
+        lhs.iter()
+            .zip(rhs.iter())
+            .map(|(lhs_element, rhs_element)| oracle_cmp(lhs_element, rhs_element))
+            .find(|ordering| *ordering != Equal)
+            .unwrap_or_else(|| lhs.len().cmp(&rhs.len()))
+    }
+
+    fn heap() -> RegionHeap {
+        RegionHeap::new(Layout::from_size_align(64 * 1024, MIN_ALIGN).unwrap())
+    }
+
+    fn model() -> impl Strategy<Value = Model> {
+        let leaf = prop_oneof![
+            number(),
+            prop_oneof![Just("a"), Just("b"), Just("true"), Just("zebra")]
+                .prop_map(|name| Model::Atom(name.to_string())),
+            Just(Model::Nil),
+            // Long enough to be a `ProcBin` instead of a `HeapBin`
+            vec(any::<u8>(), 0..80).prop_map(Model::Binary),
+        ];
+
+        leaf.prop_recursive(3, 16, 4, |element| {
+            prop_oneof![
+                vec(element.clone(), 0..4).prop_map(Model::Tuple),
+                vec(element, 1..4).prop_map(Model::List),
+            ]
+        })
+    }
+
+    fn number() -> impl Strategy<Value = Model> {
+        prop_oneof![
+            // Small enough that integers and floats are often equal
+            (-3_i8..=3).prop_map(|i| Model::Integer(i.into())),
+            (-3_i8..=3).prop_map(|i| Model::Float(i as f64)),
+            any::<i64>().prop_map(|i| Model::Integer(i.into())),
+            any::<i128>().prop_map(|i| Model::Integer(i.into())),
+            proptest::num::f64::NORMAL.prop_map(Model::Float),
+            number_near_integral_limit(),
+        ]
+    }
+
+    /// Numbers around `Float::INTEGRAL_MAX`, where integers stop converting exactly to floats.
+    fn number_near_integral_limit() -> impl Strategy<Value = Model> {
+        let limit = Float::INTEGRAL_MAX as i64;
+
+        prop_oneof![
+            (-4_i64..=4).prop_map(move |offset| Model::Integer((limit + offset).into())),
+            (-4_i64..=4).prop_map(move |offset| Model::Float((limit + offset) as f64)),
+            (-4_i64..=4).prop_map(move |offset| Model::Integer((-limit + offset).into())),
+            (-4_i64..=4).prop_map(move |offset| Model::Float((-limit + offset) as f64)),
+        ]
+    }
+}
@@ -312,15 +312,19 @@ where
 }
 
 impl Ord for Tuple {
+    /// > * Tuples are compared by size, then element by element.
     #[inline]
     fn cmp(&self, other: &Self) -> cmp::Ordering {
-        self.elements.cmp(&other.elements)
+        self.elements
+            .len()
+            .cmp(&other.elements.len())
+            .then_with(|| self.elements.cmp(&other.elements))
     }
 }
 impl PartialOrd for Tuple {
     #[inline]
     fn partial_cmp(&self, other: &Tuple) -> Option<cmp::Ordering> {
-        self.elements.partial_cmp(&other.elements)
+        Some(self.cmp(other))
     }
 }
 impl<T> PartialOrd<Boxed<T>> for Tuple
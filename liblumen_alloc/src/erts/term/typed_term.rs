@@ -15,6 +15,7 @@ use crate::erts::exception::AllocResult;
 use crate::erts::Process;
 
 use super::prelude::*;
+use super::term_ordering;
 
 /// Concrete `Term` types, i.e. resolved to concrete values, or pointers to values.
 ///
@@ -412,234 +413,12 @@ impl PartialEq<TypedTerm> for TypedTerm {
 }
 impl Eq for TypedTerm {}
 
-/// All terms in Erlang and Elixir are completely ordered.
-///
-/// number < atom < reference < function < port < pid < tuple < map < list < bitstring
-///
-/// > When comparing two numbers of different types (a number being either an integer or a float), a
-/// > conversion to the type with greater precision will always occur, unless the comparison
-/// > operator used is either === or !==. A float will be considered more precise than an integer,
-/// > unless the float is greater/less than +/-9007199254740992.0 respectively, at which point all
-/// > the significant figures of the float are to the left of the decimal point. This behavior
-/// > exists so that the comparison of large numbers remains transitive.
-/// >
-/// > The collection types are compared using the following rules:
-/// >
-/// > * Tuples are compared by size, then element by element.
-/// > * Maps are compared by size, then by keys in ascending term order, then by values in key
-/// >   order.   In the specific case of maps' key ordering, integers are always considered to be
-/// >   less than floats.
-/// > * Lists are compared element by element.
-/// > * Bitstrings are compared byte by byte, incomplete bytes are compared bit by bit.
-/// > -- https://hexdocs.pm/elixir/operators.html#term-ordering
+/// See [term_ordering](super::term_ordering) for the total order of terms.
 impl Ord for TypedTerm {
     fn cmp(&self, other: &Self) -> cmp::Ordering {
-        use cmp::Ordering::*;
-
-        match self {
-            // Numbers
-            // Order is SmallInteger, Float, BigInt because of the order of their ranges
-            TypedTerm::SmallInteger(lhs) => match other {
-                TypedTerm::SmallInteger(rhs) => lhs.cmp(rhs),
-                // Flip order so that only type that will be conversion target needs to
-                // implement `PartialOrd` between types.
-                TypedTerm::Float(rhs) => (*lhs).partial_cmp(rhs).unwrap(),
-                TypedTerm::BigInteger(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                _ => Less,
-            },
-            TypedTerm::Float(lhs) => match other {
-                TypedTerm::SmallInteger(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::Float(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::BigInteger(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                _ => Less,
-            },
-            TypedTerm::BigInteger(lhs) => match other {
-                TypedTerm::SmallInteger(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::Float(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::BigInteger(rhs) => lhs.cmp(rhs),
-                _ => Less,
-            },
-            TypedTerm::Reference(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_) | TypedTerm::BigInteger(_) => Greater,
-                TypedTerm::Reference(rhs) => lhs.cmp(rhs),
-                TypedTerm::ExternalReference(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::Atom(_) => Greater,
-                _ => Less,
-            },
-            TypedTerm::ExternalReference(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_) | TypedTerm::BigInteger(_) => Greater,
-                TypedTerm::Reference(rhs) => rhs.as_ref().partial_cmp(lhs).unwrap().reverse(),
-                TypedTerm::ExternalReference(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::Atom(_) => Greater,
-                _ => Less,
-            },
-            TypedTerm::Closure(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::ExternalReference(_) => Greater,
-                TypedTerm::Closure(rhs) => lhs.cmp(rhs),
-                TypedTerm::Atom(_) => Greater,
-                _ => Less,
-            },
-            TypedTerm::ExternalPid(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::ExternalReference(_)
-                | TypedTerm::Closure(_)
-                | TypedTerm::ExternalPort(_) => Greater,
-                TypedTerm::ExternalPid(rhs) => lhs.cmp(rhs),
-                TypedTerm::Atom(_) | TypedTerm::Port(_) | TypedTerm::Pid(_) => Greater,
-                _ => Less,
-            },
-            TypedTerm::Tuple(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::ExternalReference(_)
-                | TypedTerm::Closure(_)
-                | TypedTerm::ExternalPort(_)
-                | TypedTerm::ExternalPid(_) => Greater,
-                TypedTerm::Tuple(rhs) => lhs.cmp(rhs),
-                TypedTerm::Atom(_) | TypedTerm::Port(_) | TypedTerm::Pid(_) => Greater,
-                _ => Less,
-            },
-            TypedTerm::Map(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::Closure(_)
-                | TypedTerm::ExternalPort(_)
-                | TypedTerm::ExternalPid(_)
-                | TypedTerm::Tuple(_) => Greater,
-                TypedTerm::Map(rhs) => lhs.cmp(rhs),
-                TypedTerm::Atom(_) | TypedTerm::Port(_) | TypedTerm::Pid(_) => Greater,
-                _ => Less,
-            },
-            // Bitstrings in likely order
-            TypedTerm::HeapBinary(lhs) => match other {
-                TypedTerm::HeapBinary(rhs) => lhs.cmp(rhs),
-                TypedTerm::ProcBin(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::BinaryLiteral(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::SubBinary(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::MatchContext(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                _ => Greater,
-            },
-            TypedTerm::ProcBin(lhs) => match other {
-                TypedTerm::HeapBinary(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::ProcBin(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::BinaryLiteral(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::SubBinary(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::MatchContext(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                _ => Greater,
-            },
-            TypedTerm::BinaryLiteral(lhs) => match other {
-                TypedTerm::HeapBinary(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::ProcBin(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::BinaryLiteral(rhs) => lhs.partial_cmp(rhs).unwrap(),
-                TypedTerm::SubBinary(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::MatchContext(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                _ => Greater,
-            },
-            TypedTerm::SubBinary(lhs) => match other {
-                TypedTerm::HeapBinary(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::ProcBin(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::BinaryLiteral(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                TypedTerm::SubBinary(rhs) => lhs.cmp(rhs),
-                TypedTerm::MatchContext(rhs) => lhs.as_ref().partial_cmp(rhs).unwrap(),
-                _ => Greater,
-            },
-            TypedTerm::MatchContext(lhs) => {
-                unimplemented!("match_context ({:?}) cmp {:?}", lhs, other)
-            }
-            TypedTerm::Atom(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_) | TypedTerm::BigInteger(_) => Greater,
-                TypedTerm::Atom(rhs) => lhs.cmp(rhs),
-                _ => Less,
-            },
-            TypedTerm::Port(lhs) => unimplemented!("Port {:?} cmp {:?}", lhs, other),
-            TypedTerm::ExternalPort(lhs) => {
-                unimplemented!("ExternalPort {:?} cmp {:?}", lhs, other)
-            }
-            TypedTerm::Pid(lhs) => match other {
-                TypedTerm::SmallInteger(_) => Greater,
-                TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::Closure(_)
-                | TypedTerm::ExternalPort(_) => Greater,
-                TypedTerm::Atom(_) | TypedTerm::Port(_) => Greater,
-                TypedTerm::Pid(rhs) => lhs.cmp(rhs),
-                _ => Less,
-            },
-            TypedTerm::Nil => match other {
-                TypedTerm::SmallInteger(_)
-                | TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::Closure(_)
-                | TypedTerm::ExternalPort(_)
-                | TypedTerm::ExternalPid(_)
-                | TypedTerm::Tuple(_)
-                | TypedTerm::Map(_)
-                | TypedTerm::Atom(_)
-                | TypedTerm::Port(_)
-                | TypedTerm::Pid(_) => Greater,
-                TypedTerm::Nil => Equal,
-                _ => Less,
-            },
-            TypedTerm::List(lhs) => match other {
-                TypedTerm::SmallInteger(_)
-                | TypedTerm::Float(_)
-                | TypedTerm::BigInteger(_)
-                | TypedTerm::Reference(_)
-                | TypedTerm::Closure(_)
-                | TypedTerm::ExternalPort(_)
-                | TypedTerm::ExternalPid(_)
-                | TypedTerm::Tuple(_)
-                | TypedTerm::Map(_)
-                | TypedTerm::Atom(_)
-                | TypedTerm::Port(_)
-                | TypedTerm::Pid(_)
-                | TypedTerm::Nil => Greater,
-                TypedTerm::List(rhs) => lhs.as_ref().cmp(rhs),
-                _ => Less,
-            },
-            TypedTerm::ResourceReference(lhs) => {
-                unimplemented!("ResourceReference {:?} cmp {:?}", lhs, other)
-            }
-        }
+        term_ordering::cmp(self, other)
     }
 }
-
-/// All terms in Erlang and Elixir are completely ordered.
-///
-/// number < atom < reference < function < port < pid < tuple < map < list < bitstring
-///
-/// > When comparing two numbers of different types (a number being either an integer or a float), a
-/// > conversion to the type with greater precision will always occur, unless the comparison
-/// > operator used is either === or !==. A float will be considered more precise than an integer,
-/// > unless the float is greater/less than +/-9007199254740992.0 respectively, at which point all
-/// > the significant figures of the float are to the left of the decimal point. This behavior
-/// > exists so that the comparison of large numbers remains transitive.
-/// >
-/// > The collection types are compared using the following rules:
-/// >
-/// > * Tuples are compared by size, then element by element.
-/// > * Maps are compared by size, then by keys in ascending term order, then by values in key
-/// >   order.   In the specific case of maps' key ordering, integers are always considered to be
-/// >   less than floats.
-/// > * Lists are compared element by element.
-/// > * Bitstrings are compared byte by byte, incomplete bytes are compared bit by bit.
-/// > -- https://hexdocs.pm/elixir/operators.html#term-ordering
 impl PartialOrd<TypedTerm> for TypedTerm {
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
         Some(self.cmp(other))
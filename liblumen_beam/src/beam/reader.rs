@@ -36,6 +36,8 @@ mod beam_file;
 #[cfg(test)]
 mod test;
 
+use std::io::Read;
+
 pub use self::beam_file::BeamFile;
 
 pub type RawBeamFile = BeamFile<chunk::RawChunk>;
@@ -48,6 +50,7 @@ pub enum ReadError {
     InvalidString(std::str::Utf8Error),
     UnexpectedMagicNumber([u8; 4]),
     UnexpectedFormType([u8; 4]),
+    UnexpectedPayloadSize(u32),
     UnexpectedChunk { id: chunk::Id, expected: chunk::Id },
 }
 
@@ -67,6 +70,11 @@ impl std::fmt::Display for ReadError {
                 r#"Unexpected from type {} (expected b"BEAM")"#,
                 bytes_to_str(t)
             ),
+            UnexpectedPayloadSize(size) => write!(
+                f,
+                "Unexpected payload size {} (expected at least 4 for the form type)",
+                size
+            ),
             UnexpectedChunk {
                 ref id,
                 ref expected,
@@ -102,6 +110,22 @@ impl std::convert::From<std::str::Utf8Error> for ReadError {
     }
 }
 
+/// Reads exactly `size` bytes, without reserving them up front, as `size` is read from the file
+/// and a corrupt one can be far larger than the file itself.
+fn read_bytes<R: Read>(reader: R, size: u32) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    reader.take(size as u64).read_to_end(&mut buf)?;
+
+    if buf.len() < size as usize {
+        Err(ReadError::FileError(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            format!("needed {} bytes, but only {} available", size, buf.len()),
+        )))
+    } else {
+        Ok(buf)
+    }
+}
+
 fn bytes_to_str(bytes: &[u8]) -> String {
     std::str::from_utf8(bytes)
         .map(|x| format!("b{:?}", x))
@@ -115,8 +115,12 @@ impl<C: Chunk> BeamFile<C> {
             return Err(ReadError::UnexpectedFormType(header.type_id));
         }
 
-        let mut buf = vec![0; (header.payload_size - 4) as usize];
-        reader.read_exact(&mut buf)?;
+        // The payload size includes the form type
+        let chunks_size = header
+            .payload_size
+            .checked_sub(4)
+            .ok_or(ReadError::UnexpectedPayloadSize(header.payload_size))?;
+        let buf = super::read_bytes(&mut reader, chunks_size)?;
 
         let mut chunks: HashMap<Id, C> = HashMap::new();
         let mut order: Vec<Id> = Vec::new();
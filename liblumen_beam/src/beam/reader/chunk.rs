@@ -60,8 +60,7 @@ pub trait Chunk {
         Self: Sized,
     {
         let header = auxiliary::Header::decode(&mut reader)?;
-        let mut buf = vec![0; header.data_size as usize];
-        reader.read_exact(&mut buf)?;
+        let buf = super::read_bytes(&mut reader, header.data_size)?;
         for _ in 0..auxiliary::padding_size(header.data_size) {
             reader.read_u8()?;
         }
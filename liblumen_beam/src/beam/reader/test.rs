@@ -9,6 +9,7 @@ use crate::beam::reader::chunk::StandardChunk;
 use crate::beam::reader::parts;
 use crate::beam::reader::BeamFile;
 use crate::beam::reader::RawBeamFile;
+use crate::beam::reader::ReadError;
 use crate::beam::reader::Result;
 use crate::beam::reader::StandardBeamFile;
 
@@ -228,6 +229,43 @@ fn encode_chunks() {
     assert_eq!(original, encoded);
 }
 
+#[test]
+fn truncated_file() {
+    let original = std::fs::read(test_file("test.beam")).unwrap();
+
+    // In the header, in a chunk header, and in chunk data
+    for len in &[6, 16, 40] {
+        match RawBeamFile::from_reader(std::io::Cursor::new(&original[..*len])) {
+            Err(ReadError::FileError(error)) => {
+                assert_eq!(std::io::ErrorKind::UnexpectedEof, error.kind())
+            }
+            other => panic!("expected truncated file error, got: {:?}", other),
+        }
+    }
+}
+
+#[test]
+fn malformed_file() {
+    let mut original = std::fs::read(test_file("test.beam")).unwrap();
+
+    // Payload size too small to include the form type
+    let mut malformed = original.clone();
+    malformed[4..8].copy_from_slice(&[0, 0, 0, 2]);
+    match RawBeamFile::from_reader(std::io::Cursor::new(&malformed)) {
+        Err(ReadError::UnexpectedPayloadSize(2)) => (),
+        other => panic!("expected unexpected payload size, got: {:?}", other),
+    }
+
+    // First chunk size far past the end of the file
+    original[16..20].copy_from_slice(&[0xFF, 0xFF, 0xFF, 0xF0]);
+    match RawBeamFile::from_reader(std::io::Cursor::new(&original)) {
+        Err(ReadError::FileError(error)) => {
+            assert_eq!(std::io::ErrorKind::UnexpectedEof, error.kind())
+        }
+        other => panic!("expected truncated chunk error, got: {:?}", other),
+    }
+}
+
 fn test_file(name: &str) -> PathBuf {
     let mut path = PathBuf::from("tests/testdata/reader");
     path.push(name);
@@ -342,7 +342,14 @@ pub struct Float {
 }
 impl std::fmt::Display for Float {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        // Erlang floats need digits on both sides of the `.`, including before an exponent
+        let formatted = format!("{:?}", self.value);
+        match formatted.find('e') {
+            Some(index) if !formatted[..index].contains('.') => {
+                write!(f, "{}.0{}", &formatted[..index], &formatted[index..])
+            }
+            _ => f.write_str(&formatted),
+        }
     }
 }
 impl From<f32> for Float {
@@ -28,6 +28,9 @@ pub enum DecodeError {
     #[fail(display = "unknown tag: '{}'", tag)]
     UnknownTag { tag: u8 },
 
+    #[fail(display = "unsupported tag: '{}'", tag)]
+    UnsupportedTag { tag: u8 },
+
     #[fail(display = "unexpected type! {} is not a {}", value, expected)]
     UnexpectedType { value: Term, expected: String },
 
@@ -120,7 +123,7 @@ impl<R: std::io::Read> Decoder<R> {
         let tag = self.reader.read_u8()?;
         match tag {
             COMPRESSED_TERM => self.decode_compressed_term(),
-            DISTRIBUTION_HEADER => unimplemented!(),
+            DISTRIBUTION_HEADER => Err(DecodeError::UnsupportedTag { tag }),
             _ => self.decode_term_with_tag(tag),
         }
     }
@@ -132,7 +135,7 @@ impl<R: std::io::Read> Decoder<R> {
         match tag {
             NEW_FLOAT_EXT => self.decode_new_float_ext(),
             BIT_BINARY_EXT => self.decode_bit_binary_ext(),
-            ATOM_CACHE_REF => unimplemented!(),
+            ATOM_CACHE_REF => Err(DecodeError::UnsupportedTag { tag }),
             SMALL_INTEGER_EXT => self.decode_small_integer_ext(),
             INTEGER_EXT => self.decode_integer_ext(),
             FLOAT_EXT => self.decode_float_ext(),
@@ -178,7 +181,7 @@ impl<R: std::io::Read> Decoder<R> {
     }
     fn decode_list_ext(&mut self) -> DecodeResult {
         let count = self.reader.read_u32::<BigEndian>()? as usize;
-        let mut elements = Vec::with_capacity(count);
+        let mut elements = Vec::with_capacity(auxiliary::capacity(count));
         for _ in 0..count {
             elements.push(self.decode_term()?);
         }
@@ -203,7 +206,7 @@ impl<R: std::io::Read> Decoder<R> {
     }
     fn decode_large_tuple_ext(&mut self) -> DecodeResult {
         let count = self.reader.read_u32::<BigEndian>()? as usize;
-        let mut elements = Vec::with_capacity(count);
+        let mut elements = Vec::with_capacity(auxiliary::capacity(count));
         for _ in 0..count {
             elements.push(self.decode_term()?);
         }
@@ -211,7 +214,7 @@ impl<R: std::io::Read> Decoder<R> {
     }
     fn decode_map_ext(&mut self) -> DecodeResult {
         let count = self.reader.read_u32::<BigEndian>()? as usize;
-        let mut entries = Vec::with_capacity(count);
+        let mut entries = Vec::with_capacity(auxiliary::capacity(count));
         for _ in 0..count {
             let k = self.decode_term()?;
             let v = self.decode_term()?;
@@ -221,16 +224,20 @@ impl<R: std::io::Read> Decoder<R> {
     }
     fn decode_binary_ext(&mut self) -> DecodeResult {
         let size = self.reader.read_u32::<BigEndian>()? as usize;
-        let mut buf = vec![0; size];
-        self.reader.read_exact(&mut buf)?;
+        let buf = auxiliary::read_bytes(&mut self.reader, size)?;
         Ok(Term::from(Binary::from(buf)))
     }
     fn decode_bit_binary_ext(&mut self) -> DecodeResult {
         let size = self.reader.read_u32::<BigEndian>()? as usize;
         let tail_bits_size = self.reader.read_u8()?;
-        let mut buf = vec![0; size];
-        self.reader.read_exact(&mut buf)?;
+        let mut buf = auxiliary::read_bytes(&mut self.reader, size)?;
         if !buf.is_empty() {
+            if tail_bits_size < 1 || 8 < tail_bits_size {
+                auxiliary::invalid_data_error::<()>(format!(
+                    "A tail bits size must be 1 to 8: value={}",
+                    tail_bits_size
+                ))?;
+            }
             let last = buf[size - 1] >> (8 - tail_bits_size);
             buf[size - 1] = last;
         }
@@ -298,7 +305,7 @@ impl<R: std::io::Read> Decoder<R> {
         let uniq = self
             .decode_term()
             .and_then(auxiliary::term_into_fix_integer)?;
-        let mut vars = Vec::with_capacity(num_free as usize);
+        let mut vars = Vec::with_capacity(auxiliary::capacity(num_free as usize));
         for _ in 0..num_free {
             vars.push(self.decode_term()?);
         }
@@ -325,7 +332,7 @@ impl<R: std::io::Read> Decoder<R> {
             .decode_term()
             .and_then(auxiliary::term_into_fix_integer)?;
         let pid = self.decode_term().and_then(auxiliary::term_into_pid)?;
-        let mut vars = Vec::with_capacity(num_free as usize);
+        let mut vars = Vec::with_capacity(auxiliary::capacity(num_free as usize));
         for _ in 0..num_free {
             vars.push(self.decode_term()?);
         }
@@ -374,9 +381,8 @@ impl<R: std::io::Read> Decoder<R> {
     fn decode_large_big_ext(&mut self) -> DecodeResult {
         let count = self.reader.read_u32::<BigEndian>()? as usize;
         let sign = self.reader.read_u8()?;
-        self.buf.resize(count, 0);
-        self.reader.read_exact(&mut self.buf)?;
-        let value = BigInt::from_bytes_le(auxiliary::byte_to_sign(sign)?, &self.buf);
+        let buf = auxiliary::read_bytes(&mut self.reader, count)?;
+        let value = BigInt::from_bytes_le(auxiliary::byte_to_sign(sign)?, &buf);
         Ok(Term::from(BigInteger { value }))
     }
     fn decode_atom_ext(&mut self) -> DecodeResult {
@@ -1,3 +1,4 @@
+use std::io::Read;
 use std::ops::Range;
 
 use num::bigint::Sign;
@@ -33,6 +34,26 @@ pub fn term_into_ranged_integer(t: Term, range: Range<i32>) -> Result<i32, Decod
         }
     })
 }
+/// Counts are read from the input, so a corrupt one must not reserve more than the input could
+/// plausibly hold.
+pub fn capacity(count: usize) -> usize {
+    const MAX_PREALLOCATED: usize = 1024;
+
+    count.min(MAX_PREALLOCATED)
+}
+/// Reads exactly `size` bytes without reserving them up front, for the same reason as `capacity`.
+pub fn read_bytes<R: Read>(reader: R, size: usize) -> std::io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    reader.take(size as u64).read_to_end(&mut buf)?;
+    if buf.len() < size {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::UnexpectedEof,
+            format!("needed {} bytes, but only {} available", size, buf.len()),
+        ))
+    } else {
+        Ok(buf)
+    }
+}
 pub fn invalid_data_error<T>(message: String) -> std::io::Result<T> {
     Err(std::io::Error::new(
         std::io::ErrorKind::InvalidData,
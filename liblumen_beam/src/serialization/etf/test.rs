@@ -97,7 +97,7 @@ fn integer_test() {
 #[test]
 fn float_test() {
     // Display
-    assert_eq!("123", Float::from(123.0).to_string());
+    assert_eq!("123.0", Float::from(123.0).to_string());
     assert_eq!("123.4", Float::from(123.4).to_string());
     assert_eq!("-123.4", Float::from(-123.4).to_string());
 
@@ -471,6 +471,20 @@ fn compressed_term_test() {
     );
 }
 
+#[test]
+fn malformed_term_test() {
+    // BINARY_EXT with fewer bytes than its size
+    assert!(Term::decode(Cursor::new(&[131, 109, 0, 0, 0, 3, 1, 2])).is_err());
+    // LIST_EXT with a count far past the end of the input
+    assert!(Term::decode(Cursor::new(&[131, 108, 255, 255, 255, 255, 97, 1])).is_err());
+    // BIT_BINARY_EXT with more than 8 tail bits
+    assert!(Term::decode(Cursor::new(&[131, 77, 0, 0, 0, 1, 9, 1])).is_err());
+    // ATOM_CACHE_REF is only valid with a distribution header
+    assert!(Term::decode(Cursor::new(&[131, 82, 0])).is_err());
+    // Unknown tag
+    assert!(Term::decode(Cursor::new(&[131, 255])).is_err());
+}
+
 fn encode(term: Term) -> Vec<u8> {
     let mut buf = Vec::new();
     term.encode(&mut buf).unwrap();
@@ -1,7 +1,12 @@
+pub mod codes;
+mod json;
+
+use std::fmt;
 use std::io::Write;
 use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 pub type DisplayConfig = libeir_diagnostics::term::Config;
 pub type DisplayStyle = libeir_diagnostics::term::DisplayStyle;
@@ -14,15 +19,55 @@ pub use libeir_diagnostics::{
 };
 pub use libeir_diagnostics::{Diagnostic, Label, LabelStyle, Severity};
 
+pub use self::codes::DiagnosticCode;
+
 use crate::error::{FatalError, Verbosity};
 
 #[derive(Debug, Clone)]
 pub struct DiagnosticsConfig {
     pub warnings_as_errors: bool,
     pub no_warn: bool,
+    pub error_format: ErrorFormat,
     pub display: DisplayConfig,
 }
 
+/// How diagnostics are written to the emitter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Rendered for people, with source snippets
+    Human,
+    /// One JSON object per line in the same shape as rustc's `--error-format=json`, for editors
+    /// and build tools
+    Json,
+}
+impl ErrorFormat {
+    pub const VARIANTS: &'static [&'static str] = &["human", "json"];
+}
+impl Default for ErrorFormat {
+    fn default() -> Self {
+        Self::Human
+    }
+}
+impl fmt::Display for ErrorFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Human => f.write_str("human"),
+            Self::Json => f.write_str("json"),
+        }
+    }
+}
+impl FromStr for ErrorFormat {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            _ => Err("valid values: human, json"),
+        }
+    }
+}
+
 pub trait Emitter {
     fn buffer(&self) -> Buffer;
     fn print(&self, buffer: &Buffer) -> std::io::Result<()>;
@@ -85,6 +130,43 @@ impl Emitter for NullEmitter {
     }
 }
 
+/// An emitter which holds on to everything printed to it until it is flushed
+///
+/// This is used to keep the output of concurrent tasks from interleaving, and to print
+/// it in a fixed order regardless of which task finishes first.
+pub struct CapturedEmitter {
+    emitter: Arc<dyn Emitter>,
+    captured: Mutex<Vec<Buffer>>,
+}
+impl CapturedEmitter {
+    pub fn new(emitter: Arc<dyn Emitter>) -> Self {
+        Self {
+            emitter,
+            captured: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Prints everything captured so far to the underlying emitter
+    pub fn flush(&self) -> std::io::Result<()> {
+        let captured = std::mem::take(&mut *self.captured.lock().unwrap());
+        for buffer in captured.iter() {
+            self.emitter.print(buffer)?;
+        }
+        Ok(())
+    }
+}
+impl Emitter for CapturedEmitter {
+    #[inline(always)]
+    fn buffer(&self) -> Buffer {
+        self.emitter.buffer()
+    }
+
+    fn print(&self, buffer: &Buffer) -> std::io::Result<()> {
+        self.captured.lock().unwrap().push(buffer.clone());
+        Ok(())
+    }
+}
+
 /// Construct an in-flight diagnostic
 pub struct InFlightDiagnostic<'h> {
     handler: &'h DiagnosticsHandler,
@@ -202,9 +284,10 @@ impl<'h> InFlightDiagnostic<'h> {
 pub struct DiagnosticsHandler {
     emitter: Arc<dyn Emitter>,
     codemap: Arc<CodeMap>,
-    err_count: AtomicUsize,
+    err_count: Arc<AtomicUsize>,
     warnings_as_errors: bool,
     no_warn: bool,
+    error_format: ErrorFormat,
     display: DisplayConfig,
 }
 // We can safely implement these traits for DiagnosticsHandler,
@@ -220,13 +303,32 @@ impl DiagnosticsHandler {
         Self {
             emitter,
             codemap,
-            err_count: AtomicUsize::new(0),
+            err_count: Arc::new(AtomicUsize::new(0)),
             warnings_as_errors: config.warnings_as_errors,
             no_warn: config.no_warn,
+            error_format: config.error_format,
             display: config.display,
         }
     }
 
+    /// Returns a handler with the same configuration as this one, whose output is held
+    /// until the returned emitter is flushed
+    ///
+    /// Errors reported via the new handler are counted by this one as well.
+    pub fn capture(&self) -> (Self, Arc<CapturedEmitter>) {
+        let captured = Arc::new(CapturedEmitter::new(self.emitter.clone()));
+        let handler = Self {
+            emitter: captured.clone(),
+            codemap: self.codemap.clone(),
+            err_count: self.err_count.clone(),
+            warnings_as_errors: self.warnings_as_errors,
+            no_warn: self.no_warn,
+            error_format: self.error_format,
+            display: self.display.clone(),
+        };
+        (handler, captured)
+    }
+
     pub fn lookup_file_id(&self, filename: impl Into<FileName>) -> Option<SourceId> {
         let filename = filename.into();
         self.codemap.get_file_id(&filename)
@@ -262,6 +364,18 @@ impl DiagnosticsHandler {
         }
     }
 
+    /// Emits a diagnostic with warning severity, subject to `--warnings-as-errors` and
+    /// `--no-warn`, like `warn`
+    pub fn emit_warning(&self, mut diagnostic: Diagnostic) {
+        if self.warnings_as_errors {
+            self.err_count.fetch_add(1, Ordering::Relaxed);
+            diagnostic.severity = Severity::Error;
+            self.emit(&diagnostic);
+        } else if !self.no_warn {
+            self.emit(&diagnostic);
+        }
+    }
+
     pub fn info(&self, message: impl Into<String>) {
         let info_color = self.display.styles.header(Severity::Help);
         let mut buffer = self.emitter.buffer();
@@ -319,7 +433,26 @@ impl DiagnosticsHandler {
         use libeir_diagnostics::term;
 
         let mut buffer = self.emitter.buffer();
-        term::emit(&mut buffer, &self.display, self.codemap.deref(), diagnostic).unwrap();
+
+        match self.error_format {
+            ErrorFormat::Human => {
+                term::emit(&mut buffer, &self.display, self.codemap.deref(), diagnostic).unwrap()
+            }
+            ErrorFormat::Json => {
+                let mut rendered = Buffer::no_color();
+                term::emit(
+                    &mut rendered,
+                    &self.display,
+                    self.codemap.deref(),
+                    diagnostic,
+                )
+                .unwrap();
+                let rendered = String::from_utf8_lossy(rendered.as_slice());
+                let json = json::to_json(self.codemap.deref(), diagnostic, &rendered);
+                writeln!(&mut buffer, "{}", json).unwrap();
+            }
+        }
+
         self.emitter.print(&buffer).unwrap();
     }
 }
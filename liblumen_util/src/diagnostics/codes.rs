@@ -0,0 +1,97 @@
+//! The registry of diagnostic codes, e.g. `L0001`, which identify the kind of a diagnostic
+//! independently of its message, and have an extended explanation shown by `lumen explain`.
+//!
+//! Codes are stable: once assigned, a code is never reused for a different diagnostic, so
+//! retired codes stay in the registry.
+
+/// A registered diagnostic code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiagnosticCode {
+    pub code: &'static str,
+    /// The extended description, in Markdown, with examples
+    pub explanation: &'static str,
+}
+
+/// A call to a function which is not defined by its module, which is part of the build
+pub const UNDEFINED_FUNCTION: DiagnosticCode = DiagnosticCode {
+    code: "L0001",
+    explanation: r#"A call to a function which its module does not define.
+
+The module is part of the build, but does not define a function with the
+given name and arity, so the call will always fail with `undef`.
+
+Erroneous code example:
+
+```erlang
+-module(a).
+-export([run/0]).
+
+run() -> b:greet("world", informal).
+
+-module(b).
+-export([greet/1]).
+
+greet(Name) -> io:format("hello ~s~n", [Name]).
+```
+
+Usually the wrong number of arguments is passed, so check the arities of the
+functions which are defined, listed in the note. This warning is only
+reported by `--analyze`.
+"#,
+};
+
+/// An arithmetic operator applied to a literal which is not a number
+pub const ARITHMETIC_ON_LITERAL: DiagnosticCode = DiagnosticCode {
+    code: "L0002",
+    explanation: r#"An arithmetic operator is applied to a literal which is not a number.
+
+The arithmetic operators only accept integers and floats, so this will always
+fail with `badarith`.
+
+Erroneous code example:
+
+```erlang
+next(N) -> N + one.
+```
+
+Atoms, strings, binaries, tuples and maps must be converted to numbers first,
+e.g. with `list_to_integer/1`. This warning is only reported by `--analyze`.
+"#,
+};
+
+/// An arithmetic operator applied to the result of a function which always returns an atom
+pub const ARITHMETIC_ON_ATOM_RESULT: DiagnosticCode = DiagnosticCode {
+    code: "L0003",
+    explanation: r#"An arithmetic operator is applied to the result of a function which
+always returns an atom.
+
+Every clause of the function returns the same atom, such as `ok`, so this
+will always fail with `badarith`.
+
+Erroneous code example:
+
+```erlang
+log(Message) ->
+    io:format("~s~n", [Message]),
+    ok.
+
+count(Messages) ->
+    lists:foldl(fun (M, N) -> log(M) + N end, 0, Messages).
+```
+
+The function's result is probably not the value intended. This warning is
+only reported by `--analyze`.
+"#,
+};
+
+/// All registered codes, in order
+pub const CODES: &[DiagnosticCode] = &[
+    UNDEFINED_FUNCTION,
+    ARITHMETIC_ON_LITERAL,
+    ARITHMETIC_ON_ATOM_RESULT,
+];
+
+/// Returns the registered code named by `code`, ignoring case
+pub fn lookup(code: &str) -> Option<&'static DiagnosticCode> {
+    CODES.iter().find(|c| c.code.eq_ignore_ascii_case(code))
+}
@@ -0,0 +1,102 @@
+use crate::json::Json;
+
+use super::{codes, CodeMap, Diagnostic, Files, Label, LabelStyle, Severity, SourceId};
+
+/// Serializes `diagnostic` as a single line of JSON in the same shape as rustc's
+/// `--error-format=json`, so that tools that already consume rustc's diagnostics can consume ours.
+///
+/// `rendered` is the diagnostic as it would be displayed with `--error-format=human`.
+pub fn to_json(codemap: &CodeMap, diagnostic: &Diagnostic, rendered: &str) -> String {
+    let code = match diagnostic.code {
+        Some(ref code) => Json::object(vec![
+            ("code", code.as_str().into()),
+            (
+                "explanation",
+                codes::lookup(code).map_or(Json::Null, |code| code.explanation.into()),
+            ),
+        ]),
+        None => Json::Null,
+    };
+    let spans = diagnostic
+        .labels
+        .iter()
+        .filter_map(|label| span(codemap, label))
+        .collect();
+    let children = diagnostic
+        .notes
+        .iter()
+        .map(|note| {
+            Json::object(vec![
+                ("message", note.as_str().into()),
+                ("code", Json::Null),
+                ("level", "note".into()),
+                ("spans", Json::Array(Vec::new())),
+                ("children", Json::Array(Vec::new())),
+                ("rendered", Json::Null),
+            ])
+        })
+        .collect();
+
+    Json::object(vec![
+        ("$message_type", "diagnostic".into()),
+        ("message", diagnostic.message.as_str().into()),
+        ("code", code),
+        ("level", level(diagnostic.severity).into()),
+        ("spans", Json::Array(spans)),
+        ("children", Json::Array(children)),
+        ("rendered", rendered.into()),
+    ])
+    .to_string()
+}
+
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Bug => "error: internal compiler error",
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+        Severity::Help => "help",
+    }
+}
+
+/// Lines and columns are 1-based, and columns count characters, not bytes.  Labels in files that
+/// are no longer in the `codemap` are skipped.
+fn span(codemap: &CodeMap, label: &Label) -> Option<Json> {
+    let file_id: SourceId = label.file_id;
+    let name = codemap.name(file_id)?;
+    let source = codemap.source(file_id)?;
+    let (line_start, column_start) =
+        location(codemap, file_id, source.as_ref(), label.range.start)?;
+    let (line_end, column_end) = location(codemap, file_id, source.as_ref(), label.range.end)?;
+    let label_message = if label.message.is_empty() {
+        Json::Null
+    } else {
+        label.message.as_str().into()
+    };
+
+    Some(Json::object(vec![
+        ("file_name", name.to_string().into()),
+        ("byte_start", label.range.start.into()),
+        ("byte_end", label.range.end.into()),
+        ("line_start", line_start.into()),
+        ("line_end", line_end.into()),
+        ("column_start", column_start.into()),
+        ("column_end", column_end.into()),
+        ("is_primary", (label.style == LabelStyle::Primary).into()),
+        ("label", label_message),
+        ("suggested_replacement", Json::Null),
+    ]))
+}
+
+fn location(
+    codemap: &CodeMap,
+    file_id: SourceId,
+    source: &str,
+    byte_index: usize,
+) -> Option<(usize, usize)> {
+    let line_index = codemap.line_index(file_id, byte_index)?;
+    let line_range = codemap.line_range(file_id, line_index)?;
+    let column = source.get(line_range.start..byte_index)?.chars().count();
+
+    Some((line_index + 1, column + 1))
+}
@@ -0,0 +1,342 @@
+//! The subset of JSON needed for `--error-format=json` diagnostics and the JSON-RPC messages of
+//! `lumen lsp`
+
+use std::fmt::{self, Display, Write};
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+use anyhow::{anyhow, bail};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn parse(s: &str) -> anyhow::Result<Self> {
+        let mut parser = Parser {
+            s,
+            chars: s.char_indices().peekable(),
+        };
+        let json = parser.value()?;
+        parser.skip_whitespace();
+
+        match parser.chars.next() {
+            None => Ok(json),
+            Some((index, c)) => Err(anyhow!("unexpected `{}` at {} after value", c, index)),
+        }
+    }
+
+    pub fn object(pairs: Vec<(&str, Json)>) -> Self {
+        Self::Object(
+            pairs
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Self::Object(pairs) => pairs
+                .iter()
+                .find(|(pair_key, _)| pair_key == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Follows `keys` through nested objects
+    pub fn pointer(&self, keys: &[&str]) -> Option<&Json> {
+        keys.iter().try_fold(self, |json, key| json.get(key))
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Self::Number(n) if 0.0 <= *n && n.fract() == 0.0 => Some(*n as usize),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Self::Array(elements) => Some(elements),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Null => f.write_str("null"),
+            Self::Bool(b) => write!(f, "{}", b),
+            Self::Number(n) => {
+                if n.fract() == 0.0 && n.abs() < 1e15 {
+                    write!(f, "{}", *n as i64)
+                } else {
+                    write!(f, "{}", n)
+                }
+            }
+            Self::String(s) => write_string(f, s),
+            Self::Array(elements) => {
+                f.write_char('[')?;
+                for (index, element) in elements.iter().enumerate() {
+                    if 0 < index {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{}", element)?;
+                }
+                f.write_char(']')
+            }
+            Self::Object(pairs) => {
+                f.write_char('{')?;
+                for (index, (key, value)) in pairs.iter().enumerate() {
+                    if 0 < index {
+                        f.write_char(',')?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Self::String(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Self::String(s)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(n: usize) -> Self {
+        Self::Number(n as f64)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(b: bool) -> Self {
+        Self::Bool(b)
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
+
+struct Parser<'s> {
+    s: &'s str,
+    chars: Peekable<CharIndices<'s>>,
+}
+
+impl<'s> Parser<'s> {
+    fn value(&mut self) -> anyhow::Result<Json> {
+        self.skip_whitespace();
+
+        match self.chars.peek().cloned() {
+            Some((_, '{')) => self.object(),
+            Some((_, '[')) => self.array(),
+            Some((_, '"')) => self.string().map(Json::String),
+            Some((_, 't')) => self.literal("true", Json::Bool(true)),
+            Some((_, 'f')) => self.literal("false", Json::Bool(false)),
+            Some((_, 'n')) => self.literal("null", Json::Null),
+            Some((index, c)) if c == '-' || c.is_ascii_digit() => self.number(index),
+            Some((index, c)) => Err(anyhow!("unexpected `{}` at {}", c, index)),
+            None => Err(anyhow!("unexpected end of input")),
+        }
+    }
+
+    fn object(&mut self) -> anyhow::Result<Json> {
+        self.expect('{')?;
+        let mut pairs = Vec::new();
+        self.skip_whitespace();
+
+        if self.next_if('}') {
+            return Ok(Json::Object(pairs));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let key = self.string()?;
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.value()?;
+            pairs.push((key, value));
+            self.skip_whitespace();
+
+            if !self.next_if(',') {
+                self.expect('}')?;
+                break Ok(Json::Object(pairs));
+            }
+        }
+    }
+
+    fn array(&mut self) -> anyhow::Result<Json> {
+        self.expect('[')?;
+        let mut elements = Vec::new();
+        self.skip_whitespace();
+
+        if self.next_if(']') {
+            return Ok(Json::Array(elements));
+        }
+
+        loop {
+            elements.push(self.value()?);
+            self.skip_whitespace();
+
+            if !self.next_if(',') {
+                self.expect(']')?;
+                break Ok(Json::Array(elements));
+            }
+        }
+    }
+
+    fn string(&mut self) -> anyhow::Result<String> {
+        self.expect('"')?;
+        let mut s = String::new();
+
+        loop {
+            match self.chars.next() {
+                Some((_, '"')) => break Ok(s),
+                Some((_, '\\')) => match self.chars.next() {
+                    Some((_, '"')) => s.push('"'),
+                    Some((_, '\\')) => s.push('\\'),
+                    Some((_, '/')) => s.push('/'),
+                    Some((_, 'b')) => s.push('\u{8}'),
+                    Some((_, 'f')) => s.push('\u{c}'),
+                    Some((_, 'n')) => s.push('\n'),
+                    Some((_, 'r')) => s.push('\r'),
+                    Some((_, 't')) => s.push('\t'),
+                    Some((_, 'u')) => {
+                        let high = self.hex4()?;
+
+                        let code_point = if (0xD800..0xDC00).contains(&high) {
+                            // A surrogate pair encodes a code point outside the BMP
+                            self.expect('\\')?;
+                            self.expect('u')?;
+                            let low = self.hex4()?;
+                            0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF)
+                        } else {
+                            high
+                        };
+
+                        s.push(std::char::from_u32(code_point).unwrap_or('\u{FFFD}'));
+                    }
+                    Some((index, c)) => bail!("invalid escape `\\{}` at {}", c, index),
+                    None => bail!("unexpected end of input in string"),
+                },
+                Some((_, c)) => s.push(c),
+                None => bail!("unexpected end of input in string"),
+            }
+        }
+    }
+
+    fn hex4(&mut self) -> anyhow::Result<u32> {
+        let mut value = 0;
+
+        for _ in 0..4 {
+            match self.chars.next() {
+                Some((_, c)) if c.is_ascii_hexdigit() => {
+                    value = value * 16 + c.to_digit(16).unwrap()
+                }
+                Some((index, c)) => bail!("invalid hex digit `{}` at {}", c, index),
+                None => bail!("unexpected end of input in \\u escape"),
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn number(&mut self, start: usize) -> anyhow::Result<Json> {
+        let mut end = start;
+
+        while let Some((index, c)) = self.chars.peek().cloned() {
+            if c.is_ascii_digit() || c == '-' || c == '+' || c == '.' || c == 'e' || c == 'E' {
+                end = index + c.len_utf8();
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+
+        let number = &self.s[start..end];
+
+        number
+            .parse()
+            .map(Json::Number)
+            .map_err(|_| anyhow!("invalid number `{}` at {}", number, start))
+    }
+
+    fn literal(&mut self, literal: &str, json: Json) -> anyhow::Result<Json> {
+        for expected in literal.chars() {
+            self.expect(expected)?;
+        }
+
+        Ok(json)
+    }
+
+    fn expect(&mut self, expected: char) -> anyhow::Result<()> {
+        match self.chars.next() {
+            Some((_, c)) if c == expected => Ok(()),
+            Some((index, c)) => Err(anyhow!(
+                "expected `{}`, but got `{}` at {}",
+                expected,
+                c,
+                index
+            )),
+            None => Err(anyhow!("expected `{}`, but got end of input", expected)),
+        }
+    }
+
+    fn next_if(&mut self, expected: char) -> bool {
+        match self.chars.peek() {
+            Some((_, c)) if *c == expected => {
+                self.chars.next();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some((_, c)) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+    }
+}
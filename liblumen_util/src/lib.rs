@@ -6,6 +6,7 @@ pub mod diagnostics;
 pub mod error;
 pub mod ffi;
 pub mod fs;
+pub mod json;
 pub mod mem;
 pub mod seq;
 pub mod threading;
@@ -1,25 +1,112 @@
-use std::panic;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
 use std::thread;
 
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// A fixed-size pool of worker threads, which run jobs from a shared queue
+///
+/// Jobs are started in the order they were spawned, by whichever worker becomes idle
+/// first, so a long-running job never holds up the jobs behind it while other workers
+/// are free.
+pub struct ThreadPool {
+    queue: Option<mpsc::Sender<Job>>,
+    workers: Vec<thread::JoinHandle<()>>,
+}
+impl ThreadPool {
+    /// Creates a new pool with `threads` workers, named `{name}-{index}`
+    pub fn new(name: &str, threads: usize) -> Self {
+        let (tx, rx) = mpsc::channel::<Job>();
+        let rx = Arc::new(Mutex::new(rx));
+
+        let workers = (0..threads.max(1))
+            .map(|index| {
+                let rx = rx.clone();
+                thread::Builder::new()
+                    .name(format!("{}-{}", name, index))
+                    .spawn(move || loop {
+                        // The lock is only held while waiting for a job, not while running it
+                        let job = match rx.lock().unwrap().recv() {
+                            Ok(job) => job,
+                            // The pool was dropped
+                            Err(_) => return,
+                        };
+                        job();
+                    })
+                    .expect("failed to spawn worker thread")
+            })
+            .collect();
+
+        Self {
+            queue: Some(tx),
+            workers,
+        }
+    }
+
+    /// Returns the number of worker threads in this pool
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Queues `f` to run on the next idle worker
+    ///
+    /// The returned handle can be used to wait for the result with `join`
+    pub fn spawn<F, R>(&self, f: F) -> JoinHandle<R>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let job = Box::new(move || {
+            // Panics are caught so that they don't take down the worker, and are
+            // propagated to whoever joins the job instead
+            let result = panic::catch_unwind(AssertUnwindSafe(f));
+            // If the handle was dropped, nobody is interested in the result
+            tx.send(result).ok();
+        });
+        self.queue.as_ref().unwrap().send(job).unwrap();
+        JoinHandle(rx)
+    }
+}
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // Closing the queue causes each worker to exit once it is drained
+        self.queue.take();
+        for worker in self.workers.drain(..) {
+            worker.join().ok();
+        }
+    }
+}
+
+/// A handle to a job spawned on a `ThreadPool`
+pub struct JoinHandle<R>(mpsc::Receiver<thread::Result<R>>);
+impl<R> JoinHandle<R> {
+    /// Blocks until the job completes
+    ///
+    /// If the job panicked, the panic payload is returned as an error, as with `std::thread`
+    pub fn join(self) -> thread::Result<R> {
+        self.0.recv().unwrap()
+    }
+}
+
 pub fn with_default_thread_pool<F, R>(f: F) -> R
 where
-    F: FnOnce() -> R + Send,
-    R: Send,
+    F: FnOnce(&ThreadPool) -> R,
 {
     // the 1 here is duplicating code in config.opts.debugging_opts.threads
-    // which also defaults to 1; it ultimately doesn't matter as the default
-    // isn't threaded, and just ignores this parameter
+    // which also defaults to 1
     spawn_thread_pool(1, f)
 }
 
-pub fn spawn_thread_pool<F, R>(_threads: usize, f: F) -> R
+/// Runs `f` with a pool of `threads` workers, which is shut down when `f` returns
+pub fn spawn_thread_pool<F, R>(threads: usize, f: F) -> R
 where
-    F: FnOnce() -> R + Send,
-    R: Send,
+    F: FnOnce(&ThreadPool) -> R,
 {
-    let builder = thread::Builder::new().name("lumen".to_string());
+    let pool = ThreadPool::new("lumen", threads);
 
-    scoped_thread(builder, || f())
+    f(&pool)
 }
 
 pub fn scoped_thread<F, R>(builder: thread::Builder, f: F) -> R
@@ -0,0 +1,120 @@
+//! Binaries
+//!
+//! A binary from `enif_alloc_binary` owns its bytes until it is released or turned into a term
+//! with `enif_make_binary`.  A binary from `enif_inspect_binary` borrows the term's bytes, so it
+//! must not be released.
+
+use core::ffi::c_void;
+use core::ptr;
+
+use libc::c_int;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::env::{nif_term, term, ErlNifEnv};
+use crate::sys::*;
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_alloc_binary(size: usize, bin: *mut ErlNifBinary) -> c_int {
+    let byte_vec = vec![0; size].into_boxed_slice();
+    let data = Box::into_raw(Box::new(byte_vec));
+
+    *bin = ErlNifBinary {
+        size,
+        data: (*data).as_mut_ptr(),
+        ref_bin: data as *mut c_void,
+        __spare__: [ptr::null_mut(); 2],
+    };
+
+    1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_realloc_binary(bin: *mut ErlNifBinary, size: usize) -> c_int {
+    let bin = &mut *bin;
+    let mut byte_vec = vec![0; size];
+    let preserved = bin.size.min(size);
+    byte_vec[..preserved].copy_from_slice(core::slice::from_raw_parts(bin.data, preserved));
+
+    enif_release_binary(bin);
+    let data = Box::into_raw(Box::new(byte_vec.into_boxed_slice()));
+    bin.size = size;
+    bin.data = (*data).as_mut_ptr();
+    bin.ref_bin = data as *mut c_void;
+
+    1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_release_binary(bin: *mut ErlNifBinary) {
+    let bin = &mut *bin;
+
+    if !bin.ref_bin.is_null() {
+        drop(Box::from_raw(bin.ref_bin as *mut Box<[u8]>));
+        bin.ref_bin = ptr::null_mut();
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_inspect_binary(
+    env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    bin: *mut ErlNifBinary,
+) -> c_int {
+    let env = &mut *env;
+    let term = self::term(term);
+
+    let (data, size) = match term.decode() {
+        Ok(TypedTerm::HeapBinary(heap_binary)) => {
+            let bytes = heap_binary.as_bytes();
+
+            (bytes.as_ptr(), bytes.len())
+        }
+        Ok(TypedTerm::ProcBin(process_binary)) => {
+            let bytes = process_binary.as_bytes();
+
+            (bytes.as_ptr(), bytes.len())
+        }
+        Ok(TypedTerm::BinaryLiteral(binary_literal)) => {
+            let bytes = binary_literal.as_bytes();
+
+            (bytes.as_ptr(), bytes.len())
+        }
+        Ok(TypedTerm::SubBinary(subbinary)) if subbinary.is_binary() => {
+            if subbinary.is_aligned() {
+                let bytes = subbinary.as_bytes_unchecked();
+
+                (bytes.as_ptr(), bytes.len())
+            } else {
+                // Unaligned bytes can't be borrowed, so borrow a copy that lives as long as `env`
+                env.byte_vecs.push(subbinary.full_byte_iter().collect());
+                let bytes = env.byte_vecs.last().unwrap();
+
+                (bytes.as_ptr(), bytes.len())
+            }
+        }
+        _ => return 0,
+    };
+
+    *bin = ErlNifBinary {
+        size,
+        data: data as *mut u8,
+        ref_bin: ptr::null_mut(),
+        __spare__: [ptr::null_mut(); 2],
+    };
+
+    1
+}
+
+/// Makes a binary term from `bin`, which the term now owns, so `bin` must not be released.
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_binary(
+    env: *mut ErlNifEnv,
+    bin: *mut ErlNifBinary,
+) -> ERL_NIF_TERM {
+    let bytes = core::slice::from_raw_parts((*bin).data, (*bin).size);
+    let binary = (*env).process().binary_from_bytes(bytes);
+    enif_release_binary(bin);
+
+    nif_term(binary)
+}
@@ -0,0 +1,181 @@
+use core::ffi::c_void;
+use core::mem;
+use core::ptr;
+
+use std::sync::Arc;
+
+use anyhow::*;
+use libc::c_int;
+
+use liblumen_alloc::erts::exception::{self, Exception};
+use liblumen_alloc::erts::process::gc::RootSet;
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::CloneToProcess;
+
+use lumen_rt_core::process::spawn::options::Options;
+
+use crate::library::Library;
+use crate::sys::ERL_NIF_TERM;
+
+/// The environment that terms passed to and created by NIFs belong to.
+///
+/// A process-bound environment allocates on the calling process's heap and is only valid for the
+/// duration of the NIF call.  A process-independent environment from `enif_alloc_env` owns a heap
+/// of its own, so its terms can be kept between calls and sent with `enif_send`.
+pub struct ErlNifEnv {
+    heap: Heap,
+    library: Option<Arc<Library>>,
+    exception: Option<Exception>,
+    /// Copies of unaligned binaries made by `enif_inspect_binary`, which must live as long as the
+    /// environment's terms.
+    pub(crate) byte_vecs: Vec<Vec<u8>>,
+}
+
+enum Heap {
+    Process(*const Process),
+    Independent(Process),
+}
+
+impl ErlNifEnv {
+    pub(crate) fn for_process(process: &Process, library: Arc<Library>) -> Self {
+        Self {
+            heap: Heap::Process(process),
+            library: Some(library),
+            exception: None,
+            byte_vecs: Default::default(),
+        }
+    }
+
+    pub(crate) fn independent() -> anyhow::Result<Self> {
+        let process = Options::default()
+            .spawn(None, Atom::from_str("erlang"), Atom::from_str("nif_env"), 0)
+            .map_err(|alloc| anyhow!("could not allocate environment heap: {}", alloc))?;
+
+        Ok(Self {
+            heap: Heap::Independent(process),
+            library: None,
+            exception: None,
+            byte_vecs: Default::default(),
+        })
+    }
+
+    pub(crate) fn process(&self) -> &Process {
+        match &self.heap {
+            Heap::Process(process) => unsafe { &**process },
+            Heap::Independent(process) => process,
+        }
+    }
+
+    pub(crate) fn is_process_bound(&self) -> bool {
+        match self.heap {
+            Heap::Process(_) => true,
+            Heap::Independent(_) => false,
+        }
+    }
+
+    pub(crate) fn library(&self) -> Option<&Arc<Library>> {
+        self.library.as_ref()
+    }
+
+    /// Records the exception the NIF raises when it returns.
+    pub(crate) fn raise(&mut self, exception: Exception) -> ERL_NIF_TERM {
+        self.exception = Some(exception);
+
+        // Like the BEAM, the NIF must return the term from `enif_make_badarg` or
+        // `enif_raise_exception`, so return a term that can't be mistaken for a result.
+        Term::NONE.as_usize()
+    }
+
+    pub(crate) fn take_exception(&mut self) -> Option<Exception> {
+        self.exception.take()
+    }
+
+    fn clear(&mut self) {
+        if let Heap::Independent(process) = &self.heap {
+            let _ = process.garbage_collect(0, RootSet::default());
+        }
+
+        self.byte_vecs.clear();
+    }
+}
+
+/// Converts a term passed in by a NIF.
+pub(crate) fn term(nif_term: ERL_NIF_TERM) -> Term {
+    unsafe { mem::transmute::<usize, Term>(nif_term) }
+}
+
+/// Converts a term to pass to a NIF.
+pub(crate) fn nif_term(term: Term) -> ERL_NIF_TERM {
+    term.as_usize()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_alloc_env() -> *mut ErlNifEnv {
+    match ErlNifEnv::independent() {
+        Ok(env) => Box::into_raw(Box::new(env)),
+        Err(error) => {
+            log::error!("{:?}", error);
+
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_free_env(env: *mut ErlNifEnv) {
+    if !env.is_null() {
+        drop(Box::from_raw(env));
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_clear_env(env: *mut ErlNifEnv) {
+    (*env).clear();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_priv_data(env: *mut ErlNifEnv) -> *mut c_void {
+    match (*env).library() {
+        Some(library) => library.priv_data(),
+        None => ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_badarg(env: *mut ErlNifEnv) -> ERL_NIF_TERM {
+    (*env).raise(
+        exception::badarg(
+            Trace::capture(),
+            Some(anyhow!("NIF called enif_make_badarg").into()),
+        )
+        .into(),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_raise_exception(
+    env: *mut ErlNifEnv,
+    reason: ERL_NIF_TERM,
+) -> ERL_NIF_TERM {
+    let env = &mut *env;
+    // The reason may be in another environment, so it must outlive the NIF call on the caller's
+    // heap like any other result.
+    let reason = term(reason).clone_to_process(env.process());
+
+    env.raise(
+        exception::error(
+            reason,
+            None,
+            Trace::capture(),
+            Some(anyhow!("NIF called enif_raise_exception").into()),
+        )
+        .into(),
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_exception(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_none() as c_int
+}
@@ -0,0 +1,42 @@
+//! The subset of [erl_nif](http://erlang.org/doc/man/erl_nif.html) that lets NIF libraries
+//! written for the BEAM be used from Lumen.
+//!
+//! NIF libraries are compiled against OTP's `erl_nif.h` as usual and loaded with
+//! `erlang:load_nif/2`.  As Lumen compiles Erlang ahead of time, the stubs that `load_nif` would
+//! replace on the BEAM are still called, so they must call `lumen:call_nif/3` instead of
+//! `erlang:nif_error/1`:
+//!
+//! ```erlang
+//! add(X, Y) -> lumen:call_nif(?MODULE, add, [X, Y]).
+//! ```
+//!
+//! The `enif_*` functions are resolved against the executable, which exports them.
+//!
+//! Supported:
+//! * Terms: atoms, integers, floats, strings, tuples (including the variadic `enif_make_tuple`),
+//!   lists (including the variadic `enif_make_list`), references, and copying between
+//!   environments
+//! * Binaries: `enif_alloc_binary`, `enif_realloc_binary`, `enif_release_binary`,
+//!   `enif_inspect_binary`, and `enif_make_binary`
+//! * Environments: process-bound environments for calls and `enif_alloc_env` for
+//!   process-independent ones
+//! * Resource objects with destructors
+//! * `enif_self`, `enif_get_local_pid`, and `enif_send`
+//! * Exceptions with `enif_make_badarg` and `enif_raise_exception`
+//! * Memory with `enif_alloc`, `enif_realloc`, and `enif_free`
+//!
+//! Maps, monitors, timeslices, and dirty schedulers are not supported yet.
+
+// `enif_make_tuple` and `enif_make_list` are variadic
+#![feature(c_variadic)]
+
+mod binary;
+mod env;
+mod library;
+mod memory;
+mod process;
+mod resource;
+pub mod sys;
+mod term;
+
+pub use library::{get, load, LoadError, Nif};
@@ -0,0 +1,225 @@
+//! Loading NIF libraries and calling their functions
+
+use core::ffi::c_void;
+#[cfg(unix)]
+use core::ptr;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+#[cfg(unix)]
+use std::ffi::CStr;
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+use libc::c_int;
+use thiserror::Error;
+
+use liblumen_core::locks::RwLock;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
+
+use crate::env::{nif_term, term, ErlNifEnv};
+use crate::sys::*;
+
+pub struct Library {
+    module: Atom,
+    priv_data: AtomicPtr<c_void>,
+}
+
+impl Library {
+    pub(crate) fn priv_data(&self) -> *mut c_void {
+        self.priv_data.load(Ordering::SeqCst)
+    }
+}
+
+/// A function from a loaded NIF library
+#[derive(Clone)]
+pub struct Nif {
+    library: Arc<Library>,
+    fptr: ErlNifFptr,
+}
+
+impl Nif {
+    /// Calls the NIF on `process`'s scheduler, so it should return quickly.  Dirty NIFs are run the
+    /// same way, as there are no dirty schedulers.
+    pub fn call(&self, process: &Process, arguments: &[Term]) -> exception::Result<Term> {
+        let mut env = ErlNifEnv::for_process(process, self.library.clone());
+        let argv: Vec<ERL_NIF_TERM> = arguments
+            .iter()
+            .map(|argument| nif_term(*argument))
+            .collect();
+
+        let result = unsafe { (self.fptr)(&mut env, argv.len() as c_int, argv.as_ptr()) };
+
+        match env.take_exception() {
+            Some(exception) => Err(exception),
+            None => Ok(term(result)),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("Failed to load NIF library {path}: '{message}'")]
+    LoadFailed { path: String, message: String },
+    #[error("Library {path} has no nif_init function")]
+    NoInit { path: String },
+    #[error(
+        "Library {path} is NIF version {major}.{minor}, but only 2.15 or earlier is supported"
+    )]
+    Version {
+        path: String,
+        major: c_int,
+        minor: c_int,
+    },
+    #[error("NIF library already loaded for module ({module}) (reload disallowed since OTP 20)")]
+    Reload { module: Atom },
+    #[error("Library {path} load function failed with {code}")]
+    Load { path: String, code: c_int },
+    #[error("NIF libraries can't be loaded on this target")]
+    NotSupported,
+}
+
+impl LoadError {
+    /// The reason in `{error, {Reason, Text}}` returned by `erlang:load_nif/2`
+    pub fn reason(&self) -> &'static str {
+        match self {
+            LoadError::LoadFailed { .. } => "load_failed",
+            LoadError::NoInit { .. } | LoadError::Version { .. } => "bad_lib",
+            LoadError::Reload { .. } => "reload",
+            LoadError::Load { .. } => "load",
+            LoadError::NotSupported => "notsup",
+        }
+    }
+}
+
+pub fn get(module_function_arity: &ModuleFunctionArity) -> Option<Nif> {
+    RW_LOCK_NIF_BY_MODULE_FUNCTION_ARITY
+        .read()
+        .get(module_function_arity)
+        .cloned()
+}
+
+/// Loads the NIF library at `path`, which is without the `.so` extension like on the BEAM, and
+/// returns the module that its functions belong to.
+///
+/// `load_info` is passed to the library's `load` function, which runs on `process`.
+#[cfg(unix)]
+pub fn load(process: &Process, path: &str, load_info: Term) -> Result<Atom, LoadError> {
+    use std::ffi::CString;
+
+    let file = CString::new(format!("{}.so", path)).map_err(|_| LoadError::LoadFailed {
+        path: path.to_string(),
+        message: "path contains NUL".to_string(),
+    })?;
+
+    // Libraries are never closed, as their resources and the terms that refer to them may outlive
+    // the module.
+    let handle = unsafe { libc::dlopen(file.as_ptr(), libc::RTLD_NOW) };
+
+    if handle.is_null() {
+        return Err(LoadError::LoadFailed {
+            path: path.to_string(),
+            message: dlerror(),
+        });
+    }
+
+    let nif_init = unsafe { libc::dlsym(handle, b"nif_init\0".as_ptr() as *const libc::c_char) };
+
+    if nif_init.is_null() {
+        return Err(LoadError::NoInit {
+            path: path.to_string(),
+        });
+    }
+
+    let nif_init: unsafe extern "C" fn() -> *const ErlNifEntry =
+        unsafe { core::mem::transmute(nif_init) };
+    let entry = unsafe { &*nif_init() };
+
+    if entry.major != ERL_NIF_MAJOR_VERSION || ERL_NIF_MINOR_VERSION < entry.minor {
+        return Err(LoadError::Version {
+            path: path.to_string(),
+            major: entry.major,
+            minor: entry.minor,
+        });
+    }
+
+    let module = Atom::from_str(unsafe { CStr::from_ptr(entry.name) }.to_string_lossy());
+    let mut nif_by_module_function_arity = RW_LOCK_NIF_BY_MODULE_FUNCTION_ARITY.write();
+
+    if nif_by_module_function_arity
+        .keys()
+        .any(|module_function_arity| module_function_arity.module == module)
+    {
+        return Err(LoadError::Reload { module });
+    }
+
+    let library = Arc::new(Library {
+        module,
+        priv_data: AtomicPtr::new(ptr::null_mut()),
+    });
+
+    if let Some(load) = entry.load {
+        let mut env = ErlNifEnv::for_process(process, library.clone());
+        let mut priv_data = ptr::null_mut();
+        let code = unsafe { load(&mut env, &mut priv_data, nif_term(load_info)) };
+
+        if code != 0 {
+            return Err(LoadError::Load {
+                path: path.to_string(),
+                code,
+            });
+        }
+
+        library.priv_data.store(priv_data, Ordering::SeqCst);
+    }
+
+    let funcs = unsafe { core::slice::from_raw_parts(entry.funcs, entry.num_of_funcs as usize) };
+
+    for func in funcs {
+        let function = Atom::from_str(unsafe { CStr::from_ptr(func.name) }.to_string_lossy());
+        let module_function_arity = ModuleFunctionArity {
+            module: library.module,
+            function,
+            arity: func.arity as u8,
+        };
+
+        nif_by_module_function_arity.insert(
+            module_function_arity,
+            Nif {
+                library: library.clone(),
+                fptr: func.fptr,
+            },
+        );
+    }
+
+    Ok(module)
+}
+
+#[cfg(not(unix))]
+pub fn load(_process: &Process, _path: &str, _load_info: Term) -> Result<Atom, LoadError> {
+    Err(LoadError::NotSupported)
+}
+
+// Private
+
+#[cfg(unix)]
+fn dlerror() -> String {
+    let message = unsafe { libc::dlerror() };
+
+    if message.is_null() {
+        "unknown error".to_string()
+    } else {
+        unsafe { CStr::from_ptr(message) }
+            .to_string_lossy()
+            .into_owned()
+    }
+}
+
+lazy_static! {
+    static ref RW_LOCK_NIF_BY_MODULE_FUNCTION_ARITY: RwLock<HashMap<ModuleFunctionArity, Nif>> =
+        Default::default();
+}
@@ -0,0 +1,19 @@
+//! Memory that NIFs manage themselves.  It comes from `malloc`, so NIF libraries may also pass it
+//! to C libraries that free it.
+
+use core::ffi::c_void;
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_alloc(size: usize) -> *mut c_void {
+    libc::malloc(size)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_realloc(ptr: *mut c_void, size: usize) -> *mut c_void {
+    libc::realloc(ptr, size)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_free(ptr: *mut c_void) {
+    libc::free(ptr)
+}
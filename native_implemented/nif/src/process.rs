@@ -0,0 +1,161 @@
+//! Processes and messages
+
+use core::convert::TryInto;
+
+use libc::c_int;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use lumen_rt_core::{process, registry, send};
+
+use crate::env::{nif_term, term, ErlNifEnv};
+use crate::sys::*;
+
+/// Returns `null` when called from an environment that isn't bound to a process.
+#[no_mangle]
+pub unsafe extern "C" fn enif_self(
+    caller_env: *mut ErlNifEnv,
+    pid: *mut ErlNifPid,
+) -> *mut ErlNifPid {
+    let caller_env = &*caller_env;
+
+    if caller_env.is_process_bound() {
+        (*pid).pid = nif_term(caller_env.process().pid_term());
+
+        pid
+    } else {
+        core::ptr::null_mut()
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_local_pid(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    pid: *mut ErlNifPid,
+) -> c_int {
+    if self::term(term).is_local_pid() {
+        (*pid).pid = term;
+
+        1
+    } else {
+        0
+    }
+}
+
+/// Sends `msg` to the local process `to_pid`.  Like the BEAM, `msg_env` is cleared afterwards, and
+/// returns `0` if `to_pid` isn't alive.
+///
+/// When `msg_env` is `NULL`, `msg` belongs to `caller_env`, which must be bound to the calling
+/// process, and it is sent like `erlang:send/2`.
+#[no_mangle]
+pub unsafe extern "C" fn enif_send(
+    caller_env: *mut ErlNifEnv,
+    to_pid: *const ErlNifPid,
+    msg_env: *mut ErlNifEnv,
+    msg: ERL_NIF_TERM,
+) -> c_int {
+    let destination_arc_process = match term((*to_pid).pid)
+        .try_into()
+        .ok()
+        .and_then(|pid: Pid| registry::pid_to_process(&pid))
+    {
+        Some(destination_arc_process) => destination_arc_process,
+        None => {
+            if !msg_env.is_null() {
+                crate::env::enif_clear_env(msg_env);
+            }
+
+            return 0;
+        }
+    };
+
+    if msg_env.is_null() {
+        if caller_env.is_null() || !(*caller_env).is_process_bound() {
+            return 0;
+        }
+
+        let sent = send::send(
+            term((*to_pid).pid),
+            term(msg),
+            Default::default(),
+            (*caller_env).process(),
+        );
+
+        sent.is_ok() as c_int
+    } else {
+        let msg_env = &mut *msg_env;
+        // Empties `msg_env`'s heap once the message is copied, as `enif_clear_env` would
+        process::send_from_decoder(msg_env.process(), &destination_arc_process, term(msg));
+        msg_env.byte_vecs.clear();
+
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::ptr;
+
+    use crate::env::{enif_alloc_env, enif_free_env};
+    use crate::term::enif_make_int;
+
+    #[test]
+    fn enif_self_with_process_independent_env_returns_null() {
+        unsafe {
+            let env = enif_alloc_env();
+            let mut pid = ErlNifPid { pid: 0 };
+
+            assert!(enif_self(env, &mut pid).is_null());
+
+            enif_free_env(env);
+        }
+    }
+
+    #[test]
+    fn enif_get_local_pid_without_pid_returns_0() {
+        unsafe {
+            let env = enif_alloc_env();
+            let mut pid = ErlNifPid { pid: 0 };
+
+            assert_eq!(enif_get_local_pid(env, enif_make_int(env, 1), &mut pid), 0);
+
+            enif_free_env(env);
+        }
+    }
+
+    #[test]
+    fn enif_send_without_pid_returns_0() {
+        unsafe {
+            let msg_env = enif_alloc_env();
+            let to_pid = ErlNifPid {
+                pid: enif_make_int(msg_env, 1),
+            };
+            let msg = enif_make_int(msg_env, 2);
+
+            assert_eq!(enif_send(ptr::null_mut(), &to_pid, msg_env, msg), 0);
+
+            enif_free_env(msg_env);
+        }
+    }
+
+    #[test]
+    fn enif_send_with_dead_pid_returns_0_and_clears_msg_env() {
+        unsafe {
+            let msg_env = enif_alloc_env();
+            let to_pid = ErlNifPid {
+                pid: nif_term(Pid::next_term()),
+            };
+            let element = term(enif_make_int(msg_env, 1));
+            let msg = nif_term((*msg_env).process().list_from_slice(&[element]));
+            (*msg_env).byte_vecs.push(vec![1, 2, 3]);
+
+            assert_eq!(enif_send(ptr::null_mut(), &to_pid, msg_env, msg), 0);
+            assert!((*msg_env).byte_vecs.is_empty());
+
+            enif_free_env(msg_env);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Resource objects
+//!
+//! A resource object is memory allocated by `enif_alloc_resource` that NIFs hand to Erlang as an
+//! opaque term.  It is reference counted: each resource term and each `enif_keep_resource` holds a
+//! reference, and the resource type's destructor runs when the last one is released.
+
+use core::ffi::c_void;
+use core::mem;
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use std::ffi::{CStr, CString};
+
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+use libc::{c_char, c_int};
+
+use liblumen_core::locks::Mutex;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::env::{nif_term, term, ErlNifEnv};
+use crate::sys::*;
+
+pub struct ErlNifResourceType {
+    dtor: Option<ErlNifResourceDtor>,
+}
+
+// Resource data is aligned like `malloc`, which is what C code expects.
+#[repr(C, align(16))]
+struct Header {
+    resource_type: *const ErlNifResourceType,
+    reference_count: AtomicUsize,
+}
+
+impl Header {
+    unsafe fn from_obj<'a>(obj: *mut c_void) -> &'a Header {
+        &*((obj as *mut u8).sub(mem::size_of::<Header>()) as *const Header)
+    }
+
+    fn obj(&self) -> *mut c_void {
+        unsafe { (self as *const Header as *mut u8).add(mem::size_of::<Header>()) as *mut c_void }
+    }
+}
+
+/// The value of the `Resource` term made by `enif_make_resource`.  It holds a reference to the
+/// resource object for as long as the term is alive.
+struct Handle(NonNull<Header>);
+
+impl Handle {
+    unsafe fn new(obj: *mut c_void) -> Self {
+        enif_keep_resource(obj);
+
+        Self(NonNull::from(Header::from_obj(obj)))
+    }
+
+    fn header(&self) -> &Header {
+        unsafe { self.0.as_ref() }
+    }
+}
+
+impl Clone for Handle {
+    fn clone(&self) -> Self {
+        unsafe { Self::new(self.header().obj()) }
+    }
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        unsafe { enif_release_resource(self.header().obj()) }
+    }
+}
+
+/// Resource types are never freed, as resources of a type may outlive any library.
+#[no_mangle]
+pub unsafe extern "C" fn enif_open_resource_type(
+    _env: *mut ErlNifEnv,
+    // Unused, like on the BEAM
+    _module_str: *const c_char,
+    name: *const c_char,
+    dtor: Option<ErlNifResourceDtor>,
+    flags: ErlNifResourceFlags,
+    tried: *mut ErlNifResourceFlags,
+) -> *mut ErlNifResourceType {
+    let name = CStr::from_ptr(name).to_owned();
+    let mut resource_type_by_name = RESOURCE_TYPE_BY_NAME.lock();
+
+    let (resource_type, created) = match resource_type_by_name.get(&name) {
+        Some(resource_type) if flags & ERL_NIF_RT_TAKEOVER != 0 => {
+            let resource_type = resource_type.0 as *mut ErlNifResourceType;
+            (*resource_type).dtor = dtor;
+
+            (resource_type, ERL_NIF_RT_TAKEOVER)
+        }
+        None if flags & ERL_NIF_RT_CREATE != 0 => {
+            let resource_type = Box::into_raw(Box::new(ErlNifResourceType { dtor }));
+            resource_type_by_name.insert(name, ResourceTypePtr(resource_type));
+
+            (resource_type, ERL_NIF_RT_CREATE)
+        }
+        _ => return ptr::null_mut(),
+    };
+
+    if !tried.is_null() {
+        *tried = created;
+    }
+
+    resource_type
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_alloc_resource(
+    resource_type: *mut ErlNifResourceType,
+    size: usize,
+) -> *mut c_void {
+    let header = libc::malloc(mem::size_of::<Header>() + size) as *mut Header;
+
+    if header.is_null() {
+        return ptr::null_mut();
+    }
+
+    // The caller holds the first reference
+    ptr::write(
+        header,
+        Header {
+            resource_type,
+            reference_count: AtomicUsize::new(1),
+        },
+    );
+
+    (*header).obj()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_keep_resource(obj: *mut c_void) -> c_int {
+    Header::from_obj(obj)
+        .reference_count
+        .fetch_add(1, Ordering::Relaxed);
+
+    1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_release_resource(obj: *mut c_void) {
+    let header = Header::from_obj(obj);
+
+    if header.reference_count.fetch_sub(1, Ordering::Release) == 1 {
+        core::sync::atomic::fence(Ordering::Acquire);
+
+        if let Some(dtor) = (*header.resource_type).dtor {
+            // Destructors may run on any thread after the NIF call, so they get an environment
+            // that isn't bound to a process.
+            let env = crate::env::enif_alloc_env();
+            dtor(env, obj);
+            crate::env::enif_free_env(env);
+        }
+
+        libc::free(header as *const Header as *mut c_void);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_resource(env: *mut ErlNifEnv, obj: *mut c_void) -> ERL_NIF_TERM {
+    nif_term((*env).process().resource(Handle::new(obj)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_resource(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    resource_type: *mut ErlNifResourceType,
+    objp: *mut *mut c_void,
+) -> c_int {
+    match self::term(term).decode() {
+        Ok(TypedTerm::ResourceReference(resource)) => match resource.downcast_ref::<Handle>() {
+            Some(handle) if handle.header().resource_type == resource_type as *const _ => {
+                *objp = handle.header().obj();
+
+                1
+            }
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+struct ResourceTypePtr(*const ErlNifResourceType);
+
+unsafe impl Send for ResourceTypePtr {}
+
+lazy_static! {
+    static ref RESOURCE_TYPE_BY_NAME: Mutex<HashMap<CString, ResourceTypePtr>> = Default::default();
+}
@@ -0,0 +1,93 @@
+//! The types shared with NIF libraries.  Their layout matches `erl_nif.h` from OTP 22 (NIF
+//! version 2.15), so libraries compiled against that header can be loaded unchanged.
+
+use core::ffi::c_void;
+
+use libc::{c_char, c_int, c_uint};
+
+pub use crate::env::ErlNifEnv;
+pub use crate::resource::ErlNifResourceType;
+
+pub const ERL_NIF_MAJOR_VERSION: c_int = 2;
+pub const ERL_NIF_MINOR_VERSION: c_int = 15;
+
+#[allow(non_camel_case_types)]
+pub type ERL_NIF_TERM = usize;
+
+pub type ErlNifCharEncoding = c_uint;
+
+pub const ERL_NIF_LATIN1: ErlNifCharEncoding = 1;
+pub const ERL_NIF_UTF8: ErlNifCharEncoding = 2;
+
+pub type ErlNifResourceFlags = c_uint;
+
+pub const ERL_NIF_RT_CREATE: ErlNifResourceFlags = 1;
+pub const ERL_NIF_RT_TAKEOVER: ErlNifResourceFlags = 2;
+
+pub type ErlNifResourceDtor = unsafe extern "C" fn(env: *mut ErlNifEnv, obj: *mut c_void);
+
+pub type ErlNifFptr = unsafe extern "C" fn(
+    env: *mut ErlNifEnv,
+    argc: c_int,
+    argv: *const ERL_NIF_TERM,
+) -> ERL_NIF_TERM;
+
+#[repr(C)]
+pub struct ErlNifFunc {
+    pub name: *const c_char,
+    pub arity: c_uint,
+    pub fptr: ErlNifFptr,
+    pub flags: c_uint,
+}
+
+#[allow(non_snake_case)]
+#[repr(C)]
+pub struct ErlNifEntry {
+    pub major: c_int,
+    pub minor: c_int,
+    pub name: *const c_char,
+    pub num_of_funcs: c_int,
+    pub funcs: *const ErlNifFunc,
+    pub load: Option<
+        unsafe extern "C" fn(
+            env: *mut ErlNifEnv,
+            priv_data: *mut *mut c_void,
+            load_info: ERL_NIF_TERM,
+        ) -> c_int,
+    >,
+    pub reload: Option<
+        unsafe extern "C" fn(
+            env: *mut ErlNifEnv,
+            priv_data: *mut *mut c_void,
+            load_info: ERL_NIF_TERM,
+        ) -> c_int,
+    >,
+    pub upgrade: Option<
+        unsafe extern "C" fn(
+            env: *mut ErlNifEnv,
+            priv_data: *mut *mut c_void,
+            old_priv_data: *mut *mut c_void,
+            load_info: ERL_NIF_TERM,
+        ) -> c_int,
+    >,
+    pub unload: Option<unsafe extern "C" fn(env: *mut ErlNifEnv, priv_data: *mut c_void)>,
+    pub vm_variant: *const c_char,
+    pub options: c_uint,
+    pub sizeof_ErlNifResourceTypeInit: usize,
+    pub min_erts: *const c_char,
+}
+
+#[repr(C)]
+pub struct ErlNifBinary {
+    pub size: usize,
+    pub data: *mut u8,
+    /// Owned allocation backing `data` for binaries from `enif_alloc_binary`.  Null for binaries
+    /// from `enif_inspect_binary`, which borrow the term's bytes.
+    pub ref_bin: *mut c_void,
+    pub __spare__: [*mut c_void; 2],
+}
+
+#[repr(C)]
+pub struct ErlNifPid {
+    pub pid: ERL_NIF_TERM,
+}
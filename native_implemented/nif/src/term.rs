@@ -0,0 +1,604 @@
+//! Creating and inspecting terms
+
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
+use core::ffi::VaListImpl;
+use core::ptr;
+use core::slice;
+
+use std::ffi::CStr;
+
+use libc::{c_char, c_double, c_int, c_long, c_uint, c_ulong};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::CloneToProcess;
+
+use crate::env::{nif_term, term, ErlNifEnv};
+use crate::sys::*;
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_atom(env: *mut ErlNifEnv, name: *const c_char) -> ERL_NIF_TERM {
+    enif_make_atom_len(env, name, CStr::from_ptr(name).to_bytes().len())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_atom_len(
+    env: *mut ErlNifEnv,
+    name: *const c_char,
+    len: usize,
+) -> ERL_NIF_TERM {
+    let bytes = slice::from_raw_parts(name as *const u8, len);
+
+    match Atom::try_from_latin1_bytes(bytes) {
+        Ok(atom) => nif_term(atom.encode().unwrap()),
+        Err(_) => crate::env::enif_make_badarg(env),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_existing_atom(
+    _env: *mut ErlNifEnv,
+    name: *const c_char,
+    atom: *mut ERL_NIF_TERM,
+    encoding: ErlNifCharEncoding,
+) -> c_int {
+    let bytes = CStr::from_ptr(name).to_bytes();
+    let result = match encoding {
+        ERL_NIF_LATIN1 => Atom::try_from_latin1_bytes_existing(bytes).ok(),
+        _ => core::str::from_utf8(bytes)
+            .ok()
+            .and_then(|s| Atom::try_from_str_existing(s).ok()),
+    };
+
+    match result {
+        Some(existing) => {
+            *atom = nif_term(existing.encode().unwrap());
+
+            1
+        }
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_int(env: *mut ErlNifEnv, i: c_int) -> ERL_NIF_TERM {
+    nif_term((*env).process().integer(i))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_uint(env: *mut ErlNifEnv, i: c_uint) -> ERL_NIF_TERM {
+    nif_term((*env).process().integer(i as u64))
+}
+
+/// On LP64 targets `erl_nif.h` defines `enif_make_int64` as `enif_make_long`.
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_long(env: *mut ErlNifEnv, i: c_long) -> ERL_NIF_TERM {
+    nif_term((*env).process().integer(i as i64))
+}
+
+/// On LP64 targets `erl_nif.h` defines `enif_make_uint64` as `enif_make_ulong`.
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_ulong(env: *mut ErlNifEnv, i: c_ulong) -> ERL_NIF_TERM {
+    nif_term((*env).process().integer(i as u64))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_double(env: *mut ErlNifEnv, d: c_double) -> ERL_NIF_TERM {
+    // Like the BEAM, only finite floats are terms
+    if d.is_finite() {
+        nif_term((*env).process().float(d))
+    } else {
+        crate::env::enif_make_badarg(env)
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_string(
+    env: *mut ErlNifEnv,
+    string: *const c_char,
+    encoding: ErlNifCharEncoding,
+) -> ERL_NIF_TERM {
+    enif_make_string_len(
+        env,
+        string,
+        CStr::from_ptr(string).to_bytes().len(),
+        encoding,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_string_len(
+    env: *mut ErlNifEnv,
+    string: *const c_char,
+    len: usize,
+    encoding: ErlNifCharEncoding,
+) -> ERL_NIF_TERM {
+    let bytes = slice::from_raw_parts(string as *const u8, len);
+    let process = (*env).process();
+
+    match encoding {
+        ERL_NIF_LATIN1 => {
+            let string: String = bytes.iter().map(|byte| *byte as char).collect();
+
+            nif_term(process.list_from_chars(string.chars()))
+        }
+        _ => match core::str::from_utf8(bytes) {
+            Ok(s) => nif_term(process.list_from_chars(s.chars())),
+            Err(_) => crate::env::enif_make_badarg(env),
+        },
+    }
+}
+
+/// `enif_make_tuple1` to `enif_make_tuple9` are macros in `erl_nif.h` that call this.
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_tuple(
+    env: *mut ErlNifEnv,
+    count: c_uint,
+    mut args: ...
+) -> ERL_NIF_TERM {
+    let elements = variadic_terms(count, &mut args);
+
+    nif_term((*env).process().tuple_from_slice(&elements))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_tuple_from_array(
+    env: *mut ErlNifEnv,
+    array: *const ERL_NIF_TERM,
+    count: c_uint,
+) -> ERL_NIF_TERM {
+    let elements = terms(array, count as usize);
+
+    nif_term((*env).process().tuple_from_slice(elements))
+}
+
+/// `enif_make_list1` to `enif_make_list9` are macros in `erl_nif.h` that call this.
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_list(
+    env: *mut ErlNifEnv,
+    count: c_uint,
+    mut args: ...
+) -> ERL_NIF_TERM {
+    let elements = variadic_terms(count, &mut args);
+
+    nif_term((*env).process().list_from_slice(&elements))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_list_from_array(
+    env: *mut ErlNifEnv,
+    array: *const ERL_NIF_TERM,
+    count: c_uint,
+) -> ERL_NIF_TERM {
+    let elements = terms(array, count as usize);
+
+    nif_term((*env).process().list_from_slice(elements))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_list_cell(
+    env: *mut ErlNifEnv,
+    head: ERL_NIF_TERM,
+    tail: ERL_NIF_TERM,
+) -> ERL_NIF_TERM {
+    nif_term((*env).process().cons(term(head), term(tail)))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_ref(env: *mut ErlNifEnv) -> ERL_NIF_TERM {
+    nif_term((*env).process().next_reference())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_make_copy(
+    dst_env: *mut ErlNifEnv,
+    src_term: ERL_NIF_TERM,
+) -> ERL_NIF_TERM {
+    nif_term(term(src_term).clone_to_process((*dst_env).process()))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_int(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    ip: *mut c_int,
+) -> c_int {
+    get_integer(term, ip)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_uint(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    ip: *mut c_uint,
+) -> c_int {
+    get_integer(term, ip)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_long(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    ip: *mut c_long,
+) -> c_int {
+    get_integer(term, ip)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_ulong(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    ip: *mut c_ulong,
+) -> c_int {
+    get_integer(term, ip)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_double(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    dp: *mut c_double,
+) -> c_int {
+    // Like the BEAM, integers are not converted
+    match self::term(term).decode() {
+        Ok(TypedTerm::Float(float)) => {
+            *dp = float.into();
+
+            1
+        }
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_atom_length(
+    _env: *mut ErlNifEnv,
+    atom: ERL_NIF_TERM,
+    len: *mut c_uint,
+    encoding: ErlNifCharEncoding,
+) -> c_int {
+    match atom_bytes(atom, encoding) {
+        Some(bytes) => {
+            *len = bytes.len() as c_uint;
+
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Returns the number of bytes written including the NUL terminator, or `0` if `atom` isn't an
+/// atom or doesn't fit in `buf`.
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_atom(
+    _env: *mut ErlNifEnv,
+    atom: ERL_NIF_TERM,
+    buf: *mut c_char,
+    size: c_uint,
+    encoding: ErlNifCharEncoding,
+) -> c_int {
+    match atom_bytes(atom, encoding) {
+        Some(bytes) => write_nul_terminated(&bytes, buf, size).unwrap_or(0),
+        None => 0,
+    }
+}
+
+/// Returns the number of bytes written including the NUL terminator, `0` if `list` isn't a string
+/// in `encoding`, or `-size` if the string was truncated to fit in `buf`.
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_string(
+    _env: *mut ErlNifEnv,
+    list: ERL_NIF_TERM,
+    buf: *mut c_char,
+    size: c_uint,
+    encoding: ErlNifCharEncoding,
+) -> c_int {
+    if size == 0 {
+        return 0;
+    }
+
+    let chars = match list_to_vec(term(list)) {
+        Some(elements) => elements
+            .into_iter()
+            .map(|element| element.try_into().ok())
+            .collect::<Option<String>>(),
+        None => None,
+    };
+
+    match chars.and_then(|s| encode(&s, encoding)) {
+        Some(bytes) => match write_nul_terminated(&bytes, buf, size) {
+            Some(written) => written,
+            None => {
+                let truncated_len = size as usize - 1;
+                ptr::copy_nonoverlapping(bytes.as_ptr(), buf as *mut u8, truncated_len);
+                *buf.add(truncated_len) = 0;
+
+                -(size as c_int)
+            }
+        },
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_tuple(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    arity: *mut c_int,
+    array: *mut *const ERL_NIF_TERM,
+) -> c_int {
+    match self::term(term).decode() {
+        Ok(TypedTerm::Tuple(tuple)) => {
+            let elements = tuple.elements();
+            *arity = elements.len() as c_int;
+            *array = elements.as_ptr() as *const ERL_NIF_TERM;
+
+            1
+        }
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_list_cell(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    head: *mut ERL_NIF_TERM,
+    tail: *mut ERL_NIF_TERM,
+) -> c_int {
+    match self::term(term).decode() {
+        Ok(TypedTerm::List(cons)) => {
+            *head = nif_term(cons.head);
+            *tail = nif_term(cons.tail);
+
+            1
+        }
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_get_list_length(
+    _env: *mut ErlNifEnv,
+    term: ERL_NIF_TERM,
+    len: *mut c_uint,
+) -> c_int {
+    match list_to_vec(self::term(term)) {
+        Some(elements) => {
+            *len = elements.len() as c_uint;
+
+            1
+        }
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_atom(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_atom() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_binary(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_binary() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_empty_list(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_nil() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_list(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_list() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_number(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_number() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_pid(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_pid() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_ref(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_reference() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_tuple(_env: *mut ErlNifEnv, term: ERL_NIF_TERM) -> c_int {
+    self::term(term).is_boxed_tuple() as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_is_identical(lhs: ERL_NIF_TERM, rhs: ERL_NIF_TERM) -> c_int {
+    term(lhs).exact_eq(&term(rhs)) as c_int
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn enif_compare(lhs: ERL_NIF_TERM, rhs: ERL_NIF_TERM) -> c_int {
+    match term(lhs).partial_cmp(&term(rhs)) {
+        Some(Ordering::Less) => -1,
+        Some(Ordering::Equal) | None => 0,
+        Some(Ordering::Greater) => 1,
+    }
+}
+
+// Private
+
+fn atom_bytes(atom: ERL_NIF_TERM, encoding: ErlNifCharEncoding) -> Option<Vec<u8>> {
+    let atom: Atom = term(atom).try_into().ok()?;
+
+    encode(atom.name(), encoding)
+}
+
+fn encode(s: &str, encoding: ErlNifCharEncoding) -> Option<Vec<u8>> {
+    match encoding {
+        ERL_NIF_LATIN1 => s.chars().map(|c| u8::try_from(c as u32).ok()).collect(),
+        _ => Some(s.as_bytes().to_vec()),
+    }
+}
+
+unsafe fn get_integer<T: TryFrom<i128>>(term: ERL_NIF_TERM, ip: *mut T) -> c_int {
+    let option_i128 = match self::term(term).decode() {
+        Ok(TypedTerm::SmallInteger(small_integer)) => {
+            let i: isize = small_integer.into();
+
+            Some(i as i128)
+        }
+        Ok(TypedTerm::BigInteger(big_integer)) => {
+            let big_int: BigInt = big_integer.into();
+
+            big_int.to_i128()
+        }
+        _ => None,
+    };
+
+    match option_i128.and_then(|i| T::try_from(i).ok()) {
+        Some(i) => {
+            *ip = i;
+
+            1
+        }
+        None => 0,
+    }
+}
+
+/// The elements of `list` if it is a proper list.
+fn list_to_vec(list: Term) -> Option<Vec<Term>> {
+    match list.decode().ok()? {
+        TypedTerm::Nil => Some(Vec::new()),
+        TypedTerm::List(cons) => cons.into_iter().collect::<Result<Vec<Term>, _>>().ok(),
+        _ => None,
+    }
+}
+
+unsafe fn terms<'a>(array: *const ERL_NIF_TERM, count: usize) -> &'a [Term] {
+    if count == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(array as *const Term, count)
+    }
+}
+
+unsafe fn variadic_terms(count: c_uint, args: &mut VaListImpl) -> Vec<Term> {
+    (0..count)
+        .map(|_| term(args.arg::<ERL_NIF_TERM>()))
+        .collect()
+}
+
+/// Returns the number of bytes written including the NUL terminator, or `None` if `bytes` and the
+/// NUL terminator don't fit in `size`.
+unsafe fn write_nul_terminated(bytes: &[u8], buf: *mut c_char, size: c_uint) -> Option<c_int> {
+    if bytes.len() < size as usize {
+        ptr::copy_nonoverlapping(bytes.as_ptr(), buf as *mut u8, bytes.len());
+        *buf.add(bytes.len()) = 0;
+
+        Some((bytes.len() + 1) as c_int)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::env::{enif_alloc_env, enif_free_env};
+
+    #[test]
+    fn enif_make_tuple_with_elements_makes_tuple_of_elements() {
+        unsafe {
+            let env = enif_alloc_env();
+            let first = enif_make_int(env, 1);
+            let second = enif_make_atom(env, b"two\0".as_ptr() as *const c_char);
+
+            let tuple = enif_make_tuple(env, 2, first, second);
+
+            assert_eq!(
+                term(tuple),
+                (*env)
+                    .process()
+                    .tuple_from_slice(&[term(first), term(second)])
+            );
+
+            enif_free_env(env);
+        }
+    }
+
+    #[test]
+    fn enif_make_tuple_without_elements_makes_empty_tuple() {
+        unsafe {
+            let env = enif_alloc_env();
+
+            let tuple = enif_make_tuple(env, 0);
+
+            assert_eq!(term(tuple), (*env).process().tuple_from_slice(&[]));
+
+            enif_free_env(env);
+        }
+    }
+
+    #[test]
+    fn enif_make_list_with_elements_makes_proper_list_of_elements() {
+        unsafe {
+            let env = enif_alloc_env();
+            let first = enif_make_int(env, 1);
+            let second = enif_make_double(env, 2.0);
+            let third = enif_make_atom(env, b"three\0".as_ptr() as *const c_char);
+
+            let list = enif_make_list(env, 3, first, second, third);
+
+            let mut len: c_uint = 0;
+            assert_eq!(enif_get_list_length(env, list, &mut len), 1);
+            assert_eq!(len, 3);
+            assert_eq!(
+                term(list),
+                (*env)
+                    .process()
+                    .list_from_slice(&[term(first), term(second), term(third)])
+            );
+
+            enif_free_env(env);
+        }
+    }
+
+    #[test]
+    fn enif_make_list_without_elements_makes_empty_list() {
+        unsafe {
+            let env = enif_alloc_env();
+
+            let list = enif_make_list(env, 0);
+
+            assert_eq!(term(list), Term::NIL);
+
+            enif_free_env(env);
+        }
+    }
+
+    #[test]
+    fn enif_get_tuple_returns_elements_of_enif_make_tuple() {
+        unsafe {
+            let env = enif_alloc_env();
+            let first = enif_make_int(env, 1);
+            let second = enif_make_int(env, 2);
+            let tuple = enif_make_tuple(env, 2, first, second);
+
+            let mut arity: c_int = 0;
+            let mut array: *const ERL_NIF_TERM = ptr::null();
+            assert_eq!(enif_get_tuple(env, tuple, &mut arity, &mut array), 1);
+            assert_eq!(arity, 2);
+
+            let mut element: c_int = 0;
+            assert_eq!(enif_get_int(env, *array.add(1), &mut element), 1);
+            assert_eq!(element, 2);
+
+            enif_free_env(env);
+        }
+    }
+}
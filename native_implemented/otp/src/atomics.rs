@@ -0,0 +1,23 @@
+//! Mirrors [atomics](http://erlang.org/doc/man/atomics.html) module
+
+pub mod add_3;
+pub mod add_get_3;
+pub(crate) mod array;
+pub mod compare_exchange_4;
+pub mod exchange_3;
+pub mod get_2;
+pub mod info_1;
+pub mod new_2;
+pub mod put_3;
+pub mod sub_3;
+pub mod sub_get_3;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("atomics")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+/// Wraps around on overflow.
+#[native_implemented::function(atomics:add/3)]
+pub fn result(reference: Term, index: Term, incr: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let incr_bits = Array::incr_try_into_bits(incr)?;
+
+    atomic.fetch_add(incr_bits, Ordering::SeqCst);
+
+    Ok(atom!("ok"))
+}
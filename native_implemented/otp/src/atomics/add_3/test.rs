@@ -0,0 +1,83 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::{add_3, get_2, new_2, put_3};
+use crate::test::with_process;
+
+#[test]
+fn with_incr_adds_to_value() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            add_3::result(reference, index, process.integer(5)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            add_3::result(reference, index, process.integer(-2)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index),
+            Ok(process.integer(3))
+        );
+    });
+}
+
+#[test]
+fn with_signed_overflow_wraps_around() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            put_3::result(reference, index, process.integer(i64::max_value())),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            add_3::result(reference, index, process.integer(1)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index)
+                .unwrap()
+                .to_string(),
+            i64::min_value().to_string()
+        );
+    });
+}
+
+#[test]
+fn with_unsigned_underflow_wraps_around() {
+    with_process(|process| {
+        let options =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("signed"), false.into()])]);
+        let reference = new_2::result(process, process.integer(1), options).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            add_3::result(reference, index, process.integer(-1)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index)
+                .unwrap()
+                .to_string(),
+            u64::max_value().to_string()
+        );
+    });
+}
+
+#[test]
+fn with_incr_wider_than_64_bits_errors_badarg() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let incr = process.integer(i64::min_value() as i128 - 1);
+
+        assert_badarg!(
+            add_3::result(reference, process.integer(1), incr),
+            format!("incr ({}) is not a 64-bit integer", incr)
+        );
+    });
+}
@@ -0,0 +1,25 @@
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+/// Wraps around on overflow and returns the new value.
+#[native_implemented::function(atomics:add_get/3)]
+pub fn result(
+    process: &Process,
+    reference: Term,
+    index: Term,
+    incr: Term,
+) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let incr_bits = Array::incr_try_into_bits(incr)?;
+    let bits = atomic
+        .fetch_add(incr_bits, Ordering::SeqCst)
+        .wrapping_add(incr_bits);
+
+    Ok(array.bits_to_term(process, bits))
+}
@@ -0,0 +1,145 @@
+use std::convert::TryInto;
+use std::mem;
+use std::sync::atomic::AtomicU64;
+use std::sync::Arc;
+
+use anyhow::*;
+use num_bigint::BigInt;
+use num_traits::cast::ToPrimitive;
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::Process;
+
+use crate::runtime::context::*;
+
+/// A fixed-size array of 64-bit atomics.
+///
+/// The array is allocated on the Rust heap and only referenced from process heaps through a
+/// resource, so every process holding the reference reads and writes the same atomics without
+/// locking, and the garbage collector never copies them.
+pub struct Array {
+    signed: bool,
+    atomics: Box<[AtomicU64]>,
+}
+
+impl Array {
+    pub fn new(arity: usize, signed: bool) -> Self {
+        Self {
+            signed,
+            atomics: (0..arity).map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+
+    pub fn try_from_term(term: Term) -> anyhow::Result<Arc<Self>> {
+        let option_array = term
+            .try_into()
+            .ok()
+            .and_then(|resource: Resource| resource.downcast_ref::<Arc<Array>>().cloned());
+
+        option_array.with_context(|| term_is_not_type("reference", term, "an atomics reference"))
+    }
+
+    pub fn arity_try_from_term(arity: Term) -> anyhow::Result<usize> {
+        let arity_usize: usize = arity
+            .try_into()
+            .with_context(|| term_is_not_type("arity", arity, "a positive integer"))?;
+
+        if 0 < arity_usize {
+            Ok(arity_usize)
+        } else {
+            Err(anyhow!(term_is_not_type(
+                "arity",
+                arity,
+                "a positive integer"
+            )))
+        }
+    }
+
+    pub fn arity(&self) -> usize {
+        self.atomics.len()
+    }
+
+    pub fn atomic(&self, index: Term) -> anyhow::Result<&AtomicU64> {
+        let arity = self.arity();
+        let index_usize: usize = index
+            .try_into()
+            .with_context(|| term_is_not_in_one_based_range(index, arity))?;
+
+        if 1 <= index_usize && index_usize <= arity {
+            Ok(&self.atomics[index_usize - 1])
+        } else {
+            Err(anyhow!(term_is_not_in_one_based_range(index, arity)))
+        }
+    }
+
+    /// Converts `incr` to the bits that are added to or subtracted from an atomic with wrapping.
+    ///
+    /// Any integer that fits in 64 bits, signed or unsigned, is accepted regardless of whether
+    /// the array is signed.
+    pub fn incr_try_into_bits(incr: Term) -> anyhow::Result<u64> {
+        match term_try_into_i64(incr) {
+            Some(incr_i64) => Ok(incr_i64 as u64),
+            None => incr
+                .try_into()
+                .map_err(|_| anyhow!(term_is_not_type("incr", incr, "a 64-bit integer"))),
+        }
+    }
+
+    /// Converts `value` to bits, but only if `value` is between `min` and `max` of this array.
+    pub fn value_try_into_bits(&self, name: &str, value: Term) -> anyhow::Result<u64> {
+        let option_bits = if self.signed {
+            term_try_into_i64(value).map(|value_i64| value_i64 as u64)
+        } else {
+            value.try_into().ok()
+        };
+
+        option_bits.with_context(|| {
+            term_is_not_type(
+                name,
+                value,
+                &format!("an integer between {} and {}", self.min(), self.max()),
+            )
+        })
+    }
+
+    pub fn bits_to_term(&self, process: &Process, bits: u64) -> Term {
+        if self.signed {
+            process.integer(bits as i64)
+        } else {
+            process.integer(bits)
+        }
+    }
+
+    pub fn max(&self) -> Integer {
+        if self.signed {
+            i64::max_value().into()
+        } else {
+            u64::max_value().into()
+        }
+    }
+
+    pub fn min(&self) -> Integer {
+        if self.signed {
+            i64::min_value().into()
+        } else {
+            0_u64.into()
+        }
+    }
+
+    /// The number of bytes used by the array
+    pub fn memory(&self) -> usize {
+        mem::size_of::<Self>() + self.arity() * mem::size_of::<AtomicU64>()
+    }
+}
+
+fn term_try_into_i64(term: Term) -> Option<i64> {
+    match term.decode().unwrap() {
+        TypedTerm::SmallInteger(small_integer) => Some(small_integer.into()),
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+
+            big_int.to_i64()
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+/// Stores `desired` only if the current value is `expected`.  Returns `ok` if `desired` was stored,
+/// otherwise the current value.
+#[native_implemented::function(atomics:compare_exchange/4)]
+pub fn result(
+    process: &Process,
+    reference: Term,
+    index: Term,
+    expected: Term,
+    desired: Term,
+) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let expected_bits = array.value_try_into_bits("expected", expected)?;
+    let desired_bits = array.value_try_into_bits("desired", desired)?;
+
+    match atomic.compare_exchange(
+        expected_bits,
+        desired_bits,
+        Ordering::SeqCst,
+        Ordering::SeqCst,
+    ) {
+        Ok(_) => Ok(atom!("ok")),
+        Err(current_bits) => Ok(array.bits_to_term(process, current_bits)),
+    }
+}
@@ -0,0 +1,55 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::{compare_exchange_4, get_2, new_2, put_3};
+use crate::test::with_process;
+
+#[test]
+fn with_expected_value_stores_desired_and_returns_ok() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            compare_exchange_4::result(
+                process,
+                reference,
+                index,
+                process.integer(0),
+                process.integer(7)
+            ),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index),
+            Ok(process.integer(7))
+        );
+    });
+}
+
+#[test]
+fn without_expected_value_returns_current_value() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            put_3::result(reference, index, process.integer(2)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            compare_exchange_4::result(
+                process,
+                reference,
+                index,
+                process.integer(0),
+                process.integer(7)
+            ),
+            Ok(process.integer(2))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index),
+            Ok(process.integer(2))
+        );
+    });
+}
@@ -0,0 +1,23 @@
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+/// Returns the value before `desired` was stored.
+#[native_implemented::function(atomics:exchange/3)]
+pub fn result(
+    process: &Process,
+    reference: Term,
+    index: Term,
+    desired: Term,
+) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let desired_bits = array.value_try_into_bits("desired", desired)?;
+    let bits = atomic.swap(desired_bits, Ordering::SeqCst);
+
+    Ok(array.bits_to_term(process, bits))
+}
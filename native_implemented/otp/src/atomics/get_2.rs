@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+#[native_implemented::function(atomics:get/2)]
+pub fn result(process: &Process, reference: Term, index: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let bits = array.atomic(index)?.load(Ordering::SeqCst);
+
+    Ok(array.bits_to_term(process, bits))
+}
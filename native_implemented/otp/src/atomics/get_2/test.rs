@@ -0,0 +1,31 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::{get_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn without_atomics_reference_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            get_2::result(process, atom!("reference"), process.integer(1)),
+            "reference (reference) is not an atomics reference"
+        );
+    });
+}
+
+#[test]
+fn with_index_out_of_range_errors_badarg() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(2), Term::NIL).unwrap();
+
+        assert_badarg!(
+            get_2::result(process, reference, process.integer(0)),
+            "index (0) is not a 1-based integer between 1-2"
+        );
+        assert_badarg!(
+            get_2::result(process, reference, process.integer(3)),
+            "index (3) is not a 1-based integer between 1-2"
+        );
+    });
+}
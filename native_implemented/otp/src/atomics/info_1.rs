@@ -0,0 +1,18 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+#[native_implemented::function(atomics:info/1)]
+pub fn result(process: &Process, reference: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+
+    Ok(process.map_from_slice(&[
+        (atom!("size"), process.integer(array.arity())),
+        (atom!("max"), process.integer(array.max())),
+        (atom!("min"), process.integer(array.min())),
+        (atom!("memory"), process.integer(array.memory())),
+    ]))
+}
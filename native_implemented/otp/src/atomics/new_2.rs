@@ -0,0 +1,88 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+use crate::runtime::proplist::TryPropListFromTermError;
+
+#[native_implemented::function(atomics:new/2)]
+pub fn result(process: &Process, arity: Term, options: Term) -> exception::Result<Term> {
+    let arity_usize = Array::arity_try_from_term(arity)?;
+    let options_options: Options = options.try_into()?;
+
+    Ok(process.resource(Arc::new(Array::new(arity_usize, options_options.signed))))
+}
+
+// Private
+
+const SUPPORTED_OPTIONS_CONTEXT: &str = "supported option is {signed, boolean}";
+
+struct Options {
+    signed: bool,
+}
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> anyhow::Result<&Options> {
+        let tuple: Boxed<Tuple> = option
+            .try_into()
+            .map_err(|_| TryPropListFromTermError::PropertyType)
+            .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+        if tuple.len() == 2 {
+            let atom: Atom = tuple[0]
+                .try_into()
+                .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+            match atom.name() {
+                "signed" => {
+                    self.signed = tuple[1]
+                        .try_into()
+                        .with_context(|| format!("signed ({}) is not a boolean", tuple[1]))?;
+
+                    Ok(self)
+                }
+                name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                    .context(SUPPORTED_OPTIONS_CONTEXT),
+            }
+        } else {
+            Err(TryPropListFromTermError::TupleNotPair).context(SUPPORTED_OPTIONS_CONTEXT)
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { signed: true }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            };
+        }
+    }
+}
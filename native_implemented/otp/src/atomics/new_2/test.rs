@@ -0,0 +1,43 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::{get_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_zero_arity_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            new_2::result(process, process.integer(0), Term::NIL),
+            "arity (0) is not a positive integer"
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[atom!("write_concurrency")]);
+
+        assert_badarg!(
+            new_2::result(process, process.integer(1), options),
+            "supported option is {signed, boolean}"
+        );
+    });
+}
+
+#[test]
+fn with_arity_returns_reference_to_atomics_initialized_to_zero() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(2), Term::NIL).unwrap();
+
+        assert_eq!(
+            get_2::result(process, reference, process.integer(1)),
+            Ok(process.integer(0))
+        );
+        assert_eq!(
+            get_2::result(process, reference, process.integer(2)),
+            Ok(process.integer(0))
+        );
+    });
+}
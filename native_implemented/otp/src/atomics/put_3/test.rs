@@ -0,0 +1,61 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::{get_2, new_2, put_3};
+use crate::test::with_process;
+
+#[test]
+fn with_signed_value_out_of_range_errors_badarg() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let value = process.integer(u64::max_value());
+
+        assert_badarg!(
+            put_3::result(reference, process.integer(1), value),
+            format!(
+                "value ({}) is not an integer between {} and {}",
+                value,
+                i64::min_value(),
+                i64::max_value()
+            )
+        );
+    });
+}
+
+#[test]
+fn with_unsigned_negative_value_errors_badarg() {
+    with_process(|process| {
+        let options =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("signed"), false.into()])]);
+        let reference = new_2::result(process, process.integer(1), options).unwrap();
+
+        assert_badarg!(
+            put_3::result(reference, process.integer(1), process.integer(-1)),
+            format!(
+                "value (-1) is not an integer between 0 and {}",
+                u64::max_value()
+            )
+        );
+    });
+}
+
+#[test]
+fn with_value_in_range_stores_value() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(2), Term::NIL).unwrap();
+        let index = process.integer(2);
+
+        assert_eq!(
+            put_3::result(reference, index, process.integer(-3)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index),
+            Ok(process.integer(-3))
+        );
+        assert_eq!(
+            get_2::result(process, reference, process.integer(1)),
+            Ok(process.integer(0))
+        );
+    });
+}
@@ -0,0 +1,19 @@
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics::array::Array;
+
+/// Wraps around on overflow.
+#[native_implemented::function(atomics:sub/3)]
+pub fn result(reference: Term, index: Term, decr: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let decr_bits = Array::incr_try_into_bits(decr)?;
+
+    atomic.fetch_sub(decr_bits, Ordering::SeqCst);
+
+    Ok(atom!("ok"))
+}
@@ -1,3 +1,22 @@
+pub mod at_2;
+pub mod bin_to_list_1;
+pub mod bin_to_list_2;
+pub mod bin_to_list_3;
+pub mod compile_pattern_1;
+pub mod copy_1;
+pub mod copy_2;
+pub mod first_1;
+pub mod last_1;
+pub mod list_to_bin_1;
+mod r#match;
+pub mod match_2;
+pub mod match_3;
+pub mod part_2;
+pub mod part_3;
+mod pattern;
+mod split;
+pub mod split_2;
+pub mod split_3;
 pub mod to_term;
 
 use std::backtrace::Backtrace;
@@ -11,6 +30,14 @@ use liblumen_alloc::erts::exception::{self, ArcError, Exception, InternalExcepti
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::Process;
 
+fn module() -> Atom {
+    Atom::from_str("binary")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
 pub struct PartRange {
     pub byte_offset: usize,
     pub byte_len: usize,
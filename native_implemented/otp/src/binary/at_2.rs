@@ -0,0 +1,29 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+#[native_implemented::function(binary:at/2)]
+pub fn result(process: &Process, subject: Term, position: Term) -> exception::Result<Term> {
+    let position_usize: usize = position
+        .try_into()
+        .with_context(|| term_is_not_non_negative_integer("position", position))?;
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| term_is_not_binary("subject", subject))?;
+
+    match bytes.get(position_usize) {
+        Some(byte) => Ok((*byte).into()),
+        None => Err(anyhow!(
+            "position ({}) must be less than byte_size(subject) ({})",
+            position,
+            bytes.len()
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,17 @@
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+#[native_implemented::function(binary:bin_to_list/1)]
+pub fn result(process: &Process, subject: Term) -> exception::Result<Term> {
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| term_is_not_binary("subject", subject))?;
+    let byte_terms = bytes.iter().map(|byte| (*byte).into());
+
+    Ok(process.list_from_iter(byte_terms))
+}
@@ -0,0 +1,27 @@
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary;
+
+#[native_implemented::function(binary:bin_to_list/2)]
+pub fn result(process: &Process, subject: Term, position_length: Term) -> exception::Result<Term> {
+    let position_length_tuple = term_try_into_tuple!(position_length)?;
+
+    if position_length_tuple.len() == 2 {
+        binary::bin_to_list(
+            subject,
+            position_length_tuple[0],
+            position_length_tuple[1],
+            process,
+        )
+    } else {
+        Err(anyhow!(
+            "position_length ({}) is a tuple, but not 2-arity",
+            position_length
+        )
+        .into())
+    }
+}
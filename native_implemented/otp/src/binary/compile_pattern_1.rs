@@ -0,0 +1,14 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::pattern::Pattern;
+
+/// Preprocesses `pattern` for Boyer-Moore once, so that it can be reused across calls to
+/// `binary:match/2,3` and `binary:split/2,3`.
+#[native_implemented::function(binary:compile_pattern/1)]
+pub fn result(process: &Process, pattern: Term) -> exception::Result<Term> {
+    let pattern = Pattern::try_from_term(process, pattern)?;
+
+    Ok(process.resource(pattern))
+}
@@ -0,0 +1,10 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::copy_2;
+
+#[native_implemented::function(binary:copy/1)]
+pub fn result(process: &Process, subject: Term) -> exception::Result<Term> {
+    copy_2::result(process, subject, process.integer(1))
+}
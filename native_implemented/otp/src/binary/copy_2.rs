@@ -0,0 +1,24 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+/// Unlike `binary:part/3`, the returned binary does not share bytes with `subject`, so a small
+/// copy can be kept without keeping a large `subject` alive.
+#[native_implemented::function(binary:copy/2)]
+pub fn result(process: &Process, subject: Term, count: Term) -> exception::Result<Term> {
+    let count_usize: usize = count
+        .try_into()
+        .with_context(|| term_is_not_non_negative_integer("count", count))?;
+    let byte_vec = process
+        .bytes_from_binary(subject)
+        .with_context(|| term_is_not_binary("subject", subject))?
+        .repeat(count_usize);
+
+    Ok(process.binary_from_bytes(&byte_vec))
+}
@@ -0,0 +1,19 @@
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+#[native_implemented::function(binary:first/1)]
+pub fn result(process: &Process, subject: Term) -> exception::Result<Term> {
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| term_is_not_binary("subject", subject))?;
+
+    match bytes.first() {
+        Some(byte) => Ok((*byte).into()),
+        None => Err(anyhow!("subject ({}) is empty", subject).into()),
+    }
+}
@@ -0,0 +1,8 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(binary:list_to_bin/1)]
+pub fn result(process: &Process, byte_list: Term) -> exception::Result<Term> {
+    crate::erlang::list_to_binary_1::result(process, byte_list)
+}
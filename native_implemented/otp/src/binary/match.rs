@@ -0,0 +1,105 @@
+use std::convert::{TryFrom, TryInto};
+use std::ops::Range;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::{start_length_to_part_range, PartRangeError};
+use crate::runtime::proplist::TryPropListFromTermError;
+
+/// The `{scope, {start, length}}` option of `binary:match/3` and `binary:split/3`, which limits
+/// where matches are searched for in the subject.
+#[derive(Clone, Copy)]
+pub struct Scope {
+    start: usize,
+    length: isize,
+}
+
+impl Scope {
+    pub fn range(&self, available_byte_count: usize) -> Result<Range<usize>, PartRangeError> {
+        start_length_to_part_range(self.start, self.length, available_byte_count).map(From::from)
+    }
+}
+
+const SCOPE_CONTEXT: &str = "scope must be {start :: non_neg_integer(), length :: integer()}";
+
+impl TryFrom<Term> for Scope {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let tuple: Boxed<Tuple> = term.try_into().context(SCOPE_CONTEXT)?;
+
+        if tuple.len() == 2 {
+            let start: usize = tuple[0].try_into().context(SCOPE_CONTEXT)?;
+            let length: isize = tuple[1].try_into().context(SCOPE_CONTEXT)?;
+
+            Ok(Self { start, length })
+        } else {
+            Err(anyhow!("scope ({}) is a tuple, but not 2-arity", term)).context(SCOPE_CONTEXT)
+        }
+    }
+}
+
+pub struct Options {
+    pub scope: Option<Scope>,
+}
+
+const SUPPORTED_OPTIONS_CONTEXT: &str = "supported option is {scope, {start, length}}";
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> anyhow::Result<&Options> {
+        let tuple: Boxed<Tuple> = option
+            .try_into()
+            .map_err(|_| TryPropListFromTermError::PropertyType)
+            .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+        if tuple.len() == 2 {
+            let atom: Atom = tuple[0]
+                .try_into()
+                .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+            match atom.name() {
+                "scope" => {
+                    let scope = tuple[1].try_into()?;
+                    self.scope = Some(scope);
+
+                    Ok(self)
+                }
+                name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                    .context(SUPPORTED_OPTIONS_CONTEXT),
+            }
+        } else {
+            Err(TryPropListFromTermError::TupleNotPair).context(SUPPORTED_OPTIONS_CONTEXT)
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            };
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self { scope: None }
+    }
+}
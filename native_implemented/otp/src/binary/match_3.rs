@@ -0,0 +1,40 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::pattern::Pattern;
+use crate::binary::r#match::Options;
+use crate::runtime::context::*;
+
+/// Returns `{position, length}` of the first match of `pattern` in `subject` or `nomatch`.
+#[native_implemented::function(binary:match/3)]
+pub fn result(
+    process: &Process,
+    subject: Term,
+    pattern: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let options: Options = options.try_into()?;
+    let pattern = Pattern::try_from_term(process, pattern)?;
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| term_is_not_binary("subject", subject))?;
+    let range = match options.scope {
+        Some(scope) => scope.range(bytes.len())?,
+        None => 0..bytes.len(),
+    };
+
+    let term = match pattern.find(bytes, range.start, range.end) {
+        Some((position, length)) => {
+            process.tuple_from_slice(&[process.integer(position), process.integer(length)])
+        }
+        None => atom!("nomatch"),
+    };
+
+    Ok(term)
+}
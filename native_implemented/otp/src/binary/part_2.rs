@@ -0,0 +1,10 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the part of `binary` described by the `{start, length}` tuple as a subbinary that
+/// shares the data of `binary` instead of copying it.
+#[native_implemented::function(binary:part/2)]
+pub fn result(process: &Process, binary: Term, start_length: Term) -> exception::Result<Term> {
+    crate::erlang::binary_part_2::result(process, binary, start_length)
+}
@@ -0,0 +1,15 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the part of `binary` starting at `start` and `length` bytes long as a subbinary that
+/// shares the data of `binary` instead of copying it.
+#[native_implemented::function(binary:part/3)]
+pub fn result(
+    process: &Process,
+    binary: Term,
+    start: Term,
+    length: Term,
+) -> exception::Result<Term> {
+    crate::erlang::binary_part_3::result(process, binary, start, length)
+}
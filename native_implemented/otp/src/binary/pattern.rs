@@ -0,0 +1,237 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::Process;
+
+const PATTERN_CONTEXT: &str = "pattern must be a non-empty binary, a non-empty list of \
+     non-empty binaries, or a compiled pattern from binary:compile_pattern/1";
+
+/// One or more needles searched for with Boyer-Moore.
+///
+/// When more than one needle matches, the match starting first wins, and for needles matching at
+/// the same position, the longest wins, the same as `binary:match/2` in BEAM.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    searchers: Vec<BoyerMoore>,
+}
+
+impl Pattern {
+    pub fn new(needles: Vec<Vec<u8>>) -> Self {
+        assert!(!needles.is_empty());
+
+        Self {
+            searchers: needles.into_iter().map(BoyerMoore::new).collect(),
+        }
+    }
+
+    pub fn try_from_term(process: &Process, term: Term) -> anyhow::Result<Self> {
+        match term.decode().unwrap() {
+            TypedTerm::ResourceReference(resource_reference) => {
+                let resource: Resource = resource_reference.into();
+
+                match resource.downcast_ref::<Pattern>() {
+                    Some(pattern) => Ok(pattern.clone()),
+                    None => Err(TypeError).with_context(|| {
+                        format!(
+                            "pattern ({}) is a resource, but not a compiled pattern",
+                            term
+                        )
+                    }),
+                }
+            }
+            TypedTerm::List(cons) => {
+                let mut needles = Vec::new();
+
+                for result in cons.into_iter() {
+                    match result {
+                        Ok(element) => needles.push(needle(process, element)?),
+                        Err(_) => {
+                            return Err(ImproperListError)
+                                .with_context(|| format!("pattern ({}) is improper", term))
+                                .context(PATTERN_CONTEXT)
+                        }
+                    }
+                }
+
+                Ok(Self::new(needles))
+            }
+            _ => {
+                let needle = needle(process, term)?;
+
+                Ok(Self::new(vec![needle]))
+            }
+        }
+    }
+
+    /// Finds the first match starting at or after `from` that ends at or before `to`, returning
+    /// the position and length of the match.
+    pub fn find(&self, haystack: &[u8], from: usize, to: usize) -> Option<(usize, usize)> {
+        let window = &haystack[..to];
+
+        self.searchers
+            .iter()
+            .filter_map(|searcher| {
+                searcher
+                    .find(window, from)
+                    .map(|position| (position, searcher.len()))
+            })
+            .min_by(|(left_position, left_len), (right_position, right_len)| {
+                left_position
+                    .cmp(right_position)
+                    .then(right_len.cmp(left_len))
+            })
+    }
+
+    /// Finds all non-overlapping matches between `from` and `to`, returning the position and
+    /// length of each match.
+    pub fn find_all(&self, haystack: &[u8], from: usize, to: usize) -> Vec<(usize, usize)> {
+        let mut matches = Vec::new();
+        let mut position = from;
+
+        while let Some((match_position, match_len)) = self.find(haystack, position, to) {
+            matches.push((match_position, match_len));
+            position = match_position + match_len;
+        }
+
+        matches
+    }
+}
+
+// Private
+
+#[derive(Clone, Debug)]
+struct BoyerMoore {
+    needle: Vec<u8>,
+    /// Shift from the bad character rule, indexed by the mismatched byte in the haystack.
+    bad_character: [isize; 256],
+    /// Shift from the good suffix rule, indexed by the position of the mismatch in the needle.
+    good_suffix: Vec<isize>,
+}
+
+impl BoyerMoore {
+    fn new(needle: Vec<u8>) -> Self {
+        let bad_character = bad_character(&needle);
+        let good_suffix = good_suffix(&needle);
+
+        Self {
+            needle,
+            bad_character,
+            good_suffix,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.needle.len()
+    }
+
+    fn find(&self, haystack: &[u8], from: usize) -> Option<usize> {
+        let m = self.needle.len() as isize;
+        let n = haystack.len() as isize;
+        let mut j = from as isize;
+
+        while j <= n - m {
+            let mut i = m - 1;
+
+            while 0 <= i && self.needle[i as usize] == haystack[(i + j) as usize] {
+                i -= 1;
+            }
+
+            if i < 0 {
+                return Some(j as usize);
+            } else {
+                let bad_character_shift =
+                    self.bad_character[haystack[(i + j) as usize] as usize] - m + 1 + i;
+
+                j += cmp::max(self.good_suffix[i as usize], bad_character_shift);
+            }
+        }
+
+        None
+    }
+}
+
+fn bad_character(needle: &[u8]) -> [isize; 256] {
+    let m = needle.len();
+    let mut bad_character = [m as isize; 256];
+
+    for (i, byte) in needle[..m - 1].iter().enumerate() {
+        bad_character[*byte as usize] = (m - 1 - i) as isize;
+    }
+
+    bad_character
+}
+
+fn good_suffix(needle: &[u8]) -> Vec<isize> {
+    let m = needle.len() as isize;
+    let suffixes = suffixes(needle);
+    let mut good_suffix = vec![m; m as usize];
+
+    let mut j = 0;
+
+    for i in (0..m).rev() {
+        if suffixes[i as usize] == i + 1 {
+            while j < m - 1 - i {
+                if good_suffix[j as usize] == m {
+                    good_suffix[j as usize] = m - 1 - i;
+                }
+
+                j += 1;
+            }
+        }
+    }
+
+    for i in 0..(m - 1) {
+        good_suffix[(m - 1 - suffixes[i as usize]) as usize] = m - 1 - i;
+    }
+
+    good_suffix
+}
+
+fn needle(process: &Process, term: Term) -> anyhow::Result<Vec<u8>> {
+    let bytes = process
+        .bytes_from_binary(term)
+        .with_context(|| format!("pattern element ({}) is not a binary", term))
+        .context(PATTERN_CONTEXT)?;
+
+    if bytes.is_empty() {
+        Err(anyhow!("pattern element ({}) is empty", term)).context(PATTERN_CONTEXT)
+    } else {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// `suffixes[i]` is the length of the longest substring of `needle` ending at `i` that is also a
+/// suffix of `needle`.
+fn suffixes(needle: &[u8]) -> Vec<isize> {
+    let m = needle.len() as isize;
+    let mut suffixes = vec![0; m as usize];
+    suffixes[(m - 1) as usize] = m;
+
+    let mut f = 0;
+    let mut g = m - 1;
+
+    for i in (0..(m - 1)).rev() {
+        if i > g && suffixes[(i + m - 1 - f) as usize] < i - g {
+            suffixes[i as usize] = suffixes[(i + m - 1 - f) as usize];
+        } else {
+            if i < g {
+                g = i;
+            }
+
+            f = i;
+
+            while 0 <= g && needle[g as usize] == needle[(g + m - 1 - f) as usize] {
+                g -= 1;
+            }
+
+            suffixes[i as usize] = f - g;
+        }
+    }
+
+    suffixes
+}
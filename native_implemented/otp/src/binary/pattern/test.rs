@@ -0,0 +1,42 @@
+use proptest::collection::vec;
+use proptest::prop_assert_eq;
+use proptest::strategy::Strategy;
+
+use crate::binary::pattern::Pattern;
+
+// A small alphabet so that needles are likely to occur in the haystack
+fn bytes(max_len: usize) -> impl Strategy<Value = Vec<u8>> {
+    vec(0_u8..3, 0..max_len)
+}
+
+#[test]
+fn find_returns_same_as_naive_search() {
+    run!(|_| (bytes(32), vec(0_u8..3, 1..6)), |(haystack, needle)| {
+        let expected = haystack
+            .windows(needle.len())
+            .position(|window| window == needle.as_slice())
+            .map(|position| (position, needle.len()));
+        let pattern = Pattern::new(vec![needle]);
+
+        prop_assert_eq!(pattern.find(&haystack, 0, haystack.len()), expected);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn find_with_multiple_needles_returns_first_then_longest_match() {
+    let pattern = Pattern::new(vec![b"bc".to_vec(), b"b".to_vec(), b"abcd".to_vec()]);
+
+    assert_eq!(pattern.find(b"xabcd", 0, 5), Some((1, 4)));
+    assert_eq!(pattern.find(b"xabcd", 2, 5), Some((2, 2)));
+    assert_eq!(pattern.find(b"xabcd", 2, 3), Some((2, 1)));
+    assert_eq!(pattern.find(b"xabcd", 3, 5), None);
+}
+
+#[test]
+fn find_all_returns_non_overlapping_matches() {
+    let pattern = Pattern::new(vec![b"aa".to_vec()]);
+
+    assert_eq!(pattern.find_all(b"aaaaa", 0, 5), vec![(0, 2), (2, 2)]);
+}
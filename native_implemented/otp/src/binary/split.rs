@@ -0,0 +1,106 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::r#match::Scope;
+use crate::runtime::proplist::TryPropListFromTermError;
+
+pub struct Options {
+    pub global: bool,
+    pub scope: Option<Scope>,
+    pub trim: bool,
+    pub trim_all: bool,
+}
+
+const SUPPORTED_OPTIONS_CONTEXT: &str =
+    "supported options are global, trim, trim_all, and {scope, {start, length}}";
+
+impl Options {
+    fn put_option_atom(&mut self, atom: Atom) -> anyhow::Result<&Options> {
+        match atom.name() {
+            "global" => {
+                self.global = true;
+
+                Ok(self)
+            }
+            "trim" => {
+                self.trim = true;
+
+                Ok(self)
+            }
+            "trim_all" => {
+                self.trim_all = true;
+
+                Ok(self)
+            }
+            name => {
+                Err(TryPropListFromTermError::AtomName(name)).context(SUPPORTED_OPTIONS_CONTEXT)
+            }
+        }
+    }
+
+    fn put_option_term(&mut self, option: Term) -> anyhow::Result<&Options> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => self.put_option_atom(atom),
+            TypedTerm::Tuple(tuple) => self.put_option_tuple(&tuple),
+            _ => Err(TryPropListFromTermError::PropertyType).context(SUPPORTED_OPTIONS_CONTEXT),
+        }
+    }
+
+    fn put_option_tuple(&mut self, tuple: &Tuple) -> anyhow::Result<&Options> {
+        if tuple.len() == 2 {
+            let atom: Atom = tuple[0]
+                .try_into()
+                .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+            match atom.name() {
+                "scope" => {
+                    let scope = tuple[1].try_into()?;
+                    self.scope = Some(scope);
+
+                    Ok(self)
+                }
+                name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                    .context(SUPPORTED_OPTIONS_CONTEXT),
+            }
+        } else {
+            Err(TryPropListFromTermError::TupleNotPair).context(SUPPORTED_OPTIONS_CONTEXT)
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            };
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            global: false,
+            scope: None,
+            trim: false,
+            trim_all: false,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::binary::pattern::Pattern;
+use crate::binary::split::Options;
+use crate::erlang::binary_part_3;
+use crate::runtime::context::*;
+
+/// Splits `subject` into the parts between matches of `pattern`.  The parts are subbinaries that
+/// share the data of `subject` instead of copying it.
+#[native_implemented::function(binary:split/3)]
+pub fn result(
+    process: &Process,
+    subject: Term,
+    pattern: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let options: Options = options.try_into()?;
+    let pattern = Pattern::try_from_term(process, pattern)?;
+    let bytes = process
+        .bytes_from_binary(subject)
+        .with_context(|| term_is_not_binary("subject", subject))?;
+    let byte_len = bytes.len();
+    let range = match options.scope {
+        Some(scope) => scope.range(byte_len)?,
+        None => 0..byte_len,
+    };
+
+    let matches = if options.global {
+        pattern.find_all(bytes, range.start, range.end)
+    } else {
+        pattern
+            .find(bytes, range.start, range.end)
+            .into_iter()
+            .collect()
+    };
+
+    let mut position_lengths: Vec<(usize, usize)> = Vec::with_capacity(matches.len() + 1);
+    let mut position = 0;
+
+    for (match_position, match_length) in matches {
+        position_lengths.push((position, match_position - position));
+        position = match_position + match_length;
+    }
+
+    position_lengths.push((position, byte_len - position));
+
+    if options.trim_all {
+        position_lengths.retain(|(_, length)| 0 < *length);
+    } else if options.trim {
+        while let Some((_, 0)) = position_lengths.last() {
+            position_lengths.pop();
+        }
+    }
+
+    let mut parts: Vec<Term> = Vec::with_capacity(position_lengths.len());
+
+    for (position, length) in position_lengths {
+        let part = binary_part_3::result(
+            process,
+            subject,
+            process.integer(position),
+            process.integer(length),
+        )?;
+        parts.push(part);
+    }
+
+    Ok(process.list_from_slice(&parts))
+}
@@ -0,0 +1,15 @@
+//! Mirrors [code](http://erlang.org/doc/man/code.html) module
+
+pub mod load_binary_3;
+pub mod purge_1;
+pub mod soft_purge_1;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("code")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,35 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::code;
+
+#[native_implemented::function(code:load_binary/3)]
+pub fn result(
+    process: &Process,
+    module: Term,
+    filename: Term,
+    binary: Term,
+) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom!(module)?;
+    let filename_string = list_to_string(filename)?;
+    let bytes = process
+        .bytes_from_binary(binary)
+        .with_context(|| format!("binary ({})", binary))?;
+
+    let tuple = match code::load_binary(module_atom, &filename_string, bytes) {
+        Ok(()) => process.tuple_from_slice(&[atom!("module"), module]),
+        Err(load_error) => {
+            process.tuple_from_slice(&[atom!("error"), Atom::str_to_term(load_error.reason())])
+        }
+    };
+
+    Ok(tuple)
+}
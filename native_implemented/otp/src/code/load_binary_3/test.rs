@@ -0,0 +1,66 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::code::load_binary_3::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, module)| {
+            let filename = arc_process.charlist_from_str("load_binary_3.so");
+            let binary = arc_process.binary_from_bytes(&[]);
+
+            prop_assert_is_not_atom!(result(&arc_process, module, filename, binary), module);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_binary_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_binary(arc_process.clone()),
+            )
+        },
+        |(arc_process, binary)| {
+            let module = Atom::str_to_term("load_binary_3_without_binary");
+            let filename = arc_process.charlist_from_str("load_binary_3.so");
+
+            prop_assert_badarg!(
+                result(&arc_process, module, filename, binary),
+                format!("binary ({})", binary)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_native_object_returns_badfile_error() {
+    with_process(|process| {
+        let module = Atom::str_to_term("load_binary_3_without_native_object");
+        let filename = process.charlist_from_str("load_binary_3_without_native_object.beam");
+        // The start of a BEAM file
+        let binary = process.binary_from_bytes(b"FOR1\0\0\0\0BEAM");
+
+        assert_eq!(
+            result(process, module, filename, binary),
+            Ok(process.tuple_from_slice(&[atom!("error"), atom!("badfile")]))
+        );
+    });
+}
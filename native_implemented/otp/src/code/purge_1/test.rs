@@ -0,0 +1,24 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::code::purge_1::result;
+use crate::test::strategy;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| strategy::term::is_not_atom(arc_process.clone()),
+        |module| {
+            prop_assert_is_not_atom!(result(module), module);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_old_code_returns_false() {
+    assert_eq!(
+        result(Atom::str_to_term("purge_1_without_old_code")),
+        Ok(false.into())
+    );
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::code;
+
+#[native_implemented::function(code:soft_purge/1)]
+pub fn result(module: Term) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom!(module)?;
+
+    Ok(code::soft_purge(module_atom).into())
+}
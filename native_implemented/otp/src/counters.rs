@@ -0,0 +1,19 @@
+//! Mirrors [counters](http://erlang.org/doc/man/counters.html) module
+
+pub mod add_3;
+mod array;
+pub mod get_2;
+pub mod info_1;
+pub mod new_2;
+pub mod put_3;
+pub mod sub_3;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("counters")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
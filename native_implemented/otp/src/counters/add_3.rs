@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics;
+use crate::counters::array::Array;
+
+/// Wraps around on overflow.
+#[native_implemented::function(counters:add/3)]
+pub fn result(reference: Term, index: Term, incr: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let incr_bits = atomics::array::Array::incr_try_into_bits(incr)?;
+
+    atomic.fetch_add(incr_bits, Ordering::SeqCst);
+
+    Ok(atom!("ok"))
+}
@@ -0,0 +1,26 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::counters::{add_3, get_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_incr_adds_to_value() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            add_3::result(reference, index, process.integer(2)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            add_3::result(reference, index, process.integer(3)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index),
+            Ok(process.integer(5))
+        );
+    });
+}
@@ -0,0 +1,39 @@
+use std::convert::TryInto;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics;
+use crate::runtime::context::*;
+
+/// Signed atomics that wrap around on overflow.
+///
+/// A distinct type from `atomics::array::Array`, so that a counters reference is not accepted by
+/// the `atomics` functions and vice versa.
+pub struct Array(atomics::array::Array);
+
+impl Array {
+    pub fn new(size: usize) -> Self {
+        Self(atomics::array::Array::new(size, true))
+    }
+
+    pub fn try_from_term(term: Term) -> anyhow::Result<Arc<Self>> {
+        let option_array = term
+            .try_into()
+            .ok()
+            .and_then(|resource: Resource| resource.downcast_ref::<Arc<Array>>().cloned());
+
+        option_array.with_context(|| term_is_not_type("reference", term, "a counters reference"))
+    }
+}
+
+impl Deref for Array {
+    type Target = atomics::array::Array;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,29 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics;
+use crate::counters::{get_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_atomics_reference_errors_badarg() {
+    with_process(|process| {
+        let reference = atomics::new_2::result(process, process.integer(1), Term::NIL).unwrap();
+
+        assert_badarg!(
+            get_2::result(process, reference, process.integer(1)),
+            "is not a counters reference"
+        );
+    });
+}
+
+#[test]
+fn with_counters_reference_is_not_an_atomics_reference() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+
+        assert_badarg!(
+            atomics::get_2::result(process, reference, process.integer(1)),
+            "is not an atomics reference"
+        );
+    });
+}
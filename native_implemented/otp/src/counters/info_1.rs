@@ -0,0 +1,16 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::counters::array::Array;
+
+#[native_implemented::function(counters:info/1)]
+pub fn result(process: &Process, reference: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+
+    Ok(process.map_from_slice(&[
+        (atom!("size"), process.integer(array.arity())),
+        (atom!("memory"), process.integer(array.memory())),
+    ]))
+}
@@ -0,0 +1,69 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::{TryFrom, TryInto};
+use std::sync::Arc;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics;
+use crate::counters::array::Array;
+use crate::runtime::proplist::TryPropListFromTermError;
+
+/// Both `atomics` and `write_concurrency` counters are a single array of atomics, so the options are
+/// only validated.
+#[native_implemented::function(counters:new/2)]
+pub fn result(process: &Process, size: Term, options: Term) -> exception::Result<Term> {
+    let size_usize = atomics::array::Array::arity_try_from_term(size)?;
+    let _: Options = options.try_into()?;
+
+    Ok(process.resource(Arc::new(Array::new(size_usize))))
+}
+
+// Private
+
+const SUPPORTED_OPTIONS_CONTEXT: &str = "supported options are atomics and write_concurrency";
+
+struct Options;
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> anyhow::Result<&Options> {
+        let atom: Atom = option
+            .try_into()
+            .map_err(|_| TryPropListFromTermError::PropertyType)
+            .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+        match atom.name() {
+            "atomics" | "write_concurrency" => Ok(self),
+            name => {
+                Err(TryPropListFromTermError::AtomName(name)).context(SUPPORTED_OPTIONS_CONTEXT)
+            }
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options = Options;
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            };
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::counters::{get_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[atom!("unsupported")]);
+
+        assert_badarg!(
+            new_2::result(process, process.integer(1), options),
+            "supported options are atomics and write_concurrency"
+        );
+    });
+}
+
+#[test]
+fn with_supported_options_returns_reference_to_counters_initialized_to_zero() {
+    with_process(|process| {
+        for option in &[atom!("atomics"), atom!("write_concurrency")] {
+            let options = process.list_from_slice(&[*option]);
+            let reference = new_2::result(process, process.integer(1), options).unwrap();
+
+            assert_eq!(
+                get_2::result(process, reference, process.integer(1)),
+                Ok(process.integer(0))
+            );
+        }
+    });
+}
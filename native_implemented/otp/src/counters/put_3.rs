@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::counters::array::Array;
+
+#[native_implemented::function(counters:put/3)]
+pub fn result(reference: Term, index: Term, value: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let bits = array.value_try_into_bits("value", value)?;
+
+    atomic.store(bits, Ordering::SeqCst);
+
+    Ok(atom!("ok"))
+}
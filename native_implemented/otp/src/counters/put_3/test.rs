@@ -0,0 +1,22 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::counters::{get_2, new_2, put_3};
+use crate::test::with_process;
+
+#[test]
+fn with_value_stores_value() {
+    with_process(|process| {
+        let reference = new_2::result(process, process.integer(1), Term::NIL).unwrap();
+        let index = process.integer(1);
+
+        assert_eq!(
+            put_3::result(reference, index, process.integer(-5)),
+            Ok(atom!("ok"))
+        );
+        assert_eq!(
+            get_2::result(process, reference, index),
+            Ok(process.integer(-5))
+        );
+    });
+}
@@ -0,0 +1,20 @@
+use std::sync::atomic::Ordering;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::atomics;
+use crate::counters::array::Array;
+
+/// Wraps around on overflow.
+#[native_implemented::function(counters:sub/3)]
+pub fn result(reference: Term, index: Term, decr: Term) -> exception::Result<Term> {
+    let array = Array::try_from_term(reference)?;
+    let atomic = array.atomic(index)?;
+    let decr_bits = atomics::array::Array::incr_try_into_bits(decr)?;
+
+    atomic.fetch_sub(decr_bits, Ordering::SeqCst);
+
+    Ok(atom!("ok"))
+}
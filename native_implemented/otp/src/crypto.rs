@@ -0,0 +1,80 @@
+//! Mirrors [crypto](http://erlang.org/doc/man/crypto.html) module
+//!
+//! Only hashing, HMAC and random bytes are supported.  They use pure-Rust implementations instead
+//! of OpenSSL, so they are also available on wasm32.
+
+pub mod hash_2;
+pub mod mac_4;
+pub mod strong_rand_bytes_1;
+
+use anyhow::*;
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256, Sha512};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_atom;
+
+fn module() -> Atom {
+    Atom::from_str("crypto")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// The hash algorithms of `hash/2`, which are also the sub types of `mac(hmac, ...)`
+#[derive(Clone, Copy)]
+enum HashType {
+    Md5,
+    Sha,
+    Sha256,
+    Sha512,
+}
+impl HashType {
+    fn digest(self, data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Md5 => Md5::digest(data).to_vec(),
+            Self::Sha => Sha1::digest(data).to_vec(),
+            Self::Sha256 => Sha256::digest(data).to_vec(),
+            Self::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+
+    fn hmac(self, key: &[u8], data: &[u8]) -> Vec<u8> {
+        match self {
+            Self::Md5 => mac::<Hmac<Md5>>(key, data),
+            Self::Sha => mac::<Hmac<Sha1>>(key, data),
+            Self::Sha256 => mac::<Hmac<Sha256>>(key, data),
+            Self::Sha512 => mac::<Hmac<Sha512>>(key, data),
+        }
+    }
+}
+
+fn mac<M: Mac>(key: &[u8], data: &[u8]) -> Vec<u8> {
+    // Keys longer than the block size are hashed and shorter ones padded, so any length is valid
+    let mut mac = M::new_varkey(key).expect("HMAC accepts keys of any length");
+    mac.input(data);
+
+    mac.result().code().to_vec()
+}
+
+fn term_try_into_hash_type(name: &str, value: Term) -> exception::Result<HashType> {
+    let atom = term_try_into_atom(name, value)?;
+
+    match atom.name() {
+        "md5" => Ok(HashType::Md5),
+        "sha" => Ok(HashType::Sha),
+        "sha256" => Ok(HashType::Sha256),
+        "sha512" => Ok(HashType::Sha512),
+        _ => Err(anyhow!(
+            "{} ({}) is not a supported hash type (md5, sha, sha256, or sha512)",
+            name,
+            value
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+
+/// The digest of `data`, which is iodata, as a binary
+#[native_implemented::function(crypto:hash/2)]
+pub fn result(process: &Process, r#type: Term, data: Term) -> exception::Result<Term> {
+    let hash_type = super::term_try_into_hash_type("type", r#type)?;
+    let data_bytes = iolist_or_binary::to_byte_vec("data", data)?;
+
+    Ok(process.binary_from_bytes(&hash_type.digest(&data_bytes)))
+}
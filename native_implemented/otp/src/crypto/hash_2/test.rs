@@ -0,0 +1,100 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::crypto::hash_2::result;
+use crate::test::{hex_to_bytes, with_process};
+
+#[test]
+fn without_supported_type_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("md4"), process.binary_from_str("abc")),
+            "type (md4) is not a supported hash type (md5, sha, sha256, or sha512)"
+        );
+    });
+}
+
+#[test]
+fn without_iodata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("sha"), atom!("data")),
+            "data (data) element (data) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn with_md5_returns_digest() {
+    with_process(|process| {
+        assert_digest(
+            process,
+            "md5",
+            process.binary_from_str(""),
+            "d41d8cd98f00b204e9800998ecf8427e",
+        );
+    });
+}
+
+#[test]
+fn with_sha_returns_digest() {
+    with_process(|process| {
+        assert_digest(
+            process,
+            "sha",
+            process.binary_from_str("abc"),
+            "a9993e364706816aba3e25717850c26c9cd0d89d",
+        );
+    });
+}
+
+#[test]
+fn with_sha256_returns_digest() {
+    with_process(|process| {
+        assert_digest(
+            process,
+            "sha256",
+            process.binary_from_str("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+        );
+    });
+}
+
+#[test]
+fn with_sha512_returns_digest() {
+    with_process(|process| {
+        assert_digest(
+            process,
+            "sha512",
+            process.binary_from_str("abc"),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+        );
+    });
+}
+
+#[test]
+fn with_iolist_returns_digest_of_bytes() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.integer(b'a'),
+            process.list_from_slice(&[process.binary_from_str("b")]),
+            process.binary_from_str("c"),
+        ]);
+
+        assert_digest(
+            process,
+            "sha",
+            data,
+            "a9993e364706816aba3e25717850c26c9cd0d89d",
+        );
+    });
+}
+
+fn assert_digest(process: &Process, r#type: &str, data: Term, expected_hex: &str) {
+    assert_eq!(
+        result(process, Atom::str_to_term(r#type), data),
+        Ok(process.binary_from_bytes(&hex_to_bytes(expected_hex)))
+    );
+}
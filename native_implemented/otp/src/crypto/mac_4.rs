@@ -0,0 +1,38 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::context::term_try_into_atom;
+
+/// The MAC of `data` under `key`, both iodata, as a binary.  Only `hmac` is supported as the
+/// `type`, with the hash types of `hash/2` as the `sub_type`.
+#[native_implemented::function(crypto:mac/4)]
+pub fn result(
+    process: &Process,
+    r#type: Term,
+    sub_type: Term,
+    key: Term,
+    data: Term,
+) -> exception::Result<Term> {
+    let type_atom = term_try_into_atom("type", r#type)?;
+
+    if type_atom.name() != "hmac" {
+        return Err(anyhow!(
+            "type ({}) is not a supported mac type, which is only hmac",
+            r#type
+        )
+        .into());
+    }
+
+    let hash_type = super::term_try_into_hash_type("sub_type", sub_type)?;
+    let key_bytes = iolist_or_binary::to_byte_vec("key", key)?;
+    let data_bytes = iolist_or_binary::to_byte_vec("data", data)?;
+
+    Ok(process.binary_from_bytes(&hash_type.hmac(&key_bytes, &data_bytes)))
+}
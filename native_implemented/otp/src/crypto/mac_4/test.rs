@@ -0,0 +1,106 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::crypto::mac_4::result;
+use crate::test::{hex_to_bytes, with_process};
+
+const DATA: &str = "The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn without_hmac_type_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                atom!("cmac"),
+                atom!("sha256"),
+                process.binary_from_str("key"),
+                process.binary_from_str(DATA)
+            ),
+            "type (cmac) is not a supported mac type, which is only hmac"
+        );
+    });
+}
+
+#[test]
+fn without_supported_sub_type_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                atom!("hmac"),
+                atom!("sha3_256"),
+                process.binary_from_str("key"),
+                process.binary_from_str(DATA)
+            ),
+            "sub_type (sha3_256) is not a supported hash type (md5, sha, sha256, or sha512)"
+        );
+    });
+}
+
+#[test]
+fn without_iodata_key_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                atom!("hmac"),
+                atom!("sha256"),
+                atom!("key"),
+                process.binary_from_str(DATA)
+            ),
+            "key (key) element (key) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn with_md5_returns_hmac() {
+    with_process(|process| {
+        assert_hmac(process, "md5", "80070713463e7749b90c2dc24911e275");
+    });
+}
+
+#[test]
+fn with_sha_returns_hmac() {
+    with_process(|process| {
+        assert_hmac(process, "sha", "de7c9b85b8b78aa6bc8a7a36f70a90701c9db4d9");
+    });
+}
+
+#[test]
+fn with_sha256_returns_hmac() {
+    with_process(|process| {
+        assert_hmac(
+            process,
+            "sha256",
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8",
+        );
+    });
+}
+
+#[test]
+fn with_sha512_returns_hmac() {
+    with_process(|process| {
+        assert_hmac(
+            process,
+            "sha512",
+            "b42af09057bac1e2d41708e48a902e09b5ff7f12ab428a4fe86653c73dd248fb\
+             82f948a549f7b791a5b41915ee4d1ec3935357e4e2317250d0372afa2ebeeb3a",
+        );
+    });
+}
+
+fn assert_hmac(process: &Process, sub_type: &str, expected_hex: &str) {
+    assert_eq!(
+        result(
+            process,
+            atom!("hmac"),
+            Atom::str_to_term(sub_type),
+            process.binary_from_str("key"),
+            process.binary_from_str(DATA)
+        ),
+        Ok(process.binary_from_bytes(&hex_to_bytes(expected_hex)))
+    );
+}
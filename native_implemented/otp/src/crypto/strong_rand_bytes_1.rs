@@ -0,0 +1,25 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_non_negative_integer;
+use crate::runtime::sys::entropy;
+
+/// `n` bytes of host entropy, which are suitable for keys and tokens, as a binary
+#[native_implemented::function(crypto:strong_rand_bytes/1)]
+pub fn result(process: &Process, n: Term) -> exception::Result<Term> {
+    let n_usize: usize = n
+        .try_into()
+        .with_context(|| term_is_not_non_negative_integer("n", n))?;
+    let mut bytes = vec![0; n_usize];
+    entropy::fill_bytes(&mut bytes);
+
+    Ok(process.binary_from_bytes(&bytes))
+}
@@ -0,0 +1,42 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::crypto::strong_rand_bytes_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_non_negative_integer_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("sixteen")),
+            "n (sixteen) is not a non-negative integer"
+        );
+        assert_badarg!(
+            result(process, process.integer(-1)),
+            "n (-1) is not a non-negative integer"
+        );
+    });
+}
+
+#[test]
+fn with_zero_returns_empty_binary() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.integer(0)),
+            Ok(process.binary_from_bytes(&[]))
+        );
+    });
+}
+
+#[test]
+fn with_positive_integer_returns_binary_with_that_many_random_bytes() {
+    with_process(|process| {
+        let first = result(process, process.integer(32)).unwrap();
+        let first_binary: Boxed<HeapBin> = first.try_into().unwrap();
+
+        assert_eq!(first_binary.full_byte_len(), 32);
+        assert_ne!(result(process, process.integer(32)), Ok(first));
+    });
+}
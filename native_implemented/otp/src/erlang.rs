@@ -39,6 +39,7 @@ pub mod cancel_timer_1;
 pub mod cancel_timer_2;
 pub mod ceil_1;
 mod charlist_to_string;
+pub mod check_process_code_2;
 pub mod concatenate_2;
 pub mod convert_time_unit_3;
 pub mod date_0;
@@ -54,6 +55,7 @@ pub mod erase_1;
 pub mod error_1;
 pub mod error_2;
 pub mod exit_1;
+pub mod exit_2;
 pub mod float_1;
 pub mod float_to_binary_1;
 pub mod float_to_binary_2;
@@ -61,7 +63,11 @@ pub mod float_to_list_1;
 pub mod float_to_list_2;
 mod float_to_string;
 pub mod floor_1;
+pub mod fun_info_1;
+pub mod fun_info_2;
 pub mod function_exported_3;
+pub mod garbage_collect_0;
+pub mod garbage_collect_1;
 pub mod get_0;
 pub mod get_1;
 pub mod get_keys_0;
@@ -70,13 +76,14 @@ pub mod get_stacktrace_0;
 pub mod group_leader_0;
 pub mod group_leader_2;
 pub mod hd_1;
+pub mod hibernate_3;
 pub mod insert_element_3;
 pub mod integer_to_binary_1;
 pub mod integer_to_binary_2;
 pub mod integer_to_list_1;
 pub mod integer_to_list_2;
 mod integer_to_string;
-mod iolist_or_binary;
+pub mod iolist_or_binary;
 pub mod iolist_size_1;
 pub mod iolist_to_binary_1;
 pub mod iolist_to_iovec_1;
@@ -113,10 +120,11 @@ pub mod list_to_float_1;
 pub mod list_to_integer_1;
 pub mod list_to_integer_2;
 pub mod list_to_pid_1;
-mod list_to_string;
+pub mod list_to_string;
 pub mod list_to_tuple_1;
 pub mod load_nif_2;
 pub mod localtime_0;
+pub mod make_fun_3;
 pub mod make_ref_0;
 pub mod make_tuple_2;
 pub mod make_tuple_3;
@@ -132,13 +140,20 @@ pub mod multiply_2;
 pub mod negate_1;
 pub mod nif_error_1;
 pub mod node_0;
+pub mod node_1;
 pub mod not_1;
 pub mod now_0;
 pub mod number_or_badarith_1;
 mod number_to_integer;
+pub mod open_port_2;
 pub mod or_2;
 pub mod orelse_2;
+pub mod phash2_1;
+pub mod phash2_2;
+pub mod port_close_1;
+pub mod port_command_2;
 pub mod process_flag_2;
+pub mod process_info_1;
 pub mod process_info_2;
 pub mod put_2;
 pub mod raise_3;
@@ -169,10 +184,12 @@ pub mod spawn_link_3;
 pub mod spawn_monitor_1;
 pub mod spawn_monitor_3;
 pub mod spawn_opt_2;
+pub mod spawn_opt_3;
 pub mod spawn_opt_4;
 pub mod split_binary_2;
 pub mod start_timer_3;
 pub mod start_timer_4;
+pub mod statistics_1;
 mod string_to_float;
 mod string_to_integer;
 pub mod subtract_2;
@@ -183,12 +200,17 @@ pub mod system_time_0;
 pub mod system_time_1;
 mod term_to_binary;
 pub mod term_to_binary_1;
+pub mod term_to_binary_2;
 pub mod throw_1;
 pub mod time_0;
 pub mod time_offset_0;
 pub mod time_offset_1;
 pub mod timestamp_0;
 pub mod tl_1;
+pub mod trace;
+pub mod trace_3;
+pub mod trace_info_2;
+pub mod trace_pattern_2;
 pub mod trunc_1;
 pub mod tuple_size_1;
 pub mod tuple_to_list_1;
@@ -208,7 +230,7 @@ use anyhow::*;
 
 use liblumen_alloc::atom;
 use liblumen_alloc::erts::exception::{self, InternalResult};
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{MaxHeapSize, Process};
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::time::{Milliseconds, Monotonic};
 
@@ -271,6 +293,21 @@ fn cancel_timer(
     Ok(term)
 }
 
+/// The `max_heap_size` map returned by `process_info/2`, `system_info/1`, and `system_flag/2`
+fn max_heap_size_to_term(process: &Process, max_heap_size: MaxHeapSize) -> Term {
+    process.map_from_slice(&[
+        (
+            atom!(MaxHeapSize::ERROR_LOGGER_KEY),
+            max_heap_size.error_logger.into(),
+        ),
+        (atom!(MaxHeapSize::KILL_KEY), max_heap_size.kill.into()),
+        (
+            atom!(MaxHeapSize::SIZE_KEY),
+            process.integer(max_heap_size.size),
+        ),
+    ])
+}
+
 fn is_record(term: Term, record_tag: Term, size: Option<Term>) -> exception::Result<Term> {
     match term.decode()? {
         TypedTerm::Tuple(tuple) => {
@@ -1,11 +1,18 @@
 use std::convert::TryInto;
+use std::ffi::c_void;
+use std::mem;
+use std::ptr::NonNull;
 
 use anyhow::*;
 
+use liblumen_alloc::erts::apply::find_symbol;
 use liblumen_alloc::erts::exception::{self, badarity};
 use liblumen_alloc::erts::process::{trace::Trace, FrameWithArguments, Process};
+use liblumen_alloc::erts::term::closure::Definition;
 use liblumen_alloc::erts::term::prelude::*;
 
+use crate::erlang::apply_3::undef_source;
+
 extern "Rust" {
     #[link_name = "lumen_rt_apply_2"]
     fn runtime_apply_2(function_boxed_closure: Boxed<Closure>, arguments: Vec<Term>) -> Term;
@@ -22,7 +29,10 @@ fn result(process: &Process, function: Term, arguments: Term) -> exception::Resu
     let arity = function_boxed_closure.arity() as usize;
 
     if arguments_len == arity {
-        Ok(unsafe { runtime_apply_2(function_boxed_closure, argument_vec) })
+        let callable_boxed_closure =
+            resolve_export(process, function_boxed_closure, &argument_vec)?;
+
+        Ok(unsafe { runtime_apply_2(callable_boxed_closure, argument_vec) })
     } else {
         let mfa = function_boxed_closure.module_function_arity();
         let trace = Trace::capture();
@@ -47,6 +57,40 @@ fn result(process: &Process, function: Term, arguments: Term) -> exception::Resu
     }
 }
 
+/// External funs made before their module was loaded, such as by `erlang:make_fun/3`, have no
+/// native function, so it is looked up again now, raising `undef` if it still does not exist.
+fn resolve_export(
+    process: &Process,
+    function_boxed_closure: Boxed<Closure>,
+    argument_vec: &[Term],
+) -> exception::Result<Boxed<Closure>> {
+    match function_boxed_closure.definition() {
+        Definition::Export { function } if function_boxed_closure.callee().is_none() => {
+            let mfa = function_boxed_closure.module_function_arity();
+
+            match find_symbol(&mfa) {
+                Some(dynamic_callee) => {
+                    let native = unsafe {
+                        let ptr = mem::transmute::<_, *mut c_void>(dynamic_callee);
+                        NonNull::new_unchecked(ptr)
+                    };
+                    let resolved =
+                        process.export_closure(mfa.module, *function, mfa.arity, Some(native));
+
+                    Ok(resolved.try_into().unwrap())
+                }
+                None => {
+                    let trace = Trace::capture();
+                    trace.set_top_frame(&mfa, argument_vec);
+
+                    Err(exception::undef(trace, Some(undef_source(&mfa).into())).into())
+                }
+            }
+        }
+        _ => Ok(function_boxed_closure),
+    }
+}
+
 fn argument_list_to_vec(list: Term) -> exception::Result<Vec<Term>> {
     let mut vec = Vec::new();
 
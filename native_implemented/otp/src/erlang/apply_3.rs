@@ -2,13 +2,15 @@ use anyhow::*;
 
 use liblumen_core::sys::dynamic_call::DynamicCallee;
 
-use liblumen_alloc::erts::apply::find_symbol;
+use liblumen_alloc::erts::apply::{find_symbol, module_loaded};
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::{Arity, ModuleFunctionArity};
 
 use crate::erlang::apply::arguments_term_to_vec;
+use crate::runtime::trace;
 
 extern "Rust" {
     #[link_name = "lumen_rt_apply_3"]
@@ -20,7 +22,12 @@ extern "Rust" {
 }
 
 #[native_implemented::function(erlang:apply/3)]
-fn result(module: Term, function: Term, arguments: Term) -> exception::Result<Term> {
+fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arguments: Term,
+) -> exception::Result<Term> {
     let module_atom = term_try_into_atom!(module)?;
     let function_atom = term_try_into_atom!(function)?;
     let argument_vec = arguments_term_to_vec(arguments)?;
@@ -33,23 +40,42 @@ fn result(module: Term, function: Term, arguments: Term) -> exception::Result<Te
     };
 
     match find_symbol(&module_function_arity) {
-        Some(callee) => Ok(unsafe { runtime_apply_3(module_function_arity, callee, argument_vec) }),
+        Some(callee) => {
+            trace::call(process, &module_function_arity, arguments);
+
+            Ok(unsafe { runtime_apply_3(module_function_arity, callee, argument_vec) })
+        }
         None => {
             let trace = Trace::capture();
             trace.set_top_frame(&module_function_arity, argument_vec.as_slice());
-            Err(exception::undef(
-                trace,
-                Some(
-                    anyhow!(
-                        "{}:{}/{} is not exported",
-                        module_atom.name(),
-                        function_atom.name(),
-                        arity
-                    )
-                    .into(),
-                ),
-            )
-            .into())
+            Err(exception::undef(trace, Some(undef_source(&module_function_arity).into())).into())
         }
     }
 }
+
+pub(in crate::erlang) fn undef_source(
+    module_function_arity: &ModuleFunctionArity,
+) -> anyhow::Error {
+    let ModuleFunctionArity {
+        module,
+        function,
+        arity,
+    } = module_function_arity;
+
+    if module_loaded(*module) {
+        anyhow!(
+            "{}:{}/{} is not exported",
+            module.name(),
+            function.name(),
+            arity
+        )
+    } else {
+        anyhow!(
+            "{}:{}/{} is undefined because module ({}) is not loaded",
+            module.name(),
+            function.name(),
+            arity,
+            module.name()
+        )
+    }
+}
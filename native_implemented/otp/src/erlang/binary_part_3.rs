@@ -55,6 +55,21 @@ pub fn result(
 
             Ok(binary_part)
         }
+        TypedTerm::BinaryLiteral(binary_literal) => {
+            let available_byte_count = binary_literal.full_byte_len();
+            let PartRange {
+                byte_offset,
+                byte_len,
+            } = start_length_to_part_range(start_usize, length_isize, available_byte_count)?;
+
+            let binary_part = if (byte_offset == 0) && (byte_len == available_byte_count) {
+                binary
+            } else {
+                process.subbinary_from_original(binary, byte_offset, 0, byte_len, 0)
+            };
+
+            Ok(binary_part)
+        }
         TypedTerm::SubBinary(subbinary) => {
             let PartRange {
                 byte_offset,
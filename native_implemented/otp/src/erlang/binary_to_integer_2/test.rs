@@ -1,3 +1,4 @@
+use proptest::prop_assert_eq;
 use proptest::strategy::{Just, Strategy};
 
 use crate::erlang::binary_to_integer_2::result;
@@ -77,3 +78,40 @@ fn with_binary_without_integer_in_base_errors_badarg() {
         },
     );
 }
+
+#[test]
+fn with_binary_with_sign_returns_integer() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::base::base()),
+        |(arc_process, base)| {
+            let base_term = arc_process.integer(base);
+
+            for (string, expected) in &[("+10", base as isize), ("-10", -(base as isize))] {
+                let binary = arc_process.binary_from_str(string);
+
+                prop_assert_eq!(
+                    result(&arc_process, binary, base_term),
+                    Ok(arc_process.integer(*expected))
+                );
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_binary_with_separators_or_extra_signs_errors_badarg() {
+    crate::test::with_process_arc(|arc_process| {
+        let base = arc_process.integer(10);
+
+        for string in &["", "+", "1_0", " 10", "10 ", "+-10", "--10"] {
+            let binary = arc_process.binary_from_str(string);
+
+            assert_badarg!(
+                result(&arc_process, binary, base),
+                format!("binary ({}) is not in base ({})", binary, base)
+            );
+        }
+    });
+}
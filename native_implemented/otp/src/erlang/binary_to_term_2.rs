@@ -11,7 +11,7 @@ use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::binary::to_term::Options;
-use crate::runtime::distribution::external_term_format::{term, version};
+use crate::runtime::distribution::external_term_format::{compressed, term, version, Tag};
 
 macro_rules! maybe_aligned_maybe_binary_try_into_term {
     ($process:expr, $options:expr, $binary:expr, $ident:expr) => {
@@ -64,8 +64,17 @@ fn versioned_tagged_bytes_try_into_term(
     bytes: &[u8],
 ) -> exception::Result<Term> {
     let after_version_bytes = version::check(bytes)?;
-    let (term, after_term_bytes) =
-        term::decode_tagged(process, options.existing, after_version_bytes)?;
+
+    let (term, after_term_bytes) = match Tag::decode(after_version_bytes)? {
+        (Tag::Compressed, after_tag_bytes) => {
+            let (uncompressed_byte_vec, after_compressed_bytes) =
+                compressed::decode(after_tag_bytes)?;
+            let (term, _) = term::decode_tagged(process, options.existing, &uncompressed_byte_vec)?;
+
+            (term, after_compressed_bytes)
+        }
+        _ => term::decode_tagged(process, options.existing, after_version_bytes)?,
+    };
 
     let final_term = if options.used {
         let used_byte_len = bytes.len() - after_term_bytes.len();
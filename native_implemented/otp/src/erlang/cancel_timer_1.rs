@@ -1,3 +1,6 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
 use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::Term;
@@ -0,0 +1,53 @@
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::time::Milliseconds;
+
+use crate::erlang::cancel_timer_1::result;
+use crate::test::*;
+
+#[test]
+fn without_timeout_returns_milliseconds_remaining_and_does_not_send_timeout_message() {
+    with_timer_in_same_thread(|milliseconds, message, timer_reference, process| {
+        let start_monotonic = freeze_timeout();
+        freeze_at_timeout(start_monotonic + milliseconds / 2 + Milliseconds(1));
+
+        let milliseconds_remaining =
+            result(process, timer_reference).expect("Timer could not be canceled");
+
+        assert!(milliseconds_remaining.is_integer());
+        assert!(process.integer(0) < milliseconds_remaining);
+        assert!(milliseconds_remaining <= process.integer(milliseconds / 2));
+
+        // The message is dropped with the timer instead of being sent
+        freeze_at_timeout(start_monotonic + milliseconds + Milliseconds(1));
+
+        let timeout_message = timeout_message(timer_reference, message, process);
+
+        assert!(!has_message(process, timeout_message));
+        assert_eq!(process.mailbox.lock().borrow().len(), 0);
+
+        // again after timeout
+        assert_eq!(result(process, timer_reference), Ok(false.into()));
+    })
+}
+
+#[test]
+fn with_timeout_returns_false_and_timeout_message_outlives_timer() {
+    with_timer_in_same_thread(|milliseconds, message, timer_reference, process| {
+        let start_monotonic = freeze_timeout();
+        freeze_at_timeout(start_monotonic + milliseconds + Milliseconds(1));
+
+        assert_eq!(result(process, timer_reference), Ok(false.into()));
+
+        // The fragment holding the message is owned by the process once sent, so it can still be
+        // received after the timer is gone
+        let timeout_message = timeout_message(timer_reference, message, process);
+
+        assert!(has_heap_message(process, timeout_message));
+        assert_eq!(receive_message(process), Some(timeout_message));
+    })
+}
+
+#[test]
+fn without_timer_returns_false() {
+    crate::test::without_timer_returns_false(result);
+}
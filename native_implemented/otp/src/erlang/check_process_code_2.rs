@@ -0,0 +1,27 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::code;
+use crate::runtime::registry::pid_to_process;
+
+#[native_implemented::function(erlang:check_process_code/2)]
+pub fn result(process: &Process, pid: Term, module: Term) -> exception::Result<Term> {
+    let pid_pid = term_try_into_local_pid!(pid)?;
+    let module_atom = term_try_into_atom!(module)?;
+
+    let running_old_code = if pid_pid == process.pid() {
+        code::check_process_code(process, module_atom)
+    } else {
+        // A process that doesn't exist isn't running any code
+        match pid_to_process(&pid_pid) {
+            Some(arc_process) => code::check_process_code(&arc_process, module_atom),
+            None => false,
+        }
+    };
+
+    Ok(running_old_code.into())
+}
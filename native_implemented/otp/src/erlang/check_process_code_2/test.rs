@@ -0,0 +1,72 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::check_process_code_2::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_pid(arc_process),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_is_not_local_pid!(
+                result(&arc_process, pid, Atom::str_to_term("check_process_code_2")),
+                pid
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_pid_without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process),
+            )
+        },
+        |(arc_process, module)| {
+            prop_assert_is_not_atom!(result(&arc_process, arc_process.pid_term(), module), module);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_without_old_code_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.pid_term(),
+                Atom::str_to_term("check_process_code_2_without_old_code")
+            ),
+            Ok(false.into())
+        );
+    });
+}
+
+#[test]
+fn with_unknown_local_pid_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                Pid::next_term(),
+                Atom::str_to_term("check_process_code_2_with_unknown_local_pid")
+            ),
+            Ok(false.into())
+        );
+    });
+}
@@ -0,0 +1,45 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::erase_0::result;
+use crate::test::strategy;
+use crate::test::with_process_arc;
+
+#[test]
+fn without_entries_returns_empty_list() {
+    with_process_arc(|arc_process| {
+        assert_eq!(result(&arc_process), Term::NIL);
+    });
+}
+
+#[test]
+fn with_entries_returns_entries_and_empties_dictionary() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+        },
+        |(arc_process, key, value)| {
+            arc_process.erase_entries();
+
+            arc_process.put(key, value);
+
+            prop_assert_eq!(
+                result(&arc_process),
+                arc_process.list_from_slice(&[arc_process.tuple_from_slice(&[key, value])])
+            );
+            prop_assert_eq!(arc_process.get_entries(), Term::NIL);
+            prop_assert_eq!(
+                arc_process.get_value_from_key(key),
+                Atom::str_to_term("undefined")
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,99 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::{ExitSignalKind, Process};
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::{atom, exit};
+
+use crate::runtime::port;
+use crate::runtime::process::{send_exit_signal, send_exit_signal_to_external_pid};
+use crate::runtime::registry::pid_to_process;
+
+#[native_implemented::function(erlang:exit/2)]
+fn result(process: &Process, pid_or_port: Term, reason: Term) -> exception::Result<Term> {
+    match pid_or_port.decode()? {
+        TypedTerm::Pid(pid) => {
+            if pid == process.pid() {
+                exit_self(process, reason)
+            } else {
+                // Like the BEAM, signals to processes that aren't alive are silently dropped
+                if let Some(pid_arc_process) = pid_to_process(&pid) {
+                    send_exit_signal(
+                        &pid_arc_process,
+                        ExitSignalKind::Exit,
+                        process.pid(),
+                        reason,
+                        Trace::capture(),
+                        Some(anyhow!("exit signal from {}", process.pid()).into()),
+                    );
+                }
+
+                Ok(true.into())
+            }
+        }
+        TypedTerm::Port(port) => {
+            let is_normal = match reason.decode()? {
+                TypedTerm::Atom(atom) => atom == "normal",
+                _ => false,
+            };
+
+            // Like the BEAM, ports ignore `normal` and close for any other reason.  Ports aren't
+            // linked to their owner, so the owner isn't sent an exit signal in turn.
+            if !is_normal {
+                port::close(port);
+            }
+
+            Ok(true.into())
+        }
+        TypedTerm::ExternalPid(external_pid) => {
+            send_exit_signal_to_external_pid(process, external_pid, reason);
+
+            Ok(true.into())
+        }
+        TypedTerm::ExternalPort(_) => Err(anyhow!(
+            "pid_or_port ({}) is a port on another node, which can't be sent exit signals",
+            pid_or_port
+        )
+        .into()),
+        _ => Err(TypeError)
+            .context(format!(
+                "pid_or_port ({}) is neither a pid nor a port",
+                pid_or_port
+            ))
+            .map_err(From::from),
+    }
+}
+
+/// The process is running, so the signal is handled immediately instead of being queued
+fn exit_self(process: &Process, reason: Term) -> exception::Result<Term> {
+    let is_kill = match reason.decode()? {
+        TypedTerm::Atom(atom) => atom == "kill",
+        _ => false,
+    };
+
+    if is_kill {
+        Err(exit!(
+            atom!("killed"),
+            Trace::capture(),
+            anyhow!("explicit exit from Erlang").into()
+        )
+        .into())
+    } else if process.traps_exit() {
+        let exit_message = process.tuple_from_slice(&[atom!("EXIT"), process.pid_term(), reason]);
+        process.send_from_self(exit_message);
+
+        Ok(true.into())
+    } else {
+        // Unlike signals from other processes, `normal` exits the calling process
+        Err(exit!(
+            reason,
+            Trace::capture(),
+            anyhow!("explicit exit from Erlang").into()
+        )
+        .into())
+    }
+}
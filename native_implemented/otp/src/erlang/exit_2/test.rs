@@ -0,0 +1,42 @@
+mod with_external_pid;
+mod with_local_pid;
+mod with_port;
+
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::process::Status;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::exit_2::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_pid_or_port_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone())
+                    .prop_filter("Cannot be pid or port", |pid_or_port| {
+                        !(pid_or_port.is_pid() || pid_or_port.is_port())
+                    }),
+            )
+        },
+        |(arc_process, pid_or_port)| {
+            prop_assert_badarg!(
+                result(&arc_process, pid_or_port, Atom::str_to_term("reason")),
+                format!("pid_or_port ({}) is neither a pid nor a port", pid_or_port)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+fn exit_reason(status: &Status) -> Option<Term> {
+    match status {
+        Status::RuntimeException(exception) => Some(exception.reason()),
+        _ => None,
+    }
+}
@@ -0,0 +1,19 @@
+use super::*;
+
+use liblumen_alloc::atom;
+
+use crate::test::external_arc_node;
+
+#[test]
+fn with_unreachable_node_returns_true_and_drops_signal() {
+    with_process(|process| {
+        let external_pid = process.external_pid(external_arc_node(), 1, 0).unwrap();
+
+        // The local node isn't alive, so it can't connect to the pid's node
+        assert_eq!(
+            result(process, external_pid, atom!("kill")),
+            Ok(true.into())
+        );
+        assert!(!process.is_exiting());
+    });
+}
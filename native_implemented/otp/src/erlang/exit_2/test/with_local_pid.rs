@@ -0,0 +1,121 @@
+use super::*;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::{atom, exit};
+
+use crate::runtime::scheduler;
+
+use crate::test;
+use crate::test::has_message;
+
+#[test]
+fn with_non_existent_pid_returns_true() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, Pid::next_term(), atom!("reason")),
+            Ok(true.into())
+        );
+    });
+}
+
+#[test]
+fn with_self_and_normal_reason_exits_the_process() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.pid_term(), atom!("normal")),
+            Err(exit!(atom!("normal"), Trace::capture(), anyhow!("Test").into()).into())
+        );
+    });
+}
+
+#[test]
+fn with_self_trapping_exits_and_kill_reason_exits_the_process_with_killed() {
+    with_process(|process| {
+        process.trap_exit(true);
+
+        assert_eq!(
+            result(process, process.pid_term(), atom!("kill")),
+            Err(exit!(atom!("killed"), Trace::capture(), anyhow!("Test").into()).into())
+        );
+    });
+}
+
+#[test]
+fn with_other_process_exits_it_when_run() {
+    with_process(|process| {
+        let other_arc_process = test::process::child(process);
+        let reason = atom!("reason");
+
+        assert_eq!(
+            result(process, other_arc_process.pid_term(), reason),
+            Ok(true.into())
+        );
+
+        // The signal is only delivered when the other process is scheduled
+        assert!(!other_arc_process.is_exiting());
+
+        assert!(scheduler::run_through(&other_arc_process));
+
+        assert_eq!(exit_reason(&other_arc_process.status.read()), Some(reason));
+    });
+}
+
+#[test]
+fn with_other_process_and_normal_reason_does_not_exit_it() {
+    with_process(|process| {
+        let other_arc_process = test::process::child(process);
+
+        assert_eq!(
+            result(process, other_arc_process.pid_term(), atom!("normal")),
+            Ok(true.into())
+        );
+
+        assert!(scheduler::run_through(&other_arc_process));
+
+        assert!(!other_arc_process.is_exiting());
+    });
+}
+
+#[test]
+fn with_other_process_trapping_exits_sends_exit_message() {
+    with_process(|process| {
+        let other_arc_process = test::process::child(process);
+        other_arc_process.trap_exit(true);
+        let reason = atom!("reason");
+
+        assert_eq!(
+            result(process, other_arc_process.pid_term(), reason),
+            Ok(true.into())
+        );
+
+        assert!(scheduler::run_through(&other_arc_process));
+
+        assert!(!other_arc_process.is_exiting());
+        assert_has_message!(
+            &other_arc_process,
+            other_arc_process.tuple_from_slice(&[atom!("EXIT"), process.pid_term(), reason])
+        );
+    });
+}
+
+#[test]
+fn with_other_process_trapping_exits_and_kill_reason_exits_it_with_killed() {
+    with_process(|process| {
+        let other_arc_process = test::process::child(process);
+        other_arc_process.trap_exit(true);
+
+        assert_eq!(
+            result(process, other_arc_process.pid_term(), atom!("kill")),
+            Ok(true.into())
+        );
+
+        assert!(scheduler::run_through(&other_arc_process));
+
+        assert_eq!(
+            exit_reason(&other_arc_process.status.read()),
+            Some(atom!("killed"))
+        );
+    });
+}
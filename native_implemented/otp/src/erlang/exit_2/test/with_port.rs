@@ -0,0 +1,47 @@
+use super::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+
+use crate::erlang::{open_port_2, port_close_1};
+
+#[test]
+fn with_normal_reason_returns_true_and_leaves_port_open() {
+    with_process(|process| {
+        let port = open_cat(process);
+
+        assert_eq!(result(process, port, atom!("normal")), Ok(true.into()));
+        assert_eq!(port_close_1::result(port), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_other_reason_returns_true_and_closes_port() {
+    with_process(|process| {
+        let port = open_cat(process);
+
+        assert_eq!(result(process, port, atom!("reason")), Ok(true.into()));
+        assert_badarg!(
+            port_close_1::result(port),
+            format!("port ({}) is not open", port)
+        );
+        assert!(!process.is_exiting());
+    });
+}
+
+#[test]
+fn with_closed_port_returns_true() {
+    with_process(|process| {
+        let port = open_cat(process);
+        port_close_1::result(port).unwrap();
+
+        assert_eq!(result(process, port, atom!("kill")), Ok(true.into()));
+    });
+}
+
+fn open_cat(process: &Process) -> Term {
+    let port_name =
+        process.tuple_from_slice(&[Atom::str_to_term("spawn"), process.binary_from_str("cat")]);
+
+    open_port_2::result(process, port_name, Term::NIL).unwrap()
+}
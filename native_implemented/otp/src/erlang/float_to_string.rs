@@ -28,6 +28,7 @@ pub fn float_to_string(float: Term, options: Options) -> exception::Result<Strin
             // https://github.com/erlang/otp/blob/d293c3ff700c1a0992a32dc3da9ae18964893c23/erts/emulator/beam/bif.c#L3151
             float_to_scientific_string(float_f64, digits)
         }
+        Options::Short => float_to_short_string(float_f64),
     };
 
     Ok(string)
@@ -41,6 +42,7 @@ pub enum Options {
     Scientific {
         digits: ScientificDigits,
     },
+    Short,
 }
 
 impl Default for Options {
@@ -62,6 +64,7 @@ impl From<OptionsBuilder> for Options {
             Digits::Scientific(scientific_digits) => Options::Scientific {
                 digits: scientific_digits,
             },
+            Digits::Short => Options::Short,
         }
     }
 }
@@ -124,10 +127,75 @@ fn float_to_scientific_string(f: f64, digits: ScientificDigits) -> String {
     }
 }
 
+/// > If option short is specified, the float is formatted with the smallest number of digits
+/// > that still guarantees that F =:= list_to_float(float_to_list(F, [short])). When the float
+/// > is inside the range (-2⁵³, 2⁵³), the notation that yields the smallest number of
+/// > characters is used (scientific notation or normal decimal notation). Floats outside the
+/// > range (-2⁵³, 2⁵³) are always formatted using scientific notation to avoid confusing results
+/// > when doing arithmetic operations.
+fn float_to_short_string(f: f64) -> String {
+    // Rust's `{:e}` without a precision is already the shortest representation that round
+    // trips, e.g. `1.2345e-5` or `1e20`, so only the notation needs to be changed
+    let rust_formatted = format!("{:e}", f);
+    let reverse_parts: Vec<&str> = rust_formatted.rsplitn(2, 'e').collect();
+    assert_eq!(reverse_parts.len(), 2);
+    let exponent: i32 = reverse_parts[0].parse().unwrap();
+    let coefficient = reverse_parts[1];
+    let sign = if coefficient.starts_with('-') {
+        "-"
+    } else {
+        ""
+    };
+    let digits: String = coefficient.chars().filter(char::is_ascii_digit).collect();
+
+    // Unlike Rust, Erlang requires a fractional part, e.g. `1.0e20`
+    let fraction = if digits.len() > 1 { &digits[1..] } else { "0" };
+    let scientific = format!("{}{}.{}e{}", sign, &digits[..1], fraction, exponent);
+
+    if f.abs() < SHORT_DECIMAL_LIMIT {
+        let decimal = short_decimal_string(sign, &digits, exponent);
+
+        if decimal.len() <= scientific.len() {
+            return decimal;
+        }
+    }
+
+    scientific
+}
+
+/// 2⁵³, the largest magnitude at which every integer is exactly representable
+const SHORT_DECIMAL_LIMIT: f64 = 9007199254740992.0;
+
+/// Places the decimal point in `digits`, which are the coefficient of `d.ddd * 10^exponent`
+fn short_decimal_string(sign: &str, digits: &str, exponent: i32) -> String {
+    let integer_digits = exponent + 1;
+
+    if integer_digits <= 0 {
+        format!(
+            "{}0.{}{}",
+            sign,
+            "0".repeat(-integer_digits as usize),
+            digits
+        )
+    } else if digits.len() <= integer_digits as usize {
+        format!(
+            "{}{}{}.0",
+            sign,
+            digits,
+            "0".repeat(integer_digits as usize - digits.len())
+        )
+    } else {
+        let (integer, fraction) = digits.split_at(integer_digits as usize);
+
+        format!("{}{}.{}", sign, integer, fraction)
+    }
+}
+
 enum Digits {
     None,
     Decimal(DecimalDigits),
     Scientific(ScientificDigits),
+    Short,
 }
 
 impl Default for Digits {
@@ -150,7 +218,13 @@ impl OptionsBuilder {
 
                     Ok(self)
                 }
-                name => Err(TryAtomFromTermError(name)).context("supported atom option is compact"),
+                "short" => {
+                    self.digits = Digits::Short;
+
+                    Ok(self)
+                }
+                name => Err(TryAtomFromTermError(name))
+                    .context("supported atom options are compact and short"),
             },
             TypedTerm::Tuple(tuple) => {
                 if tuple.len() == 2 {
@@ -196,7 +270,7 @@ impl Default for OptionsBuilder {
 }
 
 const SUPPORTED_OPTIONS_CONTEXT: &str =
-    "supported options are compact, {:decimal, 0..253}, or {:scientific, 0..249}";
+    "supported options are compact, short, {:decimal, 0..253}, or {:scientific, 0..249}";
 
 impl TryFrom<Term> for OptionsBuilder {
     type Error = anyhow::Error;
@@ -0,0 +1,49 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::closure::Definition;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::fun_info_2::{fun_info, term_try_into_fun};
+
+/// The items returned by `fun_info/1` for funs created with `fun (...) -> ... end`, in the order
+/// they are returned.
+const LOCAL_ITEM_NAMES: &[&str] = &[
+    "pid",
+    "module",
+    "new_index",
+    "new_uniq",
+    "index",
+    "uniq",
+    "name",
+    "arity",
+    "env",
+    "type",
+];
+
+/// The items returned by `fun_info/1` for funs created with `fun M:F/A`, in the order they are
+/// returned.
+const EXTERNAL_ITEM_NAMES: &[&str] = &["module", "name", "arity", "env", "type"];
+
+#[native_implemented::function(erlang:fun_info/1)]
+pub fn result(process: &Process, fun: Term) -> exception::Result<Term> {
+    let boxed_closure = term_try_into_fun(fun)?;
+    let item_names = match boxed_closure.definition() {
+        Definition::Export { .. } => EXTERNAL_ITEM_NAMES,
+        Definition::Anonymous { .. } => LOCAL_ITEM_NAMES,
+    };
+
+    let mut vec = Vec::with_capacity(item_names.len());
+
+    for item_name in item_names {
+        vec.push(fun_info(
+            process,
+            &boxed_closure,
+            Atom::from_str(item_name),
+        )?);
+    }
+
+    Ok(process.list_from_slice(&vec))
+}
@@ -0,0 +1,87 @@
+use std::convert::TryInto;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::fun_info_1::result;
+use crate::test::{self, strategy, with_process};
+
+#[test]
+fn without_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_function(arc_process.clone()),
+            )
+        },
+        |(arc_process, fun)| {
+            prop_assert_badarg!(
+                result(&arc_process, fun),
+                format!("fun ({}) is not a function", fun)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_anonymous_function_returns_local_items() {
+    with_process(|process| {
+        let fun = test::anonymous_1::anonymous_closure(process);
+
+        assert_eq!(
+            item_names(result(process, fun).unwrap()),
+            vec![
+                "pid",
+                "module",
+                "new_index",
+                "new_uniq",
+                "index",
+                "uniq",
+                "name",
+                "arity",
+                "env",
+                "type"
+            ]
+        );
+    });
+}
+
+#[test]
+fn with_export_function_returns_external_items() {
+    with_process(|process| {
+        let fun = process.export_closure(test::module(), Atom::from_str("function"), 0, None);
+        let info = result(process, fun).unwrap();
+
+        assert_eq!(
+            item_names(info),
+            vec!["module", "name", "arity", "env", "type"]
+        );
+
+        let info_boxed_cons: Boxed<Cons> = info.try_into().unwrap();
+        let type_tuple = info_boxed_cons.into_iter().last().unwrap().unwrap();
+
+        assert_eq!(
+            type_tuple,
+            process.tuple_from_slice(&[atom!("type"), atom!("external")])
+        );
+    });
+}
+
+fn item_names(info: Term) -> Vec<String> {
+    let info_boxed_cons: Boxed<Cons> = info.try_into().unwrap();
+
+    info_boxed_cons
+        .into_iter()
+        .map(|result| {
+            let item_boxed_tuple: Boxed<Tuple> = result.unwrap().try_into().unwrap();
+            let item_atom: Atom = item_boxed_tuple[0].try_into().unwrap();
+
+            item_atom.name().to_string()
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, InternalResult};
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::closure::Definition;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(erlang:fun_info/2)]
+pub fn result(process: &Process, fun: Term, item: Term) -> exception::Result<Term> {
+    let boxed_closure = term_try_into_fun(fun)?;
+    let item_atom: Atom = term_try_into_atom!(item)?;
+
+    fun_info(process, &boxed_closure, item_atom).map_err(From::from)
+}
+
+pub(in crate::erlang) fn term_try_into_fun(fun: Term) -> exception::Result<Boxed<Closure>> {
+    fun.try_into()
+        .with_context(|| format!("fun ({}) is not a function", fun))
+        .map_err(From::from)
+}
+
+/// Returns the `{item, value}` tuple for `item` of `closure`.
+pub(in crate::erlang) fn fun_info(
+    process: &Process,
+    closure: &Closure,
+    item: Atom,
+) -> InternalResult<Term> {
+    let value =
+        match item.name() {
+            "arity" => process.integer(closure.arity()),
+            "env" => process.list_from_slice(closure.env_slice()),
+            // The old index isn't kept, but the compiler gives each fun the same old and new
+            // index, so the new index is also the old one
+            "index" | "new_index" => match closure.definition() {
+                Definition::Export { .. } => atom!("undefined"),
+                Definition::Anonymous { index, .. } => process.integer(*index),
+            },
+            "module" => closure.module().encode()?,
+            "name" => closure.function().encode()?,
+            "new_uniq" => match closure.definition() {
+                Definition::Export { .. } => atom!("undefined"),
+                Definition::Anonymous { unique, .. } => process.binary_from_bytes(unique),
+            },
+            // The creator of a fun is not kept, as it is only needed for distribution
+            "pid" => atom!("undefined"),
+            "type" => match closure.definition() {
+                Definition::Export { .. } => atom!("external"),
+                Definition::Anonymous { .. } => atom!("local"),
+            },
+            "uniq" => match closure.definition() {
+                Definition::Export { .. } => atom!("undefined"),
+                Definition::Anonymous { old_unique, .. } => process.integer(*old_unique),
+            },
+            name => return Err(TryAtomFromTermError(name))
+                .context(
+                    "supported items are arity, env, index, module, name, new_index, new_uniq, \
+                     pid, type, and uniq",
+                )
+                .map_err(From::from),
+        };
+
+    Ok(process.tuple_from_slice(&[item.encode()?, value]))
+}
@@ -0,0 +1,139 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::closure::*;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::fun_info_2::result;
+use crate::test::{self, strategy, with_process};
+
+#[test]
+fn without_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_function(arc_process.clone()),
+            )
+        },
+        |(arc_process, fun)| {
+            prop_assert_badarg!(
+                result(&arc_process, fun, atom!("arity")),
+                format!("fun ({}) is not a function", fun)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_function_without_supported_item_errors_badarg() {
+    with_process(|process| {
+        let fun = test::anonymous_1::anonymous_closure(process);
+
+        assert_badarg!(
+            result(process, fun, atom!("unsupported")),
+            "supported items are arity, env, index, module, name, new_index, new_uniq, pid, \
+             type, and uniq"
+        );
+    });
+}
+
+#[test]
+fn with_anonymous_function_returns_env() {
+    with_process(|process| {
+        let first = process.integer(1);
+        let second = atom!("two");
+        let fun = anonymous_closure_with_env(process, &[first, second]);
+
+        assert_eq!(
+            result(process, fun, atom!("env")),
+            Ok(process
+                .tuple_from_slice(&[atom!("env"), process.list_from_slice(&[first, second])]))
+        );
+    });
+}
+
+#[test]
+fn with_anonymous_function_returns_definition() {
+    with_process(|process| {
+        let fun = anonymous_closure_with_env(process, &[]);
+
+        assert_eq!(
+            result(process, fun, atom!("type")),
+            Ok(process.tuple_from_slice(&[atom!("type"), atom!("local")]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("module")),
+            Ok(process.tuple_from_slice(&[atom!("module"), test::module().encode().unwrap()]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("arity")),
+            Ok(process.tuple_from_slice(&[atom!("arity"), process.integer(ARITY)]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("index")),
+            Ok(process.tuple_from_slice(&[atom!("index"), process.integer(INDEX)]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("new_index")),
+            Ok(process.tuple_from_slice(&[atom!("new_index"), process.integer(INDEX)]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("uniq")),
+            Ok(process.tuple_from_slice(&[atom!("uniq"), process.integer(OLD_UNIQUE)]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("new_uniq")),
+            Ok(process.tuple_from_slice(&[atom!("new_uniq"), process.binary_from_bytes(&UNIQUE)]))
+        );
+    });
+}
+
+#[test]
+fn with_export_function_returns_definition() {
+    with_process(|process| {
+        let module = Atom::from_str("module");
+        let function = Atom::from_str("function");
+        let fun = process.export_closure(module, function, 2, None);
+
+        assert_eq!(
+            result(process, fun, atom!("type")),
+            Ok(process.tuple_from_slice(&[atom!("type"), atom!("external")]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("name")),
+            Ok(process.tuple_from_slice(&[atom!("name"), function.encode().unwrap()]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("env")),
+            Ok(process.tuple_from_slice(&[atom!("env"), Term::NIL]))
+        );
+        assert_eq!(
+            result(process, fun, atom!("index")),
+            Ok(process.tuple_from_slice(&[atom!("index"), atom!("undefined")]))
+        );
+    });
+}
+
+const INDEX: Index = 3;
+const OLD_UNIQUE: OldUnique = 4;
+const UNIQUE: Unique = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB, 0xCD, 0xEF,
+];
+const ARITY: Arity = 0;
+
+fn anonymous_closure_with_env(process: &Process, env: &[Term]) -> Term {
+    process.anonymous_closure_with_env_from_slice(
+        test::module(),
+        INDEX,
+        OLD_UNIQUE,
+        UNIQUE,
+        ARITY,
+        None,
+        process.pid().into(),
+        env,
+    )
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The full sweep is done the next time the process collects, with the roots from the stack map.
+#[native_implemented::function(erlang:garbage_collect/0)]
+pub fn result(process: &Process) -> Term {
+    process.force_garbage_collection();
+
+    true.into()
+}
@@ -0,0 +1,22 @@
+use liblumen_alloc::erts::process::ProcessFlags;
+
+use crate::erlang::garbage_collect_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_true() {
+    with_process(|process| {
+        assert_eq!(result(process), true.into());
+    });
+}
+
+#[test]
+fn forces_full_sweep_on_next_garbage_collection() {
+    with_process(|process| {
+        assert!(!process.are_flags_set(ProcessFlags::ForceGC | ProcessFlags::NeedFullSweep));
+
+        result(process);
+
+        assert!(process.are_flags_set(ProcessFlags::ForceGC | ProcessFlags::NeedFullSweep));
+    });
+}
@@ -0,0 +1,30 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::registry::pid_to_process;
+
+/// The full sweep is done the next time the process collects, with the roots from the stack map,
+/// as only the process knows which of its terms are live while it is running.
+#[native_implemented::function(erlang:garbage_collect/1)]
+pub fn result(process: &Process, pid: Term) -> exception::Result<Term> {
+    if pid == process.pid_term() {
+        process.force_garbage_collection();
+
+        Ok(true.into())
+    } else {
+        let pid_pid = term_try_into_local_pid!(pid)?;
+
+        match pid_to_process(&pid_pid) {
+            Some(arc_process) if !arc_process.is_exiting() => {
+                arc_process.force_garbage_collection();
+
+                Ok(true.into())
+            }
+            _ => Ok(false.into()),
+        }
+    }
+}
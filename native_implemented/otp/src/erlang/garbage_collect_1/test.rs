@@ -0,0 +1,76 @@
+use proptest::strategy::Just;
+use proptest::test_runner::{Config, TestRunner};
+use proptest::{prop_assert, prop_assert_eq};
+
+use liblumen_alloc::erts::process::ProcessFlags;
+
+use crate::erlang::garbage_collect_1::result;
+use crate::test::strategy;
+use crate::test::{with_process, with_process_arc};
+
+#[test]
+fn without_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_pid(arc_process),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_badarg!(
+                result(&arc_process, pid),
+                format!("pid ({}) is not a pid", pid)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_self_forces_full_sweep_on_next_garbage_collection_and_returns_true() {
+    with_process(|process| {
+        assert_eq!(result(process, process.pid_term()), Ok(true.into()));
+
+        assert!(process.are_flags_set(ProcessFlags::ForceGC | ProcessFlags::NeedFullSweep));
+    });
+}
+
+#[test]
+fn with_other_process_forces_full_sweep_on_next_garbage_collection_and_returns_true() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(&strategy::process(), |other_arc_process| {
+                prop_assert!(!other_arc_process.are_flags_set(ProcessFlags::ForceGC));
+
+                prop_assert_eq!(
+                    result(&arc_process, other_arc_process.pid_term()),
+                    Ok(true.into())
+                );
+
+                prop_assert!(other_arc_process.are_flags_set(ProcessFlags::ForceGC));
+
+                Ok(())
+            })
+            .unwrap();
+    });
+}
+
+#[test]
+fn with_exiting_process_returns_false() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(&strategy::process(), |other_arc_process| {
+                other_arc_process.exit_normal();
+
+                prop_assert_eq!(
+                    result(&arc_process, other_arc_process.pid_term()),
+                    Ok(false.into())
+                );
+
+                Ok(())
+            })
+            .unwrap();
+    });
+}
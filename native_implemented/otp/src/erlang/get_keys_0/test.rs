@@ -0,0 +1,46 @@
+use proptest::prop_assert;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::get_keys_0::result;
+use crate::test::strategy;
+use crate::test::with_process_arc;
+
+#[test]
+fn without_entries_returns_empty_list() {
+    with_process_arc(|arc_process| {
+        assert_eq!(result(&arc_process), Term::NIL);
+    });
+}
+
+#[test]
+fn with_entries_returns_all_keys() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+                .prop_filter("Keys must be different", |(_, key1, key2, _)| key1 != key2)
+        },
+        |(arc_process, key1, key2, value)| {
+            arc_process.erase_entries();
+
+            arc_process.put(key1, value);
+            arc_process.put(key2, value);
+
+            let keys = result(&arc_process);
+
+            // Dictionary iteration order is unspecified
+            prop_assert!(
+                keys == arc_process.list_from_slice(&[key1, key2])
+                    || keys == arc_process.list_from_slice(&[key2, key1])
+            );
+
+            Ok(())
+        },
+    );
+}
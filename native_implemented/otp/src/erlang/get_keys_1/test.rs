@@ -0,0 +1,83 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::get_keys_1::result;
+use crate::test::strategy;
+
+#[test]
+fn without_value_returns_empty_list() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+                .prop_filter("Values must be different", |(_, _, value, other_value)| {
+                    value != other_value
+                })
+        },
+        |(arc_process, key, value, other_value)| {
+            arc_process.erase_entries();
+
+            arc_process.put(key, value);
+
+            prop_assert_eq!(result(&arc_process, other_value), Term::NIL);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_value_returns_keys_with_value() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+        },
+        |(arc_process, key, value)| {
+            arc_process.erase_entries();
+
+            arc_process.put(key, value);
+
+            prop_assert_eq!(
+                result(&arc_process, value),
+                arc_process.list_from_slice(&[key])
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_value_returns_empty_list() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process),
+                -1_000_000_i64..1_000_000_i64,
+            )
+        },
+        |(arc_process, key, integer)| {
+            arc_process.erase_entries();
+
+            arc_process.put(key, arc_process.integer(integer));
+
+            prop_assert_eq!(
+                result(&arc_process, arc_process.float(integer as f64)),
+                Term::NIL
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,34 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply::arguments_term_to_vec;
+
+/// Discards the call stack, requests a full sweep to shrink the heap to fit the live data, and
+/// waits for a message, upon which it calls `module:function(arguments...)`.  The process exits
+/// normally when that call returns, as there is no call stack to return to.
+#[native_implemented::function(erlang:hibernate/3)]
+pub fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arguments: Term,
+) -> exception::Result<Term> {
+    term_try_into_atom!(module)?;
+    term_try_into_atom!(function)?;
+    arguments_term_to_vec(arguments)?;
+
+    process.discard_callers();
+    process.force_garbage_collection();
+
+    process.queue_frame_with_arguments(
+        label_1::frame().with_arguments(false, &[module, function, arguments]),
+    );
+
+    Ok(Term::NONE)
+}
@@ -0,0 +1,44 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (module, function, arguments)
+//! # returned from call: N/A
+//! # full stack: (module, function, arguments)
+//! # returns: value
+//! receive do
+//!   _ -> :ok
+//! after
+//!   0 -> wait_for_any_message()
+//! end
+//! apply(module, function, arguments)
+//! ```
+//!
+//! The message is left in the mailbox for `function` to receive.
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_3;
+
+#[native_implemented::label]
+fn result(process: &Process, module: Term, function: Term, arguments: Term) -> Term {
+    assert!(module.is_atom(), "module ({:?}) is not an atom", module);
+    assert!(function.is_atom());
+    assert!(arguments.is_list());
+
+    // Check the mailbox and wait under its lock, so that a message sent in between still wakes
+    // the process
+    let mailbox_guard = process.mailbox.lock();
+
+    if mailbox_guard.borrow().len() == 0 {
+        process.wait();
+        process.queue_frame_with_arguments(
+            frame().with_arguments(false, &[module, function, arguments]),
+        );
+    } else {
+        process.queue_frame_with_arguments(
+            apply_3::frame().with_arguments(false, &[module, function, arguments]),
+        );
+    }
+
+    Term::NONE
+}
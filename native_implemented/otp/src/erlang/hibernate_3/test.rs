@@ -0,0 +1,81 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::hibernate_3::result;
+use crate::test::strategy;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process),
+            )
+        },
+        |(arc_process, module)| {
+            prop_assert_badarg!(
+                result(
+                    &arc_process,
+                    module,
+                    Atom::str_to_term("function"),
+                    Term::NIL
+                ),
+                format!("module ({}) is not an atom", module)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_atom_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process),
+            )
+        },
+        |(arc_process, function)| {
+            prop_assert_badarg!(
+                result(
+                    &arc_process,
+                    Atom::str_to_term("module"),
+                    function,
+                    Term::NIL
+                ),
+                format!("function ({}) is not an atom", function)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_proper_list_arguments_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_proper_list(arc_process),
+            )
+        },
+        |(arc_process, arguments)| {
+            prop_assert_badarg!(
+                result(
+                    &arc_process,
+                    Atom::str_to_term("module"),
+                    Atom::str_to_term("function"),
+                    arguments
+                ),
+                format!("arguments ({}) is not a proper list", arguments)
+            );
+
+            Ok(())
+        },
+    );
+}
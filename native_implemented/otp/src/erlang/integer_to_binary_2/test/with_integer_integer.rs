@@ -107,3 +107,24 @@ fn is_dual_of_binary_to_integer_2() {
         },
     );
 }
+
+#[test]
+fn with_base_above_10_uses_uppercase_digits() {
+    crate::test::with_process_arc(|arc_process| {
+        let base = arc_process.integer(16);
+
+        for integer in &[arc_process.integer(255), arc_process.integer(-255)] {
+            let binary = result(&arc_process, *integer, base).unwrap();
+            let string: String = binary_to_string(binary).unwrap();
+
+            assert_eq!(string.trim_start_matches('-'), "FF");
+        }
+
+        // Large enough to be a big integer
+        let integer = arc_process.integer(u64::max_value());
+        let binary = result(&arc_process, integer, base).unwrap();
+        let string: String = binary_to_string(binary).unwrap();
+
+        assert_eq!(string, "FFFFFFFFFFFFFFFF");
+    });
+}
@@ -25,12 +25,13 @@ pub fn base_integer_to_string(base: Term, integer: Term) -> InternalResult<Strin
                 ("", radix)
             };
 
-            Ok(format!("{}{}", sign, radix))
+            // BEAM uses uppercase letters for digits above 9
+            Ok(format!("{}{}", sign, radix).to_uppercase())
         }
         TypedTerm::BigInteger(big_integer) => {
             let big_int: &BigInt = big_integer.as_ref().into();
 
-            Ok(big_int.to_str_radix(base.radix()))
+            Ok(big_int.to_str_radix(base.radix()).to_uppercase())
         }
         _ => Err(TypeError)
             .context(format!("integer ({}) is not an integer", integer))
@@ -1,5 +1,3 @@
-use std::convert::TryInto;
-
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
@@ -48,69 +46,22 @@ pub fn result(
 }
 
 pub fn to_binary(process: &Process, name: &'static str, value: Term) -> exception::Result<Term> {
-    let mut byte_vec: Vec<u8> = Vec::new();
-    let mut stack: Vec<Term> = vec![value];
-
-    while let Some(top) = stack.pop() {
-        match top.decode()? {
-            TypedTerm::SmallInteger(small_integer) => {
-                let top_byte = small_integer
-                    .try_into()
-                    .with_context(|| element_context(name, value, top))?;
-
-                byte_vec.push(top_byte);
-            }
-            TypedTerm::Nil => (),
-            TypedTerm::List(boxed_cons) => {
-                // @type iolist :: maybe_improper_list(byte() | binary() | iolist(),
-                // binary() | []) means that `byte()` isn't allowed
-                // for `tail`s unlike `head`.
-
-                let tail = boxed_cons.tail;
-                let result_u8: Result<u8, _> = tail.try_into();
-
-                match result_u8 {
-                    Ok(_) => {
-                        return Err(TypeError)
-                            .context(format!(
-                                "{} ({}) tail ({}) cannot be a byte",
-                                name, value, tail
-                            ))
-                            .map_err(From::from)
-                    }
-                    Err(_) => stack.push(tail),
-                };
+    to_byte_vec(name, value).map(|byte_vec| process.binary_from_bytes(byte_vec.as_slice()))
+}
 
-                stack.push(boxed_cons.head);
-            }
-            TypedTerm::HeapBinary(heap_binary) => {
-                byte_vec.extend_from_slice(heap_binary.as_bytes());
-            }
-            TypedTerm::SubBinary(subbinary) => {
-                if subbinary.is_binary() {
-                    if subbinary.is_aligned() {
-                        byte_vec.extend(unsafe { subbinary.as_bytes_unchecked() });
-                    } else {
-                        byte_vec.extend(subbinary.full_byte_iter());
-                    }
-                } else {
-                    return Err(NotABinary)
-                        .context(element_context(name, value, top))
-                        .map_err(From::from);
-                }
+pub fn to_byte_vec(name: &'static str, value: Term) -> exception::Result<Vec<u8>> {
+    IodataIter::new(value).bytes().map_err(|error| {
+        let context = match error {
+            IodataError::Element(element) | IodataError::NotABinary(element) => {
+                element_context(name, value, element)
             }
-            TypedTerm::ProcBin(procbin) => {
-                byte_vec.extend_from_slice(procbin.as_bytes());
+            IodataError::ByteTail(tail) => {
+                format!("{} ({}) tail ({}) cannot be a byte", name, value, tail)
             }
-            _ => {
-                return Err(TypeError)
-                    .context(element_context(name, value, top))
-                    .map_err(From::from)
-            }
-        }
-    }
+        };
 
-    Ok(process.binary_from_bytes(byte_vec.as_slice()))
+        anyhow::Error::new(error).context(context).into()
+    })
 }
 
 fn element_context(name: &'static str, value: Term, element: Term) -> String {
@@ -163,6 +163,21 @@ fn with_improper_list_smallint_tail_errors_badarg() {
     });
 }
 
+#[test]
+fn with_deeply_nested_iolist_returns_size() {
+    with_process(|process| {
+        // Like the accumulator of `Acc = [Acc | Bin]` in a loop
+        let bin = process.binary_from_bytes(&[1]);
+        let mut iolist = Term::NIL;
+
+        for _ in 0..100_000 {
+            iolist = process.cons(iolist, bin);
+        }
+
+        assert_eq!(result(process, iolist), Ok(process.integer(100_000)))
+    });
+}
+
 pub fn is_not_list_or_bitstring(arc_process: Arc<Process>) -> BoxedStrategy<Term> {
     let element = term(arc_process.clone());
     let size_range = size_range();
@@ -159,3 +159,21 @@ fn with_atom_in_iolist_errors_badarg() {
         )
     });
 }
+
+#[test]
+fn with_deeply_nested_iolist_returns_binary() {
+    with_process(|process| {
+        // Like the accumulator of `Acc = [Acc | Bin]` in a loop
+        let bin = process.binary_from_bytes(&[1]);
+        let mut iolist = Term::NIL;
+
+        for _ in 0..100_000 {
+            iolist = process.cons(iolist, bin);
+        }
+
+        assert_eq!(
+            result(process, iolist),
+            Ok(process.binary_from_bytes(&[1; 100_000]))
+        )
+    });
+}
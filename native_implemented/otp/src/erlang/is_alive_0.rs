@@ -1,7 +1,9 @@
 use liblumen_alloc::erts::term::prelude::Term;
 
-/// Distribution is not supported at this time.  Always returns `false`.
+use crate::runtime::distribution::nodes::node;
+
+/// Returns `true` once the local node has been started with a name, such as with `--name`.
 #[native_implemented::function(erlang:is_alive/0)]
 pub fn result() -> Term {
-    false.into()
+    node::is_alive().into()
 }
@@ -1,9 +1,11 @@
 use proptest::prop_assert_eq;
 use proptest::test_runner::{Config, TestRunner};
 
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
 use crate::erlang::is_function_1::result;
-use crate::test::strategy;
-use crate::test::with_process_arc;
+use crate::test::{self, strategy, with_process, with_process_arc};
 
 #[test]
 fn without_function_returns_false() {
@@ -29,3 +31,35 @@ fn with_function_returns_false() {
             .unwrap();
     });
 }
+
+#[test]
+fn with_anonymous_function_with_env_returns_true() {
+    with_process(|process| {
+        let fun = process.anonymous_closure_with_env_from_slice(
+            test::module(),
+            0,
+            1,
+            Default::default(),
+            0,
+            None,
+            process.pid().into(),
+            &[process.integer(1), atom!("two")],
+        );
+
+        assert_eq!(result(fun), true.into());
+    });
+}
+
+#[test]
+fn with_export_function_returns_true() {
+    with_process(|process| {
+        let fun = process.export_closure(
+            Atom::from_str("module"),
+            Atom::from_str("function"),
+            2,
+            None,
+        );
+
+        assert_eq!(result(fun), true.into());
+    });
+}
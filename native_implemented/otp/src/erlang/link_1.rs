@@ -9,6 +9,7 @@ use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::runtime::registry::pid_to_process;
+use crate::runtime::trace;
 
 #[native_implemented::function(erlang:link/1)]
 fn result(process: &Process, pid_or_port: Term) -> exception::Result<Term> {
@@ -20,6 +21,7 @@ fn result(process: &Process, pid_or_port: Term) -> exception::Result<Term> {
                 match pid_to_process(&pid) {
                     Some(pid_arc_process) => {
                         process.link(&pid_arc_process);
+                        trace::link(process, pid);
 
                         Ok(true.into())
                     }
@@ -1,27 +1,68 @@
 #[cfg(all(not(target_arch = "wasm32"), test))]
 mod test;
 
+mod label_1;
+
+use std::convert::TryInto;
+
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{Process, Yield};
 use liblumen_alloc::erts::term::prelude::*;
 
 #[native_implemented::function(erlang:list_to_tuple/1)]
 pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
     match list.decode().unwrap() {
         TypedTerm::Nil => Ok(process.tuple_from_slice(&[])),
-        TypedTerm::List(cons) => {
-            let vec: Vec<Term> = cons
-                .into_iter()
-                .collect::<std::result::Result<_, _>>()
-                .map_err(|_| ImproperListError)
-                .with_context(|| format!("list ({}) is improper", list))?;
-
-            Ok(process.tuple_from_slice(&vec))
-        }
+        TypedTerm::List(_) => count_then_copy(process, list, list, 0),
         _ => Err(TypeError)
             .context(format!("list ({}) is not a list", list))
             .map_err(From::from),
     }
 }
+
+// Private
+
+/// Counts the elements of `list` from `remaining` on, consuming a reduction for each, and then
+/// copies them into a tuple.  If the process is told to yield first, `label_1` resumes counting
+/// from `remaining` when the process is next run.
+fn count_then_copy(
+    process: &Process,
+    list: Term,
+    mut remaining: Term,
+    mut length: usize,
+) -> exception::Result<Term> {
+    loop {
+        match remaining.decode().unwrap() {
+            TypedTerm::Nil => {
+                let cons: Boxed<Cons> = list.try_into().unwrap();
+                let mut vec = Vec::with_capacity(length);
+
+                for result in cons.into_iter() {
+                    vec.push(result.unwrap());
+                }
+
+                return Ok(process.tuple_from_slice(&vec));
+            }
+            TypedTerm::List(cons) => {
+                remaining = cons.tail;
+                length += 1;
+
+                if process.consume_reductions(1) == Yield::Now {
+                    process.queue_frame_with_arguments(
+                        label_1::frame()
+                            .with_arguments(false, &[list, remaining, process.integer(length)]),
+                    );
+
+                    return Ok(Term::NONE);
+                }
+            }
+            _ => {
+                return Err(ImproperListError)
+                    .with_context(|| format!("list ({}) is improper", list))
+                    .map_err(From::from)
+            }
+        }
+    }
+}
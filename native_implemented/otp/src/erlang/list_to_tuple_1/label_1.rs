@@ -0,0 +1,23 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (list, remaining, length)
+//! # returned from call: N/A
+//! # full stack: (list, remaining, length)
+//! # returns: tuple
+//! count_then_copy(list, remaining, length)
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, list: Term, remaining: Term, length: Term) -> exception::Result<Term> {
+    let length_usize: usize = length.try_into().unwrap();
+
+    super::count_then_copy(process, list, remaining, length_usize)
+}
@@ -3,6 +3,7 @@ use proptest::prop_assert_eq;
 use proptest::strategy::{Just, Strategy};
 use proptest::test_runner::{Config, TestRunner};
 
+use liblumen_alloc::erts::process::{Status, MAX_REDUCTIONS_PER_RUN};
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::erlang::list_to_tuple_1::result;
@@ -101,3 +102,16 @@ fn with_nested_list_returns_tuple_with_list_element() {
         );
     });
 }
+
+#[test]
+fn with_running_process_out_of_reductions_yields() {
+    with_process(|process| {
+        *process.status.write() = Status::Running;
+        let _ = process.consume_reductions(MAX_REDUCTIONS_PER_RUN as usize - 1);
+
+        let list = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_eq!(result(process, list), Ok(Term::NONE));
+        assert!(process.is_reduced());
+    });
+}
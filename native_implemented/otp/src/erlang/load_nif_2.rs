@@ -1,15 +1,23 @@
 use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
-use liblumen_alloc::erts::term::prelude::Term;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
 
 #[native_implemented::function(erlang:load_nif/2)]
-pub fn result(process: &Process, _path: Term, _load_info: Term) -> Term {
-    let reason = atom!("notsup");
-    // Similar to the text used for HiPE compiled modules
-    let text = process
-        .list_from_chars("Calling load_nif from Lumen compiled modules not supported".chars());
-    let tag = atom!("error");
-    let value = process.tuple_from_slice(&[reason, text]);
-
-    process.tuple_from_slice(&[tag, value])
+pub fn result(process: &Process, path: Term, load_info: Term) -> exception::Result<Term> {
+    let path_string = list_to_string(path)?;
+
+    match lumen_nif::load(process, &path_string, load_info) {
+        Ok(_) => Ok(atom!("ok")),
+        Err(load_error) => {
+            let reason = Atom::str_to_term(load_error.reason());
+            let text = process.list_from_chars(load_error.to_string().chars());
+            let tag = atom!("error");
+            let value = process.tuple_from_slice(&[reason, text]);
+
+            Ok(process.tuple_from_slice(&[tag, value]))
+        }
+    }
 }
@@ -0,0 +1,42 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::ffi::c_void;
+use std::mem;
+use std::ptr::NonNull;
+
+use liblumen_alloc::erts::apply::find_symbol;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
+
+use crate::runtime::context::*;
+
+/// Returns the external fun `fun Module:Function/Arity`.
+///
+/// As in BEAM, the function does not need to exist yet: if `Module` is not loaded, or does not
+/// export `Function/Arity`, calling the fun raises `undef`.
+#[native_implemented::function(erlang:make_fun/3)]
+pub fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arity: Term,
+) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom!(module)?;
+    let function_atom = term_try_into_atom!(function)?;
+    let arity_arity = term_try_into_arity(arity)?;
+
+    let module_function_arity = ModuleFunctionArity {
+        module: module_atom,
+        function: function_atom,
+        arity: arity_arity,
+    };
+    let option_native = find_symbol(&module_function_arity).map(|dynamic_callee| unsafe {
+        let ptr = mem::transmute::<_, *mut c_void>(dynamic_callee);
+        NonNull::new_unchecked(ptr)
+    });
+
+    Ok(process.export_closure(module_atom, function_atom, arity_arity, option_native))
+}
@@ -0,0 +1,68 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+use crate::erlang::make_fun_3::result;
+use crate::test::strategy;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+                strategy::term::function::arity(arc_process.clone()),
+            )
+        },
+        |(arc_process, module, arity)| {
+            prop_assert_is_not_atom!(
+                result(&arc_process, module, atom!("function"), arity),
+                module
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_atom_module_without_atom_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+                strategy::term::function::arity(arc_process.clone()),
+            )
+        },
+        |(arc_process, function, arity)| {
+            prop_assert_is_not_atom!(
+                result(&arc_process, atom!("module"), function, arity),
+                function
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_atom_module_with_atom_function_without_arity_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_arity(arc_process.clone()),
+            )
+        },
+        |(arc_process, arity)| {
+            prop_assert_is_not_arity!(
+                result(&arc_process, atom!("module"), atom!("function"), arity),
+                arity
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -1,5 +1,11 @@
+use std::convert::TryInto;
+use std::thread;
+
+use liblumen_alloc::erts::scheduler;
+use liblumen_alloc::erts::term::prelude::*;
+
 use crate::erlang::make_ref_0::result;
-use crate::test::with_process;
+use crate::test::{process, with_process};
 
 #[test]
 fn returns_a_unique_reference() {
@@ -12,3 +18,56 @@ fn returns_a_unique_reference() {
         assert_eq!(second_reference, second_reference);
     })
 }
+
+#[test]
+fn later_reference_is_greater_than_earlier_reference() {
+    with_process(|process| {
+        let first_reference = result(process);
+        let second_reference = result(process);
+
+        assert!(first_reference < second_reference);
+    })
+}
+
+#[test]
+fn references_from_different_schedulers_are_unique() {
+    let (other_scheduler_id, other_number) = other_scheduler_reference();
+
+    with_process(|process| {
+        let reference_term = result(process);
+        let reference: Boxed<Reference> = reference_term.try_into().unwrap();
+
+        assert_ne!(reference.scheduler_id(), other_scheduler_id);
+
+        let other_reference_term =
+            process.reference_from_scheduler(other_scheduler_id, other_number);
+
+        assert_ne!(reference_term, other_reference_term);
+    })
+}
+
+#[test]
+fn references_are_ordered_by_scheduler_before_number() {
+    with_process(|process| {
+        let reference_term = result(process);
+        let reference: Boxed<Reference> = reference_term.try_into().unwrap();
+        let scheduler_id: u32 = reference.scheduler_id().into();
+
+        let later_scheduler_reference_term =
+            process.reference_from_scheduler(scheduler::ID::from(scheduler_id + 1), 0);
+
+        assert!(reference_term < later_scheduler_reference_term);
+    })
+}
+
+fn other_scheduler_reference() -> (scheduler::ID, ReferenceNumber) {
+    // Each thread has its own scheduler
+    thread::spawn(|| {
+        let arc_process = process::default();
+        let reference: Boxed<Reference> = result(&arc_process).try_into().unwrap();
+
+        (reference.scheduler_id(), reference.number())
+    })
+    .join()
+    .unwrap()
+}
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::distribution::nodes::node;
+
+#[native_implemented::function(erlang:node/1)]
+pub fn result(arg: Term) -> exception::Result<Term> {
+    let arc_node = match arg.decode()? {
+        TypedTerm::Pid(_) | TypedTerm::Port(_) | TypedTerm::Reference(_) => node::arc_node(),
+        TypedTerm::ExternalPid(external_pid) => external_pid.arc_node(),
+        TypedTerm::ExternalPort(external_port) => external_port.arc_node(),
+        TypedTerm::ExternalReference(external_reference) => external_reference.arc_node(),
+        _ => {
+            return Err(TypeError)
+                .context(format!("arg ({}) is not a pid, port, or reference", arg))
+                .map_err(From::from)
+        }
+    };
+
+    arc_node.name().encode().map_err(From::from)
+}
@@ -0,0 +1,56 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::node_1::result;
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+use crate::test::strategy;
+use crate::test::{external_arc_node, with_process};
+
+#[test]
+fn without_pid_port_or_reference_errors_badarg() {
+    run!(
+        |arc_process| {
+            strategy::term(arc_process.clone())
+                .prop_filter("Arg cannot be a pid, port, or reference", |arg| {
+                    !(arg.is_pid() || arg.is_port() || arg.is_reference())
+                })
+        },
+        |arg| {
+            prop_assert_badarg!(
+                result(arg),
+                format!("arg ({}) is not a pid, port, or reference", arg)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_local_pid_returns_local_node() {
+    run!(|arc_process| Just(arc_process.pid_term()), |pid| {
+        prop_assert_eq!(result(pid), Ok(Atom::str_to_term("nonode@nohost")));
+
+        Ok(())
+    },);
+}
+
+#[test]
+fn with_external_pid_returns_external_node() {
+    with_process(|process| {
+        let pid = process.external_pid(external_arc_node(), 2, 3).unwrap();
+
+        assert_eq!(result(pid), Ok(Atom::str_to_term("node@external")));
+    });
+}
+
+#[test]
+fn with_local_reference_returns_local_node() {
+    with_process(|process| {
+        let reference = process.next_reference();
+
+        assert_eq!(result(reference), Ok(Atom::str_to_term("nonode@nohost")));
+    });
+}
@@ -0,0 +1,49 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::binary_to_string::binary_to_string;
+use crate::runtime::port;
+
+#[native_implemented::function(erlang:open_port/2)]
+pub fn result(process: &Process, port_name: Term, port_settings: Term) -> exception::Result<Term> {
+    let command = spawn_command(port_name)?;
+    let options: port::Options = port_settings.try_into()?;
+
+    port::open(process.pid(), &command, options)
+        .map(|port| port.encode().unwrap())
+        .map_err(From::from)
+}
+
+// Private
+
+fn spawn_command(port_name: Term) -> exception::Result<String> {
+    let tuple: Boxed<Tuple> = port_name
+        .try_into()
+        .with_context(|| format!("port_name ({}) is not {{spawn, Command}}", port_name))?;
+
+    if tuple.len() == 2 && tuple[0] == Atom::str_to_term("spawn") {
+        let command = tuple[1];
+
+        if command.is_binary() {
+            binary_to_string(command)
+        } else {
+            list_to_string(command)
+        }
+    } else {
+        Err(TypeError)
+            .context(format!(
+                "port_name ({}) is not {{spawn, Command}}",
+                port_name
+            ))
+            .map_err(From::from)
+    }
+}
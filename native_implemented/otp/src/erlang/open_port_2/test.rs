@@ -0,0 +1,133 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::open_port_2::result;
+use crate::test::{has_message, strategy, with_process_arc};
+
+#[test]
+fn without_spawn_tuple_port_name_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone())
+                    .prop_filter("Port name cannot be a tuple", |port_name| {
+                        !port_name.is_boxed_tuple()
+                    }),
+            )
+        },
+        |(arc_process, port_name)| {
+            prop_assert_badarg!(
+                result(&arc_process, port_name, Term::NIL),
+                format!("port_name ({}) is not {{spawn, Command}}", port_name)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let port_name = spawn(&arc_process, "true");
+        let option = Atom::str_to_term("unsupported");
+        let port_settings = arc_process.list_from_slice(&[option]);
+
+        assert_badarg!(
+            result(&arc_process, port_name, port_settings),
+            "supported options are binary, exit_status, stream, use_stdio, or {packet, 1 | 2 | 4}"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_packet_size_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let port_name = spawn(&arc_process, "true");
+        let size = arc_process.integer(3);
+        let port_settings = arc_process
+            .list_from_slice(&[arc_process.tuple_from_slice(&[Atom::str_to_term("packet"), size])]);
+
+        assert_badarg!(
+            result(&arc_process, port_name, port_settings),
+            "packet size (3) must be 1, 2, or 4"
+        );
+    });
+}
+
+#[test]
+fn with_binary_sends_data_and_exit_status_to_owner() {
+    with_process_arc(|arc_process| {
+        let port_name = spawn(&arc_process, "printf hello; exit 3");
+        let port_settings = arc_process.list_from_slice(&[
+            Atom::str_to_term("binary"),
+            Atom::str_to_term("exit_status"),
+        ]);
+
+        let port = result(&arc_process, port_name, port_settings).unwrap();
+
+        assert!(port.is_port());
+
+        let data_message = arc_process.tuple_from_slice(&[
+            port,
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("data"),
+                arc_process.binary_from_bytes(b"hello"),
+            ]),
+        ]);
+        let exit_status_message = arc_process.tuple_from_slice(&[
+            port,
+            arc_process
+                .tuple_from_slice(&[Atom::str_to_term("exit_status"), arc_process.integer(3)]),
+        ]);
+
+        assert!(eventually_has_message(&arc_process, data_message));
+        assert!(eventually_has_message(&arc_process, exit_status_message));
+    });
+}
+
+#[test]
+fn with_packet_sends_whole_packets_as_lists_to_owner() {
+    with_process_arc(|arc_process| {
+        let port_name = spawn(&arc_process, r"printf '\000\002hi'");
+        let port_settings = arc_process
+            .list_from_slice(&[arc_process
+                .tuple_from_slice(&[Atom::str_to_term("packet"), arc_process.integer(2)])]);
+
+        let port = result(&arc_process, port_name, port_settings).unwrap();
+
+        let data_message = arc_process.tuple_from_slice(&[
+            port,
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("data"),
+                arc_process.charlist_from_str("hi"),
+            ]),
+        ]);
+
+        assert!(eventually_has_message(&arc_process, data_message));
+    });
+}
+
+fn eventually_has_message(process: &Process, message: Term) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    while !has_message(process, message) {
+        if deadline < Instant::now() {
+            return false;
+        }
+
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    true
+}
+
+fn spawn(process: &Process, command: &str) -> Term {
+    process.tuple_from_slice(&[Atom::str_to_term("spawn"), process.binary_from_str(command)])
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::hash::phash2;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Default range of `phash2/1`, so that the hash fits in a small integer on 32-bit platforms.
+pub const RANGE: u32 = 1 << 27;
+
+#[native_implemented::function(erlang:phash2/1)]
+pub fn result(process: &Process, term: Term) -> Term {
+    process.integer((phash2(term) % RANGE) as u64)
+}
@@ -0,0 +1,70 @@
+use std::convert::TryInto;
+
+use proptest::prop_assert;
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::borrow::clone_to_process::CloneToProcess;
+use liblumen_alloc::erts::process::alloc::TermAlloc;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::phash2_1::{result, RANGE};
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn returns_non_negative_integer_less_than_2_to_the_27th() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::term(arc_process)),
+        |(arc_process, term)| {
+            let hash: u64 = result(&arc_process, term).try_into().unwrap();
+
+            prop_assert!(hash < (RANGE as u64));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn equal_terms_have_equal_hashes() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::term(arc_process)),
+        |(arc_process, term)| {
+            let copy = term.clone_to_process(&arc_process);
+
+            prop_assert_eq!(result(&arc_process, term), result(&arc_process, copy));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn heap_and_process_binaries_with_the_same_bytes_have_equal_hashes() {
+    with_process(|process| {
+        let bytes = [0, 1, 2, 3];
+        let heap_binary = process.binary_from_bytes(&bytes);
+        let process_binary = process
+            .acquire_heap()
+            .procbin_from_bytes(&bytes)
+            .unwrap()
+            .encode()
+            .unwrap();
+
+        assert_eq!(
+            result(process, heap_binary),
+            result(process, process_binary)
+        );
+    });
+}
+
+#[test]
+fn integer_and_float_with_the_same_value_have_different_hashes() {
+    with_process(|process| {
+        assert_ne!(
+            result(process, process.integer(1)),
+            result(process, process.float(1.0))
+        );
+    });
+}
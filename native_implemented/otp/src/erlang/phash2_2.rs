@@ -0,0 +1,29 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::hash::phash2;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::*;
+
+/// `Range` can be at most 2^32, so that every hash is possible.
+const MAX_RANGE: u64 = 1 << 32;
+
+#[native_implemented::function(erlang:phash2/2)]
+pub fn result(process: &Process, term: Term, range: Term) -> exception::Result<Term> {
+    let range_u64: u64 = range
+        .try_into()
+        .ok()
+        .filter(|range_u64| (1..=MAX_RANGE).contains(range_u64))
+        .with_context(|| {
+            term_is_not_type("range", range, &format!("an integer in 1-{}", MAX_RANGE))
+        })?;
+
+    Ok(process.integer((phash2(term) as u64) % range_u64))
+}
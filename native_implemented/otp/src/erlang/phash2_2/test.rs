@@ -0,0 +1,122 @@
+use std::convert::TryInto;
+
+use proptest::strategy::{Just, Strategy};
+use proptest::{prop_assert, prop_assert_eq};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::phash2_2::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_integer_range_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_integer(arc_process),
+            )
+        },
+        |(arc_process, term, range)| {
+            prop_assert_badarg!(
+                result(&arc_process, term, range),
+                format!("range ({}) is not an integer in 1-4294967296", range)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_non_positive_range_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::integer::non_positive(arc_process),
+            )
+        },
+        |(arc_process, term, range)| {
+            prop_assert_badarg!(
+                result(&arc_process, term, range),
+                format!("range ({}) is not an integer in 1-4294967296", range)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_range_greater_than_2_to_the_32nd_errors_badarg() {
+    with_process(|process| {
+        let range = process.integer(4_294_967_297_u64);
+
+        assert_badarg!(
+            result(process, Atom::str_to_term("term"), range),
+            format!("range ({}) is not an integer in 1-4294967296", range)
+        );
+    });
+}
+
+#[test]
+fn with_range_returns_non_negative_integer_less_than_range() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                (1_u64..=(1 << 32)),
+            )
+                .prop_map(|(arc_process, term, range_u64)| {
+                    (
+                        arc_process.clone(),
+                        term,
+                        range_u64,
+                        arc_process.integer(range_u64),
+                    )
+                })
+        },
+        |(arc_process, term, range_u64, range)| {
+            let hash: u64 = result(&arc_process, term, range)
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+            prop_assert!(hash < range_u64);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_range_1_returns_0() {
+    run!(
+        |arc_process| (Just(arc_process.clone()), strategy::term(arc_process)),
+        |(arc_process, term)| {
+            prop_assert_eq!(
+                result(&arc_process, term, arc_process.integer(1)),
+                Ok(arc_process.integer(0))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_range_2_to_the_32nd_is_phash2_before_range() {
+    with_process(|process| {
+        let term = Atom::str_to_term("term");
+
+        assert_eq!(
+            result(process, term, process.integer(1_u64 << 32)),
+            Ok(process.integer(liblumen_alloc::erts::term::hash::phash2(term) as u64))
+        );
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::port_command_2::term_try_into_port;
+use crate::runtime::port;
+
+#[native_implemented::function(erlang:port_close/1)]
+pub fn result(port: Term) -> exception::Result<Term> {
+    let port_port = term_try_into_port(port)?;
+
+    if port::close(port_port) {
+        Ok(true.into())
+    } else {
+        Err(anyhow!("port ({}) is not open", port).into())
+    }
+}
@@ -0,0 +1,45 @@
+use proptest::strategy::Strategy;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::port_close_1::result;
+use crate::erlang::{open_port_2, port_command_2};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_port_errors_badarg() {
+    run!(
+        |arc_process| {
+            strategy::term(arc_process.clone())
+                .prop_filter("Port cannot be a port", |port| !port.is_port())
+        },
+        |port| {
+            prop_assert_badarg!(result(port), format!("port ({}) is not a port", port));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_port_that_was_never_opened_errors_badarg() {
+    let port = Port::next_term();
+
+    assert_badarg!(result(port), format!("port ({}) is not open", port));
+}
+
+#[test]
+fn with_open_port_returns_true_and_closes_port() {
+    with_process(|process| {
+        let port_name =
+            process.tuple_from_slice(&[Atom::str_to_term("spawn"), process.binary_from_str("cat")]);
+        let port = open_port_2::result(process, port_name, Term::NIL).unwrap();
+
+        assert_eq!(result(port), Ok(true.into()));
+        assert_badarg!(result(port), format!("port ({}) is not open", port));
+        assert_badarg!(
+            port_command_2::result(process, port, Term::NIL),
+            format!("port ({}) is not open", port)
+        );
+    });
+}
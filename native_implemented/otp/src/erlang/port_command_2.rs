@@ -0,0 +1,39 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::{dirty_io, port};
+
+/// Writes `data` to the port's OS process and returns `true` once it is written.
+///
+/// The write runs on the `dirty_io` pool, so that an OS process that is slow to read its stdin
+/// does not block the calling process's scheduler.
+#[native_implemented::function(erlang:port_command/2)]
+pub fn result(process: &Process, port: Term, data: Term) -> exception::Result<Term> {
+    let port_port = term_try_into_port(port)?;
+    let byte_vec = iolist_or_binary::to_byte_vec("data", data)?;
+    let packet = port::command(port_port, &byte_vec)?;
+
+    let output = dirty_io::spawn(process, move || packet.write());
+    let output_term = process.resource(output);
+    process.queue_frame_with_arguments(label_1::frame().with_arguments(false, &[output_term]));
+
+    Ok(Term::NONE)
+}
+
+pub(in crate::erlang) fn term_try_into_port(port: Term) -> exception::Result<Port> {
+    match port.decode()? {
+        TypedTerm::Port(port_port) => Ok(port_port),
+        _ => Err(TypeError)
+            .context(format!("port ({}) is not a port", port))
+            .map_err(From::from),
+    }
+}
@@ -0,0 +1,30 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::dirty_io::Output;
+
+#[native_implemented::label]
+fn result(process: &Process, output: Term) -> exception::Result<Term> {
+    let taken = match output.decode().unwrap() {
+        TypedTerm::ResourceReference(resource_reference) => {
+            let resource: Resource = resource_reference.into();
+            let output = resource
+                .downcast_ref::<Output<anyhow::Result<()>>>()
+                .unwrap();
+
+            output.take_or_wait(process)
+        }
+        _ => unreachable!("output ({}) is not a resource", output),
+    };
+
+    match taken {
+        Some(Ok(())) => Ok(true.into()),
+        Some(Err(error)) => Err(error.into()),
+        None => {
+            process.queue_frame_with_arguments(frame().with_arguments(false, &[output]));
+
+            Ok(Term::NONE)
+        }
+    }
+}
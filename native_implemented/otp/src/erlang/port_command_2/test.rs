@@ -0,0 +1,50 @@
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::port_command_2::result;
+use crate::erlang::{open_port_2, port_close_1};
+use crate::test::{strategy, with_process_arc};
+
+#[test]
+fn without_port_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone())
+                    .prop_filter("Port cannot be a port", |port| !port.is_port()),
+                Just(Term::NIL),
+            )
+        },
+        |(arc_process, port, data)| {
+            prop_assert_badarg!(
+                result(&arc_process, port, data),
+                format!("port ({}) is not a port", port)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_closed_port_errors_badarg() {
+    with_process_arc(|arc_process| {
+        let port = open(&arc_process, Term::NIL);
+
+        assert_eq!(port_close_1::result(port), Ok(true.into()));
+        assert_badarg!(
+            result(&arc_process, port, arc_process.binary_from_str("data")),
+            format!("port ({}) is not open", port)
+        );
+    });
+}
+
+fn open(process: &Process, port_settings: Term) -> Term {
+    let port_name =
+        process.tuple_from_slice(&[Atom::str_to_term("spawn"), process.binary_from_str("cat")]);
+
+    open_port_2::result(process, port_name, port_settings).unwrap()
+}
@@ -0,0 +1,59 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::process_info_2::process_info;
+use crate::runtime::registry::pid_to_process;
+
+/// The items returned by `process_info/1`, in the order they are returned.  `registered_name` is
+/// only returned when the process is registered.
+const ITEM_NAMES: &[&str] = &[
+    "registered_name",
+    "current_function",
+    "initial_call",
+    "status",
+    "message_queue_len",
+    "links",
+    "dictionary",
+    "trap_exit",
+    "total_heap_size",
+    "heap_size",
+    "stack_size",
+    "reductions",
+    "garbage_collection",
+];
+
+#[native_implemented::function(erlang:process_info/1)]
+pub fn result(process: &Process, pid: Term) -> exception::Result<Term> {
+    let pid_pid = term_try_into_local_pid!(pid)?;
+
+    if process.pid() == pid_pid {
+        process_info_list(process, process)
+    } else {
+        match pid_to_process(&pid_pid) {
+            Some(pid_arc_process) => process_info_list(process, &pid_arc_process),
+            None => Ok(atom!("undefined")),
+        }
+    }
+}
+
+// Private
+
+fn process_info_list(process: &Process, info_process: &Process) -> exception::Result<Term> {
+    let mut vec = Vec::with_capacity(ITEM_NAMES.len());
+
+    for item_name in ITEM_NAMES {
+        let item_info = process_info(process, info_process, Atom::from_str(item_name))?;
+
+        // `registered_name` is `[]` when the process is not registered
+        if item_info != Term::NIL {
+            vec.push(item_info);
+        }
+    }
+
+    Ok(process.list_from_slice(&vec))
+}
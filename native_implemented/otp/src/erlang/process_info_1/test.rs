@@ -0,0 +1,91 @@
+use std::convert::TryInto;
+
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::registry;
+
+use crate::erlang::process_info_1::result;
+use crate::test::{registered_name, strategy, with_process_arc};
+
+#[test]
+fn without_local_pid_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_local_pid(arc_process.clone()),
+            )
+        },
+        |(arc_process, pid)| {
+            prop_assert_is_not_local_pid!(result(&arc_process, pid), pid);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_process_returns_undefined() {
+    with_process_arc(|arc_process| {
+        let pid = Pid::next_term();
+
+        assert_eq!(
+            result(&arc_process, pid),
+            Ok(Atom::str_to_term("undefined"))
+        );
+    });
+}
+
+#[test]
+fn without_registered_name_starts_with_current_function() {
+    with_process_arc(|arc_process| {
+        let item_infos = item_infos(result(&arc_process, arc_process.pid_term()).unwrap());
+
+        assert_eq!(
+            item_tag(item_infos[0]),
+            Atom::str_to_term("current_function")
+        );
+        assert_eq!(
+            item_tag(item_infos[item_infos.len() - 1]),
+            Atom::str_to_term("garbage_collection")
+        );
+    });
+}
+
+#[test]
+fn with_registered_name_starts_with_registered_name() {
+    with_process_arc(|arc_process| {
+        let registered_name = registered_name();
+        let registered_name_atom: Atom = registered_name.try_into().unwrap();
+
+        assert!(registry::put_atom_to_process(
+            registered_name_atom,
+            arc_process.clone()
+        ));
+
+        let item_infos = item_infos(result(&arc_process, arc_process.pid_term()).unwrap());
+
+        assert_eq!(
+            item_infos[0],
+            arc_process.tuple_from_slice(&[Atom::str_to_term("registered_name"), registered_name])
+        );
+        assert_eq!(
+            item_tag(item_infos[1]),
+            Atom::str_to_term("current_function")
+        );
+    });
+}
+
+fn item_infos(list: Term) -> Vec<Term> {
+    let cons: Boxed<Cons> = list.try_into().unwrap();
+
+    cons.into_iter().map(|result| result.unwrap()).collect()
+}
+
+fn item_tag(item_info: Term) -> Term {
+    let tuple: Boxed<Tuple> = item_info.try_into().unwrap();
+
+    tuple[0]
+}
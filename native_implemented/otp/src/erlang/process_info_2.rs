@@ -1,14 +1,17 @@
 #[cfg(all(not(target_arch = "wasm32"), test))]
 mod test;
 
+use std::sync::atomic::Ordering;
+
 use anyhow::*;
 
 use liblumen_alloc::atom;
 use liblumen_alloc::borrow::clone_to_process::CloneToProcess;
 use liblumen_alloc::erts::exception::{self, InternalResult};
 use liblumen_alloc::erts::message::{self, Message};
-use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::process::{Priority, Process, Status};
 use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::ModuleFunctionArity;
 
 use crate::runtime::registry::pid_to_process;
 
@@ -18,53 +21,63 @@ pub fn result(process: &Process, pid: Term, item: Term) -> exception::Result<Ter
     let item_atom: Atom = term_try_into_atom!(item)?;
 
     if process.pid() == pid_pid {
-        process_info(process, item_atom)
+        process_info(process, process, item_atom)
     } else {
         match pid_to_process(&pid_pid) {
-            Some(pid_arc_process) => process_info(&pid_arc_process, item_atom),
+            Some(pid_arc_process) => process_info(process, &pid_arc_process, item_atom),
             None => Ok(atom!("undefined")),
         }
     }
     .map_err(From::from)
 }
 
-// Private
-
-fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
+/// Returns the `{item, value}` tuple for `item` of `info_process`, allocated on the heap of
+/// `process`.
+///
+/// Only one of `info_process`'s locks is held at a time.  It is released before allocating on
+/// `process`, except for `messages`, which copies the messages while holding `info_process`'s
+/// mailbox lock, so that a garbage collection of `info_process` can't move them mid-copy.
+/// Allocating on `process` never takes a lock of another process, so two processes getting each
+/// other's info still can't deadlock.
+pub(in crate::erlang) fn process_info(
+    process: &Process,
+    info_process: &Process,
+    item: Atom,
+) -> InternalResult<Term> {
     match item.name() {
         "backtrace" => unimplemented!(),
         "binary" => unimplemented!(),
         "catchlevel" => unimplemented!(),
-        "current_function" => unimplemented!(),
+        "current_function" => Ok(current_function(process, info_process)),
         "current_location" => unimplemented!(),
         "current_stacktrace" => unimplemented!(),
-        "dictionary" => unimplemented!(),
+        "dictionary" => Ok(dictionary(process, info_process)),
         "error_handler" => unimplemented!(),
-        "garbage_collection" => unimplemented!(),
-        "garbage_collection_info" => unimplemented!(),
+        "garbage_collection" => Ok(garbage_collection(process, info_process)),
+        "garbage_collection_info" => Ok(garbage_collection_info(process, info_process)),
         "group_leader" => unimplemented!(),
-        "heap_size" => unimplemented!(),
-        "initial_call" => unimplemented!(),
-        "links" => Ok(links(process)),
+        "heap_size" => Ok(heap_size(process, info_process)),
+        "initial_call" => Ok(initial_call(process, info_process)),
+        "links" => Ok(links(process, info_process)),
         "last_calls" => unimplemented!(),
         "memory" => unimplemented!(),
-        "message_queue_len" => unimplemented!(),
-        "messages" => Ok(messages(process)),
-        "min_heap_size" => unimplemented!(),
-        "min_bin_vheap_size" => unimplemented!(),
-        "monitored_by" => Ok(monitored_by(process)),
-        "monitors" => Ok(monitors(process)),
+        "message_queue_len" => Ok(message_queue_len(process, info_process)),
+        "messages" => Ok(messages(process, info_process)),
+        "min_heap_size" => Ok(min_heap_size(process, info_process)),
+        "min_bin_vheap_size" => Ok(min_bin_vheap_size(process, info_process)),
+        "monitored_by" => Ok(monitored_by(process, info_process)),
+        "monitors" => Ok(monitors(process, info_process)),
         "message_queue_data" => unimplemented!(),
-        "priority" => unimplemented!(),
-        "reductions" => unimplemented!(),
-        "registered_name" => Ok(registered_name(process)),
+        "priority" => Ok(priority(process, info_process)),
+        "reductions" => Ok(reductions(process, info_process)),
+        "registered_name" => Ok(registered_name(process, info_process)),
         "sequential_trace_token" => unimplemented!(),
-        "stack_size" => unimplemented!(),
-        "status" => unimplemented!(),
+        "stack_size" => Ok(stack_size(process, info_process)),
+        "status" => Ok(status(process, info_process)),
         "suspending" => unimplemented!(),
-        "total_heap_size" => unimplemented!(),
+        "total_heap_size" => Ok(total_heap_size(process, info_process)),
         "trace" => unimplemented!(),
-        "trap_exit" => Ok(trap_exit(process)),
+        "trap_exit" => Ok(trap_exit(process, info_process)),
         name => Err(TryAtomFromTermError(name))
             .context(
                 "supported items are backtrace, binary, catchlevel, current_function, \
@@ -80,10 +93,116 @@ fn process_info(process: &Process, item: Atom) -> InternalResult<Term> {
     }
 }
 
-fn links(process: &Process) -> Term {
+// Private
+
+/// Terms on the heap of another process need to be copied before they can be returned
+fn copy_to_process(process: &Process, info_process: &Process, term: Term) -> Term {
+    if process.pid() == info_process.pid() {
+        term
+    } else {
+        term.clone_to_process(process)
+    }
+}
+
+fn current_function(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("current_function");
+    let value = match info_process.current_module_function_arity() {
+        Some(module_function_arity) => {
+            module_function_arity_to_tuple(process, &module_function_arity)
+        }
+        None => atom!("undefined"),
+    };
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn dictionary(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("dictionary");
+
+    let vec: Vec<Term> = info_process
+        .get_entry_vec()
+        .into_iter()
+        .map(|(key, value)| {
+            process.tuple_from_slice(&[
+                copy_to_process(process, info_process, key),
+                copy_to_process(process, info_process, value),
+            ])
+        })
+        .collect();
+    let value = process.list_from_slice(&vec);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn garbage_collection(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("garbage_collection");
+
+    let max_heap_size_value = super::max_heap_size_to_term(process, info_process.max_heap_size());
+    let vec = vec![
+        process.tuple_from_slice(&[atom!("max_heap_size"), max_heap_size_value]),
+        process.tuple_from_slice(&[
+            atom!("min_bin_vheap_size"),
+            process.integer(info_process.min_vheap_size()),
+        ]),
+        process.tuple_from_slice(&[
+            atom!("min_heap_size"),
+            process.integer(info_process.min_heap_size()),
+        ]),
+        process.tuple_from_slice(&[
+            atom!("fullsweep_after"),
+            process.integer(info_process.fullsweep_after()),
+        ]),
+        process.tuple_from_slice(&[
+            atom!("minor_gcs"),
+            process.integer(info_process.minor_gcs()),
+        ]),
+    ];
+    let value = process.list_from_slice(&vec);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn garbage_collection_info(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("garbage_collection_info");
+
+    let info = info_process.garbage_collection_info();
+    let vec: Vec<Term> = [
+        ("old_heap_block_size", info.old_heap_block_size),
+        ("heap_block_size", info.heap_block_size),
+        ("mbuf_size", info.mbuf_size),
+        ("stack_size", info.stack_size),
+        ("old_heap_size", info.old_heap_size),
+        ("heap_size", info.heap_size),
+    ]
+    .iter()
+    .map(|(key, value)| {
+        process.tuple_from_slice(&[Atom::str_to_term(key), process.integer(*value)])
+    })
+    .collect();
+    let value = process.list_from_slice(&vec);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn heap_size(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("heap_size");
+    let value = process.integer(info_process.heap_size());
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn initial_call(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("initial_call");
+    let value =
+        module_function_arity_to_tuple(process, &info_process.initial_module_function_arity);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn links(process: &Process, info_process: &Process) -> Term {
     let tag = atom!("links");
 
-    let vec: Vec<Term> = process
+    let vec: Vec<Term> = info_process
         .linked_pid_set
         .iter()
         .map(|ref_multi| ref_multi.encode().unwrap())
@@ -93,16 +212,26 @@ fn links(process: &Process) -> Term {
     process.tuple_from_slice(&[tag, value])
 }
 
-fn messages(process: &Process) -> Term {
+fn message_queue_len(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("message_queue_len");
+    let len = info_process.mailbox.lock().borrow().len();
+    let value = process.integer(len);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn messages(process: &Process, info_process: &Process) -> Term {
     let tag = atom!("messages");
 
-    let vec: Vec<Term> = process
+    let vec: Vec<Term> = info_process
         .mailbox
         .lock()
         .borrow()
         .iter()
         .map(|message| match message {
-            Message::Process(message::Process { data }) => *data,
+            Message::Process(message::Process { data }) => {
+                copy_to_process(process, info_process, *data)
+            }
             Message::HeapFragment(message::HeapFragment { data, .. }) => {
                 data.clone_to_process(process)
             }
@@ -114,10 +243,35 @@ fn messages(process: &Process) -> Term {
     process.tuple_from_slice(&[tag, value])
 }
 
-fn monitored_by(process: &Process) -> Term {
+fn min_bin_vheap_size(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("min_bin_vheap_size");
+    let value = process.integer(info_process.min_vheap_size());
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn min_heap_size(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("min_heap_size");
+    let value = process.integer(info_process.min_heap_size());
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn module_function_arity_to_tuple(
+    process: &Process,
+    module_function_arity: &ModuleFunctionArity,
+) -> Term {
+    process.tuple_from_slice(&[
+        module_function_arity.module.encode().unwrap(),
+        module_function_arity.function.encode().unwrap(),
+        process.integer(module_function_arity.arity),
+    ])
+}
+
+fn monitored_by(process: &Process, info_process: &Process) -> Term {
     let tag = atom!("monitored_by");
 
-    let vec: Vec<Term> = process
+    let vec: Vec<Term> = info_process
         .monitor_by_reference
         .iter()
         .map(|ref_multi| ref_multi.monitoring_pid().encode().unwrap())
@@ -127,12 +281,16 @@ fn monitored_by(process: &Process) -> Term {
     process.tuple_from_slice(&[tag, value])
 }
 
-fn monitors(process: &Process) -> Term {
+fn monitors(process: &Process, info_process: &Process) -> Term {
     let monitor_type = atom!("process");
-    let mut vec = Vec::new();
+    let pid_vec: Vec<Pid> = info_process
+        .monitored_pid_by_reference
+        .iter()
+        .map(|ref_multi| *ref_multi.value())
+        .collect();
+    let mut vec = Vec::with_capacity(pid_vec.len());
 
-    for ref_multi in process.monitored_pid_by_reference.iter() {
-        let pid = ref_multi.value();
+    for pid in pid_vec {
         let monitor_value = pid.encode().unwrap();
         let monitor = process.tuple_from_slice(&[monitor_type, monitor_value]);
         vec.push(monitor);
@@ -144,8 +302,30 @@ fn monitors(process: &Process) -> Term {
     process.tuple_from_slice(&[tag, value])
 }
 
-fn registered_name(process: &Process) -> Term {
-    match *process.registered_name.read() {
+fn priority(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("priority");
+    let value = match info_process.priority {
+        Priority::Low => atom!("low"),
+        Priority::Normal => atom!("normal"),
+        Priority::High => atom!("high"),
+        Priority::Max => atom!("max"),
+    };
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn reductions(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("reductions");
+    let reductions = info_process.total_reductions.load(Ordering::SeqCst);
+    let value = process.integer(reductions);
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn registered_name(process: &Process, info_process: &Process) -> Term {
+    let option_registered_name = *info_process.registered_name.read();
+
+    match option_registered_name {
         Some(registered_name) => {
             let tag = atom!("registered_name");
             let value = registered_name.encode().unwrap();
@@ -156,9 +336,37 @@ fn registered_name(process: &Process) -> Term {
     }
 }
 
-fn trap_exit(process: &Process) -> Term {
+fn stack_size(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("stack_size");
+    let value = process.integer(info_process.stack_used());
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn status(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("status");
+    let value = match *info_process.status.read() {
+        Status::Unrunnable | Status::Runnable => atom!("runnable"),
+        Status::Running => atom!("running"),
+        Status::Waiting => atom!("waiting"),
+        Status::Exited | Status::SystemException(_) | Status::RuntimeException(_) => {
+            atom!("exiting")
+        }
+    };
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn total_heap_size(process: &Process, info_process: &Process) -> Term {
+    let tag = atom!("total_heap_size");
+    let value = process.integer(info_process.total_heap_size());
+
+    process.tuple_from_slice(&[tag, value])
+}
+
+fn trap_exit(process: &Process, info_process: &Process) -> Term {
     let tag = atom!("trap_exit");
-    let value = process.traps_exit().into();
+    let value = info_process.traps_exit().into();
 
     process.tuple_from_slice(&[tag, value])
 }
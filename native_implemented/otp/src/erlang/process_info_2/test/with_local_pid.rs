@@ -1,3 +1,7 @@
+mod with_dictionary;
+mod with_garbage_collection;
+mod with_garbage_collection_info;
+mod with_message_queue_len;
 mod with_registered_name;
 
 use super::*;
@@ -29,7 +33,7 @@ fn without_supported_item_errors_badarg() {
 fn unsupported_item_atom() -> BoxedStrategy<Term> {
     strategy::atom()
         .prop_filter("Item cannot be supported", |atom| match atom.name() {
-            "registered_name" => false,
+            "dictionary" | "garbage_collection" | "message_queue_len" | "registered_name" => false,
             _ => true,
         })
         .prop_map(|atom| atom.encode().unwrap())
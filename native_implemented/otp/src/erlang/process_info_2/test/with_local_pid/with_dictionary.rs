@@ -0,0 +1,50 @@
+use super::*;
+
+#[test]
+fn with_self_returns_entries() {
+    with_process_arc(|arc_process| {
+        let key = Atom::str_to_term("key");
+        let value = arc_process.integer(1);
+
+        arc_process.put(key, value);
+
+        assert_eq!(
+            result(&arc_process, arc_process.pid_term(), item()),
+            Ok(arc_process.tuple_from_slice(&[
+                item(),
+                arc_process.list_from_slice(&[arc_process.tuple_from_slice(&[key, value])])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_other_returns_entries_copied_to_self() {
+    with_process_arc(|arc_process| {
+        let other_arc_process = test::process::child(&arc_process);
+        let key = Atom::str_to_term("key");
+        let value = other_arc_process.list_from_slice(&[other_arc_process.integer(1)]);
+
+        other_arc_process.put(key, value);
+
+        let process_info = result(&arc_process, other_arc_process.pid_term(), item()).unwrap();
+
+        let process_info_ptr: *mut Term = process_info.dyn_cast();
+
+        assert!(arc_process.is_owner(process_info_ptr));
+        assert_eq!(
+            process_info,
+            arc_process.tuple_from_slice(&[
+                item(),
+                arc_process.list_from_slice(&[arc_process.tuple_from_slice(&[
+                    key,
+                    arc_process.list_from_slice(&[arc_process.integer(1)])
+                ])])
+            ])
+        );
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("dictionary")
+}
@@ -0,0 +1,62 @@
+use super::*;
+
+use liblumen_alloc::erts::process::MaxHeapSize;
+
+#[test]
+fn without_process_returns_undefined() {
+    with_process_arc(|arc_process| {
+        let pid = Pid::next_term();
+
+        assert_eq!(
+            result(&arc_process, pid, item()),
+            Ok(Atom::str_to_term("undefined"))
+        );
+    });
+}
+
+#[test]
+fn with_process_returns_garbage_collection_settings_and_minor_gcs() {
+    with_process_arc(|arc_process| {
+        let max_heap_size = arc_process.max_heap_size();
+        let max_heap_size_value = arc_process.map_from_slice(&[
+            (
+                Atom::str_to_term(MaxHeapSize::ERROR_LOGGER_KEY),
+                max_heap_size.error_logger.into(),
+            ),
+            (
+                Atom::str_to_term(MaxHeapSize::KILL_KEY),
+                max_heap_size.kill.into(),
+            ),
+            (
+                Atom::str_to_term(MaxHeapSize::SIZE_KEY),
+                arc_process.integer(max_heap_size.size),
+            ),
+        ]);
+        let value = arc_process.list_from_slice(&[
+            arc_process
+                .tuple_from_slice(&[Atom::str_to_term("max_heap_size"), max_heap_size_value]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("min_bin_vheap_size"),
+                arc_process.integer(arc_process.min_vheap_size()),
+            ]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("min_heap_size"),
+                arc_process.integer(arc_process.min_heap_size()),
+            ]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("fullsweep_after"),
+                arc_process.integer(arc_process.fullsweep_after()),
+            ]),
+            arc_process.tuple_from_slice(&[Atom::str_to_term("minor_gcs"), arc_process.integer(0)]),
+        ]);
+
+        assert_eq!(
+            result(&arc_process, arc_process.pid_term(), item()),
+            Ok(arc_process.tuple_from_slice(&[item(), value]))
+        );
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("garbage_collection")
+}
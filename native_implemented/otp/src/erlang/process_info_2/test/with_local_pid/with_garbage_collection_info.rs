@@ -0,0 +1,44 @@
+use super::*;
+
+#[test]
+fn with_process_returns_heap_sizes() {
+    with_process_arc(|arc_process| {
+        // Another process, so that allocating the result doesn't change the sizes
+        let info_arc_process = test::process::child(&arc_process);
+        let garbage_collection_info = info_arc_process.garbage_collection_info();
+
+        let value = arc_process.list_from_slice(&[
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("old_heap_block_size"),
+                arc_process.integer(0),
+            ]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("heap_block_size"),
+                arc_process.integer(info_arc_process.heap_size()),
+            ]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("mbuf_size"),
+                arc_process.integer(garbage_collection_info.mbuf_size),
+            ]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("stack_size"),
+                arc_process.integer(garbage_collection_info.stack_size),
+            ]),
+            arc_process
+                .tuple_from_slice(&[Atom::str_to_term("old_heap_size"), arc_process.integer(0)]),
+            arc_process.tuple_from_slice(&[
+                Atom::str_to_term("heap_size"),
+                arc_process.integer(garbage_collection_info.heap_size),
+            ]),
+        ]);
+
+        assert_eq!(
+            result(&arc_process, info_arc_process.pid_term(), item()),
+            Ok(arc_process.tuple_from_slice(&[item(), value]))
+        );
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("garbage_collection_info")
+}
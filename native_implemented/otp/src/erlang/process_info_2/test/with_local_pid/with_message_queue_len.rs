@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn without_messages_returns_zero() {
+    with_process_arc(|arc_process| {
+        assert_eq!(
+            result(&arc_process, arc_process.pid_term(), item()),
+            Ok(arc_process.tuple_from_slice(&[item(), arc_process.integer(0)]))
+        );
+    });
+}
+
+#[test]
+fn with_messages_returns_number_of_messages() {
+    with_process_arc(|arc_process| {
+        arc_process.send_from_self(Atom::str_to_term("first"));
+        arc_process.send_from_self(Atom::str_to_term("second"));
+
+        assert_eq!(
+            result(&arc_process, arc_process.pid_term(), item()),
+            Ok(arc_process.tuple_from_slice(&[item(), arc_process.integer(2)]))
+        );
+    });
+}
+
+fn item() -> Term {
+    Atom::str_to_term("message_queue_len")
+}
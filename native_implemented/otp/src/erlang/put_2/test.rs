@@ -55,3 +55,40 @@ fn with_key_returns_previous_value() {
         },
     );
 }
+
+#[test]
+fn entries_survive_garbage_collection() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+        },
+        |(arc_process, key, value)| {
+            arc_process.erase_entries();
+
+            result(&arc_process, key, value);
+
+            // The dictionary holds its own copies of `key` and `value`, so if its entries were not
+            // roots they would be left pointing into the freed young heap after the collection.
+            let formatted_key = format!("{}", key);
+            let formatted_value = format!("{}", value);
+
+            let mut roots = [key, value];
+            arc_process.garbage_collect(0, &mut roots[..]).unwrap();
+            let [key, value] = roots;
+
+            prop_assert_eq!(arc_process.get_value_from_key(key), value);
+
+            let entries = arc_process.get_entry_vec();
+            prop_assert_eq!(entries.len(), 1);
+            let (entry_key, entry_value) = entries[0];
+            prop_assert_eq!(format!("{}", entry_key), formatted_key);
+            prop_assert_eq!(format!("{}", entry_value), formatted_value);
+
+            Ok(())
+        },
+    );
+}
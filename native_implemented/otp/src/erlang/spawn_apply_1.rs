@@ -8,6 +8,7 @@ use liblumen_alloc::erts::term::prelude::*;
 
 use crate::runtime::process::spawn::options::Options;
 use crate::runtime::scheduler::Scheduled;
+use crate::runtime::trace;
 
 pub(in crate::erlang) fn result(
     process: &Process,
@@ -22,6 +23,17 @@ pub(in crate::erlang) fn result(
         .scheduler()
         .unwrap()
         .spawn_closure(Some(process), boxed_closure, options)
-        .map(|spawned| spawned.to_term(process))
+        .map(|spawned| {
+            // Like the BEAM, the closure is traced as the arguments of `erlang:apply/2`
+            trace::spawn(
+                process,
+                &spawned.arc_process,
+                Atom::from_str("erlang"),
+                Atom::from_str("apply"),
+                || process.list_from_slice(&[function, Term::NIL]),
+            );
+
+            spawned.to_term(process)
+        })
         .map_err(From::from)
 }
@@ -5,6 +5,7 @@ use liblumen_alloc::erts::term::prelude::*;
 use crate::erlang::apply::arguments_term_to_vec;
 use crate::runtime::process::spawn::options::Options;
 use crate::runtime::scheduler::Scheduled;
+use crate::runtime::trace;
 
 pub(in crate::erlang) fn result(
     process: &Process,
@@ -27,6 +28,16 @@ pub(in crate::erlang) fn result(
             argument_vec,
             options,
         )
-        .map(|spawned| spawned.to_term(process))
+        .map(|spawned| {
+            trace::spawn(
+                process,
+                &spawned.arc_process,
+                module_atom,
+                function_atom,
+                || arguments,
+            );
+
+            spawned.to_term(process)
+        })
         .map_err(From::from)
 }
@@ -0,0 +1,35 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use crate::erlang::spawn_apply_1;
+use crate::runtime::distribution::nodes::node;
+use crate::runtime::process::spawn::options::Options;
+
+/// Distribution is not supported at this time, so only the local `node` is accepted.
+#[native_implemented::function(erlang:spawn_opt/3)]
+pub fn result(
+    process: &Process,
+    node: Term,
+    function: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let node_atom = term_try_into_atom!(node)?;
+
+    if node == node::term() {
+        let options: Options = options.try_into()?;
+
+        spawn_apply_1::result(process, function, options)
+    } else {
+        Err(anyhow!(
+            "node ({}) is not the local node ({}) and distribution is not supported",
+            node_atom,
+            node::term()
+        )
+        .into())
+    }
+}
@@ -17,7 +17,9 @@ pub fn result(process: &Process, binary: Term, position: Term) -> exception::Res
         .with_context(|| format!("position ({}) must be in 0..byte_size(binary)", position))?;
 
     match binary.decode().unwrap() {
-        binary_box @ TypedTerm::HeapBinary(_) | binary_box @ TypedTerm::ProcBin(_) => {
+        binary_box @ TypedTerm::BinaryLiteral(_)
+        | binary_box @ TypedTerm::HeapBinary(_)
+        | binary_box @ TypedTerm::ProcBin(_) => {
             if index == 0 {
                 let mut heap = process.acquire_heap();
 
@@ -33,6 +35,7 @@ pub fn result(process: &Process, binary: Term, position: Term) -> exception::Res
                 Ok(tuple_term)
             } else {
                 let full_byte_length = match binary_box {
+                    TypedTerm::BinaryLiteral(binary_literal) => binary_literal.full_byte_len(),
                     TypedTerm::HeapBinary(heap_binary) => heap_binary.full_byte_len(),
                     TypedTerm::ProcBin(process_binary) => process_binary.full_byte_len(),
                     _ => unreachable!(),
@@ -0,0 +1,136 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Priority, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_atom;
+use crate::runtime::profile;
+use crate::runtime::scheduler::{self, statistics};
+use crate::runtime::sys::cpu_time;
+use crate::runtime::time::monotonic;
+
+#[native_implemented::function(erlang:statistics/1)]
+pub fn result(process: &Process, item: Term) -> exception::Result<Term> {
+    let item_atom = term_try_into_atom("item", item)?;
+
+    match item_atom.name() {
+        "context_switches" => {
+            let context_switches = statistics::totals().context_switches;
+
+            Ok(process.tuple_from_slice(&[process.integer(context_switches), process.integer(0)]))
+        }
+        "exact_reductions" => Ok(reductions(process, &LAST_EXACT_REDUCTIONS)),
+        "garbage_collection" => {
+            let totals = statistics::totals();
+
+            Ok(process.tuple_from_slice(&[
+                process.integer(totals.garbage_collections),
+                process.integer(totals.words_reclaimed),
+                process.integer(0),
+            ]))
+        }
+        "microstate_accounting" => Ok(microstate_accounting(process)),
+        "reductions" => Ok(reductions(process, &LAST_REDUCTIONS)),
+        "run_queue" | "total_run_queue_lengths" => {
+            let run_queue: usize = run_queue_lengths().iter().sum();
+
+            Ok(process.integer(run_queue))
+        }
+        "run_queue_lengths" => {
+            let run_queue_length_vec: Vec<Term> = run_queue_lengths()
+                .into_iter()
+                .map(|run_queue_length| process.integer(run_queue_length))
+                .collect();
+
+            Ok(process.list_from_slice(&run_queue_length_vec))
+        }
+        "runtime" => Ok(total_and_since_last_call(
+            process,
+            cpu_time::milliseconds(),
+            &LAST_RUNTIME,
+        )),
+        "wall_clock" => Ok(total_and_since_last_call(
+            process,
+            monotonic::time().0,
+            &LAST_WALL_CLOCK,
+        )),
+        _ => Err(anyhow!(
+            "item ({}) is not context_switches, exact_reductions, garbage_collection, microstate_accounting, reductions, run_queue, run_queue_lengths, runtime, total_run_queue_lengths, or wall_clock",
+            item
+        )
+        .into()),
+    }
+}
+
+// Each item has its own last call, like the BEAM
+static LAST_EXACT_REDUCTIONS: AtomicU64 = AtomicU64::new(0);
+static LAST_REDUCTIONS: AtomicU64 = AtomicU64::new(0);
+static LAST_RUNTIME: AtomicU64 = AtomicU64::new(0);
+static LAST_WALL_CLOCK: AtomicU64 = AtomicU64::new(0);
+
+/// Like `msacc`, a map for each scheduler with the time in each state, but only with the
+/// `emulator` and `gc` states and in microseconds.  `undefined` unless profiling is enabled.
+fn microstate_accounting(process: &Process) -> Term {
+    if profile::is_enabled() {
+        let thread_vec: Vec<Term> = scheduler::all()
+            .iter()
+            .map(|arc_scheduler| {
+                let microstates = arc_scheduler.statistics().microstates();
+                let counters = process.map_from_slice(&[
+                    (
+                        atom!("emulator"),
+                        process.integer(microstates.emulator_microseconds),
+                    ),
+                    (
+                        atom!("gc"),
+                        process.integer(microstates.garbage_collection_microseconds),
+                    ),
+                ]);
+                let id: u32 = arc_scheduler.id().into();
+
+                process.map_from_slice(&[
+                    (atom!("counters"), counters),
+                    (atom!("id"), process.integer(id)),
+                    (atom!("type"), atom!("scheduler")),
+                ])
+            })
+            .collect();
+
+        process.list_from_slice(&thread_vec)
+    } else {
+        atom!("undefined")
+    }
+}
+
+fn reductions(process: &Process, last: &AtomicU64) -> Term {
+    total_and_since_last_call(process, statistics::totals().reductions, last)
+}
+
+/// The number of runnable processes in each scheduler's run queues, in scheduler ID order, so
+/// waiting processes aren't counted
+fn run_queue_lengths() -> Vec<usize> {
+    scheduler::all()
+        .iter()
+        .map(|arc_scheduler| {
+            // `Low` and `Normal` share a run queue
+            [Priority::Normal, Priority::High, Priority::Max]
+                .iter()
+                .map(|priority| arc_scheduler.run_queue_len(*priority))
+                .sum()
+        })
+        .collect()
+}
+
+/// `{Total, SinceLastCall}`
+fn total_and_since_last_call(process: &Process, total: u64, last: &AtomicU64) -> Term {
+    let since_last_call = total.saturating_sub(last.swap(total, Ordering::SeqCst));
+
+    process.tuple_from_slice(&[process.integer(total), process.integer(since_last_call)])
+}
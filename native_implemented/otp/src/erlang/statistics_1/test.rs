@@ -0,0 +1,117 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::statistics_1::result;
+use crate::runtime::scheduler;
+use crate::test::with_process;
+
+#[test]
+fn with_context_switches_returns_count_and_zero() {
+    with_process(|process| {
+        let statistic = result(process, atom!("context_switches")).unwrap();
+        let tuple: Boxed<Tuple> = statistic.try_into().unwrap();
+
+        assert_eq!(tuple.len(), 2);
+        assert!(tuple[0].is_integer());
+        assert_eq!(tuple[1], process.integer(0));
+    });
+}
+
+#[test]
+fn with_reductions_or_exact_reductions_returns_total_and_since_last_call() {
+    with_process(|process| {
+        for item in &[atom!("reductions"), atom!("exact_reductions")] {
+            let statistic = result(process, *item).unwrap();
+            let tuple: Boxed<Tuple> = statistic.try_into().unwrap();
+
+            assert_eq!(tuple.len(), 2);
+            assert!(tuple[0].is_integer());
+            assert!(tuple[1].is_integer());
+            assert!(tuple[1] <= tuple[0]);
+        }
+    });
+}
+
+#[test]
+fn with_garbage_collection_returns_count_words_reclaimed_and_zero() {
+    with_process(|process| {
+        let statistic = result(process, atom!("garbage_collection")).unwrap();
+        let tuple: Boxed<Tuple> = statistic.try_into().unwrap();
+
+        assert_eq!(tuple.len(), 3);
+        assert!(tuple[0].is_integer());
+        assert!(tuple[1].is_integer());
+        assert_eq!(tuple[2], process.integer(0));
+    });
+}
+
+#[test]
+fn with_run_queue_lengths_returns_length_for_each_scheduler() {
+    with_process(|process| {
+        let statistic = result(process, atom!("run_queue_lengths")).unwrap();
+        let cons: Boxed<Cons> = statistic.try_into().unwrap();
+        let length_vec: Vec<Term> = cons.into_iter().map(|result| result.unwrap()).collect();
+
+        assert_eq!(length_vec.len(), scheduler::all().len());
+
+        let sum: usize = length_vec
+            .iter()
+            .map(|length| {
+                let length_usize: usize = (*length).try_into().unwrap();
+
+                length_usize
+            })
+            .sum();
+
+        assert_eq!(
+            result(process, atom!("total_run_queue_lengths")),
+            Ok(process.integer(sum))
+        );
+    });
+}
+
+#[test]
+fn with_runtime_or_wall_clock_returns_total_and_since_last_call() {
+    with_process(|process| {
+        for item in &[atom!("runtime"), atom!("wall_clock")] {
+            let first: Boxed<Tuple> = result(process, *item).unwrap().try_into().unwrap();
+            let second: Boxed<Tuple> = result(process, *item).unwrap().try_into().unwrap();
+
+            assert_eq!(first.len(), 2);
+            assert!(first[0] <= second[0]);
+            assert!(second[1] <= second[0]);
+        }
+    });
+}
+
+#[test]
+fn with_microstate_accounting_without_profiling_returns_undefined() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, atom!("microstate_accounting")),
+            Ok(atom!("undefined"))
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_item_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("scheduler_wall_time")),
+            "item (scheduler_wall_time) is not context_switches"
+        );
+    });
+}
+
+#[test]
+fn without_atom_item_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.integer(0)),
+            "item (0) is not an atom"
+        );
+    });
+}
@@ -18,9 +18,8 @@ pub fn base_string_to_integer(
     string: &str,
 ) -> InternalResult<Term> {
     let base_base: Base = base.try_into()?;
-    let bytes = string.as_bytes();
 
-    match BigInt::parse_bytes(bytes, base_base.radix()) {
+    match parse(string, base_base.radix()) {
         Some(big_int) => Ok(process.integer(big_int)),
         None => Err(anyhow!("{} is not in base ({})", context::string(name, term), base).into()),
     }
@@ -32,8 +31,25 @@ pub fn decimal_string_to_integer(
     term: Term,
     string: &str,
 ) -> InternalResult<Term> {
-    match BigInt::parse_bytes(string.as_bytes(), 10) {
+    match parse(string, 10) {
         Some(big_int) => Ok(process.integer(big_int)),
         None => Err(anyhow!("{} is not base 10", context::string(name, term)).into()),
     }
 }
+
+// Private
+
+/// Unlike `BigInt::parse_bytes`, which also accepts `_` between digits and a `+` after a `-`,
+/// only accepts what BEAM does: an optional sign followed by at least one digit in `radix`
+fn parse(string: &str, radix: u32) -> Option<BigInt> {
+    let digits = match string.as_bytes().first() {
+        Some(b'+') | Some(b'-') => &string[1..],
+        _ => string,
+    };
+
+    if !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix)) {
+        BigInt::parse_bytes(string.as_bytes(), radix)
+    } else {
+        None
+    }
+}
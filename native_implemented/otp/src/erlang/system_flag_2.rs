@@ -1,10 +1,16 @@
+use std::convert::TryInto;
+
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{gc_defaults, Process};
 use liblumen_alloc::erts::term::prelude::*;
 
+use crate::runtime::context::*;
+use crate::runtime::process::spawn::options::MaxHeapSize;
+
 #[native_implemented::function(erlang:system_flag/2)]
-pub fn result(flag: Term, _value: Term) -> exception::Result<Term> {
+pub fn result(process: &Process, flag: Term, value: Term) -> exception::Result<Term> {
     let flag_atom = term_try_into_atom!(flag)?;
 
     match flag_atom.name() {
@@ -12,11 +18,30 @@ pub fn result(flag: Term, _value: Term) -> exception::Result<Term> {
         "cpu_topology" => unimplemented!(),
         "dirty_cpu_schedulers_online" => unimplemented!(),
         "erts_alloc" => unimplemented!(),
-        "fullsweep_after" => unimplemented!(),
+        "fullsweep_after" => {
+            let fullsweep_after: usize = value
+                .try_into()
+                .with_context(|| term_is_not_non_negative_integer("fullsweep_after", value))?;
+
+            Ok(process.integer(gc_defaults::set_fullsweep_after(fullsweep_after)))
+        }
         "microstate_accounting" => unimplemented!(),
-        "min_heap_size" => unimplemented!(),
+        "min_heap_size" => {
+            let min_heap_size: usize = value
+                .try_into()
+                .with_context(|| term_is_not_non_negative_integer("min_heap_size", value))?;
+
+            Ok(process.integer(gc_defaults::set_min_heap_size(min_heap_size)))
+        }
         "min_bin_vheap_size" => unimplemented!(),
-        "max_heap_size" => unimplemented!(),
+        "max_heap_size" => {
+            let max_heap_size: MaxHeapSize = value.try_into()?;
+            let previous = gc_defaults::set_max_heap_size(
+                max_heap_size.cascaded(gc_defaults::max_heap_size()),
+            );
+
+            Ok(super::max_heap_size_to_term(process, previous))
+        }
         "multi_scheduling" => unimplemented!(),
         "scheduler_bind_type" => unimplemented!(),
         "schedulers_online" => unimplemented!(),
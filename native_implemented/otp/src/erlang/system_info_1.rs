@@ -1,89 +1,113 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::mem;
+
 use anyhow::*;
 
 use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{gc_defaults, Process};
+use liblumen_alloc::erts::term::atom;
 use liblumen_alloc::erts::term::prelude::*;
 
+use crate::runtime::registry;
+use crate::runtime::scheduler;
+use crate::runtime::sys::cpus;
+
+/// The release of the OTP fork that the Erlang standard library is compiled from
+const OTP_RELEASE: &str = "23";
+
 #[native_implemented::function(erlang:system_info/1)]
-pub fn result(item: Term) -> exception::Result<Term> {
+pub fn result(process: &Process, item: Term) -> exception::Result<Term> {
     match item.decode().unwrap() {
         TypedTerm::Atom(atom) => match atom.name() {
-            "alloc_util_allocators" => unimplemented!(),
-            "allocated_areas" => unimplemented!(),
-            "allocator" => unimplemented!(),
-            "atom_count" => unimplemented!(),
-            "atom_limit" => unimplemented!(),
-            "build_type" => unimplemented!(),
-            "c_compiler_used" => unimplemented!(),
-            "check_io" => unimplemented!(),
-            "compat_rel" => unimplemented!(),
-            "cpu_quota" => unimplemented!(),
-            "cpu_topology" => unimplemented!(),
-            "creation" => unimplemented!(),
-            "debug_compiled" => unimplemented!(),
-            "delayed_node_table_gc" => unimplemented!(),
-            "dirty_cpu_schedulers" => unimplemented!(),
-            "dirty_cpu_schedulers_online" => unimplemented!(),
-            "dirty_io_schedulers" => unimplemented!(),
-            "dist" => unimplemented!(),
-            "dist_buf_busy_limit" => unimplemented!(),
-            "dist_ctrl" => unimplemented!(),
-            "driver_version" => unimplemented!(),
-            "dynamic_trace" => unimplemented!(),
-            "dynamic_trace_probes" => unimplemented!(),
-            "elib_malloc" => unimplemented!(),
-            "end_time" => unimplemented!(),
-            "ets_count" => unimplemented!(),
-            "ets_limit" => unimplemented!(),
-            "fullsweep_after" => unimplemented!(),
-            "garbage_collection" => unimplemented!(),
-            "heap_sizes" => unimplemented!(),
-            "heap_type" => unimplemented!(),
-            "info" => unimplemented!(),
-            "kernel_poll" => unimplemented!(),
-            "loaded" => unimplemented!(),
-            "logic_processors" => unimplemented!(),
-            "logic_processors_available" => unimplemented!(),
-            "logical_processors_online" => unimplemented!(),
-            "machine" => unimplemented!(),
-            "max_heap_size" => unimplemented!(),
-            "message_queue_data" => unimplemented!(),
-            "min_bin_vheap_size" => unimplemented!(),
-            "min_heap_size" => unimplemented!(),
-            "modified_timing_level" => unimplemented!(),
-            "multi_scheduling" => unimplemented!(),
-            "multi_scheduling_blockers" => unimplemented!(),
-            "nif_version" => unimplemented!(),
-            "normal_multi_scheduling_blockers" => unimplemented!(),
-            "os_monotonic_time_source" => unimplemented!(),
-            "os_system_time_source" => unimplemented!(),
-            "otp_release" => unimplemented!(),
-            "port_count" => unimplemented!(),
-            "port_limit" => unimplemented!(),
-            "port_parallelism" => unimplemented!(),
-            "process_count" => unimplemented!(),
-            "process_limit" => unimplemented!(),
-            "procs" => unimplemented!(),
-            "scheduler_bind_type" => unimplemented!(),
-            "scheduler_bindings" => unimplemented!(),
-            "scheduler_id" => unimplemented!(),
-            "schedulers" => unimplemented!(),
-            "schedulers_online" => unimplemented!(),
-            "sequential_tracer" => unimplemented!(),
-            "smp_support" => unimplemented!(),
-            "start_time" => unimplemented!(),
-            "system_architecture" => unimplemented!(),
-            "system_logger" => unimplemented!(),
-            "system_version" => unimplemented!(),
-            "thread_pool_size" => unimplemented!(),
-            "threads" => unimplemented!(),
-            "time_correction" => unimplemented!(),
-            "time_offset" => unimplemented!(),
-            "time_warp_mode" => unimplemented!(),
-            "tolerant_timeofday" => unimplemented!(),
-            "trace_control_word" => unimplemented!(),
-            "update_cpu_info" => unimplemented!(),
-            "version" => unimplemented!(),
-            "wordsize" => unimplemented!(),
+            "alloc_util_allocators" => item_is_not_implemented(item),
+            "allocated_areas" => item_is_not_implemented(item),
+            "allocator" => item_is_not_implemented(item),
+            "atom_count" => Ok(process.integer(atom::count())),
+            "atom_limit" => Ok(process.integer(atom::limit())),
+            "build_type" => item_is_not_implemented(item),
+            "c_compiler_used" => item_is_not_implemented(item),
+            "check_io" => item_is_not_implemented(item),
+            "compat_rel" => item_is_not_implemented(item),
+            "cpu_quota" => item_is_not_implemented(item),
+            "cpu_topology" => item_is_not_implemented(item),
+            "creation" => item_is_not_implemented(item),
+            "debug_compiled" => item_is_not_implemented(item),
+            "delayed_node_table_gc" => item_is_not_implemented(item),
+            "dirty_cpu_schedulers" => item_is_not_implemented(item),
+            "dirty_cpu_schedulers_online" => item_is_not_implemented(item),
+            "dirty_io_schedulers" => item_is_not_implemented(item),
+            "dist" => item_is_not_implemented(item),
+            "dist_buf_busy_limit" => item_is_not_implemented(item),
+            "dist_ctrl" => item_is_not_implemented(item),
+            "driver_version" => item_is_not_implemented(item),
+            "dynamic_trace" => item_is_not_implemented(item),
+            "dynamic_trace_probes" => item_is_not_implemented(item),
+            "elib_malloc" => item_is_not_implemented(item),
+            "end_time" => item_is_not_implemented(item),
+            "ets_count" => item_is_not_implemented(item),
+            "ets_limit" => item_is_not_implemented(item),
+            "fullsweep_after" => Ok(process.tuple_from_slice(&[
+                Atom::str_to_term("fullsweep_after"),
+                process.integer(gc_defaults::fullsweep_after()),
+            ])),
+            "garbage_collection" => item_is_not_implemented(item),
+            "heap_sizes" => item_is_not_implemented(item),
+            "heap_type" => item_is_not_implemented(item),
+            "info" => item_is_not_implemented(item),
+            "kernel_poll" => item_is_not_implemented(item),
+            "loaded" => item_is_not_implemented(item),
+            "logical_processors" => Ok(process.integer(cpus::num_logical())),
+            "logical_processors_available" => item_is_not_implemented(item),
+            "logical_processors_online" => item_is_not_implemented(item),
+            "machine" => item_is_not_implemented(item),
+            "max_heap_size" => Ok(process.tuple_from_slice(&[
+                Atom::str_to_term("max_heap_size"),
+                super::max_heap_size_to_term(process, gc_defaults::max_heap_size()),
+            ])),
+            "message_queue_data" => item_is_not_implemented(item),
+            "min_bin_vheap_size" => item_is_not_implemented(item),
+            "min_heap_size" => Ok(process.tuple_from_slice(&[
+                Atom::str_to_term("min_heap_size"),
+                process.integer(gc_defaults::min_heap_size()),
+            ])),
+            "modified_timing_level" => item_is_not_implemented(item),
+            "multi_scheduling" => item_is_not_implemented(item),
+            "multi_scheduling_blockers" => item_is_not_implemented(item),
+            "nif_version" => item_is_not_implemented(item),
+            "normal_multi_scheduling_blockers" => item_is_not_implemented(item),
+            "os_monotonic_time_source" => item_is_not_implemented(item),
+            "os_system_time_source" => item_is_not_implemented(item),
+            "otp_release" => Ok(process.charlist_from_str(OTP_RELEASE)),
+            "port_count" => item_is_not_implemented(item),
+            "port_limit" => item_is_not_implemented(item),
+            "port_parallelism" => item_is_not_implemented(item),
+            "process_count" => Ok(process.integer(registry::process_count())),
+            // Pids are never reused, so the limit is the number of distinct pids
+            "process_limit" => Ok(process.integer((Pid::NUMBER_MAX + 1) * (Pid::SERIAL_MAX + 1))),
+            "procs" => item_is_not_implemented(item),
+            "scheduler_bind_type" => item_is_not_implemented(item),
+            "scheduler_bindings" => item_is_not_implemented(item),
+            "scheduler_id" => item_is_not_implemented(item),
+            // All schedulers are always online
+            "schedulers" | "schedulers_online" => Ok(process.integer(scheduler::count())),
+            "sequential_tracer" => item_is_not_implemented(item),
+            "smp_support" => item_is_not_implemented(item),
+            "start_time" => item_is_not_implemented(item),
+            "system_architecture" => Ok(process.charlist_from_str(env!("TARGET"))),
+            "system_logger" => item_is_not_implemented(item),
+            "system_version" => item_is_not_implemented(item),
+            "thread_pool_size" => item_is_not_implemented(item),
+            "threads" => item_is_not_implemented(item),
+            "time_correction" => item_is_not_implemented(item),
+            "time_offset" => item_is_not_implemented(item),
+            "time_warp_mode" => item_is_not_implemented(item),
+            "tolerant_timeofday" => item_is_not_implemented(item),
+            "trace_control_word" => item_is_not_implemented(item),
+            "update_cpu_info" => item_is_not_implemented(item),
+            "version" => Ok(process.charlist_from_str(env!("CARGO_PKG_VERSION"))),
+            "wordsize" => Ok(process.integer(mem::size_of::<Term>())),
             _ => Err(anyhow!(
                 "item ({}) is not a supported atom ({})",
                 item,
@@ -97,10 +121,10 @@ pub fn result(item: Term) -> exception::Result<Term> {
 
                 match tag.decode().unwrap() {
                     TypedTerm::Atom(tag_atom) => match tag_atom.name() {
-                        "allocator" => unimplemented!(),
-                        "allocator_sizes" => unimplemented!(),
-                        "cpu_topology" => unimplemented!(),
-                        "wordsize" => unimplemented!(),
+                        "allocator" => item_is_not_implemented(item),
+                        "allocator_sizes" => item_is_not_implemented(item),
+                        "cpu_topology" => item_is_not_implemented(item),
+                        "wordsize" => wordsize(process, item, boxed_tuple[1]),
                         _ => item_is_not_supported_tuple(item),
                     },
                     _ => item_is_not_supported_tuple(item),
@@ -120,8 +144,8 @@ pub fn result(item: Term) -> exception::Result<Term> {
 }
 
 const SUPPORTED_ATOMS: &'static str = "`allocated_areas`, `allocator`, \
-                 `alloc_util_allocators`, `elib_malloc`, `cpu_topology`, `logic_processors`, \
-                 `logic_processors_available`, `logical_processors_online`, \
+                 `alloc_util_allocators`, `elib_malloc`, `cpu_topology`, `logical_processors`, \
+                 `logical_processors_available`, `logical_processors_online`, \
                  `cpu_quota`, `update_cpu_info`, `fullsweep_after`, `garbage_collection`, \
                  `heap_sizes`, `heap_type`, `max_heap_size`, `message_queue_data`, `min_heap_size` \
                  `min_bin_vheap_size`, `procs`, `atom_count`, `atom_limit`, `ets_count`, \
@@ -144,6 +168,12 @@ const SUPPORTED_ATOMS: &'static str = "`allocated_areas`, `allocator`, \
 const SUPPORTED_TUPLES: &'static str = "`{allocator, Alloc}`, `{allocator_sizes, Alloc}`, \
           `{cpu_topology, defined | detected | used}`, or `{wordsize, internal | external}`";
 
+/// Items that the BEAM supports, but Lumen doesn't yet, error `badarg` like unknown items, so that
+/// callers probing for them can fall back.
+fn item_is_not_implemented(item: Term) -> exception::Result<Term> {
+    Err(anyhow!("item ({}) is not implemented yet", item).into())
+}
+
 fn item_is_not_supported_tuple(item: Term) -> exception::Result<Term> {
     Err(anyhow!(
         "item ({}) is not a supported tuple ({})",
@@ -152,3 +182,14 @@ fn item_is_not_supported_tuple(item: Term) -> exception::Result<Term> {
     )
     .into())
 }
+
+fn wordsize(process: &Process, item: Term, r#type: Term) -> exception::Result<Term> {
+    match r#type.decode().unwrap() {
+        TypedTerm::Atom(type_atom) => match type_atom.name() {
+            "internal" => Ok(process.integer(mem::size_of::<Term>())),
+            "external" => Ok(process.integer(mem::size_of::<usize>())),
+            _ => item_is_not_supported_tuple(item),
+        },
+        _ => item_is_not_supported_tuple(item),
+    }
+}
@@ -0,0 +1,101 @@
+use std::mem;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::system_info_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_unknown_atom_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("unknown")),
+            "item (unknown) is not a supported atom"
+        );
+    });
+}
+
+#[test]
+fn with_unknown_tuple_errors_badarg() {
+    with_process(|process| {
+        let item = process.tuple_from_slice(&[atom!("wordsize"), atom!("unknown")]);
+
+        assert_badarg!(result(process, item), "is not a supported tuple");
+    });
+}
+
+#[test]
+fn with_unimplemented_atom_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("loaded")),
+            "item (loaded) is not implemented yet"
+        );
+    });
+}
+
+#[test]
+fn with_unimplemented_tuple_errors_badarg() {
+    with_process(|process| {
+        let item = process.tuple_from_slice(&[atom!("allocator"), atom!("sys_alloc")]);
+
+        assert_badarg!(result(process, item), "is not implemented yet");
+    });
+}
+
+#[test]
+fn with_atom_count_returns_number_of_atoms() {
+    with_process(|process| {
+        let before = result(process, atom!("atom_count")).unwrap();
+        let _ = atom!("system_info_1_with_atom_count_returns_number_of_atoms");
+        let after = result(process, atom!("atom_count")).unwrap();
+
+        assert!(before < after);
+    });
+}
+
+#[test]
+fn with_otp_release_returns_charlist() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, atom!("otp_release")),
+            Ok(process.charlist_from_str("23"))
+        );
+    });
+}
+
+#[test]
+fn with_process_count_includes_calling_process() {
+    with_process(|process| {
+        let process_count = result(process, atom!("process_count")).unwrap();
+
+        assert!(process.integer(1) <= process_count);
+    });
+}
+
+#[test]
+fn with_system_architecture_returns_target_triple() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, atom!("system_architecture")),
+            Ok(process.charlist_from_str(env!("TARGET")))
+        );
+    });
+}
+
+#[test]
+fn with_wordsize_returns_size_of_term_in_bytes() {
+    with_process(|process| {
+        let size_of_term = process.integer(mem::size_of::<Term>());
+
+        assert_eq!(result(process, atom!("wordsize")), Ok(size_of_term));
+        assert_eq!(
+            result(
+                process,
+                process.tuple_from_slice(&[atom!("wordsize"), atom!("internal")])
+            ),
+            Ok(size_of_term)
+        );
+    });
+}
@@ -9,7 +9,7 @@ use compression::*;
 use minor_version::*;
 
 pub struct Options {
-    compression: Compression,
+    pub compression: Compression,
     minor_version: MinorVersion,
 }
 
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::term_to_binary::options::Options;
+use crate::erlang::term_to_binary::term_to_binary;
+
+const SUPPORTED_OPTIONS_CONTEXT: &str =
+    "supported options are compressed, {compressed, 0..9}, and {minor_version, 0..2}";
+
+#[native_implemented::function(erlang:term_to_binary/2)]
+pub fn result(process: &Process, term: Term, options: Term) -> exception::Result<Term> {
+    let options: Options = options
+        .try_into()
+        .map_err(|_| anyhow!("options ({}) are invalid", options))
+        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+    term_to_binary(process, term, options)
+}
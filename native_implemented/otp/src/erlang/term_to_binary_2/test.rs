@@ -0,0 +1,95 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::binary_to_term_1;
+use crate::erlang::term_to_binary_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_of_options_errors_badarg() {
+    with_process(|process| {
+        let options = Atom::str_to_term("compressed");
+
+        assert_badarg!(
+            result(process, Term::NIL, options),
+            format!("options ({}) are invalid", options)
+        );
+    });
+}
+
+#[test]
+fn with_compression_level_out_of_range_errors_badarg() {
+    with_process(|process| {
+        let option =
+            process.tuple_from_slice(&[Atom::str_to_term("compressed"), process.integer(10)]);
+        let options = process.list_from_slice(&[option]);
+
+        assert_badarg!(
+            result(process, Term::NIL, options),
+            SUPPORTED_OPTIONS_CONTEXT
+        );
+    });
+}
+
+#[test]
+fn with_compressed_and_compressible_term_returns_compressed_ext() {
+    with_process(|process| {
+        let term = compressible_term(process);
+        let options = process.list_from_slice(&[Atom::str_to_term("compressed")]);
+
+        let binary = result(process, term, options).unwrap();
+        let bytes = binary_to_byte_vec(binary);
+
+        assert_eq!(bytes[0], VERSION_NUMBER);
+        assert_eq!(bytes[1], COMPRESSED);
+        assert_eq!(binary_to_term_1::result(process, binary), Ok(term));
+    });
+}
+
+#[test]
+fn with_compressed_and_incompressible_term_returns_uncompressed() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[Atom::str_to_term("compressed")]);
+
+        assert_eq!(
+            result(process, Term::NIL, options),
+            Ok(process.binary_from_bytes(&[VERSION_NUMBER, NIL_EXT]))
+        );
+    });
+}
+
+#[test]
+fn with_compressed_0_returns_uncompressed() {
+    with_process(|process| {
+        let term = compressible_term(process);
+        let option =
+            process.tuple_from_slice(&[Atom::str_to_term("compressed"), process.integer(0)]);
+        let options = process.list_from_slice(&[option]);
+
+        let binary = result(process, term, options).unwrap();
+        let bytes = binary_to_byte_vec(binary);
+
+        assert_ne!(bytes[1], COMPRESSED);
+        assert_eq!(binary_to_term_1::result(process, binary), Ok(term));
+    });
+}
+
+const VERSION_NUMBER: u8 = 131;
+
+const COMPRESSED: u8 = 80;
+const NIL_EXT: u8 = 106;
+
+const SUPPORTED_OPTIONS_CONTEXT: &str =
+    "supported options are compressed, {compressed, 0..9}, and {minor_version, 0..2}";
+
+fn binary_to_byte_vec(binary: Term) -> Vec<u8> {
+    match binary.decode().unwrap() {
+        TypedTerm::HeapBinary(heap_binary) => heap_binary.as_bytes().to_vec(),
+        TypedTerm::ProcBin(process_binary) => process_binary.as_bytes().to_vec(),
+        typed_term => panic!("{:?} is not a binary", typed_term),
+    }
+}
+
+fn compressible_term(process: &Process) -> Term {
+    process.binary_from_bytes(&[0; 64])
+}
@@ -7,14 +7,16 @@ use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
-use crate::runtime::time::{monotonic, system, Unit};
+use crate::runtime::time::{self, monotonic, system, Unit};
 
 #[native_implemented::function(erlang:time_offset/1)]
 pub fn result(process: &Process, unit: Term) -> exception::Result<Term> {
     let unit_unit: Unit = unit.try_into()?;
-    let system_time = system::time_in_unit(unit_unit);
-    let monotonic_time = monotonic::time_in_unit(unit_unit);
-    let term = process.integer(system_time - monotonic_time);
+    // Convert the offset, rather than subtracting times that were each rounded down, so that
+    // `time_offset(unit)` is `convert_time_unit(time_offset(), native, unit)`
+    let system_time = system::time_in_unit(Unit::Native);
+    let monotonic_time = monotonic::time_in_unit(Unit::Native);
+    let time_offset = time::convert(system_time - monotonic_time, Unit::Native, unit_unit);
 
-    Ok(term)
+    Ok(process.integer(time_offset))
 }
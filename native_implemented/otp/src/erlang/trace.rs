@@ -0,0 +1,125 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::{term_try_into_atom, term_try_into_local_pid};
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::trace::{Flag, Flags, Tracees};
+
+const SUPPORTED_FLAGS: &str = "send, 'receive', call, procs, exiting, or {tracer, Tracer}";
+
+/// The flags of `flag_list` and the tracer of its `{tracer, Tracer}`, if any
+pub(in crate::erlang) fn term_try_into_flags_and_tracer(
+    flag_list: Term,
+) -> exception::Result<(Flags, Option<Pid>)> {
+    let mut flags = Flags::default();
+    let mut option_tracer = None;
+    let mut flag_list_term = flag_list;
+
+    loop {
+        match flag_list_term.decode()? {
+            TypedTerm::Nil => return Ok((flags, option_tracer)),
+            TypedTerm::List(cons) => {
+                let flag = cons.head;
+
+                match flag.decode()? {
+                    TypedTerm::Atom(atom) => match atom.name().parse::<Flag>() {
+                        Ok(flag) => flags.insert(flag.into()),
+                        Err(_) => return flag_is_not_supported(flag),
+                    },
+                    TypedTerm::Tuple(tuple) if tuple.len() == 2 && tuple[0] == atom!("tracer") => {
+                        option_tracer = Some(term_try_into_local_pid("tracer", tuple[1])?);
+                    }
+                    _ => return flag_is_not_supported(flag),
+                }
+
+                flag_list_term = cons.tail;
+            }
+            _ => {
+                return Err(ImproperListError)
+                    .context(format!("flag_list ({}) is not a proper list", flag_list))
+                    .map_err(From::from)
+            }
+        }
+    }
+}
+
+/// A pid of an alive local process, `existing`, `new`, or `all`
+pub(in crate::erlang) fn term_try_into_tracees(
+    process: &Process,
+    pid_spec: Term,
+) -> exception::Result<Tracees> {
+    match pid_spec.decode()? {
+        TypedTerm::Pid(pid) => {
+            if pid == process.pid() || pid_to_process(&pid).is_some() {
+                Ok(Tracees::Pid(pid))
+            } else {
+                Err(anyhow!("pid_spec ({}) is not an alive local process", pid_spec).into())
+            }
+        }
+        TypedTerm::Atom(atom) => match atom.name() {
+            "all" => Ok(Tracees::All),
+            "existing" => Ok(Tracees::Existing),
+            "new" => Ok(Tracees::New),
+            _ => pid_spec_is_not_supported(pid_spec),
+        },
+        _ => pid_spec_is_not_supported(pid_spec),
+    }
+}
+
+/// `{Module, Function, Arity}` where `Function` and `Arity` may be `'_'`
+pub(in crate::erlang) fn term_try_into_module_function_arity(
+    mfa: Term,
+) -> exception::Result<(Atom, Option<Atom>, Option<u8>)> {
+    let tuple: Boxed<Tuple> = mfa
+        .try_into()
+        .with_context(|| format!("mfa ({}) is not a {{Module, Function, Arity}} tuple", mfa))?;
+
+    if tuple.len() != 3 {
+        return Err(anyhow!("mfa ({}) is not a {{Module, Function, Arity}} tuple", mfa).into());
+    }
+
+    let module = term_try_into_atom("module", tuple[0])?;
+
+    let option_function = if tuple[1] == atom!("_") {
+        None
+    } else {
+        Some(term_try_into_atom("function", tuple[1])?)
+    };
+
+    let option_arity = if tuple[2] == atom!("_") {
+        None
+    } else {
+        let arity: u8 = tuple[2]
+            .try_into()
+            .with_context(|| format!("arity ({}) is not an arity or '_'", tuple[2]))?;
+
+        Some(arity)
+    };
+
+    Ok((module, option_function, option_arity))
+}
+
+// Private
+
+fn flag_is_not_supported<T>(flag: Term) -> exception::Result<T> {
+    Err(anyhow!(
+        "flag ({}) is not a supported flag ({})",
+        flag,
+        SUPPORTED_FLAGS
+    )
+    .into())
+}
+
+fn pid_spec_is_not_supported<T>(pid_spec: Term) -> exception::Result<T> {
+    Err(anyhow!(
+        "pid_spec ({}) is not a pid, existing, new, or all",
+        pid_spec
+    )
+    .into())
+}
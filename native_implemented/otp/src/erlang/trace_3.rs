@@ -0,0 +1,42 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::trace::{term_try_into_flags_and_tracer, term_try_into_tracees};
+use crate::runtime::context::term_try_into_bool;
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::trace;
+
+/// Only `send`, `'receive'`, `call`, `procs` and `exiting` are supported as flags, and the tracer
+/// must be a local process.  As exiting processes are not scheduled again, `exiting` only sends
+/// `{trace, Pid, out_exited, 0}`.
+#[native_implemented::function(erlang:trace/3)]
+pub fn result(
+    process: &Process,
+    pid_spec: Term,
+    how: Term,
+    flag_list: Term,
+) -> exception::Result<Term> {
+    let tracees = term_try_into_tracees(process, pid_spec)?;
+    let how_bool = term_try_into_bool("how", how)?;
+    let (flags, option_tracer) = term_try_into_flags_and_tracer(flag_list)?;
+    let tracer = option_tracer.unwrap_or_else(|| process.pid());
+
+    if how_bool && tracer != process.pid() && pid_to_process(&tracer).is_none() {
+        return Err(anyhow!("tracer ({}) is not an alive local process", tracer).into());
+    }
+
+    match trace::set(tracees, how_bool, flags, tracer) {
+        Ok(count) => Ok(process.integer(count)),
+        Err(()) => Err(anyhow!(
+            "pid_spec ({}) is already traced by another tracer",
+            pid_spec
+        )
+        .into()),
+    }
+}
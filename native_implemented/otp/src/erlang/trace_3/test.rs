@@ -0,0 +1,206 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::send_2;
+use crate::erlang::trace_3::result;
+use crate::test::{has_message, process, with_process};
+
+#[test]
+fn without_pid_existing_new_or_all_pid_spec_errors_badarg() {
+    with_process(|process| {
+        let pid_spec = atom!("everyone");
+
+        assert_badarg!(
+            result(process, pid_spec, true.into(), Term::NIL),
+            format!(
+                "pid_spec ({}) is not a pid, existing, new, or all",
+                pid_spec
+            )
+        );
+    });
+}
+
+#[test]
+fn without_boolean_how_errors_badarg() {
+    with_process(|process| {
+        let how = atom!("on");
+
+        assert_badarg!(
+            result(process, process.pid_term(), how, Term::NIL),
+            format!("how ({}) is not a boolean", how)
+        );
+    });
+}
+
+#[test]
+fn without_supported_flag_errors_badarg() {
+    with_process(|process| {
+        let flag = atom!("running");
+        let flag_list = process.list_from_slice(&[flag]);
+
+        assert_badarg!(
+            result(process, process.pid_term(), true.into(), flag_list),
+            format!("flag ({}) is not a supported flag", flag)
+        );
+    });
+}
+
+#[test]
+fn with_send_flag_sends_send_trace_message_to_tracer() {
+    with_process(|process| {
+        let tracee_arc_process = process::child(process);
+        let destination_arc_process = process::child(process);
+        let flag_list = process.list_from_slice(&[atom!("send")]);
+
+        assert_eq!(
+            result(
+                process,
+                tracee_arc_process.pid_term(),
+                true.into(),
+                flag_list
+            ),
+            Ok(process.integer(1))
+        );
+
+        let message = atom!("message");
+
+        assert_eq!(
+            send_2::result(
+                &tracee_arc_process,
+                destination_arc_process.pid_term(),
+                message
+            ),
+            Ok(message)
+        );
+
+        assert_has_message!(
+            process,
+            process.tuple_from_slice(&[
+                atom!("trace"),
+                tracee_arc_process.pid_term(),
+                atom!("send"),
+                message,
+                destination_arc_process.pid_term()
+            ])
+        );
+    });
+}
+
+#[test]
+fn with_receive_flag_sends_receive_trace_message_to_tracer() {
+    with_process(|process| {
+        let sender_arc_process = process::child(process);
+        let tracee_arc_process = process::child(process);
+        let flag_list = process.list_from_slice(&[atom!("receive")]);
+
+        assert_eq!(
+            result(
+                process,
+                tracee_arc_process.pid_term(),
+                true.into(),
+                flag_list
+            ),
+            Ok(process.integer(1))
+        );
+
+        let message = atom!("message");
+
+        assert_eq!(
+            send_2::result(&sender_arc_process, tracee_arc_process.pid_term(), message),
+            Ok(message)
+        );
+
+        assert_has_message!(
+            process,
+            process.tuple_from_slice(&[
+                atom!("trace"),
+                tracee_arc_process.pid_term(),
+                atom!("receive"),
+                message
+            ])
+        );
+    });
+}
+
+#[test]
+fn with_false_how_stops_sending_trace_messages() {
+    with_process(|process| {
+        let tracee_arc_process = process::child(process);
+        let destination_arc_process = process::child(process);
+        let flag_list = process.list_from_slice(&[atom!("send")]);
+
+        assert_eq!(
+            result(
+                process,
+                tracee_arc_process.pid_term(),
+                true.into(),
+                flag_list
+            ),
+            Ok(process.integer(1))
+        );
+        assert_eq!(
+            result(
+                process,
+                tracee_arc_process.pid_term(),
+                false.into(),
+                flag_list
+            ),
+            Ok(process.integer(1))
+        );
+
+        let message = atom!("message");
+
+        assert_eq!(
+            send_2::result(
+                &tracee_arc_process,
+                destination_arc_process.pid_term(),
+                message
+            ),
+            Ok(message)
+        );
+
+        assert!(!has_message(
+            process,
+            process.tuple_from_slice(&[
+                atom!("trace"),
+                tracee_arc_process.pid_term(),
+                atom!("send"),
+                message,
+                destination_arc_process.pid_term()
+            ])
+        ));
+    });
+}
+
+#[test]
+fn with_tracer_traced_by_another_tracer_errors_badarg() {
+    with_process(|process| {
+        let other_tracer_arc_process = process::child(process);
+        let tracee_arc_process = process::child(process);
+        let other_tracer_flag_list = process.list_from_slice(&[
+            atom!("send"),
+            process.tuple_from_slice(&[atom!("tracer"), other_tracer_arc_process.pid_term()]),
+        ]);
+
+        assert_eq!(
+            result(
+                process,
+                tracee_arc_process.pid_term(),
+                true.into(),
+                other_tracer_flag_list
+            ),
+            Ok(process.integer(1))
+        );
+
+        let pid_spec = tracee_arc_process.pid_term();
+        let flag_list = process.list_from_slice(&[atom!("send")]);
+
+        assert_badarg!(
+            result(process, pid_spec, true.into(), flag_list),
+            format!(
+                "pid_spec ({}) is already traced by another tracer",
+                pid_spec
+            )
+        );
+    });
+}
@@ -0,0 +1,124 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::ModuleFunctionArity;
+
+use crate::erlang::trace::term_try_into_module_function_arity;
+use crate::runtime::context::term_try_into_atom;
+use crate::runtime::registry::pid_to_process;
+use crate::runtime::trace::{self, Tracee};
+
+/// `PidOrFunc` is a local pid or `new` with `Item` `flags`, `tracer` or `all`, or a fully
+/// specified `{Module, Function, Arity}` with `Item` `traced`.
+#[native_implemented::function(erlang:trace_info/2)]
+pub fn result(process: &Process, pid_or_func: Term, item: Term) -> exception::Result<Term> {
+    let item_atom = term_try_into_atom("item", item)?;
+
+    match pid_or_func.decode()? {
+        TypedTerm::Pid(pid) => {
+            if pid == process.pid() || pid_to_process(&pid).is_some() {
+                tracee_info(process, trace::tracee(&pid), item_atom)
+            } else {
+                Ok(atom!("undefined"))
+            }
+        }
+        TypedTerm::Atom(atom) if atom == "new" => {
+            tracee_info(process, trace::new_tracee(), item_atom)
+        }
+        TypedTerm::Tuple(_) => {
+            let (module, option_function, option_arity) =
+                term_try_into_module_function_arity(pid_or_func)?;
+
+            match (option_function, option_arity, item_atom.name()) {
+                (Some(function), Some(arity), "traced") => {
+                    let module_function_arity = ModuleFunctionArity {
+                        module,
+                        function,
+                        arity,
+                    };
+                    let traced = if trace::is_call_traced(&module_function_arity) {
+                        atom!("global")
+                    } else {
+                        false.into()
+                    };
+
+                    Ok(process.tuple_from_slice(&[item, traced]))
+                }
+                (Some(_), Some(_), _) => item_is_not_supported(item),
+                _ => Err(anyhow!(
+                    "pid_or_func ({}) does not have a function and arity",
+                    pid_or_func
+                )
+                .into()),
+            }
+        }
+        _ => Err(TypeError)
+            .context(format!(
+                "pid_or_func ({}) is not a pid, new, or {{Module, Function, Arity}}",
+                pid_or_func
+            ))
+            .map_err(From::from),
+    }
+}
+
+fn flags_term(process: &Process, option_tracee: Option<Tracee>) -> Term {
+    let flag_vec: Vec<Term> = option_tracee
+        .map(|tracee| {
+            tracee
+                .flags
+                .iter()
+                .map(|flag| Atom::str_to_term(flag.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    process.list_from_slice(&flag_vec)
+}
+
+fn item_is_not_supported<T>(item: Term) -> exception::Result<T> {
+    Err(anyhow!(
+        "item ({}) is not flags, tracer, or all for a pid or new, or traced for a function",
+        item
+    )
+    .into())
+}
+
+fn tracee_info(
+    process: &Process,
+    option_tracee: Option<Tracee>,
+    item: Atom,
+) -> exception::Result<Term> {
+    let item_term = item.encode()?;
+
+    let value = match item.name() {
+        "all" => match option_tracee {
+            Some(_) => {
+                let flags =
+                    process.tuple_from_slice(&[atom!("flags"), flags_term(process, option_tracee)]);
+                let tracer =
+                    process.tuple_from_slice(&[atom!("tracer"), tracer_term(option_tracee)]);
+
+                process.list_from_slice(&[flags, tracer])
+            }
+            None => false.into(),
+        },
+        "flags" => flags_term(process, option_tracee),
+        "tracer" => tracer_term(option_tracee),
+        _ => return item_is_not_supported(item_term),
+    };
+
+    Ok(process.tuple_from_slice(&[item_term, value]))
+}
+
+fn tracer_term(option_tracee: Option<Tracee>) -> Term {
+    match option_tracee {
+        Some(tracee) => tracee.tracer.encode().unwrap(),
+        None => Term::NIL,
+    }
+}
@@ -0,0 +1,79 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::{trace_3, trace_info_2::result};
+use crate::test::{process, with_process};
+
+#[test]
+fn without_supported_item_errors_badarg() {
+    with_process(|process| {
+        let item = atom!("match_spec");
+
+        assert_badarg!(
+            result(process, process.pid_term(), item),
+            format!("item ({}) is not flags, tracer, or all", item)
+        );
+    });
+}
+
+#[test]
+fn with_untraced_pid_returns_no_flags_and_no_tracer() {
+    with_process(|process| {
+        let tracee_arc_process = process::child(process);
+
+        assert_eq!(
+            result(process, tracee_arc_process.pid_term(), atom!("flags")),
+            Ok(process.tuple_from_slice(&[atom!("flags"), Term::NIL]))
+        );
+        assert_eq!(
+            result(process, tracee_arc_process.pid_term(), atom!("tracer")),
+            Ok(process.tuple_from_slice(&[atom!("tracer"), Term::NIL]))
+        );
+        assert_eq!(
+            result(process, tracee_arc_process.pid_term(), atom!("all")),
+            Ok(process.tuple_from_slice(&[atom!("all"), false.into()]))
+        );
+    });
+}
+
+#[test]
+fn with_traced_pid_returns_flags_and_tracer() {
+    with_process(|process| {
+        let tracee_arc_process = process::child(process);
+        let flag_list = process.list_from_slice(&[atom!("receive"), atom!("send")]);
+
+        assert_eq!(
+            trace_3::result(
+                process,
+                tracee_arc_process.pid_term(),
+                true.into(),
+                flag_list
+            ),
+            Ok(process.integer(1))
+        );
+
+        assert_eq!(
+            result(process, tracee_arc_process.pid_term(), atom!("flags")),
+            Ok(process.tuple_from_slice(&[
+                atom!("flags"),
+                process.list_from_slice(&[atom!("send"), atom!("receive")])
+            ]))
+        );
+        assert_eq!(
+            result(process, tracee_arc_process.pid_term(), atom!("tracer")),
+            Ok(process.tuple_from_slice(&[atom!("tracer"), process.pid_term()]))
+        );
+    });
+}
+
+#[test]
+fn with_function_without_pattern_returns_not_traced() {
+    with_process(|process| {
+        let mfa = process.tuple_from_slice(&[atom!("erlang"), atom!("self"), process.integer(0)]);
+
+        assert_eq!(
+            result(process, mfa, atom!("traced")),
+            Ok(process.tuple_from_slice(&[atom!("traced"), false.into()]))
+        );
+    });
+}
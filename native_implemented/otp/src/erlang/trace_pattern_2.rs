@@ -0,0 +1,35 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::trace::term_try_into_module_function_arity;
+use crate::runtime::trace;
+
+/// Match specifications are not supported yet, so `MatchSpec` must be `true`, `[]` (the same as
+/// `true` without a match specification) or `false`.
+#[native_implemented::function(erlang:trace_pattern/2)]
+pub fn result(process: &Process, mfa: Term, match_spec: Term) -> exception::Result<Term> {
+    let (module, option_function, option_arity) = term_try_into_module_function_arity(mfa)?;
+
+    let enable = match match_spec.decode()? {
+        TypedTerm::Atom(atom) if atom == "true" => true,
+        TypedTerm::Atom(atom) if atom == "false" => false,
+        TypedTerm::Nil => true,
+        _ => {
+            return Err(anyhow!(
+            "match_spec ({}) is not true, false, or [] as match specifications are not supported",
+            match_spec
+        )
+            .into())
+        }
+    };
+
+    let count = trace::set_call_pattern(module, option_function, option_arity, enable);
+
+    Ok(process.integer(count))
+}
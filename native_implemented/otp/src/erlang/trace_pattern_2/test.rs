@@ -0,0 +1,56 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::trace_pattern_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_module_function_arity_tuple_errors_badarg() {
+    with_process(|process| {
+        let mfa = atom!("erlang");
+
+        assert_badarg!(
+            result(process, mfa, true.into()),
+            format!("mfa ({}) is not a {{Module, Function, Arity}} tuple", mfa)
+        );
+    });
+}
+
+#[test]
+fn with_match_specification_errors_badarg() {
+    with_process(|process| {
+        let mfa = process.tuple_from_slice(&[atom!("erlang"), atom!("exit"), process.integer(1)]);
+        let match_spec = process.list_from_slice(&[process.tuple_from_slice(&[
+            atom!("_"),
+            Term::NIL,
+            Term::NIL,
+        ])]);
+
+        assert_badarg!(
+            result(process, mfa, match_spec),
+            format!(
+                "match_spec ({}) is not true, false, or [] as match specifications are not supported",
+                match_spec
+            )
+        );
+    });
+}
+
+#[test]
+fn with_function_returns_number_of_matching_functions() {
+    with_process(|process| {
+        let mfa = process.tuple_from_slice(&[atom!("erlang"), atom!("exit"), process.integer(1)]);
+
+        assert_eq!(result(process, mfa, true.into()), Ok(process.integer(1)));
+        assert_eq!(result(process, mfa, false.into()), Ok(process.integer(1)));
+    });
+}
+
+#[test]
+fn with_unknown_module_returns_zero() {
+    with_process(|process| {
+        let mfa = process.tuple_from_slice(&[atom!("unknown_module"), atom!("_"), atom!("_")]);
+
+        assert_eq!(result(process, mfa, true.into()), Ok(process.integer(0)));
+    });
+}
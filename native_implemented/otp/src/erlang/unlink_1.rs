@@ -8,6 +8,7 @@ use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
 use crate::runtime::registry::pid_to_process;
+use crate::runtime::trace;
 
 #[native_implemented::function(erlang:unlink/1)]
 fn result(process: &Process, pid_or_port: Term) -> exception::Result<Term> {
@@ -19,6 +20,7 @@ fn result(process: &Process, pid_or_port: Term) -> exception::Result<Term> {
                 match pid_to_process(&pid) {
                     Some(pid_arc_process) => {
                         process.unlink(&pid_arc_process);
+                        trace::unlink(process, pid);
                     }
                     None => (),
                 }
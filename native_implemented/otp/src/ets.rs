@@ -0,0 +1,19 @@
+//! Mirrors [ets](http://erlang.org/doc/man/ets.html) module
+
+pub mod delete_1;
+pub mod delete_2;
+pub mod insert_2;
+pub mod lookup_2;
+pub mod match_object_2;
+pub mod new_2;
+pub mod select_2;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("ets")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,31 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{delete_1, lookup_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn with_table_deletes_table() {
+    with_process(|process| {
+        let table = new_2::result(process, atom!("delete_with_table"), Term::NIL).unwrap();
+
+        assert_eq!(delete_1::result(process, table), Ok(true.into()));
+        assert_badarg!(
+            lookup_2::result(process, table, atom!("key")),
+            format!("table ({}) does not exist", table)
+        );
+    });
+}
+
+#[test]
+fn with_deleted_table_errors_badarg() {
+    with_process(|process| {
+        let table = new_2::result(process, atom!("delete_with_deleted_table"), Term::NIL).unwrap();
+
+        assert_eq!(delete_1::result(process, table), Ok(true.into()));
+        assert_badarg!(
+            delete_1::result(process, table),
+            format!("table ({}) does not exist", table)
+        );
+    });
+}
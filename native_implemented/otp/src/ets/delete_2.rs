@@ -0,0 +1,12 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::ets;
+
+#[native_implemented::function(ets:delete/2)]
+pub fn result(process: &Process, table: Term, key: Term) -> exception::Result<Term> {
+    ets::delete_key(process, table, key)?;
+
+    Ok(true.into())
+}
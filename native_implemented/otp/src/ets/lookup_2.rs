@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::ets;
+
+#[native_implemented::function(ets:lookup/2)]
+pub fn result(process: &Process, table: Term, key: Term) -> exception::Result<Term> {
+    ets::lookup(process, table, key).map_err(From::from)
+}
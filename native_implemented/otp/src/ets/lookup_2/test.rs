@@ -0,0 +1,55 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::{insert_2, lookup_2, new_2};
+use crate::test::with_process;
+
+#[test]
+fn without_key_returns_empty_list() {
+    with_process(|process| {
+        let table = new_2::result(process, atom!("without_key"), Term::NIL).unwrap();
+
+        assert_eq!(
+            lookup_2::result(process, table, atom!("key")),
+            Ok(Term::NIL)
+        );
+    });
+}
+
+#[test]
+fn with_key_returns_list_with_object() {
+    with_process(|process| {
+        let table = new_2::result(process, atom!("with_key"), Term::NIL).unwrap();
+        let key = atom!("key");
+        let object = process.tuple_from_slice(&[key, process.integer(1)]);
+
+        assert_eq!(insert_2::result(process, table, object), Ok(true.into()));
+        assert_eq!(
+            lookup_2::result(process, table, key),
+            Ok(process.list_from_slice(&[object]))
+        );
+    });
+}
+
+#[test]
+fn with_key_inserted_twice_returns_last_object() {
+    with_process(|process| {
+        let table = new_2::result(process, atom!("with_key_inserted_twice"), Term::NIL).unwrap();
+        let key = atom!("key");
+        let first_object = process.tuple_from_slice(&[key, process.integer(1)]);
+        let last_object = process.tuple_from_slice(&[key, process.integer(2)]);
+
+        assert_eq!(
+            insert_2::result(process, table, first_object),
+            Ok(true.into())
+        );
+        assert_eq!(
+            insert_2::result(process, table, last_object),
+            Ok(true.into())
+        );
+        assert_eq!(
+            lookup_2::result(process, table, key),
+            Ok(process.list_from_slice(&[last_object]))
+        );
+    });
+}
@@ -0,0 +1,10 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::ets;
+
+#[native_implemented::function(ets:match_object/2)]
+pub fn result(process: &Process, table: Term, pattern: Term) -> exception::Result<Term> {
+    ets::match_object(process, table, pattern).map_err(From::from)
+}
@@ -0,0 +1,62 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ets::new_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_name_errors_badarg() {
+    with_process(|process| {
+        let name = process.integer(0);
+
+        assert_badarg!(
+            result(process, name, Term::NIL),
+            format!("name ({}) is not an atom", name)
+        );
+    });
+}
+
+#[test]
+fn without_named_table_returns_reference() {
+    with_process(|process| {
+        let table = result(process, atom!("without_named_table"), Term::NIL).unwrap();
+
+        assert!(table.is_reference());
+    });
+}
+
+#[test]
+fn with_named_table_returns_name() {
+    with_process(|process| {
+        let name = atom!("with_named_table_returns_name");
+        let options = process.list_from_slice(&[atom!("named_table")]);
+
+        assert_eq!(result(process, name, options), Ok(name));
+    });
+}
+
+#[test]
+fn with_named_table_with_existing_name_errors_badarg() {
+    with_process(|process| {
+        let name = atom!("with_named_table_with_existing_name");
+        let options = process.list_from_slice(&[atom!("named_table")]);
+
+        assert_eq!(result(process, name, options), Ok(name));
+        assert_badarg!(
+            result(process, name, options),
+            format!("table named ({}) already exists", name)
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_type_errors_badarg() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[atom!("bag")]);
+
+        assert_badarg!(
+            result(process, atom!("with_unsupported_type"), options),
+            "supported options are"
+        );
+    });
+}
@@ -0,0 +1,10 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::ets;
+
+#[native_implemented::function(ets:select/2)]
+pub fn result(process: &Process, table: Term, match_spec: Term) -> exception::Result<Term> {
+    ets::select(process, table, match_spec).map_err(From::from)
+}
@@ -0,0 +1,189 @@
+//! Mirrors [file](http://erlang.org/doc/man/file.html) module
+//!
+//! The file operations run on the `dirty_io` pool, so that they do not block the scheduler, while
+//! the calling process waits for their reply.
+
+pub mod close_1;
+pub mod delete_1;
+pub mod list_dir_1;
+pub mod open_2;
+pub mod read_2;
+pub mod read_file_1;
+pub mod read_file_info_1;
+pub mod write_2;
+pub mod write_file_2;
+pub mod write_file_3;
+
+use std::path::PathBuf;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Frame, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::dirty_io::{self, Output};
+use crate::runtime::file::{FileInfo, IoDevice, Reply};
+use crate::unicode::characters;
+
+fn module() -> Atom {
+    Atom::from_str("file")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Runs `job` on the `dirty_io` pool and queues `label_frame` to return its reply.
+fn spawn<F>(process: &Process, label_frame: Frame, job: F) -> Term
+where
+    F: FnOnce() -> Reply + Send + 'static,
+{
+    let output = dirty_io::spawn(process, job);
+    let output_term = process.resource(output);
+    process.queue_frame_with_arguments(label_frame.with_arguments(false, &[output_term]));
+
+    Term::NONE
+}
+
+/// Returns the reply in `output` if the job is done, otherwise waits for it by queueing
+/// `label_frame` again.
+fn await_reply(process: &Process, output: Term, label_frame: Frame) -> Term {
+    let reply = match output.decode().unwrap() {
+        TypedTerm::ResourceReference(resource_reference) => {
+            let resource: Resource = resource_reference.into();
+            let output = resource.downcast_ref::<Output<Reply>>().unwrap();
+
+            output.take_or_wait(process)
+        }
+        _ => unreachable!("output ({}) is not a resource", output),
+    };
+
+    match reply {
+        Some(reply) => reply_to_term(process, reply),
+        None => {
+            process.queue_frame_with_arguments(label_frame.with_arguments(false, &[output]));
+
+            Term::NONE
+        }
+    }
+}
+
+/// Converts a filename, which can be an atom, string, or binary, to a path.
+fn filename_to_path(process: &Process, filename: Term) -> exception::Result<PathBuf> {
+    let string = match filename.decode()? {
+        TypedTerm::Atom(atom) => atom.name().to_string(),
+        _ if filename.is_list() || filename.is_binary() => {
+            let bytes = characters::to_utf8_bytes(process, "filename", filename)?;
+
+            // `to_utf8_bytes` only returns valid UTF-8
+            String::from_utf8(bytes).unwrap()
+        }
+        _ => {
+            return Err(TypeError)
+                .with_context(|| {
+                    format!("filename ({}) is not an atom, string, or binary", filename)
+                })
+                .map_err(From::from)
+        }
+    };
+
+    Ok(string.into())
+}
+
+fn io_device_from_term(io_device: Term) -> exception::Result<IoDevice> {
+    match io_device.decode()? {
+        TypedTerm::ResourceReference(resource_reference) => {
+            let resource: Resource = resource_reference.into();
+
+            match resource.downcast_ref::<IoDevice>() {
+                Some(io_device) => Ok(io_device.clone()),
+                None => Err(TypeError)
+                    .with_context(|| {
+                        format!(
+                            "io_device ({}) is a resource, but not an io device",
+                            io_device
+                        )
+                    })
+                    .map_err(From::from),
+            }
+        }
+        _ => Err(TypeError)
+            .with_context(|| format!("io_device ({}) is not an io device", io_device))
+            .map_err(From::from),
+    }
+}
+
+fn reply_to_term(process: &Process, reply: Reply) -> Term {
+    match reply {
+        Reply::Ok => atom!("ok"),
+        Reply::Data { bytes, binary } => {
+            let data = if binary {
+                process.binary_from_bytes(&bytes)
+            } else {
+                let elements: Vec<Term> = bytes
+                    .into_iter()
+                    .map(|byte| process.integer(byte))
+                    .collect();
+
+                process.list_from_slice(&elements)
+            };
+
+            ok_tuple(process, data)
+        }
+        Reply::Eof => atom!("eof"),
+        Reply::IoDevice(io_device) => ok_tuple(process, process.resource(io_device)),
+        Reply::FileInfo(file_info) => ok_tuple(process, file_info_to_term(process, file_info)),
+        Reply::Filenames(filenames) => {
+            let elements: Vec<Term> = filenames
+                .iter()
+                .map(|filename| process.charlist_from_str(filename))
+                .collect();
+
+            ok_tuple(process, process.list_from_slice(&elements))
+        }
+        Reply::Error(reason) => {
+            process.tuple_from_slice(&[atom!("error"), Atom::str_to_term(reason)])
+        }
+    }
+}
+
+fn ok_tuple(process: &Process, value: Term) -> Term {
+    process.tuple_from_slice(&[atom!("ok"), value])
+}
+
+/// The `#file_info{}` record from `kernel/include/file.hrl`.
+fn file_info_to_term(process: &Process, file_info: FileInfo) -> Term {
+    process.tuple_from_slice(&[
+        Atom::str_to_term("file_info"),
+        process.integer(file_info.size),
+        Atom::str_to_term(file_info.r#type),
+        Atom::str_to_term(file_info.access),
+        datetime_to_term(process, file_info.atime),
+        datetime_to_term(process, file_info.mtime),
+        datetime_to_term(process, file_info.ctime),
+        process.integer(file_info.mode as u64),
+        process.integer(file_info.links),
+        process.integer(file_info.major_device),
+        process.integer(file_info.minor_device),
+        process.integer(file_info.inode),
+        process.integer(file_info.uid as u64),
+        process.integer(file_info.gid as u64),
+    ])
+}
+
+fn datetime_to_term(process: &Process, datetime: [usize; 6]) -> Term {
+    let date_tuple = process.tuple_from_slice(&[
+        process.integer(datetime[0]),
+        process.integer(datetime[1]),
+        process.integer(datetime[2]),
+    ]);
+    let time_tuple = process.tuple_from_slice(&[
+        process.integer(datetime[3]),
+        process.integer(datetime[4]),
+        process.integer(datetime[5]),
+    ]);
+
+    process.tuple_from_slice(&[date_tuple, time_tuple])
+}
@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `ok`, or `{error, einval}` if `io_device` is already closed.
+#[native_implemented::function(file:close/1)]
+pub fn result(process: &Process, io_device: Term) -> exception::Result<Term> {
+    let io_device = super::io_device_from_term(io_device)?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        io_device.close()
+    }))
+}
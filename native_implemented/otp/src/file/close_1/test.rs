@@ -0,0 +1,22 @@
+use crate::file::close_1;
+use crate::test::with_process;
+
+#[test]
+fn without_io_device_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            close_1::result(process, process.integer(1)),
+            "io_device (1) is not an io device"
+        );
+    });
+}
+
+#[test]
+fn with_other_resource_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            close_1::result(process, process.resource(1_u8)),
+            "is a resource, but not an io device"
+        );
+    });
+}
@@ -0,0 +1,7 @@
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::label]
+fn result(process: &Process, output: Term) -> Term {
+    crate::file::await_reply(process, output, frame())
+}
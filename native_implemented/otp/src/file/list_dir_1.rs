@@ -0,0 +1,20 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::file;
+
+/// Returns `{ok, Filenames}`, sorted, or `{error, Reason}`.
+#[native_implemented::function(file:list_dir/1)]
+pub fn result(process: &Process, dir: Term) -> exception::Result<Term> {
+    let path = super::filename_to_path(process, dir)?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        file::list_dir(path)
+    }))
+}
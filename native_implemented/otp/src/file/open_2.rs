@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::file::{self, Modes};
+
+/// Returns `{ok, IoDevice}`, where `IoDevice` is a resource for `file:read/2`, `file:write/2`,
+/// and `file:close/1`, or `{error, Reason}`.
+#[native_implemented::function(file:open/2)]
+pub fn result(process: &Process, filename: Term, modes: Term) -> exception::Result<Term> {
+    let path = super::filename_to_path(process, filename)?;
+    let modes: Modes = modes.try_into()?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        file::open(path, modes)
+    }))
+}
@@ -0,0 +1,42 @@
+use liblumen_alloc::atom;
+
+use crate::file::open_2;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_string_or_binary_filename_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            open_2::result(process, process.integer(1), process.list_from_slice(&[])),
+            "filename (1) is not an atom, string, or binary"
+        );
+    });
+}
+
+#[test]
+fn without_supported_mode_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            open_2::result(
+                process,
+                process.charlist_from_str("file.txt"),
+                process.list_from_slice(&[atom!("compressed")])
+            ),
+            "supported modes are"
+        );
+    });
+}
+
+#[test]
+fn with_improper_modes_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            open_2::result(
+                process,
+                process.charlist_from_str("file.txt"),
+                process.cons(atom!("read"), atom!("write"))
+            ),
+            "improper list"
+        );
+    });
+}
@@ -0,0 +1,25 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Data}` with up to `number` bytes, `eof`, or `{error, Reason}`.
+#[native_implemented::function(file:read/2)]
+pub fn result(process: &Process, io_device: Term, number: Term) -> exception::Result<Term> {
+    let io_device = super::io_device_from_term(io_device)?;
+    let len: usize = number
+        .try_into()
+        .with_context(|| format!("number ({}) is not a non-negative integer", number))?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        io_device.read(len)
+    }))
+}
@@ -0,0 +1,12 @@
+use crate::file::read_2;
+use crate::test::with_process;
+
+#[test]
+fn without_io_device_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            read_2::result(process, process.integer(1), process.integer(1)),
+            "io_device (1) is not an io device"
+        );
+    });
+}
@@ -0,0 +1,22 @@
+use crate::file::read_file_1;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_string_or_binary_filename_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            read_file_1::result(process, process.integer(1)),
+            "filename (1) is not an atom, string, or binary"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_string_filename_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            read_file_1::result(process, process.list_from_slice(&[process.integer(-1)])),
+            "is not valid unicode chardata"
+        );
+    });
+}
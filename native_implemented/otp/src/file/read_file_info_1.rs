@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::file;
+
+/// Returns `{ok, FileInfo}`, where `FileInfo` is a `#file_info{}` record with times in local time,
+/// or `{error, Reason}`.
+#[native_implemented::function(file:read_file_info/1)]
+pub fn result(process: &Process, filename: Term) -> exception::Result<Term> {
+    let path = super::filename_to_path(process, filename)?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        file::read_file_info(path)
+    }))
+}
@@ -0,0 +1,12 @@
+use crate::file::read_file_info_1;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_string_or_binary_filename_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            read_file_info_1::result(process, process.integer(1)),
+            "filename (1) is not an atom, string, or binary"
+        );
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+
+/// Returns `ok` or `{error, Reason}`.
+#[native_implemented::function(file:write/2)]
+pub fn result(process: &Process, io_device: Term, bytes: Term) -> exception::Result<Term> {
+    let io_device = super::io_device_from_term(io_device)?;
+    let bytes = iolist_or_binary::to_byte_vec("bytes", bytes)?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        io_device.write(&bytes)
+    }))
+}
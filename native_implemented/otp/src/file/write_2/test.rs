@@ -0,0 +1,12 @@
+use crate::file::write_2;
+use crate::test::with_process;
+
+#[test]
+fn without_io_device_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            write_2::result(process, process.integer(1), process.binary_from_str("a")),
+            "io_device (1) is not an io device"
+        );
+    });
+}
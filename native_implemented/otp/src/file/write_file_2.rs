@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::file;
+
+/// Returns `ok` or `{error, Reason}`.
+#[native_implemented::function(file:write_file/2)]
+pub fn result(process: &Process, filename: Term, bytes: Term) -> exception::Result<Term> {
+    let path = super::filename_to_path(process, filename)?;
+    let bytes = iolist_or_binary::to_byte_vec("bytes", bytes)?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        file::write_file(path, bytes, Default::default())
+    }))
+}
@@ -0,0 +1,28 @@
+use liblumen_alloc::atom;
+
+use crate::file::write_file_2;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_string_or_binary_filename_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            write_file_2::result(process, process.integer(1), process.binary_from_str("a")),
+            "filename (1) is not an atom, string, or binary"
+        );
+    });
+}
+
+#[test]
+fn without_iodata_bytes_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            write_file_2::result(
+                process,
+                process.charlist_from_str("file.txt"),
+                process.list_from_slice(&[atom!("a")])
+            ),
+            "bytes"
+        );
+    });
+}
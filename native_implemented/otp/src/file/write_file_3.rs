@@ -0,0 +1,31 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::file::{self, Modes};
+
+/// Like `file:write_file/2`, but `modes` can include `append` or `exclusive`, as the file is
+/// always opened with `write`.
+#[native_implemented::function(file:write_file/3)]
+pub fn result(
+    process: &Process,
+    filename: Term,
+    bytes: Term,
+    modes: Term,
+) -> exception::Result<Term> {
+    let path = super::filename_to_path(process, filename)?;
+    let bytes = iolist_or_binary::to_byte_vec("bytes", bytes)?;
+    let modes: Modes = modes.try_into()?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        file::write_file(path, bytes, modes)
+    }))
+}
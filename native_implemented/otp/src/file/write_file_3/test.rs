@@ -0,0 +1,19 @@
+use liblumen_alloc::atom;
+
+use crate::file::write_file_3;
+use crate::test::with_process;
+
+#[test]
+fn without_supported_mode_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            write_file_3::result(
+                process,
+                process.charlist_from_str("file.txt"),
+                process.binary_from_str("a"),
+                process.list_from_slice(&[atom!("compressed")])
+            ),
+            "supported modes are"
+        );
+    });
+}
@@ -0,0 +1,111 @@
+//! Mirrors [gb_sets](http://erlang.org/doc/man/gb_sets.html) module
+//!
+//! A set is `{Size, Tree}`, the same as in OTP, using the trees from `gb_trees` without values.
+//! Elements are compared in term order, so elements that compare equal, such as `1` and `1.0`,
+//! are the same element.
+
+pub mod add_element_2;
+pub mod delete_2;
+pub mod delete_any_2;
+pub mod empty_0;
+pub mod from_list_1;
+pub mod from_ordset_1;
+pub mod insert_2;
+pub mod is_element_2;
+pub mod new_0;
+pub mod size_1;
+pub mod to_list_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::tree::Shape;
+
+fn module() -> Atom {
+    Atom::from_str("gb_sets")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// `{Size, Tree}`
+fn term_try_into_size_tree(set: Term) -> exception::Result<(usize, Term)> {
+    let option_size_tree = match set.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+            let result_size: std::result::Result<usize, _> = tuple[0].try_into();
+
+            result_size.ok().map(|size| (size, tuple[1]))
+        }
+        _ => None,
+    };
+
+    option_size_tree
+        .with_context(|| term_is_not_set(set))
+        .map_err(From::from)
+}
+
+fn term_is_not_set(set: Term) -> String {
+    format!("set ({}) is not a set", set)
+}
+
+fn empty(process: &Process) -> Term {
+    process.tuple_from_slice(&[process.integer(0), Shape::nil()])
+}
+
+fn is_element(element: Term, set: Term) -> exception::Result<bool> {
+    let (_, root) = term_try_into_size_tree(set)?;
+    let option_node = Shape::Set
+        .lookup(element, root)
+        .with_context(|| term_is_not_set(set))?;
+
+    Ok(option_node.is_some())
+}
+
+/// Inserts `element`, which must not be in `set`
+fn insert(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    let (size, root) = term_try_into_size_tree(set)?;
+    let inserted_size = size + 1;
+    let inserted_root = Shape::Set
+        .insert(process, element, None, root, inserted_size)
+        .with_context(|| term_is_not_set(set))?;
+
+    Ok(process.tuple_from_slice(&[process.integer(inserted_size), inserted_root]))
+}
+
+/// Removes `element`, which must be in `set`
+fn delete(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    let (size, root) = term_try_into_size_tree(set)?;
+    let deleted_root = Shape::Set
+        .delete(process, element, root)
+        .with_context(|| term_is_not_set(set))?;
+
+    Ok(process.tuple_from_slice(&[process.integer(size - 1), deleted_root]))
+}
+
+/// A balanced set of the elements of `ordset`
+fn from_ordset(process: &Process, ordset: Term) -> exception::Result<Term> {
+    let element_vec = match ordset.decode()? {
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .map(|result| result.map(|element| (element, None)))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("ordset ({}) is not a proper list", ordset))?,
+        _ => {
+            return Err(TypeError)
+                .context(format!("ordset ({}) is not a proper list", ordset))
+                .map_err(From::from)
+        }
+    };
+
+    let root = Shape::balance_list(process, &element_vec);
+
+    Ok(process.tuple_from_slice(&[process.integer(element_vec.len()), root]))
+}
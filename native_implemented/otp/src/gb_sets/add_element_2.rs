@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `set` with `element` inserted, or `set` if `element` is already in it
+#[native_implemented::function(gb_sets:add_element/2)]
+pub fn result(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    if super::is_element(element, set)? {
+        Ok(set)
+    } else {
+        super::insert(process, element, set)
+    }
+}
@@ -0,0 +1,40 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::add_element_2::result;
+use crate::gb_sets::{empty_0, size_1};
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("element"), atom!("set")),
+            "set (set) is not a set"
+        );
+    });
+}
+
+#[test]
+fn without_element_inserts_element() {
+    with_process(|process| {
+        let set = result(process, atom!("element"), empty_0::result(process)).unwrap();
+
+        assert_eq!(
+            set,
+            process.tuple_from_slice(&[
+                process.integer(1),
+                process.tuple_from_slice(&[atom!("element"), atom!("nil"), atom!("nil")])
+            ])
+        );
+    });
+}
+
+#[test]
+fn with_element_returns_set() {
+    with_process(|process| {
+        let set = result(process, process.integer(1), empty_0::result(process)).unwrap();
+
+        assert_eq!(result(process, process.float(1.0), set), Ok(set));
+        assert_eq!(size_1::result(process, set), Ok(process.integer(1)));
+    });
+}
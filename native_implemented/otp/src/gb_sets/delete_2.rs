@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `set` without `element`, which must be in `set`
+#[native_implemented::function(gb_sets:delete/2)]
+pub fn result(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    if super::is_element(element, set)? {
+        super::delete(process, element, set)
+    } else {
+        Err(anyhow!("element ({}) is not in set ({})", element, set).into())
+    }
+}
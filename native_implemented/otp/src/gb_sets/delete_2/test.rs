@@ -0,0 +1,50 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+use crate::gb_sets::delete_2::result;
+use crate::gb_sets::{empty_0, from_list_1, to_list_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_element_errors_badarg() {
+    with_process(|process| {
+        let set = empty_0::result(process);
+
+        assert_badarg!(
+            result(process, atom!("element"), set),
+            format!("element (element) is not in set ({})", set)
+        );
+    });
+}
+
+#[test]
+fn with_element_removes_element() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term::atom(), 1..=32),
+            )
+        },
+        |(arc_process, element_vec)| {
+            let list = arc_process.list_from_slice(&element_vec);
+            let set = from_list_1::result(&arc_process, list).unwrap();
+            let deleted_element = element_vec[0];
+            let mut sorted_vec = element_vec.clone();
+            sorted_vec.sort();
+            sorted_vec.dedup();
+            sorted_vec.retain(|element| *element != deleted_element);
+
+            let deleted = result(&arc_process, deleted_element, set).unwrap();
+
+            prop_assert_eq!(
+                to_list_1::result(&arc_process, deleted),
+                Ok(arc_process.list_from_slice(&sorted_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,24 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::delete_any_2::result;
+use crate::gb_sets::{add_element_2, empty_0};
+use crate::test::with_process;
+
+#[test]
+fn without_element_returns_set() {
+    with_process(|process| {
+        let set = add_element_2::result(process, atom!("a"), empty_0::result(process)).unwrap();
+
+        assert_eq!(result(process, atom!("b"), set), Ok(set));
+    });
+}
+
+#[test]
+fn with_element_removes_element() {
+    with_process(|process| {
+        let empty = empty_0::result(process);
+        let set = add_element_2::result(process, atom!("a"), empty).unwrap();
+
+        assert_eq!(result(process, atom!("a"), set), Ok(empty));
+    });
+}
@@ -0,0 +1,14 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::empty_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_zero_size_and_nil_tree() {
+    with_process(|process| {
+        assert_eq!(
+            result(process),
+            process.tuple_from_slice(&[process.integer(0), atom!("nil")])
+        );
+    });
+}
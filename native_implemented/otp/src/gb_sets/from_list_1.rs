@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets;
+
+/// A balanced set of the elements of `list`
+#[native_implemented::function(gb_sets:from_list/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let ordset = ordsets::from_list_1::result(process, list)?;
+
+    super::from_ordset(process, ordset)
+}
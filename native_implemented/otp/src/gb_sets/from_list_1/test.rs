@@ -0,0 +1,53 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use crate::gb_sets::from_list_1::result;
+use crate::gb_sets::to_list_1;
+use crate::test::strategy;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_proper_list(arc_process),
+            )
+        },
+        |(arc_process, list)| {
+            prop_assert_badarg!(
+                result(&arc_process, list),
+                format!("list ({}) is not a proper list", list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_list_to_list_returns_sorted_list_without_duplicates() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term::atom(), 0..=32),
+            )
+        },
+        |(arc_process, element_vec)| {
+            let list = arc_process.list_from_slice(&element_vec);
+            let mut sorted_vec = element_vec.clone();
+            sorted_vec.sort();
+            sorted_vec.dedup();
+
+            let set = result(&arc_process, list).unwrap();
+
+            prop_assert_eq!(
+                to_list_1::result(&arc_process, set),
+                Ok(arc_process.list_from_slice(&sorted_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
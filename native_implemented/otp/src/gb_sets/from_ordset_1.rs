@@ -0,0 +1,12 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// A balanced set of the elements of `ordset`, which must already be sorted without duplicates
+#[native_implemented::function(gb_sets:from_ordset/1)]
+pub fn result(process: &Process, ordset: Term) -> exception::Result<Term> {
+    super::from_ordset(process, ordset)
+}
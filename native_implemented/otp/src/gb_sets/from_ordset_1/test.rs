@@ -0,0 +1,30 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::from_ordset_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("ordset")),
+            "ordset (ordset) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn with_ordset_returns_balanced_set() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[atom!("a"), atom!("b"), atom!("c")]);
+        let nil = atom!("nil");
+        let a = process.tuple_from_slice(&[atom!("a"), nil, nil]);
+        let c = process.tuple_from_slice(&[atom!("c"), nil, nil]);
+        let b = process.tuple_from_slice(&[atom!("b"), a, c]);
+
+        assert_eq!(
+            result(process, ordset),
+            Ok(process.tuple_from_slice(&[process.integer(3), b]))
+        );
+    });
+}
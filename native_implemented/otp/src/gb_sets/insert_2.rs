@@ -0,0 +1,27 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, error};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `set` with `element` inserted, which errors with `{key_exists, Element}` if `element` is
+/// already in `set`
+#[native_implemented::function(gb_sets:insert/2)]
+pub fn result(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    if super::is_element(element, set)? {
+        Err(error(
+            process.tuple_from_slice(&[atom!("key_exists"), element]),
+            None,
+            Trace::capture(),
+            Some(anyhow!("element ({}) is already in set ({})", element, set).into()),
+        )
+        .into())
+    } else {
+        super::insert(process, element, set)
+    }
+}
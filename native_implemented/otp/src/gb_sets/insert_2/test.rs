@@ -0,0 +1,46 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::empty_0;
+use crate::gb_sets::insert_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("element"), atom!("set")),
+            "set (set) is not a set"
+        );
+    });
+}
+
+#[test]
+fn with_element_errors_key_exists() {
+    with_process(|process| {
+        let set = result(process, atom!("element"), empty_0::result(process)).unwrap();
+
+        assert_error!(
+            result(process, atom!("element"), set),
+            process.tuple_from_slice(&[atom!("key_exists"), atom!("element")])
+        );
+    });
+}
+
+#[test]
+fn with_ascending_elements_rebalances_when_too_deep() {
+    with_process(|process| {
+        let mut set = empty_0::result(process);
+
+        for i in 1..=7 {
+            set = result(process, process.integer(i), set).unwrap();
+        }
+
+        let nil = atom!("nil");
+        let leaf = |i: usize| process.tuple_from_slice(&[process.integer(i), nil, nil]);
+        let two = process.tuple_from_slice(&[process.integer(2), leaf(1), leaf(3)]);
+        let six = process.tuple_from_slice(&[process.integer(6), leaf(5), leaf(7)]);
+        let four = process.tuple_from_slice(&[process.integer(4), two, six]);
+
+        assert_eq!(set, process.tuple_from_slice(&[process.integer(7), four]));
+    });
+}
@@ -0,0 +1,11 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Whether `element` is in `set`
+#[native_implemented::function(gb_sets:is_element/2)]
+pub fn result(element: Term, set: Term) -> exception::Result<Term> {
+    super::is_element(element, set).map(From::from)
+}
@@ -0,0 +1,50 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::is_element_2::result;
+use crate::gb_sets::{empty_0, from_list_1};
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    assert_badarg!(
+        result(atom!("element"), atom!("set")),
+        "set (set) is not a set"
+    );
+}
+
+#[test]
+fn with_gb_trees_node_errors_badarg() {
+    with_process(|process| {
+        let nil = atom!("nil");
+        let set = process.tuple_from_slice(&[
+            process.integer(1),
+            process.tuple_from_slice(&[atom!("key"), atom!("value"), nil, nil]),
+        ]);
+
+        assert_badarg!(
+            result(atom!("key"), set),
+            format!("set ({}) is not a set", set)
+        );
+    });
+}
+
+#[test]
+fn with_element_returns_true() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+        let set = from_list_1::result(process, list).unwrap();
+
+        assert_eq!(result(process.integer(2), set), Ok(true.into()));
+        assert_eq!(result(process.float(2.0), set), Ok(true.into()));
+    });
+}
+
+#[test]
+fn without_element_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            result(atom!("a"), empty_0::result(process)),
+            Ok(false.into())
+        );
+    });
+}
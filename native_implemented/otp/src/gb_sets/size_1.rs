@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The number of elements in `set`
+#[native_implemented::function(gb_sets:size/1)]
+pub fn result(process: &Process, set: Term) -> exception::Result<Term> {
+    let (size, _) = super::term_try_into_size_tree(set)?;
+
+    Ok(process.integer(size))
+}
@@ -0,0 +1,26 @@
+use liblumen_alloc::atom;
+
+use crate::gb_sets::size_1::result;
+use crate::gb_sets::{empty_0, from_list_1};
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("set")), "set (set) is not a set");
+    });
+}
+
+#[test]
+fn returns_number_of_elements() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("a"), atom!("b"), atom!("a")]);
+        let set = from_list_1::result(process, list).unwrap();
+
+        assert_eq!(
+            result(process, empty_0::result(process)),
+            Ok(process.integer(0))
+        );
+        assert_eq!(result(process, set), Ok(process.integer(2)));
+    });
+}
@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::tree::Shape;
+
+/// The elements of `set`, in order
+#[native_implemented::function(gb_sets:to_list/1)]
+pub fn result(process: &Process, set: Term) -> exception::Result<Term> {
+    let (_, root) = super::term_try_into_size_tree(set)?;
+    let element_vec: Vec<Term> = Shape::Set
+        .to_vec(root)
+        .with_context(|| super::term_is_not_set(set))?
+        .into_iter()
+        .map(|(element, _)| element)
+        .collect();
+
+    Ok(process.list_from_slice(&element_vec))
+}
@@ -0,0 +1,30 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_sets::to_list_1::result;
+use crate::gb_sets::{add_element_2, empty_0};
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("set")), "set (set) is not a set");
+    });
+}
+
+#[test]
+fn returns_elements_in_order() {
+    with_process(|process| {
+        let mut set = empty_0::result(process);
+
+        for element in &[atom!("b"), atom!("c"), atom!("a")] {
+            set = add_element_2::result(process, *element, set).unwrap();
+        }
+
+        assert_eq!(result(process, empty_0::result(process)), Ok(Term::NIL));
+        assert_eq!(
+            result(process, set),
+            Ok(process.list_from_slice(&[atom!("a"), atom!("b"), atom!("c")]))
+        );
+    });
+}
@@ -0,0 +1,112 @@
+//! Mirrors [gb_trees](http://erlang.org/doc/man/gb_trees.html) module
+//!
+//! A tree is `{Size, Tree}`, the same as in OTP, and keys are compared in term order, so keys that
+//! compare equal, such as `1` and `1.0`, are the same key.
+
+pub mod delete_2;
+pub mod delete_any_2;
+pub mod empty_0;
+pub mod enter_3;
+pub mod from_orddict_1;
+pub mod get_2;
+pub mod insert_3;
+pub mod is_defined_2;
+pub mod keys_1;
+pub mod lookup_2;
+pub mod size_1;
+pub mod to_list_1;
+pub mod tree;
+pub mod update_3;
+pub mod values_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use tree::{Node, Shape};
+
+fn module() -> Atom {
+    Atom::from_str("gb_trees")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// `{Size, Tree}`
+fn term_try_into_size_tree(tree: Term) -> exception::Result<(usize, Term)> {
+    let option_size_tree = match tree.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+            let result_size: std::result::Result<usize, _> = tuple[0].try_into();
+
+            result_size.ok().map(|size| (size, tuple[1]))
+        }
+        _ => None,
+    };
+
+    option_size_tree
+        .with_context(|| term_is_not_tree(tree))
+        .map_err(From::from)
+}
+
+fn term_is_not_tree(tree: Term) -> String {
+    format!("tree ({}) is not a tree", tree)
+}
+
+fn lookup(key: Term, tree: Term) -> exception::Result<Option<Node>> {
+    let (_, root) = term_try_into_size_tree(tree)?;
+
+    Shape::Tree
+        .lookup(key, root)
+        .with_context(|| term_is_not_tree(tree))
+        .map_err(From::from)
+}
+
+fn insert(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    let (size, root) = term_try_into_size_tree(tree)?;
+    let inserted_size = size + 1;
+    let inserted_root = Shape::Tree
+        .insert(process, key, Some(value), root, inserted_size)
+        .with_context(|| term_is_not_tree(tree))?;
+
+    Ok(process.tuple_from_slice(&[process.integer(inserted_size), inserted_root]))
+}
+
+fn delete(process: &Process, key: Term, tree: Term) -> exception::Result<Term> {
+    let (size, root) = term_try_into_size_tree(tree)?;
+    let deleted_root = Shape::Tree
+        .delete(process, key, root)
+        .with_context(|| term_is_not_tree(tree))?;
+
+    Ok(process.tuple_from_slice(&[process.integer(size - 1), deleted_root]))
+}
+
+fn update(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    let (size, root) = term_try_into_size_tree(tree)?;
+    let updated_root = Shape::Tree
+        .update(process, key, value, root)
+        .with_context(|| term_is_not_tree(tree))?;
+
+    Ok(process.tuple_from_slice(&[process.integer(size), updated_root]))
+}
+
+/// The keys and values of `tree`, in key order
+fn to_vec(tree: Term) -> exception::Result<Vec<(Term, Term)>> {
+    let (_, root) = term_try_into_size_tree(tree)?;
+    let entry_vec = Shape::Tree
+        .to_vec(root)
+        .with_context(|| term_is_not_tree(tree))?;
+
+    Ok(entry_vec
+        .into_iter()
+        .map(|(key, value)| (key, value.unwrap()))
+        .collect())
+}
+
+fn key_not_in_tree(key: Term, tree: Term) -> exception::Exception {
+    anyhow!("key ({}) is not in tree ({})", key, tree).into()
+}
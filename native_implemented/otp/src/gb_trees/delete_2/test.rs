@@ -0,0 +1,58 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+use crate::gb_trees::delete_2::result;
+use crate::gb_trees::{empty_0, insert_3, keys_1, size_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let tree = empty_0::result(process);
+
+        assert_badarg!(
+            result(process, atom!("key"), tree),
+            format!("key (key) is not in tree ({})", tree)
+        );
+    });
+}
+
+#[test]
+fn with_key_removes_key() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term::atom(), 1..=32),
+            )
+        },
+        |(arc_process, key_vec)| {
+            let mut sorted_key_vec = key_vec.clone();
+            sorted_key_vec.sort();
+            sorted_key_vec.dedup();
+
+            let mut tree = empty_0::result(&arc_process);
+
+            for key in &sorted_key_vec {
+                tree = insert_3::result(&arc_process, *key, atom!("value"), tree).unwrap();
+            }
+
+            let deleted_key = key_vec[0];
+            let deleted = result(&arc_process, deleted_key, tree).unwrap();
+            sorted_key_vec.retain(|key| *key != deleted_key);
+
+            prop_assert_eq!(
+                keys_1::result(&arc_process, deleted),
+                Ok(arc_process.list_from_slice(&sorted_key_vec))
+            );
+            prop_assert_eq!(
+                size_1::result(&arc_process, deleted),
+                Ok(arc_process.integer(sorted_key_vec.len()))
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `tree` without `key`, or `tree` if `key` is not in it
+#[native_implemented::function(gb_trees:delete_any/2)]
+pub fn result(process: &Process, key: Term, tree: Term) -> exception::Result<Term> {
+    match super::lookup(key, tree)? {
+        Some(_) => super::delete(process, key, tree),
+        None => Ok(tree),
+    }
+}
@@ -0,0 +1,30 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::delete_any_2::result;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn without_key_returns_tree() {
+    with_process(|process| {
+        let tree = insert_3::result(
+            process,
+            atom!("a"),
+            atom!("value"),
+            empty_0::result(process),
+        )
+        .unwrap();
+
+        assert_eq!(result(process, atom!("b"), tree), Ok(tree));
+    });
+}
+
+#[test]
+fn with_key_removes_key() {
+    with_process(|process| {
+        let empty = empty_0::result(process);
+        let tree = insert_3::result(process, atom!("a"), atom!("value"), empty).unwrap();
+
+        assert_eq!(result(process, atom!("a"), tree), Ok(empty));
+    });
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::tree::Shape;
+
+/// An empty tree
+#[native_implemented::function(gb_trees:empty/0)]
+pub fn result(process: &Process) -> Term {
+    process.tuple_from_slice(&[process.integer(0), Shape::nil()])
+}
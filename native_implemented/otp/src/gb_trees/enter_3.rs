@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `tree` with the value of `key` replaced by `value`, or `key` inserted if it is not in `tree`
+#[native_implemented::function(gb_trees:enter/3)]
+pub fn result(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    match super::lookup(key, tree)? {
+        Some(_) => super::update(process, key, value, tree),
+        None => super::insert(process, key, value, tree),
+    }
+}
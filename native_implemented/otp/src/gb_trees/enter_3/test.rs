@@ -0,0 +1,80 @@
+use std::collections::BTreeMap;
+
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+use crate::gb_trees::enter_3::result;
+use crate::gb_trees::{empty_0, to_list_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("key"), atom!("value"), atom!("tree")),
+            "tree (tree) is not a tree"
+        );
+    });
+}
+
+#[test]
+fn with_key_replaces_value() {
+    with_process(|process| {
+        let tree = result(
+            process,
+            atom!("key"),
+            atom!("old"),
+            empty_0::result(process),
+        )
+        .unwrap();
+
+        assert_eq!(
+            result(process, atom!("key"), atom!("new"), tree),
+            Ok(process.tuple_from_slice(&[
+                process.integer(1),
+                process.tuple_from_slice(&[atom!("key"), atom!("new"), atom!("nil"), atom!("nil")])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_keys_to_list_is_like_btree_map() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(
+                    (
+                        strategy::term::atom(),
+                        strategy::term::is_integer(arc_process),
+                    ),
+                    0..=32,
+                ),
+            )
+        },
+        |(arc_process, entry_vec)| {
+            let mut tree = empty_0::result(&arc_process);
+            let mut btree_map = BTreeMap::new();
+
+            for (key, value) in entry_vec {
+                tree = result(&arc_process, key, value, tree).unwrap();
+                btree_map.insert(key, value);
+            }
+
+            let pair_vec: Vec<_> = btree_map
+                .into_iter()
+                .map(|(key, value)| arc_process.tuple_from_slice(&[key, value]))
+                .collect();
+
+            prop_assert_eq!(
+                to_list_1::result(&arc_process, tree),
+                Ok(arc_process.list_from_slice(&pair_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,51 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::tree::Shape;
+
+/// A balanced tree of the `{Key, Value}` pairs in `list`, which must already be sorted by key
+/// without duplicates, like an orddict
+#[native_implemented::function(gb_trees:from_orddict/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let mut entry_vec = Vec::new();
+
+    match list.decode()? {
+        TypedTerm::Nil => (),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                let element = result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("list ({}) is not a proper list", list))?;
+
+                match element.decode()? {
+                    TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+                        entry_vec.push((tuple[0], Some(tuple[1])))
+                    }
+                    _ => {
+                        return Err(anyhow!(
+                            "list ({}) element ({}) is not a {{Key, Value}} pair",
+                            list,
+                            element
+                        )
+                        .into())
+                    }
+                }
+            }
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!("list ({}) is not a proper list", list))
+                .map_err(From::from)
+        }
+    }
+
+    let root = Shape::balance_list(process, &entry_vec);
+
+    Ok(process.tuple_from_slice(&[process.integer(entry_vec.len()), root]))
+}
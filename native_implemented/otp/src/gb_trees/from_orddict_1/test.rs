@@ -0,0 +1,59 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::from_orddict_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("list")),
+            "list (list) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn without_pair_element_errors_badarg() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("a")]);
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) element (a) is not a {{Key, Value}} pair", list)
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_tree() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, Term::NIL),
+            Ok(process.tuple_from_slice(&[process.integer(0), atom!("nil")]))
+        );
+    });
+}
+
+#[test]
+fn with_orddict_returns_balanced_tree() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[process.integer(1), atom!("one")]),
+            process.tuple_from_slice(&[process.integer(2), atom!("two")]),
+            process.tuple_from_slice(&[process.integer(3), atom!("three")]),
+            process.tuple_from_slice(&[process.integer(4), atom!("four")]),
+        ]);
+        let nil = atom!("nil");
+        let one = process.tuple_from_slice(&[process.integer(1), atom!("one"), nil, nil]);
+        let four = process.tuple_from_slice(&[process.integer(4), atom!("four"), nil, nil]);
+        let two = process.tuple_from_slice(&[process.integer(2), atom!("two"), one, nil]);
+        let three = process.tuple_from_slice(&[process.integer(3), atom!("three"), two, four]);
+
+        assert_eq!(
+            result(process, orddict),
+            Ok(process.tuple_from_slice(&[process.integer(4), three]))
+        );
+    });
+}
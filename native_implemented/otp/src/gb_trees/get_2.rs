@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The value for `key`, which must be in `tree`
+#[native_implemented::function(gb_trees:get/2)]
+pub fn result(key: Term, tree: Term) -> exception::Result<Term> {
+    match super::lookup(key, tree)? {
+        Some(node) => Ok(node.value.unwrap()),
+        None => Err(super::key_not_in_tree(key, tree)),
+    }
+}
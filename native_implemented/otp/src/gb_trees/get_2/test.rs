@@ -0,0 +1,32 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::get_2::result;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_value() {
+    with_process(|process| {
+        let tree = insert_3::result(
+            process,
+            atom!("a"),
+            process.integer(1),
+            empty_0::result(process),
+        )
+        .unwrap();
+
+        assert_eq!(result(atom!("a"), tree), Ok(process.integer(1)));
+    });
+}
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let tree = empty_0::result(process);
+
+        assert_badarg!(
+            result(atom!("a"), tree),
+            format!("key (a) is not in tree ({})", tree)
+        );
+    });
+}
@@ -0,0 +1,26 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, error};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `tree` with `key` inserted with `value`, which errors with `{key_exists, Key}` if `key` is
+/// already in `tree`
+#[native_implemented::function(gb_trees:insert/3)]
+pub fn result(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    match super::lookup(key, tree)? {
+        Some(_) => Err(error(
+            process.tuple_from_slice(&[atom!("key_exists"), key]),
+            None,
+            Trace::capture(),
+            Some(anyhow!("key ({}) is already in tree ({})", key, tree).into()),
+        )
+        .into()),
+        None => super::insert(process, key, value, tree),
+    }
+}
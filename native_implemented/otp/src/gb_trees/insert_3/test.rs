@@ -0,0 +1,86 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+
+use crate::gb_trees::insert_3::result;
+use crate::gb_trees::{empty_0, keys_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("key"), atom!("value"), atom!("tree")),
+            "tree (tree) is not a tree"
+        );
+    });
+}
+
+#[test]
+fn with_key_errors_key_exists() {
+    with_process(|process| {
+        let tree = result(
+            process,
+            atom!("key"),
+            atom!("value"),
+            empty_0::result(process),
+        )
+        .unwrap();
+
+        assert_error!(
+            result(process, atom!("key"), atom!("value"), tree),
+            process.tuple_from_slice(&[atom!("key_exists"), atom!("key")])
+        );
+    });
+}
+
+#[test]
+fn with_ascending_keys_rebalances_when_too_deep() {
+    with_process(|process| {
+        let mut tree = empty_0::result(process);
+
+        for i in 1..=7 {
+            tree = result(process, process.integer(i), atom!("value"), tree).unwrap();
+        }
+
+        let nil = atom!("nil");
+        let leaf =
+            |i: usize| process.tuple_from_slice(&[process.integer(i), atom!("value"), nil, nil]);
+        let two = process.tuple_from_slice(&[process.integer(2), atom!("value"), leaf(1), leaf(3)]);
+        let six = process.tuple_from_slice(&[process.integer(6), atom!("value"), leaf(5), leaf(7)]);
+        let four = process.tuple_from_slice(&[process.integer(4), atom!("value"), two, six]);
+
+        assert_eq!(tree, process.tuple_from_slice(&[process.integer(7), four]));
+    });
+}
+
+#[test]
+fn with_distinct_keys_keys_are_sorted() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term::is_integer(arc_process), 0..=32),
+            )
+        },
+        |(arc_process, key_vec)| {
+            let mut sorted_key_vec = key_vec.clone();
+            sorted_key_vec.sort();
+            sorted_key_vec.dedup();
+
+            let mut tree = empty_0::result(&arc_process);
+
+            for key in sorted_key_vec.iter().rev() {
+                tree = result(&arc_process, *key, atom!("value"), tree).unwrap();
+            }
+
+            prop_assert_eq!(
+                keys_1::result(&arc_process, tree),
+                Ok(arc_process.list_from_slice(&sorted_key_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Whether `key` is in `tree`
+#[native_implemented::function(gb_trees:is_defined/2)]
+pub fn result(key: Term, tree: Term) -> exception::Result<Term> {
+    let is_defined = super::lookup(key, tree)?.is_some();
+
+    Ok(is_defined.into())
+}
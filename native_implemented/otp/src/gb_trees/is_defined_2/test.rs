@@ -0,0 +1,38 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::is_defined_2::result;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    assert_badarg!(
+        result(atom!("key"), atom!("tree")),
+        "tree (tree) is not a tree"
+    );
+}
+
+#[test]
+fn with_key_returns_true() {
+    with_process(|process| {
+        let tree = insert_3::result(
+            process,
+            atom!("a"),
+            process.integer(1),
+            empty_0::result(process),
+        )
+        .unwrap();
+
+        assert_eq!(result(atom!("a"), tree), Ok(true.into()));
+    });
+}
+
+#[test]
+fn without_key_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            result(atom!("a"), empty_0::result(process)),
+            Ok(false.into())
+        );
+    });
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The keys in `tree`, in order
+#[native_implemented::function(gb_trees:keys/1)]
+pub fn result(process: &Process, tree: Term) -> exception::Result<Term> {
+    let key_vec: Vec<Term> = super::to_vec(tree)?
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+
+    Ok(process.list_from_slice(&key_vec))
+}
@@ -0,0 +1,26 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::keys_1::result;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("tree")), "tree (tree) is not a tree");
+    });
+}
+
+#[test]
+fn returns_keys_in_key_order() {
+    with_process(|process| {
+        let mut tree = empty_0::result(process);
+        tree = insert_3::result(process, atom!("b"), process.integer(2), tree).unwrap();
+        tree = insert_3::result(process, atom!("a"), process.integer(1), tree).unwrap();
+
+        assert_eq!(
+            result(process, tree),
+            Ok(process.list_from_slice(&[atom!("a"), atom!("b")]))
+        );
+    });
+}
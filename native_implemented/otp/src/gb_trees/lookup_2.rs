@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `{value, Value}` for `key` in `tree`, or `none`
+#[native_implemented::function(gb_trees:lookup/2)]
+pub fn result(process: &Process, key: Term, tree: Term) -> exception::Result<Term> {
+    let lookup = match super::lookup(key, tree)? {
+        Some(node) => process.tuple_from_slice(&[atom!("value"), node.value.unwrap()]),
+        None => atom!("none"),
+    };
+
+    Ok(lookup)
+}
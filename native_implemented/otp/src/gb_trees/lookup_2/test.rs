@@ -0,0 +1,63 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::lookup_2::result;
+use crate::gb_trees::{empty_0, enter_3};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("key"), atom!("tree")),
+            "tree (tree) is not a tree"
+        );
+    });
+}
+
+#[test]
+fn with_malformed_node_errors_badarg() {
+    with_process(|process| {
+        let tree = process.tuple_from_slice(&[process.integer(1), atom!("node")]);
+
+        assert_badarg!(
+            result(process, atom!("key"), tree),
+            format!("tree ({}) is not a tree", tree)
+        );
+    });
+}
+
+#[test]
+fn with_key_returns_value() {
+    with_process(|process| {
+        let mut tree = empty_0::result(process);
+
+        for i in 0..10 {
+            tree = enter_3::result(process, process.integer(i), process.integer(i * 10), tree)
+                .unwrap();
+        }
+
+        assert_eq!(
+            result(process, process.integer(7), tree),
+            Ok(process.tuple_from_slice(&[atom!("value"), process.integer(70)]))
+        );
+        assert_eq!(
+            result(process, process.float(7.0), tree),
+            Ok(process.tuple_from_slice(&[atom!("value"), process.integer(70)]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_none() {
+    with_process(|process| {
+        let tree = enter_3::result(
+            process,
+            atom!("a"),
+            process.integer(1),
+            empty_0::result(process),
+        )
+        .unwrap();
+
+        assert_eq!(result(process, atom!("b"), tree), Ok(atom!("none")));
+    });
+}
@@ -0,0 +1,29 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::size_1::result;
+use crate::gb_trees::{empty_0, from_orddict_1};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("tree")), "tree (tree) is not a tree");
+    });
+}
+
+#[test]
+fn returns_number_of_keys() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[atom!("a"), process.integer(1)]),
+            process.tuple_from_slice(&[atom!("b"), process.integer(2)]),
+        ]);
+        let tree = from_orddict_1::result(process, orddict).unwrap();
+
+        assert_eq!(
+            result(process, empty_0::result(process)),
+            Ok(process.integer(0))
+        );
+        assert_eq!(result(process, tree), Ok(process.integer(2)));
+    });
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The `{Key, Value}` pairs in `tree`, in key order
+#[native_implemented::function(gb_trees:to_list/1)]
+pub fn result(process: &Process, tree: Term) -> exception::Result<Term> {
+    let pair_vec: Vec<Term> = super::to_vec(tree)?
+        .into_iter()
+        .map(|(key, value)| process.tuple_from_slice(&[key, value]))
+        .collect();
+
+    Ok(process.list_from_slice(&pair_vec))
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gb_trees::to_list_1::result;
+use crate::gb_trees::{empty_0, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn without_tree_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("tree")), "tree (tree) is not a tree");
+    });
+}
+
+#[test]
+fn returns_pairs_in_key_order() {
+    with_process(|process| {
+        let mut tree = empty_0::result(process);
+
+        for key in &[atom!("b"), atom!("c"), atom!("a")] {
+            tree = insert_3::result(process, *key, process.integer(1), tree).unwrap();
+        }
+
+        assert_eq!(result(process, empty_0::result(process)), Ok(Term::NIL));
+        assert_eq!(
+            result(process, tree),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[atom!("a"), process.integer(1)]),
+                process.tuple_from_slice(&[atom!("b"), process.integer(1)]),
+                process.tuple_from_slice(&[atom!("c"), process.integer(1)]),
+            ]))
+        );
+    });
+}
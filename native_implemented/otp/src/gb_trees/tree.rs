@@ -0,0 +1,343 @@
+//! The balanced trees shared by `gb_trees` and `gb_sets`
+//!
+//! Nodes are `{Key, Value, Smaller, Bigger}` in `gb_trees` and `{Key, Smaller, Bigger}` in
+//! `gb_sets`, and empty trees are `nil`.  Insertion rebalances the same way as OTP, so the trees
+//! built here have the same shape as those built on the BEAM.
+
+use std::cmp::Ordering;
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[derive(Clone, Copy)]
+pub enum Shape {
+    /// `gb_trees` nodes, which have values
+    Tree,
+    /// `gb_sets` nodes, which only have keys
+    Set,
+}
+impl Shape {
+    pub fn nil() -> Term {
+        atom!("nil")
+    }
+
+    /// The node with `key`, if any
+    pub fn lookup(self, key: Term, tree: Term) -> Result<Option<Node>> {
+        let mut tree = tree;
+
+        while let Some(node) = self.try_into_node(tree)? {
+            match key.cmp(&node.key) {
+                Ordering::Less => tree = node.smaller,
+                Ordering::Equal => return Ok(Some(node)),
+                Ordering::Greater => tree = node.bigger,
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Inserts `key`, which must not be in `tree`, where `size` is the size after the insertion.
+    ///
+    /// Like OTP, a path is allowed to be twice as long as in a perfectly balanced tree before the
+    /// smallest subtree that is too deep is rebalanced.
+    pub fn insert(
+        self,
+        process: &Process,
+        key: Term,
+        value: Option<Term>,
+        tree: Term,
+        size: usize,
+    ) -> Result<Term> {
+        let tree = match self.insert_1(process, key, value, tree, (size as u128).pow(2))? {
+            Inserted::Tree(tree) => tree,
+            Inserted::Counted { tree, .. } => tree,
+        };
+
+        Ok(tree)
+    }
+
+    fn insert_1(
+        self,
+        process: &Process,
+        key: Term,
+        value: Option<Term>,
+        tree: Term,
+        budget: u128,
+    ) -> Result<Inserted> {
+        let inserted = match self.try_into_node(tree)? {
+            None => {
+                let leaf = Node::leaf(key, value).to_term(process);
+
+                if budget == 0 {
+                    Inserted::Counted {
+                        tree: leaf,
+                        height: 1,
+                        size: 1,
+                    }
+                } else {
+                    Inserted::Tree(leaf)
+                }
+            }
+            Some(node) => {
+                let (subtree, other_subtree) = match key.cmp(&node.key) {
+                    Ordering::Less => (node.smaller, node.bigger),
+                    Ordering::Equal => unreachable!("key ({}) is already in tree", key),
+                    Ordering::Greater => (node.bigger, node.smaller),
+                };
+                let with_subtree = |subtree| match key.cmp(&node.key) {
+                    Ordering::Less => Node {
+                        smaller: subtree,
+                        ..node
+                    },
+                    _ => Node {
+                        bigger: subtree,
+                        ..node
+                    },
+                };
+
+                match self.insert_1(process, key, value, subtree, budget >> 1)? {
+                    Inserted::Tree(subtree) => {
+                        Inserted::Tree(with_subtree(subtree).to_term(process))
+                    }
+                    Inserted::Counted {
+                        tree: subtree,
+                        height: subtree_height,
+                        size: subtree_size,
+                    } => {
+                        let node = with_subtree(subtree);
+                        let (other_height, other_size) = self.count(other_subtree)?;
+                        let height = subtree_height.max(other_height) << 1;
+                        let size = subtree_size + other_size + 1;
+
+                        if (size as u128).pow(2) < height {
+                            Inserted::Tree(self.balance(process, node, size)?)
+                        } else {
+                            Inserted::Counted {
+                                tree: node.to_term(process),
+                                height,
+                                size,
+                            }
+                        }
+                    }
+                }
+            }
+        };
+
+        Ok(inserted)
+    }
+
+    /// `2^height` and the size of `tree`
+    fn count(self, tree: Term) -> Result<(u128, usize)> {
+        match self.try_into_node(tree)? {
+            None => Ok((1, 0)),
+            Some(node) => {
+                if node.smaller == Self::nil() && node.bigger == Self::nil() {
+                    Ok((1, 1))
+                } else {
+                    let (smaller_height, smaller_size) = self.count(node.smaller)?;
+                    let (bigger_height, bigger_size) = self.count(node.bigger)?;
+
+                    Ok((
+                        smaller_height.max(bigger_height) << 1,
+                        smaller_size + bigger_size + 1,
+                    ))
+                }
+            }
+        }
+    }
+
+    fn balance(self, process: &Process, node: Node, size: usize) -> Result<Term> {
+        let mut entry_vec = self.to_vec(node.smaller)?;
+        entry_vec.push((node.key, node.value));
+        entry_vec.extend(self.to_vec(node.bigger)?);
+        debug_assert_eq!(entry_vec.len(), size);
+
+        Ok(Self::balance_list(process, &entry_vec))
+    }
+
+    /// A perfectly balanced tree of `entries`, which must be sorted by key
+    pub fn balance_list(process: &Process, entries: &[(Term, Option<Term>)]) -> Term {
+        match entries.len() {
+            0 => Self::nil(),
+            len => {
+                let smaller_len = len - 1 - (len - 1) / 2;
+                let (smaller_entries, rest) = entries.split_at(smaller_len);
+                let ((key, value), bigger_entries) = rest.split_first().unwrap();
+
+                Node {
+                    key: *key,
+                    value: *value,
+                    smaller: Self::balance_list(process, smaller_entries),
+                    bigger: Self::balance_list(process, bigger_entries),
+                }
+                .to_term(process)
+            }
+        }
+    }
+
+    /// Removes `key`, which must be in `tree`
+    pub fn delete(self, process: &Process, key: Term, tree: Term) -> Result<Term> {
+        let node = self.try_into_node(tree)?.unwrap();
+
+        let deleted = match key.cmp(&node.key) {
+            Ordering::Less => Node {
+                smaller: self.delete(process, key, node.smaller)?,
+                ..node
+            }
+            .to_term(process),
+            Ordering::Equal => self.merge(process, node.smaller, node.bigger)?,
+            Ordering::Greater => Node {
+                bigger: self.delete(process, key, node.bigger)?,
+                ..node
+            }
+            .to_term(process),
+        };
+
+        Ok(deleted)
+    }
+
+    /// Joins `smaller` and `bigger`, whose keys are all less than those in `bigger`, by moving the
+    /// smallest node of `bigger` up to be the root
+    fn merge(self, process: &Process, smaller: Term, bigger: Term) -> Result<Term> {
+        if bigger == Self::nil() {
+            Ok(smaller)
+        } else if smaller == Self::nil() {
+            Ok(bigger)
+        } else {
+            let (key, value, bigger) = self.take_smallest(process, bigger)?;
+
+            Ok(Node {
+                key,
+                value,
+                smaller,
+                bigger,
+            }
+            .to_term(process))
+        }
+    }
+
+    fn take_smallest(self, process: &Process, tree: Term) -> Result<(Term, Option<Term>, Term)> {
+        let node = self.try_into_node(tree)?.unwrap();
+
+        if node.smaller == Self::nil() {
+            Ok((node.key, node.value, node.bigger))
+        } else {
+            let (key, value, smaller) = self.take_smallest(process, node.smaller)?;
+
+            Ok((key, value, Node { smaller, ..node }.to_term(process)))
+        }
+    }
+
+    /// Replaces the value of `key`, which must be in `tree`
+    pub fn update(self, process: &Process, key: Term, value: Term, tree: Term) -> Result<Term> {
+        let node = self.try_into_node(tree)?.unwrap();
+
+        let updated = match key.cmp(&node.key) {
+            Ordering::Less => Node {
+                smaller: self.update(process, key, value, node.smaller)?,
+                ..node
+            },
+            Ordering::Equal => Node {
+                value: Some(value),
+                ..node
+            },
+            Ordering::Greater => Node {
+                bigger: self.update(process, key, value, node.bigger)?,
+                ..node
+            },
+        };
+
+        Ok(updated.to_term(process))
+    }
+
+    /// The keys and values of `tree`, in key order
+    pub fn to_vec(self, tree: Term) -> Result<Vec<(Term, Option<Term>)>> {
+        let mut entry_vec = Vec::new();
+        let mut stack = Vec::new();
+        let mut tree = tree;
+
+        loop {
+            match self.try_into_node(tree)? {
+                Some(node) => {
+                    stack.push(node);
+                    tree = node.smaller;
+                }
+                None => match stack.pop() {
+                    Some(node) => {
+                        entry_vec.push((node.key, node.value));
+                        tree = node.bigger;
+                    }
+                    None => break,
+                },
+            }
+        }
+
+        Ok(entry_vec)
+    }
+
+    fn try_into_node(self, tree: Term) -> Result<Option<Node>> {
+        if tree == Self::nil() {
+            return Ok(None);
+        }
+
+        let tuple: Boxed<Tuple> = tree.try_into().map_err(|_| TypeError)?;
+
+        match (self, tuple.len()) {
+            (Shape::Tree, 4) => Ok(Some(Node {
+                key: tuple[0],
+                value: Some(tuple[1]),
+                smaller: tuple[2],
+                bigger: tuple[3],
+            })),
+            (Shape::Set, 3) => Ok(Some(Node {
+                key: tuple[0],
+                value: None,
+                smaller: tuple[1],
+                bigger: tuple[2],
+            })),
+            _ => Err(TypeError.into()),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Node {
+    pub key: Term,
+    /// `None` for `gb_sets` nodes
+    pub value: Option<Term>,
+    smaller: Term,
+    bigger: Term,
+}
+impl Node {
+    fn leaf(key: Term, value: Option<Term>) -> Self {
+        Self {
+            key,
+            value,
+            smaller: Shape::nil(),
+            bigger: Shape::nil(),
+        }
+    }
+
+    fn to_term(&self, process: &Process) -> Term {
+        match self.value {
+            Some(value) => process.tuple_from_slice(&[self.key, value, self.smaller, self.bigger]),
+            None => process.tuple_from_slice(&[self.key, self.smaller, self.bigger]),
+        }
+    }
+}
+
+/// The result of inserting into a subtree.  While a subtree is deeper than its share of the
+/// depth allowed for the whole tree, its `height` and `size` are counted so that the first
+/// ancestor that is too deep for its size can be rebalanced.
+enum Inserted {
+    Tree(Term),
+    Counted {
+        tree: Term,
+        height: u128,
+        size: usize,
+    },
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `tree` with the value of `key`, which must be in `tree`, replaced by `value`
+#[native_implemented::function(gb_trees:update/3)]
+pub fn result(process: &Process, key: Term, value: Term, tree: Term) -> exception::Result<Term> {
+    match super::lookup(key, tree)? {
+        Some(_) => super::update(process, key, value, tree),
+        None => Err(super::key_not_in_tree(key, tree)),
+    }
+}
@@ -0,0 +1,33 @@
+use liblumen_alloc::atom;
+
+use crate::gb_trees::update_3::result;
+use crate::gb_trees::{empty_0, get_2, insert_3};
+use crate::test::with_process;
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let tree = empty_0::result(process);
+
+        assert_badarg!(
+            result(process, atom!("key"), atom!("value"), tree),
+            format!("key (key) is not in tree ({})", tree)
+        );
+    });
+}
+
+#[test]
+fn with_key_replaces_value() {
+    with_process(|process| {
+        let mut tree = empty_0::result(process);
+
+        for i in 0..10 {
+            tree = insert_3::result(process, process.integer(i), atom!("old"), tree).unwrap();
+        }
+
+        let updated = result(process, process.integer(3), atom!("new"), tree).unwrap();
+
+        assert_eq!(get_2::result(process.integer(3), updated), Ok(atom!("new")));
+        assert_eq!(get_2::result(process.integer(4), updated), Ok(atom!("old")));
+    });
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The values in `tree`, in the order of their keys
+#[native_implemented::function(gb_trees:values/1)]
+pub fn result(process: &Process, tree: Term) -> exception::Result<Term> {
+    let value_vec: Vec<Term> = super::to_vec(tree)?
+        .into_iter()
+        .map(|(_, value)| value)
+        .collect();
+
+    Ok(process.list_from_slice(&value_vec))
+}
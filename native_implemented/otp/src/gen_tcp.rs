@@ -0,0 +1,84 @@
+//! Mirrors [gen_tcp](http://erlang.org/doc/man/gen_tcp.html) module
+//!
+//! The socket operations that wait for a peer run as futures on the `task` pool, so that they do
+//! not block the scheduler, while the calling process waits for their reply.
+
+pub mod accept_1;
+pub mod accept_2;
+pub mod close_1;
+pub mod connect_3;
+pub mod connect_4;
+pub mod controlling_process_2;
+pub mod listen_2;
+pub mod recv_2;
+pub mod recv_3;
+pub mod send_2;
+
+use std::convert::TryInto;
+use std::time::Duration;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Frame, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::inet::{spawn, term_try_into_address, term_try_into_port_number, term_try_into_socket};
+use crate::runtime::inet::{self, tcp};
+
+fn module() -> Atom {
+    Atom::from_str("gen_tcp")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+fn accept(
+    process: &Process,
+    label_frame: Frame,
+    listen_socket: Term,
+    timeout: Option<Duration>,
+) -> exception::Result<Term> {
+    let listen_socket_port = term_try_into_socket("listen_socket", listen_socket)?;
+    let owner = process.pid();
+
+    Ok(spawn(process, label_frame, move || {
+        tcp::accept(owner, listen_socket_port, timeout)
+    }))
+}
+
+fn connect(
+    process: &Process,
+    label_frame: Frame,
+    address: Term,
+    port: Term,
+    options: Term,
+    timeout: Option<Duration>,
+) -> exception::Result<Term> {
+    let address = term_try_into_address(process, address)?;
+    let port_number = term_try_into_port_number(port)?;
+    let options: inet::Options = options.try_into()?;
+    let owner = process.pid();
+
+    Ok(spawn(process, label_frame, move || {
+        tcp::connect(owner, &address, port_number, options, timeout)
+    }))
+}
+
+fn recv(
+    process: &Process,
+    label_frame: Frame,
+    socket: Term,
+    length: Term,
+    timeout: Option<Duration>,
+) -> exception::Result<Term> {
+    let socket_port = term_try_into_socket("socket", socket)?;
+    let len: usize = length
+        .try_into()
+        .with_context(|| format!("length ({}) is not a non-negative integer", length))?;
+
+    Ok(spawn(process, label_frame, move || {
+        tcp::recv(socket_port, len, timeout)
+    }))
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Socket}` for the next connection to `listen_socket`, or `{error, Reason}`.
+#[native_implemented::function(gen_tcp:accept/1)]
+pub fn result(process: &Process, listen_socket: Term) -> exception::Result<Term> {
+    super::accept(process, label_1::frame(), listen_socket, None)
+}
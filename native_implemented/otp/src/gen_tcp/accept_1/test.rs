@@ -0,0 +1,12 @@
+use crate::gen_tcp::accept_1;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            accept_1::result(process, process.integer(1)),
+            "listen_socket (1) is not a socket"
+        );
+    });
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Socket}` for the next connection to `listen_socket`, or `{error, timeout}` if
+/// there is none within `timeout` milliseconds.
+#[native_implemented::function(gen_tcp:accept/2)]
+pub fn result(process: &Process, listen_socket: Term, timeout: Term) -> exception::Result<Term> {
+    let timeout = crate::inet::term_try_into_timeout(timeout)?;
+
+    super::accept(process, label_1::frame(), listen_socket, timeout)
+}
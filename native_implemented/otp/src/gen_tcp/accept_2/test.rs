@@ -0,0 +1,15 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gen_tcp::accept_2;
+use crate::test::with_process;
+
+#[test]
+fn without_infinity_or_non_negative_integer_timeout_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            accept_2::result(process, Port::next_term(), atom!("never")),
+            "timeout (never) is not infinity or a non-negative integer"
+        );
+    });
+}
@@ -0,0 +1,19 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Socket}` connected to `port` on `address`, or `{error, Reason}`.
+#[native_implemented::function(gen_tcp:connect/3)]
+pub fn result(
+    process: &Process,
+    address: Term,
+    port: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    super::connect(process, label_1::frame(), address, port, options, None)
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::atom;
+
+use crate::gen_tcp::connect_3;
+use crate::test::with_process;
+
+#[test]
+fn without_address_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_3::result(
+                process,
+                process.integer(1),
+                process.integer(80),
+                process.list_from_slice(&[])
+            ),
+            "address (1) is not a hostname atom, string, or binary, or an IP address tuple"
+        );
+    });
+}
+
+#[test]
+fn with_out_of_range_port_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_3::result(
+                process,
+                atom!("localhost"),
+                process.integer(65536),
+                process.list_from_slice(&[])
+            ),
+            "port (65536) is not a port number (0-65535)"
+        );
+    });
+}
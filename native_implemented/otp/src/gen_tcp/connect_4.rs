@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Socket}` connected to `port` on `address`, or `{error, timeout}` if that takes
+/// longer than `timeout` milliseconds.
+#[native_implemented::function(gen_tcp:connect/4)]
+pub fn result(
+    process: &Process,
+    address: Term,
+    port: Term,
+    options: Term,
+    timeout: Term,
+) -> exception::Result<Term> {
+    let timeout = crate::inet::term_try_into_timeout(timeout)?;
+
+    super::connect(process, label_1::frame(), address, port, options, timeout)
+}
@@ -0,0 +1,20 @@
+use liblumen_alloc::atom;
+
+use crate::gen_tcp::connect_4;
+use crate::test::with_process;
+
+#[test]
+fn without_supported_option_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_4::result(
+                process,
+                atom!("localhost"),
+                process.integer(80),
+                process.list_from_slice(&[atom!("nodelay")]),
+                atom!("infinity")
+            ),
+            "supported options are"
+        );
+    });
+}
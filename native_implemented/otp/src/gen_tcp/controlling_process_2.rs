@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet;
+
+/// Returns `ok` after making `pid` the owner of `socket`, which then receives its messages, or
+/// `{error, Reason}`.  Only the owner can give the socket away, such as an acceptor handing an
+/// accepted socket to a worker.
+#[native_implemented::function(gen_tcp:controlling_process/2)]
+pub fn result(process: &Process, socket: Term, pid: Term) -> exception::Result<Term> {
+    let socket_port = crate::inet::term_try_into_socket("socket", socket)?;
+    let pid_pid = term_try_into_local_pid!(pid)?;
+
+    Ok(crate::inet::reply_to_term(
+        process,
+        inet::controlling_process(process.pid(), socket_port, pid_pid),
+    ))
+}
@@ -0,0 +1,35 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gen_tcp::controlling_process_2;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            controlling_process_2::result(process, process.integer(1), process.pid_term()),
+            "socket (1) is not a socket"
+        );
+    });
+}
+
+#[test]
+fn without_pid_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            controlling_process_2::result(process, Port::next_term(), process.integer(1)),
+            "pid (1) is not a pid"
+        );
+    });
+}
+
+#[test]
+fn with_closed_socket_returns_closed_error() {
+    with_process(|process| {
+        assert_eq!(
+            controlling_process_2::result(process, Port::next_term(), process.pid_term()),
+            Ok(process.tuple_from_slice(&[atom!("error"), Atom::str_to_term("closed")]))
+        );
+    });
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet::{self, tcp};
+
+/// Returns `{ok, ListenSocket}` listening on `port` on all interfaces, or `{error, Reason}`.
+/// Binding does not wait for a peer, so it is done by the calling process.
+#[native_implemented::function(gen_tcp:listen/2)]
+pub fn result(process: &Process, port: Term, options: Term) -> exception::Result<Term> {
+    let port_number = crate::inet::term_try_into_port_number(port)?;
+    let options: inet::Options = options.try_into()?;
+
+    Ok(crate::inet::reply_to_term(
+        process,
+        tcp::listen(process.pid(), port_number, options),
+    ))
+}
@@ -0,0 +1,12 @@
+use crate::gen_tcp::listen_2;
+use crate::test::with_process;
+
+#[test]
+fn without_port_number_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            listen_2::result(process, process.integer(-1), process.list_from_slice(&[])),
+            "port (-1) is not a port number (0-65535)"
+        );
+    });
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Packet}` with exactly `length` bytes from the passive `socket`, or whatever is
+/// available if `length` is `0`, or `{error, Reason}`.
+#[native_implemented::function(gen_tcp:recv/2)]
+pub fn result(process: &Process, socket: Term, length: Term) -> exception::Result<Term> {
+    super::recv(process, label_1::frame(), socket, length, None)
+}
@@ -0,0 +1,14 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gen_tcp::recv_2;
+use crate::test::with_process;
+
+#[test]
+fn without_non_negative_integer_length_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            recv_2::result(process, Port::next_term(), process.integer(-1)),
+            "length (-1) is not a non-negative integer"
+        );
+    });
+}
@@ -0,0 +1,17 @@
+use crate::gen_tcp::recv_3;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            recv_3::result(
+                process,
+                process.integer(1),
+                process.integer(0),
+                process.integer(100)
+            ),
+            "socket (1) is not a socket"
+        );
+    });
+}
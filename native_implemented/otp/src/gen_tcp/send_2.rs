@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::inet::tcp;
+
+/// Returns `ok` once all of `packet` is written to `socket`, or `{error, Reason}`.
+#[native_implemented::function(gen_tcp:send/2)]
+pub fn result(process: &Process, socket: Term, packet: Term) -> exception::Result<Term> {
+    let socket_port = crate::inet::term_try_into_socket("socket", socket)?;
+    let bytes = iolist_or_binary::to_byte_vec("packet", packet)?;
+
+    Ok(crate::inet::spawn(process, label_1::frame(), move || {
+        tcp::send(socket_port, &bytes)
+    }))
+}
@@ -0,0 +1,58 @@
+//! Mirrors [gen_udp](http://erlang.org/doc/man/gen_udp.html) module
+//!
+//! Sending and receiving run as futures on the `task` pool, so that they do not block the
+//! scheduler, while the calling process waits for their reply.
+
+pub mod close_1;
+pub mod open_1;
+pub mod open_2;
+pub mod recv_2;
+pub mod recv_3;
+pub mod send_4;
+
+use std::convert::TryInto;
+use std::time::Duration;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Frame, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::inet::{reply_to_term, spawn, term_try_into_port_number, term_try_into_socket};
+use crate::runtime::inet::{self, udp};
+
+fn module() -> Atom {
+    Atom::from_str("gen_udp")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Opening does not wait for a peer, so it is done by the calling process.
+fn open(process: &Process, port: Term, options: inet::Options) -> exception::Result<Term> {
+    let port_number = term_try_into_port_number(port)?;
+
+    Ok(reply_to_term(
+        process,
+        udp::open(process.pid(), port_number, options),
+    ))
+}
+
+fn recv(
+    process: &Process,
+    label_frame: Frame,
+    socket: Term,
+    length: Term,
+    timeout: Option<Duration>,
+) -> exception::Result<Term> {
+    let socket_port = term_try_into_socket("socket", socket)?;
+    let len: usize = length
+        .try_into()
+        .with_context(|| format!("length ({}) is not a non-negative integer", length))?;
+
+    Ok(spawn(process, label_frame, move || {
+        udp::recv(socket_port, len, timeout)
+    }))
+}
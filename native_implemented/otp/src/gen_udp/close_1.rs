@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet;
+
+/// Returns `ok`, even if `socket` is already closed.
+#[native_implemented::function(gen_udp:close/1)]
+pub fn result(socket: Term) -> exception::Result<Term> {
+    let socket_port = crate::inet::term_try_into_socket("socket", socket)?;
+    inet::close(socket_port);
+
+    Ok(atom!("ok"))
+}
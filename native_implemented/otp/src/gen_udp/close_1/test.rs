@@ -0,0 +1,20 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gen_udp::close_1;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            close_1::result(process.integer(1)),
+            "socket (1) is not a socket"
+        );
+    });
+}
+
+#[test]
+fn with_closed_socket_returns_ok() {
+    assert_eq!(close_1::result(Port::next_term()), Ok(atom!("ok")));
+}
@@ -0,0 +1,12 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Socket}` for an active socket on `port` on all interfaces, or `{error, Reason}`.
+#[native_implemented::function(gen_udp:open/1)]
+pub fn result(process: &Process, port: Term) -> exception::Result<Term> {
+    super::open(process, port, Default::default())
+}
@@ -0,0 +1,12 @@
+use crate::gen_udp::open_1;
+use crate::test::with_process;
+
+#[test]
+fn without_port_number_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            open_1::result(process, process.integer(65536)),
+            "port (65536) is not a port number (0-65535)"
+        );
+    });
+}
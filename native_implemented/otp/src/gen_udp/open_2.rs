@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Socket}` for a socket on `port` on all interfaces, or `{error, Reason}`.
+#[native_implemented::function(gen_udp:open/2)]
+pub fn result(process: &Process, port: Term, options: Term) -> exception::Result<Term> {
+    let options = options.try_into()?;
+
+    super::open(process, port, options)
+}
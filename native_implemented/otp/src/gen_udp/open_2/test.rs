@@ -0,0 +1,18 @@
+use liblumen_alloc::atom;
+
+use crate::gen_udp::open_2;
+use crate::test::with_process;
+
+#[test]
+fn without_supported_option_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            open_2::result(
+                process,
+                process.integer(0),
+                process.list_from_slice(&[atom!("nodelay")])
+            ),
+            "supported options are"
+        );
+    });
+}
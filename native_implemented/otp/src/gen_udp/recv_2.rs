@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, {Address, Port, Packet}}` with the next datagram received by the passive
+/// `socket`, truncated to `length` bytes unless `length` is `0`, or `{error, Reason}`.
+#[native_implemented::function(gen_udp:recv/2)]
+pub fn result(process: &Process, socket: Term, length: Term) -> exception::Result<Term> {
+    super::recv(process, label_1::frame(), socket, length, None)
+}
@@ -0,0 +1,12 @@
+use crate::gen_udp::recv_2;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            recv_2::result(process, process.integer(1), process.integer(0)),
+            "socket (1) is not a socket"
+        );
+    });
+}
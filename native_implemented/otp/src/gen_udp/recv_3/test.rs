@@ -0,0 +1,20 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gen_udp::recv_3;
+use crate::test::with_process;
+
+#[test]
+fn without_infinity_or_non_negative_integer_timeout_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            recv_3::result(
+                process,
+                Port::next_term(),
+                process.integer(0),
+                atom!("never")
+            ),
+            "timeout (never) is not infinity or a non-negative integer"
+        );
+    });
+}
@@ -0,0 +1,32 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::inet::{spawn, term_try_into_address, term_try_into_port_number, term_try_into_socket};
+use crate::runtime::inet::udp;
+
+/// Returns `ok` once `packet` is sent as one datagram from `socket` to `port` on `address`, or
+/// `{error, Reason}`.
+#[native_implemented::function(gen_udp:send/4)]
+pub fn result(
+    process: &Process,
+    socket: Term,
+    address: Term,
+    port: Term,
+    packet: Term,
+) -> exception::Result<Term> {
+    let socket_port = term_try_into_socket("socket", socket)?;
+    let address = term_try_into_address(process, address)?;
+    let port_number = term_try_into_port_number(port)?;
+    let bytes = iolist_or_binary::to_byte_vec("packet", packet)?;
+
+    Ok(spawn(process, label_1::frame(), move || {
+        udp::send(socket_port, &address, port_number, &bytes)
+    }))
+}
@@ -0,0 +1,20 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::gen_udp::send_4;
+use crate::test::with_process;
+
+#[test]
+fn without_address_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            send_4::result(
+                process,
+                Port::next_term(),
+                process.integer(1),
+                process.integer(53),
+                process.binary_from_str("hi")
+            ),
+            "address (1) is not a hostname atom, string, or binary, or an IP address tuple"
+        );
+    });
+}
@@ -0,0 +1,239 @@
+//! Mirrors [inet](http://erlang.org/doc/man/inet.html) module
+//!
+//! Also holds what the sockets of [gen_tcp](http://erlang.org/doc/man/gen_tcp.html) and
+//! [gen_udp](http://erlang.org/doc/man/gen_udp.html) share, since they are built on `inet` like in
+//! OTP.
+//!
+//! The socket operations that wait for a peer and the name resolution that waits for DNS run as
+//! futures on the `task` pool, so that they do not block the scheduler, while the calling process
+//! waits for their reply.
+
+pub mod getaddr_2;
+pub mod gethostbyname_1;
+pub mod getifaddrs_0;
+pub mod ntoa_1;
+pub mod parse_address_1;
+pub mod setopts_2;
+
+use std::convert::TryInto;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::time::Duration;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Frame, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::dirty_io::Output;
+use crate::runtime::inet::{bytes_to_term, ip_addr_to_term, Address, HostEnt, Reply};
+use crate::runtime::task;
+use crate::unicode::characters;
+
+fn module() -> Atom {
+    Atom::from_str("inet")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Runs `job` as a future on the `task` pool and queues `label_frame` to return its reply.
+pub(crate) fn spawn<F>(process: &Process, label_frame: Frame, job: F) -> Term
+where
+    F: FnOnce() -> Reply + Send + 'static,
+{
+    let output = task::spawn_output(process, task::blocking(job));
+    let output_term = process.resource(output);
+    process.queue_frame_with_arguments(label_frame.with_arguments(false, &[output_term]));
+
+    Term::NONE
+}
+
+/// Returns the reply in `output` if the job is done, otherwise waits for it by queueing
+/// `label_frame` again.
+pub(crate) fn await_reply(process: &Process, output: Term, label_frame: Frame) -> Term {
+    let reply = match output.decode().unwrap() {
+        TypedTerm::ResourceReference(resource_reference) => {
+            let resource: Resource = resource_reference.into();
+            let output = resource.downcast_ref::<Output<Reply>>().unwrap();
+
+            output.take_or_wait(process)
+        }
+        _ => unreachable!("output ({}) is not a resource", output),
+    };
+
+    match reply {
+        Some(reply) => reply_to_term(process, reply),
+        None => {
+            process.queue_frame_with_arguments(label_frame.with_arguments(false, &[output]));
+
+            Term::NONE
+        }
+    }
+}
+
+pub(crate) fn reply_to_term(process: &Process, reply: Reply) -> Term {
+    match reply {
+        Reply::Ok => atom!("ok"),
+        Reply::Socket(port) => ok_tuple(process, port.encode().unwrap()),
+        Reply::Data { bytes, binary } => ok_tuple(process, bytes_to_term(process, &bytes, binary)),
+        Reply::Datagram {
+            ip_addr,
+            port,
+            bytes,
+            binary,
+        } => {
+            let packet = bytes_to_term(process, &bytes, binary);
+
+            ok_tuple(
+                process,
+                process.tuple_from_slice(&[
+                    ip_addr_to_term(process, ip_addr),
+                    process.integer(port as u64),
+                    packet,
+                ]),
+            )
+        }
+        Reply::Address(ip_addr) => ok_tuple(process, ip_addr_to_term(process, ip_addr)),
+        Reply::HostEnt(HostEnt {
+            name,
+            inet6,
+            ip_addrs,
+        }) => {
+            let (address_type, length): (Term, u8) = if inet6 {
+                (atom!("inet6"), 16)
+            } else {
+                (atom!("inet"), 4)
+            };
+            let address_vec: Vec<Term> = ip_addrs
+                .into_iter()
+                .map(|ip_addr| ip_addr_to_term(process, ip_addr))
+                .collect();
+
+            ok_tuple(
+                process,
+                process.tuple_from_slice(&[
+                    atom!("hostent"),
+                    process.charlist_from_str(&name),
+                    Term::NIL,
+                    address_type,
+                    process.integer(length),
+                    process.list_from_slice(&address_vec),
+                ]),
+            )
+        }
+        Reply::Error(reason) => error_tuple(process, reason),
+        Reply::TlsAlert { alert, description } => {
+            let alert_tuple = process.tuple_from_slice(&[
+                Atom::str_to_term(alert),
+                process.charlist_from_str(&description),
+            ]);
+            let reason = process.tuple_from_slice(&[atom!("tls_alert"), alert_tuple]);
+
+            process.tuple_from_slice(&[atom!("error"), reason])
+        }
+    }
+}
+
+fn error_tuple(process: &Process, reason: &str) -> Term {
+    process.tuple_from_slice(&[atom!("error"), Atom::str_to_term(reason)])
+}
+
+fn ok_tuple(process: &Process, value: Term) -> Term {
+    process.tuple_from_slice(&[atom!("ok"), value])
+}
+
+/// A hostname as an atom, string, or binary, or an IP address as a tuple.
+pub(crate) fn term_try_into_address(
+    process: &Process,
+    address: Term,
+) -> exception::Result<Address> {
+    match address.decode()? {
+        TypedTerm::Atom(atom) => Ok(Address::Hostname(atom.name().to_string())),
+        TypedTerm::Tuple(tuple) => tuple_try_into_ip_addr(&tuple)
+            .map(Address::Ip)
+            .with_context(|| address_is_not_address(address))
+            .map_err(From::from),
+        _ if address.is_list() || address.is_binary() => {
+            let bytes = characters::to_utf8_bytes(process, "address", address)?;
+
+            // `to_utf8_bytes` only returns valid UTF-8
+            Ok(Address::Hostname(String::from_utf8(bytes).unwrap()))
+        }
+        _ => Err(TypeError)
+            .with_context(|| address_is_not_address(address))
+            .map_err(From::from),
+    }
+}
+
+fn address_is_not_address(address: Term) -> String {
+    format!(
+        "address ({}) is not a hostname atom, string, or binary, or an IP address tuple",
+        address
+    )
+}
+
+/// `{A, B, C, D}` of octets or `{A, B, C, D, E, F, G, H}` of 16-bit segments, like
+/// `inet:ip_address()`.
+fn tuple_try_into_ip_addr(tuple: &Tuple) -> Option<IpAddr> {
+    match tuple.len() {
+        4 => {
+            let mut octets = [0u8; 4];
+
+            for (octet, element) in octets.iter_mut().zip(tuple.iter()) {
+                *octet = (*element).try_into().ok()?;
+            }
+
+            Some(IpAddr::V4(Ipv4Addr::from(octets)))
+        }
+        8 => {
+            let mut segments = [0u16; 8];
+
+            for (segment, element) in segments.iter_mut().zip(tuple.iter()) {
+                *segment = term_try_into_u16(*element)?;
+            }
+
+            Some(IpAddr::V6(Ipv6Addr::from(segments)))
+        }
+        _ => None,
+    }
+}
+
+pub(crate) fn term_try_into_port_number(port: Term) -> exception::Result<u16> {
+    term_try_into_u16(port)
+        .with_context(|| format!("port ({}) is not a port number (0-65535)", port))
+        .map_err(From::from)
+}
+
+pub(crate) fn term_try_into_socket(name: &str, socket: Term) -> exception::Result<Port> {
+    match socket.decode()? {
+        TypedTerm::Port(port) => Ok(port),
+        _ => Err(TypeError)
+            .with_context(|| format!("{} ({}) is not a socket", name, socket))
+            .map_err(From::from),
+    }
+}
+
+/// `infinity` or a timeout in milliseconds.
+pub(crate) fn term_try_into_timeout(timeout: Term) -> exception::Result<Option<Duration>> {
+    if timeout == atom!("infinity") {
+        Ok(None)
+    } else {
+        let milliseconds: u64 = timeout.try_into().with_context(|| {
+            format!(
+                "timeout ({}) is not infinity or a non-negative integer",
+                timeout
+            )
+        })?;
+
+        Ok(Some(Duration::from_millis(milliseconds)))
+    }
+}
+
+fn term_try_into_u16(term: Term) -> Option<u16> {
+    let u: u64 = term.try_into().ok()?;
+
+    u.try_into().ok()
+}
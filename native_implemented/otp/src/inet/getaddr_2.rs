@@ -0,0 +1,31 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet;
+
+/// Returns `{ok, IPAddress}` with the first address of `family` for `host`, resolving it if it is
+/// a hostname, or `{error, Reason}`.
+#[native_implemented::function(inet:getaddr/2)]
+pub fn result(process: &Process, host: Term, family: Term) -> exception::Result<Term> {
+    let address = super::term_try_into_address(process, host)?;
+    let inet6 = if family == atom!("inet") {
+        false
+    } else if family == atom!("inet6") {
+        true
+    } else {
+        return Err(anyhow!("family ({}) is not inet or inet6", family).into());
+    };
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        inet::getaddr(&address, inet6)
+    }))
+}
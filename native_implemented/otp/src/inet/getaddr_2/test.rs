@@ -0,0 +1,24 @@
+use liblumen_alloc::atom;
+
+use crate::inet::getaddr_2;
+use crate::test::with_process;
+
+#[test]
+fn without_address_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            getaddr_2::result(process, process.integer(1), atom!("inet")),
+            "address (1) is not a hostname atom, string, or binary, or an IP address tuple"
+        );
+    });
+}
+
+#[test]
+fn without_family_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            getaddr_2::result(process, atom!("localhost"), atom!("local")),
+            "family (local) is not inet or inet6"
+        );
+    });
+}
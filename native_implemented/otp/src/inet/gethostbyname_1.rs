@@ -0,0 +1,20 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet;
+
+/// Returns `{ok, Hostent}` with the IPv4 addresses of `name`, or `{error, Reason}`.
+#[native_implemented::function(inet:gethostbyname/1)]
+pub fn result(process: &Process, name: Term) -> exception::Result<Term> {
+    let address = super::term_try_into_address(process, name)?;
+
+    Ok(super::spawn(process, label_1::frame(), move || {
+        inet::gethostbyname(&address)
+    }))
+}
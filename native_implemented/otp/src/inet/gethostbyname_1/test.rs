@@ -0,0 +1,12 @@
+use crate::inet::gethostbyname_1;
+use crate::test::with_process;
+
+#[test]
+fn without_name_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            gethostbyname_1::result(process, process.integer(1)),
+            "address (1) is not a hostname atom, string, or binary, or an IP address tuple"
+        );
+    });
+}
@@ -0,0 +1,53 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet::{self, ip_addr_to_term, Interface};
+
+/// Returns `{ok, [{Ifname, [{flags, Flags} | {addr, Addr} | {netmask, Netmask}]}]}`, where each
+/// `addr` is followed by its `netmask`, or `{error, Reason}`.
+#[native_implemented::function(inet:getifaddrs/0)]
+pub fn result(process: &Process) -> exception::Result<Term> {
+    let term = match inet::interfaces() {
+        Ok(interface_vec) => {
+            let interface_term_vec: Vec<Term> = interface_vec
+                .into_iter()
+                .map(|interface| interface_to_term(process, interface))
+                .collect();
+
+            super::ok_tuple(process, process.list_from_slice(&interface_term_vec))
+        }
+        Err(reason) => super::error_tuple(process, reason),
+    };
+
+    Ok(term)
+}
+
+fn interface_to_term(process: &Process, interface: Interface) -> Term {
+    let flag_vec: Vec<Term> = interface
+        .flags
+        .iter()
+        .map(|flag| Atom::str_to_term(flag))
+        .collect();
+    let flags = process.list_from_slice(&flag_vec);
+    let mut option_vec = vec![process.tuple_from_slice(&[atom!("flags"), flags])];
+
+    for (ip_addr, netmask) in interface.addresses {
+        let addr = ip_addr_to_term(process, ip_addr);
+        option_vec.push(process.tuple_from_slice(&[atom!("addr"), addr]));
+
+        if let Some(netmask) = netmask {
+            let netmask = ip_addr_to_term(process, netmask);
+            option_vec.push(process.tuple_from_slice(&[atom!("netmask"), netmask]));
+        }
+    }
+
+    process.tuple_from_slice(&[
+        process.charlist_from_str(&interface.name),
+        process.list_from_slice(&option_vec),
+    ])
+}
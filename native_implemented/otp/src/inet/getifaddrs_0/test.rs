@@ -0,0 +1,19 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::inet::getifaddrs_0;
+use crate::test::with_process;
+
+#[test]
+fn returns_ok_tuple_with_list() {
+    with_process(|process| {
+        let result_term = getifaddrs_0::result(process).unwrap();
+        let result_tuple: Boxed<Tuple> = result_term.try_into().unwrap();
+
+        assert_eq!(result_tuple.len(), 2);
+        assert_eq!(result_tuple[0], atom!("ok"));
+        assert!(result_tuple[1].is_list());
+    });
+}
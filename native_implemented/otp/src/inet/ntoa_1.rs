@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the string of an IPv4 or IPv6 address tuple, otherwise `{error, einval}`.
+#[native_implemented::function(inet:ntoa/1)]
+pub fn result(process: &Process, ip_address: Term) -> exception::Result<Term> {
+    let option_ip_addr = match ip_address.decode()? {
+        TypedTerm::Tuple(tuple) => super::tuple_try_into_ip_addr(&tuple),
+        _ => None,
+    };
+
+    let term = match option_ip_addr {
+        Some(ip_addr) => process.charlist_from_str(&ip_addr.to_string()),
+        None => super::error_tuple(process, "einval"),
+    };
+
+    Ok(term)
+}
@@ -0,0 +1,50 @@
+use liblumen_alloc::atom;
+
+use crate::inet::ntoa_1;
+use crate::test::with_process;
+
+#[test]
+fn with_ipv4_address_returns_string() {
+    with_process(|process| {
+        assert_eq!(
+            ntoa_1::result(
+                process,
+                process.tuple_from_slice(&[
+                    process.integer(192),
+                    process.integer(168),
+                    process.integer(0),
+                    process.integer(1)
+                ])
+            ),
+            Ok(process.charlist_from_str("192.168.0.1"))
+        );
+    });
+}
+
+#[test]
+fn with_out_of_range_octet_returns_einval() {
+    with_process(|process| {
+        assert_eq!(
+            ntoa_1::result(
+                process,
+                process.tuple_from_slice(&[
+                    process.integer(256),
+                    process.integer(0),
+                    process.integer(0),
+                    process.integer(1)
+                ])
+            ),
+            Ok(process.tuple_from_slice(&[atom!("error"), atom!("einval")]))
+        );
+    });
+}
+
+#[test]
+fn without_tuple_returns_einval() {
+    with_process(|process| {
+        assert_eq!(
+            ntoa_1::result(process, process.charlist_from_str("127.0.0.1")),
+            Ok(process.tuple_from_slice(&[atom!("error"), atom!("einval")]))
+        );
+    });
+}
@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::net::IpAddr;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::runtime::inet::ip_addr_to_term;
+
+/// Returns `{ok, IPAddress}` for an IPv4 or IPv6 address string, otherwise `{error, einval}`.
+#[native_implemented::function(inet:parse_address/1)]
+pub fn result(process: &Process, address: Term) -> exception::Result<Term> {
+    let string = list_to_string(address)?;
+
+    let term = match string.parse::<IpAddr>() {
+        Ok(ip_addr) => super::ok_tuple(process, ip_addr_to_term(process, ip_addr)),
+        Err(_) => super::error_tuple(process, "einval"),
+    };
+
+    Ok(term)
+}
@@ -0,0 +1,66 @@
+use liblumen_alloc::atom;
+
+use crate::inet::parse_address_1;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            parse_address_1::result(process, atom!("localhost")),
+            "list (localhost) is not a list"
+        );
+    });
+}
+
+#[test]
+fn with_ipv4_address_returns_ok_tuple() {
+    with_process(|process| {
+        assert_eq!(
+            parse_address_1::result(process, process.charlist_from_str("127.0.0.1")),
+            Ok(process.tuple_from_slice(&[
+                atom!("ok"),
+                process.tuple_from_slice(&[
+                    process.integer(127),
+                    process.integer(0),
+                    process.integer(0),
+                    process.integer(1)
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_ipv6_address_returns_ok_tuple() {
+    with_process(|process| {
+        let zero = process.integer(0);
+
+        assert_eq!(
+            parse_address_1::result(process, process.charlist_from_str("::1")),
+            Ok(process.tuple_from_slice(&[
+                atom!("ok"),
+                process.tuple_from_slice(&[
+                    zero,
+                    zero,
+                    zero,
+                    zero,
+                    zero,
+                    zero,
+                    zero,
+                    process.integer(1)
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_hostname_returns_einval() {
+    with_process(|process| {
+        assert_eq!(
+            parse_address_1::result(process, process.charlist_from_str("localhost")),
+            Ok(process.tuple_from_slice(&[atom!("error"), atom!("einval")]))
+        );
+    });
+}
@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet;
+
+/// Returns `ok` after changing `active` and whether data is a binary or a list for `socket`, or
+/// `{error, einval}` if it is closed.  Setting `{active, once}` again after its message makes the
+/// socket send the next one.
+#[native_implemented::function(inet:setopts/2)]
+pub fn result(process: &Process, socket: Term, options: Term) -> exception::Result<Term> {
+    let socket_port = super::term_try_into_socket("socket", socket)?;
+    let set_options: inet::SetOptions = options.try_into()?;
+
+    Ok(super::reply_to_term(
+        process,
+        inet::setopts(socket_port, set_options),
+    ))
+}
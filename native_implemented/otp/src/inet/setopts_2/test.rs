@@ -0,0 +1,41 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::inet::setopts_2;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            setopts_2::result(process, process.integer(1), Term::NIL),
+            "socket (1) is not a socket"
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_option_errors_badarg() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[
+            process.tuple_from_slice(&[Atom::str_to_term("reuseaddr"), true.into()])
+        ]);
+
+        assert_badarg!(
+            setopts_2::result(process, Port::next_term(), options),
+            "supported options are binary, list, or {active, true | false | once}"
+        );
+    });
+}
+
+#[test]
+fn with_closed_socket_returns_einval_error() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[atom!("binary")]);
+
+        assert_eq!(
+            setopts_2::result(process, Port::next_term(), options),
+            Ok(process.tuple_from_slice(&[atom!("error"), Atom::str_to_term("einval")]))
+        );
+    });
+}
@@ -0,0 +1,44 @@
+//! Mirrors [io](http://erlang.org/doc/man/io.html) module
+//!
+//! There are no I/O servers, so output goes straight to the console instead of through the group
+//! leader.
+
+pub mod format_1;
+pub mod format_2;
+pub mod format_3;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib;
+use crate::runtime::sys::io::{put_chars, put_error_chars};
+
+fn format(io_device: Term, format: Term, data: Term) -> exception::Result<Term> {
+    let io_device_atom = term_try_into_atom!(io_device)?;
+    let string = io_lib::format::format(format, data)?;
+
+    match io_device_atom.name() {
+        "standard_io" | "user" => put_chars(&string),
+        "standard_error" => put_error_chars(&string),
+        _ => {
+            return Err(anyhow!(
+                "io_device ({}) is not standard_io, standard_error, or user",
+                io_device
+            )
+            .into())
+        }
+    }
+
+    Ok(atom!("ok"))
+}
+
+fn module() -> Atom {
+    Atom::from_str("io")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
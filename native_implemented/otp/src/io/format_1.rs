@@ -0,0 +1,11 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(io:format/1)]
+pub fn result(format: Term) -> exception::Result<Term> {
+    crate::io::format(atom!("standard_io"), format, Term::NIL)
+}
@@ -0,0 +1,21 @@
+use liblumen_alloc::atom;
+
+use crate::io::format_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_string_returns_ok() {
+    with_process(|process| {
+        assert_eq!(result(process.charlist_from_str("")), Ok(atom!("ok")));
+    });
+}
+
+#[test]
+fn with_control_sequence_using_data_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process.charlist_from_str("~w")),
+            "does not have enough arguments"
+        );
+    });
+}
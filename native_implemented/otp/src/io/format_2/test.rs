@@ -0,0 +1,28 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io::format_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_data_returns_ok() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process.charlist_from_str("~w~n"),
+                process.list_from_slice(&[atom!("format_2")])
+            ),
+            Ok(atom!("ok"))
+        );
+    });
+}
+
+#[test]
+fn with_too_few_arguments_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process.charlist_from_str("~w"), Term::NIL),
+            "does not have enough arguments"
+        );
+    });
+}
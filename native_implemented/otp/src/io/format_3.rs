@@ -0,0 +1,10 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(io:format/3)]
+pub fn result(io_device: Term, format: Term, data: Term) -> exception::Result<Term> {
+    crate::io::format(io_device, format, data)
+}
@@ -0,0 +1,37 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io::format_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_standard_io_standard_error_or_user_returns_ok() {
+    with_process(|process| {
+        for io_device in &[atom!("standard_io"), atom!("standard_error"), atom!("user")] {
+            assert_eq!(
+                result(*io_device, process.charlist_from_str(""), Term::NIL),
+                Ok(atom!("ok"))
+            );
+        }
+    });
+}
+
+#[test]
+fn with_other_io_device_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(atom!("other"), process.charlist_from_str(""), Term::NIL),
+            "io_device (other) is not standard_io, standard_error, or user"
+        );
+    });
+}
+
+#[test]
+fn without_atom_io_device_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process.integer(0), process.charlist_from_str(""), Term::NIL),
+            "io_device (0) is not an atom"
+        );
+    });
+}
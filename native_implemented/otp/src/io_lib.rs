@@ -0,0 +1,15 @@
+//! Mirrors [io_lib](http://erlang.org/doc/man/io_lib.html) module
+
+pub mod format;
+pub mod format_2;
+pub mod write;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("io_lib")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,613 @@
+//! Interprets the control sequences of `io:format` and `io_lib:format`.
+//!
+//! A control sequence is `~F.P.PadModC`, where the field width `F`, precision `P`, padding
+//! character `Pad` and modifiers `Mod` are optional, and `F` and `P` may be `*` to take them from
+//! the arguments.
+
+use std::convert::TryInto;
+use std::iter;
+use std::vec;
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+use crate::io_lib::write;
+
+/// The line length `~p` breaks terms at when there is no field width
+pub const DEFAULT_LINE_LENGTH: usize = 80;
+
+/// Formats `data` with the control sequences in `format`, which may be a string, binary or atom.
+pub fn format(format: Term, data: Term) -> exception::Result<String> {
+    let format_string = format_to_string(format)?;
+    let arguments = data_to_vec(data)?;
+    let mut formatter = Formatter {
+        format,
+        data,
+        arguments: arguments.into_iter(),
+        string: String::new(),
+    };
+    let mut chars = format_string.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '~' {
+            let control_sequence = formatter.parse(&mut chars)?;
+            formatter.control(control_sequence)?;
+        } else {
+            formatter.string.push(c);
+        }
+    }
+
+    if formatter.arguments.len() == 0 {
+        Ok(formatter.string)
+    } else {
+        Err(anyhow!(
+            "data ({}) has more arguments than format ({}) uses",
+            data,
+            format
+        )
+        .into())
+    }
+}
+
+/// The characters of `chardata`, as written by `~s`, or `~ts` when `unicode` is set.
+pub fn chardata_to_string(chardata: Term, unicode: bool) -> exception::Result<String> {
+    if let TypedTerm::Atom(atom) = chardata.decode()? {
+        return Ok(atom.name().to_string());
+    }
+
+    let mut string = String::new();
+    let mut stack = vec![chardata];
+
+    while let Some(top) = stack.pop() {
+        match top.decode()? {
+            TypedTerm::SmallInteger(_) => {
+                let c: char = top
+                    .try_into()
+                    .ok()
+                    .filter(|c| unicode || (*c as u32) < 256)
+                    .with_context(|| chardata_element_context(chardata, top))?;
+
+                string.push(c);
+            }
+            TypedTerm::Nil => (),
+            TypedTerm::List(cons) => {
+                stack.push(cons.tail);
+                stack.push(cons.head);
+            }
+            TypedTerm::HeapBinary(heap_binary) => {
+                push_binary(&mut string, heap_binary.as_bytes(), chardata, unicode)?
+            }
+            TypedTerm::ProcBin(process_binary) => {
+                push_binary(&mut string, process_binary.as_bytes(), chardata, unicode)?
+            }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                push_binary(&mut string, binary_literal.as_bytes(), chardata, unicode)?
+            }
+            TypedTerm::SubBinary(subbinary) if subbinary.is_binary() => {
+                let bytes: Vec<u8> = subbinary.full_byte_iter().collect();
+
+                push_binary(&mut string, &bytes, chardata, unicode)?
+            }
+            _ => {
+                return Err(TypeError)
+                    .context(chardata_element_context(chardata, top))
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    Ok(string)
+}
+
+// Private
+
+struct ControlSequence {
+    field_width: Option<usize>,
+    left_adjust: bool,
+    precision: Option<usize>,
+    pad: char,
+    unicode: bool,
+    printable_lists: bool,
+    control: char,
+}
+
+struct Formatter {
+    format: Term,
+    data: Term,
+    arguments: vec::IntoIter<Term>,
+    string: String,
+}
+
+impl Formatter {
+    fn parse(
+        &mut self,
+        chars: &mut iter::Peekable<impl Iterator<Item = char>>,
+    ) -> exception::Result<ControlSequence> {
+        let mut left_adjust = false;
+
+        if chars.peek() == Some(&'-') {
+            chars.next();
+            left_adjust = true;
+        }
+
+        let field_width = match self.parse_number(chars)? {
+            Some(width) if width < 0 => {
+                left_adjust = true;
+
+                Some(-width as usize)
+            }
+            Some(width) => Some(width as usize),
+            None => None,
+        };
+
+        let mut precision = None;
+        let mut pad = ' ';
+
+        if chars.peek() == Some(&'.') {
+            chars.next();
+
+            precision = match self.parse_number(chars)? {
+                Some(precision) if precision < 0 => {
+                    return Err(anyhow!(
+                        "format ({}) precision ({}) is negative",
+                        self.format,
+                        precision
+                    )
+                    .into())
+                }
+                precision => precision.map(|precision| precision as usize),
+            };
+
+            if chars.peek() == Some(&'.') {
+                chars.next();
+
+                pad = match chars.next() {
+                    Some('*') => {
+                        let argument = self.next_argument()?;
+
+                        argument.try_into().with_context(|| {
+                            format!("pad character ({}) is not a char", argument)
+                        })?
+                    }
+                    Some(pad) => pad,
+                    None => return Err(self.incomplete()),
+                };
+            }
+        }
+
+        let mut unicode = false;
+        let mut printable_lists = true;
+
+        loop {
+            match chars.peek() {
+                Some('t') => unicode = true,
+                Some('l') => printable_lists = false,
+                // Maps are always written in key order
+                Some('k') => (),
+                _ => break,
+            }
+
+            chars.next();
+        }
+
+        let control = chars.next().ok_or_else(|| self.incomplete())?;
+
+        Ok(ControlSequence {
+            field_width,
+            left_adjust,
+            precision,
+            pad,
+            unicode,
+            printable_lists,
+            control,
+        })
+    }
+
+    fn parse_number(
+        &mut self,
+        chars: &mut iter::Peekable<impl Iterator<Item = char>>,
+    ) -> exception::Result<Option<isize>> {
+        if chars.peek() == Some(&'*') {
+            chars.next();
+            let argument = self.next_argument()?;
+            let number: isize = argument
+                .try_into()
+                .with_context(|| format!("* argument ({}) is not an integer", argument))?;
+
+            return Ok(Some(number));
+        }
+
+        let mut number = None;
+
+        while let Some(digit) = chars.peek().and_then(|c| c.to_digit(10)) {
+            chars.next();
+            number = Some(number.unwrap_or(0) * 10 + digit as isize);
+        }
+
+        Ok(number)
+    }
+
+    fn control(&mut self, control_sequence: ControlSequence) -> exception::Result<()> {
+        let ControlSequence {
+            field_width,
+            left_adjust,
+            precision,
+            pad,
+            unicode,
+            printable_lists,
+            control,
+        } = control_sequence;
+
+        let string = match control {
+            '~' => repeat_char('~', field_width, left_adjust, precision, pad),
+            'n' => "\n".to_string(),
+            'i' => {
+                self.next_argument()?;
+
+                String::new()
+            }
+            'c' => {
+                let argument = self.next_argument()?;
+                let code_point: u32 = argument
+                    .try_into()
+                    .with_context(|| format!("character ({}) is not a char", argument))?;
+                // Like the BEAM, only the low byte is used without the `t` modifier
+                let code_point = if unicode {
+                    code_point
+                } else {
+                    code_point & 0xFF
+                };
+                let c = std::char::from_u32(code_point)
+                    .with_context(|| format!("character ({}) is not a char", argument))?;
+
+                repeat_char(c, field_width, left_adjust, precision, pad)
+            }
+            's' => {
+                let argument = self.next_argument()?;
+                let chars: Vec<char> = chardata_to_string(argument, unicode)?.chars().collect();
+
+                string_field(chars, field_width, left_adjust, precision, pad)?
+            }
+            'w' | 'W' => {
+                let argument = self.next_argument()?;
+                let depth = if control == 'W' {
+                    self.next_depth()?
+                } else {
+                    -1
+                };
+
+                term_field(write::write(argument, depth), field_width, left_adjust, pad)
+            }
+            'p' | 'P' => {
+                let argument = self.next_argument()?;
+                let depth = if control == 'P' {
+                    self.next_depth()?
+                } else {
+                    -1
+                };
+                let indentation = precision.unwrap_or_else(|| self.column());
+
+                write::print(
+                    argument,
+                    depth,
+                    field_width.unwrap_or(DEFAULT_LINE_LENGTH),
+                    indentation,
+                    unicode,
+                    printable_lists,
+                )
+            }
+            'e' | 'f' | 'g' => {
+                let argument = self.next_argument()?;
+                let f = match argument.decode()? {
+                    TypedTerm::Float(float) => float.into(),
+                    _ => {
+                        return Err(TypeError)
+                            .context(format!("float ({}) is not a float", argument))
+                            .map_err(From::from)
+                    }
+                };
+                let formatted = match control {
+                    'e' => exponential(f, precision.unwrap_or(6))?,
+                    'f' => fixed(f, precision.unwrap_or(6))?,
+                    _ => general(f, precision.unwrap_or(6))?,
+                };
+
+                term_field(formatted, field_width, left_adjust, pad)
+            }
+            'b' | 'B' | 'x' | 'X' | '#' | '+' => {
+                let argument = self.next_argument()?;
+                let integer = term_to_big_int(argument)?;
+                let base = precision.unwrap_or(10);
+
+                if !(2..=36).contains(&base) {
+                    return Err(
+                        anyhow!("format ({}) base ({}) is not in 2-36", self.format, base).into(),
+                    );
+                }
+
+                let digits = integer.magnitude().to_str_radix(base as u32);
+                let digits = match control {
+                    'B' | 'X' | '#' => digits.to_uppercase(),
+                    _ => digits,
+                };
+                let sign = if integer.sign() == Sign::Minus {
+                    "-"
+                } else {
+                    ""
+                };
+                let prefix = match control {
+                    'x' | 'X' => {
+                        let prefix_argument = self.next_argument()?;
+
+                        chardata_to_string(prefix_argument, true)?
+                    }
+                    '#' | '+' => format!("{}#", base),
+                    _ => String::new(),
+                };
+
+                term_field(
+                    format!("{}{}{}", sign, prefix, digits),
+                    field_width,
+                    left_adjust,
+                    pad,
+                )
+            }
+            _ => {
+                return Err(anyhow!(
+                    "format ({}) control sequence (~{}) is not supported",
+                    self.format,
+                    control
+                )
+                .into())
+            }
+        };
+
+        self.string.push_str(&string);
+
+        Ok(())
+    }
+
+    /// The number of characters on the current line, which `~p` indents by
+    fn column(&self) -> usize {
+        match self.string.rfind('\n') {
+            Some(index) => self.string[(index + 1)..].chars().count(),
+            None => self.string.chars().count(),
+        }
+    }
+
+    fn incomplete(&self) -> exception::Exception {
+        anyhow!(
+            "format ({}) ends in the middle of a control sequence",
+            self.format
+        )
+        .into()
+    }
+
+    fn next_argument(&mut self) -> exception::Result<Term> {
+        match self.arguments.next() {
+            Some(argument) => Ok(argument),
+            None => Err(anyhow!(
+                "data ({}) does not have enough arguments for format ({})",
+                self.data,
+                self.format
+            )
+            .into()),
+        }
+    }
+
+    fn next_depth(&mut self) -> exception::Result<isize> {
+        let argument = self.next_argument()?;
+        let depth: isize = argument
+            .try_into()
+            .with_context(|| format!("depth ({}) is not an integer", argument))?;
+
+        // Like the BEAM, any negative depth is unlimited
+        Ok(depth.max(-1))
+    }
+}
+
+fn adjust(string: String, width: usize, left_adjust: bool, pad: char) -> String {
+    let len = string.chars().count();
+
+    if width <= len {
+        string
+    } else {
+        let padding: String = iter::repeat(pad).take(width - len).collect();
+
+        if left_adjust {
+            string + &padding
+        } else {
+            padding + &string
+        }
+    }
+}
+
+/// Formats the characters of `~s`, which are cut to the precision, then to the field width.
+fn string_field(
+    mut chars: Vec<char>,
+    field_width: Option<usize>,
+    left_adjust: bool,
+    precision: Option<usize>,
+    pad: char,
+) -> exception::Result<String> {
+    if let (Some(field_width), Some(precision)) = (field_width, precision) {
+        if field_width < precision {
+            return Err(anyhow!(
+                "field width ({}) is less than precision ({})",
+                field_width,
+                precision
+            )
+            .into());
+        }
+    }
+
+    if let Some(precision) = precision {
+        chars.truncate(precision);
+        chars.resize(precision, pad);
+    }
+
+    if let Some(field_width) = field_width {
+        chars.truncate(field_width);
+
+        Ok(adjust(
+            chars.into_iter().collect(),
+            field_width,
+            left_adjust,
+            pad,
+        ))
+    } else {
+        Ok(chars.into_iter().collect())
+    }
+}
+
+/// Formats a written term or number, which is replaced by `*`s if it doesn't fit in the field.
+fn term_field(string: String, field_width: Option<usize>, left_adjust: bool, pad: char) -> String {
+    match field_width {
+        Some(field_width) if field_width < string.chars().count() => {
+            iter::repeat('*').take(field_width).collect()
+        }
+        Some(field_width) => adjust(string, field_width, left_adjust, pad),
+        None => string,
+    }
+}
+
+/// `~c` and `~~` repeat the character precision times, or field width times without a precision.
+fn repeat_char(
+    c: char,
+    field_width: Option<usize>,
+    left_adjust: bool,
+    precision: Option<usize>,
+    pad: char,
+) -> String {
+    let count = precision.or(field_width).unwrap_or(1);
+    let repeated: String = iter::repeat(c).take(count).collect();
+
+    match field_width {
+        Some(field_width) => adjust(repeated, field_width, left_adjust, pad),
+        None => repeated,
+    }
+}
+
+fn exponential(f: f64, precision: usize) -> exception::Result<String> {
+    if precision < 2 {
+        return Err(anyhow!("precision ({}) of ~e is less than 2", precision).into());
+    }
+
+    // Rust writes `1.5e3` where Erlang writes `1.5e+3`
+    let rust_formatted = format!("{:.*e}", precision - 1, f);
+    let (mantissa, exponent) = rust_formatted.split_at(rust_formatted.find('e').unwrap());
+    let exponent = &exponent[1..];
+
+    if exponent.starts_with('-') {
+        Ok(format!("{}e{}", mantissa, exponent))
+    } else {
+        Ok(format!("{}e+{}", mantissa, exponent))
+    }
+}
+
+fn fixed(f: f64, precision: usize) -> exception::Result<String> {
+    if precision < 1 {
+        return Err(anyhow!("precision ({}) of ~f is less than 1", precision).into());
+    }
+
+    Ok(format!("{:.*}", precision, f))
+}
+
+/// `~g` is `~f` for `0.1 <= abs(f) < 10^precision` and `~e` otherwise.
+fn general(f: f64, precision: usize) -> exception::Result<String> {
+    if precision < 1 {
+        return Err(anyhow!("precision ({}) of ~g is less than 1", precision).into());
+    }
+
+    let abs = f.abs();
+    let precision = precision as i32;
+    let exponent = if abs < 0.1 {
+        -2
+    } else {
+        let mut exponent = -1;
+
+        while exponent <= precision && 10_f64.powi(exponent + 1) <= abs {
+            exponent += 1;
+        }
+
+        exponent
+    };
+
+    if (precision <= 1 && exponent == -1) || (-1 <= exponent && exponent < precision - 1) {
+        fixed(f, (precision - 1 - exponent) as usize)
+    } else {
+        exponential(f, precision.max(2) as usize)
+    }
+}
+
+fn chardata_element_context(chardata: Term, element: Term) -> String {
+    format!(
+        "string ({}) element ({}) is not a character, binary, or nested list",
+        chardata, element
+    )
+}
+
+fn push_binary(
+    string: &mut String,
+    bytes: &[u8],
+    chardata: Term,
+    unicode: bool,
+) -> exception::Result<()> {
+    if unicode {
+        let s = std::str::from_utf8(bytes)
+            .with_context(|| format!("string ({}) binary is not UTF-8", chardata))?;
+        string.push_str(s);
+    } else {
+        string.extend(bytes.iter().map(|byte| *byte as char));
+    }
+
+    Ok(())
+}
+
+fn data_to_vec(data: Term) -> exception::Result<Vec<Term>> {
+    match data.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .iter()
+            .map(|result| {
+                result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("data ({}) is improper", data))
+                    .map_err(From::from)
+            })
+            .collect(),
+        _ => Err(TypeError)
+            .context(format!("data ({}) is not a list", data))
+            .map_err(From::from),
+    }
+}
+
+fn format_to_string(format: Term) -> exception::Result<String> {
+    match format.decode()? {
+        TypedTerm::Atom(atom) => Ok(atom.name().to_string()),
+        TypedTerm::Nil | TypedTerm::List(_) => list_to_string(format),
+        _ => chardata_to_string(format, true),
+    }
+}
+
+fn term_to_big_int(integer: Term) -> exception::Result<BigInt> {
+    match integer.decode()? {
+        TypedTerm::SmallInteger(small_integer) => {
+            let integer_isize: isize = small_integer.into();
+
+            Ok(integer_isize.into())
+        }
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+
+            Ok(big_int.clone())
+        }
+        _ => Err(TypeError)
+            .context(format!("integer ({}) is not an integer", integer))
+            .map_err(From::from),
+    }
+}
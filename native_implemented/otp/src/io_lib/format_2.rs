@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib::format;
+
+/// Returns the formatted characters as a flat list, which is also an iolist when they are all
+/// bytes.
+#[native_implemented::function(io_lib:format/2)]
+pub fn result(process: &Process, format: Term, data: Term) -> exception::Result<Term> {
+    let string = format::format(format, data)?;
+
+    Ok(process.list_from_chars(string.chars()))
+}
@@ -0,0 +1,233 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib::format_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_data_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.charlist_from_str("~w"), atom!("data")),
+            "data (data) is not a list"
+        );
+    });
+}
+
+#[test]
+fn with_too_few_arguments_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.charlist_from_str("~w ~w"),
+                list(process, &[atom!("a")])
+            ),
+            "does not have enough arguments"
+        );
+    });
+}
+
+#[test]
+fn with_too_many_arguments_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.charlist_from_str("~w"),
+                list(process, &[atom!("a"), atom!("b")])
+            ),
+            "has more arguments than format"
+        );
+    });
+}
+
+#[test]
+fn with_unsupported_control_sequence_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.charlist_from_str("~y"), Term::NIL),
+            "control sequence (~y) is not supported"
+        );
+    });
+}
+
+#[test]
+fn with_incomplete_control_sequence_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.charlist_from_str("~10"), Term::NIL),
+            "ends in the middle of a control sequence"
+        );
+    });
+}
+
+#[test]
+fn with_binary_or_atom_format_formats() {
+    with_process(|process| {
+        let data = list(process, &[process.integer(1)]);
+
+        assert_formats(process, process.binary_from_str("x=~w"), data, "x=1");
+        assert_formats(process, Atom::str_to_term("x=~w"), data, "x=1");
+    });
+}
+
+#[test]
+fn with_tilde_and_newline_formats() {
+    with_process(|process| {
+        assert_formats(process, process.charlist_from_str("~~~n"), Term::NIL, "~\n");
+    });
+}
+
+#[test]
+fn with_w_writes_term() {
+    with_process(|process| {
+        let term = process.tuple_from_slice(&[
+            atom!("ok"),
+            process.list_from_slice(&[process.integer(1), process.integer(2)]),
+            process.charlist_from_str("ab"),
+            process.float(1.5),
+        ]);
+
+        assert_formats(
+            process,
+            process.charlist_from_str("~w"),
+            list(process, &[term]),
+            "{ok,[1,2],[97,98],1.5}",
+        );
+    });
+}
+
+#[test]
+fn with_p_prints_printable_list_as_string() {
+    with_process(|process| {
+        assert_formats(
+            process,
+            process.charlist_from_str("~p"),
+            list(process, &[process.charlist_from_str("ab")]),
+            "\"ab\"",
+        );
+    });
+}
+
+#[test]
+fn with_depth_truncates_with_ellipsis() {
+    with_process(|process| {
+        let term = process.list_from_slice(&[
+            process.integer(1),
+            process.integer(2),
+            process.integer(3),
+            process.integer(4),
+        ]);
+
+        assert_formats(
+            process,
+            process.charlist_from_str("~W"),
+            list(process, &[term, process.integer(3)]),
+            "[1,2|...]",
+        );
+        assert_formats(
+            process,
+            process.charlist_from_str("~P"),
+            list(process, &[term, process.integer(-1)]),
+            "[1,2,3,4]",
+        );
+    });
+}
+
+#[test]
+fn with_s_inserts_chardata() {
+    with_process(|process| {
+        let chardata = process.list_from_slice(&[
+            process.charlist_from_str("ab"),
+            process.binary_from_str("cd"),
+        ]);
+
+        assert_formats(
+            process,
+            process.charlist_from_str("~s|~s"),
+            list(process, &[chardata, atom!("ef")]),
+            "abcd|ef",
+        );
+    });
+}
+
+#[test]
+fn with_field_width_pads() {
+    with_process(|process| {
+        let data = list(process, &[process.charlist_from_str("ab")]);
+
+        assert_formats(process, process.charlist_from_str("~5s|"), data, "   ab|");
+        assert_formats(process, process.charlist_from_str("~-5s|"), data, "ab   |");
+        assert_formats(
+            process,
+            process.charlist_from_str("~5.._s|"),
+            data,
+            "___ab|",
+        );
+    });
+}
+
+#[test]
+fn with_c_inserts_character() {
+    with_process(|process| {
+        assert_formats(
+            process,
+            process.charlist_from_str("~c~3c"),
+            list(
+                process,
+                &[process.integer('a' as isize), process.integer('b' as isize)],
+            ),
+            "abbb",
+        );
+    });
+}
+
+#[test]
+fn with_integer_controls_writes_in_base() {
+    with_process(|process| {
+        let integer = process.integer(255);
+
+        assert_formats(
+            process,
+            process.charlist_from_str("~b ~.2b ~.16B ~.16#"),
+            list(process, &[integer, integer, integer, integer]),
+            "255 11111111 FF 16#FF",
+        );
+        assert_formats(
+            process,
+            process.charlist_from_str("~.16x"),
+            list(
+                process,
+                &[process.integer(-255), process.charlist_from_str("0x")],
+            ),
+            "-0xff",
+        );
+    });
+}
+
+#[test]
+fn with_float_controls_writes_float() {
+    with_process(|process| {
+        let float = process.float(1.5);
+
+        assert_formats(
+            process,
+            process.charlist_from_str("~f ~.2f ~e"),
+            list(process, &[float, float, float]),
+            "1.500000 1.50 1.50000e+0",
+        );
+    });
+}
+
+fn assert_formats(process: &Process, format: Term, data: Term, expected: &str) {
+    assert_eq!(
+        result(process, format, data),
+        Ok(process.list_from_chars(expected.chars()))
+    );
+}
+
+fn list(process: &Process, elements: &[Term]) -> Term {
+    process.list_from_slice(elements)
+}
@@ -0,0 +1,480 @@
+//! Writes terms in Erlang syntax for the `~w`, `~W`, `~p` and `~P` control sequences.
+//!
+//! Depths follow `io_lib:write/2`: a depth of `-1` is unlimited, otherwise each level of nesting
+//! and each further element of a tuple, list, map or binary uses up one, and whatever is left
+//! when it runs out is written as `...`.
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `~w` and `~W`: no strings and no line breaks.
+pub fn write(term: Term, depth: isize) -> String {
+    let writer = Writer {
+        strings: false,
+        printable_lists: false,
+        unicode: false,
+    };
+    let mut string = String::new();
+    writer.flat(term, depth, &mut string);
+
+    string
+}
+
+/// `~p` and `~P`: printable lists and binaries are written as strings, and terms that don't fit in
+/// `line_length` are broken over lines, with each line after the first indented by `indentation`
+/// plus the nesting.
+pub fn print(
+    term: Term,
+    depth: isize,
+    line_length: usize,
+    indentation: usize,
+    unicode: bool,
+    printable_lists: bool,
+) -> String {
+    let writer = Writer {
+        strings: true,
+        printable_lists,
+        unicode,
+    };
+    let mut string = String::new();
+    writer.pretty(term, depth, line_length, indentation, &mut string);
+
+    string
+}
+
+pub fn is_printable(c: char, unicode: bool) -> bool {
+    match c {
+        '\n' | '\r' | '\t' | '\u{B}' | '\u{8}' | '\u{C}' | '\u{1B}' => true,
+        ' '..='~' | '\u{A0}'..='\u{FF}' => true,
+        _ if unicode => match c {
+            '\u{100}'..='\u{D7FF}' | '\u{E000}'..='\u{FFFD}' | '\u{10000}'..='\u{10FFFF}' => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+// Private
+
+/// An element of a container and the depth it is written with
+enum Item {
+    Term(Term, isize),
+    Association(Term, Term, isize),
+    /// The improper tail of a list
+    Tail(Term, isize),
+    Ellipsis,
+}
+
+impl Item {
+    fn separator(&self, close: &str) -> &'static str {
+        match self {
+            Item::Tail(..) => "|",
+            Item::Ellipsis if close == "]" => "|",
+            _ => ",",
+        }
+    }
+}
+
+struct Writer {
+    strings: bool,
+    printable_lists: bool,
+    unicode: bool,
+}
+
+impl Writer {
+    fn flat(&self, term: Term, depth: isize, string: &mut String) {
+        if depth == 0 {
+            string.push_str("...");
+            return;
+        }
+
+        if let Some(chars) = self.string(term) {
+            write_string(&chars, depth, "", string);
+            return;
+        }
+
+        match self.container(term, depth) {
+            Some((open, items, close)) => {
+                string.push_str(open);
+
+                for (index, item) in items.iter().enumerate() {
+                    if 0 < index {
+                        string.push_str(item.separator(close));
+                    }
+
+                    self.flat_item(item, string);
+                }
+
+                string.push_str(close);
+            }
+            None => self.flat_atomic(term, depth, string),
+        }
+    }
+
+    fn flat_atomic(&self, term: Term, depth: isize, string: &mut String) {
+        match term.decode().unwrap() {
+            TypedTerm::Float(float) => string.push_str(&short_float(float.into())),
+            TypedTerm::HeapBinary(heap_binary) => {
+                self.binary(heap_binary.as_bytes(), depth, string)
+            }
+            TypedTerm::ProcBin(process_binary) => {
+                self.binary(process_binary.as_bytes(), depth, string)
+            }
+            TypedTerm::BinaryLiteral(binary_literal) => {
+                self.binary(binary_literal.as_bytes(), depth, string)
+            }
+            TypedTerm::SubBinary(subbinary) if subbinary.is_binary() => {
+                let bytes: Vec<u8> = subbinary.full_byte_iter().collect();
+
+                self.binary(&bytes, depth, string)
+            }
+            _ => string.push_str(&term.to_string()),
+        }
+    }
+
+    fn flat_item(&self, item: &Item, string: &mut String) {
+        match item {
+            Item::Term(term, depth) | Item::Tail(term, depth) => self.flat(*term, *depth, string),
+            Item::Association(key, value, depth) => {
+                self.flat(*key, *depth, string);
+                string.push_str(" => ");
+                self.flat(*value, *depth, string);
+            }
+            Item::Ellipsis => string.push_str("..."),
+        }
+    }
+
+    fn pretty(
+        &self,
+        term: Term,
+        depth: isize,
+        line_length: usize,
+        indentation: usize,
+        string: &mut String,
+    ) {
+        let mut flat = String::new();
+        self.flat(term, depth, &mut flat);
+
+        if indentation + flat.chars().count() <= line_length || self.string(term).is_some() {
+            string.push_str(&flat);
+            return;
+        }
+
+        match self.container(term, depth) {
+            Some((open, items, close)) => {
+                let item_indentation = indentation + open.len();
+                string.push_str(open);
+
+                for (index, item) in items.iter().enumerate() {
+                    if 0 < index {
+                        let separator = item.separator(close);
+                        string.push_str(separator);
+
+                        if separator == "," {
+                            string.push('\n');
+                            string.extend(std::iter::repeat(' ').take(item_indentation));
+                        }
+                    }
+
+                    match item {
+                        Item::Term(term, depth) | Item::Tail(term, depth) => {
+                            self.pretty(*term, *depth, line_length, item_indentation, string)
+                        }
+                        Item::Association(key, value, depth) => {
+                            let mut key_string = String::new();
+                            self.flat(*key, *depth, &mut key_string);
+                            string.push_str(&key_string);
+                            string.push_str(" => ");
+
+                            let value_indentation =
+                                item_indentation + key_string.chars().count() + " => ".len();
+                            self.pretty(*value, *depth, line_length, value_indentation, string)
+                        }
+                        Item::Ellipsis => string.push_str("..."),
+                    }
+                }
+
+                string.push_str(close);
+            }
+            None => string.push_str(&flat),
+        }
+    }
+
+    /// Binaries are written byte by byte, except by `~p`, which writes printable binaries as
+    /// strings.
+    fn binary(&self, bytes: &[u8], depth: isize, string: &mut String) {
+        if self.strings && !bytes.is_empty() {
+            if let Some(chars) = printable_binary_chars(bytes, self.unicode) {
+                let suffix = if bytes.is_ascii() || !self.unicode {
+                    ""
+                } else {
+                    "/utf8"
+                };
+
+                string.push_str("<<");
+                write_string(&chars, depth, suffix, string);
+                string.push_str(">>");
+
+                return;
+            }
+        }
+
+        string.push_str("<<");
+
+        let mut remaining = depth;
+
+        for (index, byte) in bytes.iter().enumerate() {
+            if 0 < index {
+                string.push(',');
+            }
+
+            if remaining == 1 {
+                string.push_str("...");
+                break;
+            }
+
+            string.push_str(&byte.to_string());
+            remaining = decrement(remaining);
+        }
+
+        string.push_str(">>");
+    }
+
+    /// The delimiters and items of a term that contains other terms, or `None` for terms that are
+    /// written as a whole.
+    fn container(
+        &self,
+        term: Term,
+        depth: isize,
+    ) -> Option<(&'static str, Vec<Item>, &'static str)> {
+        match term.decode().unwrap() {
+            TypedTerm::Tuple(tuple) => Some(if depth == 1 {
+                ("{", vec![Item::Ellipsis], "}")
+            } else {
+                ("{", elements_to_items(tuple.iter().copied(), depth).0, "}")
+            }),
+            TypedTerm::List(cons) => Some(if depth == 1 {
+                ("[", vec![Item::Ellipsis], "]")
+            } else {
+                ("[", list_to_items(cons, depth), "]")
+            }),
+            TypedTerm::Map(map) if map.len() > 0 => Some(if depth == 1 {
+                ("#{", vec![Item::Ellipsis], "}")
+            } else {
+                // Unlike tuple and list elements, every association gets the same depth
+                let association_depth = decrement(depth);
+                let mut items = Vec::with_capacity(map.len());
+                let mut remaining = association_depth;
+
+                for (index, (key, value)) in map.iter().enumerate() {
+                    if 0 < index {
+                        if remaining == 1 {
+                            items.push(Item::Ellipsis);
+                            break;
+                        }
+
+                        remaining = decrement(remaining);
+                    }
+
+                    items.push(Item::Association(*key, *value, association_depth));
+                }
+
+                ("#{", items, "}")
+            }),
+            _ => None,
+        }
+    }
+
+    /// The characters of `term` if `~p` writes it as a string
+    fn string(&self, term: Term) -> Option<Vec<char>> {
+        if !(self.strings && self.printable_lists) {
+            return None;
+        }
+
+        match term.decode().unwrap() {
+            TypedTerm::List(cons) => {
+                let mut chars = Vec::new();
+
+                for result in cons.iter() {
+                    let c: char = result.ok()?.try_into().ok()?;
+
+                    if !is_printable(c, self.unicode) {
+                        return None;
+                    }
+
+                    chars.push(c);
+                }
+
+                Some(chars)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn decrement(depth: isize) -> isize {
+    if depth < 0 {
+        depth
+    } else {
+        depth - 1
+    }
+}
+
+/// Like `io_lib:write/2`, the first element is written with one less depth than the container and
+/// each later element with one less again, until the depth runs out and the rest is `...`.
+///
+/// Also returns the depth left for an improper tail, or `None` if the elements were cut short.
+fn elements_to_items(
+    elements: impl Iterator<Item = Term>,
+    depth: isize,
+) -> (Vec<Item>, Option<isize>) {
+    let mut items = Vec::new();
+    let mut remaining = decrement(depth);
+
+    for (index, element) in elements.enumerate() {
+        if 0 < index {
+            if remaining == 1 {
+                items.push(Item::Ellipsis);
+
+                return (items, None);
+            }
+
+            remaining = decrement(remaining);
+        }
+
+        items.push(Item::Term(element, remaining));
+    }
+
+    (items, Some(remaining))
+}
+
+fn list_to_items(cons: Boxed<Cons>, depth: isize) -> Vec<Item> {
+    let mut elements = Vec::new();
+    let mut tail = None;
+
+    for result in cons.iter() {
+        match result {
+            Ok(element) => elements.push(element),
+            Err(ImproperList {
+                tail: improper_tail,
+            }) => tail = Some(improper_tail),
+        }
+    }
+
+    let (mut items, tail_depth) = elements_to_items(elements.into_iter(), depth);
+
+    if let (Some(tail), Some(tail_depth)) = (tail, tail_depth) {
+        items.push(if tail_depth == 1 {
+            Item::Ellipsis
+        } else {
+            Item::Tail(tail, decrement(tail_depth))
+        });
+    }
+
+    items
+}
+
+fn printable_binary_chars(bytes: &[u8], unicode: bool) -> Option<Vec<char>> {
+    let chars: Vec<char> = if unicode {
+        std::str::from_utf8(bytes).ok()?.chars().collect()
+    } else {
+        bytes.iter().map(|byte| *byte as char).collect()
+    };
+
+    if chars.iter().all(|c| is_printable(*c, unicode)) {
+        Some(chars)
+    } else {
+        None
+    }
+}
+
+/// Like `float_to_list(Float, [short])`: the fewest digits that read back as the same float, in
+/// whichever of decimal or scientific notation is shorter.
+fn short_float(f: f64) -> String {
+    // Rust's `{:e}` also uses the fewest digits that round-trip
+    let scientific = format!("{:e}", f);
+    let (mantissa, exponent) = scientific.split_at(scientific.find('e').unwrap());
+    let exponent: i32 = exponent[1..].parse().unwrap();
+    let (sign, mantissa) = if mantissa.starts_with('-') {
+        ("-", &mantissa[1..])
+    } else {
+        ("", mantissa)
+    };
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+
+    let scientific = format!(
+        "{}{}.{}e{}",
+        sign,
+        &digits[..1],
+        if digits.len() > 1 { &digits[1..] } else { "0" },
+        exponent
+    );
+
+    let decimal = if exponent < 0 {
+        format!(
+            "{}0.{}{}",
+            sign,
+            "0".repeat((-exponent - 1) as usize),
+            digits
+        )
+    } else {
+        let integer_len = exponent as usize + 1;
+
+        if digits.len() > integer_len {
+            format!(
+                "{}{}.{}",
+                sign,
+                &digits[..integer_len],
+                &digits[integer_len..]
+            )
+        } else {
+            format!(
+                "{}{}{}.0",
+                sign,
+                digits,
+                "0".repeat(integer_len - digits.len())
+            )
+        }
+    };
+
+    if decimal.len() <= scientific.len() {
+        decimal
+    } else {
+        scientific
+    }
+}
+
+/// Writes `chars` as a quoted string, which is cut short with `...` after the quote once `depth`
+/// runs out.
+fn write_string(chars: &[char], depth: isize, suffix: &str, string: &mut String) {
+    let (written, truncated) = if 0 < depth && (depth as usize) <= chars.len() {
+        (&chars[..(depth as usize - 1)], true)
+    } else {
+        (chars, false)
+    };
+
+    string.push('"');
+
+    for c in written {
+        match c {
+            '"' => string.push_str("\\\""),
+            '\\' => string.push_str("\\\\"),
+            '\n' => string.push_str("\\n"),
+            '\r' => string.push_str("\\r"),
+            '\t' => string.push_str("\\t"),
+            '\u{B}' => string.push_str("\\v"),
+            '\u{8}' => string.push_str("\\b"),
+            '\u{C}' => string.push_str("\\f"),
+            '\u{1B}' => string.push_str("\\e"),
+            _ => string.push(*c),
+        }
+    }
+
+    string.push('"');
+    string.push_str(suffix);
+
+    if truncated {
+        string.push_str("...");
+    }
+}
@@ -9,17 +9,46 @@
 #[macro_use]
 mod macros;
 
+pub mod atomics;
 pub mod binary;
+pub mod code;
+pub mod counters;
+pub mod crypto;
 pub mod erlang;
+pub mod ets;
+pub mod file;
+pub mod gb_sets;
+pub mod gb_trees;
+pub mod gen_tcp;
+pub mod gen_udp;
+pub mod inet;
+pub mod io;
+pub mod io_lib;
 pub mod lists;
+pub mod logger;
 pub mod lumen;
 pub mod maps;
+pub mod math;
 pub mod number;
+pub mod orddict;
+pub mod ordsets;
+pub mod os;
+pub mod persistent_term;
+pub mod proplists;
+pub mod queue;
+pub mod rand;
+pub mod re;
 #[cfg(not(test))]
 use lumen_rt_core as runtime;
 #[cfg(test)]
 use lumen_rt_full as runtime;
+pub mod sets;
+#[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+pub mod ssl;
+pub mod string;
 pub mod timer;
+pub mod unicode;
+pub mod zlib;
 
 #[cfg(test)]
 mod test;
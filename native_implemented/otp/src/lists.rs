@@ -1,12 +1,21 @@
 //! Mirrors [lists](http://erlang.org/doc/man/lists.html) module
 
+pub mod flatten_1;
+pub mod foldl_3;
 pub mod keyfind_3;
 pub mod keymember_3;
+pub mod map_2;
 pub mod member_2;
 pub mod reverse_1;
 pub mod reverse_2;
+pub mod seq_2;
+pub mod seq_3;
+pub mod sort_1;
 
-use liblumen_alloc::erts::term::prelude::Atom;
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
 
 fn module() -> Atom {
     Atom::from_str("lists")
@@ -15,3 +24,20 @@ fn module() -> Atom {
 fn module_id() -> usize {
     module().id()
 }
+
+/// Returns the first cell of `list` if it is a non-empty proper list or `None` if it is empty, so
+/// that labels walking the list can assume each tail is a list.
+fn term_try_into_proper_list(list: Term) -> exception::Result<Option<Boxed<Cons>>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(None),
+        TypedTerm::List(cons) => match cons.count() {
+            Some(_) => Ok(Some(cons)),
+            None => Err(ImproperListError)
+                .context(format!("list ({}) is improper", list))
+                .map_err(From::from),
+        },
+        _ => Err(TypeError)
+            .context(format!("list ({}) is not a list", list))
+            .map_err(From::from),
+    }
+}
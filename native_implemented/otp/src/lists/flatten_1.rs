@@ -0,0 +1,52 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(lists:flatten/1)]
+pub fn result(process: &Process, deep_list: Term) -> exception::Result<Term> {
+    match deep_list.decode()? {
+        TypedTerm::Nil => Ok(deep_list),
+        TypedTerm::List(_) => {
+            let mut vec = Vec::new();
+            flatten(deep_list, &mut vec)?;
+
+            Ok(process.list_from_slice(&vec))
+        }
+        _ => Err(TypeError)
+            .context(format!("deep_list ({}) is not a list", deep_list))
+            .map_err(From::from),
+    }
+}
+
+// Private
+
+fn flatten(list: Term, vec: &mut Vec<Term>) -> anyhow::Result<()> {
+    match list.decode().unwrap() {
+        TypedTerm::Nil => Ok(()),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => {
+                        if element.is_list() {
+                            flatten(element, vec)?;
+                        } else {
+                            vec.push(element);
+                        }
+                    }
+                    Err(_) => {
+                        return Err(ImproperListError)
+                            .context(format!("list ({}) is improper", list))
+                    }
+                }
+            }
+
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
+}
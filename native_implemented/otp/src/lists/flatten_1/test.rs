@@ -0,0 +1,59 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::flatten_1::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_list(arc_process.clone()),
+            )
+        },
+        |(arc_process, deep_list)| {
+            prop_assert_badarg!(
+                result(&arc_process, deep_list),
+                format!("deep_list ({}) is not a list", deep_list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_improper_nested_list_errors_badarg() {
+    with_process(|process| {
+        let nested = process.improper_list_from_slice(&[process.integer(1)], process.integer(2));
+        let deep_list = process.list_from_slice(&[nested]);
+
+        assert_badarg!(
+            result(process, deep_list),
+            format!("list ({}) is improper", nested)
+        );
+    });
+}
+
+#[test]
+fn with_nested_lists_returns_elements_in_order() {
+    with_process(|process| {
+        let inner = process.list_from_slice(&[process.integer(3)]);
+        let nested = process.list_from_slice(&[process.integer(2), inner, Term::NIL]);
+        let deep_list = process.list_from_slice(&[process.integer(1), nested, process.integer(4)]);
+
+        assert_eq!(
+            result(process, deep_list),
+            Ok(process.list_from_slice(&[
+                process.integer(1),
+                process.integer(2),
+                process.integer(3),
+                process.integer(4)
+            ]))
+        );
+    });
+}
@@ -0,0 +1,29 @@
+//! ```elixir
+//! def foldl(function, acc, []), do: acc
+//! def foldl(function, acc, [head | tail]) do
+//!   foldl(function, function.(head, acc), tail)
+//! end
+//! ```
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(lists:foldl/3)]
+pub fn result(
+    process: &Process,
+    function: Term,
+    acc0: Term,
+    list: Term,
+) -> exception::Result<Term> {
+    term_try_into_function_with_arity!(function, 2)?;
+    super::term_try_into_proper_list(list)?;
+
+    process.queue_frame_with_arguments(
+        label_1::frame().with_arguments(false, &[acc0, function, list]),
+    );
+
+    Ok(Term::NONE)
+}
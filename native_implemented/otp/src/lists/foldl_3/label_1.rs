@@ -0,0 +1,37 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (function, list)
+//! # returned from call: acc
+//! # full stack: (acc, function, list)
+//! # returns: acc
+//! case list do
+//!   [] -> acc
+//!   [head | tail] -> foldl(function, function.(head, acc), tail)
+//! end
+//! ```
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, acc: Term, function: Term, list: Term) -> Term {
+    assert!(function.is_boxed_function());
+
+    match list.decode().unwrap() {
+        TypedTerm::Nil => acc,
+        TypedTerm::List(cons) => {
+            let arguments = process.list_from_slice(&[cons.head, acc]);
+
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(function, arguments));
+            process
+                .queue_frame_with_arguments(frame().with_arguments(true, &[function, cons.tail]));
+
+            Term::NONE
+        }
+        _ => unreachable!("list ({}) is not a proper list", list),
+    }
+}
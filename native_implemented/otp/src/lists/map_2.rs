@@ -0,0 +1,35 @@
+//! ```elixir
+//! def map(function, list), do: map(function, list, [])
+//!
+//! defp map(_function, [], reversed_mapped), do: :lists.reverse(reversed_mapped)
+//! defp map(function, [head | tail], reversed_mapped) do
+//!   map(function, tail, [function.(head) | reversed_mapped])
+//! end
+//! ```
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+#[native_implemented::function(lists:map/2)]
+pub fn result(process: &Process, function: Term, list: Term) -> exception::Result<Term> {
+    term_try_into_function_with_arity!(function, 1)?;
+
+    match super::term_try_into_proper_list(list)? {
+        Some(cons) => {
+            let arguments = process.list_from_slice(&[cons.head]);
+
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(function, arguments));
+            process.queue_frame_with_arguments(
+                label_1::frame().with_arguments(true, &[function, cons.tail, Term::NIL]),
+            );
+
+            Ok(Term::NONE)
+        }
+        None => Ok(Term::NIL),
+    }
+}
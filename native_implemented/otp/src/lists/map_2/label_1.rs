@@ -0,0 +1,57 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (function, tail, reversed_mapped)
+//! # returned from call: mapped
+//! # full stack: (mapped, function, tail, reversed_mapped)
+//! # returns: mapped_list
+//! case tail do
+//!   [] -> :lists.reverse([mapped | reversed_mapped])
+//!   [head | tail] -> map(function, tail, [function.(head) | [mapped | reversed_mapped]])
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+// Private
+
+#[native_implemented::label]
+fn result(
+    process: &Process,
+    mapped: Term,
+    function: Term,
+    tail: Term,
+    reversed_mapped: Term,
+) -> Term {
+    assert!(function.is_boxed_function());
+
+    let reversed_mapped = process.cons(mapped, reversed_mapped);
+
+    match tail.decode().unwrap() {
+        TypedTerm::Nil => {
+            let reversed_mapped_cons: Boxed<Cons> = reversed_mapped.try_into().unwrap();
+            let mut mapped_list = Term::NIL;
+
+            for result in reversed_mapped_cons.into_iter() {
+                mapped_list = process.cons(result.unwrap(), mapped_list);
+            }
+
+            mapped_list
+        }
+        TypedTerm::List(cons) => {
+            let arguments = process.list_from_slice(&[cons.head]);
+
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(function, arguments));
+            process.queue_frame_with_arguments(
+                frame().with_arguments(true, &[function, cons.tail, reversed_mapped]),
+            );
+
+            Term::NONE
+        }
+        _ => unreachable!("tail ({}) is not a proper list", tail),
+    }
+}
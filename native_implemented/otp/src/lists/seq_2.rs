@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(lists:seq/2)]
+pub fn result(process: &Process, from: Term, to: Term) -> exception::Result<Term> {
+    let from_isize = term_try_into_isize!(from)?;
+    let to_isize = term_try_into_isize!(to)?;
+
+    if from_isize - 1 <= to_isize {
+        let vec: Vec<Term> = (from_isize..=to_isize)
+            .map(|i| process.integer(i))
+            .collect();
+
+        Ok(process.list_from_slice(&vec))
+    } else {
+        Err(anyhow!("to ({}) is less than from ({}) - 1", to, from).into())
+    }
+}
@@ -0,0 +1,69 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::seq_2::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_integer_from_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_integer(arc_process.clone()),
+            )
+        },
+        |(arc_process, from)| {
+            let to = arc_process.integer(1);
+
+            prop_assert_badarg!(
+                result(&arc_process, from, to),
+                format!("from ({}) is not an integer", from)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_to_one_less_than_from_returns_empty_list() {
+    with_process(|process| {
+        let from = process.integer(1);
+        let to = process.integer(0);
+
+        assert_eq!(result(process, from, to), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn with_to_greater_than_from_returns_inclusive_list() {
+    with_process(|process| {
+        let from = process.integer(1);
+        let to = process.integer(3);
+
+        assert_eq!(
+            result(process, from, to),
+            Ok(process.list_from_slice(&[
+                process.integer(1),
+                process.integer(2),
+                process.integer(3)
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_to_less_than_from_minus_one_errors_badarg() {
+    with_process(|process| {
+        let from = process.integer(2);
+        let to = process.integer(0);
+
+        assert_badarg!(
+            result(process, from, to),
+            format!("to ({}) is less than from ({}) - 1", to, from)
+        );
+    });
+}
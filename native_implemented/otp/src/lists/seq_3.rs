@@ -0,0 +1,41 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(lists:seq/3)]
+pub fn result(process: &Process, from: Term, to: Term, incr: Term) -> exception::Result<Term> {
+    let from_isize = term_try_into_isize!(from)?;
+    let to_isize = term_try_into_isize!(to)?;
+    let incr_isize = term_try_into_isize!(incr)?;
+
+    let vec: Vec<Term> = match incr_isize.cmp(&0) {
+        Ordering::Greater if from_isize - incr_isize <= to_isize => (from_isize..=to_isize)
+            .step_by(incr_isize as usize)
+            .map(|i| process.integer(i))
+            .collect(),
+        Ordering::Less if to_isize <= from_isize - incr_isize => (to_isize..=from_isize)
+            .rev()
+            .step_by((-incr_isize) as usize)
+            .map(|i| process.integer(i))
+            .collect(),
+        Ordering::Equal if from_isize == to_isize => vec![from],
+        _ => {
+            return Err(anyhow!(
+                "to ({}) cannot be reached from from ({}) by incr ({})",
+                to,
+                from,
+                incr
+            )
+            .into())
+        }
+    };
+
+    Ok(process.list_from_slice(&vec))
+}
@@ -0,0 +1,81 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::seq_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_positive_incr_returns_list_up_to_to() {
+    with_process(|process| {
+        let from = process.integer(1);
+        let to = process.integer(6);
+        let incr = process.integer(2);
+
+        assert_eq!(
+            result(process, from, to, incr),
+            Ok(process.list_from_slice(&[
+                process.integer(1),
+                process.integer(3),
+                process.integer(5)
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_negative_incr_returns_list_down_to_to() {
+    with_process(|process| {
+        let from = process.integer(5);
+        let to = process.integer(1);
+        let incr = process.integer(-2);
+
+        assert_eq!(
+            result(process, from, to, incr),
+            Ok(process.list_from_slice(&[
+                process.integer(5),
+                process.integer(3),
+                process.integer(1)
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_zero_incr_and_from_equal_to_to_returns_from() {
+    with_process(|process| {
+        let from = process.integer(1);
+        let incr = process.integer(0);
+
+        assert_eq!(
+            result(process, from, from, incr),
+            Ok(process.list_from_slice(&[from]))
+        );
+    });
+}
+
+#[test]
+fn with_to_before_from_minus_incr_errors_badarg() {
+    with_process(|process| {
+        let from = process.integer(5);
+        let to = process.integer(1);
+        let incr = process.integer(1);
+
+        assert_badarg!(
+            result(process, from, to, incr),
+            format!(
+                "to ({}) cannot be reached from from ({}) by incr ({})",
+                to, from, incr
+            )
+        );
+    });
+}
+
+#[test]
+fn with_to_one_before_from_returns_empty_list() {
+    with_process(|process| {
+        let from = process.integer(1);
+        let to = process.integer(0);
+        let incr = process.integer(1);
+
+        assert_eq!(result(process, from, to, incr), Ok(Term::NIL));
+    });
+}
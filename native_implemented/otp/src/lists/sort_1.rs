@@ -0,0 +1,30 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(lists:sort/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(list),
+        TypedTerm::List(cons) => {
+            let mut vec = cons
+                .into_iter()
+                .collect::<Result<Vec<Term>, _>>()
+                .map_err(|_| ImproperListError)
+                .with_context(|| format!("list ({}) is improper", list))?;
+            // Stable, like BEAM's merge sort, so elements that compare equal, such as `1` and
+            // `1.0`, keep their relative order
+            vec.sort();
+
+            Ok(process.list_from_slice(&vec))
+        }
+        _ => Err(TypeError)
+            .context(format!("list ({}) is not a list", list))
+            .map_err(From::from),
+    }
+}
@@ -0,0 +1,53 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lists::sort_1::result;
+use crate::test::strategy;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_list(arc_process.clone()),
+            )
+        },
+        |(arc_process, list)| {
+            prop_assert_badarg!(
+                result(&arc_process, list),
+                format!("list ({}) is not a list", list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_improper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process.improper_list_from_slice(&[process.integer(1)], process.integer(2));
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) is improper", list)
+        );
+    });
+}
+
+#[test]
+fn with_proper_list_returns_list_in_term_order() {
+    with_process(|process| {
+        let atom = Atom::str_to_term("a");
+        let list =
+            process.list_from_slice(&[atom, process.integer(2), Term::NIL, process.integer(1)]);
+
+        assert_eq!(
+            result(process, list),
+            Ok(process.list_from_slice(&[process.integer(1), process.integer(2), atom, Term::NIL]))
+        );
+    });
+}
@@ -0,0 +1,162 @@
+//! Mirrors [logger](http://erlang.org/doc/man/logger.html) module
+//!
+//! There is no logger process, so events are filtered by the primary level, which the runtime
+//! sets when it starts, and then formatted and written by the console handler in the calling
+//! process.  The console handler uses the template `[level, ": ", msg, "\n"]` and writes events
+//! at `error` or more severe to stderr and the rest to stdout, which on wasm32 are
+//! `console.error` and `console.log`.
+
+pub mod get_primary_config_0;
+pub mod log_3;
+pub mod log_4;
+pub mod set_primary_config_2;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::io_lib::format::{self, chardata_to_string, DEFAULT_LINE_LENGTH};
+use crate::io_lib::write;
+use crate::runtime::context::term_try_into_atom;
+use crate::runtime::logger::{self, Level};
+use crate::runtime::sys::io::{put_chars, put_error_chars};
+
+/// The indentation of each `Key: Value` line of a report
+const REPORT_INDENTATION: usize = 4;
+
+/// The message of a log event, which is only formatted if the event passes the primary level
+enum Message {
+    /// `Format` and `Args`, as passed to `io_lib:format/2`
+    Format { format: Term, args: Term },
+    /// Chardata, written as is
+    String(Term),
+    /// The `{Key, Value}` pairs of a map or proplist report
+    Report(Vec<(Term, Term)>),
+}
+
+impl Message {
+    /// `logger:format_report/1` for reports
+    fn to_string(&self) -> exception::Result<String> {
+        match self {
+            Message::Format { format, args } => format::format(*format, *args),
+            Message::String(string) => chardata_to_string(*string, true),
+            Message::Report(pairs) => {
+                let lines: Vec<String> = pairs
+                    .iter()
+                    .map(|(key, value)| {
+                        let mut line = " ".repeat(REPORT_INDENTATION);
+                        line.push_str(&print(*key, REPORT_INDENTATION));
+                        line.push_str(": ");
+
+                        let column = line.chars().count();
+                        let value_string =
+                            printable_string(*value).unwrap_or_else(|| print(*value, column));
+                        line.push_str(&value_string);
+
+                        line
+                    })
+                    .collect();
+
+                Ok(lines.join("\n"))
+            }
+        }
+    }
+}
+
+fn log(level: Term, message: Message) -> exception::Result<Term> {
+    let level = term_try_into_level("level", level)?;
+
+    if logger::primary_level().allows(level) {
+        let string = format!("{}: {}\n", level, message.to_string()?);
+
+        if level <= Level::Error {
+            put_error_chars(&string);
+        } else {
+            put_chars(&string);
+        }
+    }
+
+    Ok(atom!("ok"))
+}
+
+fn module() -> Atom {
+    Atom::from_str("logger")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// `~tp`
+fn print(term: Term, indentation: usize) -> String {
+    write::print(term, -1, DEFAULT_LINE_LENGTH, indentation, true, true)
+}
+
+/// Report values that are printable lists are written with `~ts`, so without quotes
+fn printable_string(value: Term) -> Option<String> {
+    match value.decode() {
+        Ok(TypedTerm::List(_)) => chardata_to_string(value, true)
+            .ok()
+            .filter(|string| string.chars().all(|c| write::is_printable(c, true))),
+        _ => None,
+    }
+}
+
+fn term_try_into_level(name: &str, term: Term) -> exception::Result<Level> {
+    let atom = term_try_into_atom(name, term)?;
+
+    match atom.name().parse() {
+        Ok(level) => Ok(level),
+        Err(_) => Err(anyhow!(
+            "{} ({}) is not emergency, alert, critical, error, warning, notice, info, or debug",
+            name,
+            term
+        )
+        .into()),
+    }
+}
+
+fn term_try_into_metadata(term: Term) -> exception::Result<Boxed<Map>> {
+    term.try_into()
+        .with_context(|| format!("metadata ({}) is not a map", term))
+        .map_err(From::from)
+}
+
+/// A string, or a report, which is a map or a non-empty list of `{Key, Value}` pairs
+fn term_try_into_string_or_report(term: Term) -> exception::Result<Message> {
+    match term.decode()? {
+        TypedTerm::Map(map) => {
+            let mut pairs: Vec<(Term, Term)> =
+                map.iter().map(|(key, value)| (*key, *value)).collect();
+            pairs.sort_by(|(left_key, _), (right_key, _)| left_key.cmp(right_key));
+
+            Ok(Message::Report(pairs))
+        }
+        TypedTerm::List(cons) => {
+            let option_pairs: Option<Vec<(Term, Term)>> = cons
+                .iter()
+                .map(|result| match result.ok()?.decode() {
+                    Ok(TypedTerm::Tuple(tuple)) if tuple.len() == 2 => Some((tuple[0], tuple[1])),
+                    _ => None,
+                })
+                .collect();
+
+            match option_pairs {
+                Some(pairs) => Ok(Message::Report(pairs)),
+                None => Ok(Message::String(term)),
+            }
+        }
+        TypedTerm::Nil => Ok(Message::String(term)),
+        typed_term if typed_term.is_binary() => Ok(Message::String(term)),
+        _ => Err(TypeError)
+            .context(format!(
+                "string_or_report ({}) is not a string or report",
+                term
+            ))
+            .map_err(From::from),
+    }
+}
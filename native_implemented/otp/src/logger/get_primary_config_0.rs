@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::logger;
+
+/// Filters and metadata can't be configured, so they are always the defaults
+#[native_implemented::function(logger:get_primary_config/0)]
+pub fn result(process: &Process) -> Term {
+    process.map_from_slice(&[
+        (atom!("filter_default"), atom!("log")),
+        (atom!("filters"), Term::NIL),
+        (
+            atom!("level"),
+            Atom::str_to_term(logger::primary_level().as_str()),
+        ),
+        (atom!("metadata"), process.map_from_slice(&[])),
+    ])
+}
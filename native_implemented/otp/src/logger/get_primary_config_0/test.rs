@@ -0,0 +1,23 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::logger::get_primary_config_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_map_with_level_and_default_filters_and_metadata() {
+    with_process(|process| {
+        let config = result(process);
+        let config_map: Boxed<Map> = config.try_into().unwrap();
+
+        assert!(config_map.get(atom!("level")).unwrap().is_atom());
+        assert_eq!(config_map.get(atom!("filter_default")), Some(atom!("log")));
+        assert_eq!(config_map.get(atom!("filters")), Some(Term::NIL));
+        assert_eq!(
+            config_map.get(atom!("metadata")),
+            Some(process.map_from_slice(&[]))
+        );
+    });
+}
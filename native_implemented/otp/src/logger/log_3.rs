@@ -0,0 +1,30 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::logger::{log, term_try_into_string_or_report, Message};
+
+/// `log(Level, StringOrReport, Metadata)` or `log(Level, Format, Args)`
+#[native_implemented::function(logger:log/3)]
+pub fn result(level: Term, message: Term, args_or_metadata: Term) -> exception::Result<Term> {
+    match args_or_metadata.decode()? {
+        TypedTerm::Map(_) => log(level, term_try_into_string_or_report(message)?),
+        TypedTerm::Nil | TypedTerm::List(_) => log(
+            level,
+            Message::Format {
+                format: message,
+                args: args_or_metadata,
+            },
+        ),
+        _ => Err(TypeError)
+            .context(format!(
+                "args_or_metadata ({}) is not a list or map",
+                args_or_metadata
+            ))
+            .map_err(From::from),
+    }
+}
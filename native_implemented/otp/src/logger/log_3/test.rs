@@ -0,0 +1,124 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::logger::log_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_format_and_args_returns_ok() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                atom!("emergency"),
+                process.charlist_from_str("~w is ~s~n"),
+                process.list_from_slice(&[atom!("one"), process.charlist_from_str("two")])
+            ),
+            Ok(atom!("ok"))
+        );
+    });
+}
+
+#[test]
+fn with_string_and_metadata_returns_ok() {
+    with_process(|process| {
+        let metadata = process.map_from_slice(&[]);
+
+        for string in &[
+            process.charlist_from_str("charlist"),
+            process.binary_from_str("binary"),
+            Term::NIL,
+        ] {
+            assert_eq!(
+                result(atom!("emergency"), *string, metadata),
+                Ok(atom!("ok"))
+            );
+        }
+    });
+}
+
+#[test]
+fn with_map_or_proplist_report_and_metadata_returns_ok() {
+    with_process(|process| {
+        let metadata = process.map_from_slice(&[]);
+
+        for report in &[
+            process.map_from_slice(&[(atom!("key"), process.charlist_from_str("value"))]),
+            process.list_from_slice(&[
+                process.tuple_from_slice(&[atom!("key"), process.integer(1)]),
+                process.tuple_from_slice(&[atom!("other_key"), atom!("value")]),
+            ]),
+        ] {
+            assert_eq!(
+                result(atom!("emergency"), *report, metadata),
+                Ok(atom!("ok"))
+            );
+        }
+    });
+}
+
+#[test]
+fn below_primary_level_returns_ok_without_formatting() {
+    with_process(|process| {
+        // Too few arguments, but `debug` is below the default primary level of `notice`
+        assert_eq!(
+            result(atom!("debug"), process.charlist_from_str("~w"), Term::NIL),
+            Ok(atom!("ok"))
+        );
+    });
+}
+
+#[test]
+fn with_too_few_args_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                atom!("emergency"),
+                process.charlist_from_str("~w"),
+                Term::NIL
+            ),
+            "does not have enough arguments"
+        );
+    });
+}
+
+#[test]
+fn without_level_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                atom!("verbose"),
+                process.charlist_from_str("message"),
+                Term::NIL
+            ),
+            "level (verbose) is not emergency, alert, critical, error, warning, notice, info, or debug"
+        );
+    });
+}
+
+#[test]
+fn without_string_or_report_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                atom!("emergency"),
+                process.integer(1),
+                process.map_from_slice(&[])
+            ),
+            "string_or_report (1) is not a string or report"
+        );
+    });
+}
+
+#[test]
+fn without_list_args_or_map_metadata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                atom!("emergency"),
+                process.charlist_from_str("message"),
+                atom!("metadata")
+            ),
+            "args_or_metadata (metadata) is not a list or map"
+        );
+    });
+}
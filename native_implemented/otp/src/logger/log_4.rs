@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::logger::{log, term_try_into_metadata, Message};
+
+#[native_implemented::function(logger:log/4)]
+pub fn result(level: Term, format: Term, args: Term, metadata: Term) -> exception::Result<Term> {
+    term_try_into_metadata(metadata)?;
+
+    log(level, Message::Format { format, args })
+}
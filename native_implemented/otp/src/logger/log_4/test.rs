@@ -0,0 +1,50 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::logger::log_4::result;
+use crate::test::with_process;
+
+#[test]
+fn with_format_args_and_metadata_returns_ok() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                atom!("emergency"),
+                process.charlist_from_str("~p~n"),
+                process.list_from_slice(&[process.integer(1)]),
+                process.map_from_slice(&[(atom!("domain"), Term::NIL)])
+            ),
+            Ok(atom!("ok"))
+        );
+    });
+}
+
+#[test]
+fn without_level_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process.integer(1),
+                process.charlist_from_str("message"),
+                Term::NIL,
+                process.map_from_slice(&[])
+            ),
+            "level (1) is not an atom"
+        );
+    });
+}
+
+#[test]
+fn without_map_metadata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                atom!("emergency"),
+                process.charlist_from_str("message"),
+                Term::NIL,
+                Term::NIL
+            ),
+            "metadata ([]) is not a map"
+        );
+    });
+}
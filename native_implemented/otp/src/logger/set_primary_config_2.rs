@@ -0,0 +1,44 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_atom;
+use crate::runtime::logger::{self, PrimaryLevel};
+
+/// Only `level` can be set, so other keys are `{error, {invalid_primary_config, {Key, Value}}}`
+#[native_implemented::function(logger:set_primary_config/2)]
+pub fn result(process: &Process, key: Term, value: Term) -> exception::Result<Term> {
+    let key_atom = term_try_into_atom("key", key)?;
+
+    let result = if key_atom.name() == "level" {
+        let result_atom: Result<Atom, _> = value.try_into();
+
+        match result_atom
+            .ok()
+            .and_then(|atom| atom.name().parse::<PrimaryLevel>().ok())
+        {
+            Some(primary_level) => {
+                logger::set_primary_level(primary_level);
+
+                Ok(())
+            }
+            None => Err(process.tuple_from_slice(&[atom!("invalid_level"), value])),
+        }
+    } else {
+        Err(process.tuple_from_slice(&[
+            atom!("invalid_primary_config"),
+            process.tuple_from_slice(&[key, value]),
+        ]))
+    };
+
+    Ok(match result {
+        Ok(()) => atom!("ok"),
+        Err(reason) => process.tuple_from_slice(&[atom!("error"), reason]),
+    })
+}
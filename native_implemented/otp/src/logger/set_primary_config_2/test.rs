@@ -0,0 +1,73 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::logger::get_primary_config_0;
+use crate::logger::set_primary_config_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_level_sets_primary_level() {
+    with_process(|process| {
+        // Other tests rely on `emergency` being logged and `debug` being dropped, so stay between
+        assert_eq!(
+            result(process, atom!("level"), atom!("alert")),
+            Ok(atom!("ok"))
+        );
+
+        let config = get_primary_config_0::result(process);
+        let config_map: Boxed<Map> = config.try_into().unwrap();
+
+        assert_eq!(config_map.get(atom!("level")), Some(atom!("alert")));
+
+        assert_eq!(
+            result(process, atom!("level"), atom!("notice")),
+            Ok(atom!("ok"))
+        );
+    });
+}
+
+#[test]
+fn without_level_returns_invalid_level_error() {
+    with_process(|process| {
+        for level in &[atom!("verbose"), process.integer(1)] {
+            assert_eq!(
+                result(process, atom!("level"), *level),
+                Ok(process.tuple_from_slice(&[
+                    atom!("error"),
+                    process.tuple_from_slice(&[atom!("invalid_level"), *level])
+                ]))
+            );
+        }
+    });
+}
+
+#[test]
+fn with_other_key_returns_invalid_primary_config_error() {
+    with_process(|process| {
+        let key = atom!("filters");
+        let value = Term::NIL;
+
+        assert_eq!(
+            result(process, key, value),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.tuple_from_slice(&[
+                    atom!("invalid_primary_config"),
+                    process.tuple_from_slice(&[key, value])
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_atom_key_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.integer(1), atom!("notice")),
+            "key (1) is not an atom"
+        );
+    });
+}
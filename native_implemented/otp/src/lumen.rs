@@ -2,6 +2,7 @@
 
 pub mod apply_apply_2_1;
 pub mod apply_apply_3_1;
+pub mod call_nif_3;
 pub mod is_big_integer_1;
 pub mod is_small_integer_1;
 pub mod log_exit_1;
@@ -0,0 +1,56 @@
+//! Calls a function from a NIF library loaded with `erlang:load_nif/2`.  The Erlang stubs of NIF
+//! functions call this, as compiled modules can't be replaced by the library.
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::{Arity, ModuleFunctionArity};
+
+use crate::erlang::apply::arguments_term_to_vec;
+
+#[native_implemented::function(lumen:call_nif/3)]
+fn result(
+    process: &Process,
+    module: Term,
+    function: Term,
+    arguments: Term,
+) -> exception::Result<Term> {
+    let module_atom = term_try_into_atom!(module)?;
+    let function_atom = term_try_into_atom!(function)?;
+    let argument_vec = arguments_term_to_vec(arguments)?;
+    let arity = argument_vec.len() as Arity;
+
+    let module_function_arity = ModuleFunctionArity {
+        module: module_atom,
+        function: function_atom,
+        arity,
+    };
+
+    match lumen_nif::get(&module_function_arity) {
+        Some(nif) => nif.call(process, &argument_vec),
+        None => {
+            let trace = Trace::capture();
+            trace.set_top_frame(&module_function_arity, argument_vec.as_slice());
+
+            Err(exception::undef(
+                trace,
+                Some(
+                    anyhow!(
+                        "{}:{}/{} is not a loaded NIF",
+                        module_atom.name(),
+                        function_atom.name(),
+                        arity
+                    )
+                    .into(),
+                ),
+            )
+            .into())
+        }
+    }
+}
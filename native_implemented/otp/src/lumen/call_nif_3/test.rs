@@ -0,0 +1,56 @@
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lumen::call_nif_3::result;
+use crate::test::strategy;
+
+#[test]
+fn without_atom_module_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_atom(arc_process.clone()),
+            )
+        },
+        |(arc_process, module)| {
+            prop_assert_is_not_atom!(
+                result(&arc_process, module, Atom::str_to_term("add"), Term::NIL),
+                module
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_loaded_nif_errors_undef() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, argument)| {
+            let arguments = arc_process.list_from_slice(&[argument]);
+
+            prop_assert_error!(
+                result(
+                    &arc_process,
+                    Atom::str_to_term("not_a_nif_library"),
+                    Atom::str_to_term("add"),
+                    arguments
+                ),
+                "undef",
+                atom!("undef"),
+                "not_a_nif_library:add/1 is not a loaded NIF"
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -17,6 +17,12 @@ macro_rules! term_try_into_bool {
     };
 }
 
+macro_rules! term_try_into_function_with_arity {
+    ($name:ident, $arity:expr) => {
+        crate::runtime::context::term_try_into_function_with_arity(stringify!($name), $name, $arity)
+    };
+}
+
 macro_rules! term_try_into_isize {
     ($name:ident) => {
         crate::runtime::context::term_try_into_isize(stringify!($name), $name)
@@ -81,45 +81,26 @@ macro_rules! bitshift_infix_operator {
         use liblumen_alloc::erts::term::prelude::*;
         use liblumen_alloc::erts::process::trace::Trace;
 
-        pub const MAX_SHIFT: usize = std::mem::size_of::<isize>() * 8 - 1;
-
         let option_shifted = match $integer.decode().unwrap() {
             TypedTerm::SmallInteger(integer_small_integer) => {
-                let integer_isize: isize = integer_small_integer.into();
+                let integer: Integer = integer_small_integer.into();
                 let shift_isize: isize = term_try_into_isize!($shift).map_err(ArcError::new).map_err(|source| badarith(Trace::capture(), Some(source)))?;
 
-                // Rust doesn't support negative shift, so negative left shifts need to be right shifts
-                if 0 <= shift_isize {
+                // Rust doesn't support negative shift, so negative left shifts need to be right
+                // shifts.  Shifting `Integer` promotes to a `BigInteger` when bits would be shifted
+                // out.
+                let shifted = if 0 <= shift_isize {
                     let shift_usize = shift_isize as usize;
 
-                    if shift_usize <= MAX_SHIFT {
-                        let shifted = integer_isize $positive shift_usize;
-                        let shifted_term = $process.integer(shifted);
-
-                        Some(shifted_term)
-                    } else {
-                        let big_int: BigInt = integer_isize.into();
-                        let shifted = big_int $positive shift_usize;
-                        let shifted_term = $process.integer(shifted);
-
-                        Some(shifted_term)
-                    }
+                    integer $positive shift_usize
                 } else {
                     let shift_usize = (-shift_isize) as usize;
 
-                    if shift_usize <= MAX_SHIFT {
-                        let shifted = integer_isize $negative shift_usize;
-                        let shifted_term = $process.integer(shifted);
-
-                        Some(shifted_term)
-                    } else {
-                        let big_int: BigInt = integer_isize.into();
-                        let shifted = big_int $negative shift_usize;
-                        let shifted_term = $process.integer(shifted);
+                    integer $negative shift_usize
+                };
+                let shifted_term = $process.integer(shifted);
 
-                        Some(shifted_term)
-                    }
-                }
+                Some(shifted_term)
             }
             TypedTerm::BigInteger(integer_big_integer) => {
                 let big_int = integer_big_integer.as_ref();
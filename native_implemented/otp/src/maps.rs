@@ -1,17 +1,29 @@
 pub mod find_2;
+pub mod fold_3;
 pub mod from_list_1;
 pub mod get_2;
 pub mod get_3;
 pub mod is_key_2;
 pub mod keys_1;
 pub mod merge_2;
+pub mod new_0;
 pub mod put_3;
 pub mod remove_2;
+pub mod size_1;
 pub mod take_2;
+pub mod to_list_1;
 pub mod update_3;
+pub mod update_with_3;
+pub mod update_with_4;
 pub mod values_1;
+pub mod with_2;
+pub mod without_2;
 
-use liblumen_alloc::erts::term::prelude::Atom;
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
 
 fn module() -> Atom {
     Atom::from_str("maps")
@@ -20,3 +32,38 @@ fn module() -> Atom {
 fn module_id() -> usize {
     module().id()
 }
+
+fn keys_to_vec(keys: Term) -> exception::Result<Vec<Term>> {
+    match keys.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => {
+            let mut vec = Vec::new();
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(key) => vec.push(key),
+                    Err(_) => {
+                        return Err(ImproperListError)
+                            .context(format!("keys ({}) is improper", keys))
+                            .map_err(From::from)
+                    }
+                }
+            }
+
+            Ok(vec)
+        }
+        _ => Err(TypeError)
+            .context(format!("keys ({}) is not a list", keys))
+            .map_err(From::from),
+    }
+}
+
+/// `{key, value}` tuples in the same order as `Map::keys` and `Map::values`.
+fn to_list(process: &Process, boxed_map: &Boxed<Map>) -> Term {
+    let entry_vec: Vec<Term> = boxed_map
+        .iter()
+        .map(|(key, value)| process.tuple_from_slice(&[*key, *value]))
+        .collect();
+
+    process.list_from_slice(&entry_vec)
+}
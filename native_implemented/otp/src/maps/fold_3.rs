@@ -0,0 +1,37 @@
+//! ```elixir
+//! def fold(function, initial, map) do
+//!   fold_list(function, initial, :maps.to_list(map))
+//! end
+//!
+//! defp fold_list(_function, acc, []), do: acc
+//! defp fold_list(function, acc, [{key, value} | tail]) do
+//!   fold_list(function, function.(key, value, acc), tail)
+//! end
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(maps:fold/3)]
+pub fn result(
+    process: &Process,
+    function: Term,
+    initial: Term,
+    map: Term,
+) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+    term_try_into_function_with_arity!(function, 3)?;
+    let iterator = super::to_list(process, &boxed_map);
+
+    process.queue_frame_with_arguments(
+        label_1::frame().with_arguments(false, &[initial, function, iterator]),
+    );
+
+    Ok(Term::NONE)
+}
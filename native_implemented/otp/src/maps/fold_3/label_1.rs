@@ -0,0 +1,40 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (function, iterator)
+//! # returned from call: acc
+//! # full stack: (acc, function, iterator)
+//! # returns: acc
+//! case iterator do
+//!   [] -> acc
+//!   [{key, value} | tail] -> fold_list(function, function.(key, value, acc), tail)
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, acc: Term, function: Term, iterator: Term) -> Term {
+    assert!(function.is_boxed_function());
+
+    match iterator.decode().unwrap() {
+        TypedTerm::Nil => acc,
+        TypedTerm::List(cons) => {
+            let entry: Boxed<Tuple> = cons.head.try_into().unwrap();
+            let arguments = process.list_from_slice(&[entry[0], entry[1], acc]);
+            let tail_frame_with_arguments = frame().with_arguments(true, &[function, cons.tail]);
+
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(function, arguments));
+            process.queue_frame_with_arguments(tail_frame_with_arguments);
+
+            Term::NONE
+        }
+        _ => unreachable!("iterator ({}) is not a list", iterator),
+    }
+}
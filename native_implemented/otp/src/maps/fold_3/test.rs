@@ -0,0 +1,98 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::maps::fold_3::result;
+use crate::test::strategy;
+
+#[test]
+fn without_map_errors_badmap() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_function_with_arity(arc_process.clone(), 3),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_map(arc_process.clone()),
+            )
+        },
+        |(arc_process, function, initial, map)| {
+            prop_assert_badmap!(
+                result(&arc_process, function, initial, map),
+                &arc_process,
+                map
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_without_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_function(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, function, initial)| {
+            let map = arc_process.map_from_slice(&[]);
+
+            prop_assert_badarg!(
+                result(&arc_process, function, initial, map),
+                format!("function ({}) is not a function", function)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_without_arity_3_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_function_with_arity(arc_process.clone(), 2),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, function, initial)| {
+            let map = arc_process.map_from_slice(&[]);
+
+            prop_assert_badarg!(
+                result(&arc_process, function, initial, map),
+                format!("function ({}) arity (2) is not 3", function)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_with_arity_3_function_folds_in_queued_frames() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_function_with_arity(arc_process.clone(), 3),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, function, initial, key, value)| {
+            let map = arc_process.map_from_slice(&[(key, value)]);
+
+            prop_assert_eq!(result(&arc_process, function, initial, map), Ok(Term::NONE));
+
+            Ok(())
+        },
+    );
+}
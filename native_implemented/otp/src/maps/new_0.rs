@@ -0,0 +1,10 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(maps:new/0)]
+pub fn result(process: &Process) -> Term {
+    process.map_from_slice(&[])
+}
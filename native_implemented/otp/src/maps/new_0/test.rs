@@ -0,0 +1,11 @@
+use crate::maps::new_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_map() {
+    with_process(|process| {
+        let empty_map = process.map_from_slice(&[]);
+
+        assert_eq!(result(process), empty_map);
+    });
+}
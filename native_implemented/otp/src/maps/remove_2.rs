@@ -10,7 +10,7 @@ pub fn result(process: &Process, key: Term, map: Term) -> exception::Result<Term
     let boxed_map = term_try_into_map_or_badmap!(process, map)?;
 
     match boxed_map.remove(key) {
-        Some(hash_map) => Ok(process.map_from_hash_map(hash_map)),
+        Some(new_map) => Ok(process.map(new_map)),
         None => Ok(map),
     }
 }
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(maps:size/1)]
+pub fn result(process: &Process, map: Term) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+    let len = boxed_map.len();
+    let len_term = process.integer(len);
+
+    Ok(len_term)
+}
@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn with_empty_map_returns_zero() {
+    with_process_arc(|arc_process| {
+        let empty_map = arc_process.map_from_slice(&[]);
+
+        assert_eq!(result(&arc_process, empty_map), Ok(arc_process.integer(0)));
+    });
+}
+
+#[test]
+fn returns_number_of_entries() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(
+                &(strategy::term(arc_process.clone())).prop_map(|value| {
+                    arc_process.map_from_slice(&[(atom!("key1"), value), (atom!("key2"), value)])
+                }),
+                |map| {
+                    prop_assert_eq!(result(&arc_process, map), Ok(arc_process.integer(2)));
+
+                    Ok(())
+                },
+            )
+            .unwrap();
+    });
+}
@@ -11,9 +11,9 @@ pub fn result(process: &Process, key: Term, map: Term) -> exception::Result<Term
     let boxed_map = term_try_into_map_or_badmap!(process, map)?;
 
     let result = match boxed_map.take(key) {
-        Some((value, hash_map)) => {
-            let map = process.map_from_hash_map(hash_map);
-            process.tuple_from_slice(&[value, map])
+        Some((value, new_map)) => {
+            let new_map = process.map(new_map);
+            process.tuple_from_slice(&[value, new_map])
         }
         None => atom!("error"),
     };
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(maps:to_list/1)]
+pub fn result(process: &Process, map: Term) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+    let list = super::to_list(process, &boxed_map);
+
+    Ok(list)
+}
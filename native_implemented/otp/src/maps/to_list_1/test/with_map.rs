@@ -0,0 +1,33 @@
+use super::*;
+
+#[test]
+fn with_empty_map_returns_empty_list() {
+    with_process_arc(|arc_process| {
+        let empty_map = arc_process.map_from_slice(&[]);
+
+        assert_eq!(result(&arc_process, empty_map), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn returns_list_of_key_value_tuples() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(
+                &(strategy::term(arc_process.clone())).prop_map(|value| {
+                    let key = atom!("key");
+
+                    (
+                        arc_process.list_from_slice(&[arc_process.tuple_from_slice(&[key, value])]),
+                        arc_process.map_from_slice(&[(key, value)]),
+                    )
+                }),
+                |(list, map)| {
+                    prop_assert_eq!(result(&arc_process, map), Ok(list));
+
+                    Ok(())
+                },
+            )
+            .unwrap();
+    });
+}
@@ -13,7 +13,7 @@ pub fn result(process: &Process, key: Term, value: Term, map: Term) -> exception
     let boxed_map = term_try_into_map_or_badmap!(process, map)?;
 
     match boxed_map.update(key, value) {
-        Some(hash_map) => Ok(process.map_from_hash_map(hash_map)),
+        Some(new_map) => Ok(process.map(new_map)),
         None => Err(badkey(
             process,
             key,
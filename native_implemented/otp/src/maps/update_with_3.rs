@@ -0,0 +1,44 @@
+//! ```elixir
+//! def update_with(key, function, map) do
+//!   value = :maps.get(key, map)
+//!   new_value = function.(value)
+//!   :maps.update(key, new_value, map)
+//! end
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception::{self, *};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+
+#[native_implemented::function(maps:update_with/3)]
+pub fn result(process: &Process, key: Term, function: Term, map: Term) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+    term_try_into_function_with_arity!(function, 1)?;
+
+    match boxed_map.get(key) {
+        Some(value) => {
+            let arguments = process.list_from_slice(&[value]);
+
+            process.queue_frame_with_arguments(apply_2::frame_with_arguments(function, arguments));
+            process.queue_frame_with_arguments(label_1::frame().with_arguments(true, &[key, map]));
+
+            Ok(Term::NONE)
+        }
+        None => Err(badkey(
+            process,
+            key,
+            Trace::capture(),
+            anyhow!("key ({}) does not exist in map ({})", key, map).into(),
+        )),
+    }
+}
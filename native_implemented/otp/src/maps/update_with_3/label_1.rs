@@ -0,0 +1,23 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (key, map)
+//! # returned from call: new_value
+//! # full stack: (new_value, key, map)
+//! # returns: updated_map
+//! :maps.update(key, new_value, map)
+//! ```
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, new_value: Term, key: Term, map: Term) -> Term {
+    let boxed_map: Boxed<Map> = map.try_into().unwrap();
+    let new_map = boxed_map.update(key, new_value).unwrap();
+
+    process.map(new_map)
+}
@@ -0,0 +1,95 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::maps::update_with_3::result;
+use crate::test::strategy;
+
+#[test]
+fn without_map_errors_badmap() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_function_with_arity(arc_process.clone(), 1),
+                strategy::term::is_not_map(arc_process.clone()),
+            )
+        },
+        |(arc_process, key, function, map)| {
+            prop_assert_badmap!(result(&arc_process, key, function, map), &arc_process, map);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_without_arity_1_function_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_function(arc_process.clone()),
+            )
+        },
+        |(arc_process, key, function)| {
+            let map = arc_process.map_from_slice(&[(key, key)]);
+
+            prop_assert_badarg!(
+                result(&arc_process, key, function, map),
+                format!("function ({}) is not a function", function)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_without_key_errors_badkey() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_function_with_arity(arc_process.clone(), 1),
+            )
+        },
+        |(arc_process, key, function)| {
+            let empty_map = arc_process.map_from_slice(&[]);
+
+            prop_assert_badkey!(
+                result(&arc_process, key, function, empty_map),
+                &arc_process,
+                key,
+                format!("key ({}) does not exist in map ({})", key, empty_map)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_map_with_key_updates_in_queued_frames() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_function_with_arity(arc_process.clone(), 1),
+            )
+        },
+        |(arc_process, key, value, function)| {
+            let map = arc_process.map_from_slice(&[(key, value)]);
+
+            prop_assert_eq!(result(&arc_process, key, function, map), Ok(Term::NONE));
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,38 @@
+//! ```elixir
+//! def update_with(key, function, initial, map) do
+//!   case :maps.find(key, map) do
+//!     {:ok, _} -> :maps.update_with(key, function, map)
+//!     :error -> :maps.put(key, initial, map)
+//!   end
+//! end
+//! ```
+
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::update_with_3;
+
+#[native_implemented::function(maps:update_with/4)]
+pub fn result(
+    process: &Process,
+    key: Term,
+    function: Term,
+    initial: Term,
+    map: Term,
+) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+
+    if boxed_map.is_key(key) {
+        update_with_3::result(process, key, function, map)
+    } else {
+        term_try_into_function_with_arity!(function, 1)?;
+
+        let new_map = boxed_map.put(key, initial).unwrap();
+
+        Ok(process.map(new_map))
+    }
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use hashbrown::HashMap;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(maps:with/2)]
+pub fn result(process: &Process, keys: Term, map: Term) -> exception::Result<Term> {
+    let boxed_map = term_try_into_map_or_badmap!(process, map)?;
+    let key_vec = super::keys_to_vec(keys)?;
+    let mut hash_map = HashMap::with_capacity(key_vec.len());
+
+    for key in key_vec {
+        if let Some(value) = boxed_map.get(key) {
+            hash_map.insert(key, value);
+        }
+    }
+
+    Ok(process.map_from_hash_map(hash_map))
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(
+                &(
+                    strategy::term::is_not_list(arc_process.clone()),
+                    strategy::term::map(arc_process.clone()),
+                ),
+                |(keys, map)| {
+                    prop_assert_badarg!(
+                        result(&arc_process, keys, map),
+                        format!("keys ({}) is not a list", keys)
+                    );
+
+                    Ok(())
+                },
+            )
+            .unwrap();
+    });
+}
+
+#[test]
+fn with_key_returns_map_with_only_key() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(&strategy::term(arc_process.clone()), |value| {
+                let key = atom!("key");
+                let other_key = atom!("other_key");
+                let map = arc_process.map_from_slice(&[(key, value), (other_key, value)]);
+                let keys = arc_process.list_from_slice(&[key]);
+
+                prop_assert_eq!(
+                    result(&arc_process, keys, map),
+                    Ok(arc_process.map_from_slice(&[(key, value)]))
+                );
+
+                Ok(())
+            })
+            .unwrap();
+    });
+}
+
+#[test]
+fn without_key_returns_empty_map() {
+    with_process_arc(|arc_process| {
+        TestRunner::new(Config::with_source_file(file!()))
+            .run(&strategy::term(arc_process.clone()), |value| {
+                let map = arc_process.map_from_slice(&[(atom!("key"), value)]);
+                let keys = arc_process.list_from_slice(&[atom!("non_key")]);
+
+                prop_assert_eq!(
+                    result(&arc_process, keys, map),
+                    Ok(arc_process.map_from_slice(&[]))
+                );
+
+                Ok(())
+            })
+            .unwrap();
+    });
+}
@@ -0,0 +1,93 @@
+//! Mirrors [math](http://erlang.org/doc/man/math.html) module
+//!
+//! Like the BEAM, arguments that are not numbers error `badarg` and results that are not finite,
+//! such as from `sqrt(-1)` or `log(0)`, error `badarith`.
+
+pub mod acos_1;
+pub mod asin_1;
+pub mod atan2_2;
+pub mod atan_1;
+pub mod ceil_1;
+pub mod cos_1;
+pub mod erf_1;
+pub mod exp_1;
+pub mod floor_1;
+pub mod fmod_2;
+pub mod log10_1;
+pub mod log2_1;
+pub mod log_1;
+pub mod pi_0;
+pub mod pow_2;
+pub mod sin_1;
+pub mod sqrt_1;
+pub mod tan_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception::{self, *};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_is_not_number;
+
+fn module() -> Atom {
+    Atom::from_str("math")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// `function(x)` as a float.
+fn unary(
+    process: &Process,
+    function: &'static str,
+    x: Term,
+    f: impl FnOnce(f64) -> f64,
+) -> exception::Result<Term> {
+    let x_f64 = number_to_f64("x", x)?;
+
+    finite_to_term(process, f(x_f64), || format!("{}({})", function, x))
+}
+
+/// `function(left, right)` as a float.
+fn binary(
+    process: &Process,
+    function: &'static str,
+    (left_name, left): (&'static str, Term),
+    (right_name, right): (&'static str, Term),
+    f: impl FnOnce(f64, f64) -> f64,
+) -> exception::Result<Term> {
+    let left_f64 = number_to_f64(left_name, left)?;
+    let right_f64 = number_to_f64(right_name, right)?;
+
+    finite_to_term(process, f(left_f64, right_f64), || {
+        format!("{}({}, {})", function, left, right)
+    })
+}
+
+fn finite_to_term(
+    process: &Process,
+    f: f64,
+    call: impl FnOnce() -> String,
+) -> exception::Result<Term> {
+    if f.is_finite() {
+        Ok(process.float(f))
+    } else {
+        Err(badarith(
+            Trace::capture(),
+            Some(anyhow!("{} is {}, which is not a finite float", call(), f).into()),
+        )
+        .into())
+    }
+}
+
+fn number_to_f64(name: &'static str, number: Term) -> exception::Result<f64> {
+    number
+        .try_into()
+        .with_context(|| term_is_not_number(name, number))
+        .map_err(From::from)
+}
@@ -0,0 +1,25 @@
+use liblumen_alloc::atom;
+
+use crate::math::asin_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_number_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("zero")), "x (zero) is not a number");
+    });
+}
+
+#[test]
+fn with_number_returns_float() {
+    with_process(|process| {
+        assert_eq!(result(process, process.integer(0)), Ok(process.float(0.0)));
+    });
+}
+
+#[test]
+fn outside_domain_errors_badarith() {
+    with_process(|process| {
+        assert_badarith!(result(process, process.float(-1.5)));
+    });
+}
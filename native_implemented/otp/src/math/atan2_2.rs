@@ -0,0 +1,12 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The arc tangent of `y / x`, in the quadrant of the point `(x, y)`.
+#[native_implemented::function(math:atan2/2)]
+pub fn result(process: &Process, y: Term, x: Term) -> exception::Result<Term> {
+    super::binary(process, "atan2", ("y", y), ("x", x), f64::atan2)
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::atom;
+
+use crate::math::atan2_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_number_y_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("zero"), process.integer(1)),
+            "y (zero) is not a number"
+        );
+    });
+}
+
+#[test]
+fn with_number_y_without_number_x_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.integer(1), atom!("zero")),
+            "x (zero) is not a number"
+        );
+    });
+}
+
+#[test]
+fn with_numbers_returns_float() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.integer(0), process.integer(1)),
+            Ok(process.float(0.0))
+        );
+    });
+}
@@ -0,0 +1,12 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the smallest integral float not less than `x`.
+#[native_implemented::function(math:ceil/1)]
+pub fn result(process: &Process, x: Term) -> exception::Result<Term> {
+    super::unary(process, "ceil", x, f64::ceil)
+}
@@ -0,0 +1,18 @@
+use liblumen_alloc::atom;
+
+use crate::math::ceil_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_number_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("zero")), "x (zero) is not a number");
+    });
+}
+
+#[test]
+fn with_number_returns_float() {
+    with_process(|process| {
+        assert_eq!(result(process, process.float(1.2)), Ok(process.float(2.0)));
+    });
+}
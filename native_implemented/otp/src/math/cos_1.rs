@@ -0,0 +1,11 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(math:cos/1)]
+pub fn result(process: &Process, x: Term) -> exception::Result<Term> {
+    super::unary(process, "cos", x, f64::cos)
+}
@@ -0,0 +1,12 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The remainder of `x / y`, with the sign of `x`.
+#[native_implemented::function(math:fmod/2)]
+pub fn result(process: &Process, x: Term, y: Term) -> exception::Result<Term> {
+    super::binary(process, "fmod", ("x", x), ("y", y), |x, y| x % y)
+}
@@ -0,0 +1,41 @@
+use liblumen_alloc::atom;
+
+use crate::math::fmod_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_number_x_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("zero"), process.integer(1)),
+            "x (zero) is not a number"
+        );
+    });
+}
+
+#[test]
+fn with_number_x_without_number_y_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.integer(1), atom!("zero")),
+            "y (zero) is not a number"
+        );
+    });
+}
+
+#[test]
+fn with_numbers_returns_float() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.integer(7), process.integer(-3)),
+            Ok(process.float(1.0))
+        );
+    });
+}
+
+#[test]
+fn outside_domain_errors_badarith() {
+    with_process(|process| {
+        assert_badarith!(result(process, process.integer(1), process.integer(0)));
+    });
+}
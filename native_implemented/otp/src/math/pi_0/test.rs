@@ -0,0 +1,11 @@
+use std::f64::consts::PI;
+
+use crate::math::pi_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_pi() {
+    with_process(|process| {
+        assert_eq!(result(process), process.float(PI));
+    });
+}
@@ -0,0 +1,11 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(math:pow/2)]
+pub fn result(process: &Process, x: Term, y: Term) -> exception::Result<Term> {
+    super::binary(process, "pow", ("x", x), ("y", y), f64::powf)
+}
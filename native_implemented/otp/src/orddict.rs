@@ -0,0 +1,95 @@
+//! Mirrors [orddict](http://erlang.org/doc/man/orddict.html) module
+//!
+//! An orddict is a proper list of `{Key, Value}` pairs ordered by key.  Like OTP, keys are compared
+//! with `==` and `<`, so `1` and `1.0` are the same key.
+
+pub mod erase_2;
+pub mod fetch_2;
+pub mod find_2;
+pub mod from_list_1;
+pub mod is_key_2;
+pub mod new_0;
+pub mod store_3;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("orddict")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Where `key` belongs in an orddict
+struct Position {
+    /// The entries with lesser keys
+    before: Vec<Term>,
+    /// The entry with `key`, if any
+    found: Option<Boxed<Tuple>>,
+    /// The entries with greater keys
+    after: Term,
+}
+
+fn position(key: Term, orddict: Term) -> exception::Result<Position> {
+    let mut before = Vec::new();
+    let mut list = orddict;
+
+    loop {
+        match list.decode()? {
+            TypedTerm::Nil => {
+                return Ok(Position {
+                    before,
+                    found: None,
+                    after: list,
+                })
+            }
+            TypedTerm::List(cons) => {
+                let entry = term_try_into_entry("orddict", orddict, cons.head)?;
+
+                match key.cmp(&entry[0]) {
+                    Ordering::Less => {
+                        return Ok(Position {
+                            before,
+                            found: None,
+                            after: list,
+                        })
+                    }
+                    Ordering::Equal => {
+                        return Ok(Position {
+                            before,
+                            found: Some(entry),
+                            after: cons.tail,
+                        })
+                    }
+                    Ordering::Greater => {
+                        before.push(cons.head);
+                        list = cons.tail;
+                    }
+                }
+            }
+            _ => {
+                return Err(ImproperListError)
+                    .context(format!("orddict ({}) is not a proper list", orddict))
+                    .map_err(From::from)
+            }
+        }
+    }
+}
+
+fn term_try_into_entry(name: &str, list: Term, entry: Term) -> exception::Result<Boxed<Tuple>> {
+    match entry.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => Ok(tuple),
+        _ => Err(TypeError)
+            .context(format!(
+                "{} ({}) entry ({}) is not a {{Key, Value}} pair",
+                name, list, entry
+            ))
+            .map_err(From::from),
+    }
+}
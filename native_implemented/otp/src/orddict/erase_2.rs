@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `orddict` without `key`.  The entries after `key` are shared with `orddict`.
+#[native_implemented::function(orddict:erase/2)]
+pub fn result(process: &Process, key: Term, orddict: Term) -> exception::Result<Term> {
+    let position = super::position(key, orddict)?;
+
+    Ok(match position.found {
+        Some(_) => process.improper_list_from_slice(&position.before, position.after),
+        None => orddict,
+    })
+}
@@ -0,0 +1,35 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::erase_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_key_removes_entry() {
+    with_process(|process| {
+        let a = process.tuple_from_slice(&[atom!("a"), process.integer(1)]);
+        let b = process.tuple_from_slice(&[atom!("b"), process.integer(2)]);
+        let c = process.tuple_from_slice(&[atom!("c"), process.integer(3)]);
+        let orddict = process.list_from_slice(&[a, b, c]);
+
+        assert_eq!(
+            result(process, atom!("b"), orddict),
+            Ok(process.list_from_slice(&[a, c]))
+        );
+        assert_eq!(
+            result(process, atom!("a"), orddict),
+            Ok(process.list_from_slice(&[b, c]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_orddict() {
+    with_process(|process| {
+        let orddict =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("a"), process.integer(1)])]);
+
+        assert_eq!(result(process, atom!("b"), orddict), Ok(orddict));
+        assert_eq!(result(process, atom!("b"), Term::NIL), Ok(Term::NIL));
+    });
+}
@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The value for `key` in `orddict`, which must be there
+#[native_implemented::function(orddict:fetch/2)]
+pub fn result(key: Term, orddict: Term) -> exception::Result<Term> {
+    let position = super::position(key, orddict)?;
+
+    match position.found {
+        Some(entry) => Ok(entry[1]),
+        None => Err(anyhow!("key ({}) is not in orddict ({})", key, orddict).into()),
+    }
+}
@@ -0,0 +1,27 @@
+use liblumen_alloc::atom;
+
+use crate::orddict::fetch_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_value() {
+    with_process(|process| {
+        let orddict =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("a"), process.integer(1)])]);
+
+        assert_eq!(result(atom!("a"), orddict), Ok(process.integer(1)));
+    });
+}
+
+#[test]
+fn without_key_errors_badarg() {
+    with_process(|process| {
+        let orddict =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("a"), process.integer(1)])]);
+
+        assert_badarg!(
+            result(atom!("b"), orddict),
+            format!("key (b) is not in orddict ({})", orddict)
+        );
+    });
+}
@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `{ok, Value}` for `key` in `orddict`, or `error` if it is not there
+#[native_implemented::function(orddict:find/2)]
+pub fn result(process: &Process, key: Term, orddict: Term) -> exception::Result<Term> {
+    let position = super::position(key, orddict)?;
+
+    Ok(match position.found {
+        Some(entry) => process.tuple_from_slice(&[atom!("ok"), entry[1]]),
+        None => atom!("error"),
+    })
+}
@@ -0,0 +1,91 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::find_2::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                proptest::strategy::Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_list(arc_process),
+            )
+        },
+        |(arc_process, key, orddict)| {
+            prop_assert_badarg!(
+                result(&arc_process, key, orddict),
+                format!("orddict ({}) is not a proper list", orddict)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn without_pair_entry_errors_badarg() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[atom!("entry")]);
+
+        assert_badarg!(
+            result(process, atom!("key"), orddict),
+            format!(
+                "orddict ({}) entry (entry) is not a {{Key, Value}} pair",
+                orddict
+            )
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_error() {
+    with_process(|process| {
+        assert_eq!(result(process, atom!("key"), Term::NIL), Ok(atom!("error")));
+    });
+}
+
+#[test]
+fn with_key_returns_ok_value() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[process.integer(1), atom!("one")]),
+            process.tuple_from_slice(&[process.integer(2), atom!("two")]),
+        ]);
+
+        assert_eq!(
+            result(process, process.integer(2), orddict),
+            Ok(process.tuple_from_slice(&[atom!("ok"), atom!("two")]))
+        );
+    });
+}
+
+#[test]
+fn with_equal_key_of_other_number_type_returns_ok_value() {
+    with_process(|process| {
+        let orddict = process
+            .list_from_slice(&[process.tuple_from_slice(&[process.integer(1), atom!("one")])]);
+
+        assert_eq!(
+            result(process, process.float(1.0), orddict),
+            Ok(process.tuple_from_slice(&[atom!("ok"), atom!("one")]))
+        );
+    });
+}
+
+#[test]
+fn without_key_returns_error() {
+    with_process(|process| {
+        let orddict = process.list_from_slice(&[
+            process.tuple_from_slice(&[process.integer(1), atom!("one")]),
+            process.tuple_from_slice(&[process.integer(3), atom!("three")]),
+        ]);
+
+        assert_eq!(
+            result(process, process.integer(2), orddict),
+            Ok(atom!("error"))
+        );
+    });
+}
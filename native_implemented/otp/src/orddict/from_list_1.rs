@@ -0,0 +1,46 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// An orddict of the `{Key, Value}` pairs in `list`, where the last value for a key wins
+#[native_implemented::function(orddict:from_list/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let mut entries = match list.decode()? {
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => {
+            let mut entries = Vec::new();
+
+            for result in cons.into_iter() {
+                let element = result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("list ({}) is improper", list))?;
+                let entry = super::term_try_into_entry("list", list, element)?;
+                entries.push((entry[0], element));
+            }
+
+            entries
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!("list ({}) is not a list", list))
+                .map_err(From::from)
+        }
+    };
+
+    // Like `lists:ukeysort(1, lists:reverse(List))`, the stable sort keeps the last value of each
+    // key first, so it is the one kept by `dedup_by`
+    entries.reverse();
+    entries.sort_by(|(left_key, _), (right_key, _)| left_key.cmp(right_key));
+    entries.dedup_by(|(right_key, _), (left_key, _)| left_key.cmp(&right_key) == Ordering::Equal);
+
+    let entry_terms: Vec<Term> = entries.into_iter().map(|(_, entry)| entry).collect();
+
+    Ok(process.list_from_slice(&entry_terms))
+}
@@ -0,0 +1,50 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::from_list_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("list")), "list (list) is not a list");
+    });
+}
+
+#[test]
+fn without_pair_element_errors_badarg() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[process.tuple_from_slice(&[atom!("a")])]);
+
+        assert_badarg!(
+            result(process, list),
+            format!("list ({}) entry ({{a}}) is not a {{Key, Value}} pair", list)
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(result(process, Term::NIL), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn sorts_by_key_keeping_last_value() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            process.tuple_from_slice(&[atom!("b"), process.integer(1)]),
+            process.tuple_from_slice(&[atom!("a"), process.integer(2)]),
+            process.tuple_from_slice(&[atom!("b"), process.integer(3)]),
+        ]);
+
+        assert_eq!(
+            result(process, list),
+            Ok(process.list_from_slice(&[
+                process.tuple_from_slice(&[atom!("a"), process.integer(2)]),
+                process.tuple_from_slice(&[atom!("b"), process.integer(3)]),
+            ]))
+        );
+    });
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Whether `key` is in `orddict`
+#[native_implemented::function(orddict:is_key/2)]
+pub fn result(key: Term, orddict: Term) -> exception::Result<Term> {
+    let position = super::position(key, orddict)?;
+
+    Ok(position.found.is_some().into())
+}
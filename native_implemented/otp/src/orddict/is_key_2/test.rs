@@ -0,0 +1,26 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::is_key_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_key_returns_true() {
+    with_process(|process| {
+        let orddict =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("a"), process.integer(1)])]);
+
+        assert_eq!(result(atom!("a"), orddict), Ok(true.into()));
+    });
+}
+
+#[test]
+fn without_key_returns_false() {
+    with_process(|process| {
+        let orddict =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("a"), process.integer(1)])]);
+
+        assert_eq!(result(atom!("b"), orddict), Ok(false.into()));
+        assert_eq!(result(atom!("b"), Term::NIL), Ok(false.into()));
+    });
+}
@@ -0,0 +1,10 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+/// An empty orddict
+#[native_implemented::function(orddict:new/0)]
+pub fn result() -> Term {
+    Term::NIL
+}
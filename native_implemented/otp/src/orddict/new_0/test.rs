@@ -0,0 +1,8 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::new_0::result;
+
+#[test]
+fn returns_empty_list() {
+    assert_eq!(result(), Term::NIL);
+}
@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `orddict` with `value` for `key`, replacing any previous value.  The entries after `key` are
+/// shared with `orddict`.
+#[native_implemented::function(orddict:store/3)]
+pub fn result(process: &Process, key: Term, value: Term, orddict: Term) -> exception::Result<Term> {
+    let mut position = super::position(key, orddict)?;
+    position
+        .before
+        .push(process.tuple_from_slice(&[key, value]));
+
+    Ok(process.improper_list_from_slice(&position.before, position.after))
+}
@@ -0,0 +1,53 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::orddict::store_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_empty_list_returns_single_entry() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, atom!("a"), process.integer(1), Term::NIL),
+            Ok(process
+                .list_from_slice(&[process.tuple_from_slice(&[atom!("a"), process.integer(1)])]))
+        );
+    });
+}
+
+#[test]
+fn without_key_inserts_entry_in_order() {
+    with_process(|process| {
+        let a = process.tuple_from_slice(&[atom!("a"), process.integer(1)]);
+        let c = process.tuple_from_slice(&[atom!("c"), process.integer(3)]);
+        let orddict = process.list_from_slice(&[a, c]);
+        let b = process.tuple_from_slice(&[atom!("b"), process.integer(2)]);
+
+        assert_eq!(
+            result(process, atom!("b"), process.integer(2), orddict),
+            Ok(process.list_from_slice(&[a, b, c]))
+        );
+
+        let d = process.tuple_from_slice(&[atom!("d"), process.integer(4)]);
+
+        assert_eq!(
+            result(process, atom!("d"), process.integer(4), orddict),
+            Ok(process.list_from_slice(&[a, c, d]))
+        );
+    });
+}
+
+#[test]
+fn with_key_replaces_value() {
+    with_process(|process| {
+        let a = process.tuple_from_slice(&[atom!("a"), process.integer(1)]);
+        let b = process.tuple_from_slice(&[atom!("b"), process.integer(2)]);
+        let orddict = process.list_from_slice(&[a, b]);
+
+        assert_eq!(
+            result(process, atom!("a"), atom!("one"), orddict),
+            Ok(process
+                .list_from_slice(&[process.tuple_from_slice(&[atom!("a"), atom!("one")]), b]))
+        );
+    });
+}
@@ -0,0 +1,88 @@
+//! Mirrors [ordsets](http://erlang.org/doc/man/ordsets.html) module
+//!
+//! An ordset is a proper list sorted in term order without duplicates, where elements that
+//! compare equal, such as `1` and `1.0`, are duplicates.
+
+pub mod add_element_2;
+pub mod del_element_2;
+pub mod from_list_1;
+pub mod intersection_2;
+pub mod is_element_2;
+pub mod new_0;
+pub mod size_1;
+pub mod subtract_2;
+pub mod to_list_1;
+pub mod union_2;
+
+use std::cmp::Ordering;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("ordsets")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// The elements of `list`, which must be a proper list
+fn term_try_into_vec(name: &str, list: Term) -> exception::Result<Vec<Term>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("{} ({}) is not a proper list", name, list))
+            .map_err(From::from),
+        _ => Err(TypeError)
+            .context(format!("{} ({}) is not a proper list", name, list))
+            .map_err(From::from),
+    }
+}
+
+/// Sorts `vec` and removes duplicates, keeping the first of equal elements, like `lists:usort/1`
+fn usort(mut vec: Vec<Term>) -> Vec<Term> {
+    vec.sort();
+    vec.dedup_by(|later, earlier| later.cmp(&earlier) == Ordering::Equal);
+
+    vec
+}
+
+/// Walks both ordsets in order, calling `f` with elements only in the first, elements only in the
+/// second, and equal elements, so that each set operation only decides what to keep
+fn merge<F>(ordset1: &[Term], ordset2: &[Term], mut f: F)
+where
+    F: FnMut(Merged),
+{
+    let mut iter1 = ordset1.iter().copied().peekable();
+    let mut iter2 = ordset2.iter().copied().peekable();
+
+    loop {
+        match (iter1.peek(), iter2.peek()) {
+            (Some(element1), Some(element2)) => match element1.cmp(element2) {
+                Ordering::Less => f(Merged::First(iter1.next().unwrap())),
+                Ordering::Equal => {
+                    iter2.next();
+                    f(Merged::Both(iter1.next().unwrap()))
+                }
+                Ordering::Greater => f(Merged::Second(iter2.next().unwrap())),
+            },
+            (Some(_), None) => f(Merged::First(iter1.next().unwrap())),
+            (None, Some(_)) => f(Merged::Second(iter2.next().unwrap())),
+            (None, None) => break,
+        }
+    }
+}
+
+/// Where an element from `merge` came from.  For equal elements, it is the one from the first
+/// ordset, like OTP.
+enum Merged {
+    First(Term),
+    Second(Term),
+    Both(Term),
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `ordset` with `element` inserted in order, unless an equal element is already in it
+#[native_implemented::function(ordsets:add_element/2)]
+pub fn result(process: &Process, element: Term, ordset: Term) -> exception::Result<Term> {
+    let mut vec = super::term_try_into_vec("ordset", ordset)?;
+
+    match vec.binary_search_by(|member| member.cmp(&element)) {
+        Ok(_) => Ok(ordset),
+        Err(index) => {
+            vec.insert(index, element);
+
+            Ok(process.list_from_slice(&vec))
+        }
+    }
+}
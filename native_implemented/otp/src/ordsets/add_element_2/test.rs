@@ -0,0 +1,40 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets::add_element_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("a"), atom!("ordset")),
+            "ordset (ordset) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn without_element_inserts_in_order() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[atom!("a"), atom!("c")]);
+
+        assert_eq!(
+            result(process, atom!("b"), ordset),
+            Ok(process.list_from_slice(&[atom!("a"), atom!("b"), atom!("c")]))
+        );
+        assert_eq!(
+            result(process, atom!("a"), Term::NIL),
+            Ok(process.list_from_slice(&[atom!("a")]))
+        );
+    });
+}
+
+#[test]
+fn with_equal_element_returns_ordset() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[process.integer(1), atom!("a")]);
+
+        assert_eq!(result(process, process.float(1.0), ordset), Ok(ordset));
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `ordset` without the element that compares equal to `element`, if any
+#[native_implemented::function(ordsets:del_element/2)]
+pub fn result(process: &Process, element: Term, ordset: Term) -> exception::Result<Term> {
+    let mut vec = super::term_try_into_vec("ordset", ordset)?;
+
+    match vec.binary_search_by(|member| member.cmp(&element)) {
+        Ok(index) => {
+            vec.remove(index);
+
+            Ok(process.list_from_slice(&vec))
+        }
+        Err(_) => Ok(ordset),
+    }
+}
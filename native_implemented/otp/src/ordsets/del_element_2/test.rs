@@ -0,0 +1,35 @@
+use liblumen_alloc::atom;
+
+use crate::ordsets::del_element_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("a"), atom!("ordset")),
+            "ordset (ordset) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn with_element_removes_it() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[atom!("a"), atom!("b"), atom!("c")]);
+
+        assert_eq!(
+            result(process, atom!("b"), ordset),
+            Ok(process.list_from_slice(&[atom!("a"), atom!("c")]))
+        );
+    });
+}
+
+#[test]
+fn without_element_returns_ordset() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[atom!("a"), atom!("c")]);
+
+        assert_eq!(result(process, atom!("b"), ordset), Ok(ordset));
+    });
+}
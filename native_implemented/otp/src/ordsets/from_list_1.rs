@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// An ordset of the elements of `list`
+#[native_implemented::function(ordsets:from_list/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let vec = super::term_try_into_vec("list", list)?;
+
+    Ok(process.list_from_slice(&super::usort(vec)))
+}
@@ -0,0 +1,67 @@
+use std::cmp::Ordering;
+
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets::from_list_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_proper_list(arc_process),
+            )
+        },
+        |(arc_process, list)| {
+            prop_assert_badarg!(
+                result(&arc_process, list),
+                format!("list ({}) is not a proper list", list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_proper_list_returns_sorted_list_without_duplicates() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term(arc_process), 0..=8),
+            )
+        },
+        |(arc_process, element_vec)| {
+            let list = arc_process.list_from_slice(&element_vec);
+            let mut ordset_vec = element_vec.clone();
+            ordset_vec.sort();
+            ordset_vec.dedup_by(|later, earlier| later.cmp(&earlier) == Ordering::Equal);
+
+            prop_assert_eq!(
+                result(&arc_process, list),
+                Ok(arc_process.list_from_slice(&ordset_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_equal_numbers_keeps_first() {
+    with_process(|process| {
+        let list =
+            process.list_from_slice(&[process.float(1.0), process.integer(2), process.integer(1)]);
+
+        assert_eq!(
+            result(process, list),
+            Ok(process.list_from_slice(&[process.float(1.0), process.integer(2)]))
+        );
+    });
+}
@@ -0,0 +1,24 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::Merged;
+
+/// The elements in both `ordset1` and `ordset2`
+#[native_implemented::function(ordsets:intersection/2)]
+pub fn result(process: &Process, ordset1: Term, ordset2: Term) -> exception::Result<Term> {
+    let vec1 = super::term_try_into_vec("ordset1", ordset1)?;
+    let vec2 = super::term_try_into_vec("ordset2", ordset2)?;
+    let mut intersection = Vec::new();
+
+    super::merge(&vec1, &vec2, |merged| {
+        if let Merged::Both(element) = merged {
+            intersection.push(element)
+        }
+    });
+
+    Ok(process.list_from_slice(&intersection))
+}
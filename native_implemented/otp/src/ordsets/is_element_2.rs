@@ -0,0 +1,19 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::cmp::Ordering;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Whether `ordset` has an element that compares equal to `element`
+#[native_implemented::function(ordsets:is_element/2)]
+pub fn result(element: Term, ordset: Term) -> exception::Result<Term> {
+    let vec = super::term_try_into_vec("ordset", ordset)?;
+    let is_element = vec
+        .iter()
+        .find(|member| element.cmp(member) != Ordering::Greater)
+        .map_or(false, |member| element.cmp(member) == Ordering::Equal);
+
+    Ok(is_element.into())
+}
@@ -0,0 +1,40 @@
+use liblumen_alloc::atom;
+
+use crate::ordsets::is_element_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    assert_badarg!(
+        result(atom!("a"), atom!("ordset")),
+        "ordset (ordset) is not a proper list"
+    );
+}
+
+#[test]
+fn with_element_returns_true() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[process.integer(1), atom!("a"), atom!("b")]);
+
+        assert_eq!(result(atom!("a"), ordset), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_equal_number_returns_true() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[process.integer(1)]);
+
+        assert_eq!(result(process.float(1.0), ordset), Ok(true.into()));
+    });
+}
+
+#[test]
+fn without_element_returns_false() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[process.integer(1), atom!("b")]);
+
+        assert_eq!(result(atom!("a"), ordset), Ok(false.into()));
+        assert_eq!(result(atom!("c"), ordset), Ok(false.into()));
+    });
+}
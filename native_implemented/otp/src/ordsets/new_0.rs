@@ -0,0 +1,10 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+/// An empty ordset
+#[native_implemented::function(ordsets:new/0)]
+pub fn result() -> Term {
+    Term::NIL
+}
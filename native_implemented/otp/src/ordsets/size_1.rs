@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The number of elements in `ordset`
+#[native_implemented::function(ordsets:size/1)]
+pub fn result(process: &Process, ordset: Term) -> exception::Result<Term> {
+    let vec = super::term_try_into_vec("ordset", ordset)?;
+
+    Ok(process.integer(vec.len()))
+}
@@ -0,0 +1,27 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets::size_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    with_process(|process| {
+        let ordset = process.improper_list_from_slice(&[atom!("a")], atom!("b"));
+
+        assert_badarg!(
+            result(process, ordset),
+            format!("ordset ({}) is not a proper list", ordset)
+        );
+    });
+}
+
+#[test]
+fn returns_number_of_elements() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[atom!("a"), atom!("b")]);
+
+        assert_eq!(result(process, Term::NIL), Ok(process.integer(0)));
+        assert_eq!(result(process, ordset), Ok(process.integer(2)));
+    });
+}
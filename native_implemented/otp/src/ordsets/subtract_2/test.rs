@@ -0,0 +1,58 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::ordsets::subtract_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_ordset1_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                atom!("ordset1"),
+                process.list_from_slice(&[atom!("a")])
+            ),
+            "ordset1 (ordset1) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn without_proper_list_ordset2_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.list_from_slice(&[atom!("a")]),
+                atom!("ordset2")
+            ),
+            "ordset2 (ordset2) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn with_ordsets_returns_ordset() {
+    with_process(|process| {
+        let ordset1 =
+            process.list_from_slice(&[process.integer(1), process.integer(2), process.integer(3)]);
+        let ordset2 =
+            process.list_from_slice(&[process.integer(2), process.integer(3), process.integer(4)]);
+
+        assert_eq!(
+            result(process, ordset1, ordset2),
+            Ok(process.list_from_slice(&[process.integer(1)]))
+        );
+    });
+}
+
+#[test]
+fn with_equal_numbers_removes_element() {
+    with_process(|process| {
+        let ordset1 = process.list_from_slice(&[process.float(1.0)]);
+        let ordset2 = process.list_from_slice(&[process.integer(1)]);
+
+        assert_eq!(result(process, ordset1, ordset2), Ok(Term::NIL));
+    });
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The elements of `ordset`, which is already a list
+#[native_implemented::function(ordsets:to_list/1)]
+pub fn result(ordset: Term) -> exception::Result<Term> {
+    super::term_try_into_vec("ordset", ordset)?;
+
+    Ok(ordset)
+}
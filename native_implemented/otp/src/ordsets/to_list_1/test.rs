@@ -0,0 +1,21 @@
+use liblumen_alloc::atom;
+
+use crate::ordsets::to_list_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    assert_badarg!(
+        result(atom!("ordset")),
+        "ordset (ordset) is not a proper list"
+    );
+}
+
+#[test]
+fn with_ordset_returns_ordset() {
+    with_process(|process| {
+        let ordset = process.list_from_slice(&[process.integer(1), atom!("a")]);
+
+        assert_eq!(result(ordset), Ok(ordset));
+    });
+}
@@ -0,0 +1,36 @@
+//! Mirrors [os](http://erlang.org/doc/man/os.html) module
+
+pub mod cmd_1;
+pub mod getenv_0;
+pub mod getenv_1;
+pub mod getenv_2;
+pub mod system_time_0;
+pub mod system_time_1;
+pub mod type_0;
+
+use std::env;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::list_to_string::list_to_string;
+
+fn module() -> Atom {
+    Atom::from_str("os")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// The value of the environment variable named by the string `name`, or `None` if it is not set.
+fn getenv(name: Term) -> exception::Result<Option<String>> {
+    let name = list_to_string(name)?;
+
+    // `env::var_os` may panic on names that can't be set
+    if name.is_empty() || name.contains(&['=', '\0'][..]) {
+        Ok(None)
+    } else {
+        Ok(env::var_os(name).map(|value| value.to_string_lossy().into_owned()))
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod label_1;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::unicode::characters;
+
+/// Runs `command` with `/bin/sh` and returns what it writes to stdout and stderr as a string.
+///
+/// The command runs on the `dirty_io` pool, so the calling process waits without blocking its
+/// scheduler.  On wasm32, where there are no OS processes, it errors `badarg`.
+#[native_implemented::function(os:cmd/1)]
+pub fn result(process: &Process, command: Term) -> exception::Result<Term> {
+    let command_string = match command.decode()? {
+        TypedTerm::Atom(atom) => atom.name().to_string(),
+        TypedTerm::Nil | TypedTerm::List(_) => {
+            let bytes = characters::to_utf8_bytes(process, "command", command)?;
+
+            // `to_utf8_bytes` only returns valid UTF-8
+            String::from_utf8(bytes).unwrap()
+        }
+        _ => {
+            return Err(TypeError)
+                .with_context(|| format!("command ({}) is not an atom or string", command))
+                .map_err(From::from)
+        }
+    };
+
+    spawn(process, command_string)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn(process: &Process, command: String) -> exception::Result<Term> {
+    use crate::runtime::{dirty_io, port};
+
+    let output = dirty_io::spawn(process, move || port::cmd(&command));
+    let output_term = process.resource(output);
+    process.queue_frame_with_arguments(label_1::frame().with_arguments(false, &[output_term]));
+
+    Ok(Term::NONE)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn spawn(_process: &Process, command: String) -> exception::Result<Term> {
+    Err(anyhow!("command ({:?}) cannot be run without OS processes", command).into())
+}
@@ -0,0 +1,42 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::dirty_io::Output;
+
+#[native_implemented::label]
+fn result(process: &Process, output: Term) -> exception::Result<Term> {
+    let taken = match output.decode().unwrap() {
+        TypedTerm::ResourceReference(resource_reference) => {
+            let resource: Resource = resource_reference.into();
+            let output = resource
+                .downcast_ref::<Output<anyhow::Result<Vec<u8>>>>()
+                .unwrap();
+
+            output.take_or_wait(process)
+        }
+        _ => unreachable!("output ({}) is not a resource", output),
+    };
+
+    match taken {
+        // Output that isn't UTF-8 is returned as bytes, as `latin1`
+        Some(Ok(bytes)) => match String::from_utf8(bytes) {
+            Ok(string) => Ok(process.charlist_from_str(&string)),
+            Err(error) => {
+                let chars: String = error
+                    .into_bytes()
+                    .iter()
+                    .map(|byte| *byte as char)
+                    .collect();
+
+                Ok(process.charlist_from_str(&chars))
+            }
+        },
+        Some(Err(error)) => Err(error.into()),
+        None => {
+            process.queue_frame_with_arguments(frame().with_arguments(false, &[output]));
+
+            Ok(Term::NONE)
+        }
+    }
+}
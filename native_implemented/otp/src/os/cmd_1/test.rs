@@ -0,0 +1,12 @@
+use crate::os::cmd_1;
+use crate::test::with_process;
+
+#[test]
+fn without_atom_or_string_command_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            cmd_1::result(process, process.integer(1)),
+            "command (1) is not an atom or string"
+        );
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::env;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the environment variables as a list of `"Name=Value"` strings.
+#[native_implemented::function(os:getenv/0)]
+pub fn result(process: &Process) -> Term {
+    let variables: Vec<Term> = env::vars_os()
+        .map(|(name, value)| {
+            let variable = format!("{}={}", name.to_string_lossy(), value.to_string_lossy());
+
+            process.charlist_from_str(&variable)
+        })
+        .collect();
+
+    process.list_from_slice(&variables)
+}
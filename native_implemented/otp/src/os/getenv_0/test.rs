@@ -0,0 +1,25 @@
+use std::env;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::os::getenv_0;
+use crate::test::with_process;
+
+#[test]
+fn includes_set_variable_as_name_equals_value() {
+    with_process(|process| {
+        env::set_var("LUMEN_OS_GETENV_0", "value");
+
+        let variables = getenv_0::result(process);
+        let variable = process.charlist_from_str("LUMEN_OS_GETENV_0=value");
+
+        match variables.decode().unwrap() {
+            TypedTerm::List(cons) => {
+                assert!(cons
+                    .into_iter()
+                    .any(|result| matches!(result, Ok(element) if element == variable)));
+            }
+            _ => panic!("variables ({}) is not a non-empty list", variables),
+        }
+    });
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the value of the environment variable `name` as a string, or `false` if it is not set.
+#[native_implemented::function(os:getenv/1)]
+pub fn result(process: &Process, name: Term) -> exception::Result<Term> {
+    let term = match super::getenv(name)? {
+        Some(value) => process.charlist_from_str(&value),
+        None => false.into(),
+    };
+
+    Ok(term)
+}
@@ -0,0 +1,49 @@
+use std::env;
+
+use crate::os::getenv_1;
+use crate::test::with_process;
+
+#[test]
+fn without_string_name_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            getenv_1::result(process, process.integer(1)),
+            "is not a list"
+        );
+    });
+}
+
+#[test]
+fn with_set_variable_returns_value_as_string() {
+    with_process(|process| {
+        env::set_var("LUMEN_OS_GETENV_1_SET", "value");
+
+        assert_eq!(
+            getenv_1::result(process, process.charlist_from_str("LUMEN_OS_GETENV_1_SET")),
+            Ok(process.charlist_from_str("value"))
+        );
+    });
+}
+
+#[test]
+fn without_set_variable_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            getenv_1::result(
+                process,
+                process.charlist_from_str("LUMEN_OS_GETENV_1_UNSET")
+            ),
+            Ok(false.into())
+        );
+    });
+}
+
+#[test]
+fn with_name_that_cannot_be_set_returns_false() {
+    with_process(|process| {
+        assert_eq!(
+            getenv_1::result(process, process.charlist_from_str("LUMEN=OS")),
+            Ok(false.into())
+        );
+    });
+}
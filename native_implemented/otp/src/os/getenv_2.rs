@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the value of the environment variable `name` as a string, or `default` if it is not
+/// set.
+#[native_implemented::function(os:getenv/2)]
+pub fn result(process: &Process, name: Term, default: Term) -> exception::Result<Term> {
+    let term = match super::getenv(name)? {
+        Some(value) => process.charlist_from_str(&value),
+        None => default,
+    };
+
+    Ok(term)
+}
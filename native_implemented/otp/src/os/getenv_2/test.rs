@@ -0,0 +1,36 @@
+use std::env;
+
+use liblumen_alloc::atom;
+
+use crate::os::getenv_2;
+use crate::test::with_process;
+
+#[test]
+fn with_set_variable_returns_value_as_string() {
+    with_process(|process| {
+        env::set_var("LUMEN_OS_GETENV_2_SET", "value");
+
+        assert_eq!(
+            getenv_2::result(
+                process,
+                process.charlist_from_str("LUMEN_OS_GETENV_2_SET"),
+                atom!("default")
+            ),
+            Ok(process.charlist_from_str("value"))
+        );
+    });
+}
+
+#[test]
+fn without_set_variable_returns_default() {
+    with_process(|process| {
+        assert_eq!(
+            getenv_2::result(
+                process,
+                process.charlist_from_str("LUMEN_OS_GETENV_2_UNSET"),
+                atom!("default")
+            ),
+            Ok(atom!("default"))
+        );
+    });
+}
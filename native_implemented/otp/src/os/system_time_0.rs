@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::time::{system, Unit::Native};
+
+/// Returns the OS system time in `native` time units, which `erlang:system_time/0` returns too.
+#[native_implemented::function(os:system_time/0)]
+pub fn result(process: &Process) -> Term {
+    let big_int = system::time_in_unit(Native);
+
+    process.integer(big_int)
+}
@@ -0,0 +1,18 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::os::system_time_0::result;
+use crate::test::with_process;
+
+#[test]
+fn increases_after_2_native_time_units() {
+    with_process(|process| {
+        let first = result(process);
+
+        thread::sleep(Duration::from_millis(2));
+
+        let second = result(process);
+
+        assert!(first < second);
+    });
+}
@@ -0,0 +1,20 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::Term;
+
+use crate::runtime::time::{system, Unit};
+
+/// Returns the OS system time in `unit`, which `erlang:system_time/1` returns too.
+#[native_implemented::function(os:system_time/1)]
+pub fn result(process: &Process, unit: Term) -> exception::Result<Term> {
+    let unit_unit: Unit = unit.try_into()?;
+    let big_int = system::time_in_unit(unit_unit);
+    let term = process.integer(big_int);
+
+    Ok(term)
+}
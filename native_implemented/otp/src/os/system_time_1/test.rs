@@ -0,0 +1,27 @@
+use std::thread;
+use std::time::Duration;
+
+use liblumen_alloc::atom;
+
+use crate::os::system_time_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_supported_unit_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("minute")), "supported units are");
+    });
+}
+
+#[test]
+fn with_millisecond_increases_after_2_milliseconds() {
+    with_process(|process| {
+        let first = result(process, atom!("millisecond")).unwrap();
+
+        thread::sleep(Duration::from_millis(2));
+
+        let second = result(process, atom!("millisecond")).unwrap();
+
+        assert!(first < second);
+    });
+}
@@ -0,0 +1,21 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::env::consts::{FAMILY, OS};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{OsFamily, OsName}` with the names the BEAM uses, such as `{unix, linux}`,
+/// `{unix, darwin}`, or `{win32, nt}`.  On wasm32, it is `{wasm32, unknown}` or `{wasm32, wasi}`.
+#[native_implemented::function(os:type/0)]
+pub fn result(process: &Process) -> Term {
+    let (os_family, os_name) = match (FAMILY, OS) {
+        ("windows", _) => ("win32", "nt"),
+        (_, "macos") => ("unix", "darwin"),
+        ("", os) => ("wasm32", os),
+        family_os => family_os,
+    };
+
+    process.tuple_from_slice(&[Atom::str_to_term(os_family), Atom::str_to_term(os_name)])
+}
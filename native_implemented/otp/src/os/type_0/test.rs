@@ -0,0 +1,26 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::os::type_0::result;
+use crate::test::with_process;
+
+#[cfg(target_os = "linux")]
+#[test]
+fn on_linux_returns_unix_linux() {
+    with_process(|process| {
+        assert_eq!(
+            result(process),
+            process.tuple_from_slice(&[Atom::str_to_term("unix"), Atom::str_to_term("linux")])
+        );
+    });
+}
+
+#[cfg(target_os = "macos")]
+#[test]
+fn on_macos_returns_unix_darwin() {
+    with_process(|process| {
+        assert_eq!(
+            result(process),
+            process.tuple_from_slice(&[Atom::str_to_term("unix"), Atom::str_to_term("darwin")])
+        );
+    });
+}
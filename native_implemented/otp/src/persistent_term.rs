@@ -0,0 +1,17 @@
+//! Mirrors [persistent_term](http://erlang.org/doc/man/persistent_term.html) module
+
+pub mod erase_1;
+pub mod get_1;
+pub mod get_2;
+pub mod info_0;
+pub mod put_2;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("persistent_term")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,23 @@
+use liblumen_alloc::atom;
+
+use crate::persistent_term::{erase_1, get_2, put_2};
+use crate::test::with_process;
+
+#[test]
+fn without_key_returns_false() {
+    assert_eq!(erase_1::result(atom!("erase_1_without_key")), false.into());
+}
+
+#[test]
+fn with_key_returns_true_and_removes_key() {
+    with_process(|process| {
+        let key = atom!("erase_1_with_key");
+        let value = process.tuple_from_slice(&[process.integer(1)]);
+        let default = atom!("default");
+
+        assert_eq!(put_2::result(key, value), Ok(atom!("ok")));
+        assert_eq!(erase_1::result(key), true.into());
+        assert_eq!(get_2::result(key, default), default);
+        assert_eq!(erase_1::result(key), false.into());
+    });
+}
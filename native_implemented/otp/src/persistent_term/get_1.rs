@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::persistent_term;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the shared term itself, without copying it to the calling process's heap.
+#[native_implemented::function(persistent_term:get/1)]
+pub fn result(key: Term) -> exception::Result<Term> {
+    persistent_term::get(key)
+        .ok_or_else(|| anyhow!("no persistent term stored with key ({})", key).into())
+}
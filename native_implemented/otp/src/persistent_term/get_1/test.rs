@@ -0,0 +1,31 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::alloc::Heap;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::persistent_term::{get_1, put_2};
+use crate::test::with_process;
+
+#[test]
+fn without_key_errors_badarg() {
+    assert_badarg!(
+        get_1::result(atom!("get_1_without_key")),
+        "no persistent term stored with key (get_1_without_key)"
+    );
+}
+
+#[test]
+fn with_key_returns_value_without_copying_to_process() {
+    with_process(|process| {
+        let key = atom!("get_1_with_key");
+        let value = process.tuple_from_slice(&[atom!("config"), process.integer(1)]);
+
+        assert_eq!(put_2::result(key, value), Ok(atom!("ok")));
+
+        let stored = get_1::result(key).unwrap();
+        let stored_ptr: *const Term = stored.dyn_cast();
+
+        assert_eq!(stored, value);
+        assert!(!process.acquire_heap().contains(stored_ptr));
+        assert_eq!(get_1::result(key), Ok(stored));
+    });
+}
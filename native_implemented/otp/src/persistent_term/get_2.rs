@@ -0,0 +1,10 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::persistent_term;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(persistent_term:get/2)]
+pub fn result(key: Term, default: Term) -> Term {
+    persistent_term::get(key).unwrap_or(default)
+}
@@ -0,0 +1,22 @@
+use liblumen_alloc::atom;
+
+use crate::persistent_term::{get_2, put_2};
+use crate::test::with_process;
+
+#[test]
+fn without_key_returns_default() {
+    let default = atom!("default");
+
+    assert_eq!(get_2::result(atom!("get_2_without_key"), default), default);
+}
+
+#[test]
+fn with_key_returns_value() {
+    with_process(|process| {
+        let key = atom!("get_2_with_key");
+        let value = process.list_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_eq!(put_2::result(key, value), Ok(atom!("ok")));
+        assert_eq!(get_2::result(key, atom!("default")), value);
+    });
+}
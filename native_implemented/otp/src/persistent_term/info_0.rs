@@ -0,0 +1,14 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::persistent_term;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(persistent_term:info/0)]
+pub fn result(process: &Process) -> Term {
+    let info = persistent_term::info();
+
+    process.map_from_slice(&[
+        (atom!("count"), process.integer(info.count)),
+        (atom!("memory"), process.integer(info.memory)),
+    ])
+}
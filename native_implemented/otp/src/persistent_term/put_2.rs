@@ -0,0 +1,11 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::persistent_term;
+use liblumen_alloc::erts::term::prelude::*;
+
+#[native_implemented::function(persistent_term:put/2)]
+pub fn result(key: Term, value: Term) -> exception::Result<Term> {
+    persistent_term::put(key, value)?;
+
+    Ok(atom!("ok"))
+}
@@ -0,0 +1,80 @@
+//! Mirrors [proplists](http://erlang.org/doc/man/proplists.html) module
+//!
+//! A property is either an atom, which is short for `{Atom, true}`, or a tuple whose first element
+//! is its key.  Like OTP, keys are compared with `=:=`, and elements that are neither are skipped.
+
+pub mod delete_2;
+pub mod get_bool_2;
+pub mod get_value_2;
+pub mod get_value_3;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("proplists")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// A property found for a key
+enum Property {
+    /// The key itself, which means `true`
+    Atom,
+    Tuple(Boxed<Tuple>),
+}
+impl Property {
+    /// The value of the property, or `None` if it is a tuple that is not a pair
+    fn value(&self) -> Option<Term> {
+        match self {
+            Self::Atom => Some(true.into()),
+            Self::Tuple(tuple) if tuple.len() == 2 => Some(tuple[1]),
+            Self::Tuple(_) => None,
+        }
+    }
+}
+
+/// The first property for `key` in `list`, a proper list
+fn lookup(key: Term, list: Term) -> exception::Result<Option<Property>> {
+    for property in term_try_into_properties(list)? {
+        if let Some(found) = property_for_key(key, property) {
+            return Ok(Some(found));
+        }
+    }
+
+    Ok(None)
+}
+
+/// `property` if it is `key` or a tuple whose first element is `key`
+fn property_for_key(key: Term, property: Term) -> Option<Property> {
+    match property.decode().unwrap() {
+        TypedTerm::Atom(_) if property == key => Some(Property::Atom),
+        TypedTerm::Tuple(tuple) if (1 <= tuple.len()) && exactly_equal(tuple[0], key) => {
+            Some(Property::Tuple(tuple))
+        }
+        _ => None,
+    }
+}
+
+fn exactly_equal(left: Term, right: Term) -> bool {
+    left.decode().unwrap().exact_eq(&right.decode().unwrap())
+}
+
+fn term_try_into_properties(list: Term) -> exception::Result<Vec<Term>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<Result<Vec<Term>, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("list ({}) is improper", list))
+            .map_err(From::from),
+        _ => Err(TypeError)
+            .context(format!("list ({}) is not a list", list))
+            .map_err(From::from),
+    }
+}
@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `list` without any of the properties for `key`
+#[native_implemented::function(proplists:delete/2)]
+pub fn result(process: &Process, key: Term, list: Term) -> exception::Result<Term> {
+    let kept: Vec<Term> = super::term_try_into_properties(list)?
+        .into_iter()
+        .filter(|property| super::property_for_key(key, *property).is_none())
+        .collect();
+
+    Ok(process.list_from_slice(&kept))
+}
@@ -0,0 +1,41 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplists::delete_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("key"), atom!("list")),
+            "list (list) is not a list"
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_list() {
+    with_process(|process| {
+        assert_eq!(result(process, atom!("key"), Term::NIL), Ok(Term::NIL));
+    });
+}
+
+#[test]
+fn removes_all_properties_for_key() {
+    with_process(|process| {
+        let other = process.tuple_from_slice(&[atom!("other"), process.integer(1)]);
+        let list = process.list_from_slice(&[
+            atom!("key"),
+            other,
+            process.tuple_from_slice(&[atom!("key"), process.integer(2)]),
+            process.tuple_from_slice(&[atom!("key")]),
+            process.integer(3),
+        ]);
+
+        assert_eq!(
+            result(process, atom!("key"), list),
+            Ok(process.list_from_slice(&[other, process.integer(3)]))
+        );
+    });
+}
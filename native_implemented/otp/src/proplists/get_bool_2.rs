@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Whether the first property for `key` in `list` is `key` or `{key, true}`
+#[native_implemented::function(proplists:get_bool/2)]
+pub fn result(key: Term, list: Term) -> exception::Result<Term> {
+    let value = super::lookup(key, list)?.and_then(|property| property.value());
+
+    Ok((value == Some(atom!("true"))).into())
+}
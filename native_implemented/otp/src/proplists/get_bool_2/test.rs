@@ -0,0 +1,58 @@
+use liblumen_alloc::atom;
+
+use crate::proplists::get_bool_2::result;
+use crate::test::with_process;
+
+#[test]
+fn with_improper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process.cons(atom!("a"), atom!("b"));
+
+        assert_badarg!(
+            result(atom!("c"), list),
+            format!("list ({}) is improper", list)
+        );
+    });
+}
+
+#[test]
+fn without_property_returns_false() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("other")]);
+
+        assert_eq!(result(atom!("key"), list), Ok(false.into()));
+    });
+}
+
+#[test]
+fn with_atom_property_returns_true() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("key")]);
+
+        assert_eq!(result(atom!("key"), list), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_first_pair_true_returns_true() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            process.tuple_from_slice(&[atom!("key"), true.into()]),
+            process.tuple_from_slice(&[atom!("key"), false.into()]),
+        ]);
+
+        assert_eq!(result(atom!("key"), list), Ok(true.into()));
+    });
+}
+
+#[test]
+fn with_first_pair_not_true_returns_false() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            process.tuple_from_slice(&[atom!("key"), atom!("yes")]),
+            process.tuple_from_slice(&[atom!("key"), true.into()]),
+        ]);
+
+        assert_eq!(result(atom!("key"), list), Ok(false.into()));
+    });
+}
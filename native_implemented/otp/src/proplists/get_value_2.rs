@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplists::get_value_3;
+
+/// `get_value(key, list, undefined)`
+#[native_implemented::function(proplists:get_value/2)]
+pub fn result(key: Term, list: Term) -> exception::Result<Term> {
+    get_value_3::result(key, list, atom!("undefined"))
+}
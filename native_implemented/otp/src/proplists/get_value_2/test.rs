@@ -0,0 +1,23 @@
+use liblumen_alloc::atom;
+
+use crate::proplists::get_value_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_property_returns_undefined() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("other")]);
+
+        assert_eq!(result(atom!("key"), list), Ok(atom!("undefined")));
+    });
+}
+
+#[test]
+fn with_pair_returns_value() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process.tuple_from_slice(&[atom!("key"), process.integer(1)])]);
+
+        assert_eq!(result(atom!("key"), list), Ok(process.integer(1)));
+    });
+}
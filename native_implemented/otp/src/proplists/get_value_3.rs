@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The value of the first property for `key` in `list`, or `default` if there is none or it is a
+/// tuple that is not a pair
+#[native_implemented::function(proplists:get_value/3)]
+pub fn result(key: Term, list: Term, default: Term) -> exception::Result<Term> {
+    let value = super::lookup(key, list)?.and_then(|property| property.value());
+
+    Ok(value.unwrap_or(default))
+}
@@ -0,0 +1,113 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplists::get_value_3::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_list(arc_process.clone()),
+                strategy::term(arc_process),
+            )
+        },
+        |(key, list, default)| {
+            prop_assert_badarg!(
+                result(key, list, default),
+                format!("list ({}) is not a list", list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_improper_list_errors_badarg() {
+    with_process(|process| {
+        let list = process.cons(atom!("a"), atom!("b"));
+
+        assert_badarg!(
+            result(atom!("c"), list, atom!("default")),
+            format!("list ({}) is improper", list)
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_default() {
+    run!(
+        |arc_process| {
+            (
+                strategy::term(arc_process.clone()),
+                Just(Term::NIL),
+                strategy::term(arc_process),
+            )
+        },
+        |(key, list, default)| {
+            prop_assert_eq!(result(key, list, default), Ok(default));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_atom_property_returns_true() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("other"), atom!("key")]);
+
+        assert_eq!(
+            result(atom!("key"), list, atom!("default")),
+            Ok(true.into())
+        );
+    });
+}
+
+#[test]
+fn with_pair_returns_value_of_first() {
+    with_process(|process| {
+        let key = process.integer(1);
+        let list = process.list_from_slice(&[
+            process.tuple_from_slice(&[process.integer(2), atom!("two")]),
+            process.tuple_from_slice(&[key, atom!("first")]),
+            process.tuple_from_slice(&[key, atom!("second")]),
+        ]);
+
+        assert_eq!(result(key, list, atom!("default")), Ok(atom!("first")));
+    });
+}
+
+#[test]
+fn with_tuple_that_is_not_pair_returns_default() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[
+            process.tuple_from_slice(&[atom!("key"), atom!("a"), atom!("b")]),
+            process.tuple_from_slice(&[atom!("key"), atom!("value")]),
+        ]);
+
+        assert_eq!(
+            result(atom!("key"), list, atom!("default")),
+            Ok(atom!("default"))
+        );
+    });
+}
+
+#[test]
+fn compares_keys_exactly() {
+    with_process(|process| {
+        let list = process
+            .list_from_slice(&[process.tuple_from_slice(&[process.float(1.0), atom!("float")])]);
+
+        assert_eq!(
+            result(process.integer(1), list, atom!("default")),
+            Ok(atom!("default"))
+        );
+    });
+}
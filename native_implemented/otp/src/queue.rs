@@ -0,0 +1,116 @@
+//! Mirrors [queue](http://erlang.org/doc/man/queue.html) module
+//!
+//! A queue is `{Rear, Front}`, the same as in OTP, so queues can be passed to and from Erlang
+//! code: `Front` has the oldest items first and `Rear` has the newest items first.  When one list
+//! runs out, half of the other is reversed into it, so `in/2` and `out/1` are amortized O(1).
+
+pub mod from_list_1;
+pub mod in_2;
+pub mod len_1;
+pub mod new_0;
+pub mod out_1;
+pub mod peek_1;
+pub mod to_list_1;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("queue")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+struct Queue {
+    rear: Term,
+    front: Term,
+}
+
+/// `queue` as its `{Rear, Front}` lists, which are checked to be lists, but not proper lists,
+/// like the guards in OTP
+fn term_try_into_queue(queue: Term) -> exception::Result<Queue> {
+    match queue.decode()? {
+        TypedTerm::Tuple(tuple)
+            if (tuple.len() == 2) && tuple[0].is_list() && tuple[1].is_list() =>
+        {
+            Ok(Queue {
+                rear: tuple[0],
+                front: tuple[1],
+            })
+        }
+        _ => Err(TypeError)
+            .context(format!("queue ({}) is not a queue", queue))
+            .map_err(From::from),
+    }
+}
+
+/// The elements of `list`, one of the lists in `queue`
+fn queue_list_to_vec(queue: Term, list: Term) -> exception::Result<Vec<Term>> {
+    match list.decode()? {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("queue ({}) is not a queue", queue))
+            .map_err(From::from),
+        _ => Err(TypeError)
+            .context(format!("queue ({}) is not a queue", queue))
+            .map_err(From::from),
+    }
+}
+
+fn queue(process: &Process, rear: Term, front: Term) -> Term {
+    process.tuple_from_slice(&[rear, front])
+}
+
+/// A queue of `front`, the oldest first, where all but the first half is moved to `Rear`
+fn front_to_queue(process: &Process, front: &[Term]) -> Term {
+    match front.len() {
+        0 => queue(process, Term::NIL, Term::NIL),
+        1 => queue(process, Term::NIL, process.list_from_slice(front)),
+        2 => queue(
+            process,
+            process.list_from_slice(&front[1..]),
+            process.list_from_slice(&front[..1]),
+        ),
+        len => {
+            let (front_front, front_rear) = front.split_at(len / 2 + 1);
+            let rear: Vec<Term> = front_rear.iter().rev().copied().collect();
+
+            queue(
+                process,
+                process.list_from_slice(&rear),
+                process.list_from_slice(front_front),
+            )
+        }
+    }
+}
+
+/// A queue of `rear`, the newest first, where all but the first half is moved to `Front`
+fn rear_to_queue(process: &Process, rear: &[Term]) -> Term {
+    match rear.len() {
+        0 => queue(process, Term::NIL, Term::NIL),
+        1 => queue(process, Term::NIL, process.list_from_slice(rear)),
+        2 => queue(
+            process,
+            process.list_from_slice(&rear[..1]),
+            process.list_from_slice(&rear[1..]),
+        ),
+        len => {
+            let (rear_rear, rear_front) = rear.split_at(len / 2 + 1);
+            let front: Vec<Term> = rear_front.iter().rev().copied().collect();
+
+            queue(
+                process,
+                process.list_from_slice(rear_rear),
+                process.list_from_slice(&front),
+            )
+        }
+    }
+}
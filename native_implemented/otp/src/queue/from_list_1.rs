@@ -0,0 +1,28 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// A queue of the items in `list`, where the head is the oldest
+#[native_implemented::function(queue:from_list/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let front_vec = match list.decode()? {
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .collect::<std::result::Result<Vec<Term>, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("list ({}) is not a proper list", list))?,
+        _ => {
+            return Err(TypeError)
+                .context(format!("list ({}) is not a proper list", list))
+                .map_err(From::from)
+        }
+    };
+
+    Ok(super::front_to_queue(process, &front_vec))
+}
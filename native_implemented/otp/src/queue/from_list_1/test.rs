@@ -0,0 +1,88 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::{Just, Strategy};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::from_list_1::result;
+use crate::queue::to_list_1;
+use crate::test::strategy;
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_proper_list(arc_process),
+            )
+        },
+        |(arc_process, list)| {
+            prop_assert_badarg!(
+                result(&arc_process, list),
+                format!("list ({}) is not a proper list", list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_proper_list_splits_items_between_rear_and_front() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term(arc_process), 0..=4),
+            )
+        },
+        |(arc_process, item_vec)| {
+            let list = arc_process.list_from_slice(&item_vec);
+            let (rear_vec, front_vec): (Vec<Term>, Vec<Term>) = match item_vec.len() {
+                0 => (vec![], vec![]),
+                1 => (vec![], vec![item_vec[0]]),
+                2 => (vec![item_vec[1]], vec![item_vec[0]]),
+                3 => (vec![item_vec[2]], vec![item_vec[0], item_vec[1]]),
+                _ => (
+                    vec![item_vec[3]],
+                    vec![item_vec[0], item_vec[1], item_vec[2]],
+                ),
+            };
+            let queue = arc_process.tuple_from_slice(&[
+                arc_process.list_from_slice(&rear_vec),
+                arc_process.list_from_slice(&front_vec),
+            ]);
+
+            prop_assert_eq!(result(&arc_process, list), Ok(queue));
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_proper_list_to_list_returns_list() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(
+                    strategy::term(arc_process),
+                    strategy::NON_EMPTY_RANGE_INCLUSIVE,
+                ),
+            )
+                .prop_map(|(arc_process, item_vec)| {
+                    let list = arc_process.list_from_slice(&item_vec);
+
+                    (arc_process, list)
+                })
+        },
+        |(arc_process, list)| {
+            let queue = result(&arc_process, list).unwrap();
+
+            prop_assert_eq!(to_list_1::result(&arc_process, queue), Ok(list));
+
+            Ok(())
+        },
+    );
+}
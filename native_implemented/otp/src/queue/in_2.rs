@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Inserts `item` at the rear of `queue`
+#[native_implemented::function(queue:in/2)]
+pub fn result(process: &Process, item: Term, queue: Term) -> exception::Result<Term> {
+    let super::Queue { rear, front } = super::term_try_into_queue(queue)?;
+
+    // Keeps `Front` non-empty when `Rear` has more than one item, so `out/1` does not reverse
+    let queue = match rear.decode()? {
+        TypedTerm::List(cons) if front.is_nil() && cons.tail.is_nil() => {
+            super::queue(process, process.cons(item, Term::NIL), rear)
+        }
+        _ => super::queue(process, process.cons(item, rear), front),
+    };
+
+    Ok(queue)
+}
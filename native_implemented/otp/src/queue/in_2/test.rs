@@ -0,0 +1,108 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::in_2::result;
+use crate::queue::{new_0, to_list_1};
+use crate::test::strategy;
+
+#[test]
+fn without_queue_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term::is_not_tuple(arc_process),
+            )
+        },
+        |(arc_process, item, queue)| {
+            prop_assert_badarg!(
+                result(&arc_process, item, queue),
+                format!("queue ({}) is not a queue", queue)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_empty_queue_puts_item_in_rear() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, item)| {
+            let queue = new_0::result(&arc_process);
+
+            prop_assert_eq!(
+                result(&arc_process, item, queue),
+                Ok(arc_process
+                    .tuple_from_slice(&[arc_process.list_from_slice(&[item]), Term::NIL]))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_one_item_in_rear_moves_it_to_front() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+                strategy::term(arc_process.clone()),
+            )
+        },
+        |(arc_process, first, second)| {
+            let queue =
+                arc_process.tuple_from_slice(&[arc_process.list_from_slice(&[first]), Term::NIL]);
+
+            prop_assert_eq!(
+                result(&arc_process, second, queue),
+                Ok(arc_process.tuple_from_slice(&[
+                    arc_process.list_from_slice(&[second]),
+                    arc_process.list_from_slice(&[first])
+                ]))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_items_to_list_returns_items_in_order() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(
+                    strategy::term(arc_process),
+                    strategy::NON_EMPTY_RANGE_INCLUSIVE,
+                ),
+            )
+        },
+        |(arc_process, item_vec)| {
+            let mut queue = new_0::result(&arc_process);
+
+            for item in &item_vec {
+                queue = result(&arc_process, *item, queue).unwrap();
+            }
+
+            prop_assert_eq!(
+                to_list_1::result(&arc_process, queue),
+                Ok(arc_process.list_from_slice(&item_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,16 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The number of items in `queue`
+#[native_implemented::function(queue:len/1)]
+pub fn result(process: &Process, queue: Term) -> exception::Result<Term> {
+    let super::Queue { rear, front } = super::term_try_into_queue(queue)?;
+    let len = super::queue_list_to_vec(queue, rear)?.len()
+        + super::queue_list_to_vec(queue, front)?.len();
+
+    Ok(process.integer(len))
+}
@@ -0,0 +1,54 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use crate::queue::len_1::result;
+use crate::queue::{from_list_1, in_2};
+use crate::test::strategy;
+
+#[test]
+fn without_queue_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_tuple(arc_process),
+            )
+        },
+        |(arc_process, queue)| {
+            prop_assert_badarg!(
+                result(&arc_process, queue),
+                format!("queue ({}) is not a queue", queue)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_queue_returns_number_of_items() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term(arc_process.clone()), 0..=8),
+                proptest::collection::vec(strategy::term(arc_process), 0..=8),
+            )
+        },
+        |(arc_process, from_list_vec, in_vec)| {
+            let list = arc_process.list_from_slice(&from_list_vec);
+            let mut queue = from_list_1::result(&arc_process, list).unwrap();
+
+            for item in &in_vec {
+                queue = in_2::result(&arc_process, *item, queue).unwrap();
+            }
+
+            prop_assert_eq!(
+                result(&arc_process, queue),
+                Ok(arc_process.integer(from_list_vec.len() + in_vec.len()))
+            );
+
+            Ok(())
+        },
+    );
+}
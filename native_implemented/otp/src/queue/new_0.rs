@@ -0,0 +1,11 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// An empty queue
+#[native_implemented::function(queue:new/0)]
+pub fn result(process: &Process) -> Term {
+    super::queue(process, Term::NIL, Term::NIL)
+}
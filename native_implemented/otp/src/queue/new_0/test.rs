@@ -0,0 +1,14 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::new_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_rear_and_front() {
+    with_process(|process| {
+        assert_eq!(
+            result(process),
+            process.tuple_from_slice(&[Term::NIL, Term::NIL])
+        );
+    });
+}
@@ -0,0 +1,54 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Removes the oldest item from `queue`, as `{{value, Item}, Queue}`, or `{empty, Queue}` if
+/// `queue` is empty
+#[native_implemented::function(queue:out/1)]
+pub fn result(process: &Process, queue: Term) -> exception::Result<Term> {
+    let super::Queue { rear, front } = super::term_try_into_queue(queue)?;
+
+    let (item, out_queue) = match front.decode()? {
+        TypedTerm::List(cons) => {
+            let out_queue = if cons.tail.is_nil() {
+                let rear_vec = super::queue_list_to_vec(queue, rear)?;
+
+                super::rear_to_queue(process, &rear_vec)
+            } else {
+                super::queue(process, rear, cons.tail)
+            };
+
+            (cons.head, out_queue)
+        }
+        _ => {
+            let rear_vec = super::queue_list_to_vec(queue, rear)?;
+
+            match rear_vec.split_first() {
+                None => return Ok(process.tuple_from_slice(&[atom!("empty"), queue])),
+                Some((newest, [])) => (*newest, super::queue(process, Term::NIL, Term::NIL)),
+                // Everything but the newest item moves to `Front`, oldest first
+                Some((newest, older)) => {
+                    let front_vec: Vec<Term> = older.iter().rev().copied().collect();
+                    let (oldest, out_front) = front_vec.split_first().unwrap();
+
+                    (
+                        *oldest,
+                        super::queue(
+                            process,
+                            process.list_from_slice(&[*newest]),
+                            process.list_from_slice(out_front),
+                        ),
+                    )
+                }
+            }
+        }
+    };
+
+    let value = process.tuple_from_slice(&[atom!("value"), item]);
+
+    Ok(process.tuple_from_slice(&[value, out_queue]))
+}
@@ -0,0 +1,115 @@
+use std::collections::VecDeque;
+use std::convert::TryInto;
+
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::out_1::result;
+use crate::queue::{in_2, new_0, to_list_1};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_queue_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_tuple(arc_process),
+            )
+        },
+        |(arc_process, queue)| {
+            prop_assert_badarg!(
+                result(&arc_process, queue),
+                format!("queue ({}) is not a queue", queue)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_empty_queue_returns_empty_and_queue() {
+    with_process(|process| {
+        let queue = new_0::result(process);
+
+        assert_eq!(
+            result(process, queue),
+            Ok(process.tuple_from_slice(&[atom!("empty"), queue]))
+        );
+    });
+}
+
+#[test]
+fn with_items_only_in_rear_moves_all_but_newest_to_front() {
+    with_process(|process| {
+        let queue = process.tuple_from_slice(&[
+            process.list_from_slice(&[process.integer(3), process.integer(2), process.integer(1)]),
+            Term::NIL,
+        ]);
+
+        assert_eq!(
+            result(process, queue),
+            Ok(process.tuple_from_slice(&[
+                process.tuple_from_slice(&[atom!("value"), process.integer(1)]),
+                process.tuple_from_slice(&[
+                    process.list_from_slice(&[process.integer(3)]),
+                    process.list_from_slice(&[process.integer(2)])
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_ins_and_outs_returns_items_in_order_like_vec_deque() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                // `Some(item)` is `in(item, Queue)` and `None` is `out(Queue)`
+                proptest::collection::vec(
+                    proptest::option::of(strategy::term(arc_process)),
+                    0..=32,
+                ),
+            )
+        },
+        |(arc_process, operation_vec)| {
+            let mut queue = new_0::result(&arc_process);
+            let mut vec_deque = VecDeque::new();
+
+            for operation in operation_vec {
+                match operation {
+                    Some(item) => {
+                        queue = in_2::result(&arc_process, item, queue).unwrap();
+                        vec_deque.push_back(item);
+                    }
+                    None => {
+                        let out: Boxed<Tuple> =
+                            result(&arc_process, queue).unwrap().try_into().unwrap();
+                        let expected_value = match vec_deque.pop_front() {
+                            Some(item) => arc_process.tuple_from_slice(&[atom!("value"), item]),
+                            None => atom!("empty"),
+                        };
+
+                        prop_assert_eq!(out[0], expected_value);
+
+                        queue = out[1];
+                    }
+                }
+            }
+
+            let remaining_vec: Vec<Term> = vec_deque.into_iter().collect();
+
+            prop_assert_eq!(
+                to_list_1::result(&arc_process, queue),
+                Ok(arc_process.list_from_slice(&remaining_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
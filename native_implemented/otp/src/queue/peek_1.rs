@@ -0,0 +1,25 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The oldest item in `queue`, as `{value, Item}`, or `empty` if `queue` is empty
+#[native_implemented::function(queue:peek/1)]
+pub fn result(process: &Process, queue: Term) -> exception::Result<Term> {
+    let super::Queue { rear, front } = super::term_try_into_queue(queue)?;
+
+    let option_item = match front.decode()? {
+        TypedTerm::List(cons) => Some(cons.head),
+        _ => super::queue_list_to_vec(queue, rear)?.last().copied(),
+    };
+
+    let peek = match option_item {
+        Some(item) => process.tuple_from_slice(&[atom!("value"), item]),
+        None => atom!("empty"),
+    };
+
+    Ok(peek)
+}
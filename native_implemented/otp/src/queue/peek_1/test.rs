@@ -0,0 +1,82 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::peek_1::result;
+use crate::queue::{in_2, new_0};
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_queue_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_tuple(arc_process),
+            )
+        },
+        |(arc_process, queue)| {
+            prop_assert_badarg!(
+                result(&arc_process, queue),
+                format!("queue ({}) is not a queue", queue)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_empty_queue_returns_empty() {
+    with_process(|process| {
+        let queue = new_0::result(process);
+
+        assert_eq!(result(process, queue), Ok(atom!("empty")));
+    });
+}
+
+#[test]
+fn with_items_returns_oldest_item() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(
+                    strategy::term(arc_process),
+                    strategy::NON_EMPTY_RANGE_INCLUSIVE,
+                ),
+            )
+        },
+        |(arc_process, item_vec)| {
+            let mut queue = new_0::result(&arc_process);
+
+            for item in &item_vec {
+                queue = in_2::result(&arc_process, *item, queue).unwrap();
+            }
+
+            prop_assert_eq!(
+                result(&arc_process, queue),
+                Ok(arc_process.tuple_from_slice(&[atom!("value"), item_vec[0]]))
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_items_only_in_rear_returns_oldest_item() {
+    with_process(|process| {
+        let queue = process.tuple_from_slice(&[
+            process.list_from_slice(&[process.integer(3), process.integer(2), process.integer(1)]),
+            Term::NIL,
+        ]);
+
+        assert_eq!(
+            result(process, queue),
+            Ok(process.tuple_from_slice(&[atom!("value"), process.integer(1)]))
+        );
+    });
+}
@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The items in `queue`, oldest first
+#[native_implemented::function(queue:to_list/1)]
+pub fn result(process: &Process, queue: Term) -> exception::Result<Term> {
+    let super::Queue { rear, front } = super::term_try_into_queue(queue)?;
+    let front_vec = super::queue_list_to_vec(queue, front)?;
+
+    // Consing `Rear`, newest first, onto `[]` reverses it to oldest first
+    let mut list = Term::NIL;
+
+    for item in super::queue_list_to_vec(queue, rear)? {
+        list = process.cons(item, list);
+    }
+
+    Ok(process.improper_list_from_slice(&front_vec, list))
+}
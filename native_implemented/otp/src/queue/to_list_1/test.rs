@@ -0,0 +1,73 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::queue::to_list_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_queue_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_tuple(arc_process),
+            )
+        },
+        |(arc_process, queue)| {
+            prop_assert_badarg!(
+                result(&arc_process, queue),
+                format!("queue ({}) is not a queue", queue)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_improper_rear_errors_badarg() {
+    with_process(|process| {
+        let queue = process.tuple_from_slice(&[
+            process.improper_list_from_slice(&[process.integer(1)], process.integer(2)),
+            Term::NIL,
+        ]);
+
+        assert_badarg!(
+            result(process, queue),
+            format!("queue ({}) is not a queue", queue)
+        );
+    });
+}
+
+#[test]
+fn returns_front_then_reversed_rear() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term(arc_process.clone()), 0..=4),
+                proptest::collection::vec(strategy::term(arc_process), 0..=4),
+            )
+        },
+        |(arc_process, rear_vec, front_vec)| {
+            let queue = arc_process.tuple_from_slice(&[
+                arc_process.list_from_slice(&rear_vec),
+                arc_process.list_from_slice(&front_vec),
+            ]);
+            let item_vec: Vec<Term> = front_vec
+                .iter()
+                .chain(rear_vec.iter().rev())
+                .copied()
+                .collect();
+
+            prop_assert_eq!(
+                result(&arc_process, queue),
+                Ok(arc_process.list_from_slice(&item_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
@@ -0,0 +1,154 @@
+//! Mirrors [rand](http://erlang.org/doc/man/rand.html) module
+//!
+//! Only the default algorithm, `exsss`, is supported.  Like OTP, the functions with implicit state
+//! keep it in the process dictionary under `rand_seed`, as `{#{type => exsss, bits => 58},
+//! [S0 | S1]}`, and seed it from host entropy when it is first used.
+
+mod exsss;
+pub mod normal_0;
+pub mod seed_1;
+pub mod seed_2;
+pub mod uniform_0;
+pub mod uniform_1;
+pub mod uniform_real_0;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use num_bigint::{BigInt, Sign};
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_atom;
+
+use exsss::Exsss;
+
+fn module() -> Atom {
+    Atom::from_str("rand")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+fn seed_key() -> Term {
+    atom!("rand_seed")
+}
+
+/// Runs `f` on the process's state, seeding it first if needed, and stores the updated state
+fn with_state<T>(process: &Process, f: impl FnOnce(&mut Exsss) -> T) -> exception::Result<T> {
+    let state = process.get_value_from_key(seed_key());
+
+    let mut exsss = if state == atom!("undefined") {
+        Exsss::from_entropy()
+    } else {
+        term_try_into_state("rand_seed", state)?
+    };
+
+    let t = f(&mut exsss);
+    put(process, exsss);
+
+    Ok(t)
+}
+
+/// Stores `exsss` as the process's state and returns it as a term
+fn put(process: &Process, exsss: Exsss) -> Term {
+    let algorithm_handler = process.map_from_slice(&[
+        (atom!("bits"), process.integer(exsss::BITS as usize)),
+        (atom!("type"), atom!("exsss")),
+    ]);
+    let algorithm_state = process.cons(process.integer(exsss.head), process.integer(exsss.tail));
+    let state = process.tuple_from_slice(&[algorithm_handler, algorithm_state]);
+
+    process.put(seed_key(), state);
+
+    state
+}
+
+/// Checks that `algorithm` is `exsss`, or `default`, which is the same
+fn term_try_into_algorithm(name: &str, algorithm: Term) -> exception::Result<()> {
+    let algorithm_atom = term_try_into_atom(name, algorithm)?;
+
+    match algorithm_atom.name() {
+        "default" | "exsss" => Ok(()),
+        _ => Err(anyhow!(
+            "{} ({}) is not a supported algorithm, which is only exsss",
+            name,
+            algorithm
+        )
+        .into()),
+    }
+}
+
+/// A state returned by `seed`, `{#{type => exsss, ...}, [S0 | S1]}`, or exported by
+/// `export_seed`, `{exsss, [S0 | S1]}`
+fn term_try_into_state(name: &str, state: Term) -> exception::Result<Exsss> {
+    let option_exsss = match state.decode() {
+        Ok(TypedTerm::Tuple(tuple)) if tuple.len() == 2 => {
+            let algorithm = match tuple[0].decode() {
+                Ok(TypedTerm::Map(map)) => map.get(atom!("type")).unwrap_or(tuple[0]),
+                _ => tuple[0],
+            };
+
+            if term_try_into_algorithm(name, algorithm).is_ok() {
+                algorithm_state_to_exsss(tuple[1])
+            } else {
+                None
+            }
+        }
+        _ => None,
+    };
+
+    option_exsss
+        .with_context(|| format!("{} ({}) is not a valid exsss state", name, state))
+        .map_err(From::from)
+}
+
+fn algorithm_state_to_exsss(algorithm_state: Term) -> Option<Exsss> {
+    match algorithm_state.decode() {
+        Ok(TypedTerm::List(cons)) => {
+            let head: u64 = cons.head.try_into().ok()?;
+            let tail: u64 = cons.tail.try_into().ok()?;
+
+            Exsss::new(head, tail)
+        }
+        _ => None,
+    }
+}
+
+fn term_try_into_big_int(name: &str, integer: Term) -> exception::Result<BigInt> {
+    match integer.decode()? {
+        TypedTerm::SmallInteger(small_integer) => {
+            let integer_isize: isize = small_integer.into();
+
+            Ok(integer_isize.into())
+        }
+        TypedTerm::BigInteger(big_integer) => {
+            let big_int: &BigInt = big_integer.as_ref().into();
+
+            Ok(big_int.clone())
+        }
+        _ => Err(TypeError)
+            .context(format!("{} ({}) is not an integer", name, integer))
+            .map_err(From::from),
+    }
+}
+
+/// The lowest 64 bits of `integer` in two's complement, like `Integer band 16#ffffffffffffffff`
+fn term_try_into_low_u64(name: &str, integer: Term) -> exception::Result<u64> {
+    let big_int = term_try_into_big_int(name, integer)?;
+    let (sign, bytes) = big_int.to_bytes_le();
+    let magnitude = bytes
+        .iter()
+        .take(8)
+        .rev()
+        .fold(0_u64, |acc, byte| (acc << 8) | (*byte as u64));
+
+    Ok(match sign {
+        Sign::Minus => magnitude.wrapping_neg(),
+        _ => magnitude,
+    })
+}
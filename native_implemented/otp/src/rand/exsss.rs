@@ -0,0 +1,221 @@
+//! Xorshift116**, OTP's `exsss` algorithm, with 58 bits of precision and a period of 2^116-1.
+//!
+//! Each step matches `rand:exsss_next/1`, and seeding matches `rand:exsss_seed/1`, so a seed
+//! produces the same sequence as on the BEAM.
+
+use num_bigint::BigInt;
+use num_traits::{One, ToPrimitive};
+
+use crate::runtime::sys::entropy;
+
+pub const BITS: u32 = 58;
+
+const MASK_58: u64 = (1 << BITS) - 1;
+const TWO_POW_MINUS_53: f64 = 1.0 / ((1_u64 << 53) as f64);
+
+/// The state, `[Head | Tail]` in Erlang, which is always two non-zero 58-bit integers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Exsss {
+    pub head: u64,
+    pub tail: u64,
+}
+impl Exsss {
+    /// Validates a state from `[Head | Tail]`
+    pub fn new(head: u64, tail: u64) -> Option<Self> {
+        if (0 < head) && (head <= MASK_58) && (0 < tail) && (tail <= MASK_58) {
+            Some(Self { head, tail })
+        } else {
+            None
+        }
+    }
+
+    /// Seeds from host entropy, in place of OTP's seeding from the node, pid, time and a unique
+    /// integer, which could be guessed.
+    pub fn from_entropy() -> Self {
+        Self::from_integers(entropy::u64(), entropy::u64(), entropy::u64())
+    }
+
+    /// `seed(exsss, Integer)`, where `x` is the lowest 64 bits of `Integer`
+    pub fn from_integer(x: u64) -> Self {
+        let (head, x) = seed58(x);
+        let (tail, _) = seed58(x);
+
+        Self { head, tail }
+    }
+
+    /// `seed(exsss, {A1, A2, A3})`, where each argument is the lowest 64 bits of `An`
+    pub fn from_integers(a1: u64, a2: u64, a3: u64) -> Self {
+        let (_, x0) = seed58(a1);
+        let (head, x1) = seed58(a2 ^ x0);
+        let (tail, _) = seed58(a3 ^ x1);
+
+        Self { head, tail }
+    }
+
+    /// The next 58-bit output
+    pub fn next(&mut self) -> u64 {
+        let s1 = self.head;
+        let s0 = self.tail & MASK_58;
+        let s1 = s1 ^ bsl_58(s1, 24);
+
+        self.head = s0;
+        self.tail = s1 ^ s0 ^ (s1 >> 11) ^ (s0 >> 41);
+
+        scramble_starstar(s0)
+    }
+
+    /// A float in `0.0 =< X < 1.0`, like `rand:uniform/0`
+    pub fn uniform(&mut self) -> f64 {
+        ((self.next() >> (BITS - 53)) as f64) * TWO_POW_MINUS_53
+    }
+
+    /// A float in `0.0 < X < 1.0`, like `rand:uniform_real/0`
+    ///
+    /// Values close to `0.0` take extra outputs so that they keep 53 bits of precision, instead
+    /// of being rounded to multiples of `2^-53`.
+    pub fn uniform_real(&mut self) -> f64 {
+        let m = self.next() >> (BITS - 56);
+
+        if (1 << 55) <= m {
+            ((m >> 3) as f64) * 2.0_f64.powi(-53)
+        } else if (1 << 54) <= m {
+            ((m >> 2) as f64) * 2.0_f64.powi(-54)
+        } else if (1 << 53) <= m {
+            ((m >> 1) as f64) * 2.0_f64.powi(-55)
+        } else if (1 << 52) <= m {
+            (m as f64) * 2.0_f64.powi(-56)
+        } else {
+            let mut m = m;
+            let mut exponent = -56;
+
+            loop {
+                let v = self.next();
+                // Fill the mantissa up to 53 bits
+                let missing = 53 - (64 - m.leading_zeros() as i32);
+                m = (m << missing) | (v >> (BITS as i32 - missing));
+                exponent -= missing;
+
+                // The odds of running out of exponent are around 2^-1008
+                if ((1 << 52) <= m) || (exponent <= -1064) {
+                    break (m as f64) * 2.0_f64.powi(exponent);
+                }
+            }
+        }
+    }
+
+    /// An integer in `1 =< X =< range`, like `rand:uniform/1`
+    ///
+    /// Outputs in the truncated top of the range are rejected, so that all integers are equally
+    /// likely.
+    pub fn uniform_range(&mut self, range: &BigInt) -> BigInt {
+        loop {
+            let v = self.next();
+
+            match range.to_u64() {
+                Some(range_u64) if range_u64 <= (1 << BITS) => {
+                    let max_minus_range = (1 << BITS) - range_u64;
+
+                    if v < range_u64 {
+                        break (v + 1).into();
+                    }
+
+                    let i = v % range_u64;
+
+                    if v - i <= max_minus_range {
+                        break (i + 1).into();
+                    }
+                }
+                _ => {
+                    if let Some(i) = self.uniform_big_range(range, v) {
+                        break i;
+                    }
+                }
+            }
+        }
+    }
+
+    /// `uniform_range` for ranges wider than one output, which are filled from several
+    fn uniform_big_range(&mut self, range: &BigInt, v: u64) -> Option<BigInt> {
+        let range_minus_one = range - BigInt::one();
+        let is_power_of_two = (BigInt::one() << (range_minus_one.bits())) == *range;
+
+        if is_power_of_two {
+            let (v, _) = self.fill(range >> BITS as usize, v);
+
+            Some((v % range) + BigInt::one())
+        } else {
+            // Two bits more than the range makes the odds of rejecting the output under 1/4
+            let (v, bits) = self.fill(range >> (BITS - 2) as usize, v);
+            let i = &v % range;
+
+            if (v - &i) <= ((BigInt::one() << bits) - range) {
+                Some(i + BigInt::one())
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Shifts in outputs after `v` until `remaining` has been shifted away
+    fn fill(&mut self, remaining: BigInt, v: u64) -> (BigInt, usize) {
+        let outputs = (remaining.bits() + (BITS as usize) - 1) / (BITS as usize);
+        let mut filled: BigInt = v.into();
+
+        for _ in 0..outputs {
+            filled = (filled << BITS as usize) + BigInt::from(self.next());
+        }
+
+        (filled, (outputs + 1) * (BITS as usize))
+    }
+
+    /// A standard normal float, with mean `0.0` and variance `1.0`, like `rand:normal/0`
+    ///
+    /// Uses the Marsaglia polar method instead of OTP's ziggurat, so the distribution is the
+    /// same, but the values drawn for a seed differ from the BEAM's.
+    pub fn normal(&mut self) -> f64 {
+        loop {
+            let u = 2.0 * self.uniform() - 1.0;
+            let v = 2.0 * self.uniform() - 1.0;
+            let s = u * u + v * v;
+
+            if (0.0 < s) && (s < 1.0) {
+                break u * (-2.0 * s.ln() / s).sqrt();
+            }
+        }
+    }
+}
+
+/// `(x band (2^(58 - n) - 1)) bsl n`, so that the result stays within 58 bits
+fn bsl_58(x: u64, n: u32) -> u64 {
+    (x & ((1 << (BITS - n)) - 1)) << n
+}
+
+fn scramble_starstar(x: u64) -> u64 {
+    let a = (x + bsl_58(x, 2)) & MASK_58;
+    let b = bsl_58(a, 7) | (a >> (BITS - 7));
+
+    (b + bsl_58(b, 3)) & MASK_58
+}
+
+/// A non-zero 58-bit integer from SplitMix64, and the next SplitMix64 state
+fn seed58(x: u64) -> (u64, u64) {
+    let mut x = x;
+
+    loop {
+        let (z, next_x) = splitmix64_next(x);
+        let z = z & MASK_58;
+        x = next_x;
+
+        if z != 0 {
+            break (z, x);
+        }
+    }
+}
+
+fn splitmix64_next(x: u64) -> (u64, u64) {
+    let x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let z = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    (z ^ (z >> 31), x)
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// A float from the standard normal distribution, with mean `0.0` and variance `1.0`
+#[native_implemented::function(rand:normal/0)]
+pub fn result(process: &Process) -> exception::Result<Term> {
+    let f = super::with_state(process, |exsss| exsss.normal())?;
+
+    Ok(process.float(f))
+}
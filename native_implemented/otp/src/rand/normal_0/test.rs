@@ -0,0 +1,25 @@
+use std::convert::TryInto;
+
+use crate::rand::normal_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_floats_with_mean_near_zero() {
+    with_process(|process| {
+        let count = 10_000;
+        let mut sum = 0.0;
+
+        for _ in 0..count {
+            let f: f64 = result(process).unwrap().try_into().unwrap();
+
+            assert!(f.is_finite());
+
+            sum += f;
+        }
+
+        let mean = sum / (count as f64);
+
+        // The standard error of the mean is 0.01, so this fails for 1 in ~1.7 million seeds
+        assert!(mean.abs() < 0.05, "mean ({}) is not near 0.0", mean);
+    });
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::exsss::Exsss;
+
+/// Seeds the process's state from host entropy when given an algorithm, or sets it to a state
+/// returned by `seed/1,2`.  Returns the new state.
+#[native_implemented::function(rand:seed/1)]
+pub fn result(process: &Process, alg_or_state: Term) -> exception::Result<Term> {
+    let exsss = if alg_or_state.is_atom() {
+        super::term_try_into_algorithm("alg_or_state", alg_or_state)?;
+
+        Exsss::from_entropy()
+    } else {
+        super::term_try_into_state("alg_or_state", alg_or_state)?
+    };
+
+    Ok(super::put(process, exsss))
+}
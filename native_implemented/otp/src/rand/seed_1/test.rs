@@ -0,0 +1,78 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::rand::seed_1::result;
+use crate::rand::uniform_0;
+use crate::test::with_process;
+
+#[test]
+fn with_unsupported_algorithm_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("exrop")),
+            "alg_or_state (exrop) is not a supported algorithm, which is only exsss"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_state_errors_badarg() {
+    with_process(|process| {
+        let state = process.tuple_from_slice(&[
+            atom!("exsss"),
+            process.cons(process.integer(0), process.integer(0)),
+        ]);
+
+        assert_badarg!(
+            result(process, state),
+            format!("alg_or_state ({}) is not a valid exsss state", state)
+        );
+    });
+}
+
+#[test]
+fn with_algorithm_stores_state() {
+    with_process(|process| {
+        for algorithm in &[atom!("exsss"), atom!("default")] {
+            let state = result(process, *algorithm).unwrap();
+
+            assert_eq!(process.get_value_from_key(atom!("rand_seed")), state);
+        }
+    });
+}
+
+#[test]
+fn with_algorithm_seeds_differently_each_time() {
+    with_process(|process| {
+        let first = result(process, atom!("exsss")).unwrap();
+        let second = result(process, atom!("exsss")).unwrap();
+
+        assert_ne!(first, second);
+    });
+}
+
+#[test]
+fn with_state_restores_sequence() {
+    with_process(|process| {
+        let state = result(process, atom!("exsss")).unwrap();
+        let first = uniform_0::result(process).unwrap();
+
+        assert_eq!(result(process, state), Ok(state));
+        assert_eq!(uniform_0::result(process), Ok(first));
+    });
+}
+
+#[test]
+fn with_exported_state_restores_sequence() {
+    with_process(|process| {
+        let state = result(process, atom!("exsss")).unwrap();
+        let state_tuple: Boxed<Tuple> = state.try_into().unwrap();
+        let exported_state = process.tuple_from_slice(&[atom!("exsss"), state_tuple[1]]);
+        let first = uniform_0::result(process).unwrap();
+
+        assert_eq!(result(process, exported_state), Ok(state));
+        assert_eq!(uniform_0::result(process), Ok(first));
+    });
+}
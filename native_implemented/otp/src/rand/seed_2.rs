@@ -0,0 +1,37 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::exsss::Exsss;
+
+/// Seeds the process's state from `seed`, either an integer or a tuple of three integers, so that
+/// the same seed always produces the same values.  Returns the new state.
+#[native_implemented::function(rand:seed/2)]
+pub fn result(process: &Process, alg: Term, seed: Term) -> exception::Result<Term> {
+    super::term_try_into_algorithm("alg", alg)?;
+
+    let exsss = match seed.decode()? {
+        TypedTerm::SmallInteger(_) | TypedTerm::BigInteger(_) => {
+            Exsss::from_integer(super::term_try_into_low_u64("seed", seed)?)
+        }
+        TypedTerm::Tuple(tuple) if tuple.len() == 3 => Exsss::from_integers(
+            super::term_try_into_low_u64("seed", tuple[0])?,
+            super::term_try_into_low_u64("seed", tuple[1])?,
+            super::term_try_into_low_u64("seed", tuple[2])?,
+        ),
+        _ => {
+            return Err(anyhow!(
+                "seed ({}) is not an integer or a tuple of three integers",
+                seed
+            )
+            .into())
+        }
+    };
+
+    Ok(super::put(process, exsss))
+}
@@ -0,0 +1,85 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::rand::seed_2::result;
+use crate::rand::uniform_1;
+use crate::test::with_process;
+
+#[test]
+fn with_unsupported_algorithm_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("exs1024s"), process.integer(1)),
+            "alg (exs1024s) is not a supported algorithm, which is only exsss"
+        );
+    });
+}
+
+#[test]
+fn without_integer_or_tuple_of_three_integers_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("exsss"), atom!("seed")),
+            "seed (seed) is not an integer or a tuple of three integers"
+        );
+
+        let seed = process.tuple_from_slice(&[process.integer(1), process.integer(2)]);
+
+        assert_badarg!(
+            result(process, atom!("exsss"), seed),
+            format!(
+                "seed ({}) is not an integer or a tuple of three integers",
+                seed
+            )
+        );
+
+        let seed =
+            process.tuple_from_slice(&[process.integer(1), process.integer(2), atom!("three")]);
+
+        assert_badarg!(
+            result(process, atom!("exsss"), seed),
+            "seed (three) is not an integer"
+        );
+    });
+}
+
+#[test]
+fn with_same_seed_returns_same_sequence() {
+    with_process(|process| {
+        let n = process.integer(1_000_000);
+
+        for seed in &[
+            process.integer(42),
+            process.tuple_from_slice(&[process.integer(1), process.integer(2), process.integer(3)]),
+        ] {
+            let state = result(process, atom!("exsss"), *seed).unwrap();
+            let first: Vec<Term> = (0..10)
+                .map(|_| uniform_1::result(process, n).unwrap())
+                .collect();
+
+            assert_eq!(result(process, atom!("exsss"), *seed), Ok(state));
+
+            let second: Vec<Term> = (0..10)
+                .map(|_| uniform_1::result(process, n).unwrap())
+                .collect();
+
+            assert_eq!(first, second);
+        }
+    });
+}
+
+#[test]
+fn with_integer_uses_lowest_64_bits() {
+    with_process(|process| {
+        let state = result(process, atom!("exsss"), process.integer(-1)).unwrap();
+
+        assert_eq!(
+            result(process, atom!("exsss"), process.integer(u64::max_value())),
+            Ok(state)
+        );
+        assert_eq!(
+            result(process, atom!("exsss"), process.integer((1_u128 << 65) - 1)),
+            Ok(state)
+        );
+    });
+}
@@ -0,0 +1,46 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+
+use crate::rand::uniform_0::result;
+use crate::test::with_process;
+
+#[test]
+fn without_seed_seeds_state() {
+    with_process(|process| {
+        assert_eq!(
+            process.get_value_from_key(atom!("rand_seed")),
+            atom!("undefined")
+        );
+
+        assert!(result(process).is_ok());
+
+        assert_ne!(
+            process.get_value_from_key(atom!("rand_seed")),
+            atom!("undefined")
+        );
+    });
+}
+
+#[test]
+fn returns_float_at_least_zero_and_less_than_one() {
+    with_process(|process| {
+        for _ in 0..1_000 {
+            let f: f64 = result(process).unwrap().try_into().unwrap();
+
+            assert!((0.0 <= f) && (f < 1.0), "{} is not in [0.0, 1.0)", f);
+        }
+    });
+}
+
+#[test]
+fn with_invalid_state_errors_badarg() {
+    with_process(|process| {
+        process.put(atom!("rand_seed"), atom!("state"));
+
+        assert_badarg!(
+            result(process),
+            "rand_seed (state) is not a valid exsss state"
+        );
+    });
+}
@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+use num_traits::Signed;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// An integer uniformly distributed in `1 =< X =< N`
+#[native_implemented::function(rand:uniform/1)]
+pub fn result(process: &Process, n: Term) -> exception::Result<Term> {
+    let n_big_int = super::term_try_into_big_int("n", n)?;
+
+    if n_big_int.is_positive() {
+        let i = super::with_state(process, |exsss| exsss.uniform_range(&n_big_int))?;
+
+        Ok(process.integer(i))
+    } else {
+        Err(anyhow!("n ({}) is not a positive integer", n).into())
+    }
+}
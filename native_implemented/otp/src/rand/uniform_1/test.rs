@@ -0,0 +1,80 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::rand::uniform_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_integer_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("ten")), "n (ten) is not an integer");
+    });
+}
+
+#[test]
+fn without_positive_integer_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.integer(0)),
+            "n (0) is not a positive integer"
+        );
+        assert_badarg!(
+            result(process, process.integer(-1)),
+            "n (-1) is not a positive integer"
+        );
+    });
+}
+
+#[test]
+fn with_one_returns_one() {
+    with_process(|process| {
+        assert_eq!(result(process, process.integer(1)), Ok(process.integer(1)));
+    });
+}
+
+#[test]
+fn with_small_integer_returns_integer_between_one_and_n() {
+    with_process(|process| {
+        let n = process.integer(6);
+        let mut seen = [false; 6];
+
+        for _ in 0..1_000 {
+            let i = result(process, n).unwrap();
+
+            assert!(
+                (process.integer(1) <= i) && (i <= n),
+                "{} is not in 1..6",
+                i
+            );
+
+            let index: usize = i.try_into().unwrap();
+            seen[index - 1] = true;
+        }
+
+        assert!(seen.iter().all(|seen| *seen));
+    });
+}
+
+#[test]
+fn with_big_integer_returns_integer_between_one_and_n() {
+    with_process(|process| {
+        // Wider than the 58 bits of one output, and a power of two
+        for n in &[
+            process.integer(u64::max_value()),
+            process.integer(1_u128 << 100),
+        ] {
+            for _ in 0..100 {
+                let i = result(process, *n).unwrap();
+
+                assert!(
+                    (process.integer(1) <= i) && (i <= *n),
+                    "{} is not in 1..{}",
+                    i,
+                    n
+                );
+            }
+        }
+    });
+}
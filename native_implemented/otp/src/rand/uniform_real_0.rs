@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// A float uniformly distributed in `0.0 < X < 1.0`, which, unlike `uniform/0`, is never `0.0`
+#[native_implemented::function(rand:uniform_real/0)]
+pub fn result(process: &Process) -> exception::Result<Term> {
+    let f = super::with_state(process, |exsss| exsss.uniform_real())?;
+
+    Ok(process.float(f))
+}
@@ -0,0 +1,15 @@
+use std::convert::TryInto;
+
+use crate::rand::uniform_real_0::result;
+use crate::test::with_process;
+
+#[test]
+fn returns_float_greater_than_zero_and_less_than_one() {
+    with_process(|process| {
+        for _ in 0..1_000 {
+            let f: f64 = result(process).unwrap().try_into().unwrap();
+
+            assert!((0.0 < f) && (f < 1.0), "{} is not in (0.0, 1.0)", f);
+        }
+    });
+}
@@ -0,0 +1,55 @@
+//! Mirrors [re](http://erlang.org/doc/man/re.html) module
+//!
+//! Regular expressions are matched by the `regex` crate instead of PCRE, so the syntax is that of
+//! the `regex` crate, which lacks backreferences and lookaround, and offsets are in bytes of the
+//! subject as a binary: UTF-8 with the `unicode` option and `latin1` otherwise.
+
+pub mod compile_1;
+pub mod compile_2;
+mod options;
+mod pattern;
+pub mod replace_3;
+pub mod replace_4;
+pub mod run_2;
+pub mod run_3;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::unicode::characters;
+
+fn module() -> Atom {
+    Atom::from_str("re")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Converts `value`, which is chardata when `unicode` and iodata otherwise, to bytes.
+fn to_bytes(
+    process: &Process,
+    name: &'static str,
+    value: Term,
+    unicode: bool,
+) -> exception::Result<Vec<u8>> {
+    if unicode {
+        characters::to_utf8_bytes(process, name, value)
+    } else {
+        iolist_or_binary::to_byte_vec(name, value)
+    }
+}
+
+/// Converts `bytes`, which are UTF-8 when `unicode` and `latin1` otherwise, to a list of
+/// characters.
+fn bytes_to_list(process: &Process, bytes: &[u8], unicode: bool) -> Term {
+    if unicode {
+        process.list_from_chars(String::from_utf8_lossy(bytes).chars())
+    } else {
+        let string: String = bytes.iter().map(|byte| *byte as char).collect();
+
+        process.list_from_chars(string.chars())
+    }
+}
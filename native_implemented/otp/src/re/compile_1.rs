@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::compile_2;
+
+#[native_implemented::function(re:compile/1)]
+pub fn result(process: &Process, regexp: Term) -> exception::Result<Term> {
+    compile_2::result(process, regexp, Term::NIL)
+}
@@ -0,0 +1,41 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::compile_1::result;
+use crate::test::with_process;
+
+#[test]
+fn with_valid_regexp_returns_ok_with_compiled_pattern() {
+    with_process(|process| {
+        let compiled = result(process, process.charlist_from_str("a+b")).unwrap();
+        let tuple: Boxed<Tuple> = compiled.try_into().unwrap();
+
+        assert_eq!(tuple.len(), 2);
+        assert_eq!(tuple[0], atom!("ok"));
+        assert!(tuple[1].is_boxed_resource_reference());
+    });
+}
+
+#[test]
+fn with_invalid_regexp_returns_error_with_error_spec() {
+    with_process(|process| {
+        let compiled = result(process, process.binary_from_str("(a")).unwrap();
+        let tuple: Boxed<Tuple> = compiled.try_into().unwrap();
+
+        assert_eq!(tuple[0], atom!("error"));
+
+        let error_spec: Boxed<Tuple> = tuple[1].try_into().unwrap();
+
+        assert!(error_spec[0].is_list());
+        assert_eq!(error_spec[1], process.integer(0));
+    });
+}
+
+#[test]
+fn without_iodata_regexp_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("regexp")), "regexp (regexp)");
+    });
+}
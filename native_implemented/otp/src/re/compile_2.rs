@@ -0,0 +1,46 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::options::CompileOptions;
+use crate::re::pattern::Pattern;
+
+/// Returns `{ok, MP}`, where `MP` is a resource that `re:run/2,3` and `re:replace/3,4` accept in
+/// place of a regexp, or `{error, {ErrString, 0}}`, as the `regex` crate does not report where
+/// the error is.
+#[native_implemented::function(re:compile/2)]
+pub fn result(process: &Process, regexp: Term, options: Term) -> exception::Result<Term> {
+    let options: CompileOptions = options.try_into()?;
+    let regexp_bytes = crate::re::to_bytes(process, "regexp", regexp, options.unicode)?;
+
+    match Pattern::compile(&regexp_bytes, options) {
+        Ok(pattern) => Ok(process.tuple_from_slice(&[atom!("ok"), process.resource(pattern)])),
+        Err(error) => {
+            let error_string = error_string(&error);
+            let error_spec = process
+                .tuple_from_slice(&[process.charlist_from_str(&error_string), process.integer(0)]);
+
+            Ok(process.tuple_from_slice(&[atom!("error"), error_spec]))
+        }
+    }
+}
+
+/// The `regex` crate's syntax errors show the pattern with the error underlined before the
+/// message on the last line.
+fn error_string(error: &regex::Error) -> String {
+    let message = error.to_string();
+
+    message
+        .lines()
+        .last()
+        .unwrap_or("")
+        .trim()
+        .trim_start_matches("error: ")
+        .to_string()
+}
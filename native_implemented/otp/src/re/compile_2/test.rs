@@ -0,0 +1,67 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::{compile_2, run_2};
+use crate::test::with_process;
+
+#[test]
+fn without_supported_option_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            compile_2::result(
+                process,
+                process.charlist_from_str("a"),
+                process.list_from_slice(&[atom!("global")])
+            ),
+            "supported compile options are"
+        );
+    });
+}
+
+#[test]
+fn with_caseless_compiled_pattern_matches_case_insensitively() {
+    with_process(|process| {
+        let compiled = compile_2::result(
+            process,
+            process.charlist_from_str("abc"),
+            process.list_from_slice(&[atom!("caseless")]),
+        )
+        .unwrap();
+        let tuple: Boxed<Tuple> = compiled.try_into().unwrap();
+
+        assert_eq!(
+            run_2::result(process, process.binary_from_str("xABC"), tuple[1]),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[process.integer(1), process.integer(3)])
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_unicode_regexp_matches_characters() {
+    with_process(|process| {
+        let compiled = compile_2::result(
+            process,
+            process.charlist_from_str("^.$"),
+            process.list_from_slice(&[atom!("unicode")]),
+        )
+        .unwrap();
+        let tuple: Boxed<Tuple> = compiled.try_into().unwrap();
+
+        assert_eq!(
+            run_2::result(process, process.binary_from_str("é"), tuple[1]),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[process.integer(0), process.integer(2)])
+                ])
+            ]))
+        );
+    });
+}
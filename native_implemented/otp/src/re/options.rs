@@ -0,0 +1,372 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::proplist::TryPropListFromTermError;
+
+const COMPILE_OPTIONS_CONTEXT: &str =
+    "supported compile options are anchored, caseless, dotall, extended, multiline, ungreedy, and \
+     unicode";
+const RUN_OPTIONS_CONTEXT: &str = "supported options are the compile options, global, \
+     {offset, Offset}, {capture, ValueSpec}, and {capture, ValueSpec, Type}";
+const REPLACE_OPTIONS_CONTEXT: &str = "supported options are the compile options, global, \
+     {offset, Offset}, and {return, iodata | list | binary}";
+const VALUE_SPEC_CONTEXT: &str = "ValueSpec must be all, all_but_first, all_names, first, none, \
+     or a list of group numbers and names";
+
+#[derive(Clone, Debug, Default)]
+pub struct CompileOptions {
+    pub anchored: bool,
+    pub caseless: bool,
+    pub dotall: bool,
+    pub extended: bool,
+    pub multiline: bool,
+    pub ungreedy: bool,
+    pub unicode: bool,
+}
+
+impl CompileOptions {
+    /// Returns `false` if `atom` is not a compile option.
+    fn put_option_atom(&mut self, atom: Atom) -> bool {
+        let flag = match atom.name() {
+            "anchored" => &mut self.anchored,
+            "caseless" => &mut self.caseless,
+            "dotall" => &mut self.dotall,
+            "extended" => &mut self.extended,
+            "multiline" => &mut self.multiline,
+            "ungreedy" => &mut self.ungreedy,
+            "unicode" => &mut self.unicode,
+            _ => return false,
+        };
+        *flag = true;
+
+        true
+    }
+}
+
+impl TryFrom<Term> for CompileOptions {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options: CompileOptions = Default::default();
+
+        for_each_option(term, |option| {
+            let atom: Atom = option
+                .try_into()
+                .map_err(|_| TryPropListFromTermError::PropertyType)
+                .context(COMPILE_OPTIONS_CONTEXT)?;
+
+            if options.put_option_atom(atom) {
+                Ok(())
+            } else {
+                Err(TryPropListFromTermError::AtomName(atom.name()))
+                    .context(COMPILE_OPTIONS_CONTEXT)
+            }
+        })?;
+
+        Ok(options)
+    }
+}
+
+/// Which groups `re:run` returns for each match and how
+#[derive(Clone, Debug)]
+pub struct Capture {
+    pub values: Values,
+    pub r#type: Type,
+}
+
+impl Default for Capture {
+    fn default() -> Self {
+        Self {
+            values: Values::All,
+            r#type: Type::Index,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Values {
+    All,
+    AllButFirst,
+    AllNames,
+    First,
+    None,
+    Groups(Vec<Group>),
+}
+
+impl TryFrom<Term> for Values {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        match term.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "all" => Ok(Values::All),
+                "all_but_first" => Ok(Values::AllButFirst),
+                "all_names" => Ok(Values::AllNames),
+                "first" => Ok(Values::First),
+                "none" => Ok(Values::None),
+                name => Err(TryPropListFromTermError::AtomName(name)).context(VALUE_SPEC_CONTEXT),
+            },
+            TypedTerm::Nil => Ok(Values::Groups(Vec::new())),
+            TypedTerm::List(cons) => {
+                let mut groups = Vec::new();
+
+                for result in cons.into_iter() {
+                    let element = result
+                        .map_err(|_| ImproperListError)
+                        .with_context(|| format!("ValueSpec ({}) is improper", term))?;
+
+                    groups.push(element.try_into().context(VALUE_SPEC_CONTEXT)?);
+                }
+
+                Ok(Values::Groups(groups))
+            }
+            _ => Err(TypeError).context(VALUE_SPEC_CONTEXT),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Group {
+    Number(usize),
+    Name(String),
+}
+
+impl TryFrom<Term> for Group {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        match term.decode().unwrap() {
+            TypedTerm::SmallInteger(_) => {
+                let number: usize = term.try_into().with_context(|| {
+                    format!("group number ({}) is not a non-negative integer", term)
+                })?;
+
+                Ok(Group::Number(number))
+            }
+            TypedTerm::Atom(atom) => Ok(Group::Name(atom.name().to_string())),
+            TypedTerm::Nil | TypedTerm::List(_) => {
+                let name = crate::erlang::list_to_string::list_to_string(term)
+                    .map_err(|_| TypeError)
+                    .with_context(|| format!("group name ({}) is not a string", term))?;
+
+                Ok(Group::Name(name))
+            }
+            _ => Err(TypeError)
+                .with_context(|| format!("group ({}) is not a number, atom, or string", term)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type {
+    Binary,
+    Index,
+    List,
+}
+
+impl TryFrom<Term> for Type {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let atom: Atom = term
+            .try_into()
+            .with_context(|| format!("capture type ({}) is not an atom", term))?;
+
+        match atom.name() {
+            "binary" => Ok(Type::Binary),
+            "index" => Ok(Type::Index),
+            "list" => Ok(Type::List),
+            name => Err(TryPropListFromTermError::AtomName(name))
+                .context("capture type must be binary, index, or list"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub compile: CompileOptions,
+    pub capture: Capture,
+    pub global: bool,
+    pub offset: usize,
+}
+
+impl RunOptions {
+    fn put_option_tuple(&mut self, tuple: &Tuple) -> anyhow::Result<()> {
+        let name: Atom = tuple[0]
+            .try_into()
+            .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+            .context(RUN_OPTIONS_CONTEXT)?;
+
+        match (name.name(), tuple.len()) {
+            ("offset", 2) => {
+                self.offset = offset(tuple[1])?;
+
+                Ok(())
+            }
+            ("capture", 2) => {
+                self.capture.values = tuple[1].try_into()?;
+
+                Ok(())
+            }
+            ("capture", 3) => {
+                self.capture.values = tuple[1].try_into()?;
+                self.capture.r#type = tuple[2].try_into()?;
+
+                Ok(())
+            }
+            (name, _) => {
+                Err(TryPropListFromTermError::KeywordKeyName(name)).context(RUN_OPTIONS_CONTEXT)
+            }
+        }
+    }
+}
+
+impl TryFrom<Term> for RunOptions {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options: RunOptions = Default::default();
+
+        for_each_option(term, |option| match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => {
+                if atom.name() == "global" {
+                    options.global = true;
+
+                    Ok(())
+                } else if options.compile.put_option_atom(atom) {
+                    Ok(())
+                } else {
+                    Err(TryPropListFromTermError::AtomName(atom.name()))
+                        .context(RUN_OPTIONS_CONTEXT)
+                }
+            }
+            TypedTerm::Tuple(tuple) => options.put_option_tuple(&tuple),
+            _ => Err(TryPropListFromTermError::PropertyType).context(RUN_OPTIONS_CONTEXT),
+        })?;
+
+        Ok(options)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Return {
+    Binary,
+    Iodata,
+    List,
+}
+
+#[derive(Clone, Debug)]
+pub struct ReplaceOptions {
+    pub compile: CompileOptions,
+    pub global: bool,
+    pub offset: usize,
+    pub r#return: Return,
+}
+
+impl ReplaceOptions {
+    fn put_option_tuple(&mut self, tuple: &Tuple) -> anyhow::Result<()> {
+        if tuple.len() != 2 {
+            return Err(TryPropListFromTermError::TupleNotPair).context(REPLACE_OPTIONS_CONTEXT);
+        }
+
+        let name: Atom = tuple[0]
+            .try_into()
+            .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+            .context(REPLACE_OPTIONS_CONTEXT)?;
+
+        match name.name() {
+            "offset" => {
+                self.offset = offset(tuple[1])?;
+
+                Ok(())
+            }
+            "return" => {
+                let r#return: Atom = tuple[1]
+                    .try_into()
+                    .with_context(|| format!("return type ({}) is not an atom", tuple[1]))?;
+
+                self.r#return = match r#return.name() {
+                    "binary" => Return::Binary,
+                    "iodata" => Return::Iodata,
+                    "list" => Return::List,
+                    name => {
+                        return Err(TryPropListFromTermError::AtomName(name))
+                            .context("return type must be binary, iodata, or list")
+                    }
+                };
+
+                Ok(())
+            }
+            name => {
+                Err(TryPropListFromTermError::KeywordKeyName(name)).context(REPLACE_OPTIONS_CONTEXT)
+            }
+        }
+    }
+}
+
+impl Default for ReplaceOptions {
+    fn default() -> Self {
+        Self {
+            compile: Default::default(),
+            global: false,
+            offset: 0,
+            r#return: Return::Iodata,
+        }
+    }
+}
+
+impl TryFrom<Term> for ReplaceOptions {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> anyhow::Result<Self> {
+        let mut options: ReplaceOptions = Default::default();
+
+        for_each_option(term, |option| match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => {
+                if atom.name() == "global" {
+                    options.global = true;
+
+                    Ok(())
+                } else if options.compile.put_option_atom(atom) {
+                    Ok(())
+                } else {
+                    Err(TryPropListFromTermError::AtomName(atom.name()))
+                        .context(REPLACE_OPTIONS_CONTEXT)
+                }
+            }
+            TypedTerm::Tuple(tuple) => options.put_option_tuple(&tuple),
+            _ => Err(TryPropListFromTermError::PropertyType).context(REPLACE_OPTIONS_CONTEXT),
+        })?;
+
+        Ok(options)
+    }
+}
+
+fn for_each_option(
+    term: Term,
+    mut put_option: impl FnMut(Term) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let mut options_term = term;
+
+    loop {
+        match options_term.decode().unwrap() {
+            TypedTerm::Nil => return Ok(()),
+            TypedTerm::List(cons) => {
+                put_option(cons.head)?;
+                options_term = cons.tail;
+            }
+            _ => {
+                return Err(ImproperListError)
+                    .with_context(|| format!("options ({}) is not a proper list", term))
+            }
+        }
+    }
+}
+
+fn offset(term: Term) -> anyhow::Result<usize> {
+    term.try_into()
+        .with_context(|| format!("offset ({}) is not a non-negative integer", term))
+}
@@ -0,0 +1,242 @@
+use std::fmt::Write;
+
+use anyhow::*;
+use regex::bytes::{CaptureLocations, Regex, RegexBuilder};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::Process;
+
+use crate::re::options::CompileOptions;
+
+/// The start and end byte offsets of each group of a match, or `None` for groups that did not
+/// participate in the match.
+pub type Locations = Vec<Option<(usize, usize)>>;
+
+/// A regular expression compiled by `re:compile/1,2`.
+///
+/// Matching is done by the `regex` crate instead of PCRE, so backreferences and lookaround are
+/// syntax errors.
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    regex: Regex,
+    /// `regex` anchored at both the start and end of the text, for retrying an empty match as a
+    /// non-empty one.
+    whole_regex: Regex,
+    options: CompileOptions,
+}
+
+impl Pattern {
+    /// Compiles `regexp`, which is UTF-8 when `options.unicode` and `latin1` otherwise.
+    pub fn compile(regexp: &[u8], options: CompileOptions) -> Result<Self, regex::Error> {
+        let mut pattern = String::new();
+
+        if options.unicode {
+            match std::str::from_utf8(regexp) {
+                Ok(regexp) => pattern.push_str(regexp),
+                Err(_) => {
+                    return Err(regex::Error::Syntax(
+                        "regexp is not valid UTF-8".to_string(),
+                    ))
+                }
+            }
+        } else {
+            // Without Unicode, the pattern matches bytes, so non-ASCII bytes are escaped instead
+            // of being interpreted as characters.
+            for byte in regexp {
+                if byte.is_ascii() {
+                    pattern.push(*byte as char);
+                } else {
+                    write!(pattern, r"\x{:02X}", byte).unwrap();
+                }
+            }
+        }
+
+        let whole_regex = Self::build(&format!(r"\A(?:{})\z", pattern), &options)?;
+
+        if options.anchored {
+            pattern = format!(r"\A(?:{})", pattern);
+        }
+
+        let regex = Self::build(&pattern, &options)?;
+
+        Ok(Self {
+            regex,
+            whole_regex,
+            options,
+        })
+    }
+
+    fn build(pattern: &str, options: &CompileOptions) -> Result<Regex, regex::Error> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(options.caseless)
+            .multi_line(options.multiline)
+            .dot_matches_new_line(options.dotall)
+            .ignore_whitespace(options.extended)
+            .swap_greed(options.ungreedy)
+            .unicode(options.unicode)
+            .build()
+    }
+
+    /// `term` is either a pattern compiled by `re:compile/1,2` or a regexp to compile with
+    /// `options`.
+    pub fn try_from_term(
+        process: &Process,
+        term: Term,
+        options: &CompileOptions,
+    ) -> exception::Result<Self> {
+        match term.decode()? {
+            TypedTerm::ResourceReference(resource_reference) => {
+                let resource: Resource = resource_reference.into();
+
+                match resource.downcast_ref::<Pattern>() {
+                    Some(pattern) => Ok(pattern.clone()),
+                    None => Err(TypeError)
+                        .with_context(|| {
+                            format!(
+                                "regexp ({}) is a resource, but not a compiled pattern",
+                                term
+                            )
+                        })
+                        .map_err(From::from),
+                }
+            }
+            _ => {
+                let regexp = super::to_bytes(process, "regexp", term, options.unicode)?;
+
+                Self::compile(&regexp, options.clone())
+                    .with_context(|| format!("regexp ({}) could not be compiled", term))
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    pub fn unicode(&self) -> bool {
+        self.options.unicode
+    }
+
+    /// The group numbers of the named groups, sorted by name.
+    pub fn named_groups(&self) -> Vec<usize> {
+        let mut named_groups: Vec<(&str, usize)> = self
+            .regex
+            .capture_names()
+            .enumerate()
+            .filter_map(|(number, name)| name.map(|name| (name, number)))
+            .collect();
+        named_groups.sort();
+
+        named_groups.into_iter().map(|(_, number)| number).collect()
+    }
+
+    pub fn group_number(&self, name: &str) -> Option<usize> {
+        self.regex
+            .capture_names()
+            .position(|group_name| group_name == Some(name))
+    }
+
+    pub fn groups_len(&self) -> usize {
+        self.regex.captures_len()
+    }
+
+    /// The locations of the first match at or after `offset`, or with `global`, of each match
+    /// after that too.
+    ///
+    /// Like `re:run/3`, after an empty match, the search is retried at the same place for a
+    /// non-empty match, and only if there is none does it resume one character later.  PCRE takes
+    /// the first non-empty match in the pattern's order of preference, but the `regex` crate can't
+    /// exclude empty matches, so the shortest non-empty match is taken instead, and assertions,
+    /// such as `$` and `\b`, see the end of the candidate match as the end of the subject.
+    pub fn matches(&self, subject: &[u8], offset: usize, global: bool) -> Vec<Locations> {
+        let mut matches = Vec::new();
+        let mut start = offset;
+        let mut retry_not_empty = false;
+        let mut capture_locations = self.regex.capture_locations();
+
+        while start <= subject.len() {
+            if retry_not_empty {
+                retry_not_empty = false;
+
+                match self.not_empty_match_at(subject, start) {
+                    Some(locations) => {
+                        start = locations[0].unwrap().1;
+                        matches.push(locations);
+                    }
+                    None => {
+                        start += self.char_len_at(subject, start);
+
+                        continue;
+                    }
+                }
+            } else {
+                // An anchored pattern must match at `start`, which `\A` only does at the start of
+                // the text searched.
+                let (text, base) = if self.options.anchored {
+                    (&subject[start..], start)
+                } else {
+                    (subject, 0)
+                };
+                let search_start = start - base;
+
+                let (match_start, match_end) =
+                    match self
+                        .regex
+                        .captures_read_at(&mut capture_locations, text, search_start)
+                    {
+                        Some(found) => (found.start() + base, found.end() + base),
+                        None => break,
+                    };
+
+                retry_not_empty = match_start == match_end;
+                start = match_end;
+                matches.push(Self::locations(&capture_locations, base));
+            }
+
+            if !global {
+                break;
+            }
+        }
+
+        matches
+    }
+
+    /// The shortest non-empty match starting at `start`, if any.
+    fn not_empty_match_at(&self, subject: &[u8], start: usize) -> Option<Locations> {
+        let mut capture_locations = self.whole_regex.capture_locations();
+        let mut end = start;
+
+        while end < subject.len() {
+            end = (end + self.char_len_at(subject, end)).min(subject.len());
+
+            if self
+                .whole_regex
+                .captures_read(&mut capture_locations, &subject[start..end])
+                .is_some()
+            {
+                return Some(Self::locations(&capture_locations, start));
+            }
+        }
+
+        None
+    }
+
+    fn locations(capture_locations: &CaptureLocations, base: usize) -> Locations {
+        (0..capture_locations.len())
+            .map(|group| {
+                capture_locations
+                    .get(group)
+                    .map(|(start, end)| (start + base, end + base))
+            })
+            .collect()
+    }
+
+    fn char_len_at(&self, subject: &[u8], index: usize) -> usize {
+        match subject.get(index) {
+            // The number of leading ones in a UTF-8 lead byte is the length of its character
+            Some(byte) if self.options.unicode => match (!byte).leading_zeros() {
+                len @ 2..=4 => len as usize,
+                _ => 1,
+            },
+            _ => 1,
+        }
+    }
+}
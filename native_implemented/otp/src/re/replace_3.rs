@@ -0,0 +1,18 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::replace_4;
+
+#[native_implemented::function(re:replace/3)]
+pub fn result(
+    process: &Process,
+    subject: Term,
+    regexp: Term,
+    replacement: Term,
+) -> exception::Result<Term> {
+    replace_4::result(process, subject, regexp, replacement, Term::NIL)
+}
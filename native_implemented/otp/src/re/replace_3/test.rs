@@ -0,0 +1,49 @@
+use liblumen_alloc::atom;
+
+use crate::re::replace_3::result;
+use crate::test::with_process;
+
+#[test]
+fn replaces_first_match_and_returns_binary() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a-b-c"),
+                process.charlist_from_str("-"),
+                process.charlist_from_str("+")
+            ),
+            Ok(process.binary_from_str("a+b-c"))
+        );
+    });
+}
+
+#[test]
+fn without_match_returns_subject() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("abc"),
+                process.charlist_from_str("x"),
+                process.charlist_from_str("y")
+            ),
+            Ok(process.binary_from_str("abc"))
+        );
+    });
+}
+
+#[test]
+fn without_iodata_replacement_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.charlist_from_str("b"),
+                atom!("replacement")
+            ),
+            "replacement (replacement)"
+        );
+    });
+}
@@ -0,0 +1,187 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::options::{ReplaceOptions, Return};
+use crate::re::pattern::{Locations, Pattern};
+
+/// Replaces the first match, or with `global`, each match, of `regexp` in `subject` with
+/// `replacement`, where `&` is the whole match, `\N`, `\gN` and `\g{N}` are group `N`, and `\&`
+/// and `\\` are literal.
+///
+/// `{return, iodata}`, the default, returns a binary, which is iodata too.
+#[native_implemented::function(re:replace/4)]
+pub fn result(
+    process: &Process,
+    subject: Term,
+    regexp: Term,
+    replacement: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let options: ReplaceOptions = options.try_into()?;
+    let pattern = Pattern::try_from_term(process, regexp, &options.compile)?;
+    let unicode = pattern.unicode();
+    let subject_bytes = crate::re::to_bytes(process, "subject", subject, unicode)?;
+    let replacement_bytes = crate::re::to_bytes(process, "replacement", replacement, unicode)?;
+
+    if subject_bytes.len() < options.offset {
+        return Err(anyhow!(
+            "offset ({}) is greater than the length ({}) of subject ({})",
+            options.offset,
+            subject_bytes.len(),
+            subject
+        )
+        .into());
+    }
+
+    let parts = parse_replacement(&replacement_bytes);
+    let mut replaced = Vec::with_capacity(subject_bytes.len());
+    let mut copied_end = 0;
+
+    for locations in pattern.matches(&subject_bytes, options.offset, options.global) {
+        let (match_start, match_end) = locations[0].unwrap();
+
+        replaced.extend_from_slice(&subject_bytes[copied_end..match_start]);
+        push_replacement(&mut replaced, &subject_bytes, &locations, &parts);
+        copied_end = match_end;
+    }
+
+    replaced.extend_from_slice(&subject_bytes[copied_end..]);
+
+    let result = match options.r#return {
+        Return::Binary | Return::Iodata => process.binary_from_bytes(&replaced),
+        Return::List => crate::re::bytes_to_list(process, &replaced, unicode),
+    };
+
+    Ok(result)
+}
+
+enum Part {
+    Literal(Vec<u8>),
+    Group(usize),
+}
+
+fn parse_replacement(replacement: &[u8]) -> Vec<Part> {
+    let mut parts = Vec::new();
+    let mut literal = Vec::new();
+    let mut index = 0;
+
+    while index < replacement.len() {
+        let group = match replacement[index] {
+            b'&' => {
+                index += 1;
+
+                Some(0)
+            }
+            b'\\' => match replacement.get(index + 1) {
+                Some(b'&') | Some(b'\\') => {
+                    literal.push(replacement[index + 1]);
+                    index += 2;
+
+                    None
+                }
+                Some(b'g') if replacement.get(index + 2) == Some(&b'{') => {
+                    match digits(&replacement[(index + 3)..]) {
+                        Some((number, len)) if replacement.get(index + 3 + len) == Some(&b'}') => {
+                            index += 4 + len;
+
+                            Some(number)
+                        }
+                        _ => {
+                            literal.push(b'\\');
+                            index += 1;
+
+                            None
+                        }
+                    }
+                }
+                Some(b'g') => match digits(&replacement[(index + 2)..]) {
+                    Some((number, len)) => {
+                        index += 2 + len;
+
+                        Some(number)
+                    }
+                    None => {
+                        literal.push(b'\\');
+                        index += 1;
+
+                        None
+                    }
+                },
+                _ => match digits(&replacement[(index + 1)..]) {
+                    Some((number, len)) => {
+                        index += 1 + len;
+
+                        Some(number)
+                    }
+                    None => {
+                        literal.push(b'\\');
+                        index += 1;
+
+                        None
+                    }
+                },
+            },
+            byte => {
+                literal.push(byte);
+                index += 1;
+
+                None
+            }
+        };
+
+        if let Some(number) = group {
+            if !literal.is_empty() {
+                parts.push(Part::Literal(std::mem::take(&mut literal)));
+            }
+
+            parts.push(Part::Group(number));
+        }
+    }
+
+    if !literal.is_empty() {
+        parts.push(Part::Literal(literal));
+    }
+
+    parts
+}
+
+/// The number at the start of `bytes` and the number of its digits.
+fn digits(bytes: &[u8]) -> Option<(usize, usize)> {
+    let len = bytes
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+
+    if len == 0 {
+        None
+    } else {
+        std::str::from_utf8(&bytes[..len])
+            .unwrap()
+            .parse()
+            .ok()
+            .map(|number| (number, len))
+    }
+}
+
+/// Groups that are not in the pattern or did not participate in the match are replaced by
+/// nothing.
+fn push_replacement(replaced: &mut Vec<u8>, subject: &[u8], locations: &Locations, parts: &[Part]) {
+    for part in parts {
+        match part {
+            Part::Literal(literal) => replaced.extend_from_slice(literal),
+            Part::Group(number) => {
+                if let Some(Some((start, end))) = locations.get(*number) {
+                    replaced.extend_from_slice(&subject[*start..*end]);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use liblumen_alloc::atom;
+
+use crate::re::replace_4::result;
+use crate::test::with_process;
+
+#[test]
+fn with_global_replaces_each_match() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a-b-c"),
+                process.charlist_from_str("-"),
+                process.charlist_from_str("+"),
+                process.list_from_slice(&[atom!("global")])
+            ),
+            Ok(process.binary_from_str("a+b+c"))
+        );
+    });
+}
+
+#[test]
+fn with_group_references_inserts_groups() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("key=value"),
+                process.charlist_from_str("(\\w+)=(\\w+)"),
+                process.charlist_from_str("\\2:\\g{1} [&] \\& \\\\"),
+                process.list_from_slice(&[])
+            ),
+            Ok(process.binary_from_str("value:key [key=value] & \\"))
+        );
+    });
+}
+
+#[test]
+fn with_return_list_returns_characters() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[
+            atom!("unicode"),
+            process.tuple_from_slice(&[atom!("return"), atom!("list")]),
+        ]);
+
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("café"),
+                process.charlist_from_str("é"),
+                process.charlist_from_str("e"),
+                options
+            ),
+            Ok(process.charlist_from_str("cafe"))
+        );
+    });
+}
+
+#[test]
+fn with_global_and_empty_matches_inserts_between_characters() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("ab"),
+                process.charlist_from_str(""),
+                process.charlist_from_str("-"),
+                process.list_from_slice(&[atom!("global")])
+            ),
+            Ok(process.binary_from_str("-a-b-"))
+        );
+    });
+}
+
+#[test]
+fn without_supported_return_type_errors_badarg() {
+    with_process(|process| {
+        let options =
+            process.list_from_slice(&[process.tuple_from_slice(&[atom!("return"), atom!("atom")])]);
+
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("a"),
+                process.charlist_from_str("a"),
+                process.charlist_from_str("b"),
+                options
+            ),
+            "return type must be binary, iodata, or list"
+        );
+    });
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::run_3;
+
+#[native_implemented::function(re:run/2)]
+pub fn result(process: &Process, subject: Term, regexp: Term) -> exception::Result<Term> {
+    run_3::result(process, subject, regexp, Term::NIL)
+}
@@ -0,0 +1,50 @@
+use liblumen_alloc::atom;
+
+use crate::re::run_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_match_returns_nomatch() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.charlist_from_str("d")
+            ),
+            Ok(atom!("nomatch"))
+        );
+    });
+}
+
+#[test]
+fn with_match_returns_index_of_each_group() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("key=value"),
+                process.charlist_from_str("(\\w+)=(\\w+)(;)?")
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[process.integer(0), process.integer(9)]),
+                    process.tuple_from_slice(&[process.integer(0), process.integer(3)]),
+                    process.tuple_from_slice(&[process.integer(4), process.integer(5)]),
+                    process.tuple_from_slice(&[process.integer(-1), process.integer(0)])
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_iodata_subject_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("subject"), process.charlist_from_str("a")),
+            "subject (subject)"
+        );
+    });
+}
@@ -0,0 +1,125 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::options::{Group, RunOptions, Type, Values};
+use crate::re::pattern::{Locations, Pattern};
+
+/// Returns `match` or `{match, Captured}` if `regexp` matches `subject`, where `Captured` has the
+/// groups selected by `{capture, ValueSpec, Type}` for the first match, or with `global`, a list
+/// of them for each match.
+#[native_implemented::function(re:run/3)]
+pub fn result(
+    process: &Process,
+    subject: Term,
+    regexp: Term,
+    options: Term,
+) -> exception::Result<Term> {
+    let options: RunOptions = options.try_into()?;
+    let pattern = Pattern::try_from_term(process, regexp, &options.compile)?;
+    let subject_bytes = crate::re::to_bytes(process, "subject", subject, pattern.unicode())?;
+
+    if subject_bytes.len() < options.offset {
+        return Err(anyhow!(
+            "offset ({}) is greater than the length ({}) of subject ({})",
+            options.offset,
+            subject_bytes.len(),
+            subject
+        )
+        .into());
+    }
+
+    let matches = pattern.matches(&subject_bytes, options.offset, options.global);
+
+    if matches.is_empty() {
+        return Ok(atom!("nomatch"));
+    }
+
+    let groups = groups(&pattern, &options.capture.values);
+
+    if groups.is_empty() {
+        return Ok(atom!("match"));
+    }
+
+    let captured_vec: Vec<Term> = matches
+        .iter()
+        .map(|locations| {
+            captured(
+                process,
+                &pattern,
+                &subject_bytes,
+                locations,
+                &groups,
+                options.capture.r#type,
+            )
+        })
+        .collect();
+    let captured = if options.global {
+        process.list_from_slice(&captured_vec)
+    } else {
+        captured_vec[0]
+    };
+
+    Ok(process.tuple_from_slice(&[atom!("match"), captured]))
+}
+
+/// The group numbers selected by `values`, where `None` is a group that isn't in the pattern.
+fn groups(pattern: &Pattern, values: &Values) -> Vec<Option<usize>> {
+    match values {
+        Values::All => (0..pattern.groups_len()).map(Some).collect(),
+        Values::AllButFirst => (1..pattern.groups_len()).map(Some).collect(),
+        Values::AllNames => pattern.named_groups().into_iter().map(Some).collect(),
+        Values::First => vec![Some(0)],
+        Values::None => Vec::new(),
+        Values::Groups(groups) => groups
+            .iter()
+            .map(|group| match group {
+                Group::Number(number) if *number < pattern.groups_len() => Some(*number),
+                Group::Number(_) => None,
+                Group::Name(name) => pattern.group_number(name),
+            })
+            .collect(),
+    }
+}
+
+fn captured(
+    process: &Process,
+    pattern: &Pattern,
+    subject: &[u8],
+    locations: &Locations,
+    groups: &[Option<usize>],
+    r#type: Type,
+) -> Term {
+    let group_captured_vec: Vec<Term> = groups
+        .iter()
+        .map(|group| {
+            let location = group.and_then(|number| locations[number]);
+
+            match (r#type, location) {
+                (Type::Index, Some((start, end))) => process
+                    .tuple_from_slice(&[process.integer(start), process.integer(end - start)]),
+                (Type::Index, None) => {
+                    process.tuple_from_slice(&[process.integer(-1), process.integer(0)])
+                }
+                (Type::Binary, Some((start, end))) => {
+                    process.binary_from_bytes(&subject[start..end])
+                }
+                (Type::Binary, None) => process.binary_from_bytes(&[]),
+                (Type::List, Some((start, end))) => {
+                    crate::re::bytes_to_list(process, &subject[start..end], pattern.unicode())
+                }
+                (Type::List, None) => Term::NIL,
+            }
+        })
+        .collect();
+
+    process.list_from_slice(&group_captured_vec)
+}
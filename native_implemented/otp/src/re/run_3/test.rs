@@ -0,0 +1,191 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::re::run_3::result;
+use crate::test::with_process;
+
+#[test]
+fn with_global_returns_captured_for_each_match() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[
+            atom!("global"),
+            process.tuple_from_slice(&[atom!("capture"), atom!("first"), atom!("binary")]),
+        ]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a1b22c333"),
+                process.charlist_from_str("[0-9]+"),
+                options
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.list_from_slice(&[process.binary_from_str("1")]),
+                    process.list_from_slice(&[process.binary_from_str("22")]),
+                    process.list_from_slice(&[process.binary_from_str("333")])
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_global_retries_empty_match_as_non_empty_match() {
+    with_process(|process| {
+        let index = |start: isize, length: isize| {
+            process.tuple_from_slice(&[process.integer(start), process.integer(length)])
+        };
+        let group_and_whole =
+            |start, length| process.list_from_slice(&[index(start, length), index(start, length)]);
+
+        // re:run("cat", "(|at)", [global])
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("cat"),
+                process.charlist_from_str("(|at)"),
+                process.list_from_slice(&[atom!("global")])
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    group_and_whole(0, 0),
+                    group_and_whole(1, 0),
+                    group_and_whole(1, 2),
+                    group_and_whole(3, 0)
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_capture_none_returns_match() {
+    with_process(|process| {
+        let options = process
+            .list_from_slice(&[process.tuple_from_slice(&[atom!("capture"), atom!("none")])]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.charlist_from_str("b"),
+                options
+            ),
+            Ok(atom!("match"))
+        );
+    });
+}
+
+#[test]
+fn with_capture_names_as_list_returns_named_groups() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[process.tuple_from_slice(&[
+            atom!("capture"),
+            process.list_from_slice(&[atom!("year"), process.integer(2), atom!("missing")]),
+            atom!("list"),
+        ])]);
+
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("2020-06"),
+                process.charlist_from_str("(?P<year>\\d+)-(?P<month>\\d+)"),
+                options
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.charlist_from_str("2020"),
+                    process.charlist_from_str("06"),
+                    Term::NIL
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_all_names_returns_named_groups_sorted_by_name() {
+    with_process(|process| {
+        let options = process.list_from_slice(&[process.tuple_from_slice(&[
+            atom!("capture"),
+            atom!("all_names"),
+            atom!("binary"),
+        ])]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("2020-06"),
+                process.charlist_from_str("(?P<year>\\d+)-(?P<month>\\d+)"),
+                options
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.binary_from_str("06"),
+                    process.binary_from_str("2020")
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_offset_starts_matching_at_offset() {
+    with_process(|process| {
+        let options = process
+            .list_from_slice(&[process.tuple_from_slice(&[atom!("offset"), process.integer(2)])]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abab"),
+                process.charlist_from_str("ab"),
+                options
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("match"),
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[process.integer(2), process.integer(2)])
+                ])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_offset_greater_than_subject_errors_badarg() {
+    with_process(|process| {
+        let options = process
+            .list_from_slice(&[process.tuple_from_slice(&[atom!("offset"), process.integer(4)])]);
+
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.charlist_from_str("a"),
+                options
+            ),
+            "offset (4) is greater than the length (3)"
+        );
+    });
+}
+
+#[test]
+fn with_invalid_regexp_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.charlist_from_str("(a"),
+                Term::NIL
+            ),
+            "could not be compiled"
+        );
+    });
+}
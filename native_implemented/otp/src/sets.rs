@@ -0,0 +1,34 @@
+//! Mirrors [sets](http://erlang.org/doc/man/sets.html) module
+//!
+//! Sets are maps from each element to `[]`, like version 2 sets in OTP 24, which became the
+//! default in OTP 28.  Elements are matched exactly, like map keys, so `1` and `1.0` are different
+//! elements.
+
+pub mod add_element_2;
+pub mod del_element_2;
+pub mod from_list_1;
+pub mod is_element_2;
+pub mod new_0;
+pub mod size_1;
+pub mod to_list_1;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+fn module() -> Atom {
+    Atom::from_str("sets")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+fn term_try_into_set(set: Term) -> exception::Result<Boxed<Map>> {
+    set.try_into()
+        .with_context(|| format!("set ({}) is not a set", set))
+        .map_err(From::from)
+}
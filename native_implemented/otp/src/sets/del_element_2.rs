@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `set` without `element`
+#[native_implemented::function(sets:del_element/2)]
+pub fn result(process: &Process, element: Term, set: Term) -> exception::Result<Term> {
+    let boxed_map = super::term_try_into_set(set)?;
+
+    match boxed_map.remove(element) {
+        Some(new_map) => Ok(process.map(new_map)),
+        None => Ok(set),
+    }
+}
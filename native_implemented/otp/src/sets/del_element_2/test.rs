@@ -0,0 +1,36 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::del_element_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("a"), atom!("set")),
+            "set (set) is not a set"
+        );
+    });
+}
+
+#[test]
+fn with_element_removes_element() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(atom!("a"), Term::NIL), (atom!("b"), Term::NIL)]);
+
+        assert_eq!(
+            result(process, atom!("a"), set),
+            Ok(process.map_from_slice(&[(atom!("b"), Term::NIL)]))
+        );
+    });
+}
+
+#[test]
+fn without_element_returns_set() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(atom!("a"), Term::NIL)]);
+
+        assert_eq!(result(process, atom!("b"), set), Ok(set));
+    });
+}
@@ -0,0 +1,29 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// A set of the elements of `list`
+#[native_implemented::function(sets:from_list/1)]
+pub fn result(process: &Process, list: Term) -> exception::Result<Term> {
+    let entry_vec: Vec<(Term, Term)> = match list.decode()? {
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => cons
+            .into_iter()
+            .map(|result| result.map(|element| (element, Term::NIL)))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| ImproperListError)
+            .with_context(|| format!("list ({}) is not a proper list", list))?,
+        _ => {
+            return Err(TypeError)
+                .context(format!("list ({}) is not a proper list", list))
+                .map_err(From::from)
+        }
+    };
+
+    Ok(process.map_from_hash_map(entry_vec.into_iter().collect()))
+}
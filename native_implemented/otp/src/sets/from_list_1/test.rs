@@ -0,0 +1,69 @@
+use proptest::prop_assert_eq;
+use proptest::strategy::Just;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::from_list_1::result;
+use crate::test::{strategy, with_process};
+
+#[test]
+fn without_proper_list_errors_badarg() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                strategy::term::is_not_proper_list(arc_process),
+            )
+        },
+        |(arc_process, list)| {
+            prop_assert_badarg!(
+                result(&arc_process, list),
+                format!("list ({}) is not a proper list", list)
+            );
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn with_proper_list_returns_map_of_elements_to_empty_list() {
+    with_process(|process| {
+        let list = process.list_from_slice(&[atom!("b"), atom!("a"), atom!("b")]);
+
+        assert_eq!(
+            result(process, list),
+            Ok(process.map_from_slice(&[(atom!("a"), Term::NIL), (atom!("b"), Term::NIL)]))
+        );
+    });
+}
+
+#[test]
+fn with_proper_list_of_atoms_has_each_atom_once() {
+    run!(
+        |arc_process| {
+            (
+                Just(arc_process.clone()),
+                proptest::collection::vec(strategy::term::atom(), 0..=8),
+            )
+        },
+        |(arc_process, element_vec)| {
+            let list = arc_process.list_from_slice(&element_vec);
+            let mut unique_vec = element_vec.clone();
+            unique_vec.sort();
+            unique_vec.dedup();
+            let entry_vec: Vec<(Term, Term)> = unique_vec
+                .into_iter()
+                .map(|element| (element, Term::NIL))
+                .collect();
+
+            prop_assert_eq!(
+                result(&arc_process, list),
+                Ok(arc_process.map_from_slice(&entry_vec))
+            );
+
+            Ok(())
+        },
+    );
+}
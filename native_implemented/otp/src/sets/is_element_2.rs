@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Whether `element` is in `set`
+#[native_implemented::function(sets:is_element/2)]
+pub fn result(element: Term, set: Term) -> exception::Result<Term> {
+    let boxed_map = super::term_try_into_set(set)?;
+
+    Ok(boxed_map.is_key(element).into())
+}
@@ -0,0 +1,37 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::is_element_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    assert_badarg!(result(atom!("a"), atom!("set")), "set (set) is not a set");
+}
+
+#[test]
+fn with_element_returns_true() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(atom!("a"), Term::NIL)]);
+
+        assert_eq!(result(atom!("a"), set), Ok(true.into()));
+    });
+}
+
+#[test]
+fn without_element_returns_false() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(atom!("a"), Term::NIL)]);
+
+        assert_eq!(result(atom!("b"), set), Ok(false.into()));
+    });
+}
+
+#[test]
+fn with_equal_but_not_exactly_equal_number_returns_false() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(process.integer(1), Term::NIL)]);
+
+        assert_eq!(result(process.float(1.0), set), Ok(false.into()));
+    });
+}
@@ -0,0 +1,15 @@
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::new_0::result;
+use crate::sets::size_1;
+use crate::test::with_process;
+
+#[test]
+fn returns_empty_set() {
+    with_process(|process| {
+        let set = result(process);
+
+        assert!(set.is_boxed_map());
+        assert_eq!(size_1::result(process, set), Ok(process.integer(0)));
+    });
+}
@@ -0,0 +1,21 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::size_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("set")), "set (set) is not a set");
+    });
+}
+
+#[test]
+fn returns_number_of_elements() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(atom!("a"), Term::NIL), (atom!("b"), Term::NIL)]);
+
+        assert_eq!(result(process, set), Ok(process.integer(2)));
+    });
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// The elements of `set`, in no particular order
+#[native_implemented::function(sets:to_list/1)]
+pub fn result(process: &Process, set: Term) -> exception::Result<Term> {
+    let boxed_map = super::term_try_into_set(set)?;
+
+    Ok(process.list_from_slice(&boxed_map.keys()))
+}
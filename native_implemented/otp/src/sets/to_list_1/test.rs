@@ -0,0 +1,24 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sets::to_list_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_set_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(result(process, atom!("set")), "set (set) is not a set");
+    });
+}
+
+#[test]
+fn returns_elements() {
+    with_process(|process| {
+        let set = process.map_from_slice(&[(atom!("a"), Term::NIL), (atom!("b"), Term::NIL)]);
+
+        assert_eq!(
+            result(process, set),
+            Ok(process.list_from_slice(&[atom!("a"), atom!("b")]))
+        );
+    });
+}
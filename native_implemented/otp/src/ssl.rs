@@ -0,0 +1,158 @@
+//! Mirrors [ssl](http://erlang.org/doc/man/ssl.html) module
+//!
+//! Only TLS clients that verify the server's certificate, and servers that upgrade an accepted
+//! `gen_tcp` socket with `ssl:handshake/2`, are supported.  Like `gen_tcp`, the operations that
+//! wait for the peer run as futures on the `task` pool.
+//!
+//! Only built with the `ssl` feature, which is on by default, and not for wasm32, as `rustls`
+//! can't be built for it.
+
+pub mod close_1;
+pub mod connect_3;
+pub mod connect_4;
+pub mod handshake_2;
+pub mod recv_2;
+pub mod recv_3;
+pub mod send_2;
+
+use std::convert::TryInto;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::{Frame, Process};
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::inet::{spawn, term_try_into_address, term_try_into_port_number, term_try_into_socket};
+use crate::runtime::inet::{self, tls};
+use crate::unicode::characters;
+
+fn module() -> Atom {
+    Atom::from_str("ssl")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+fn connect(
+    process: &Process,
+    label_frame: Frame,
+    host: Term,
+    port: Term,
+    options: Term,
+    timeout: Option<Duration>,
+) -> exception::Result<Term> {
+    let address = term_try_into_address(process, host)?;
+    let port_number = term_try_into_port_number(port)?;
+    let (options, config) = term_try_into_options(process, options)?;
+    let owner = process.pid();
+
+    Ok(spawn(process, label_frame, move || {
+        tls::connect(owner, &address, port_number, options, config, timeout)
+    }))
+}
+
+fn recv(
+    process: &Process,
+    label_frame: Frame,
+    ssl_socket: Term,
+    length: Term,
+    timeout: Option<Duration>,
+) -> exception::Result<Term> {
+    let socket_port = term_try_into_socket("ssl_socket", ssl_socket)?;
+    let len: usize = length
+        .try_into()
+        .with_context(|| format!("length ({}) is not a non-negative integer", length))?;
+
+    Ok(spawn(process, label_frame, move || {
+        tls::recv(socket_port, len, timeout)
+    }))
+}
+
+/// Splits the TLS options out of `options`, which leaves the options of `gen_tcp`.
+fn term_try_into_options(
+    process: &Process,
+    options: Term,
+) -> exception::Result<(inet::Options, tls::Config)> {
+    let mut config = tls::Config::default();
+    let mut inet_option_vec = Vec::new();
+    let mut options_term = options;
+
+    loop {
+        match options_term.decode()? {
+            TypedTerm::Nil => break,
+            TypedTerm::List(cons) => {
+                if !put_tls_option(process, &mut config, cons.head)? {
+                    inet_option_vec.push(cons.head);
+                }
+
+                options_term = cons.tail;
+            }
+            _ => {
+                return Err(ImproperListError)
+                    .with_context(|| format!("options ({}) is not a proper list", options))
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    let inet_options: inet::Options = process.list_from_slice(&inet_option_vec).try_into()?;
+
+    Ok((inet_options, config))
+}
+
+/// Returns `false` if `option` isn't a TLS option.
+fn put_tls_option(
+    process: &Process,
+    config: &mut tls::Config,
+    option: Term,
+) -> exception::Result<bool> {
+    let tuple = match option.decode()? {
+        TypedTerm::Tuple(tuple) if tuple.len() == 2 => tuple,
+        _ => return Ok(false),
+    };
+    let name: Atom = match tuple[0].try_into() {
+        Ok(name) => name,
+        Err(_) => return Ok(false),
+    };
+    let value = tuple[1];
+
+    match name.name() {
+        "cacertfile" => config.cacertfile = Some(term_try_into_path(process, name, value)?),
+        "certfile" => config.certfile = Some(term_try_into_path(process, name, value)?),
+        "keyfile" => config.keyfile = Some(term_try_into_path(process, name, value)?),
+        "server_name_indication" => {
+            config.server_name_indication = Some(term_try_into_string(process, name, value)?)
+        }
+        // Servers are always verified
+        "verify" => {
+            if value != atom!("verify_peer") {
+                return Err(anyhow!("verify ({}) is not verify_peer", value).into());
+            }
+        }
+        _ => return Ok(false),
+    }
+
+    Ok(true)
+}
+
+fn term_try_into_path(process: &Process, name: Atom, term: Term) -> exception::Result<PathBuf> {
+    term_try_into_string(process, name, term).map(From::from)
+}
+
+fn term_try_into_string(process: &Process, name: Atom, term: Term) -> exception::Result<String> {
+    if !(term.is_list() || term.is_binary()) {
+        return Err(TypeError)
+            .with_context(|| format!("{} ({}) is not a string or binary", name, term))
+            .map_err(From::from);
+    }
+
+    let bytes = characters::to_utf8_bytes(process, name.name(), term)?;
+
+    // `to_utf8_bytes` only returns valid UTF-8
+    Ok(String::from_utf8(bytes).unwrap())
+}
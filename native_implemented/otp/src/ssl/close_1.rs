@@ -0,0 +1,17 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet;
+
+/// Sends the close_notify alert and returns `ok`, even if `ssl_socket` is already closed.
+#[native_implemented::function(ssl:close/1)]
+pub fn result(ssl_socket: Term) -> exception::Result<Term> {
+    let socket_port = crate::inet::term_try_into_socket("ssl_socket", ssl_socket)?;
+    inet::close(socket_port);
+
+    Ok(atom!("ok"))
+}
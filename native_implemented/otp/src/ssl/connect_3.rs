@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, SslSocket}` connected to `port` on `host` once the server's certificate is
+/// verified for `host`, or `{error, Reason}`.
+#[native_implemented::function(ssl:connect/3)]
+pub fn result(process: &Process, host: Term, port: Term, options: Term) -> exception::Result<Term> {
+    super::connect(process, label_1::frame(), host, port, options, None)
+}
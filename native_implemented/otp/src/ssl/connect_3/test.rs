@@ -0,0 +1,36 @@
+use liblumen_alloc::atom;
+
+use crate::ssl::connect_3;
+use crate::test::with_process;
+
+#[test]
+fn without_address_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_3::result(
+                process,
+                process.integer(1),
+                process.integer(443),
+                process.list_from_slice(&[])
+            ),
+            "address (1) is not a hostname atom, string, or binary, or an IP address tuple"
+        );
+    });
+}
+
+#[test]
+fn with_cacertfile_that_is_not_a_string_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_3::result(
+                process,
+                atom!("localhost"),
+                process.integer(443),
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[atom!("cacertfile"), process.integer(1)])
+                ])
+            ),
+            "cacertfile (1) is not a string or binary"
+        );
+    });
+}
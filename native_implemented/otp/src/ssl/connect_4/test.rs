@@ -0,0 +1,38 @@
+use liblumen_alloc::atom;
+
+use crate::ssl::connect_4;
+use crate::test::with_process;
+
+#[test]
+fn with_verify_none_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_4::result(
+                process,
+                atom!("localhost"),
+                process.integer(443),
+                process.list_from_slice(&[
+                    process.tuple_from_slice(&[atom!("verify"), atom!("verify_none")])
+                ]),
+                atom!("infinity")
+            ),
+            "verify (verify_none) is not verify_peer"
+        );
+    });
+}
+
+#[test]
+fn without_supported_option_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            connect_4::result(
+                process,
+                atom!("localhost"),
+                process.integer(443),
+                process.list_from_slice(&[atom!("nodelay")]),
+                atom!("infinity")
+            ),
+            "supported options are"
+        );
+    });
+}
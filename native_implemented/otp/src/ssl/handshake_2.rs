@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::inet::tls;
+
+/// Upgrades the passive `gen_tcp` `socket` to `{ok, SslSocket}`, as the server with the
+/// `certfile` and `keyfile` of `options`, or returns `{error, Reason}`.
+#[native_implemented::function(ssl:handshake/2)]
+pub fn result(process: &Process, socket: Term, options: Term) -> exception::Result<Term> {
+    let socket_port = crate::inet::term_try_into_socket("socket", socket)?;
+    let (options, config) = super::term_try_into_options(process, options)?;
+    let owner = process.pid();
+
+    Ok(crate::inet::spawn(process, label_1::frame(), move || {
+        tls::accept(owner, socket_port, options, config)
+    }))
+}
@@ -0,0 +1,12 @@
+use crate::ssl::handshake_2;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            handshake_2::result(process, process.integer(1), process.list_from_slice(&[])),
+            "socket (1) is not a socket"
+        );
+    });
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `{ok, Data}` with exactly `length` decrypted bytes from the passive `ssl_socket`, or
+/// whatever is available if `length` is `0`, or `{error, Reason}`.
+#[native_implemented::function(ssl:recv/2)]
+pub fn result(process: &Process, ssl_socket: Term, length: Term) -> exception::Result<Term> {
+    super::recv(process, label_1::frame(), ssl_socket, length, None)
+}
@@ -0,0 +1,22 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Like `ssl:recv/2`, but returns `{error, timeout}` if the bytes don't arrive within `timeout`
+/// milliseconds.
+#[native_implemented::function(ssl:recv/3)]
+pub fn result(
+    process: &Process,
+    ssl_socket: Term,
+    length: Term,
+    timeout: Term,
+) -> exception::Result<Term> {
+    let timeout = crate::inet::term_try_into_timeout(timeout)?;
+
+    super::recv(process, label_1::frame(), ssl_socket, length, timeout)
+}
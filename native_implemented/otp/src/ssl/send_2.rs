@@ -0,0 +1,23 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+mod label_1;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+use crate::runtime::inet::tls;
+
+/// Returns `ok` once all of `data` is encrypted and written to `ssl_socket`, or
+/// `{error, Reason}`.
+#[native_implemented::function(ssl:send/2)]
+pub fn result(process: &Process, ssl_socket: Term, data: Term) -> exception::Result<Term> {
+    let socket_port = crate::inet::term_try_into_socket("ssl_socket", ssl_socket)?;
+    let bytes = iolist_or_binary::to_byte_vec("data", data)?;
+
+    Ok(crate::inet::spawn(process, label_1::frame(), move || {
+        tls::send(socket_port, &bytes)
+    }))
+}
@@ -0,0 +1,12 @@
+use crate::ssl::send_2;
+use crate::test::with_process;
+
+#[test]
+fn without_socket_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            send_2::result(process, process.integer(1), process.binary_from_str("hi")),
+            "ssl_socket (1) is not a socket"
+        );
+    });
+}
@@ -0,0 +1,218 @@
+//! Mirrors [string](http://erlang.org/doc/man/string.html) module
+//!
+//! Only the functions of the Unicode-aware API added in OTP 20 are supported.  Like OTP, they
+//! work on grapheme clusters, so a pattern never matches part of a cluster, such as the `\r` of
+//! `\r\n`.  Binary arguments give binary results, and any other chardata gives a flat charlist.
+
+pub mod lowercase_1;
+pub mod split_2;
+pub mod split_3;
+pub mod to_integer_1;
+pub mod trim_1;
+pub mod trim_2;
+pub mod trim_3;
+pub mod uppercase_1;
+
+use anyhow::*;
+use unicode_segmentation::UnicodeSegmentation;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::context::term_try_into_atom;
+use crate::unicode::characters;
+
+fn module() -> Atom {
+    Atom::from_str("string")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Whether results are returned as binaries or charlists
+#[derive(Clone, Copy)]
+enum Output {
+    Binary,
+    List,
+}
+
+struct Chardata {
+    string: String,
+    output: Output,
+}
+impl Chardata {
+    fn to_term(&self, process: &Process, s: &str) -> Term {
+        match self.output {
+            Output::Binary => process.binary_from_str(s),
+            Output::List => process.list_from_chars(s.chars()),
+        }
+    }
+}
+
+fn term_try_into_chardata(
+    process: &Process,
+    name: &str,
+    chardata: Term,
+) -> exception::Result<Chardata> {
+    let bytes = characters::to_utf8_bytes(process, name, chardata)?;
+    // `to_utf8_bytes` only returns valid UTF-8
+    let string = String::from_utf8(bytes).unwrap();
+    let output = if chardata.is_binary() {
+        Output::Binary
+    } else {
+        Output::List
+    };
+
+    Ok(Chardata { string, output })
+}
+
+/// Which end of the string `trim` removes characters from
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Leading,
+    Trailing,
+    Both,
+}
+
+fn term_try_into_direction(direction: Term) -> exception::Result<Direction> {
+    let direction_atom = term_try_into_atom("dir", direction)?;
+
+    match direction_atom.name() {
+        "leading" => Ok(Direction::Leading),
+        "trailing" => Ok(Direction::Trailing),
+        "both" => Ok(Direction::Both),
+        _ => Err(anyhow!("dir ({}) is not leading, trailing, or both", direction).into()),
+    }
+}
+
+/// The grapheme clusters that are whitespace, like `unicode_util:whitespace/0`
+fn whitespace() -> Vec<String> {
+    [
+        "\r\n", "\t", "\n", "\u{B}", "\u{C}", "\r", " ", "\u{85}", "\u{200E}", "\u{200F}",
+        "\u{2028}", "\u{2029}",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+fn trim(
+    process: &Process,
+    string: Term,
+    direction: Direction,
+    characters: &[String],
+) -> exception::Result<Term> {
+    let chardata = term_try_into_chardata(process, "string", string)?;
+    let s = chardata.string.as_str();
+    let is_trimmed = |grapheme: &&str| {
+        characters
+            .iter()
+            .any(|character| character.as_str() == *grapheme)
+    };
+
+    let start = if direction == Direction::Trailing {
+        0
+    } else {
+        s.grapheme_indices(true)
+            .find(|(_, grapheme)| !is_trimmed(grapheme))
+            .map_or(s.len(), |(index, _)| index)
+    };
+    let end = if direction == Direction::Leading {
+        s.len()
+    } else {
+        s.grapheme_indices(true)
+            .rev()
+            .find(|(_, grapheme)| !is_trimmed(grapheme))
+            .map_or(start, |(index, grapheme)| index + grapheme.len())
+    };
+
+    Ok(chardata.to_term(process, &s[start..end.max(start)]))
+}
+
+/// Which occurrences of the pattern `split` splits at
+#[derive(Clone, Copy)]
+enum Where {
+    Leading,
+    Trailing,
+    All,
+}
+
+fn term_try_into_where(r#where: Term) -> exception::Result<Where> {
+    let where_atom = term_try_into_atom("where", r#where)?;
+
+    match where_atom.name() {
+        "leading" => Ok(Where::Leading),
+        "trailing" => Ok(Where::Trailing),
+        "all" => Ok(Where::All),
+        _ => Err(anyhow!("where ({}) is not leading, trailing, or all", r#where).into()),
+    }
+}
+
+fn split(
+    process: &Process,
+    string: Term,
+    search_pattern: Term,
+    r#where: Where,
+) -> exception::Result<Term> {
+    let chardata = term_try_into_chardata(process, "string", string)?;
+    let pattern = term_try_into_chardata(process, "search_pattern", search_pattern)?.string;
+    let s = chardata.string.as_str();
+
+    let grapheme_vec: Vec<(usize, &str)> = s.grapheme_indices(true).collect();
+    let pattern_vec: Vec<&str> = pattern.graphemes(true).collect();
+    let len = grapheme_vec.len();
+    let pattern_len = pattern_vec.len();
+    let byte_index = |index: usize| grapheme_vec.get(index).map_or(s.len(), |(i, _)| *i);
+    let is_match_at = |index: usize| {
+        grapheme_vec[index..index + pattern_len]
+            .iter()
+            .map(|(_, grapheme)| grapheme)
+            .eq(pattern_vec.iter())
+    };
+
+    // The byte ranges of the occurrences of the pattern to split at
+    let mut match_vec = Vec::new();
+
+    if (0 < pattern_len) && (pattern_len <= len) {
+        let last_start = len - pattern_len;
+
+        match r#where {
+            Where::Leading => {
+                if let Some(index) = (0..=last_start).find(|index| is_match_at(*index)) {
+                    match_vec.push(index);
+                }
+            }
+            Where::Trailing => {
+                if let Some(index) = (0..=last_start).rev().find(|index| is_match_at(*index)) {
+                    match_vec.push(index);
+                }
+            }
+            Where::All => {
+                let mut index = 0;
+
+                while index <= last_start {
+                    if is_match_at(index) {
+                        match_vec.push(index);
+                        index += pattern_len;
+                    } else {
+                        index += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut part_vec = Vec::with_capacity(match_vec.len() + 1);
+    let mut part_start = 0;
+
+    for index in match_vec {
+        part_vec.push(chardata.to_term(process, &s[part_start..byte_index(index)]));
+        part_start = byte_index(index + pattern_len);
+    }
+
+    part_vec.push(chardata.to_term(process, &s[part_start..]));
+
+    Ok(process.list_from_slice(&part_vec))
+}
@@ -0,0 +1,34 @@
+use liblumen_alloc::atom;
+
+use crate::string::lowercase_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_chardata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("string")),
+            "data (string) is not a binary or list"
+        );
+    });
+}
+
+#[test]
+fn with_binary_returns_binary() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str("HeLLo ÅÄÖ")),
+            Ok(process.binary_from_str("hello åäö"))
+        );
+    });
+}
+
+#[test]
+fn with_list_returns_list() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("ÀÉÎ")),
+            Ok(process.charlist_from_str("àéî"))
+        );
+    });
+}
@@ -0,0 +1,77 @@
+use liblumen_alloc::atom;
+
+use crate::string::split_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_chardata_string_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("string"), process.charlist_from_str(",")),
+            "data (string) is not a binary or list"
+        );
+    });
+}
+
+#[test]
+fn with_binary_splits_at_first_occurrence_into_binaries() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a,b,c"),
+                process.charlist_from_str(",")
+            ),
+            Ok(process
+                .list_from_slice(&[process.binary_from_str("a"), process.binary_from_str("b,c")]))
+        );
+    });
+}
+
+#[test]
+fn with_list_splits_into_lists() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("key=value"),
+                process.binary_from_str("=")
+            ),
+            Ok(process.list_from_slice(&[
+                process.charlist_from_str("key"),
+                process.charlist_from_str("value")
+            ]))
+        );
+    });
+}
+
+#[test]
+fn without_occurrence_returns_string() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("abc"),
+                process.charlist_from_str(",")
+            ),
+            Ok(process.list_from_slice(&[process.binary_from_str("abc")]))
+        );
+    });
+}
+
+#[test]
+fn does_not_split_grapheme_cluster() {
+    with_process(|process| {
+        let string = process.binary_from_str("a\r\nb");
+
+        assert_eq!(
+            result(process, string, process.charlist_from_str("\r")),
+            Ok(process.list_from_slice(&[string]))
+        );
+        assert_eq!(
+            result(process, string, process.charlist_from_str("\r\n")),
+            Ok(process
+                .list_from_slice(&[process.binary_from_str("a"), process.binary_from_str("b")]))
+        );
+    });
+}
@@ -0,0 +1,20 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Splits `string` at the first (`leading`), last (`trailing`) or every (`all`) occurrence of
+/// `search_pattern`
+#[native_implemented::function(string:split/3)]
+pub fn result(
+    process: &Process,
+    string: Term,
+    search_pattern: Term,
+    r#where: Term,
+) -> exception::Result<Term> {
+    let r#where = super::term_try_into_where(r#where)?;
+
+    super::split(process, string, search_pattern, r#where)
+}
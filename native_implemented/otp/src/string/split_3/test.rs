@@ -0,0 +1,84 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::split_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_where_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("a,b"),
+                process.charlist_from_str(","),
+                atom!("middle")
+            ),
+            "where (middle) is not leading, trailing, or all"
+        );
+    });
+}
+
+#[test]
+fn with_trailing_splits_at_last_occurrence() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a,b,c"),
+                process.charlist_from_str(","),
+                atom!("trailing")
+            ),
+            Ok(process
+                .list_from_slice(&[process.binary_from_str("a,b"), process.binary_from_str("c")]))
+        );
+    });
+}
+
+#[test]
+fn with_trailing_finds_overlapping_last_occurrence() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("xaaa"),
+                process.charlist_from_str("aa"),
+                atom!("trailing")
+            ),
+            Ok(process
+                .list_from_slice(&[process.binary_from_str("xa"), process.binary_from_str("")]))
+        );
+    });
+}
+
+#[test]
+fn with_all_splits_at_every_occurrence() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.charlist_from_str("a,b,,c"),
+                process.charlist_from_str(","),
+                atom!("all")
+            ),
+            Ok(process.list_from_slice(&[
+                process.charlist_from_str("a"),
+                process.charlist_from_str("b"),
+                Term::NIL,
+                process.charlist_from_str("c")
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_empty_pattern_returns_string() {
+    with_process(|process| {
+        let string = process.binary_from_str("abc");
+
+        assert_eq!(
+            result(process, string, Term::NIL, atom!("all")),
+            Ok(process.list_from_slice(&[string]))
+        );
+    });
+}
@@ -0,0 +1,45 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use num_bigint::BigInt;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `{Int, Rest}` for the integer at the start of `string`, which may have a `+` or `-` sign, or
+/// `{error, no_integer}` if it does not start with one, or `{error, badarg}` if it is not
+/// chardata
+#[native_implemented::function(string:to_integer/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    let chardata = match super::term_try_into_chardata(process, "string", string) {
+        Ok(chardata) => chardata,
+        Err(_) => return Ok(error(process, atom!("badarg"))),
+    };
+    let s = chardata.string.as_str();
+
+    let sign_len = if s.starts_with('+') || s.starts_with('-') {
+        1
+    } else {
+        0
+    };
+    let digits_len = s[sign_len..]
+        .bytes()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+
+    if digits_len == 0 {
+        Ok(error(process, atom!("no_integer")))
+    } else {
+        let (integer_str, rest) = s.split_at(sign_len + digits_len);
+        let unsigned_str = integer_str.trim_start_matches('+');
+        let big_int = BigInt::parse_bytes(unsigned_str.as_bytes(), 10).unwrap();
+
+        Ok(process.tuple_from_slice(&[process.integer(big_int), chardata.to_term(process, rest)]))
+    }
+}
+
+fn error(process: &Process, reason: Term) -> Term {
+    process.tuple_from_slice(&[atom!("error"), reason])
+}
@@ -0,0 +1,60 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::string::to_integer_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_chardata_returns_error_badarg() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, atom!("string")),
+            Ok(process.tuple_from_slice(&[atom!("error"), atom!("badarg")]))
+        );
+    });
+}
+
+#[test]
+fn without_integer_returns_error_no_integer() {
+    with_process(|process| {
+        let error = process.tuple_from_slice(&[atom!("error"), atom!("no_integer")]);
+
+        assert_eq!(result(process, process.binary_from_str("abc")), Ok(error));
+        assert_eq!(result(process, process.binary_from_str("-")), Ok(error));
+        assert_eq!(result(process, process.binary_from_str(" 1")), Ok(error));
+        assert_eq!(result(process, Term::NIL), Ok(error));
+    });
+}
+
+#[test]
+fn with_binary_returns_integer_and_binary_rest() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str("-123abc")),
+            Ok(process.tuple_from_slice(&[process.integer(-123), process.binary_from_str("abc")]))
+        );
+    });
+}
+
+#[test]
+fn with_list_returns_integer_and_list_rest() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("+42")),
+            Ok(process.tuple_from_slice(&[process.integer(42), Term::NIL]))
+        );
+    });
+}
+
+#[test]
+fn with_big_integer_returns_big_integer() {
+    with_process(|process| {
+        let integer = "123456789012345678901234567890";
+        let big_int = num_bigint::BigInt::parse_bytes(integer.as_bytes(), 10).unwrap();
+
+        assert_eq!(
+            result(process, process.charlist_from_str(integer)),
+            Ok(process.tuple_from_slice(&[process.integer(big_int), Term::NIL]))
+        );
+    });
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::Direction;
+
+/// `string` without leading and trailing whitespace
+#[native_implemented::function(string:trim/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    super::trim(process, string, Direction::Both, &super::whitespace())
+}
@@ -0,0 +1,44 @@
+use liblumen_alloc::atom;
+
+use crate::string::trim_1::result;
+use crate::test::with_process;
+
+#[test]
+fn without_chardata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("string")),
+            "data (string) is not a binary or list"
+        );
+    });
+}
+
+#[test]
+fn with_binary_trims_both_ends() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str("\t a b \r\n")),
+            Ok(process.binary_from_str("a b"))
+        );
+    });
+}
+
+#[test]
+fn with_list_returns_list() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.charlist_from_str("  abc\u{2029}")),
+            Ok(process.charlist_from_str("abc"))
+        );
+    });
+}
+
+#[test]
+fn with_only_whitespace_returns_empty() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str(" \n ")),
+            Ok(process.binary_from_str(""))
+        );
+    });
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `string` without whitespace at the `leading`, `trailing` or `both` ends
+#[native_implemented::function(string:trim/2)]
+pub fn result(process: &Process, string: Term, dir: Term) -> exception::Result<Term> {
+    let direction = super::term_try_into_direction(dir)?;
+
+    super::trim(process, string, direction, &super::whitespace())
+}
@@ -0,0 +1,44 @@
+use liblumen_alloc::atom;
+
+use crate::string::trim_2::result;
+use crate::test::with_process;
+
+#[test]
+fn without_dir_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.binary_from_str(" a "), atom!("middle")),
+            "dir (middle) is not leading, trailing, or both"
+        );
+    });
+}
+
+#[test]
+fn with_leading_trims_start() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str(" a "), atom!("leading")),
+            Ok(process.binary_from_str("a "))
+        );
+    });
+}
+
+#[test]
+fn with_trailing_trims_end() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str(" a "), atom!("trailing")),
+            Ok(process.binary_from_str(" a"))
+        );
+    });
+}
+
+#[test]
+fn with_both_trims_both_ends() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, process.binary_from_str(" a "), atom!("both")),
+            Ok(process.binary_from_str("a"))
+        );
+    });
+}
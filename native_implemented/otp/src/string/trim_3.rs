@@ -0,0 +1,66 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `string` without the grapheme clusters in `characters` at the `leading`, `trailing` or `both`
+/// ends
+#[native_implemented::function(string:trim/3)]
+pub fn result(
+    process: &Process,
+    string: Term,
+    dir: Term,
+    characters: Term,
+) -> exception::Result<Term> {
+    let direction = super::term_try_into_direction(dir)?;
+    let character_vec = term_try_into_characters(process, characters)?;
+
+    super::trim(process, string, direction, &character_vec)
+}
+
+/// A list of grapheme clusters, each either a character or a list of characters
+fn term_try_into_characters(process: &Process, characters: Term) -> exception::Result<Vec<String>> {
+    let mut character_vec = Vec::new();
+
+    match characters.decode()? {
+        TypedTerm::Nil => (),
+        TypedTerm::List(cons) => {
+            for result in cons.into_iter() {
+                let element = result
+                    .map_err(|_| ImproperListError)
+                    .with_context(|| format!("characters ({}) is not a proper list", characters))?;
+                let result_char: std::result::Result<char, _> = element.try_into();
+
+                let character = match result_char {
+                    Ok(c) => c.to_string(),
+                    Err(_) if element.is_list() => {
+                        super::term_try_into_chardata(process, "characters", element)?.string
+                    }
+                    Err(_) => {
+                        return Err(anyhow!(
+                            "characters ({}) element ({}) is not a grapheme cluster",
+                            characters,
+                            element
+                        )
+                        .into())
+                    }
+                };
+
+                character_vec.push(character);
+            }
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!("characters ({}) is not a proper list", characters))
+                .map_err(From::from)
+        }
+    }
+
+    Ok(character_vec)
+}
@@ -0,0 +1,82 @@
+use liblumen_alloc::atom;
+
+use crate::string::trim_3::result;
+use crate::test::with_process;
+
+#[test]
+fn without_proper_list_characters_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("a"),
+                atom!("both"),
+                atom!("characters")
+            ),
+            "characters (characters) is not a proper list"
+        );
+    });
+}
+
+#[test]
+fn without_grapheme_cluster_element_errors_badarg() {
+    with_process(|process| {
+        let characters = process.list_from_slice(&[atom!("a")]);
+
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("a"),
+                atom!("both"),
+                characters
+            ),
+            format!(
+                "characters ({}) element (a) is not a grapheme cluster",
+                characters
+            )
+        );
+    });
+}
+
+#[test]
+fn with_characters_trims_them() {
+    with_process(|process| {
+        let characters = process.list_from_slice(&[process.integer('.' as u32)]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("..a.b.."),
+                atom!("both"),
+                characters
+            ),
+            Ok(process.binary_from_str("a.b"))
+        );
+    });
+}
+
+#[test]
+fn with_grapheme_cluster_character_trims_whole_cluster() {
+    with_process(|process| {
+        let characters = process.list_from_slice(&[process.charlist_from_str("\r\n")]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a\r\n\r\n"),
+                atom!("trailing"),
+                characters
+            ),
+            Ok(process.binary_from_str("a"))
+        );
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a\n"),
+                atom!("trailing"),
+                characters
+            ),
+            Ok(process.binary_from_str("a\n"))
+        );
+    });
+}
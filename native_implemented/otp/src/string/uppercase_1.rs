@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// `string` with every character converted to uppercase
+#[native_implemented::function(string:uppercase/1)]
+pub fn result(process: &Process, string: Term) -> exception::Result<Term> {
+    let chardata = super::term_try_into_chardata(process, "string", string)?;
+
+    Ok(chardata.to_term(process, &chardata.string.to_uppercase()))
+}
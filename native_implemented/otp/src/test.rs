@@ -17,6 +17,8 @@ pub mod strategy;
 
 #[cfg(all(not(target_arch = "wasm32"), test))]
 pub use self::proptest::*;
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod task;
 
 use std::sync::Arc;
 
@@ -48,6 +50,14 @@ pub fn freeze_at_timeout(frozen: Monotonic) {
     timer::timeout();
 }
 
+/// The bytes of `hex`, such as a digest from a test vector
+pub fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
 pub fn module() -> Atom {
     Atom::from_str("test")
 }
@@ -0,0 +1,87 @@
+//! `runtime::task` needs processes with schedulers to deliver to, so it is tested here.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::task;
+
+use super::{has_message, with_process_arc};
+
+#[test]
+fn spawn_with_ready_future_sends_output_to_destination() {
+    with_process_arc(|arc_process| {
+        task::spawn(arc_process.pid(), async { 1 }, |output, builder| {
+            builder.integer(output)
+        });
+
+        assert!(eventually(|| has_message(
+            &arc_process,
+            arc_process.integer(1)
+        )));
+    });
+}
+
+#[test]
+fn spawn_with_pending_future_sends_output_to_destination_once_woken() {
+    with_process_arc(|arc_process| {
+        let future = task::blocking(|| {
+            thread::sleep(Duration::from_millis(10));
+
+            "woken"
+        });
+
+        task::spawn(arc_process.pid(), future, |output, _| {
+            Atom::str_to_term(output)
+        });
+
+        assert!(eventually(|| has_message(
+            &arc_process,
+            Atom::str_to_term("woken")
+        )));
+    });
+}
+
+#[test]
+fn spawn_with_dead_destination_drops_output() {
+    let made_message = Arc::new(AtomicBool::new(false));
+    let dropped = Arc::new(AtomicBool::new(false));
+    let output = Output(dropped.clone());
+    let message_made_message = made_message.clone();
+
+    task::spawn(Pid::next(), async move { output }, move |_, _: &Process| {
+        message_made_message.store(true, Ordering::SeqCst);
+
+        Atom::str_to_term("unsent")
+    });
+
+    assert!(eventually(|| dropped.load(Ordering::SeqCst)));
+    assert!(!made_message.load(Ordering::SeqCst));
+}
+
+/// Sets its flag when dropped
+struct Output(Arc<AtomicBool>);
+
+impl Drop for Output {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+fn eventually<F: Fn() -> bool>(condition: F) -> bool {
+    let deadline = Instant::now() + Duration::from_secs(5);
+
+    while Instant::now() < deadline {
+        if condition() {
+            return true;
+        }
+
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    condition()
+}
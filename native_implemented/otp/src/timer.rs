@@ -1,11 +1,37 @@
-use liblumen_alloc::erts::term::prelude::Atom;
+//! Mirrors [timer](http://erlang.org/doc/man/timer.html) module
+//!
+//! Unlike OTP, there is no timer server: timers are started on the scheduler's timer wheel, like
+//! those of `erlang:send_after/3`.
 
+pub mod apply_after_4;
+pub mod send_after_2;
+pub mod send_after_3;
+pub mod send_interval_2;
+pub mod send_interval_3;
+pub mod sleep_1;
+pub mod tc_1;
+pub mod tc_2;
 pub mod tc_3;
 
 pub mod cancel;
 pub mod read;
 pub mod start;
 
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::AllocResult;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::time::Milliseconds;
+
+use crate::runtime;
+use crate::runtime::registry::pid_to_self_or_process;
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+use crate::runtime::time::monotonic;
+use crate::runtime::timer::{Destination, Format, SourceEvent};
+
 fn module() -> Atom {
     Atom::try_from_str("timer").unwrap()
 }
@@ -13,3 +39,60 @@ fn module() -> Atom {
 fn module_id() -> usize {
     module().id()
 }
+
+/// Sends `message` to `destination` after `time` milliseconds, and then every `time` milliseconds
+/// if `interval`.
+///
+/// Returns `{ok, timer_reference}`, or `{error, badarg}` for invalid arguments, as the `timer`
+/// functions do not raise.
+fn send(
+    arc_process: Arc<Process>,
+    time: Term,
+    destination: Term,
+    message: Term,
+    interval: bool,
+) -> AllocResult<Term> {
+    let milliseconds: Milliseconds = match time.try_into() {
+        Ok(milliseconds) => milliseconds,
+        Err(_) => return Ok(error_badarg(&arc_process)),
+    };
+
+    let destination = match destination.decode() {
+        Ok(TypedTerm::Atom(destination_atom)) => Destination::Name(destination_atom),
+        // Like `erlang:send_after/3`, a reference is still returned when the process does not
+        // exist.
+        Ok(TypedTerm::Pid(destination_pid)) => {
+            match pid_to_self_or_process(destination_pid, &arc_process) {
+                Some(pid_arc_process) => Destination::Process(Arc::downgrade(&pid_arc_process)),
+                None => return Ok(ok(&arc_process, arc_process.next_reference())),
+            }
+        }
+        _ => return Ok(error_badarg(&arc_process)),
+    };
+
+    let event = if interval {
+        SourceEvent::Interval {
+            destination,
+            interval: milliseconds,
+            term: message,
+        }
+    } else {
+        SourceEvent::Message {
+            destination,
+            format: Format::Message,
+            term: message,
+        }
+    };
+    let timer_reference =
+        runtime::timer::start(monotonic::time() + milliseconds, event, arc_process.clone())?;
+
+    Ok(ok(&arc_process, timer_reference))
+}
+
+fn ok(process: &Process, value: Term) -> Term {
+    process.tuple_from_slice(&[atom!("ok"), value])
+}
+
+fn error_badarg(process: &Process) -> Term {
+    process.tuple_from_slice(&[atom!("error"), atom!("badarg")])
+}
@@ -0,0 +1,47 @@
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::time::Milliseconds;
+
+use crate::runtime;
+use crate::runtime::time::monotonic;
+use crate::runtime::timer::SourceEvent;
+
+/// Spawns a process calling `apply(module, function, arguments)` after `time` milliseconds.
+///
+/// Returns `{ok, timer_reference}`, or `{error, badarg}` for invalid arguments.
+#[native_implemented::function(timer:apply_after/4)]
+pub fn result(
+    arc_process: Arc<Process>,
+    time: Term,
+    module: Term,
+    function: Term,
+    arguments: Term,
+) -> exception::Result<Term> {
+    let milliseconds: Result<Milliseconds, _> = time.try_into();
+    let module_atom: Result<Atom, _> = module.try_into();
+    let function_atom: Result<Atom, _> = function.try_into();
+    let is_proper_list = arguments
+        .decode()
+        .map_or(false, |typed_term| typed_term.is_proper_list());
+
+    match (milliseconds, module_atom, function_atom) {
+        (Ok(milliseconds), Ok(module_atom), Ok(function_atom)) if is_proper_list => {
+            let timer_reference = runtime::timer::start(
+                monotonic::time() + milliseconds,
+                SourceEvent::Spawn {
+                    module: module_atom,
+                    function: function_atom,
+                    arguments,
+                },
+                arc_process.clone(),
+            )?;
+
+            Ok(super::ok(&arc_process, timer_reference))
+        }
+        _ => Ok(super::error_badarg(&arc_process)),
+    }
+}
@@ -0,0 +1,13 @@
+use std::sync::Arc;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Sends `message` to the calling process after `time` milliseconds.
+#[native_implemented::function(timer:send_after/2)]
+pub fn result(arc_process: Arc<Process>, time: Term, message: Term) -> exception::Result<Term> {
+    let destination = arc_process.pid_term();
+
+    super::send(arc_process, time, destination, message, false).map_err(From::from)
+}
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Sends `message` to `destination`, a pid or registered name, after `time` milliseconds.
+#[native_implemented::function(timer:send_after/3)]
+pub fn result(
+    arc_process: Arc<Process>,
+    time: Term,
+    destination: Term,
+    message: Term,
+) -> exception::Result<Term> {
+    super::send(arc_process, time, destination, message, false).map_err(From::from)
+}
@@ -0,0 +1,14 @@
+use std::sync::Arc;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Sends `message` to the calling process every `time` milliseconds, until the timer is canceled
+/// with `erlang:cancel_timer/1` or the calling process exits.
+#[native_implemented::function(timer:send_interval/2)]
+pub fn result(arc_process: Arc<Process>, time: Term, message: Term) -> exception::Result<Term> {
+    let destination = arc_process.pid_term();
+
+    super::send(arc_process, time, destination, message, true).map_err(From::from)
+}
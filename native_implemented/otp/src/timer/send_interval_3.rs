@@ -0,0 +1,18 @@
+use std::sync::Arc;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Sends `message` to `destination` every `time` milliseconds, until the timer is canceled with
+/// `erlang:cancel_timer/1` or `destination` exits.  When `destination` is a registered name, the
+/// timer stops when the calling process exits instead.
+#[native_implemented::function(timer:send_interval/3)]
+pub fn result(
+    arc_process: Arc<Process>,
+    time: Term,
+    destination: Term,
+    message: Term,
+) -> exception::Result<Term> {
+    super::send(arc_process, time, destination, message, true).map_err(From::from)
+}
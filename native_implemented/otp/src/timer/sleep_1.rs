@@ -0,0 +1,45 @@
+//! ```elixir
+//! def sleep(time) do
+//!   receive do
+//!   after
+//!     time -> :ok
+//!   end
+//! end
+//! ```
+
+mod label_1;
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::time::Milliseconds;
+
+use crate::runtime;
+use crate::runtime::context::*;
+use crate::runtime::time::monotonic;
+use crate::runtime::timer::SourceEvent;
+
+#[native_implemented::function(timer:sleep/1)]
+fn result(arc_process: Arc<Process>, time: Term) -> exception::Result<Term> {
+    let deadline = if time == atom!("infinity") {
+        time
+    } else {
+        let milliseconds: Milliseconds = time
+            .try_into()
+            .with_context(|| term_is_not_non_negative_integer("time", time))?;
+        let monotonic = monotonic::time() + milliseconds;
+        runtime::timer::start(monotonic, SourceEvent::StopWaiting, arc_process.clone())?;
+
+        arc_process.integer(monotonic.0)
+    };
+
+    arc_process.queue_frame_with_arguments(label_1::frame().with_arguments(false, &[deadline]));
+
+    Ok(Term::NONE)
+}
@@ -0,0 +1,43 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (deadline)
+//! # returned from call: N/A
+//! # full stack: (deadline)
+//! # returns: :ok
+//! receive do
+//! after
+//!   deadline -> :ok
+//! end
+//! ```
+//!
+//! Messages wake the process before the deadline, so it waits again until the timer started by
+//! `sleep/1` stops it waiting.
+
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::time::Monotonic;
+
+use crate::runtime::time::monotonic;
+
+#[native_implemented::label]
+fn result(process: &Process, deadline: Term) -> Term {
+    let timed_out = if deadline == atom!("infinity") {
+        false
+    } else {
+        let deadline_monotonic: Monotonic = deadline.try_into().unwrap();
+
+        deadline_monotonic <= monotonic::time()
+    };
+
+    if timed_out {
+        atom!("ok")
+    } else {
+        process.wait();
+        process.queue_frame_with_arguments(frame().with_arguments(false, &[deadline]));
+
+        Term::NONE
+    }
+}
@@ -0,0 +1,27 @@
+//! ```elixir
+//! def tc(function) do
+//!   before = :erlang.monotonic_time()
+//!   value = function.()
+//!   after = :erlang.monotonic_time()
+//!   duration = after - before
+//!   time = :erlang.convert_time_unit(duration, :native, :microsecond)
+//!   {time, value}
+//! end
+//! ```
+
+mod label_1;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::monotonic_time_0;
+
+// Private
+
+#[native_implemented::function(timer:tc/1)]
+fn result(process: &Process, function: Term) -> Term {
+    process.queue_frame_with_arguments(monotonic_time_0::frame().with_arguments(false, &[]));
+    process.queue_frame_with_arguments(label_1::frame().with_arguments(true, &[function]));
+
+    Term::NONE
+}
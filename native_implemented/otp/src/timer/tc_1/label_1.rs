@@ -0,0 +1,31 @@
+//! ```elixir
+//! # label 1
+//! # pushed to stack: (function)
+//! # returned from call: before
+//! # full stack: (before, function)
+//! # returns: value
+//! value = function.()
+//! after = :erlang.monotonic_time()
+//! duration = after - before
+//! time = :erlang.convert_time_unit(duration, :native, :microsecond)
+//! {time, value}
+//! ```
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::apply_2;
+use crate::timer::tc_3::label_2;
+
+// Private
+
+#[native_implemented::label]
+fn result(process: &Process, before: Term, function: Term) -> Term {
+    assert!(before.is_integer());
+
+    process
+        .queue_frame_with_arguments(apply_2::frame().with_arguments(false, &[function, Term::NIL]));
+    process.queue_frame_with_arguments(label_2::frame().with_arguments(true, &[before]));
+
+    Term::NONE
+}
@@ -0,0 +1,28 @@
+//! ```elixir
+//! def tc(function, arguments) do
+//!   before = :erlang.monotonic_time()
+//!   value = apply(function, arguments)
+//!   after = :erlang.monotonic_time()
+//!   duration = after - before
+//!   time = :erlang.convert_time_unit(duration, :native, :microsecond)
+//!   {time, value}
+//! end
+//! ```
+
+mod label_1;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::monotonic_time_0;
+
+// Private
+
+#[native_implemented::function(timer:tc/2)]
+fn result(process: &Process, function: Term, arguments: Term) -> Term {
+    process.queue_frame_with_arguments(monotonic_time_0::frame().with_arguments(false, &[]));
+    process
+        .queue_frame_with_arguments(label_1::frame().with_arguments(true, &[function, arguments]));
+
+    Term::NONE
+}
@@ -10,7 +10,7 @@
 //! ```
 
 mod label_1;
-mod label_2;
+pub(in crate::timer) mod label_2;
 mod label_3;
 mod label_4;
 mod label_5;
@@ -0,0 +1,18 @@
+//! Mirrors [unicode](http://erlang.org/doc/man/unicode.html) module
+
+pub mod characters;
+pub mod characters_to_binary_1;
+pub mod characters_to_binary_2;
+pub mod characters_to_binary_3;
+pub mod characters_to_list_1;
+pub mod characters_to_list_2;
+
+use liblumen_alloc::erts::term::prelude::Atom;
+
+fn module() -> Atom {
+    Atom::from_str("unicode")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,389 @@
+//! Converts chardata between `latin1`, UTF-8, UTF-16 and UTF-32.
+//!
+//! Conversion stops at the first character that is invalid in the input encoding or can't be
+//! represented in the output encoding, returning `{error, Converted, Rest}`, or at data that ends
+//! in the middle of a character, returning `{incomplete, Converted, Rest}`.  Bytes of a character
+//! split across binaries in a list are joined, like the BEAM.
+
+use std::convert::TryInto;
+use std::str;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+const MAX_LATIN1: u32 = 0xFF;
+const MAX_UNICODE: u32 = 0x10_FFFF;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Latin1,
+    Utf8,
+    Utf16(Endianness),
+    Utf32(Endianness),
+}
+
+impl Encoding {
+    /// `latin1`, `unicode`, `utf8`, `utf16`, `utf32`, or `{utf16 | utf32, big | little}`, where
+    /// UTF-16 and UTF-32 are big-endian by default.
+    pub fn try_from_term(term: Term) -> exception::Result<Self> {
+        let encoding = match term.decode()? {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "latin1" => Some(Encoding::Latin1),
+                "unicode" | "utf8" => Some(Encoding::Utf8),
+                "utf16" => Some(Encoding::Utf16(Endianness::Big)),
+                "utf32" => Some(Encoding::Utf32(Endianness::Big)),
+                _ => None,
+            },
+            TypedTerm::Tuple(tuple) if tuple.len() == 2 => {
+                let name: Option<Atom> = tuple[0].try_into().ok();
+                let endianness: Option<Atom> = tuple[1].try_into().ok();
+                let endianness = match endianness.as_ref().map(Atom::name) {
+                    Some("big") => Some(Endianness::Big),
+                    Some("little") => Some(Endianness::Little),
+                    _ => None,
+                };
+
+                match (name.as_ref().map(Atom::name), endianness) {
+                    (Some("utf16"), Some(endianness)) => Some(Encoding::Utf16(endianness)),
+                    (Some("utf32"), Some(endianness)) => Some(Encoding::Utf32(endianness)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        encoding
+            .with_context(|| {
+                format!(
+                    "encoding ({}) is not latin1, unicode, utf8, utf16, utf32, or {{utf16 | utf32, big | little}}",
+                    term
+                )
+            })
+            .map_err(From::from)
+    }
+}
+
+pub fn characters_to_binary(
+    process: &Process,
+    data: Term,
+    in_encoding: Encoding,
+    out_encoding: Encoding,
+) -> exception::Result<Term> {
+    let Conversion { chars, stop } = convert(process, data, in_encoding, out_encoding)?;
+    let binary = process.binary_from_bytes(&encode(&chars, out_encoding));
+
+    Ok(result(process, binary, stop))
+}
+
+pub fn characters_to_list(
+    process: &Process,
+    data: Term,
+    in_encoding: Encoding,
+) -> exception::Result<Term> {
+    let Conversion { chars, stop } = convert(process, data, in_encoding, Encoding::Utf8)?;
+    let string: String = chars.into_iter().collect();
+    let list = process.list_from_chars(string.chars());
+
+    Ok(result(process, list, stop))
+}
+
+/// Converts `value` to UTF-8, where stopping at a character that can't be converted is a badarg.
+pub fn to_utf8_bytes(process: &Process, name: &str, value: Term) -> exception::Result<Vec<u8>> {
+    let Conversion { chars, stop } = convert(process, value, Encoding::Utf8, Encoding::Utf8)?;
+
+    match stop {
+        None => Ok(encode(&chars, Encoding::Utf8)),
+        Some(_) => Err(anyhow!("{} ({}) is not valid unicode chardata", name, value).into()),
+    }
+}
+
+// Private
+
+struct Conversion {
+    chars: Vec<char>,
+    stop: Option<Stop>,
+}
+
+enum Stop {
+    Error(Term),
+    Incomplete(Term),
+}
+
+enum Decoded {
+    Complete,
+    /// The bytes from the index are the start of a character
+    Incomplete(usize),
+    /// The character at the index is invalid or too large for the output encoding
+    Invalid(usize),
+}
+
+fn convert(
+    process: &Process,
+    data: Term,
+    in_encoding: Encoding,
+    out_encoding: Encoding,
+) -> exception::Result<Conversion> {
+    if !(data.is_binary() || data.is_list()) {
+        return Err(TypeError)
+            .with_context(|| format!("data ({}) is not a binary or list", data))
+            .map_err(From::from);
+    }
+
+    let max = if in_encoding == Encoding::Latin1 || out_encoding == Encoding::Latin1 {
+        MAX_LATIN1
+    } else {
+        MAX_UNICODE
+    };
+    let mut chars = Vec::new();
+    // Bytes at the end of a binary that are only the start of a character
+    let mut pending: Vec<u8> = Vec::new();
+    let mut stack = vec![data];
+
+    while let Some(top) = stack.pop() {
+        match top.decode()? {
+            TypedTerm::SmallInteger(_) | TypedTerm::BigInteger(_) => {
+                if !pending.is_empty() {
+                    stack.push(top);
+                    let offending = process.binary_from_bytes(&pending);
+
+                    return Ok(stop_with_error(process, data, chars, offending, stack));
+                }
+
+                let c: Option<char> = top.try_into().ok();
+
+                match c.filter(|c| (*c as u32) <= max) {
+                    Some(c) => chars.push(c),
+                    None => return Ok(stop_with_error(process, data, chars, top, stack)),
+                }
+            }
+            TypedTerm::Nil => (),
+            TypedTerm::List(cons) => {
+                if !(cons.tail.is_list() || cons.tail.is_binary()) {
+                    return Err(ImproperListError)
+                        .with_context(|| format!("data ({}) is improper", data))
+                        .map_err(From::from);
+                }
+
+                stack.push(cons.tail);
+                stack.push(cons.head);
+            }
+            _ if top.is_binary() => {
+                let bytes = process.bytes_from_binary(top).with_context(|| {
+                    format!("data ({}) element ({}) is not a binary", data, top)
+                })?;
+                pending.extend_from_slice(bytes);
+
+                match decode(&pending, in_encoding, max, &mut chars) {
+                    Decoded::Complete => pending.clear(),
+                    Decoded::Incomplete(index) => {
+                        pending.drain(..index);
+                    }
+                    Decoded::Invalid(index) => {
+                        let offending = process.binary_from_bytes(&pending[index..]);
+
+                        return Ok(stop_with_error(process, data, chars, offending, stack));
+                    }
+                }
+            }
+            _ => {
+                return Err(TypeError)
+                    .with_context(|| {
+                        format!(
+                            "data ({}) element ({}) is not a character, binary, or nested list",
+                            data, top
+                        )
+                    })
+                    .map_err(From::from)
+            }
+        }
+    }
+
+    let stop = if pending.is_empty() {
+        None
+    } else {
+        Some(Stop::Incomplete(process.binary_from_bytes(&pending)))
+    };
+
+    Ok(Conversion { chars, stop })
+}
+
+/// Decodes complete characters from `bytes` onto `chars` until one is invalid or greater than
+/// `max`.
+fn decode(bytes: &[u8], encoding: Encoding, max: u32, chars: &mut Vec<char>) -> Decoded {
+    match encoding {
+        Encoding::Latin1 => {
+            chars.extend(bytes.iter().map(|byte| *byte as char));
+
+            Decoded::Complete
+        }
+        Encoding::Utf8 => {
+            let (valid, error) = match str::from_utf8(bytes) {
+                Ok(valid) => (valid, None),
+                Err(error) => (
+                    str::from_utf8(&bytes[..error.valid_up_to()]).unwrap(),
+                    Some(error),
+                ),
+            };
+
+            for (index, c) in valid.char_indices() {
+                if (c as u32) > max {
+                    return Decoded::Invalid(index);
+                }
+
+                chars.push(c);
+            }
+
+            match error {
+                None => Decoded::Complete,
+                Some(error) => match error.error_len() {
+                    None => Decoded::Incomplete(error.valid_up_to()),
+                    Some(_) => Decoded::Invalid(error.valid_up_to()),
+                },
+            }
+        }
+        Encoding::Utf16(endianness) => {
+            let mut index = 0;
+
+            while index < bytes.len() {
+                if bytes.len() - index < 2 {
+                    return Decoded::Incomplete(index);
+                }
+
+                let unit = code_unit(&bytes[index..index + 2], endianness);
+                let (code_point, len) = match unit {
+                    0xD800..=0xDBFF => {
+                        if bytes.len() - index < 4 {
+                            return Decoded::Incomplete(index);
+                        }
+
+                        let low = code_unit(&bytes[index + 2..index + 4], endianness);
+
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return Decoded::Invalid(index);
+                        }
+
+                        (0x1_0000 + (((unit - 0xD800) << 10) | (low - 0xDC00)), 4)
+                    }
+                    0xDC00..=0xDFFF => return Decoded::Invalid(index),
+                    _ => (unit, 2),
+                };
+
+                match std::char::from_u32(code_point).filter(|_| code_point <= max) {
+                    Some(c) => chars.push(c),
+                    None => return Decoded::Invalid(index),
+                }
+
+                index += len;
+            }
+
+            Decoded::Complete
+        }
+        Encoding::Utf32(endianness) => {
+            for (chunk_index, chunk) in bytes.chunks(4).enumerate() {
+                let index = chunk_index * 4;
+
+                if chunk.len() < 4 {
+                    return Decoded::Incomplete(index);
+                }
+
+                let code_point = code_unit(chunk, endianness);
+
+                match std::char::from_u32(code_point).filter(|_| code_point <= max) {
+                    Some(c) => chars.push(c),
+                    None => return Decoded::Invalid(index),
+                }
+            }
+
+            Decoded::Complete
+        }
+    }
+}
+
+fn code_unit(bytes: &[u8], endianness: Endianness) -> u32 {
+    let fold = |acc: u32, byte: &u8| (acc << 8) | (*byte as u32);
+
+    match endianness {
+        Endianness::Big => bytes.iter().fold(0, fold),
+        Endianness::Little => bytes.iter().rev().fold(0, fold),
+    }
+}
+
+/// Encodes `chars`, which were limited to `latin1` during conversion if that is the encoding.
+fn encode(chars: &[char], encoding: Encoding) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(chars.len());
+
+    for c in chars {
+        match encoding {
+            Encoding::Latin1 => bytes.push(*c as u8),
+            Encoding::Utf8 => {
+                let mut buffer = [0; 4];
+
+                bytes.extend_from_slice(c.encode_utf8(&mut buffer).as_bytes());
+            }
+            Encoding::Utf16(endianness) => {
+                let mut buffer = [0; 2];
+
+                for unit in c.encode_utf16(&mut buffer) {
+                    push_code_unit(&mut bytes, *unit as u32, 2, endianness);
+                }
+            }
+            Encoding::Utf32(endianness) => push_code_unit(&mut bytes, *c as u32, 4, endianness),
+        }
+    }
+
+    bytes
+}
+
+fn push_code_unit(bytes: &mut Vec<u8>, unit: u32, size: usize, endianness: Endianness) {
+    let big_endian = unit.to_be_bytes();
+    let unit_bytes = &big_endian[(4 - size)..];
+
+    match endianness {
+        Endianness::Big => bytes.extend_from_slice(unit_bytes),
+        Endianness::Little => bytes.extend(unit_bytes.iter().rev()),
+    }
+}
+
+fn result(process: &Process, converted: Term, stop: Option<Stop>) -> Term {
+    match stop {
+        None => converted,
+        Some(Stop::Error(rest)) => process.tuple_from_slice(&[atom!("error"), converted, rest]),
+        Some(Stop::Incomplete(rest)) => {
+            process.tuple_from_slice(&[atom!("incomplete"), converted, rest])
+        }
+    }
+}
+
+/// `Rest` is the `offending` binary or character followed by the data that was not reached yet,
+/// or only the `offending` binary when `data` is a binary.
+fn stop_with_error(
+    process: &Process,
+    data: Term,
+    chars: Vec<char>,
+    offending: Term,
+    stack: Vec<Term>,
+) -> Conversion {
+    let rest = if data.is_binary() {
+        offending
+    } else {
+        let mut rest_vec = vec![offending];
+        rest_vec.extend(stack.into_iter().rev().filter(|term| !term.is_nil()));
+
+        process.list_from_slice(&rest_vec)
+    };
+
+    Conversion {
+        chars,
+        stop: Some(Stop::Error(rest)),
+    }
+}
@@ -0,0 +1,13 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::unicode::characters::{self, Encoding};
+
+#[native_implemented::function(unicode:characters_to_binary/1)]
+pub fn result(process: &Process, data: Term) -> exception::Result<Term> {
+    characters::characters_to_binary(process, data, Encoding::Utf8, Encoding::Utf8)
+}
@@ -0,0 +1,121 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_1::result;
+
+#[test]
+fn without_binary_or_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("data")),
+            "data (data) is not a binary or list"
+        );
+    });
+}
+
+#[test]
+fn with_element_that_is_not_character_binary_or_list_errors_badarg() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[process.integer('a' as isize), atom!("b")]);
+
+        assert_badarg!(
+            result(process, data),
+            "element (b) is not a character, binary, or nested list"
+        );
+    });
+}
+
+#[test]
+fn with_improper_list_errors_badarg() {
+    with_process(|process| {
+        let data = process.cons(process.integer('a' as isize), process.integer('b' as isize));
+
+        assert_badarg!(result(process, data), "is improper");
+    });
+}
+
+#[test]
+fn with_chardata_returns_utf8_binary() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.charlist_from_str("aé"),
+            process.binary_from_str("b"),
+            process.cons(process.integer('😀' as isize), process.binary_from_str("c")),
+        ]);
+
+        assert_eq!(result(process, data), Ok(process.binary_from_str("aéb😀c")));
+    });
+}
+
+#[test]
+fn with_character_split_across_binaries_joins_bytes() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_bytes(&[0xC3]),
+            process.binary_from_bytes(&[0xA9]),
+        ]);
+
+        assert_eq!(result(process, data), Ok(process.binary_from_str("é")));
+    });
+}
+
+#[test]
+fn with_invalid_binary_returns_error_with_converted_and_rest() {
+    with_process(|process| {
+        let data = process.binary_from_bytes(&[b'a', 0xFF, b'b']);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.binary_from_str("a"),
+                process.binary_from_bytes(&[0xFF, b'b'])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_invalid_character_returns_error_with_rest_of_list() {
+    with_process(|process| {
+        let surrogate = process.integer(0xD800);
+        let tail = process.binary_from_str("b");
+        let data = process.list_from_slice(&[process.integer('a' as isize), surrogate, tail]);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.binary_from_str("a"),
+                process.list_from_slice(&[surrogate, process.list_from_slice(&[tail])])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_binary_ending_in_middle_of_character_returns_incomplete() {
+    with_process(|process| {
+        let data = process.binary_from_bytes(&[b'a', 0xE2, 0x82]);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("incomplete"),
+                process.binary_from_str("a"),
+                process.binary_from_bytes(&[0xE2, 0x82])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_empty_list_returns_empty_binary() {
+    with_process(|process| {
+        assert_eq!(
+            result(process, Term::NIL),
+            Ok(process.binary_from_bytes(&[]))
+        );
+    });
+}
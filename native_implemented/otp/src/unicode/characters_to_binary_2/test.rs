@@ -0,0 +1,93 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_2::result;
+
+#[test]
+fn without_encoding_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.binary_from_str("a"), atom!("utf7")),
+            "encoding (utf7) is not latin1, unicode, utf8, utf16, utf32"
+        );
+    });
+}
+
+#[test]
+fn with_latin1_converts_bytes_to_utf8() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[b'a', 0xE9]),
+                atom!("latin1")
+            ),
+            Ok(process.binary_from_str("aé"))
+        );
+    });
+}
+
+#[test]
+fn with_latin1_and_character_greater_than_255_returns_error() {
+    with_process(|process| {
+        let character = process.integer(0x100);
+        let data = process.list_from_slice(&[process.integer('a' as isize), character]);
+
+        assert_eq!(
+            result(process, data, atom!("latin1")),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.binary_from_str("a"),
+                process.list_from_slice(&[character])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_utf16_converts_surrogate_pairs() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[0x00, b'a', 0xD8, 0x3D, 0xDE, 0x00]),
+                atom!("utf16")
+            ),
+            Ok(process.binary_from_str("a😀"))
+        );
+    });
+}
+
+#[test]
+fn with_little_endian_utf32_converts() {
+    with_process(|process| {
+        let encoding = process.tuple_from_slice(&[atom!("utf32"), atom!("little")]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[0xE9, 0x00, 0x00, 0x00]),
+                encoding
+            ),
+            Ok(process.binary_from_str("é"))
+        );
+    });
+}
+
+#[test]
+fn with_utf16_and_unpaired_surrogate_returns_error() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[0x00, b'a', 0xDC, 0x00]),
+                atom!("utf16")
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.binary_from_str("a"),
+                process.binary_from_bytes(&[0xDC, 0x00])
+            ]))
+        );
+    });
+}
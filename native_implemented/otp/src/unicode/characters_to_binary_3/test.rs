@@ -0,0 +1,89 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_binary_3::result;
+
+#[test]
+fn without_out_encoding_errors_badarg() {
+    with_process(|process| {
+        let out_encoding = process.tuple_from_slice(&[atom!("utf8"), atom!("little")]);
+
+        assert_badarg!(
+            result(
+                process,
+                process.binary_from_str("a"),
+                atom!("unicode"),
+                out_encoding
+            ),
+            "is not latin1, unicode, utf8, utf16, utf32"
+        );
+    });
+}
+
+#[test]
+fn with_utf16_out_encoding_encodes_surrogate_pairs() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("a😀"),
+                atom!("unicode"),
+                atom!("utf16")
+            ),
+            Ok(process.binary_from_bytes(&[0x00, b'a', 0xD8, 0x3D, 0xDE, 0x00]))
+        );
+    });
+}
+
+#[test]
+fn with_little_endian_utf16_out_encoding_encodes() {
+    with_process(|process| {
+        let out_encoding = process.tuple_from_slice(&[atom!("utf16"), atom!("little")]);
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("é"),
+                atom!("unicode"),
+                out_encoding
+            ),
+            Ok(process.binary_from_bytes(&[0xE9, 0x00]))
+        );
+    });
+}
+
+#[test]
+fn with_latin1_out_encoding_and_character_greater_than_255_returns_error() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_str("aĀb"),
+                atom!("unicode"),
+                atom!("latin1")
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.binary_from_str("a"),
+                process.binary_from_str("Āb")
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_utf32_in_and_out_encoding_returns_same_bytes() {
+    with_process(|process| {
+        let bytes = [0x00, 0x01, 0xF6, 0x00];
+
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&bytes),
+                atom!("utf32"),
+                atom!("utf32")
+            ),
+            Ok(process.binary_from_bytes(&bytes))
+        );
+    });
+}
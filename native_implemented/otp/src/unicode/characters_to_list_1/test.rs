@@ -0,0 +1,61 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_list_1::result;
+
+#[test]
+fn without_binary_or_list_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.integer('a' as isize)),
+            "is not a binary or list"
+        );
+    });
+}
+
+#[test]
+fn with_chardata_returns_code_points() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_str("aé"),
+            process.charlist_from_str("😀"),
+        ]);
+
+        assert_eq!(result(process, data), Ok(process.charlist_from_str("aé😀")));
+    });
+}
+
+#[test]
+fn with_invalid_binary_returns_error_with_converted_list() {
+    with_process(|process| {
+        let data = process.binary_from_bytes(&[b'a', 0x80]);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                process.charlist_from_str("a"),
+                process.binary_from_bytes(&[0x80])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_incomplete_binary_in_list_returns_incomplete() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_str("a"),
+            process.binary_from_bytes(&[0xF0, 0x9F]),
+        ]);
+
+        assert_eq!(
+            result(process, data),
+            Ok(process.tuple_from_slice(&[
+                atom!("incomplete"),
+                process.charlist_from_str("a"),
+                process.binary_from_bytes(&[0xF0, 0x9F])
+            ]))
+        );
+    });
+}
@@ -0,0 +1,15 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::unicode::characters::{self, Encoding};
+
+#[native_implemented::function(unicode:characters_to_list/2)]
+pub fn result(process: &Process, data: Term, in_encoding: Term) -> exception::Result<Term> {
+    let in_encoding = Encoding::try_from_term(in_encoding)?;
+
+    characters::characters_to_list(process, data, in_encoding)
+}
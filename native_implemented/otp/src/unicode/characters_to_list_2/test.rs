@@ -0,0 +1,63 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::unicode::characters_to_list_2::result;
+
+#[test]
+fn without_encoding_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, process.binary_from_str("a"), process.integer(8)),
+            "encoding (8) is not latin1"
+        );
+    });
+}
+
+#[test]
+fn with_latin1_returns_bytes_as_code_points() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[b'a', 0xFF]),
+                atom!("latin1")
+            ),
+            Ok(process.charlist_from_str("aÿ"))
+        );
+    });
+}
+
+#[test]
+fn with_utf32_and_odd_bytes_returns_incomplete() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&[0x00, 0x00, 0x00, b'a', 0x00, 0x00]),
+                atom!("utf32")
+            ),
+            Ok(process.tuple_from_slice(&[
+                atom!("incomplete"),
+                process.charlist_from_str("a"),
+                process.binary_from_bytes(&[0x00, 0x00])
+            ]))
+        );
+    });
+}
+
+#[test]
+fn with_utf32_and_code_point_greater_than_max_returns_error() {
+    with_process(|process| {
+        let rest = [0x00, 0x11, 0x00, 0x00];
+
+        assert_eq!(
+            result(process, process.binary_from_bytes(&rest), atom!("utf32")),
+            Ok(process.tuple_from_slice(&[
+                atom!("error"),
+                Term::NIL,
+                process.binary_from_bytes(&rest)
+            ]))
+        );
+    });
+}
@@ -0,0 +1,128 @@
+//! Mirrors [zlib](http://erlang.org/doc/man/zlib.html) module
+//!
+//! Only the one-shot functions are supported, not streams opened with `zlib:open/0`.  Like
+//! `term_to_binary(Term, [compressed])`, they use `flate2`'s pure-Rust backend, so they are also
+//! available on wasm32.
+
+pub mod compress_1;
+pub mod gunzip_1;
+pub mod gzip_1;
+pub mod uncompress_1;
+pub mod unzip_1;
+pub mod zip_1;
+
+use std::io::{Read, Write};
+
+use anyhow::*;
+use flate2::read::GzDecoder;
+use flate2::write::{DeflateEncoder, GzEncoder, ZlibEncoder};
+use flate2::{Compression, Decompress, FlushDecompress, Status};
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception::{self, error};
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::erlang::iolist_or_binary;
+
+fn module() -> Atom {
+    Atom::from_str("zlib")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// The framing around the deflate stream
+#[derive(Clone, Copy)]
+enum Format {
+    /// No header or checksum, as in `zip/1`
+    Raw,
+    /// A zlib header and Adler-32 checksum, as in `compress/1`
+    Zlib,
+    /// A gzip header and CRC-32 checksum, as in `gzip/1`
+    Gzip,
+}
+
+/// Deflates `data`, which is iodata, at the default level
+fn deflate(process: &Process, format: Format, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_byte_vec("data", data)?;
+    let compression = Compression::default();
+
+    let result = match format {
+        Format::Raw => {
+            let mut encoder = DeflateEncoder::new(Vec::new(), compression);
+            encoder.write_all(&bytes).and_then(|_| encoder.finish())
+        }
+        Format::Zlib => {
+            let mut encoder = ZlibEncoder::new(Vec::new(), compression);
+            encoder.write_all(&bytes).and_then(|_| encoder.finish())
+        }
+        Format::Gzip => {
+            let mut encoder = GzEncoder::new(Vec::new(), compression);
+            encoder.write_all(&bytes).and_then(|_| encoder.finish())
+        }
+    };
+    // Writing to a `Vec` can't fail
+    let compressed = result.unwrap();
+
+    Ok(process.binary_from_bytes(&compressed))
+}
+
+/// Inflates `data`, which is iodata.  Like the BEAM, errors `data_error` if `data` is not
+/// a complete stream in `format`.
+fn inflate(process: &Process, format: Format, data: Term) -> exception::Result<Term> {
+    let bytes = iolist_or_binary::to_byte_vec("data", data)?;
+
+    let result = match format {
+        Format::Raw => decompress(&bytes, false),
+        Format::Zlib => decompress(&bytes, true),
+        // `GzDecoder` checks the CRC-32 and length in the trailer, so it errors if it is truncated
+        Format::Gzip => {
+            let mut uncompressed = Vec::new();
+
+            GzDecoder::new(bytes.as_slice())
+                .read_to_end(&mut uncompressed)
+                .map(|_| uncompressed)
+                .map_err(From::from)
+        }
+    };
+
+    match result {
+        Ok(uncompressed) => Ok(process.binary_from_bytes(&uncompressed)),
+        Err(err) => Err(error(
+            atom!("data_error"),
+            None,
+            Trace::capture(),
+            Some(anyhow!("data ({}) could not be inflated: {}", data, err).into()),
+        )
+        .into()),
+    }
+}
+
+/// Inflates all of `bytes`, which must end the stream.  `flate2::read::{DeflateDecoder,
+/// ZlibDecoder}` return what they inflated so far when the input runs out, so a truncated stream
+/// would not be an error.
+fn decompress(bytes: &[u8], zlib_header: bool) -> Result<Vec<u8>> {
+    let mut decompress = Decompress::new(zlib_header);
+    // Never empty, so that reserving doubles the capacity
+    let mut uncompressed = Vec::with_capacity(bytes.len() * 2 + 64);
+
+    loop {
+        let consumed = decompress.total_in() as usize;
+
+        match decompress.decompress_vec(
+            &bytes[consumed..],
+            &mut uncompressed,
+            FlushDecompress::Finish,
+        )? {
+            Status::StreamEnd => break Ok(uncompressed),
+            // With space left in `uncompressed`, inflating only stops when `bytes` runs out
+            _ if uncompressed.len() < uncompressed.capacity() => {
+                break Err(anyhow!("stream is truncated"))
+            }
+            _ => uncompressed.reserve(uncompressed.capacity()),
+        }
+    }
+}
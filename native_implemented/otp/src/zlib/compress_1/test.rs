@@ -0,0 +1,39 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::zlib::compress_1::result;
+use crate::zlib::uncompress_1;
+
+#[test]
+fn without_iodata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("data")),
+            "data (data) element (data) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn with_iodata_returns_zlib_stream_of_bytes() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_str("hello"),
+            process.integer(b' '),
+            process.binary_from_str("world"),
+        ]);
+        let compressed = result(process, data).unwrap();
+
+        let compressed_binary: Boxed<HeapBin> = compressed.try_into().unwrap();
+        // CMF for deflate with a 32K window, then FLG for the default level
+        assert_eq!(&compressed_binary.as_bytes()[0..2], &[0x78, 0x9c]);
+
+        assert_eq!(
+            uncompress_1::result(process, compressed),
+            Ok(process.binary_from_str("hello world"))
+        );
+    });
+}
@@ -0,0 +1,39 @@
+use liblumen_alloc::atom;
+
+use crate::test::{hex_to_bytes, with_process};
+use crate::zlib::gunzip_1::result;
+
+#[test]
+fn without_iodata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("data")),
+            "data (data) element (data) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn without_gzip_stream_errors_data_error() {
+    with_process(|process| {
+        assert_error!(
+            result(process, process.binary_from_bytes(&[0xff; 8])),
+            atom!("data_error")
+        );
+    });
+}
+
+#[test]
+fn with_gzip_stream_returns_bytes() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&hex_to_bytes(
+                    "1f8b0800000000000203cb48cdc9c9070086a6103605000000"
+                ))
+            ),
+            Ok(process.binary_from_str("hello"))
+        );
+    });
+}
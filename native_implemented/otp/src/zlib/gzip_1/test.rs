@@ -0,0 +1,39 @@
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::test::with_process;
+use crate::zlib::gunzip_1;
+use crate::zlib::gzip_1::result;
+
+#[test]
+fn without_iodata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("data")),
+            "data (data) element (data) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn with_iodata_returns_gzip_stream_of_bytes() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_str("hello"),
+            process.integer(b' '),
+            process.binary_from_str("world"),
+        ]);
+        let compressed = result(process, data).unwrap();
+
+        let compressed_binary: Boxed<HeapBin> = compressed.try_into().unwrap();
+        // ID1, ID2 and CM for deflate
+        assert_eq!(&compressed_binary.as_bytes()[0..3], &[0x1f, 0x8b, 0x08]);
+
+        assert_eq!(
+            gunzip_1::result(process, compressed),
+            Ok(process.binary_from_str("hello world"))
+        );
+    });
+}
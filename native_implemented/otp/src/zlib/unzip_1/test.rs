@@ -0,0 +1,51 @@
+use liblumen_alloc::atom;
+
+use crate::test::{hex_to_bytes, with_process};
+use crate::zlib::unzip_1::result;
+
+#[test]
+fn without_iodata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("data")),
+            "data (data) element (data) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn without_raw_deflate_stream_errors_data_error() {
+    with_process(|process| {
+        assert_error!(
+            result(process, process.binary_from_bytes(&[0xff; 8])),
+            atom!("data_error")
+        );
+    });
+}
+
+#[test]
+fn with_truncated_raw_deflate_stream_errors_data_error() {
+    with_process(|process| {
+        // "hello" without its last block
+        assert_error!(
+            result(
+                process,
+                process.binary_from_bytes(&hex_to_bytes("cb48cdc9"))
+            ),
+            atom!("data_error")
+        );
+    });
+}
+
+#[test]
+fn with_raw_deflate_stream_returns_bytes() {
+    with_process(|process| {
+        assert_eq!(
+            result(
+                process,
+                process.binary_from_bytes(&hex_to_bytes("cb48cdc9c90700"))
+            ),
+            Ok(process.binary_from_str("hello"))
+        );
+    });
+}
@@ -0,0 +1,14 @@
+#[cfg(all(not(target_arch = "wasm32"), test))]
+mod test;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::Format;
+
+/// Compresses `data`, which is iodata, into the raw deflate format
+#[native_implemented::function(zlib:zip/1)]
+pub fn result(process: &Process, data: Term) -> exception::Result<Term> {
+    super::deflate(process, Format::Raw, data)
+}
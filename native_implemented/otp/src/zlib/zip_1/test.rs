@@ -0,0 +1,32 @@
+use liblumen_alloc::atom;
+
+use crate::test::with_process;
+use crate::zlib::unzip_1;
+use crate::zlib::zip_1::result;
+
+#[test]
+fn without_iodata_errors_badarg() {
+    with_process(|process| {
+        assert_badarg!(
+            result(process, atom!("data")),
+            "data (data) element (data) is not a byte, binary, or nested iolist"
+        );
+    });
+}
+
+#[test]
+fn with_iodata_returns_raw_deflate_stream_of_bytes() {
+    with_process(|process| {
+        let data = process.list_from_slice(&[
+            process.binary_from_str("hello"),
+            process.integer(b' '),
+            process.binary_from_str("world"),
+        ]);
+        let compressed = result(process, data).unwrap();
+
+        assert_eq!(
+            unzip_1::result(process, compressed),
+            Ok(process.binary_from_str("hello world"))
+        );
+    });
+}
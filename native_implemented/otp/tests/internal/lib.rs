@@ -1,7 +1,42 @@
+#[path = "lib/binary.rs"]
+pub mod binary;
+#[path = "lib/binary_match.rs"]
+pub mod binary_match;
+#[path = "lib/comprehension.rs"]
+pub mod comprehension;
+#[path = "lib/counters.rs"]
+pub mod counters;
 #[path = "lib/erlang.rs"]
 pub mod erlang;
+#[path = "lib/ets.rs"]
+pub mod ets;
+#[path = "lib/file.rs"]
+pub mod file;
+#[path = "lib/gen_tcp.rs"]
+pub mod gen_tcp;
+#[path = "lib/gen_udp.rs"]
+pub mod gen_udp;
+#[path = "lib/inet.rs"]
+pub mod inet;
+#[path = "lib/lists.rs"]
+pub mod lists;
+#[path = "lib/logger.rs"]
+pub mod logger;
+#[path = "lib/lumen.rs"]
+pub mod lumen;
 #[path = "lib/maps.rs"]
 pub mod maps;
+#[path = "lib/os.rs"]
+pub mod os;
+#[path = "lib/persistent_term.rs"]
+pub mod persistent_term;
+#[path = "lib/selective_receive.rs"]
+pub mod selective_receive;
+#[cfg(feature = "ssl")]
+#[path = "lib/ssl.rs"]
+pub mod ssl;
+#[path = "lib/timer.rs"]
+pub mod timer;
 
 test_stderr_substrings!(
     backtrace,
@@ -0,0 +1,12 @@
+#[path = "binary/compile_pattern_1.rs"]
+mod compile_pattern_1;
+#[path = "binary/copy_2.rs"]
+mod copy_2;
+#[path = "binary/match_2.rs"]
+mod match_2;
+#[path = "binary/match_3.rs"]
+mod match_3;
+#[path = "binary/part_3.rs"]
+mod part_3;
+#[path = "binary/split_3.rs"]
+mod split_3;
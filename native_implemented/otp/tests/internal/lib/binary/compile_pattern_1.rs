@@ -0,0 +1,4 @@
+test_stdout!(
+    with_patterns_returns_compiled_pattern_usable_by_match_and_split,
+    "{1, 1}\n[<<\"a\">>, <<\"b\">>, <<\"c\">>]\n"
+);
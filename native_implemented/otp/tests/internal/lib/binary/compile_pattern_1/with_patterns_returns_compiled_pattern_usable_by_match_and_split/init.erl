@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  CompiledPattern = binary:compile_pattern([<<",">>, <<";">>]),
+  display(binary:match(<<"a;b,c">>, CompiledPattern)),
+  display(binary:split(<<"a;b,c">>, CompiledPattern, [global])).
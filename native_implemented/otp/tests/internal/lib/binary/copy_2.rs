@@ -0,0 +1,4 @@
+test_stdout!(
+    with_count_returns_subject_repeated_count_times,
+    "<<\"abcabcabc\">>\n0\n"
+);
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(binary:copy(<<"abc">>, 3)),
+  display(byte_size(binary:copy(<<"abc">>, 0))).
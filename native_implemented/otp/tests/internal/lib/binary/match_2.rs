@@ -0,0 +1,4 @@
+test_stdout!(
+    with_pattern_returns_first_longest_match_or_nomatch,
+    "{2, 2}\n{1, 2}\nnomatch\n"
+);
@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Subject = <<"abcde">>,
+  display(binary:match(Subject, <<"cd">>)),
+  display(binary:match(Subject, [<<"b">>, <<"bc">>, <<"d">>])),
+  display(binary:match(Subject, <<"x">>)).
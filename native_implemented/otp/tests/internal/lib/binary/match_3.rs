@@ -0,0 +1 @@
+test_stdout!(with_scope_only_matches_in_scope, "{3, 3}\nnomatch\n");
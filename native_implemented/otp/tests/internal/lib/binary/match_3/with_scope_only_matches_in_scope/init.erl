@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Subject = <<"abcabc">>,
+  display(binary:match(Subject, <<"abc">>, [{scope, {1, 5}}])),
+  display(binary:match(Subject, <<"abc">>, [{scope, {1, 4}}])).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_binary_returns_subbinary,
+    "<<3,4,5>>\n<<1,2,3,4,5,6>>\n"
+);
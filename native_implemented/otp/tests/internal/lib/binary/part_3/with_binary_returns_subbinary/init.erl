@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Binary = <<1, 2, 3, 4, 5, 6>>,
+  display(binary:part(Binary, 2, 3)),
+  display(binary:part(Binary, 6, -6)).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_options_returns_parts,
+    "[<<\"a\">>, <<\"b,,c,\">>]\n5\n4\n[<<\"a\">>, <<\"b\">>, <<\"c\">>]\n"
+);
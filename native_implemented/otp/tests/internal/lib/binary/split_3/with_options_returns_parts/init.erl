@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Subject = <<"a,b,,c,">>,
+  display(binary:split(Subject, <<",">>)),
+  display(length(binary:split(Subject, <<",">>, [global]))),
+  display(length(binary:split(Subject, <<",">>, [global, trim]))),
+  display(binary:split(Subject, <<",">>, [global, trim_all])).
@@ -0,0 +1,3 @@
+test_stdout!(with_integer_segments, "1\n1059\n-1\n<<1,2>>\n");
+test_stdout!(with_utf_segments, "233\n128512\n65\n");
+test_stdout!(with_float_segments, "true\ntrue\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  <<F:32/float, G/float-little>> = <<1.5:32/float, 2.5/float-little>>,
+  display(F =:= 1.5),
+  display(G =:= 2.5).
@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  <<A:4, B:12/little, C:8/signed, Rest/binary>> = <<16#12, 16#34, 16#FF, 1, 2>>,
+  display(A),
+  display(B),
+  display(C),
+  display(Rest).
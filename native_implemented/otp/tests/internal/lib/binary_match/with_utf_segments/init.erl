@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  <<X/utf8, Y/utf16, Z/utf32-little>> = <<16#C3, 16#A9, 16#D8, 16#3D, 16#DE, 16#00, 16#41, 0, 0, 0>>,
+  display(X),
+  display(Y),
+  display(Z).
@@ -0,0 +1,3 @@
+test_stdout!(binary_comprehension_with_list_generator, "<<2,4,6>>\n");
+test_stdout!(list_comprehension_with_binary_generator, "[1, 2, 3]\n");
+test_stdout!(binary_comprehension_with_binary_generator, "<<3,2,1>>\n");
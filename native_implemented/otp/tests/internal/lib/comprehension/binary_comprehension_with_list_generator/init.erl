@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Binary = << <<(X * 2)>> || X <- [1, 2, 3] >>,
+  display(Binary).
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  List = [X || <<X>> <= <<1, 2, 3>>],
+  display(List).
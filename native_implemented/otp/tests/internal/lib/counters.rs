@@ -0,0 +1,2 @@
+#[path = "counters/add_3.rs"]
+mod add_3;
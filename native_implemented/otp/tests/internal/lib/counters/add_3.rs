@@ -0,0 +1 @@
+test_stdout!(with_spawned_process_shares_counter, "5\n");
@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Reference = counters:new(1, []),
+  Parent = self(),
+  spawn(fun () ->
+    ok = counters:add(Reference, 1, 2),
+    Parent ! added
+  end),
+  receive
+    added -> ok
+  end,
+  ok = counters:add(Reference, 1, 3),
+  display(counters:get(Reference, 1)).
@@ -10,6 +10,8 @@ pub mod andalso_2;
 pub mod append_element_2;
 #[path = "erlang/apply_2.rs"]
 pub mod apply_2;
+#[path = "erlang/apply_3.rs"]
+pub mod apply_3;
 #[path = "erlang/atom_to_binary_2.rs"]
 pub mod atom_to_binary_2;
 #[path = "erlang/atom_to_list_1.rs"]
@@ -78,6 +80,8 @@ pub mod error_1;
 pub mod error_2;
 #[path = "erlang/exit_1.rs"]
 pub mod exit_1;
+#[path = "erlang/exit_2.rs"]
+pub mod exit_2;
 #[path = "erlang/float_1.rs"]
 pub mod float_1;
 #[path = "erlang/float_to_binary_1.rs"]
@@ -92,6 +96,8 @@ pub mod float_to_list_2;
 pub mod floor_1;
 #[path = "erlang/function_exported_3.rs"]
 pub mod function_exported_3;
+#[path = "erlang/garbage_collect_1.rs"]
+pub mod garbage_collect_1;
 #[path = "erlang/get_0.rs"]
 pub mod get_0;
 #[path = "erlang/get_1.rs"]
@@ -102,6 +108,8 @@ pub mod get_keys_0;
 pub mod get_keys_1;
 #[path = "erlang/hd_1.rs"]
 pub mod hd_1;
+#[path = "erlang/hibernate_3.rs"]
+pub mod hibernate_3;
 #[path = "erlang/insert_element_3.rs"]
 pub mod insert_element_3;
 #[path = "erlang/integer_to_binary_1.rs"]
@@ -132,16 +140,28 @@ pub mod is_pid_1;
 pub mod is_process_alive_1;
 #[path = "erlang/link_1.rs"]
 pub mod link_1;
+#[path = "erlang/list_to_tuple_1.rs"]
+pub mod list_to_tuple_1;
 #[path = "erlang/load_nif_2.rs"]
 pub mod load_nif_2;
+#[path = "erlang/make_fun_3.rs"]
+pub mod make_fun_3;
 #[path = "erlang/module_loaded_1.rs"]
 pub mod module_loaded_1;
 #[path = "erlang/nif_error_1.rs"]
 pub mod nif_error_1;
 #[path = "erlang/or_2.rs"]
 pub mod or_2;
+#[path = "erlang/phash2_2.rs"]
+pub mod phash2_2;
+#[path = "erlang/port_command_2.rs"]
+pub mod port_command_2;
 #[path = "erlang/process_flag_2.rs"]
 pub mod process_flag_2;
+#[path = "erlang/raise_3.rs"]
+pub mod raise_3;
+#[path = "erlang/register_2.rs"]
+pub mod register_2;
 #[path = "erlang/seq_trace_2.rs"]
 pub mod seq_trace_2;
 #[path = "erlang/seq_trace_info_1.rs"]
@@ -162,9 +182,19 @@ pub mod spawn_monitor_1;
 pub mod spawn_monitor_3;
 #[path = "erlang/spawn_opt_2.rs"]
 pub mod spawn_opt_2;
+#[path = "erlang/spawn_opt_3.rs"]
+pub mod spawn_opt_3;
 #[path = "erlang/spawn_opt_4.rs"]
 pub mod spawn_opt_4;
+#[path = "erlang/split_binary_2.rs"]
+pub mod split_binary_2;
 #[path = "erlang/system_flag_2.rs"]
 pub mod system_flag_2;
+#[path = "erlang/system_info_1.rs"]
+pub mod system_info_1;
+#[path = "erlang/term_to_binary_2.rs"]
+pub mod term_to_binary_2;
 #[path = "erlang/tl_1.rs"]
 pub mod tl_1;
+#[path = "erlang/trace_3.rs"]
+pub mod trace_3;
@@ -0,0 +1,9 @@
+test_stdout!(with_exported_function_returns_result, "3\n");
+test_stdout!(
+    without_atom_module_errors_badarg,
+    "{caught, error, badarg}\n{caught, error, badarg}\n"
+);
+test_stdout!(
+    without_exported_function_errors_undef,
+    "{caught, error, undef}\n{caught, error, undef}\n"
+);
@@ -0,0 +1,12 @@
+-module(init).
+-export([add/2, start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  %% Computed at runtime, so the call can only be resolved with the exports table
+  Module = list_to_atom("init"),
+  Function = list_to_atom("add"),
+  display(apply(Module, Function, [1, 2])).
+
+add(A, B) ->
+  A + B.
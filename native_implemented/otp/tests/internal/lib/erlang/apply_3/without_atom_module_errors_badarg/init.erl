@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  test(<<"init">>, start),
+  test(init, <<"start">>).
+
+test(Module, Function) ->
+  try apply(Module, Function, []) of
+    _ -> display(applied)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
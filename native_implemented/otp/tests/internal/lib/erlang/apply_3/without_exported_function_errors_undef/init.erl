@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  %% Module is not loaded
+  test(list_to_atom("not_loaded"), start, []),
+  %% Module is loaded, but function is not exported with that arity
+  test(list_to_atom("init"), start, [1]).
+
+test(Module, Function, Arguments) ->
+  try apply(Module, Function, Arguments) of
+    _ -> display(applied)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
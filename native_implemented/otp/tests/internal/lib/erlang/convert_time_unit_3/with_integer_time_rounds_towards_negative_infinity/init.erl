@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [convert_time_unit/3, display/1]).
+
+start() ->
+  display(convert_time_unit(1, 3, 7)),
+  display(convert_time_unit(-1, 3, 7)),
+  display(convert_time_unit(1999, millisecond, second)),
+  display(convert_time_unit(-1, millisecond, second)),
+  display(convert_time_unit(-1000, millisecond, second)).
@@ -0,0 +1,13 @@
+// `without_pid_or_port_errors_badarg` in unit tests
+test_stdout!(
+    with_kill_reason_exits_process_waiting_in_receive_even_when_trapping_exits,
+    "{child, exited, killed}\n"
+);
+test_stdout!(
+    with_reason_sends_exit_message_to_process_trapping_exits,
+    "{child, received, reason}\n{child, exited, normal}\n"
+);
+test_stdout!(
+    with_normal_reason_does_not_exit_process_not_trapping_exits,
+    "{child, alive, true}\n"
+);
@@ -0,0 +1,20 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  Child = spawn(fun () ->
+    process_flag(trap_exit, true),
+    Parent ! trapping,
+    receive
+      Message -> display({child, received, Message})
+    end
+  end),
+  Reference = monitor(process, Child),
+  receive
+    trapping -> exit(Child, kill)
+  end,
+  receive
+    {'DOWN', Reference, process, _, Reason} -> display({child, exited, Reason})
+  end.
@@ -0,0 +1,18 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Child = spawn(fun () ->
+    receive
+      shutdown -> ok
+    end
+  end),
+  Reference = monitor(process, Child),
+  exit(Child, normal),
+  receive
+    {'DOWN', Reference, process, _, Reason} -> display({child, exited, Reason})
+  after 10 ->
+    display({child, alive, is_process_alive(Child)})
+  end,
+  Child ! shutdown.
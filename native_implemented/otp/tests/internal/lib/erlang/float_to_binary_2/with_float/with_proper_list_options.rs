@@ -2,5 +2,7 @@
 pub mod with_decimals;
 #[path = "with_proper_list_options/with_scientific.rs"]
 pub mod with_scientific;
+#[path = "with_proper_list_options/with_short.rs"]
+pub mod with_short;
 
 // `without_valid_option_errors_badarg` in unit tests
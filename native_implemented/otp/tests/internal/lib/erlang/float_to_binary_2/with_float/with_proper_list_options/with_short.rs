@@ -0,0 +1,8 @@
+test_stdout!(
+    uses_fewest_digits_that_round_trip,
+    "<<\"7.12\">>\n<<\"0.1\">>\n<<\"123456.0\">>\n<<\"-0.001\">>\n"
+);
+test_stdout!(
+    uses_scientific_notation_when_shorter,
+    "<<\"1.0e4\">>\n<<\"1.0e-5\">>\n<<\"1.5e20\">>\n<<\"9.007199254740992e15\">>\n"
+);
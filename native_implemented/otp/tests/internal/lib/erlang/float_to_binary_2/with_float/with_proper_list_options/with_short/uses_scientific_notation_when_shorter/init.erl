@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, float_to_binary/2]).
+
+start() ->
+  display(float_to_binary(10000.0, [short])),
+  display(float_to_binary(0.00001, [short])),
+  display(float_to_binary(1.5e20, [short])),
+  %% Outside of (-2⁵³, 2⁵³) scientific notation is always used
+  display(float_to_binary(9007199254740992.0, [short])).
@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, float_to_list/2]).
+
+start() ->
+  display(float_to_list(7.12, [short])),
+  display(float_to_list(0.1, [short])),
+  display(float_to_list(123456.0, [short])),
+  display(float_to_list(-0.001, [short])).
@@ -0,0 +1,3 @@
+// `without_pid_errors_badarg` in unit tests
+test_stdout!(with_self_or_other_process_returns_true, "true\ntrue\n");
+test_stdout!(without_process_returns_false, "false\n");
@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(garbage_collect(self())),
+  Child = spawn(fun () ->
+    receive
+      stop -> ok
+    end
+  end),
+  display(garbage_collect(Child)),
+  Child ! stop.
@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Child = spawn(fun () -> ok end),
+  Reference = monitor(process, Child),
+  receive
+    {'DOWN', Reference, process, _, _} -> ok
+  end,
+  display(garbage_collect(Child)).
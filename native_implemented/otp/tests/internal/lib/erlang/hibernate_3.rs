@@ -0,0 +1,7 @@
+// `without_atom_module_errors_badarg` in unit tests
+// `without_atom_function_errors_badarg` in unit tests
+// `without_proper_list_arguments_errors_badarg` in unit tests
+test_stdout!(
+    with_message_calls_function_and_exits_normally,
+    "{woken, wake}\n{child, exited, normal}\n"
+);
@@ -0,0 +1,23 @@
+-module(init).
+-export([start/0, woken/1]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  Child = spawn(fun () ->
+    erlang:hibernate(init, woken, [Parent]),
+    display(unreachable)
+  end),
+  Reference = monitor(process, Child),
+  Child ! wake,
+  receive
+    {woken, Message} -> display({woken, Message})
+  end,
+  receive
+    {'DOWN', Reference, process, _, Reason} -> display({child, exited, Reason})
+  end.
+
+woken(Parent) ->
+  receive
+    Message -> Parent ! {woken, Message}
+  end.
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_list_longer_than_reductions_per_run_returns_tuple,
+    "100000\n"
+);
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Tuple = list_to_tuple(lists:seq(1, 100000)),
+  display(tuple_size(Tuple)).
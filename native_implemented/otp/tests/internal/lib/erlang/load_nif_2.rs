@@ -1 +1 @@
-test_stdout!(returns_error_not_sup, "{returned, {error, {notsup, \"Calling load_nif from Lumen compiled modules not supported\"}}}\n");
+test_stdout!(without_library_returns_load_failed_error, "load_failed\n");
@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, load_nif/2]).
+
+start() ->
+  Path = "my_nif",
+  LoadInfo = 0,
+  {error, {Reason, _Text}} = load_nif(Path, LoadInfo),
+  display(Reason).
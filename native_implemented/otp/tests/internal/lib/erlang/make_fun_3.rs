@@ -0,0 +1,12 @@
+test_stdout!(
+    with_exported_function_returns_callable_fun,
+    "true\ntrue\n4\n"
+);
+test_stdout!(
+    without_loaded_module_errors_undef,
+    "true\n{caught, error, undef}\ntrue\n{caught, error, undef}\n"
+);
+test_stdout!(
+    without_atoms_or_arity_errors_badarg,
+    "{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n"
+);
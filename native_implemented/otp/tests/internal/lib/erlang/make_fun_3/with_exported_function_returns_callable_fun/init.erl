@@ -0,0 +1,12 @@
+-module(init).
+-export([double/1, start/0]).
+-import(erlang, [display/1, make_fun/3]).
+
+start() ->
+  Fun = make_fun(init, double, 1),
+  display(is_function(Fun, 1)),
+  display(Fun =:= fun init:double/1),
+  display(apply(Fun, [2])).
+
+double(N) ->
+  N * 2.
@@ -0,0 +1,15 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, make_fun/3]).
+
+start() ->
+  test(<<"init">>, start, 0),
+  test(init, <<"start">>, 0),
+  test(init, start, 256).
+
+test(Module, Function, Arity) ->
+  try make_fun(Module, Function, Arity) of
+    _ -> display(made)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
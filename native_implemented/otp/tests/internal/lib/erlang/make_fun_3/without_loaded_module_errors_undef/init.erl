@@ -0,0 +1,19 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, make_fun/3]).
+
+start() ->
+  %% Module is not loaded
+  test(list_to_atom("not_loaded"), start, []),
+  %% Module is loaded, but function is not exported with that arity
+  test(init, start, [argument]).
+
+test(Module, Function, Arguments) ->
+  Arity = length(Arguments),
+  Fun = make_fun(Module, Function, Arity),
+  display(is_function(Fun, Arity)),
+  try apply(Fun, Arguments) of
+    _ -> display(applied)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
@@ -0,0 +1,8 @@
+test_stdout!(
+    without_valid_range_errors_badarg,
+    "{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n"
+);
+test_stdout!(
+    with_equal_terms_returns_equal_hashes,
+    "true\ntrue\ntrue\n0\n"
+);
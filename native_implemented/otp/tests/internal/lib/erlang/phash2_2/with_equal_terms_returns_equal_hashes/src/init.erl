@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Binary = <<"0123456789012345678901234567890123456789012345678901234567890123456789">>,
+  <<_, SubBinary/binary>> = <<"_", Binary/binary>>,
+  display(hash(Binary) == hash(SubBinary)),
+  display(hash(#{a => 1, b => 2}) == hash(maps:put(a, 1, #{b => 2}))),
+  display(hash({Binary, [1, 2.0]}) == hash({SubBinary, [1, 2.0]})),
+  display(erlang:phash2(Binary, 1)).
+
+hash(Term) ->
+  erlang:phash2(Term, 4294967296).
@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  caught(0),
+  caught(-1),
+  caught(4294967297),
+  caught(range).
+
+caught(Range) ->
+  test:caught(fun () ->
+    erlang:phash2(term, Range)
+  end).
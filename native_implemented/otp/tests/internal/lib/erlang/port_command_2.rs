@@ -0,0 +1,4 @@
+test_stdout!(
+    with_open_port_writes_data_to_os_process,
+    "true\n<<\"hello\">>\ntrue\n"
+);
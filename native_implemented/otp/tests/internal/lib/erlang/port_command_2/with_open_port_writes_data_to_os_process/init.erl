@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Port = open_port({spawn, "cat"}, [binary]),
+  display(port_command(Port, [<<"hel">>, "lo"])),
+  receive
+    {Port, {data, Data}} -> display(Data)
+  after 5000 ->
+    display(timeout)
+  end,
+  display(port_close(Port)).
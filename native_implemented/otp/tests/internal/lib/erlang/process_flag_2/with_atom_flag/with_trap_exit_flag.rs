@@ -16,3 +16,7 @@ test_stdout!(
     with_true_value_then_false_value_exits_when_linked_process_does_not_exit_normal,
     "{trap_exit, true}\n{trap_exit, false}\n{child, exited, abnormal}\n{parent, exited, abnormal}\n"
 );
+test_stdout!(
+    with_true_value_with_linked_receive_exit_message_and_unlinks_when_linked_process_exits_normal,
+    "{trap_exit, true}\n{child, exited, normal}\n{links, []}\n"
+);
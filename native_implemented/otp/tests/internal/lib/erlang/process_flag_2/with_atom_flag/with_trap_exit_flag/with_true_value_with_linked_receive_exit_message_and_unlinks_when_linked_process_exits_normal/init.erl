@@ -0,0 +1,17 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  process_flag(trap_exit, true),
+  display(process_info(self(), trap_exit)),
+  ChildPid = spawn_link(fun () ->
+    ok
+  end),
+  receive
+    {'EXIT', ChildPid, Reason} ->
+      display({child, exited, Reason})
+  after 10 ->
+    display({child, alive, is_process_alive(ChildPid)})
+  end,
+  display(process_info(self(), links)).
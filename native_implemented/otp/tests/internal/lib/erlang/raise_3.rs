@@ -0,0 +1,12 @@
+test_stdout!(
+    with_valid_stacktrace_reraises_with_class_reason_and_stacktrace,
+    "{error, reason, true}\n{exit, reason, true}\n{throw, reason, true}\n"
+);
+test_stdout!(
+    without_valid_class_errors_badarg,
+    "{caught, error, badarg}\n"
+);
+test_stdout!(
+    without_valid_stacktrace_errors_badarg,
+    "{caught, error, badarg}\n{caught, error, badarg}\n"
+);
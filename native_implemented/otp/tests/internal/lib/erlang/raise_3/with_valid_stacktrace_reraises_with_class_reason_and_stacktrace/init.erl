@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Stacktrace = [{module, function, 1, [{file, "module.erl"}, {line, 2}]}, {module, function, [argument], []}],
+  test(error, Stacktrace),
+  test(exit, Stacktrace),
+  test(throw, Stacktrace).
+
+test(Class, Stacktrace) ->
+  try erlang:raise(Class, reason, Stacktrace) of
+    _ -> display(returned)
+  catch
+    CaughtClass:Reason:CaughtStacktrace -> display({CaughtClass, Reason, CaughtStacktrace == Stacktrace})
+  end.
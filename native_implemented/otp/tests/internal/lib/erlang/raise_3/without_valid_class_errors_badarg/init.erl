@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  try erlang:raise(class, reason, []) of
+    _ -> display(returned)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
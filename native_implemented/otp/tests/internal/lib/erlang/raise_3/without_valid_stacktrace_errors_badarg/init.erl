@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  test(stacktrace),
+  test([{module, function, arity, []}]).
+
+test(Stacktrace) ->
+  try erlang:raise(error, reason, Stacktrace) of
+    _ -> display(returned)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_exited_process_releases_name,
+    "true\ntrue\nnormal\nundefined\ntrue\n"
+);
@@ -0,0 +1,20 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Name = registered_name,
+  {Pid, Reference} = spawn_monitor(fun () ->
+    receive
+      stop -> ok
+    end
+  end),
+  display(register(Name, Pid)),
+  display(whereis(Name) == Pid),
+  Pid ! stop,
+  receive
+    {'DOWN', Reference, process, _, Reason} ->
+      display(Reason)
+  end,
+  display(whereis(Name)),
+  display(register(Name, self())).
@@ -4,7 +4,11 @@ mod with_empty_list_options;
 mod with_link_and_monitor_in_options_list;
 #[path = "with_function/with_link_in_options_list.rs"]
 mod with_link_in_options_list;
+#[path = "with_function/with_min_heap_size_in_options_list.rs"]
+mod with_min_heap_size_in_options_list;
 #[path = "with_function/with_monitor_in_options_list.rs"]
 mod with_monitor_in_options_list;
+#[path = "with_function/with_priority_in_options_list.rs"]
+mod with_priority_in_options_list;
 
 // `without_proper_list_options_errors_badarg` in unit tests
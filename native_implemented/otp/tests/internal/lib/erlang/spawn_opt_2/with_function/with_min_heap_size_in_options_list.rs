@@ -0,0 +1 @@
+test_stdout!(child_process_has_at_least_min_heap_size, "true\n");
@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Options = [{min_heap_size, 1000}],
+  ChildPid = spawn_opt(fun () ->
+    receive
+      stop -> ok
+    end
+  end, Options),
+  {min_heap_size, MinHeapSize} = process_info(ChildPid, min_heap_size),
+  display(MinHeapSize >= 1000),
+  ChildPid ! stop.
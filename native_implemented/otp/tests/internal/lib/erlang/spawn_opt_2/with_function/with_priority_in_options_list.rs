@@ -0,0 +1 @@
+test_stdout!(child_process_has_priority, "{priority, high}\n");
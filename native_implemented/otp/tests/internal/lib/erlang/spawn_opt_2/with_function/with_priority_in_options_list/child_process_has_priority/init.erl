@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Options = [{priority, high}],
+  ChildPid = spawn_opt(fun () ->
+    receive
+      stop -> ok
+    end
+  end, Options),
+  display(process_info(ChildPid, priority)),
+  ChildPid ! stop.
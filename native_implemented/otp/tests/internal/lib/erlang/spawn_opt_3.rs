@@ -0,0 +1 @@
+test_stdout!(with_local_node_runs_function_in_child_process, "from_fun\n");
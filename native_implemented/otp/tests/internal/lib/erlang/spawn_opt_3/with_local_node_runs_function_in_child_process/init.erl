@@ -0,0 +1,15 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Options = [monitor],
+  {ChildPid, ChildMonitorReference} = spawn_opt(node(), fun () ->
+    display(from_fun)
+  end, Options),
+  receive
+    {'DOWN', ChildMonitorReference, process, ChildPid, normal} ->
+      ok
+    after 10 ->
+      display(timeout)
+  end.
@@ -0,0 +1,6 @@
+// `without_bitstring_binary_errors_badarg` in unit tests
+
+test_stdout!(
+    with_binary_literal_returns_subbinaries,
+    "{<<>>, <<\"hello\">>}\n{<<\"he\">>, <<\"llo\">>}\n{<<\"hello\">>, <<>>}\n{caught, error, badarg}\n"
+);
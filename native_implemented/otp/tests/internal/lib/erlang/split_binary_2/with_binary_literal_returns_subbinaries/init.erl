@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(split_binary(<<"hello">>, 0)),
+  display(split_binary(<<"hello">>, 2)),
+  display(split_binary(<<"hello">>, 5)),
+  test:caught(fun () ->
+    split_binary(<<"hello">>, 6)
+  end).
@@ -1,9 +1,17 @@
-// TODO with_*_flag
-// #[path = "system_flag_2/with_*_flag.rs"]
-// mod with_*_flag;
-
 test_stdout!(without_atom_flag_errors_badarg, "{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n{caught, error, badarg}\n");
 test_stdout!(
     without_supported_atom_flag_errors_badarg,
     "{caught, error, badarg}\n"
 );
+test_stdout!(
+    with_fullsweep_after_flag_returns_previous_value,
+    "65535\n{fullsweep_after, 10}\n"
+);
+test_stdout!(
+    with_min_heap_size_flag_returns_previous_value,
+    "233\n{min_heap_size, 233}\n"
+);
+test_stdout!(
+    with_max_heap_size_flag_kills_processes_exceeding_it,
+    "{1000, true, false}\n{child, exited, killed}\n"
+);
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, system_flag/2, system_info/1]).
+
+start() ->
+  display(system_flag(fullsweep_after, 10)),
+  display(system_info(fullsweep_after)).
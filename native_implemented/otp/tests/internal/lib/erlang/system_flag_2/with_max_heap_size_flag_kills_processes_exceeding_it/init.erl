@@ -0,0 +1,18 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, system_flag/2, system_info/1]).
+-import(lumen, [log_exit/1]).
+
+start() ->
+  log_exit(false),
+  #{size := 0} = system_flag(max_heap_size, #{size => 1000, error_logger => false}),
+  {max_heap_size, #{size := Size, kill := Kill, error_logger := ErrorLogger}} =
+    system_info(max_heap_size),
+  display({Size, Kill, ErrorLogger}),
+  {_, Reference} = spawn_monitor(fun () ->
+    List = lists:seq(1, 100000),
+    display({child, length, length(List)})
+  end),
+  receive
+    {'DOWN', Reference, process, _, Reason} -> display({child, exited, Reason})
+  end.
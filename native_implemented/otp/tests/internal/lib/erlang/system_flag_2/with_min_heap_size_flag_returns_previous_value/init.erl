@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1, system_flag/2, system_info/1]).
+
+start() ->
+  display(system_flag(min_heap_size, 233)),
+  display(system_info(min_heap_size)).
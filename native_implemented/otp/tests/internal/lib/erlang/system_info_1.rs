@@ -0,0 +1 @@
+test_stdout!(with_schedulers_returns_positive_integer, "true\ntrue\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Schedulers = erlang:system_info(schedulers),
+  display(is_integer(Schedulers) andalso Schedulers >= 1),
+  display(erlang:system_info(schedulers_online) == Schedulers).
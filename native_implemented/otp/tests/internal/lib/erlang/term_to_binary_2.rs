@@ -0,0 +1,4 @@
+test_stdout!(
+    with_compressed_roundtrips_through_binary_to_term,
+    "true\ntrue\n"
+);
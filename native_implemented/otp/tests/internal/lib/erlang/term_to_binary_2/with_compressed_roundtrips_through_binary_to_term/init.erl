@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [binary_to_term/1, display/1, term_to_binary/1, term_to_binary/2]).
+
+start() ->
+  Term = [zero, zero, zero, zero, zero, zero, zero, zero, zero, zero],
+  Compressed = term_to_binary(Term, [compressed]),
+
+  %% Compressed is smaller than the uncompressed encoding
+  display(byte_size(Compressed) < byte_size(term_to_binary(Term))),
+  display(binary_to_term(Compressed) == Term).
@@ -0,0 +1,12 @@
+test_stdout!(
+    with_send_and_receive_flags_sends_trace_messages_to_tracer,
+    "received\nsent\ndone\n"
+);
+test_stdout!(
+    with_procs_flag_sends_exit_trace_message_to_tracer,
+    "{exit, done}\n"
+);
+test_stdout!(
+    with_call_flag_and_pattern_sends_call_trace_message_to_tracer,
+    "4\ncalled\n"
+);
@@ -0,0 +1,19 @@
+-module(init).
+-export([double/1, start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  1 = erlang:trace(self(), true, [call]),
+  1 = erlang:trace_pattern({init, double, 1}, true),
+  %% Only calls through `erlang:apply/3` are traced
+  Module = list_to_atom("init"),
+  Function = list_to_atom("double"),
+  display(apply(Module, Function, [2])),
+  receive
+    {trace, _, call, {init, double, [2]}} -> display(called)
+  after 100 ->
+    display(timeout)
+  end.
+
+double(X) ->
+  X * 2.
@@ -0,0 +1,19 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [log_exit/1]).
+
+start() ->
+  log_exit(false),
+  Tracee = spawn(fun () ->
+    receive
+      go -> exit(done)
+    end
+  end),
+  1 = erlang:trace(Tracee, true, [procs]),
+  Tracee ! go,
+  receive
+    {trace, Tracee, exit, Reason} -> display({exit, Reason})
+  after 100 ->
+    display(timeout)
+  end.
@@ -0,0 +1,28 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Tracer = self(),
+  Tracee = spawn(fun () ->
+    receive
+      go -> Tracer ! done
+    end
+  end),
+  1 = erlang:trace(Tracee, true, [send, 'receive']),
+  Tracee ! go,
+  receive
+    {trace, Tracee, 'receive', go} -> display(received)
+  after 100 ->
+    display(timeout)
+  end,
+  receive
+    {trace, Tracee, send, done, Tracer} -> display(sent)
+  after 100 ->
+    display(timeout)
+  end,
+  receive
+    done -> display(done)
+  after 100 ->
+    display(timeout)
+  end.
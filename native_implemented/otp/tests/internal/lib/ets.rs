@@ -0,0 +1,6 @@
+#[path = "ets/delete_1.rs"]
+mod delete_1;
+#[path = "ets/match_object_2.rs"]
+mod match_object_2;
+#[path = "ets/select_2.rs"]
+mod select_2;
@@ -0,0 +1,8 @@
+test_stdout!(
+    with_owner_exit_deletes_table,
+    "[{key, value}]\n{caught, error, badarg}\n"
+);
+test_stdout!(
+    with_deleted_table_errors_badarg,
+    "true\n{caught, error, badarg}\n"
+);
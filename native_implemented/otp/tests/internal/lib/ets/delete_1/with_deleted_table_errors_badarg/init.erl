@@ -0,0 +1,12 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Table = ets:new(table, [set]),
+  display(ets:delete(Table)),
+  try ets:insert(Table, {key, value}) of
+    Inserted -> display({inserted, Inserted})
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
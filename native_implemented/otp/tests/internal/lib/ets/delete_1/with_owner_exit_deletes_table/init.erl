@@ -0,0 +1,33 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  {Owner, OwnerMonitorReference} = spawn_monitor(fun () ->
+    owned = ets:new(owned, [named_table, public]),
+    true = ets:insert(owned, {key, value}),
+    Parent ! created,
+    wait_to_shutdown()
+  end),
+  receive
+    created -> ok
+  end,
+  display(ets:lookup(owned, key)),
+  shutdown(Owner),
+  receive
+    {'DOWN', OwnerMonitorReference, process, _, normal} -> ok
+  end,
+  try ets:lookup(owned, key) of
+    Objects -> display({objects, Objects})
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
+
+wait_to_shutdown() ->
+  receive
+    shutdown -> ok
+  end.
+
+shutdown(Pid) ->
+  Pid ! shutdown.
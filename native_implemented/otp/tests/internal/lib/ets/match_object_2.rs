@@ -0,0 +1 @@
+test_stdout!(with_pattern_returns_matching_objects, "[{two, 2, even}]\n");
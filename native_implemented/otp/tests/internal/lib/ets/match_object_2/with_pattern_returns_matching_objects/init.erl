@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Table = ets:new(table, [set]),
+  true = ets:insert(Table, [{one, 1, odd}, {two, 2, even}, {three, 3, odd}]),
+  display(ets:match_object(Table, {'_', '_', even})).
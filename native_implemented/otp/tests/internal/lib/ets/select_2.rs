@@ -0,0 +1,4 @@
+test_stdout!(
+    with_match_spec_returns_evaluated_bodies,
+    "[{1, one}, {3, three}]\n"
+);
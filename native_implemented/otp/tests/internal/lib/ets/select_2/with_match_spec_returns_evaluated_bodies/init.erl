@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Table = ets:new(table, [ordered_set]),
+  true = ets:insert(Table, [{one, 1, odd}, {two, 2, even}, {three, 3, odd}]),
+  Selected = ets:select(Table, [{{'$1', '$2', odd}, [], [{{'$2', '$1'}}]}]),
+  display(Selected).
@@ -0,0 +1,18 @@
+#[path = "file/delete_1.rs"]
+mod delete_1;
+#[path = "file/list_dir_1.rs"]
+mod list_dir_1;
+#[path = "file/open_2.rs"]
+mod open_2;
+#[path = "file/read_2.rs"]
+mod read_2;
+#[path = "file/read_file_1.rs"]
+mod read_file_1;
+#[path = "file/read_file_info_1.rs"]
+mod read_file_info_1;
+#[path = "file/write_2.rs"]
+mod write_2;
+#[path = "file/write_file_2.rs"]
+mod write_file_2;
+#[path = "file/write_file_3.rs"]
+mod write_file_3;
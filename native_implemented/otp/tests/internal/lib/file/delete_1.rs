@@ -0,0 +1 @@
+test_stdout!(with_deleted_file_returns_enoent, "ok\n{error, enoent}\n");
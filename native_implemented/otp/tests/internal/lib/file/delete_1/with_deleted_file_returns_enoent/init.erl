@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_delete_1.txt",
+  ok = file:write_file(Filename, <<"delete">>),
+  display(file:delete(Filename)),
+  display(file:delete(Filename)).
@@ -0,0 +1,2 @@
+test_stdout!(with_file_returns_enotdir, "{error, enotdir}\n");
+test_stdout!(with_missing_dir_returns_enoent, "{error, enoent}\n");
@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_list_dir_1.txt",
+  ok = file:write_file(Filename, <<"list_dir">>),
+  display(file:list_dir(Filename)),
+  ok = file:delete(Filename).
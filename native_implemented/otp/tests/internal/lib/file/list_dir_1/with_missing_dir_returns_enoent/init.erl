@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(file:list_dir("/tmp/lumen_file_list_dir_1_missing")).
@@ -0,0 +1,8 @@
+test_stdout!(
+    with_read_of_missing_file_returns_enoent,
+    "{error, enoent}\n"
+);
+test_stdout!(
+    with_write_and_exclusive_of_existing_file_returns_eexist,
+    "{error, eexist}\n"
+);
@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(file:open("/tmp/lumen_file_open_2_missing.txt", [read])).
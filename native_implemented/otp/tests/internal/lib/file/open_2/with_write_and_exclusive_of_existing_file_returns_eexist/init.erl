@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_open_2_exclusive.txt",
+  ok = file:write_file(Filename, <<"exclusive">>),
+  display(file:open(Filename, [write, exclusive])),
+  ok = file:delete(Filename).
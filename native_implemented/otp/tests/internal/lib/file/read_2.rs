@@ -0,0 +1,5 @@
+test_stdout!(
+    with_binary_reads_up_to_number_bytes_then_eof,
+    "{ok, <<\"hel\">>}\n{ok, <<\"lo\">>}\neof\n"
+);
+test_stdout!(without_binary_reads_list, "{ok, \"hi\"}\n");
@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_read_2_binary.txt",
+  ok = file:write_file(Filename, <<"hello">>),
+  {ok, IoDevice} = file:open(Filename, [read, binary]),
+  display(file:read(IoDevice, 3)),
+  display(file:read(IoDevice, 3)),
+  display(file:read(IoDevice, 3)),
+  ok = file:close(IoDevice),
+  ok = file:delete(Filename).
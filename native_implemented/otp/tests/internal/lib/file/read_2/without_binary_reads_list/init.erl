@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_read_2_list.txt",
+  ok = file:write_file(Filename, <<"hi">>),
+  {ok, IoDevice} = file:open(Filename, [read]),
+  display(file:read(IoDevice, 2)),
+  ok = file:close(IoDevice),
+  ok = file:delete(Filename).
@@ -0,0 +1 @@
+test_stdout!(with_missing_file_returns_enoent, "{error, enoent}\n");
@@ -0,0 +1,6 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(file:read_file("/tmp/lumen_file_read_file_1_missing.txt")).
@@ -0,0 +1,5 @@
+test_stdout!(
+    with_regular_file_returns_size_type_and_access,
+    "{5, regular, read_write}\n"
+);
+test_stdout!(with_missing_file_returns_enoent, "{error, enoent}\n");
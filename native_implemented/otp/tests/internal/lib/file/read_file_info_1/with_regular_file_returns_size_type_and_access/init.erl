@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_read_file_info_1.txt",
+  ok = file:write_file(Filename, <<"hello">>),
+  {ok, {file_info, Size, Type, Access, _, _, _, _, _, _, _, _, _, _}} = file:read_file_info(Filename),
+  display({Size, Type, Access}),
+  ok = file:delete(Filename).
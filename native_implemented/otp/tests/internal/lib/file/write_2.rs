@@ -0,0 +1,5 @@
+test_stdout!(
+    with_closed_io_device_returns_einval,
+    "ok\n{error, einval}\n"
+);
+test_stdout!(with_iodata_writes_bytes, "ok\n{ok, <<\"hello world\">>}\n");
@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_write_2_closed.txt",
+  {ok, IoDevice} = file:open(Filename, [write]),
+  display(file:close(IoDevice)),
+  display(file:write(IoDevice, <<"closed">>)),
+  ok = file:delete(Filename).
@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_write_2_iodata.txt",
+  {ok, IoDevice} = file:open(Filename, [write]),
+  display(file:write(IoDevice, [<<"hello">>, $\s, "world"])),
+  ok = file:close(IoDevice),
+  display(file:read_file(Filename)),
+  ok = file:delete(Filename).
@@ -0,0 +1 @@
+test_stdout!(then_read_file_returns_bytes, "ok\n{ok, <<\"hello\">>}\n");
@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_write_file_2.txt",
+  display(file:write_file(Filename, <<"hello">>)),
+  display(file:read_file(Filename)),
+  ok = file:delete(Filename).
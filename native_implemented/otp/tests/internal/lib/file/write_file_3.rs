@@ -0,0 +1 @@
+test_stdout!(with_append_appends_bytes, "{ok, <<\"hello world\">>}\n");
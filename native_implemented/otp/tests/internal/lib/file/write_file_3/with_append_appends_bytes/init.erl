@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Filename = "/tmp/lumen_file_write_file_3.txt",
+  ok = file:write_file(Filename, <<"hello">>),
+  ok = file:write_file(Filename, <<" world">>, [append]),
+  display(file:read_file(Filename)),
+  ok = file:delete(Filename).
@@ -0,0 +1,8 @@
+#[path = "gen_tcp/accept_2.rs"]
+mod accept_2;
+#[path = "gen_tcp/connect_3.rs"]
+mod connect_3;
+#[path = "gen_tcp/controlling_process_2.rs"]
+mod controlling_process_2;
+#[path = "gen_tcp/recv_2.rs"]
+mod recv_2;
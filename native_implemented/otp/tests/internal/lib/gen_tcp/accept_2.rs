@@ -0,0 +1,4 @@
+test_stdout!(
+    without_connection_within_timeout_returns_timeout_error,
+    "{error, timeout}\n"
+);
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, ListenSocket} = gen_tcp:listen(47100, [{active, false}]),
+  display(gen_tcp:accept(ListenSocket, 10)),
+  ok = gen_tcp:close(ListenSocket).
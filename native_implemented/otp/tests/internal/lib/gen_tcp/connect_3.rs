@@ -0,0 +1,4 @@
+test_stdout!(
+    with_active_socket_sends_tcp_messages_to_owner,
+    "{tcp, <<\"hello\">>}\ntcp_closed\n"
+);
@@ -0,0 +1,23 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, ListenSocket} = gen_tcp:listen(47101, [{active, false}]),
+  spawn(fun () ->
+    {ok, AcceptedSocket} = gen_tcp:accept(ListenSocket),
+    ok = gen_tcp:send(AcceptedSocket, <<"hello">>),
+    ok = gen_tcp:close(AcceptedSocket)
+  end),
+  {ok, Socket} = gen_tcp:connect({127, 0, 0, 1}, 47101, [binary, {active, true}]),
+  receive
+    {tcp, Socket, Data} -> display({tcp, Data})
+  after 1000 ->
+    display(timeout)
+  end,
+  receive
+    {tcp_closed, Socket} -> display(tcp_closed)
+  after 1000 ->
+    display(timeout)
+  end,
+  ok = gen_tcp:close(ListenSocket).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_owner_sends_messages_to_new_owner,
+    "{error, not_owner}\n{worker, <<\"hello\">>}\n"
+);
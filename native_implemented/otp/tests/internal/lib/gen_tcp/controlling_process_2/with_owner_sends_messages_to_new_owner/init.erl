@@ -0,0 +1,32 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  {ok, ListenSocket} = gen_tcp:listen(47104, [binary, {active, true}]),
+  Worker = spawn(fun () ->
+    receive
+      {tcp, _, Data} -> Parent ! {worker, Data}
+    after 1000 ->
+      Parent ! {worker, timeout}
+    end
+  end),
+  spawn(fun () ->
+    {ok, ConnectedSocket} = gen_tcp:connect({127, 0, 0, 1}, 47104, [{active, false}]),
+    receive
+    after 100 ->
+      ok
+    end,
+    ok = gen_tcp:send(ConnectedSocket, <<"hello">>),
+    ok = gen_tcp:close(ConnectedSocket)
+  end),
+  {ok, Socket} = gen_tcp:accept(ListenSocket),
+  ok = gen_tcp:controlling_process(Socket, Worker),
+  display(gen_tcp:controlling_process(Socket, self())),
+  receive
+    {worker, Received} -> display({worker, Received})
+  after 2000 ->
+    display(timeout)
+  end,
+  ok = gen_tcp:close(ListenSocket).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_passive_socket_returns_sent_bytes,
+    "{ok, \"hello\"}\n{error, closed}\n"
+);
@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, ListenSocket} = gen_tcp:listen(47102, [{active, false}]),
+  spawn(fun () ->
+    {ok, ConnectedSocket} = gen_tcp:connect("localhost", 47102, [{active, false}]),
+    ok = gen_tcp:send(ConnectedSocket, "hello"),
+    ok = gen_tcp:close(ConnectedSocket)
+  end),
+  {ok, Socket} = gen_tcp:accept(ListenSocket),
+  display(gen_tcp:recv(Socket, 5)),
+  display(gen_tcp:recv(Socket, 0)),
+  ok = gen_tcp:close(Socket),
+  ok = gen_tcp:close(ListenSocket).
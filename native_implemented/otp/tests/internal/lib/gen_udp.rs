@@ -0,0 +1,4 @@
+#[path = "gen_udp/open_2.rs"]
+mod open_2;
+#[path = "gen_udp/recv_2.rs"]
+mod recv_2;
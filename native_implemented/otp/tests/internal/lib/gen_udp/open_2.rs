@@ -0,0 +1,4 @@
+test_stdout!(
+    with_active_socket_sends_udp_messages_to_owner,
+    "{udp, {127, 0, 0, 1}, 47111, <<\"hello\">>}\n"
+);
@@ -0,0 +1,15 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, Socket} = gen_udp:open(47110, [binary, {active, true}]),
+  {ok, SenderSocket} = gen_udp:open(47111),
+  ok = gen_udp:send(SenderSocket, {127, 0, 0, 1}, 47110, <<"hello">>),
+  receive
+    {udp, Socket, IP, InPortNo, Packet} -> display({udp, IP, InPortNo, Packet})
+  after 1000 ->
+    display(timeout)
+  end,
+  ok = gen_udp:close(SenderSocket),
+  ok = gen_udp:close(Socket).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_passive_socket_returns_address_port_and_packet,
+    "{ok, {{0, 0, 0, 0, 0, 0, 0, 1}, 47113, \"hi\"}}\n{error, timeout}\n"
+);
@@ -0,0 +1,12 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, Socket} = gen_udp:open(47112, [inet6, {active, false}]),
+  {ok, SenderSocket} = gen_udp:open(47113, [inet6]),
+  ok = gen_udp:send(SenderSocket, {0, 0, 0, 0, 0, 0, 0, 1}, 47112, "hi"),
+  display(gen_udp:recv(Socket, 0)),
+  display(gen_udp:recv(Socket, 0, 10)),
+  ok = gen_udp:close(SenderSocket),
+  ok = gen_udp:close(Socket).
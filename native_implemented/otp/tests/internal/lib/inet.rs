@@ -0,0 +1,10 @@
+#[path = "inet/getaddr_2.rs"]
+mod getaddr_2;
+#[path = "inet/gethostbyname_1.rs"]
+mod gethostbyname_1;
+#[path = "inet/ntoa_1.rs"]
+mod ntoa_1;
+#[path = "inet/parse_address_1.rs"]
+mod parse_address_1;
+#[path = "inet/setopts_2.rs"]
+mod setopts_2;
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_localhost_returns_loopback_address,
+    "{ok, {127, 0, 0, 1}}\n{ok, {127, 0, 0, 1}}\n{ok, {0, 0, 0, 0, 0, 65535, 32512, 1}}\n{error, einval}\n"
+);
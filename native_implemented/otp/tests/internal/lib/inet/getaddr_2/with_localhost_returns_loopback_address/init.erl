@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(inet:getaddr("localhost", inet)),
+  display(inet:getaddr({127, 0, 0, 1}, inet)),
+  display(inet:getaddr({127, 0, 0, 1}, inet6)),
+  display(inet:getaddr({0, 0, 0, 0, 0, 0, 0, 1}, inet)).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_localhost_returns_hostent,
+    "{\"localhost\", [], inet, 4}\n"
+);
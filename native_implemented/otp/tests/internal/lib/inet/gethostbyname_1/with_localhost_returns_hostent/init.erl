@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, {hostent, Name, Aliases, AddrType, Length, [_ | _]}} = inet:gethostbyname(localhost),
+  display({Name, Aliases, AddrType, Length}).
@@ -0,0 +1 @@
+test_stdout!(with_ipv6_address_returns_string, "\"::1\"\n\"10.0.0.1\"\n");
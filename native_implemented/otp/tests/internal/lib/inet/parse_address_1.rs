@@ -0,0 +1,4 @@
+test_stdout!(
+    with_ipv4_address_returns_ok_tuple,
+    "{ok, {10, 0, 0, 1}}\n{error, einval}\n"
+);
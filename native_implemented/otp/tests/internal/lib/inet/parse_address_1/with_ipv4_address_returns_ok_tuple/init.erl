@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(inet:parse_address("10.0.0.1")),
+  display(inet:parse_address("10.0.0.256")).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_active_once_sends_next_message_after_rearming,
+    "{tcp, <<\"hello\">>}\npassive\n{tcp, <<\"world\">>}\n"
+);
@@ -0,0 +1,35 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, ListenSocket} = gen_tcp:listen(47103, [{active, false}]),
+  spawn(fun () ->
+    {ok, AcceptedSocket} = gen_tcp:accept(ListenSocket),
+    ok = gen_tcp:send(AcceptedSocket, <<"hello">>),
+    receive
+    after 100 ->
+      ok
+    end,
+    ok = gen_tcp:send(AcceptedSocket, <<"world">>),
+    ok = gen_tcp:close(AcceptedSocket)
+  end),
+  {ok, Socket} = gen_tcp:connect({127, 0, 0, 1}, 47103, [binary, {active, once}]),
+  receive
+    {tcp, Socket, First} -> display({tcp, First})
+  after 1000 ->
+    display(timeout)
+  end,
+  receive
+    {tcp, Socket, _} -> display(active)
+  after 300 ->
+    display(passive)
+  end,
+  ok = inet:setopts(Socket, [{active, once}]),
+  receive
+    {tcp, Socket, Second} -> display({tcp, Second})
+  after 1000 ->
+    display(timeout)
+  end,
+  ok = gen_tcp:close(Socket),
+  ok = gen_tcp:close(ListenSocket).
@@ -0,0 +1,4 @@
+#[path = "lists/foldl_3.rs"]
+mod foldl_3;
+#[path = "lists/map_2.rs"]
+mod map_2;
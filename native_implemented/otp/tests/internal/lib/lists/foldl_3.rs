@@ -0,0 +1 @@
+test_stdout!(with_function_returns_accumulator, "[3, 2, 1]\n");
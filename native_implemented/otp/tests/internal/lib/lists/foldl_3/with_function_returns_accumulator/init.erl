@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Reversed = lists:foldl(fun (Element, Acc) ->
+    [Element | Acc]
+  end, [], [1, 2, 3]),
+  display(Reversed).
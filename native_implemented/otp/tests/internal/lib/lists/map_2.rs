@@ -0,0 +1 @@
+test_stdout!(with_function_returns_mapped_list, "[2, 4, 6]\n");
@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Doubled = lists:map(fun (Element) ->
+    Element * 2
+  end, [1, 2, 3]),
+  display(Doubled).
@@ -0,0 +1,4 @@
+#[path = "logger/log_3.rs"]
+mod log_3;
+#[path = "logger/set_primary_config_2.rs"]
+mod set_primary_config_2;
@@ -0,0 +1,7 @@
+test_stdout!(with_format_writes_to_stdout, "notice: one is two\n");
+test_stdout!(
+    with_report_writes_key_value_lines,
+    "warning:     a: 1\n    b: two\n"
+);
+test_stdout!(below_primary_level_writes_nothing, "ok\n");
+test_stderr_substrings!(with_error_writes_to_stderr, vec!["error: failed: reason\n"]);
@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  ok = logger:log(info, "hidden", #{}),
+  display(ok).
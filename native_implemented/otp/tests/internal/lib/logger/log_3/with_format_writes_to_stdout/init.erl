@@ -0,0 +1,5 @@
+-module(init).
+-export([start/0]).
+
+start() ->
+  ok = logger:log(notice, "~w is ~s", [one, "two"]).
@@ -0,0 +1,5 @@
+-module(init).
+-export([start/0]).
+
+start() ->
+  ok = logger:log(warning, #{a => 1, b => "two"}, #{}).
@@ -0,0 +1 @@
+test_stdout!(with_level_changes_what_is_written, "debug: shown\n");
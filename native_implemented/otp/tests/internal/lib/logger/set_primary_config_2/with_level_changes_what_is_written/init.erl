@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+
+start() ->
+  ok = logger:log(debug, "hidden", #{}),
+  ok = logger:set_primary_config(level, debug),
+  ok = logger:log(debug, "shown", #{}).
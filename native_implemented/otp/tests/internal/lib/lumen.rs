@@ -0,0 +1,2 @@
+#[path = "lumen/is_big_integer_1.rs"]
+mod is_big_integer_1;
@@ -0,0 +1,16 @@
+test_stdout!(
+    with_add_and_sub_promotes_and_demotes,
+    "true\nfalse\n1\ntrue\nfalse\n-1\n"
+);
+test_stdout!(
+    with_mul_and_div_promotes_and_demotes,
+    "true\nfalse\ntrue\nfalse\n2\n"
+);
+test_stdout!(
+    with_bitwise_promotes_and_demotes,
+    "true\nfalse\n1\nfalse\n0\ntrue\nfalse\n1\n"
+);
+test_stdout!(
+    with_bitshift_promotes_and_demotes,
+    "true\nfalse\n3\ntrue\nfalse\n-1\n"
+);
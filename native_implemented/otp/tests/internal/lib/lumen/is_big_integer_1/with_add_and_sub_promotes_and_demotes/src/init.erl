@@ -0,0 +1,18 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_big_integer/1]).
+
+start() ->
+  Positive = test_big_integer:positive(),
+  Sum = Positive + 1,
+  display(is_big_integer(Sum)),
+  PositiveDifference = Sum - Positive,
+  display(is_big_integer(PositiveDifference)),
+  display(PositiveDifference),
+  Negative = test_big_integer:negative(),
+  Difference = Negative - 1,
+  display(is_big_integer(Difference)),
+  NegativeSum = Difference - Negative,
+  display(is_big_integer(NegativeSum)),
+  display(NegativeSum).
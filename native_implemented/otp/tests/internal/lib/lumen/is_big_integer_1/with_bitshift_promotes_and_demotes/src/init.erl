@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_big_integer/1]).
+
+start() ->
+  Left = 3 bsl 62,
+  display(is_big_integer(Left)),
+  Right = Left bsr 62,
+  display(is_big_integer(Right)),
+  display(Right),
+  NegativeLeft = -1 bsl 64,
+  display(is_big_integer(NegativeLeft)),
+  NegativeRight = NegativeLeft bsr 128,
+  display(is_big_integer(NegativeRight)),
+  display(NegativeRight).
@@ -0,0 +1,20 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_big_integer/1]).
+
+start() ->
+  Positive = test_big_integer:positive(),
+  Or = Positive bor 1,
+  display(is_big_integer(Or)),
+  And = Or band 1,
+  display(is_big_integer(And)),
+  display(And),
+  Xor = Positive bxor Positive,
+  display(is_big_integer(Xor)),
+  display(Xor),
+  NegativeOr = test_big_integer:negative() bor 1,
+  display(is_big_integer(NegativeOr)),
+  NegativeAnd = NegativeOr band 3,
+  display(is_big_integer(NegativeAnd)),
+  display(NegativeAnd).
@@ -0,0 +1,15 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+-import(lumen, [is_big_integer/1]).
+
+start() ->
+  Factor = 4294967296,
+  Product = Factor * Factor,
+  display(is_big_integer(Product)),
+  Quotient = Product div Factor,
+  display(is_big_integer(Quotient)),
+  display(Quotient == Factor),
+  Remainder = Product rem 7,
+  display(is_big_integer(Remainder)),
+  display(Remainder).
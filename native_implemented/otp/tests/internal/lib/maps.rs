@@ -1,2 +1,8 @@
+#[path = "maps/fold_3.rs"]
+mod fold_3;
 #[path = "maps/from_list_1.rs"]
 mod from_list_1;
+#[path = "maps/update_with_3.rs"]
+mod update_with_3;
+#[path = "maps/update_with_4.rs"]
+mod update_with_4;
@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Map = #{a => 1, b => 2, c => 3},
+  Sum = maps:fold(fun (_Key, Value, Acc) ->
+    Value + Acc
+  end, 0, Map),
+  display(Sum).
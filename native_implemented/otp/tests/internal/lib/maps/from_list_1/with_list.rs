@@ -14,3 +14,7 @@ test_stdout!(
     with_duplicate_keys_preserves_last_value,
     "#{key => last_value}\n"
 );
+test_stdout!(
+    with_more_than_32_keys_returns_map_with_every_key,
+    "100\n154\ntrue\nfalse\n"
+);
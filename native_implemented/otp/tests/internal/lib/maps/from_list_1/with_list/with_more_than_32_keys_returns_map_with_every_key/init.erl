@@ -0,0 +1,11 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  List = [{Key, Key * 2} || Key <- lists:seq(1, 100)],
+  Map = maps:from_list(List),
+  display(maps:size(Map)),
+  display(maps:get(77, Map)),
+  display(Map =:= maps:from_list(lists:reverse(List))),
+  display(maps:is_key(77, maps:remove(77, Map))).
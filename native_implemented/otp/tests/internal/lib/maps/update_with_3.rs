@@ -0,0 +1 @@
+test_stdout!(with_key_returns_updated_map, "#{key => 2}\n");
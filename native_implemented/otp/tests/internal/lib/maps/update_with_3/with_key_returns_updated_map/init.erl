@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Map = maps:update_with(key, fun (Value) ->
+    Value + 1
+  end, #{key => 1}),
+  display(Map).
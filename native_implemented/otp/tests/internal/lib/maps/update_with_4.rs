@@ -0,0 +1 @@
+test_stdout!(without_key_returns_map_with_initial, "#{key => initial}\n");
@@ -0,0 +1,2 @@
+#[path = "os/cmd_1.rs"]
+mod cmd_1;
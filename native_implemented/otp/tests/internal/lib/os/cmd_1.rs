@@ -0,0 +1,2 @@
+test_stdout!(with_command_returns_stdout, "\"hello\"\n");
+test_stdout!(with_command_returns_stderr, "\"hello\"\n");
@@ -0,0 +1,2 @@
+#[path = "persistent_term/get_2.rs"]
+mod get_2;
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_put_and_erase_returns_value_then_default,
+    "{debug, true}\ntrue\ndefault\n"
+);
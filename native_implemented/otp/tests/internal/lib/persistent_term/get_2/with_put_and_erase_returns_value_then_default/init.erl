@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  ok = persistent_term:put(config, {debug, true}),
+  display(persistent_term:get(config, default)),
+  display(persistent_term:erase(config)),
+  display(persistent_term:get(config, default)).
@@ -0,0 +1,8 @@
+test_stdout!(
+    with_non_matching_message_receives_it_later,
+    "second\nfirst\nthird\n"
+);
+test_stdout!(
+    with_message_sent_while_waiting_receives_skipped_message_later,
+    "late\nearly\n"
+);
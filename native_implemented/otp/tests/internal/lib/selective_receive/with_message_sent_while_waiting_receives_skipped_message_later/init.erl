@@ -0,0 +1,16 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Parent = self(),
+  Parent ! early,
+  spawn(fun () ->
+    Parent ! late
+  end),
+  receive
+    late -> display(late)
+  end,
+  receive
+    Early -> display(Early)
+  end.
@@ -0,0 +1,17 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  self() ! first,
+  self() ! second,
+  self() ! third,
+  receive
+    second -> display(second)
+  end,
+  receive
+    First -> display(First)
+  end,
+  receive
+    Third -> display(Third)
+  end.
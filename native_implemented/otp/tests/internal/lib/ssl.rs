@@ -0,0 +1,4 @@
+#[path = "ssl/connect_3.rs"]
+mod connect_3;
+#[path = "ssl/handshake_2.rs"]
+mod handshake_2;
@@ -0,0 +1 @@
+test_stdout!(without_cacertfile_issuer_returns_unknown_ca, "unknown_ca\n");
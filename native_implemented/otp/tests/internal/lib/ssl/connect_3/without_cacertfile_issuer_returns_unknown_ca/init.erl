@@ -0,0 +1,17 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Directory = "tests/internal/lib/ssl/handshake_2/with_certfile_and_keyfile_upgrades_gen_tcp_socket/",
+  {ok, ListenSocket} = gen_tcp:listen(47121, [binary, {active, false}]),
+  spawn(fun () ->
+    {ok, AcceptedSocket} = gen_tcp:accept(ListenSocket),
+    ssl:handshake(AcceptedSocket, [
+      {certfile, Directory ++ "cert.pem"},
+      {keyfile, Directory ++ "key.pem"}
+    ])
+  end),
+  {error, {tls_alert, {Alert, _}}} = ssl:connect("localhost", 47121, [binary, {active, false}]),
+  display(Alert),
+  ok = gen_tcp:close(ListenSocket).
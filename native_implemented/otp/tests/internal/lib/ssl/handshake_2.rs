@@ -0,0 +1,4 @@
+test_stdout!(
+    with_certfile_and_keyfile_upgrades_gen_tcp_socket,
+    "{ok, <<\"pong\">>}\n{error, closed}\n"
+);
@@ -0,0 +1,34 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  Directory = "tests/internal/lib/ssl/handshake_2/with_certfile_and_keyfile_upgrades_gen_tcp_socket/",
+  {ok, ListenSocket} = gen_tcp:listen(47120, [binary, {active, false}]),
+  Parent = self(),
+  spawn(fun () ->
+    {ok, AcceptedSocket} = gen_tcp:accept(ListenSocket),
+    {ok, ServerSocket} = ssl:handshake(AcceptedSocket, [
+      binary,
+      {active, false},
+      {certfile, Directory ++ "cert.pem"},
+      {keyfile, Directory ++ "key.pem"}
+    ]),
+    {ok, <<"ping">>} = ssl:recv(ServerSocket, 4),
+    ok = ssl:send(ServerSocket, <<"pong">>),
+    Parent ! {server, ssl:recv(ServerSocket, 0)}
+  end),
+  {ok, Socket} = ssl:connect("localhost", 47120, [
+    binary,
+    {active, false},
+    {cacertfile, Directory ++ "ca.pem"}
+  ]),
+  ok = ssl:send(Socket, <<"ping">>),
+  display(ssl:recv(Socket, 4, 1000)),
+  ok = ssl:close(Socket),
+  receive
+    {server, Received} -> display(Received)
+  after 1000 ->
+    display(timeout)
+  end,
+  ok = gen_tcp:close(ListenSocket).
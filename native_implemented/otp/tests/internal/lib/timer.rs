@@ -0,0 +1,14 @@
+#[path = "timer/apply_after_4.rs"]
+mod apply_after_4;
+#[path = "timer/send_after_2.rs"]
+mod send_after_2;
+#[path = "timer/send_after_3.rs"]
+mod send_after_3;
+#[path = "timer/send_interval_3.rs"]
+mod send_interval_3;
+#[path = "timer/sleep_1.rs"]
+mod sleep_1;
+#[path = "timer/tc_1.rs"]
+mod tc_1;
+#[path = "timer/tc_2.rs"]
+mod tc_2;
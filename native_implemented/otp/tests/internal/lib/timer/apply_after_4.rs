@@ -0,0 +1,5 @@
+test_stdout!(with_time_spawns_process_applying_function, "applied\n");
+test_stdout!(
+    without_valid_arguments_returns_error_badarg,
+    "{error, badarg}\n{error, badarg}\n"
+);
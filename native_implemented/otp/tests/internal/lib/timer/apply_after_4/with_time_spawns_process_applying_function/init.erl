@@ -0,0 +1,9 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, _} = timer:apply_after(5, erlang, send, [self(), applied]),
+  receive
+    Message -> display(Message)
+  end.
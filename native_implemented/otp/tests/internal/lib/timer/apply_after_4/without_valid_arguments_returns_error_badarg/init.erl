@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(timer:apply_after(5, <<"erlang">>, send, [])),
+  display(timer:apply_after(5, erlang, send, improper)).
@@ -0,0 +1 @@
+test_stdout!(with_time_sends_message_to_self, "true\nmessage\n");
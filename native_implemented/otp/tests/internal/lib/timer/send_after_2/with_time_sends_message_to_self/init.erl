@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, Reference} = timer:send_after(5, message),
+  display(is_reference(Reference)),
+  receive
+    Message -> display(Message)
+  end.
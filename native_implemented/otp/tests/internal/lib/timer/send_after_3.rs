@@ -0,0 +1,5 @@
+test_stdout!(with_registered_name_sends_message, "message\n");
+test_stdout!(
+    without_valid_arguments_returns_error_badarg,
+    "{error, badarg}\n{error, badarg}\n"
+);
@@ -0,0 +1,10 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  register(receiver, self()),
+  {ok, _} = timer:send_after(5, receiver, message),
+  receive
+    Message -> display(Message)
+  end.
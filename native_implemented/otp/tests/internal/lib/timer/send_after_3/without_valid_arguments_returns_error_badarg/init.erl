@@ -0,0 +1,7 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  display(timer:send_after(-1, self(), message)),
+  display(timer:send_after(5, <<"receiver">>, message)).
@@ -0,0 +1,4 @@
+test_stdout!(
+    with_time_sends_message_until_canceled,
+    "tick\ntick\ntick\ncanceled\n"
+);
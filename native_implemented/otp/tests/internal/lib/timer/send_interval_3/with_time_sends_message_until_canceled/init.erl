@@ -0,0 +1,20 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {ok, Reference} = timer:send_interval(5, self(), tick),
+  receive_tick(),
+  receive_tick(),
+  receive_tick(),
+  erlang:cancel_timer(Reference),
+  receive
+    tick -> display(tick)
+  after
+    20 -> display(canceled)
+  end.
+
+receive_tick() ->
+  receive
+    tick -> display(tick)
+  end.
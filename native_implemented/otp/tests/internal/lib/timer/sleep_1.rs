@@ -0,0 +1,8 @@
+test_stdout!(
+    with_time_waits_until_time_even_with_message,
+    "ok\ntrue\nmessage\n"
+);
+test_stdout!(
+    without_time_errors_badarg,
+    "{caught, error, badarg}\n{caught, error, badarg}\n"
+);
@@ -0,0 +1,13 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  self() ! message,
+  Before = erlang:monotonic_time(millisecond),
+  display(timer:sleep(10)),
+  After = erlang:monotonic_time(millisecond),
+  display(After - Before >= 10),
+  receive
+    Message -> display(Message)
+  end.
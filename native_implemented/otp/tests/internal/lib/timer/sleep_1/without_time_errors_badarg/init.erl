@@ -0,0 +1,14 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  test(-1),
+  test(atom).
+
+test(Time) ->
+  try timer:sleep(Time) of
+    _ -> display(slept)
+  catch
+    Class:Exception -> display({caught, Class, Exception})
+  end.
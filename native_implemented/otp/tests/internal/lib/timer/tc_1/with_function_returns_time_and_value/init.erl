@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {Time, Value} = timer:tc(fun () -> value end),
+  display(is_integer(Time)),
+  display(Value).
@@ -0,0 +1 @@
+test_stdout!(with_function_returns_time_and_value, "true\n3\n");
@@ -0,0 +1,8 @@
+-module(init).
+-export([start/0]).
+-import(erlang, [display/1]).
+
+start() ->
+  {Time, Value} = timer:tc(fun (A, B) -> A + B end, [1, 2]),
+  display(is_integer(Time)),
+  display(Value).
@@ -13,6 +13,7 @@ use crate::executor::{self, Executor};
 use crate::promise;
 use crate::runtime::process::spawn::options::Options;
 use crate::runtime::scheduler;
+use crate::scheduler_loop;
 
 use liblumen_alloc::erts::term::list::optional_cons_to_term;
 use liblumen_otp::erlang::apply::arguments_term_to_vec;
@@ -88,5 +89,7 @@ pub fn promise(
 
     std::mem::drop(executor_non_null_heap_fragment);
 
+    scheduler_loop::wake();
+
     Ok(promise)
 }
@@ -9,6 +9,7 @@ use std::convert::TryInto;
 use std::mem;
 
 use anyhow::*;
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::Document;
 
 use liblumen_alloc::erts::exception;
@@ -30,7 +31,15 @@ fn from_term(term: Term) -> Result<&'static Document, exception::Exception> {
         .with_context(|| format!("{} must be a document resource", term))?;
     let document_reference: Resource = boxed.into();
 
-    match document_reference.downcast_ref() {
+    // A `JsValue`, such as from `dom` or `js:call/3`, may also be a document
+    let option_document: Option<&Document> = match document_reference.downcast_ref() {
+        Some(document) => Some(document),
+        None => document_reference
+            .downcast_ref::<JsValue>()
+            .and_then(|js_value| js_value.dyn_ref()),
+    };
+
+    match option_document {
         Some(document) => {
             let static_document: &'static Document =
                 unsafe { mem::transmute::<&Document, _>(document) };
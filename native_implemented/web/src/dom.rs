@@ -0,0 +1,79 @@
+//! Builds and queries the page's DOM from Lumen processes.
+//!
+//! ```erlang
+//! {ok, Div} = dom:create_element(<<"div">>),
+//! ok = dom:set_attribute(Div, <<"class">>, <<"greeting">>),
+//! ok = dom:set_text_content(Div, <<"Hello">>),
+//! {ok, Body} = dom:query_selector(<<"body">>),
+//! ok = dom:append_child(Body, Div).
+//! ```
+//!
+//! Nodes are JS values, like those of the `js` module, so either module, or the `Lumen.Web`
+//! modules, such as `Lumen.Web.Node`, can be used on them. Failed DOM operations return errors
+//! like the `Lumen.Web` modules: `{error, {syntax, Message}}` for invalid selectors, and otherwise
+//! `{error, Reason}`, where `Reason` is the snake case name of the `DOMException`, such as
+//! `hierarchy_request` for `HierarchyRequestError`.
+
+pub mod append_child_2;
+pub mod create_element_1;
+pub mod query_selector_1;
+pub mod query_selector_2;
+pub mod remove_child_2;
+pub mod set_attribute_3;
+pub mod set_text_content_2;
+pub mod text_content_1;
+
+use anyhow::*;
+
+use wasm_bindgen::{JsCast, JsValue};
+
+use web_sys::{Document, Element};
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js_value;
+
+pub fn module() -> Atom {
+    Atom::from_str("dom")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+fn document() -> exception::Result<Document> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .context("window has no document")
+        .map_err(From::from)
+}
+
+fn ok_tuple(process: &Process, js_value: JsValue) -> Term {
+    process.tuple_from_slice(&[atom!("ok"), js_value::to_term(js_value, process)])
+}
+
+/// Returns `{ok, Element}`, `none` if nothing matches `selectors`, or
+/// `{error, {syntax, Message}}` if `selectors` is invalid.
+fn query_selector_result_to_term(
+    process: &Process,
+    result: Result<Option<Element>, JsValue>,
+) -> Term {
+    match result {
+        Ok(Some(element)) => ok_tuple(process, element.into()),
+        Ok(None) => atom!("none"),
+        Err(js_value) => crate::error_tuple(process, js_value),
+    }
+}
+
+/// `term` as the JS `interface`, such as an `Element` from `dom:create_element/1` or
+/// `js:call/3`.
+fn term_try_into_js<T: JsCast>(name: &str, term: Term, interface: &str) -> exception::Result<T> {
+    js_value::try_from_term(term)
+        .ok()
+        .and_then(|js_value| js_value.dyn_into().ok())
+        .with_context(|| format!("{} ({}) is not a JS {}", name, term, interface))
+        .map_err(From::from)
+}
@@ -0,0 +1,21 @@
+use web_sys::Node;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns `ok` once `child` is the last child of `parent`, moving it if it is already in the
+/// document, or `{error, hierarchy_request}` if `child` contains `parent`.
+#[native_implemented::function(dom:append_child/2)]
+pub fn result(process: &Process, parent: Term, child: Term) -> exception::Result<Term> {
+    let parent_node: Node = super::term_try_into_js("parent", parent, "Node")?;
+    let child_node: Node = super::term_try_into_js("child", child, "Node")?;
+
+    let result_term = match parent_node.append_child(&child_node) {
+        Ok(_) => atom!("ok"),
+        Err(js_value) => crate::error_tuple(process, js_value),
+    };
+
+    Ok(result_term)
+}
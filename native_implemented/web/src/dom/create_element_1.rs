@@ -0,0 +1,19 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Returns `{ok, Element}` with a new element with `tag` in the page's document, or
+/// `{error, invalid_character}` if `tag` is not a valid name.
+#[native_implemented::function(dom:create_element/1)]
+pub fn result(process: &Process, tag: Term) -> exception::Result<Term> {
+    let tag_string = binary_to_string(tag)?;
+
+    let result_term = match super::document()?.create_element(&tag_string) {
+        Ok(element) => super::ok_tuple(process, element.into()),
+        Err(js_value) => crate::error_tuple(process, js_value),
+    };
+
+    Ok(result_term)
+}
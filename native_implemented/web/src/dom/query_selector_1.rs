@@ -0,0 +1,15 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Returns `{ok, Element}` with the first element in the page's document that matches the CSS
+/// `selectors`, `none` if none do, or `{error, {syntax, Message}}` if `selectors` is invalid.
+#[native_implemented::function(dom:query_selector/1)]
+pub fn result(process: &Process, selectors: Term) -> exception::Result<Term> {
+    let selectors_string = binary_to_string(selectors)?;
+    let result = super::document()?.query_selector(&selectors_string);
+
+    Ok(super::query_selector_result_to_term(process, result))
+}
@@ -0,0 +1,31 @@
+use anyhow::*;
+
+use wasm_bindgen::JsCast;
+
+use web_sys::{Document, Element};
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js_value;
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Like `dom:query_selector/1`, but only matches descendants of the `root` element or document.
+#[native_implemented::function(dom:query_selector/2)]
+pub fn result(process: &Process, root: Term, selectors: Term) -> exception::Result<Term> {
+    let root_js_value = js_value::try_from_term(root)
+        .ok()
+        .filter(|js_value| js_value.has_type::<Element>() || js_value.has_type::<Document>())
+        .with_context(|| format!("root ({}) is not a JS Element or Document", root))?;
+    let selectors_string = binary_to_string(selectors)?;
+
+    let result = match root_js_value.dyn_ref::<Element>() {
+        Some(element) => element.query_selector(&selectors_string),
+        None => root_js_value
+            .unchecked_ref::<Document>()
+            .query_selector(&selectors_string),
+    };
+
+    Ok(super::query_selector_result_to_term(process, result))
+}
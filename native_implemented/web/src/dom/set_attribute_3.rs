@@ -0,0 +1,29 @@
+use web_sys::Element;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Returns `ok` once the attribute `name` of `element` is `value`, or
+/// `{error, invalid_character}` if `name` is not a valid name.
+#[native_implemented::function(dom:set_attribute/3)]
+pub fn result(
+    process: &Process,
+    element: Term,
+    name: Term,
+    value: Term,
+) -> exception::Result<Term> {
+    let element_element: Element = super::term_try_into_js("element", element, "Element")?;
+    let name_string = binary_to_string(name)?;
+    let value_string = binary_to_string(value)?;
+
+    let result_term = match element_element.set_attribute(&name_string, &value_string) {
+        Ok(()) => atom!("ok"),
+        Err(js_value) => crate::error_tuple(process, js_value),
+    };
+
+    Ok(result_term)
+}
@@ -0,0 +1,18 @@
+use web_sys::Node;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Replaces the children of `node` with `text` and returns `ok`.
+#[native_implemented::function(dom:set_text_content/2)]
+pub fn result(node: Term, text: Term) -> exception::Result<Term> {
+    let node_node: Node = super::term_try_into_js("node", node, "Node")?;
+    let text_string = binary_to_string(text)?;
+
+    node_node.set_text_content(Some(&text_string));
+
+    Ok(atom!("ok"))
+}
@@ -0,0 +1,19 @@
+use web_sys::Node;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+/// Returns the text of `node` and its descendants as a binary, or `null` for a document.
+#[native_implemented::function(dom:text_content/1)]
+pub fn result(process: &Process, node: Term) -> exception::Result<Term> {
+    let node_node: Node = super::term_try_into_js("node", node, "Node")?;
+
+    let text_term = match node_node.text_content() {
+        Some(text) => process.binary_from_str(&text),
+        None => atom!("null"),
+    };
+
+    Ok(text_term)
+}
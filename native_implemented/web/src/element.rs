@@ -6,7 +6,7 @@ use std::convert::TryInto;
 use std::mem;
 
 use anyhow::*;
-use wasm_bindgen::JsCast;
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{Element, HtmlBodyElement, HtmlElement, HtmlTableElement, Node};
 
 use liblumen_alloc::erts::exception::InternalResult;
@@ -63,9 +63,22 @@ fn from_term(term: Term) -> InternalResult<&'static Element> {
             }
             None => Err(anyhow!("{} is a Node, but not an Element", term).into()),
         }
+    } else if resource_reference.is::<JsValue>() {
+        // Such as from `dom` or `js:call/3`
+        let js_value: &JsValue = resource_reference.downcast_ref().unwrap();
+
+        match js_value.dyn_ref() {
+            Some(element) => {
+                let static_element: &'static Element =
+                    unsafe { mem::transmute::<&Element, &'static Element>(element) };
+
+                Ok(static_element)
+            }
+            None => Err(anyhow!("{} is a JS value, but not an Element", term).into()),
+        }
     } else {
         Err(anyhow!(
-            "{} is a resource, but not an Element, HTMLBodyElement, HTMLElement, HTMLTableElement, or Node", term
+            "{} is a resource, but not an Element, HTMLBodyElement, HTMLElement, HTMLTableElement, Node, or JS value", term
         ).into())
     }
 }
@@ -6,6 +6,7 @@ use wasm_bindgen::JsValue;
 
 use js_sys::{Function, Promise};
 
+use liblumen_alloc::erts::exception;
 use liblumen_alloc::erts::term::prelude::*;
 
 use super::js_value;
@@ -54,11 +55,16 @@ impl Executor {
         }
     }
 
-    pub fn resolve(&mut self, term: Term) {
+    /// Errors `badarg` if `term` cannot be converted to a `JsValue`, in which case the executor is
+    /// still pending, so it rejects the promise when it is dropped.
+    pub fn resolve(&mut self, term: Term) -> exception::Result<()> {
         match &self.state {
             State::Pending { resolve, .. } => {
-                drop(resolve.call1(&JsValue::undefined(), &js_value::from_term(term)));
+                let js_value = js_value::from_term(term)?;
+                drop(resolve.call1(&JsValue::undefined(), &js_value));
                 self.state = State::Resolved;
+
+                Ok(())
             }
             _ => panic!("Can only resolve executor when pending"),
         }
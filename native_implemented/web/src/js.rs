@@ -0,0 +1,68 @@
+//! Calls JavaScript from Lumen processes.
+//!
+//! ```elixir
+//! case :js.call(:global, "fetch", [url]) do
+//!   {:ok, value} -> value
+//!   {:promise, reference} ->
+//!     receive do
+//!       {^reference, {:ok, value}} -> value
+//!       {^reference, {:error, reason}} -> raise reason
+//!     end
+//!   {:error, reason} -> raise reason
+//! end
+//! ```
+//!
+//! JS values are converted with `crate::js_value`, so objects, such as DOM elements and events,
+//! are resource references that can be passed back to JS.
+
+pub mod call_3;
+pub mod subscribe_2;
+pub(crate) mod subscription;
+pub mod unsubscribe_1;
+
+use wasm_bindgen::JsValue;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js_value;
+use crate::runtime::registry;
+use crate::runtime::scheduler::Scheduled;
+use crate::scheduler_loop;
+
+fn module() -> Atom {
+    Atom::from_str("js")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
+
+/// Sends the message returned by `f` to the process with `pid` if it is still alive.
+///
+/// JS callbacks only run between scheduler runs, so the message is allocated directly on the
+/// process's heap, and then the scheduler loop is woken to run the process.
+pub(crate) fn send_from_js<F>(pid: Pid, f: F)
+where
+    F: FnOnce(&Process) -> Term,
+{
+    if let Some(arc_process) = registry::pid_to_process(&pid) {
+        let message = f(&arc_process);
+        arc_process.send_from_self(message);
+
+        if let Some(scheduler) = arc_process.scheduler() {
+            scheduler.stop_waiting(&arc_process);
+        }
+
+        scheduler_loop::wake();
+    }
+}
+
+fn error_tuple(process: &Process, js_value: JsValue) -> Term {
+    process.tuple_from_slice(&[atom!("error"), js_value::to_term(js_value, process)])
+}
+
+fn ok_tuple(process: &Process, js_value: JsValue) -> Term {
+    process.tuple_from_slice(&[atom!("ok"), js_value::to_term(js_value, process)])
+}
@@ -0,0 +1,121 @@
+//! ```elixir
+//! case :js.call(target, function, arguments) do
+//!   {:ok, value} -> ...
+//!   {:promise, reference} -> receive do {^reference, result} -> ... end
+//!   {:error, reason} -> ...
+//! end
+//! ```
+
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+
+use js_sys::{Array, Function, Promise, Reflect};
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js_value;
+use crate::runtime::binary_to_string::binary_to_string;
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+
+/// Calls the method named `function` on `target`, which is either `global` for the JS global
+/// object or a term that converts to a JS value, with `arguments` converted to JS values.
+///
+/// If the method returns a `Promise`, `{promise, reference}` is returned and the calling process
+/// is sent `{reference, {ok, value}}` when the promise is fulfilled or
+/// `{reference, {error, reason}}` when it is rejected.
+#[native_implemented::function(js:call/3)]
+pub fn result(
+    process: &Process,
+    target: Term,
+    function: Term,
+    arguments: Term,
+) -> exception::Result<Term> {
+    let target_js_value = target_to_js_value(target)?;
+    let function_string = binary_to_string(function)?;
+    let arguments_array = arguments_to_array(arguments)?;
+
+    let js_function: Function = Reflect::get(&target_js_value, &function_string.as_str().into())
+        .ok()
+        .and_then(|function_js_value| function_js_value.dyn_into().ok())
+        .with_context(|| {
+            format!(
+                "function ({}) is not the name of a function of target ({})",
+                function, target
+            )
+        })?;
+
+    let result_tuple = match js_function.apply(&target_js_value, &arguments_array) {
+        Ok(value) => match value.dyn_into::<Promise>() {
+            Ok(promise) => promise_tuple(process, promise),
+            Err(value) => super::ok_tuple(process, value),
+        },
+        Err(reason) => super::error_tuple(process, reason),
+    };
+
+    Ok(result_tuple)
+}
+
+// Private
+
+fn arguments_to_array(arguments: Term) -> anyhow::Result<Array> {
+    match arguments.decode().unwrap() {
+        TypedTerm::Nil | TypedTerm::List(_) => {
+            let array_js_value = js_value::try_from_term(arguments)
+                .with_context(|| format!("arguments ({}) cannot be converted to JS", arguments))?;
+
+            Ok(array_js_value.unchecked_into())
+        }
+        _ => Err(TypeError).with_context(|| format!("arguments ({}) is not a list", arguments)),
+    }
+}
+
+fn promise_tuple(process: &Process, promise: Promise) -> Term {
+    let reference = process.next_reference();
+    let boxed_reference: Boxed<Reference> = reference.try_into().unwrap();
+    let scheduler_id = boxed_reference.scheduler_id();
+    let number = boxed_reference.number();
+    let pid = process.pid();
+
+    let on_fulfilled: Closure<dyn FnMut(JsValue)> = Closure::once(move |value: JsValue| {
+        super::send_from_js(pid, |process| {
+            let reference = process.reference_from_scheduler(scheduler_id, number);
+            let ok_tuple = super::ok_tuple(process, value);
+
+            process.tuple_from_slice(&[reference, ok_tuple])
+        })
+    });
+    let on_rejected: Closure<dyn FnMut(JsValue)> = Closure::once(move |reason: JsValue| {
+        super::send_from_js(pid, |process| {
+            let reference = process.reference_from_scheduler(scheduler_id, number);
+            let error_tuple = super::error_tuple(process, reason);
+
+            process.tuple_from_slice(&[reference, error_tuple])
+        })
+    });
+
+    // The promise returned by `then` is not needed because the result is sent as a message
+    let _ = promise.then2(&on_fulfilled, &on_rejected);
+
+    // JS owns the closures until the promise settles.  Only one of them is ever called, so neither
+    // can free both, and they are leaked instead.
+    on_fulfilled.forget();
+    on_rejected.forget();
+
+    process.tuple_from_slice(&[atom!("promise"), reference])
+}
+
+fn target_to_js_value(target: Term) -> anyhow::Result<JsValue> {
+    if target == atom!("global") {
+        Ok(js_sys::global().into())
+    } else {
+        js_value::try_from_term(target)
+            .with_context(|| format!("target ({}) is not global or a JS value", target))
+    }
+}
@@ -0,0 +1,34 @@
+//! ```elixir
+//! reference = :js.subscribe(target, "click")
+//! receive do
+//!   {^reference, event} -> ...
+//! end
+//! ```
+
+use anyhow::*;
+
+use wasm_bindgen::JsCast;
+
+use web_sys::EventTarget;
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js::subscription;
+use crate::js_value;
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Subscribes the calling process to the `type` events of `target`, such as a DOM element.  Each
+/// event is sent as `{reference, event}` until `js:unsubscribe(reference)`.
+#[native_implemented::function(js:subscribe/2)]
+pub fn result(process: &Process, target: Term, r#type: Term) -> exception::Result<Term> {
+    let event_target: EventTarget = js_value::try_from_term(target)
+        .ok()
+        .and_then(|target_js_value| target_js_value.dyn_into().ok())
+        .with_context(|| format!("target ({}) is not a JS event target", target))?;
+    let type_string = binary_to_string(r#type)?;
+
+    subscription::subscribe(process, event_target, type_string)
+        .map_err(|error| anyhow!("could not subscribe to target ({}): {:?}", target, error).into())
+}
@@ -0,0 +1,100 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+
+use web_sys::{Event, EventTarget};
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::scheduler;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+
+thread_local! {
+    static SUBSCRIPTION_BY_KEY: RefCell<HashMap<Key, Subscription>> = Default::default();
+}
+
+/// Adds an event listener for `type` to `target` that sends `{reference, event}` to `process` for
+/// each event, and returns `reference`, which identifies the subscription to `unsubscribe`.
+pub fn subscribe(process: &Process, target: EventTarget, r#type: String) -> Result<Term, JsValue> {
+    let reference = process.next_reference();
+    let key = Key::from_reference(reference.try_into().unwrap());
+    let pid = process.pid();
+
+    let closure: Closure<dyn FnMut(Event)> = Closure::wrap(Box::new(move |event: Event| {
+        super::send_from_js(pid, |process| {
+            let reference = process.reference_from_scheduler(key.scheduler_id, key.number);
+            let event_term = process.resource(JsValue::from(event));
+
+            process.tuple_from_slice(&[reference, event_term])
+        })
+    }));
+
+    target.add_event_listener_with_callback(&r#type, closure.as_ref().unchecked_ref())?;
+
+    SUBSCRIPTION_BY_KEY.with(|subscription_by_key| {
+        subscription_by_key.borrow_mut().insert(
+            key,
+            Subscription {
+                target,
+                r#type,
+                closure,
+            },
+        )
+    });
+
+    Ok(reference)
+}
+
+/// Removes the event listener for the subscription identified by `reference`.  Returns `false` if
+/// there is no such subscription.
+///
+/// Subscriptions are not removed when the subscribing process exits, but their events are no
+/// longer sent.
+pub fn unsubscribe(reference: Boxed<Reference>) -> bool {
+    let key = Key::from_reference(reference);
+
+    match SUBSCRIPTION_BY_KEY
+        .with(|subscription_by_key| subscription_by_key.borrow_mut().remove(&key))
+    {
+        Some(subscription) => {
+            // The closure is dropped after it is removed as a listener, so it is never called
+            // after it is freed
+            drop(subscription.target.remove_event_listener_with_callback(
+                &subscription.r#type,
+                subscription.closure.as_ref().unchecked_ref(),
+            ));
+
+            true
+        }
+        None => false,
+    }
+}
+
+// Private
+
+/// Identifies a subscription, or a `lumen_web` WebSocket, by its reference without keeping the
+/// reference's process alive.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub(crate) struct Key {
+    pub(crate) scheduler_id: scheduler::ID,
+    pub(crate) number: ReferenceNumber,
+}
+
+impl Key {
+    pub(crate) fn from_reference(reference: Boxed<Reference>) -> Self {
+        Self {
+            scheduler_id: reference.scheduler_id(),
+            number: reference.number(),
+        }
+    }
+}
+
+struct Subscription {
+    target: EventTarget,
+    r#type: String,
+    closure: Closure<dyn FnMut(Event)>,
+}
@@ -0,0 +1,13 @@
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js::subscription;
+use crate::runtime::context::term_try_into_local_reference;
+
+/// Returns `true` if the subscription existed.
+#[native_implemented::function(js:unsubscribe/1)]
+pub fn result(reference: Term) -> exception::Result<Term> {
+    let boxed_reference = term_try_into_local_reference("reference", reference)?;
+
+    Ok(subscription::unsubscribe(boxed_reference).into())
+}
@@ -1,25 +1,101 @@
+use std::convert::TryInto;
 use std::str;
 
-use wasm_bindgen::JsValue;
+use anyhow::*;
+
+use wasm_bindgen::{JsCast, JsValue};
+
+use js_sys::{Array, Symbol};
 
 use web_sys::{
     Document, Element, HtmlBodyElement, HtmlElement, HtmlTableElement, Node, Text, WebSocket,
 };
 
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
 
-pub fn from_term(term: Term) -> JsValue {
+/// Like `try_from_term`, but errors `badarg` if `term` cannot be converted.
+pub fn from_term(term: Term) -> exception::Result<JsValue> {
+    try_from_term(term).map_err(From::from)
+}
+
+/// Converts `term` to a `JsValue`.
+///
+/// * `true` and `false` become booleans, `undefined` becomes `undefined`, `null` becomes `null`,
+///   and other atoms become symbols from the global symbol registry.
+/// * Integers and floats become numbers.
+/// * Binaries become strings if they are UTF-8 and `Uint8Array`s otherwise.
+/// * Lists and tuples become arrays.
+/// * Resource references to JS values become those values.
+pub fn try_from_term(term: Term) -> anyhow::Result<JsValue> {
     match term.decode().unwrap() {
-        TypedTerm::Atom(atom) => from_atom(atom),
-        TypedTerm::HeapBinary(heap_binary) => from_aligned_binary(heap_binary),
-        TypedTerm::ProcBin(process_binary) => from_aligned_binary(process_binary),
+        TypedTerm::Atom(atom) => Ok(from_atom(atom)),
+        TypedTerm::BigInteger(big_integer) => {
+            let f: f64 = big_integer.as_ref().into();
+
+            Ok(f.into())
+        }
+        TypedTerm::Float(_) => {
+            let f: f64 = term.try_into().unwrap();
+
+            Ok(f.into())
+        }
+        TypedTerm::HeapBinary(heap_binary) => Ok(from_aligned_binary(heap_binary)),
+        TypedTerm::List(cons) => from_list(term, &cons),
+        TypedTerm::Nil => Ok(Array::new().into()),
+        TypedTerm::ProcBin(process_binary) => Ok(from_aligned_binary(process_binary)),
         TypedTerm::ResourceReference(resource_reference) => {
-            from_resource_reference(resource_reference.into())
+            from_resource_reference(term, resource_reference.into())
         }
         TypedTerm::Tuple(tuple) => from_tuple(&tuple),
-        TypedTerm::Pid(pid) => from_pid(pid),
-        TypedTerm::SmallInteger(small_integer) => from_small_integer(small_integer),
-        _ => unimplemented!("Convert {:?} to JsValue", term),
+        TypedTerm::Pid(pid) => Ok(from_pid(pid)),
+        TypedTerm::SmallInteger(small_integer) => Ok(from_small_integer(small_integer)),
+        _ => Err(anyhow!("term ({}) cannot be converted to a JsValue", term)),
+    }
+}
+
+/// Converts `js_value` to a term on `process`'s heap.
+///
+/// The inverse of `try_from_term` for booleans, `undefined`, `null`, numbers, strings, arrays and
+/// symbols from the global symbol registry.  Any other value, such as an object or a function, is
+/// stored as a resource reference, so that it can be passed back to JS.
+pub fn to_term(js_value: JsValue, process: &Process) -> Term {
+    if js_value.is_undefined() {
+        atom!("undefined")
+    } else if js_value.is_null() {
+        atom!("null")
+    } else if let Some(b) = js_value.as_bool() {
+        b.into()
+    } else if let Some(f) = js_value.as_f64() {
+        if f.fract() == 0.0 && (std::i64::MIN as f64) <= f && f <= (std::i64::MAX as f64) {
+            process.integer(f as i64)
+        } else {
+            process.float(f)
+        }
+    } else if let Some(s) = js_value.as_string() {
+        process.binary_from_str(&s)
+    } else if Array::is_array(&js_value) {
+        let array: Array = js_value.unchecked_into();
+        let vec: Vec<Term> = array
+            .iter()
+            .map(|element| to_term(element, process))
+            .collect();
+
+        process.list_from_slice(&vec)
+    } else if js_value.is_symbol() {
+        let symbol: &Symbol = js_value.unchecked_ref();
+
+        match Symbol::key_for(symbol)
+            .as_string()
+            .and_then(|key| Atom::try_from_str(key).ok())
+        {
+            Some(atom) => atom.encode().unwrap(),
+            None => process.resource(js_value),
+        }
+    } else {
+        process.resource(js_value)
     }
 }
 
@@ -30,7 +106,13 @@ fn from_aligned_binary<A: AlignedBinary>(aligned_binary: A) -> JsValue {
 }
 
 fn from_atom(atom: Atom) -> JsValue {
-    js_sys::Symbol::for_(atom.name()).into()
+    match atom.name() {
+        "false" => false.into(),
+        "null" => JsValue::null(),
+        "true" => true.into(),
+        "undefined" => JsValue::undefined(),
+        name => Symbol::for_(name).into(),
+    }
 }
 
 fn from_bytes(bytes: &[u8]) -> JsValue {
@@ -45,7 +127,7 @@ fn from_bytes(bytes: &[u8]) -> JsValue {
 }
 
 fn from_pid(pid: Pid) -> JsValue {
-    let array = js_sys::Array::new();
+    let array = Array::new();
 
     array.push(&(pid.number() as i32).into());
     array.push(&(pid.serial() as i32).into());
@@ -53,8 +135,30 @@ fn from_pid(pid: Pid) -> JsValue {
     array.into()
 }
 
-fn from_resource_reference(resource_reference: Resource) -> JsValue {
-    if resource_reference.is::<Document>() {
+fn from_list(list: Term, cons: &Cons) -> anyhow::Result<JsValue> {
+    let array = Array::new();
+
+    for result in cons.into_iter() {
+        match result {
+            Ok(element_term) => {
+                array.push(&try_from_term(element_term)?);
+            }
+            Err(_) => {
+                return Err(ImproperListError)
+                    .with_context(|| format!("list ({}) is improper", list))
+            }
+        }
+    }
+
+    Ok(array.into())
+}
+
+fn from_resource_reference(term: Term, resource_reference: Resource) -> anyhow::Result<JsValue> {
+    let js_value = if resource_reference.is::<JsValue>() {
+        let js_value: &JsValue = resource_reference.downcast_ref().unwrap();
+
+        js_value.clone()
+    } else if resource_reference.is::<Document>() {
         let document: &Document = resource_reference.downcast_ref().unwrap();
 
         document.into()
@@ -87,8 +191,10 @@ fn from_resource_reference(resource_reference: Resource) -> JsValue {
 
         web_socket.into()
     } else {
-        unimplemented!("Convert {:?} to JsValue", resource_reference);
-    }
+        return Err(anyhow!("resource reference ({}) is not a JS value", term));
+    };
+
+    Ok(js_value)
 }
 
 fn from_small_integer(small_integer: SmallInteger) -> JsValue {
@@ -101,13 +207,13 @@ fn from_small_integer(small_integer: SmallInteger) -> JsValue {
     }
 }
 
-fn from_tuple(tuple: &Tuple) -> JsValue {
-    let array = js_sys::Array::new();
+fn from_tuple(tuple: &Tuple) -> anyhow::Result<JsValue> {
+    let array = Array::new();
 
     for element_term in tuple.iter() {
-        let element_js_value = from_term(*element_term);
+        let element_js_value = try_from_term(*element_term)?;
         array.push(&element_js_value);
     }
 
-    array.into()
+    Ok(array.into())
 }
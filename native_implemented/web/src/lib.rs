@@ -3,24 +3,25 @@
 
 pub mod r#async;
 pub mod document;
+pub mod dom;
 pub mod element;
 pub mod event;
 pub mod event_listener;
 pub mod executor;
 pub mod html_form_element;
 pub mod html_input_element;
+pub mod js;
 pub mod js_value;
+pub mod lumen_web;
 pub mod math;
 pub mod node;
 pub mod promise;
+mod scheduler_loop;
 pub mod web_socket;
 pub mod window;
 
 pub use lumen_rt_full as runtime;
 
-use std::cell::RefCell;
-use std::rc::Rc;
-
 use panic_control::chain_hook_ignoring;
 
 use wasm_bindgen::prelude::*;
@@ -33,15 +34,12 @@ use liblumen_core::entry;
 
 use liblumen_alloc::atom;
 use liblumen_alloc::erts::process::Process;
-use liblumen_alloc::erts::term::prelude::Term;
-use liblumen_alloc::erts::time::Milliseconds;
+use liblumen_alloc::erts::term::prelude::{Atom, Term};
 
-use crate::runtime::scheduler;
-use crate::runtime::time::monotonic;
 use crate::window::add_event_listener;
 
-/// Starts the scheduler loop.  It yield and reschedule itself using
-/// [requestAnimationFrame](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame).
+/// Starts the scheduler loop.  It runs a bounded number of reductions at a time on the browser's
+/// event loop, so that it never blocks the UI thread.
 #[cfg_attr(not(test), entry)]
 pub fn start() {
     // Ignore panics created by full runtime's `__lumen_start_panic`.  `catch_unwind` although
@@ -49,16 +47,11 @@ pub fn start() {
     // backtrace without this.
     chain_hook_ignoring::<Term>();
     add_event_listeners();
-    request_animation_frames();
+    scheduler_loop::start();
 }
 
 // Private
 
-const MILLISECONDS_PER_SECOND: MillisecondsPerSecond = MillisecondsPerSecond(1000);
-const FRAMES_PER_SECOND: FramesPerSecond = FramesPerSecond(60);
-const MILLISECONDS_PER_FRAME: MillisecondsPerFrame =
-    MILLISECONDS_PER_SECOND.const_div(FRAMES_PER_SECOND);
-
 fn add_event_listeners() {
     let window = web_sys::window().unwrap();
     add_submit_listener(&window);
@@ -74,24 +67,51 @@ fn add_submit_listener(window: &Window) {
     );
 }
 
+/// `{error, {syntax, Message}}` and `{error, {security, Message}}` for those `DOMException`s,
+/// `{error, Reason}` where `Reason` is the snake case name of any other `DOMException`, such as
+/// `hierarchy_request` for `HierarchyRequestError`, and `{error, Value}` for any other JS value.
 fn error_tuple(process: &Process, js_value: JsValue) -> Term {
     let error = atom!("error");
-    let dom_exception = js_value.dyn_ref::<DomException>().unwrap();
 
-    match dom_exception.name().as_ref() {
-        "SyntaxError" => {
-            let tag = atom!("syntax");
-            let message = process.binary_from_str(&dom_exception.message());
-            let reason = process.tuple_from_slice(&[tag, message]);
+    let reason = match js_value.dyn_ref::<DomException>() {
+        Some(dom_exception) => match dom_exception.name().as_ref() {
+            "SyntaxError" => {
+                let tag = atom!("syntax");
+                let message = process.binary_from_str(&dom_exception.message());
+
+                process.tuple_from_slice(&[tag, message])
+            }
+            "SecurityError" => {
+                let tag = atom!("security");
+                let message = process.binary_from_str(&dom_exception.message());
+
+                process.tuple_from_slice(&[tag, message])
+            }
+            name => Atom::str_to_term(&dom_exception_name_to_reason(name)),
+        },
+        None => js_value::to_term(js_value, process),
+    };
+
+    process.tuple_from_slice(&[error, reason])
+}
+
+/// `HierarchyRequestError` becomes `hierarchy_request`, like `Lumen.Web.Node.replace_child/3`.
+fn dom_exception_name_to_reason(name: &str) -> String {
+    let mut reason = String::new();
 
-            process.tuple_from_slice(&[error, reason])
+    for (index, c) in name.trim_end_matches("Error").char_indices() {
+        if c.is_ascii_uppercase() {
+            if index > 0 {
+                reason.push('_');
+            }
+
+            reason.push(c.to_ascii_lowercase());
+        } else {
+            reason.push(c);
         }
-        name => unimplemented!(
-            "Converting {} DomException: {}",
-            name,
-            dom_exception.message()
-        ),
     }
+
+    reason
 }
 
 fn ok_tuple<V: Clone + 'static>(process: &Process, value: V) -> Term {
@@ -107,52 +127,3 @@ fn option_to_ok_tuple_or_error<V: Clone + 'static>(process: &Process, option: Op
         None => atom!("error"),
     }
 }
-
-fn request_animation_frame(f: &Closure<dyn FnMut()>) {
-    web_sys::window()
-        .unwrap()
-        .request_animation_frame(f.as_ref().unchecked_ref())
-        .unwrap();
-}
-
-fn request_animation_frames() {
-    // Based on https://github.com/rustwasm/wasm-bindgen/blob/603d5742eeca2a7a978f13614de9282229d1835e/examples/request-animation-frame/src/lib.rs
-    let f = Rc::new(RefCell::new(None));
-    let g = f.clone();
-
-    *g.borrow_mut() = Some(Closure::wrap(Box::new(move || {
-        run_for_milliseconds(MILLISECONDS_PER_FRAME.const_mul(Frames(1)));
-
-        // Schedule ourselves for another requestAnimationFrame callback.
-        request_animation_frame(f.borrow().as_ref().unwrap());
-    }) as Box<dyn FnMut()>));
-
-    request_animation_frame(g.borrow().as_ref().unwrap());
-}
-
-fn run_for_milliseconds(duration: Milliseconds) {
-    let scheduler = scheduler::current();
-    let timeout = monotonic::time() + duration;
-
-    while (monotonic::time() < timeout) && scheduler.run_once() {}
-}
-
-struct Frames(u64);
-
-struct FramesPerSecond(u64);
-
-struct MillisecondsPerFrame(u64);
-
-impl MillisecondsPerFrame {
-    const fn const_mul(self, rhs: Frames) -> Milliseconds {
-        Milliseconds(self.0 * rhs.0)
-    }
-}
-
-struct MillisecondsPerSecond(u64);
-
-impl MillisecondsPerSecond {
-    const fn const_div(self, rhs: FramesPerSecond) -> MillisecondsPerFrame {
-        MillisecondsPerFrame(self.0 / rhs.0)
-    }
-}
@@ -0,0 +1,29 @@
+//! Browser connections for Erlang processes.
+//!
+//! ```erlang
+//! {ok, WebSocket} = lumen_web:websocket_connect(<<"wss://example.com/socket">>),
+//! receive
+//!   {websocket, WebSocket, open} -> ok
+//! end,
+//! ok = lumen_web:websocket_send(WebSocket, {text, <<"hello">>}),
+//! receive
+//!   {websocket, WebSocket, {text, Text}} -> Text;
+//!   {websocket, WebSocket, {binary, Binary}} -> Binary;
+//!   {websocket, WebSocket, {close, Code, Reason}} -> exit({Code, Reason})
+//! end.
+//! ```
+
+mod websocket;
+pub mod websocket_close_1;
+pub mod websocket_connect_1;
+pub mod websocket_send_2;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+pub fn module() -> Atom {
+    Atom::from_str("lumen_web")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
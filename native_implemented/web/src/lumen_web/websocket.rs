@@ -0,0 +1,185 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use js_sys::Uint8Array;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+
+use web_sys::{BinaryType, CloseEvent, Event, MessageEvent, WebSocket};
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::js::subscription::Key;
+use crate::runtime::registry;
+use crate::runtime::scheduler::SchedulerDependentAlloc;
+
+thread_local! {
+    static CONNECTION_BY_KEY: RefCell<HashMap<Key, Connection>> = Default::default();
+}
+
+/// A frame sent with `lumen_web:websocket_send/2`.
+pub enum Frame {
+    Text(String),
+    Binary(Vec<u8>),
+}
+
+/// Opens a WebSocket to `url` whose events are sent to `process` as
+/// `{websocket, reference, event}`, and returns `reference`, which identifies the connection like
+/// the pid of a port.
+pub fn connect(process: &Process, url: &str) -> Result<Term, JsValue> {
+    let web_socket = WebSocket::new(url)?;
+    web_socket.set_binary_type(BinaryType::Arraybuffer);
+
+    let reference = process.next_reference();
+    let key = Key::from_reference(reference.try_into().unwrap());
+    let pid = process.pid();
+
+    let on_open: Closure<dyn FnMut(Event)> = Closure::wrap(Box::new(move |_: Event| {
+        send_event(pid, key, |_| atom!("open"))
+    }));
+    let on_message: Closure<dyn FnMut(MessageEvent)> =
+        Closure::wrap(Box::new(move |message_event: MessageEvent| {
+            let data = message_event.data();
+
+            send_event(pid, key, |process| match data.as_string() {
+                Some(text) => {
+                    process.tuple_from_slice(&[atom!("text"), process.binary_from_str(&text)])
+                }
+                // `binaryType` is `arraybuffer`, so binary frames are never `Blob`s
+                None => {
+                    let bytes = Uint8Array::new(&data).to_vec();
+
+                    process.tuple_from_slice(&[atom!("binary"), process.binary_from_bytes(&bytes)])
+                }
+            })
+        }));
+    // Browsers give no reason for errors, but always close the connection after one
+    let on_error: Closure<dyn FnMut(Event)> = Closure::wrap(Box::new(move |_: Event| {
+        send_event(pid, key, |_| atom!("error"))
+    }));
+    // Frees the other closures, which is only safe once none of them can be running
+    let on_close = Closure::once_into_js(move |close_event: CloseEvent| {
+        remove(key);
+
+        send_event(pid, key, |process| {
+            process.tuple_from_slice(&[
+                atom!("close"),
+                process.integer(close_event.code() as u64),
+                process.binary_from_str(&close_event.reason()),
+            ])
+        })
+    });
+
+    web_socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+    web_socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    web_socket.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+    web_socket.set_onclose(Some(on_close.unchecked_ref()));
+
+    CONNECTION_BY_KEY.with(|connection_by_key| {
+        connection_by_key.borrow_mut().insert(
+            key,
+            Connection {
+                web_socket,
+                _on_open: on_open,
+                _on_message: on_message,
+                _on_error: on_error,
+            },
+        )
+    });
+
+    Ok(reference)
+}
+
+/// Sends `frame` on the connection identified by `reference`, or returns why it can't be sent:
+/// `connecting` before the `open` event and `closed` after the `close` event or
+/// `lumen_web:websocket_close/1`.
+pub fn send(reference: Boxed<Reference>, frame: Frame) -> Result<(), &'static str> {
+    let web_socket = get(Key::from_reference(reference)).ok_or("closed")?;
+
+    match web_socket.ready_state() {
+        WebSocket::OPEN => {
+            let result = match frame {
+                Frame::Text(text) => web_socket.send_with_str(&text),
+                Frame::Binary(bytes) => {
+                    web_socket.send_with_array_buffer_view(&Uint8Array::from(bytes.as_slice()))
+                }
+            };
+
+            result.map_err(|_| "closed")
+        }
+        WebSocket::CONNECTING => Err("connecting"),
+        _ => Err("closed"),
+    }
+}
+
+/// Starts closing the connection identified by `reference`.  Its `close` event is still sent.
+/// Returns `false` if there is no such connection.
+pub fn close(reference: Boxed<Reference>) -> bool {
+    close_key(Key::from_reference(reference))
+}
+
+// Private
+
+struct Connection {
+    web_socket: WebSocket,
+    // Only held so that they live as long as the `WebSocket` calls them
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_error: Closure<dyn FnMut(Event)>,
+}
+
+fn close_key(key: Key) -> bool {
+    match get(key) {
+        Some(web_socket) => {
+            drop(web_socket.close());
+
+            true
+        }
+        None => false,
+    }
+}
+
+fn get(key: Key) -> Option<WebSocket> {
+    CONNECTION_BY_KEY.with(|connection_by_key| {
+        connection_by_key
+            .borrow()
+            .get(&key)
+            .map(|connection| connection.web_socket.clone())
+    })
+}
+
+fn remove(key: Key) {
+    let option_connection =
+        CONNECTION_BY_KEY.with(|connection_by_key| connection_by_key.borrow_mut().remove(&key));
+
+    if let Some(connection) = option_connection {
+        let web_socket = &connection.web_socket;
+        web_socket.set_onopen(None);
+        web_socket.set_onmessage(None);
+        web_socket.set_onerror(None);
+    }
+}
+
+/// Sends `{websocket, reference, event}` to the owner, or closes the connection if the owner
+/// exited, like a port.
+fn send_event<F>(pid: Pid, key: Key, event: F)
+where
+    F: FnOnce(&Process) -> Term,
+{
+    if registry::pid_to_process(&pid).is_none() {
+        close_key(key);
+
+        return;
+    }
+
+    crate::js::send_from_js(pid, |process| {
+        let reference = process.reference_from_scheduler(key.scheduler_id, key.number);
+        let event_term = event(process);
+
+        process.tuple_from_slice(&[atom!("websocket"), reference, event_term])
+    })
+}
@@ -0,0 +1,16 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lumen_web::websocket;
+use crate::runtime::context::term_try_into_local_reference;
+
+/// Starts closing `web_socket` and returns `ok`, even if it is already closed.  The owner still
+/// receives `{websocket, WebSocket, {close, Code, Reason}}` once it is closed.
+#[native_implemented::function(lumen_web:websocket_close/1)]
+pub fn result(web_socket: Term) -> exception::Result<Term> {
+    let boxed_reference = term_try_into_local_reference("web_socket", web_socket)?;
+    websocket::close(boxed_reference);
+
+    Ok(atom!("ok"))
+}
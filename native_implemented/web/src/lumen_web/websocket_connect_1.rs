@@ -0,0 +1,22 @@
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::lumen_web::websocket;
+use crate::runtime::binary_to_string::binary_to_string;
+
+/// Returns `{ok, WebSocket}` for a connection to the `url` binary, or `{error, Reason}` if the URL
+/// is invalid.  The calling process receives the connection's events as
+/// `{websocket, WebSocket, open | {text, Text} | {binary, Binary} | error | {close, Code, Reason}}`.
+#[native_implemented::function(lumen_web:websocket_connect/1)]
+pub fn result(process: &Process, url: Term) -> exception::Result<Term> {
+    let url_string = binary_to_string(url)?;
+
+    let result_tuple = match websocket::connect(process, &url_string) {
+        Ok(web_socket) => process.tuple_from_slice(&[atom!("ok"), web_socket]),
+        Err(js_value) => crate::error_tuple(process, js_value),
+    };
+
+    Ok(result_tuple)
+}
@@ -0,0 +1,56 @@
+use std::convert::TryInto;
+
+use anyhow::*;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_otp::erlang::iolist_or_binary;
+
+use crate::lumen_web::websocket::{self, Frame};
+use crate::runtime::binary_to_string::binary_to_string;
+use crate::runtime::context::term_try_into_local_reference;
+
+/// Returns `ok` once `frame`, `{text, Binary}` or `{binary, IoData}`, is queued to send on
+/// `web_socket`, or `{error, connecting | closed}`.
+#[native_implemented::function(lumen_web:websocket_send/2)]
+pub fn result(process: &Process, web_socket: Term, frame: Term) -> exception::Result<Term> {
+    let boxed_reference = term_try_into_local_reference("web_socket", web_socket)?;
+    let frame = term_try_into_frame(frame)?;
+
+    let result_term = match websocket::send(boxed_reference, frame) {
+        Ok(()) => atom!("ok"),
+        Err(reason) => process.tuple_from_slice(&[atom!("error"), Atom::str_to_term(reason)]),
+    };
+
+    Ok(result_term)
+}
+
+fn term_try_into_frame(frame: Term) -> exception::Result<Frame> {
+    let tuple: Boxed<Tuple> = frame
+        .try_into()
+        .with_context(|| frame_is_not_frame(frame))?;
+
+    if tuple.len() == 2 {
+        if tuple[0] == atom!("text") {
+            let text = binary_to_string(tuple[1])?;
+
+            return Ok(Frame::Text(text));
+        } else if tuple[0] == atom!("binary") {
+            let bytes = iolist_or_binary::to_byte_vec("binary", tuple[1])?;
+
+            return Ok(Frame::Binary(bytes));
+        }
+    }
+
+    Err(anyhow!(frame_is_not_frame(frame)).into())
+}
+
+fn frame_is_not_frame(frame: Term) -> String {
+    format!(
+        "frame ({}) is not {{text, Binary}} or {{binary, IoData}}",
+        frame
+    )
+}
@@ -9,6 +9,7 @@ use std::convert::TryInto;
 use std::mem;
 
 use anyhow::*;
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{Document, Element, HtmlBodyElement, HtmlElement, HtmlTableElement, Node, Text};
 
 use liblumen_alloc::erts::exception;
@@ -68,6 +69,21 @@ fn from_term(term: Term) -> Result<&'static Node, exception::Exception> {
         let node: &'static Node = unsafe { mem::transmute::<&Node, &'static Node>(text.as_ref()) };
 
         Ok(node)
+    } else if resource_reference.is::<JsValue>() {
+        // Such as from `dom` or `js:call/3`
+        let js_value: &JsValue = resource_reference.downcast_ref().unwrap();
+
+        match js_value.dyn_ref::<Node>() {
+            Some(node) => {
+                let static_node: &'static Node =
+                    unsafe { mem::transmute::<&Node, &'static Node>(node) };
+
+                Ok(static_node)
+            }
+            None => Err(TypeError)
+                .with_context(|| format!("{} is a JS value, but not a node", term))
+                .map_err(From::from),
+        }
     } else {
         Err(TypeError)
             .with_context(|| format!("{} is a resource, but cannot be converted to a node", term))
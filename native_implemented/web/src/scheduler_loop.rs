@@ -0,0 +1,188 @@
+//! Drives the scheduler from the browser's event loop without blocking the UI thread.
+//!
+//! Each run is bounded by both reductions and time, and then the loop continues with:
+//!
+//! * [queueMicrotask](https://developer.mozilla.org/en-US/docs/Web/API/queueMicrotask) when JS
+//!   wakes a process, so the process runs as soon as the JS callback returns.
+//! * [setTimeout](https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/setTimeout)
+//!   of `0` when processes are still runnable, so the browser can handle input and render between
+//!   runs.
+//! * `setTimeout` until the next timer when all processes are waiting, so that receive timeouts and
+//!   `erlang:send_after/3` fire on time, even in background tabs.
+//! * [requestAnimationFrame](https://developer.mozilla.org/en-US/docs/Web/API/window/requestAnimationFrame)
+//!   when there are no timers, so that a process woken from outside `wake` is still run.
+
+use std::cell::RefCell;
+
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+use web_sys::Window;
+
+use liblumen_alloc::erts::process::Priority;
+use liblumen_alloc::erts::time::Milliseconds;
+
+use crate::runtime::scheduler;
+use crate::runtime::time::monotonic;
+use crate::runtime::timer;
+
+/// Starts running the scheduler on the browser's event loop.
+pub fn start() {
+    let run = Closure::wrap(Box::new(run) as Box<dyn FnMut()>);
+
+    LOOP.with(|cell| {
+        *cell.borrow_mut() = Some(Loop {
+            run,
+            continuation: Continuation::None,
+            running: false,
+        })
+    });
+
+    wake();
+}
+
+/// Runs the scheduler as soon as the current JS callback returns, as a process may have become
+/// runnable.  Does nothing if the loop isn't started or is already about to run.
+pub fn wake() {
+    LOOP.with(|cell| {
+        if let Some(ref mut scheduler_loop) = *cell.borrow_mut() {
+            if !scheduler_loop.running && scheduler_loop.continuation != Continuation::Microtask {
+                scheduler_loop.cancel();
+                queue_microtask(scheduler_loop.run.as_ref().unchecked_ref());
+                scheduler_loop.continuation = Continuation::Microtask;
+            }
+        }
+    });
+}
+
+// Private
+
+const REDUCTIONS_PER_RUN: u64 = 20_000;
+/// Half a frame at 60 frames per second, so the browser has the other half to render.
+const MILLISECONDS_PER_RUN: Milliseconds = Milliseconds(8);
+
+thread_local! {
+    static LOOP: RefCell<Option<Loop>> = RefCell::new(None);
+}
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = queueMicrotask)]
+    fn queue_microtask(callback: &js_sys::Function);
+}
+
+#[derive(PartialEq)]
+enum Continuation {
+    None,
+    Microtask,
+    Timeout(i32),
+    AnimationFrame(i32),
+}
+
+struct Loop {
+    run: Closure<dyn FnMut()>,
+    continuation: Continuation,
+    /// Processes can call into JS that calls `wake`, which would otherwise schedule a second run
+    running: bool,
+}
+
+impl Loop {
+    /// Microtasks can't be cancelled, but they are only replaced by the run they start.
+    fn cancel(&mut self) {
+        match self.continuation {
+            Continuation::Timeout(handle) => window().clear_timeout_with_handle(handle),
+            Continuation::AnimationFrame(handle) => {
+                window().cancel_animation_frame(handle).unwrap()
+            }
+            Continuation::None | Continuation::Microtask => (),
+        }
+
+        self.continuation = Continuation::None;
+    }
+
+    fn schedule(&mut self, next: Next) {
+        let window = window();
+        let function = self.run.as_ref().unchecked_ref();
+
+        self.continuation = match next {
+            Next::Yield => Continuation::Timeout(
+                window
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(function, 0)
+                    .unwrap(),
+            ),
+            Next::Sleep(Some(milliseconds)) => Continuation::Timeout(
+                window
+                    .set_timeout_with_callback_and_timeout_and_arguments_0(
+                        function,
+                        milliseconds.as_u64().min(std::i32::MAX as u64) as i32,
+                    )
+                    .unwrap(),
+            ),
+            Next::Sleep(None) => {
+                Continuation::AnimationFrame(window.request_animation_frame(function).unwrap())
+            }
+        };
+    }
+}
+
+enum Next {
+    /// Processes are still runnable
+    Yield,
+    /// All processes are waiting, for the next timer if there is one
+    Sleep(Option<Milliseconds>),
+}
+
+fn run() {
+    LOOP.with(|cell| {
+        if let Some(ref mut scheduler_loop) = *cell.borrow_mut() {
+            // Whichever continuation this is, it has now run
+            scheduler_loop.cancel();
+            scheduler_loop.running = true;
+        }
+    });
+
+    // `LOOP` isn't borrowed while processes run, so that they can `wake` it
+    let next = run_once();
+
+    LOOP.with(|cell| {
+        if let Some(ref mut scheduler_loop) = *cell.borrow_mut() {
+            scheduler_loop.running = false;
+            scheduler_loop.schedule(next);
+        }
+    });
+}
+
+fn run_once() -> Next {
+    let scheduler = scheduler::current();
+    let reductions_timeout = scheduler.statistics().totals().reductions + REDUCTIONS_PER_RUN;
+    let timeout = monotonic::time() + MILLISECONDS_PER_RUN;
+    // Waiting processes are also in the run queues, so only the queues of each priority count
+    let is_runnable = || {
+        [Priority::Normal, Priority::High, Priority::Max]
+            .iter()
+            .any(|priority| 0 < scheduler.run_queue_len(*priority))
+    };
+
+    // Receive timeouts that passed while sleeping make their processes runnable again
+    timer::timeout();
+
+    while is_runnable() {
+        if (reductions_timeout <= scheduler.statistics().totals().reductions)
+            || (timeout <= monotonic::time())
+        {
+            return Next::Yield;
+        }
+
+        let _ = scheduler.run_once();
+    }
+
+    Next::Sleep(timer::next_monotonic().map(|next_monotonic| {
+        next_monotonic
+            .checked_sub(monotonic::time())
+            .unwrap_or(Milliseconds(0))
+    }))
+}
+
+fn window() -> Window {
+    web_sys::window().unwrap()
+}
@@ -6,12 +6,22 @@
 
 #[path = "web/document.rs"]
 mod document;
+#[path = "web/dom.rs"]
+mod dom;
 #[path = "web/element.rs"]
 mod element;
+#[path = "web/executor.rs"]
+mod executor;
+#[path = "web/js.rs"]
+mod js;
+#[path = "web/lumen_web.rs"]
+mod lumen_web;
 #[path = "web/math.rs"]
 mod math;
 #[path = "web/node.rs"]
 mod node;
+#[path = "web/scheduler_loop.rs"]
+mod scheduler_loop;
 #[path = "web/web_socket.rs"]
 mod web_socket;
 
@@ -40,19 +50,27 @@ fn initialize_dispatch_table() {
     let function_symbols = vec![
         // Library
         liblumen_web::document::new_0::function_symbol(),
+        liblumen_web::dom::create_element_1::function_symbol(),
+        liblumen_web::dom::query_selector_1::function_symbol(),
         liblumen_web::executor::apply_4::function_symbol(),
+        liblumen_web::js::call_3::function_symbol(),
+        liblumen_web::lumen_web::websocket_connect_1::function_symbol(),
         liblumen_web::web_socket::new_1::function_symbol(),
+        liblumen_otp::erlang::make_ref_0::function_symbol(),
 
         // Test
         document::body_1::with_body::function_symbol(),
         document::body_1::without_body::function_symbol(),
         element::class_name_1::test_0::function_symbol(),
         element::remove_1::removes_element::function_symbol(),
+        js::call_3::with_global_function_returns_ok_result::function_symbol(),
+        lumen_web::websocket::sends_frames_and_receives_events::function_symbol(),
         math::random_integer_1::returns_integer_between_0_inclusive_and_max_exclusive::function_symbol(),
         node::insert_before_3::with_nil_reference_child_appends_new_child::function_symbol(),
         node::insert_before_3::with_reference_child_inserts_before_reference_child::function_symbol(),
         node::replace_child_3::with_new_child_is_parent_returns_error_hierarchy_request::function_symbol(),
-        node::replace_child_3::with_new_child_returns_ok_replaced_child::function_symbol()
+        node::replace_child_3::with_new_child_returns_ok_replaced_child::function_symbol(),
+        scheduler_loop::receive_after::resumes_after_timeout::function_symbol()
     ];
 
     unsafe {
@@ -0,0 +1,6 @@
+#[path = "dom/create_element_1.rs"]
+mod create_element_1;
+#[path = "dom/query_selector_1.rs"]
+mod query_selector_1;
+
+use super::*;
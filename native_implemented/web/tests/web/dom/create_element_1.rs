@@ -0,0 +1,85 @@
+use super::*;
+
+use js_sys::{Reflect, Symbol};
+
+use wasm_bindgen::JsCast;
+
+use web_sys::Element;
+
+use liblumen_alloc::erts::fragment::HeapFragment;
+
+use liblumen_web::dom;
+
+#[wasm_bindgen_test]
+async fn with_valid_tag_returns_ok_element() {
+    start_once();
+
+    let (tag, tag_non_null_heap_fragment) = HeapFragment::new_binary_from_str("div").unwrap();
+    let options: Options = Default::default();
+
+    // ```erlang
+    // dom:create_element(Tag)
+    // ```
+    let promise = r#async::apply_3::promise(
+        dom::module(),
+        dom::create_element_1::function(),
+        vec![tag],
+        options,
+    )
+    .unwrap();
+
+    std::mem::drop(tag_non_null_heap_fragment);
+
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    assert!(js_sys::Array::is_array(&resolved));
+
+    let resolved_array: js_sys::Array = resolved.dyn_into().unwrap();
+
+    assert_eq!(resolved_array.length(), 2);
+
+    let ok: JsValue = Symbol::for_("ok").into();
+    assert_eq!(Reflect::get(&resolved_array, &0.into()).unwrap(), ok);
+
+    let element: Element = Reflect::get(&resolved_array, &1.into())
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    assert_eq!(element.tag_name(), "DIV");
+}
+
+#[wasm_bindgen_test]
+async fn with_invalid_tag_returns_error_invalid_character() {
+    start_once();
+
+    let (tag, tag_non_null_heap_fragment) = HeapFragment::new_binary_from_str("<div>").unwrap();
+    let options: Options = Default::default();
+
+    // ```erlang
+    // dom:create_element(Tag)
+    // ```
+    let promise = r#async::apply_3::promise(
+        dom::module(),
+        dom::create_element_1::function(),
+        vec![tag],
+        options,
+    )
+    .unwrap();
+
+    std::mem::drop(tag_non_null_heap_fragment);
+
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    assert!(js_sys::Array::is_array(&resolved));
+
+    let resolved_array: js_sys::Array = resolved.dyn_into().unwrap();
+
+    assert_eq!(resolved_array.length(), 2);
+
+    let error: JsValue = Symbol::for_("error").into();
+    assert_eq!(Reflect::get(&resolved_array, &0.into()).unwrap(), error);
+
+    let reason: JsValue = Symbol::for_("invalid_character").into();
+    assert_eq!(Reflect::get(&resolved_array, &1.into()).unwrap(), reason);
+}
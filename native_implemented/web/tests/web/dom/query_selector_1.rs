@@ -0,0 +1,121 @@
+use super::*;
+
+use js_sys::{Reflect, Symbol};
+
+use wasm_bindgen::JsCast;
+
+use web_sys::Element;
+
+use liblumen_alloc::erts::fragment::HeapFragment;
+
+use liblumen_web::dom;
+
+#[wasm_bindgen_test]
+async fn with_matching_selectors_returns_ok_element() {
+    start_once();
+
+    let (selectors, selectors_non_null_heap_fragment) =
+        HeapFragment::new_binary_from_str("body").unwrap();
+    let options: Options = Default::default();
+
+    // ```erlang
+    // dom:query_selector(Selectors)
+    // ```
+    let promise = r#async::apply_3::promise(
+        dom::module(),
+        dom::query_selector_1::function(),
+        vec![selectors],
+        options,
+    )
+    .unwrap();
+
+    std::mem::drop(selectors_non_null_heap_fragment);
+
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    assert!(js_sys::Array::is_array(&resolved));
+
+    let resolved_array: js_sys::Array = resolved.dyn_into().unwrap();
+
+    assert_eq!(resolved_array.length(), 2);
+
+    let ok: JsValue = Symbol::for_("ok").into();
+    assert_eq!(Reflect::get(&resolved_array, &0.into()).unwrap(), ok);
+
+    let element: Element = Reflect::get(&resolved_array, &1.into())
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    assert_eq!(element.tag_name(), "BODY");
+}
+
+#[wasm_bindgen_test]
+async fn without_matching_selectors_returns_none() {
+    start_once();
+
+    let (selectors, selectors_non_null_heap_fragment) =
+        HeapFragment::new_binary_from_str("#does-not-exist").unwrap();
+    let options: Options = Default::default();
+
+    // ```erlang
+    // dom:query_selector(Selectors)
+    // ```
+    let promise = r#async::apply_3::promise(
+        dom::module(),
+        dom::query_selector_1::function(),
+        vec![selectors],
+        options,
+    )
+    .unwrap();
+
+    std::mem::drop(selectors_non_null_heap_fragment);
+
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    let none: JsValue = Symbol::for_("none").into();
+    assert_eq!(resolved, none);
+}
+
+#[wasm_bindgen_test]
+async fn with_invalid_selectors_returns_error_syntax() {
+    start_once();
+
+    let (selectors, selectors_non_null_heap_fragment) =
+        HeapFragment::new_binary_from_str("[").unwrap();
+    let options: Options = Default::default();
+
+    // ```erlang
+    // dom:query_selector(Selectors)
+    // ```
+    let promise = r#async::apply_3::promise(
+        dom::module(),
+        dom::query_selector_1::function(),
+        vec![selectors],
+        options,
+    )
+    .unwrap();
+
+    std::mem::drop(selectors_non_null_heap_fragment);
+
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    assert!(js_sys::Array::is_array(&resolved));
+
+    let resolved_array: js_sys::Array = resolved.dyn_into().unwrap();
+
+    assert_eq!(resolved_array.length(), 2);
+
+    let error: JsValue = Symbol::for_("error").into();
+    assert_eq!(Reflect::get(&resolved_array, &0.into()).unwrap(), error);
+
+    let reason = Reflect::get(&resolved_array, &1.into()).unwrap();
+
+    let reason_array: js_sys::Array = reason.dyn_into().unwrap();
+
+    assert_eq!(reason_array.length(), 2);
+
+    let tag: JsValue = Symbol::for_("syntax").into();
+    assert_eq!(Reflect::get(&reason_array, &0.into()).unwrap(), tag);
+    assert!(Reflect::get(&reason_array, &1.into()).unwrap().is_string());
+}
@@ -0,0 +1,4 @@
+#[path = "executor/apply_4.rs"]
+pub mod apply_4;
+
+use super::*;
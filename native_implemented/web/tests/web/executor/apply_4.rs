@@ -0,0 +1,17 @@
+use super::*;
+
+#[wasm_bindgen_test]
+async fn without_js_value_return_rejects_promise() {
+    start_once();
+
+    // References can't be converted to JS values
+    let promise = r#async::apply_3::promise(
+        Atom::from_str("erlang"),
+        Atom::from_str("make_ref"),
+        vec![],
+        Default::default(),
+    )
+    .unwrap();
+
+    assert!(JsFuture::from(promise).await.is_err());
+}
@@ -0,0 +1,4 @@
+#[path = "js/call_3.rs"]
+pub mod call_3;
+
+use super::*;
@@ -0,0 +1,46 @@
+#[path = "call_3/with_global_function_returns_ok_result.rs"]
+pub mod with_global_function_returns_ok_result;
+
+use super::*;
+
+use wasm_bindgen::JsCast;
+
+use js_sys::{Reflect, Symbol};
+
+#[wasm_bindgen_test]
+async fn with_global_function_returns_ok_result() {
+    start_once();
+
+    let promise = r#async::apply_3::promise(
+        module(),
+        with_global_function_returns_ok_result::function(),
+        vec![],
+        Default::default(),
+    )
+    .unwrap();
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    assert!(
+        js_sys::Array::is_array(&resolved),
+        "{:?} is not an array",
+        resolved
+    );
+
+    let resolved_array: js_sys::Array = resolved.dyn_into().unwrap();
+
+    assert_eq!(resolved_array.length(), 2);
+
+    let ok: JsValue = Symbol::for_("ok").into();
+    assert_eq!(Reflect::get(&resolved_array, &0.into()).unwrap(), ok);
+
+    let result = Reflect::get(&resolved_array, &1.into()).unwrap();
+    assert_eq!(result.as_f64(), Some(42.0));
+}
+
+fn module() -> Atom {
+    Atom::from_str("Elixir.Lumen.Web.JS.Call3")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,29 @@
+//! ```elixir
+//! {:ok, result} = :js.call(:global, "parseInt", ["42"])
+//! ```
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_web::js::call_3;
+
+#[native_implemented::function(Elixir.Lumen.Web.JS.Call3:with_global_function_returns_ok_result/0)]
+fn result(process: &Process) -> Term {
+    let target = atom!("global");
+    let function = process.binary_from_str("parseInt");
+    let argument = process.binary_from_str("42");
+    let arguments = process.list_from_slice(&[argument]);
+
+    // ```elixir
+    // # pushed to stack: (target, function, arguments)
+    // # returned from call: N/A
+    // # full stack: ()
+    // # returns: {:ok, result}
+    // ```
+    process.queue_frame_with_arguments(
+        call_3::frame().with_arguments(false, &[target, function, arguments]),
+    );
+
+    Term::NONE
+}
@@ -0,0 +1,6 @@
+#[path = "lumen_web/websocket.rs"]
+pub mod websocket;
+#[path = "lumen_web/websocket_connect_1.rs"]
+mod websocket_connect_1;
+
+use super::*;
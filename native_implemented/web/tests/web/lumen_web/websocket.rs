@@ -0,0 +1,118 @@
+//! `lumen_web:websocket_send/2`, `lumen_web:websocket_close/1` and the `{websocket, WebSocket,
+//! Event}` messages, against a mock `WebSocket` that echoes what is sent to it, like
+//! `wss://echo.websocket.org`, so that the tests don't depend on the network.
+
+#[path = "websocket/sends_frames_and_receives_events.rs"]
+pub mod sends_frames_and_receives_events;
+
+use super::*;
+
+use js_sys::{Function, Reflect, Symbol};
+
+use wasm_bindgen::JsCast;
+
+use liblumen_alloc::erts::fragment::HeapFragment;
+
+/// Fires `open` and echoes each frame as a message on later tasks, like a real `WebSocket`.
+const MOCK_WEB_SOCKET: &str = r#"
+return class MockWebSocket {
+  constructor(url) {
+    this.url = url;
+    this.readyState = 0;
+
+    setTimeout(() => {
+      this.readyState = 1;
+      this.onopen(new Event("open"));
+    });
+  }
+
+  send(data) {
+    const echoed = typeof data === "string" ? data : data.slice().buffer;
+
+    setTimeout(() => this.onmessage(new MessageEvent("message", { data: echoed })));
+  }
+
+  close() {
+    this.readyState = 2;
+
+    setTimeout(() => {
+      this.readyState = 3;
+      this.onclose(new CloseEvent("close", { code: 1000, reason: "closed" }));
+    });
+  }
+};
+"#;
+
+#[wasm_bindgen_test]
+async fn sends_frames_and_receives_events() {
+    start_once();
+
+    let global = js_sys::global();
+    let web_socket_key: JsValue = "WebSocket".into();
+    let web_socket_class = Reflect::get(&global, &web_socket_key).unwrap();
+    let mock_web_socket_class = Function::new_no_args(MOCK_WEB_SOCKET)
+        .call0(&JsValue::NULL)
+        .unwrap();
+    Reflect::set(&global, &web_socket_key, &mock_web_socket_class).unwrap();
+
+    let (url, url_non_null_heap_fragment) =
+        HeapFragment::new_binary_from_str("wss://mock.invalid").unwrap();
+
+    let promise = r#async::apply_3::promise(
+        module(),
+        sends_frames_and_receives_events::function(),
+        vec![url],
+        Default::default(),
+    )
+    .unwrap();
+
+    std::mem::drop(url_non_null_heap_fragment);
+
+    let resolved = JsFuture::from(promise).await;
+
+    Reflect::set(&global, &web_socket_key, &web_socket_class).unwrap();
+
+    let resolved_array: js_sys::Array = resolved.unwrap().dyn_into().unwrap();
+
+    assert_eq!(resolved_array.length(), 9);
+
+    assert_tuple(
+        &resolved_array.get(0),
+        &[symbol("error"), symbol("connecting")],
+    );
+    assert_eq!(resolved_array.get(1), symbol("open"));
+    assert_eq!(resolved_array.get(2), symbol("ok"));
+    assert_tuple(&resolved_array.get(3), &[symbol("text"), "hello".into()]);
+    assert_eq!(resolved_array.get(4), symbol("ok"));
+    assert_tuple(&resolved_array.get(5), &[symbol("binary"), "bytes".into()]);
+    assert_eq!(resolved_array.get(6), symbol("ok"));
+    assert_tuple(
+        &resolved_array.get(7),
+        &[symbol("close"), 1000.into(), "closed".into()],
+    );
+    assert_tuple(&resolved_array.get(8), &[symbol("error"), symbol("closed")]);
+}
+
+fn assert_tuple(js_value: &JsValue, elements: &[JsValue]) {
+    assert!(
+        js_sys::Array::is_array(js_value),
+        "{:?} is not an array",
+        js_value
+    );
+
+    let array: &js_sys::Array = js_value.unchecked_ref();
+
+    assert_eq!(array.iter().collect::<Vec<JsValue>>(), elements);
+}
+
+fn symbol(name: &str) -> JsValue {
+    Symbol::for_(name).into()
+}
+
+fn module() -> Atom {
+    Atom::from_str("Elixir.Lumen.Web.LumenWeb.WebSocket")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,101 @@
+//! ```erlang
+//! {ok, WebSocket} = lumen_web:websocket_connect(Url),
+//! Connecting = lumen_web:websocket_send(WebSocket, {text, <<"hello">>}),
+//! % label 1
+//! receive {websocket, WebSocket, Open} -> ok end,
+//! SentText = lumen_web:websocket_send(WebSocket, {text, <<"hello">>}),
+//! % label 2
+//! receive {websocket, WebSocket, Text} -> ok end,
+//! SentBinary = lumen_web:websocket_send(WebSocket, {binary, <<"bytes">>}),
+//! % label 3
+//! receive {websocket, WebSocket, Binary} -> ok end,
+//! Closing = lumen_web:websocket_close(WebSocket),
+//! % label 4
+//! receive {websocket, WebSocket, Close} -> ok end,
+//! Closed = lumen_web:websocket_send(WebSocket, {text, <<"hello">>}),
+//! {Connecting, Open, SentText, Text, SentBinary, Binary, Closing, Close, Closed}.
+//! ```
+
+#[path = "sends_frames_and_receives_events/label_1.rs"]
+pub mod label_1;
+#[path = "sends_frames_and_receives_events/label_2.rs"]
+pub mod label_2;
+#[path = "sends_frames_and_receives_events/label_3.rs"]
+pub mod label_3;
+#[path = "sends_frames_and_receives_events/label_4.rs"]
+pub mod label_4;
+
+use std::convert::TryInto;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_web::lumen_web::{websocket_connect_1, websocket_send_2};
+
+#[native_implemented::function(Elixir.Lumen.Web.LumenWeb.WebSocket:sends_frames_and_receives_events/1)]
+fn result(process: &Process, url: Term) -> exception::Result<Term> {
+    let ok_web_socket = websocket_connect_1::result(process, url)?;
+    let ok_web_socket_tuple: Boxed<Tuple> = ok_web_socket.try_into().unwrap();
+    assert_eq!(ok_web_socket_tuple.len(), 2);
+    assert_eq!(ok_web_socket_tuple[0], atom!("ok"));
+    let web_socket = ok_web_socket_tuple[1];
+
+    // `open` is only sent on a later task, so the connection is still connecting
+    let connecting = websocket_send_2::result(process, web_socket, text_frame(process))?;
+    let results = process.tuple_from_slice(&[connecting]);
+
+    process
+        .queue_frame_with_arguments(label_1::frame().with_arguments(false, &[web_socket, results]));
+
+    Ok(Term::NONE)
+}
+
+/// Returns `results` with `terms` appended.  `results` is a tuple, so that it resolves to an array.
+fn append(process: &Process, results: Term, terms: &[Term]) -> Term {
+    let results_tuple: Boxed<Tuple> = results.try_into().unwrap();
+    let mut result_vec: Vec<Term> = results_tuple.iter().copied().collect();
+    result_vec.extend_from_slice(terms);
+
+    process.tuple_from_slice(&result_vec)
+}
+
+fn is_event(message: Term, web_socket: Term) -> bool {
+    match message.decode().unwrap() {
+        TypedTerm::Tuple(tuple) => {
+            tuple.len() == 3 && tuple[0] == atom!("websocket") && tuple[1] == web_socket
+        }
+        _ => false,
+    }
+}
+
+/// Removes the first `{websocket, web_socket, event}` message from the mailbox and returns
+/// `event`.  Returns `None` and makes `process` wait if there is no such message yet.
+fn receive_event(process: &Process, web_socket: Term) -> Option<Term> {
+    // Check the mailbox and wait under its lock, so that a message sent in between still wakes
+    // the process
+    let mailbox_guard = process.mailbox.lock();
+    let mut mailbox = mailbox_guard.borrow_mut();
+    let option_cursor =
+        (0..mailbox.len()).find(|cursor| is_event(mailbox.recv_peek(*cursor).unwrap(), web_socket));
+
+    match option_cursor {
+        Some(cursor) => {
+            let message = mailbox.recv_move_to_heap(cursor, process).unwrap();
+            mailbox.recv_received(cursor);
+            let message_tuple: Boxed<Tuple> = message.try_into().unwrap();
+
+            Some(message_tuple[2])
+        }
+        None => {
+            process.wait();
+
+            None
+        }
+    }
+}
+
+fn text_frame(process: &Process) -> Term {
+    process.tuple_from_slice(&[atom!("text"), process.binary_from_str("hello")])
+}
@@ -0,0 +1,37 @@
+//! ```erlang
+//! % label 1
+//! % pushed to stack: (WebSocket, Results)
+//! % returned from call: N/A
+//! % full stack: (WebSocket, Results)
+//! % returns: Results
+//! receive {websocket, WebSocket, Open} -> ok end,
+//! SentText = lumen_web:websocket_send(WebSocket, {text, <<"hello">>}),
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_web::lumen_web::websocket_send_2;
+
+use super::{append, label_2, receive_event, text_frame};
+
+#[native_implemented::label]
+fn result(process: &Process, web_socket: Term, results: Term) -> exception::Result<Term> {
+    match receive_event(process, web_socket) {
+        Some(open) => {
+            let sent_text = websocket_send_2::result(process, web_socket, text_frame(process))?;
+            let results = append(process, results, &[open, sent_text]);
+
+            process.queue_frame_with_arguments(
+                label_2::frame().with_arguments(false, &[web_socket, results]),
+            );
+        }
+        None => {
+            process
+                .queue_frame_with_arguments(frame().with_arguments(false, &[web_socket, results]));
+        }
+    }
+
+    Ok(Term::NONE)
+}
@@ -0,0 +1,40 @@
+//! ```erlang
+//! % label 2
+//! % pushed to stack: (WebSocket, Results)
+//! % returned from call: N/A
+//! % full stack: (WebSocket, Results)
+//! % returns: Results
+//! receive {websocket, WebSocket, Text} -> ok end,
+//! SentBinary = lumen_web:websocket_send(WebSocket, {binary, <<"bytes">>}),
+//! ```
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_web::lumen_web::websocket_send_2;
+
+use super::{append, label_3, receive_event};
+
+#[native_implemented::label]
+fn result(process: &Process, web_socket: Term, results: Term) -> exception::Result<Term> {
+    match receive_event(process, web_socket) {
+        Some(text) => {
+            let binary_frame =
+                process.tuple_from_slice(&[atom!("binary"), process.binary_from_str("bytes")]);
+            let sent_binary = websocket_send_2::result(process, web_socket, binary_frame)?;
+            let results = append(process, results, &[text, sent_binary]);
+
+            process.queue_frame_with_arguments(
+                label_3::frame().with_arguments(false, &[web_socket, results]),
+            );
+        }
+        None => {
+            process
+                .queue_frame_with_arguments(frame().with_arguments(false, &[web_socket, results]));
+        }
+    }
+
+    Ok(Term::NONE)
+}
@@ -0,0 +1,37 @@
+//! ```erlang
+//! % label 3
+//! % pushed to stack: (WebSocket, Results)
+//! % returned from call: N/A
+//! % full stack: (WebSocket, Results)
+//! % returns: Results
+//! receive {websocket, WebSocket, Binary} -> ok end,
+//! Closing = lumen_web:websocket_close(WebSocket),
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_web::lumen_web::websocket_close_1;
+
+use super::{append, label_4, receive_event};
+
+#[native_implemented::label]
+fn result(process: &Process, web_socket: Term, results: Term) -> exception::Result<Term> {
+    match receive_event(process, web_socket) {
+        Some(binary) => {
+            let closing = websocket_close_1::result(web_socket)?;
+            let results = append(process, results, &[binary, closing]);
+
+            process.queue_frame_with_arguments(
+                label_4::frame().with_arguments(false, &[web_socket, results]),
+            );
+        }
+        None => {
+            process
+                .queue_frame_with_arguments(frame().with_arguments(false, &[web_socket, results]));
+        }
+    }
+
+    Ok(Term::NONE)
+}
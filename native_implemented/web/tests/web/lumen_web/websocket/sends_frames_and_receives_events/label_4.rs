@@ -0,0 +1,35 @@
+//! ```erlang
+//! % label 4
+//! % pushed to stack: (WebSocket, Results)
+//! % returned from call: N/A
+//! % full stack: (WebSocket, Results)
+//! % returns: Results
+//! receive {websocket, WebSocket, Close} -> ok end,
+//! Closed = lumen_web:websocket_send(WebSocket, {text, <<"hello">>}),
+//! {Connecting, Open, SentText, Text, SentBinary, Binary, Closing, Close, Closed}.
+//! ```
+
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_web::lumen_web::websocket_send_2;
+
+use super::{append, receive_event, text_frame};
+
+#[native_implemented::label]
+fn result(process: &Process, web_socket: Term, results: Term) -> exception::Result<Term> {
+    match receive_event(process, web_socket) {
+        Some(close) => {
+            let closed = websocket_send_2::result(process, web_socket, text_frame(process))?;
+
+            Ok(append(process, results, &[close, closed]))
+        }
+        None => {
+            process
+                .queue_frame_with_arguments(frame().with_arguments(false, &[web_socket, results]));
+
+            Ok(Term::NONE)
+        }
+    }
+}
@@ -0,0 +1,4 @@
+#[path = "scheduler_loop/receive_after.rs"]
+pub mod receive_after;
+
+use super::*;
@@ -0,0 +1,36 @@
+#[path = "receive_after/resumes_after_timeout.rs"]
+pub mod resumes_after_timeout;
+
+use self::resumes_after_timeout::MILLISECONDS;
+use super::*;
+
+use js_sys::{Date, Symbol};
+
+#[wasm_bindgen_test]
+async fn resumes_after_timeout() {
+    start_once();
+
+    let start = Date::now();
+    let promise = r#async::apply_3::promise(
+        module(),
+        resumes_after_timeout::function(),
+        vec![],
+        Default::default(),
+    )
+    .unwrap();
+    // Nothing else wakes the scheduler loop while the process waits, so this only resolves if the
+    // loop sleeps with a `setTimeout` until the receive timeout
+    let resolved = JsFuture::from(promise).await.unwrap();
+
+    let ok: JsValue = Symbol::for_("ok").into();
+    assert_eq!(resolved, ok);
+    assert!((MILLISECONDS as f64) <= Date::now() - start);
+}
+
+fn module() -> Atom {
+    Atom::from_str("Elixir.Lumen.Web.SchedulerLoop.ReceiveAfter")
+}
+
+fn module_id() -> usize {
+    module().id()
+}
@@ -0,0 +1,29 @@
+//! ```elixir
+//! milliseconds = 100
+//! receive do
+//! after
+//!   milliseconds -> :ok
+//! end
+//! ```
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use liblumen_otp::timer::sleep_1;
+
+pub const MILLISECONDS: u64 = 100;
+
+#[native_implemented::function(Elixir.Lumen.Web.SchedulerLoop.ReceiveAfter:resumes_after_timeout/0)]
+fn result(process: &Process) -> Term {
+    let milliseconds = process.integer(MILLISECONDS);
+
+    // ```elixir
+    // # pushed to stack: (milliseconds)
+    // # returned from call: N/A
+    // # full stack: ()
+    // # returns: :ok
+    // ```
+    process.queue_frame_with_arguments(sleep_1::frame().with_arguments(false, &[milliseconds]));
+
+    Term::NONE
+}
@@ -44,7 +44,7 @@ pub extern "C" fn builtin_map_insert(map: Term, key: Term, value: Term) -> Term
     let decoded_map: Result<Boxed<Map>, _> = map.decode().unwrap().try_into();
     if let Ok(m) = decoded_map {
         if let Some(new_map) = m.put(key, value) {
-            current_process().map_from_hash_map(new_map)
+            current_process().map(new_map)
         } else {
             map
         }
@@ -58,7 +58,7 @@ pub extern "C" fn builtin_map_update(map: Term, key: Term, value: Term) -> Term
     let decoded_map: Result<Boxed<Map>, _> = map.decode().unwrap().try_into();
     if let Ok(m) = decoded_map {
         if let Some(new_map) = m.update(key, value) {
-            current_process().map_from_hash_map(new_map)
+            current_process().map(new_map)
         } else {
             // TODO: Trigger badkey error
             Term::NONE
@@ -477,65 +477,49 @@ pub extern "C" fn builtin_binary_push_string(
 
 #[export_name = "__lumen_builtin_binary_match.raw"]
 pub extern "C" fn builtin_binary_match_raw(bin: Term, unit: u8, size: Term) -> BinaryMatchResult {
-    let size_opt = if size.is_none() {
-        None
-    } else {
-        let size_decoded: Result<SmallInteger, _> = size.decode().unwrap().try_into();
-        // TODO: Should throw badarg
-        let size: usize = size_decoded.unwrap().try_into().unwrap();
-        Some(size)
-    };
-    let result = match bin.decode().unwrap() {
-        TypedTerm::HeapBinary(bin) => binary::matcher::match_raw(bin, unit, size_opt),
-        TypedTerm::ProcBin(bin) => binary::matcher::match_raw(bin, unit, size_opt),
-        TypedTerm::BinaryLiteral(bin) => binary::matcher::match_raw(bin, unit, size_opt),
-        TypedTerm::SubBinary(bin) => binary::matcher::match_raw(bin, unit, size_opt),
-        TypedTerm::MatchContext(bin) => binary::matcher::match_raw(bin, unit, size_opt),
-        _ => Err(()),
-    };
-    result.unwrap_or_else(|_| BinaryMatchResult::failed())
+    binary::matcher::match_raw(&current_process(), bin, unit, size)
 }
 
 #[export_name = "__lumen_builtin_binary_match.integer"]
 pub extern "C" fn builtin_binary_match_integer(
-    _bin: Term,
-    _signed: bool,
-    _endianness: Endianness,
-    _unit: u8,
-    _size: Term,
+    bin: Term,
+    signed: bool,
+    endianness: Endianness,
+    unit: u8,
+    size: Term,
 ) -> BinaryMatchResult {
-    unimplemented!()
+    binary::matcher::match_integer(&current_process(), bin, signed, endianness, unit, size)
 }
 
 #[export_name = "__lumen_builtin_binary_match.float"]
 pub extern "C" fn builtin_binary_match_float(
-    _bin: Term,
-    _endianness: Endianness,
-    _unit: u8,
-    _size: Term,
+    bin: Term,
+    endianness: Endianness,
+    unit: u8,
+    size: Term,
 ) -> BinaryMatchResult {
-    unimplemented!()
+    binary::matcher::match_float(&current_process(), bin, endianness, unit, size)
 }
 
 #[export_name = "__lumen_builtin_binary_match.utf8"]
-pub extern "C" fn builtin_binary_match_utf8(_bin: Term, _size: Term) -> BinaryMatchResult {
-    unimplemented!()
+pub extern "C" fn builtin_binary_match_utf8(bin: Term, size: Term) -> BinaryMatchResult {
+    binary::matcher::match_utf8(&current_process(), bin, size)
 }
 
 #[export_name = "__lumen_builtin_binary_match.utf16"]
 pub extern "C" fn builtin_binary_match_utf16(
-    _bin: Term,
-    _endianness: Endianness,
-    _size: Term,
+    bin: Term,
+    endianness: Endianness,
+    size: Term,
 ) -> BinaryMatchResult {
-    unimplemented!()
+    binary::matcher::match_utf16(&current_process(), bin, endianness, size)
 }
 
 #[export_name = "__lumen_builtin_binary_match.utf32"]
 pub extern "C" fn builtin_binary_match_utf32(
-    _bin: Term,
-    _endianness: Endianness,
-    _size: Term,
+    bin: Term,
+    endianness: Endianness,
+    size: Term,
 ) -> BinaryMatchResult {
-    unimplemented!();
+    binary::matcher::match_utf32(&current_process(), bin, endianness, size)
 }
@@ -0,0 +1,357 @@
+//! The code server loads modules at runtime and purges their old versions.
+//!
+//! Like the BEAM, each module has at most two versions.  `load_binary` makes a newly compiled
+//! module the current version, so that calls resolved through the dispatch table, such as
+//! `apply/3` and `spawn/3`, go to it, while processes already running the previous version keep
+//! running it as old code until it is purged.
+//!
+//! Only native objects can be loaded: shared libraries compiled by lumen, which carry their own
+//! atom and symbol tables.  There is no interpreter to fall back to, so other binaries, such as
+//! BEAM files, are `badfile`.
+
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::apply;
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::Process;
+
+use crate::registry;
+
+#[derive(Debug, Error)]
+pub enum LoadError {
+    #[error("{filename} is not a loadable object: {message}")]
+    Badfile { filename: String, message: String },
+    #[error("module ({module}) must be purged before loading")]
+    NotPurged { module: Atom },
+}
+
+impl LoadError {
+    /// The `What` in `{error, What}` returned by `code:load_binary/3`
+    pub fn reason(&self) -> &'static str {
+        match self {
+            LoadError::Badfile { .. } => "badfile",
+            LoadError::NotPurged { .. } => "not_purged",
+        }
+    }
+}
+
+/// Whether `process` may be running the old version of `module`.
+///
+/// Only the frames queued in `process.frames` can be inspected.  A process running compiled code
+/// on its own native stack keeps its return addresses there, and that stack can't be walked yet,
+/// so such a process is conservatively assumed to be running the old version whenever `module`
+/// has one.
+pub fn check_process_code(process: &Process, module: Atom) -> bool {
+    if !apply::has_old_code(module) {
+        return false;
+    }
+
+    has_native_stack(process) || is_running_old_code_in_frames(process, module)
+}
+
+/// Loads `bytes`, the object code of `module` read from `filename`, as the current version of
+/// `module`.
+pub fn load_binary(module: Atom, filename: &str, bytes: &[u8]) -> Result<(), LoadError> {
+    let functions = object::load(module, filename, bytes)?;
+
+    if apply::load_module(module, functions) {
+        Ok(())
+    } else {
+        Err(LoadError::NotPurged { module })
+    }
+}
+
+/// Kills the processes running the old version of `module` and then removes it.
+///
+/// Unlike `check_process_code`, only processes whose queued frames show the old version are
+/// killed, as assuming that every process with a native stack runs it would kill all of them.
+///
+/// Returns `false` if `module` has no old version.
+pub fn purge(module: Atom) -> bool {
+    if !apply::has_old_code(module) {
+        return false;
+    }
+
+    for arc_process in registry::processes()
+        .into_iter()
+        .filter(|arc_process| is_running_old_code_in_frames(arc_process, module))
+    {
+        kill(&arc_process);
+    }
+
+    apply::purge_module(module)
+}
+
+/// Removes the old version of `module` if no process is running it.
+///
+/// Returns `false` if a process may be running the old version, as decided by
+/// `check_process_code`.
+pub fn soft_purge(module: Atom) -> bool {
+    if registry::processes()
+        .iter()
+        .all(|arc_process| !check_process_code(arc_process, module))
+    {
+        apply::purge_module(module);
+
+        true
+    } else {
+        false
+    }
+}
+
+// Private
+
+fn kill(arc_process: &Arc<Process>) {
+    arc_process.exit(atom!("killed"), Trace::capture(), None);
+    arc_process.scheduler().unwrap().stop_waiting(arc_process);
+}
+
+// Processes spawned to run compiled code get a native stack, while the ones only running native
+// functions through `frames` don't.
+fn has_native_stack(process: &Process) -> bool {
+    !process.stack().lock().base.is_null()
+}
+
+fn is_running_old_code_in_frames(process: &Process, module: Atom) -> bool {
+    process.frames.lock().iter().any(|frame| {
+        let module_function_arity = frame.module_function_arity();
+
+        module_function_arity.module == module
+            && apply::is_old_code(&module_function_arity, frame.native().ptr())
+    })
+}
+
+#[cfg(unix)]
+mod object {
+    use core::ffi::c_void;
+    use core::slice;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use std::ffi::{CStr, CString};
+    use std::fs;
+
+    use hashbrown::HashMap;
+    use libc::{c_char, c_uint};
+
+    use liblumen_core::atoms::ConstantAtom;
+    use liblumen_core::symbols::FunctionSymbol;
+
+    use liblumen_alloc::erts::term::prelude::*;
+    use liblumen_alloc::Arity;
+
+    use super::LoadError;
+
+    const ELF_MAGIC: &[u8] = b"\x7fELF";
+    const MACH_O_64_MAGIC: &[u8] = &[0xcf, 0xfa, 0xed, 0xfe];
+
+    pub fn load(
+        module: Atom,
+        filename: &str,
+        bytes: &[u8],
+    ) -> Result<HashMap<(Atom, Arity), *const c_void>, LoadError> {
+        let badfile = |message: String| LoadError::Badfile {
+            filename: filename.to_string(),
+            message,
+        };
+
+        if !(bytes.starts_with(ELF_MAGIC) || bytes.starts_with(MACH_O_64_MAGIC)) {
+            return Err(badfile("not a native object".to_string()));
+        }
+
+        // `dlopen` only opens files, so the object is written out first.  Each load gets its own
+        // file, as the dynamic linker would otherwise return the previously loaded version.
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = std::env::temp_dir().join(format!(
+            "lumen-{}-{}-{}.so",
+            std::process::id(),
+            module,
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        fs::write(&path, bytes)
+            .map_err(|error| badfile(format!("could not write {}: {}", path.display(), error)))?;
+
+        let c_path = CString::new(path.to_string_lossy().into_owned()).unwrap();
+        // The object is never closed, as processes killed by a purge may still be running its code
+        // until their scheduler notices.
+        let handle = unsafe { libc::dlopen(c_path.as_ptr(), libc::RTLD_NOW | libc::RTLD_LOCAL) };
+        let _ = fs::remove_file(&path);
+
+        if handle.is_null() {
+            return Err(badfile(dlerror()));
+        }
+
+        let atom_table = unsafe { symbol::<*const ConstantAtom>(handle, "__LUMEN_ATOM_TABLE\0") };
+        let atom_table_size = unsafe { symbol::<c_uint>(handle, "__LUMEN_ATOM_TABLE_SIZE\0") };
+        let symbol_table =
+            unsafe { symbol::<*const FunctionSymbol>(handle, "__LUMEN_SYMBOL_TABLE\0") };
+        let symbol_table_size = unsafe { symbol::<usize>(handle, "__LUMEN_SYMBOL_TABLE_SIZE\0") };
+
+        let (atom_table, atom_table_size, symbol_table, symbol_table_size) =
+            match (atom_table, atom_table_size, symbol_table, symbol_table_size) {
+                (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+                _ => return Err(badfile("no atom or symbol table".to_string())),
+            };
+
+        // The object's code uses the ids in its atom table as constants, so they must be the ids
+        // the running system has for the same atoms.
+        let constant_atoms = unsafe { slice::from_raw_parts(atom_table, atom_table_size as usize) };
+
+        for ConstantAtom { id, value } in constant_atoms {
+            let name = unsafe { CStr::from_ptr(*value as *const c_char) }.to_string_lossy();
+
+            match Atom::try_from_str(&name) {
+                Ok(atom) if atom.id() == *id => (),
+                _ => {
+                    return Err(badfile(format!(
+                        "atom ({}) does not have the same id ({}) as in the running system",
+                        name, id
+                    )))
+                }
+            }
+        }
+
+        let function_symbols =
+            unsafe { slice::from_raw_parts(symbol_table, symbol_table_size as usize) };
+        let functions: HashMap<(Atom, Arity), *const c_void> = function_symbols
+            .iter()
+            .filter(|function_symbol| function_symbol.module == module.id())
+            .map(|function_symbol| {
+                (
+                    // The function's atom was checked with the rest of the atom table
+                    (
+                        unsafe { Atom::from_id(function_symbol.function) },
+                        function_symbol.arity,
+                    ),
+                    function_symbol.ptr,
+                )
+            })
+            .collect();
+
+        if functions.is_empty() {
+            Err(badfile(format!("does not define module ({})", module)))
+        } else {
+            Ok(functions)
+        }
+    }
+
+    fn dlerror() -> String {
+        let message = unsafe { libc::dlerror() };
+
+        if message.is_null() {
+            "unknown error".to_string()
+        } else {
+            unsafe { CStr::from_ptr(message) }
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+
+    /// Reads the global named `name`, which must be NUL-terminated.
+    unsafe fn symbol<T: Copy>(handle: *mut c_void, name: &str) -> Option<T> {
+        let address = libc::dlsym(handle, name.as_ptr() as *const c_char) as *const T;
+
+        if address.is_null() {
+            None
+        } else {
+            Some(*address)
+        }
+    }
+}
+
+#[cfg(not(unix))]
+mod object {
+    use core::ffi::c_void;
+
+    use hashbrown::HashMap;
+
+    use liblumen_alloc::erts::term::prelude::*;
+    use liblumen_alloc::Arity;
+
+    use super::LoadError;
+
+    pub fn load(
+        _module: Atom,
+        filename: &str,
+        _bytes: &[u8],
+    ) -> Result<HashMap<(Atom, Arity), *const c_void>, LoadError> {
+        Err(LoadError::Badfile {
+            filename: filename.to_string(),
+            message: "native objects can't be loaded on this target".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use hashbrown::HashMap;
+
+    use liblumen_alloc::erts::process::{alloc, Priority};
+    use liblumen_alloc::erts::ModuleFunctionArity;
+
+    #[test]
+    fn check_process_code_without_old_code_returns_false() {
+        let module = Atom::from_str("code_check_process_code_without_old_code");
+        let process = process_with_stack(module);
+
+        assert!(!check_process_code(&process, module));
+    }
+
+    #[test]
+    fn check_process_code_with_old_code_with_native_stack_returns_true() {
+        let module = Atom::from_str("code_check_process_code_with_native_stack");
+        load_twice(module);
+        let process = process_with_stack(module);
+
+        assert!(check_process_code(&process, module));
+    }
+
+    #[test]
+    fn check_process_code_with_old_code_without_native_stack_or_frames_returns_false() {
+        let module = Atom::from_str("code_check_process_code_without_native_stack");
+        load_twice(module);
+        let (heap, heap_size) = alloc::default_heap().unwrap();
+        let process = Process::new(
+            Priority::Normal,
+            None,
+            module_function_arity(module),
+            heap,
+            heap_size,
+        );
+
+        assert!(!check_process_code(&process, module));
+    }
+
+    fn load_twice(module: Atom) {
+        assert!(apply::load_module(module, HashMap::new()));
+        assert!(apply::load_module(module, HashMap::new()));
+        assert!(apply::has_old_code(module));
+    }
+
+    fn module_function_arity(module: Atom) -> ModuleFunctionArity {
+        ModuleFunctionArity {
+            module,
+            function: Atom::from_str("init"),
+            arity: 0,
+        }
+    }
+
+    fn process_with_stack(module: Atom) -> Process {
+        let (heap, heap_size) = alloc::default_heap().unwrap();
+
+        Process::new_with_stack(
+            Priority::Normal,
+            None,
+            module_function_arity(module),
+            heap,
+            heap_size,
+        )
+        .unwrap()
+    }
+}
@@ -35,6 +35,10 @@ pub fn term_is_not_binary(name: &str, value: Term) -> String {
     term_is_not_type(name, value, "a binary")
 }
 
+pub fn term_is_not_function(name: &str, value: Term) -> String {
+    term_is_not_type(name, value, "a function")
+}
+
 pub fn term_is_not_integer(name: &str, value: Term) -> String {
     term_is_not_type(name, value, "an integer")
 }
@@ -111,6 +115,28 @@ pub fn terms_try_into_bools(
     Ok((left_bool, right_bool))
 }
 
+pub fn term_try_into_function_with_arity(
+    name: &str,
+    value: Term,
+    arity: u8,
+) -> anyhow::Result<Boxed<Closure>> {
+    let boxed_closure: Boxed<Closure> = value
+        .try_into()
+        .with_context(|| term_is_not_function(name, value))?;
+
+    if boxed_closure.arity() == arity {
+        Ok(boxed_closure)
+    } else {
+        Err(anyhow!(
+            "{} ({}) arity ({}) is not {}",
+            name,
+            value,
+            boxed_closure.arity(),
+            arity
+        ))
+    }
+}
+
 pub fn term_try_into_isize(name: &str, value: Term) -> anyhow::Result<isize> {
     value
         .try_into()
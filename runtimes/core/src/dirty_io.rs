@@ -0,0 +1,120 @@
+//! A pool of threads for blocking IO, like the dirty IO schedulers of the BEAM, so that a process
+//! doing file IO does not block the other processes on its scheduler.
+//!
+//! A native function spawns its IO with `spawn` and then queues a label that calls
+//! `Output::take_or_wait` until the output is ready.
+
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::Mutex;
+
+use liblumen_alloc::erts::term::prelude::Pid;
+use liblumen_alloc::erts::Process;
+
+use crate::registry;
+use crate::scheduler::Scheduled;
+
+/// The number of threads, which is the same as the BEAM's default number of dirty IO schedulers.
+pub const THREADS: usize = 10;
+
+/// The output of a job spawned with `spawn`.
+///
+/// It is `Clone` so that it can be stored in a resource and passed to the label that takes it.
+pub struct Output<T>(Arc<Mutex<Option<T>>>);
+
+impl<T> Output<T> {
+    pub(crate) fn new() -> Self {
+        Self(Arc::new(Mutex::new(None)))
+    }
+
+    /// Stores the output of the job and wakes the process with `pid` that is waiting for it.
+    pub(crate) fn put(&self, pid: &Pid, output: T) {
+        *self.0.lock() = Some(output);
+
+        // The process may have exited while the job ran
+        if let Some(arc_process) = registry::pid_to_process(pid) {
+            if let Some(scheduler) = arc_process.scheduler() {
+                scheduler.stop_waiting(&arc_process);
+            }
+        }
+    }
+
+    /// Takes the output if the job is done, otherwise makes `process` wait until it is.
+    ///
+    /// Returns `None` when `process` should wait.  It may be woken by a message before the job is
+    /// done, so the caller has to call this again when woken.
+    pub fn take_or_wait(&self, process: &Process) -> Option<T> {
+        // The job stores its output under the same lock, so `process` either sees the output or
+        // waits before the job can wake it.
+        let mut guard = self.0.lock();
+
+        match guard.take() {
+            Some(output) => Some(output),
+            None => {
+                process.wait();
+
+                None
+            }
+        }
+    }
+}
+
+impl<T> Clone for Output<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// Runs `job` on the pool, waking `process` when it is done.
+pub fn spawn<T, F>(process: &Process, job: F) -> Output<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let output = Output::new();
+    let job_output = output.clone();
+    let pid = process.pid();
+
+    SENDER
+        .lock()
+        .send(Box::new(move || job_output.put(&pid, job())))
+        .unwrap();
+
+    output
+}
+
+// Private
+
+type Job = Box<dyn FnOnce() + Send>;
+
+lazy_static! {
+    static ref SENDER: Mutex<mpsc::Sender<Job>> = Mutex::new(start());
+}
+
+fn start() -> mpsc::Sender<Job> {
+    let (sender, receiver) = mpsc::channel::<Job>();
+    let arc_receiver = Arc::new(Mutex::new(receiver));
+
+    for index in 0..THREADS {
+        let arc_receiver = arc_receiver.clone();
+
+        thread::Builder::new()
+            .name(format!("dirty_io_{}", index))
+            .spawn(move || loop {
+                // The lock is only held while waiting for a job, so the other threads can take the
+                // next job while this one runs.
+                let received = arc_receiver.lock().recv();
+
+                match received {
+                    Ok(job) => job(),
+                    Err(_) => break,
+                }
+            })
+            .unwrap();
+    }
+
+    sender
+}
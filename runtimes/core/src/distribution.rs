@@ -1,2 +1,140 @@
+pub mod connection;
+mod epmd;
 pub mod external_term_format;
+mod handshake;
 pub mod nodes;
+
+use std::env;
+use std::fs;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread;
+
+use anyhow::*;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::RwLock;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use self::nodes::node;
+
+/// Whether node names use the full host name, like `-name`, or only its first label, like
+/// `-sname`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NameType {
+    Long,
+    Short,
+}
+
+/// Makes the local node alive as `name`, so that it can send messages to and receive messages
+/// from other nodes.
+///
+/// `name` is either `alive@host` or only `alive`, in which case the local host name is used as
+/// the host according to `name_type`.  When `cookie` is `None`, it is read from
+/// `~/.erlang.cookie` like `erl` does.
+pub fn start(name: &str, name_type: NameType, cookie: Option<String>) -> anyhow::Result<()> {
+    ensure!(!node::is_alive(), "local node is already alive");
+
+    let full_name = if name.contains('@') {
+        name.to_string()
+    } else {
+        format!("{}@{}", name, host_name(name_type)?)
+    };
+    let (alive, _) = split_name(&full_name)?;
+    let cookie = match cookie {
+        Some(cookie) => cookie,
+        None => read_cookie_file()?,
+    };
+
+    let listener = TcpListener::bind(("0.0.0.0", 0))
+        .context("could not listen for connections from other nodes")?;
+    let port = listener.local_addr()?.port();
+    let (registration, creation) = epmd::register(alive, port)?;
+
+    *RW_LOCK_COOKIE.write() = cookie;
+    node::set(Atom::try_from_str(&full_name)?, creation);
+
+    thread::spawn(move || {
+        connection::listen(listener);
+        // EPMD unregisters the name when the registration connection closes
+        drop(registration);
+    });
+
+    Ok(())
+}
+
+// Private
+
+fn cookie() -> String {
+    RW_LOCK_COOKIE.read().clone()
+}
+
+#[cfg(unix)]
+fn host_name(name_type: NameType) -> anyhow::Result<String> {
+    let mut buffer = [0_u8; 256];
+    let result =
+        unsafe { libc::gethostname(buffer.as_mut_ptr() as *mut libc::c_char, buffer.len()) };
+    ensure!(result == 0, "could not get host name");
+
+    let len = buffer
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(buffer.len());
+    let host_name = std::str::from_utf8(&buffer[..len]).context("host name is not UTF-8")?;
+
+    match name_type {
+        NameType::Long => Ok(host_name.to_string()),
+        NameType::Short => Ok(host_name.split('.').next().unwrap().to_string()),
+    }
+}
+
+#[cfg(not(unix))]
+fn host_name(_name_type: NameType) -> anyhow::Result<String> {
+    Ok("localhost".to_string())
+}
+
+fn read_cookie_file() -> anyhow::Result<String> {
+    let home = env::var_os("HOME").context("HOME is not set, so ~/.erlang.cookie can't be read")?;
+    let path: PathBuf = [home.as_os_str(), ".erlang.cookie".as_ref()]
+        .iter()
+        .collect();
+    let contents =
+        fs::read_to_string(&path).with_context(|| format!("could not read {}", path.display()))?;
+
+    Ok(contents.trim().to_string())
+}
+
+/// Splits a node name into its alive and host parts.
+fn split_name(name: &str) -> anyhow::Result<(&str, &str)> {
+    let mut parts = name.splitn(2, '@');
+
+    match (parts.next(), parts.next()) {
+        (Some(alive), Some(host)) if !alive.is_empty() && !host.is_empty() => Ok((alive, host)),
+        _ => Err(anyhow!("node name ({}) is not alive@host", name)),
+    }
+}
+
+lazy_static! {
+    static ref RW_LOCK_COOKIE: RwLock<String> = Default::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_name_splits_alive_and_host() {
+        assert_eq!(
+            split_name("lumen@example.com").unwrap(),
+            ("lumen", "example.com")
+        );
+    }
+
+    #[test]
+    fn split_name_without_host_errors() {
+        assert!(split_name("lumen").is_err());
+        assert!(split_name("lumen@").is_err());
+        assert!(split_name("@example.com").is_err());
+    }
+}
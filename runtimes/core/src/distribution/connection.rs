@@ -0,0 +1,315 @@
+//! Connections to other nodes once the handshake has authenticated them.
+//!
+//! After the handshake, each message is prefixed with its 4 byte length.  A 0 length message is
+//! a tick that keeps the connection alive.  All other messages are
+//! [pass through](http://erlang.org/doc/apps/erts/erl_dist_protocol.html#protocol-between-connected-nodes)
+//! messages: a control message, such as `SEND` or `REG_SEND`, and an optional message, both in
+//! the external term format.
+
+use std::convert::TryInto;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Weak};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::*;
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::{Mutex, RwLock};
+
+use liblumen_alloc::erts::process::gc::RootSet;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::{Node, Process};
+
+use crate::distribution::external_term_format::{encode, term, version};
+use crate::distribution::nodes::{self, node};
+use crate::distribution::{epmd, handshake, split_name};
+use crate::process;
+use crate::process::spawn::options::Options;
+use crate::registry;
+
+const PASS_THROUGH: u8 = 112;
+
+const SEND: u8 = 2;
+const REG_SEND: u8 = 6;
+const EXIT2: u8 = 8;
+const SEND_SENDER: u8 = 22;
+
+/// A quarter of the default `net_ticktime`, so the peer hears from this node well within the time
+/// it waits before considering the connection dead.
+const TICK_INTERVAL: Duration = Duration::from_secs(15);
+
+pub struct Connection {
+    arc_node: Arc<Node>,
+    stream: Mutex<TcpStream>,
+}
+
+impl Connection {
+    pub fn arc_node(&self) -> Arc<Node> {
+        self.arc_node.clone()
+    }
+
+    /// `{name, node} ! message`
+    pub fn reg_send(&self, process: &Process, name: Atom, message: Term) -> io::Result<()> {
+        let control = process.tuple_from_slice(&[
+            process.integer(REG_SEND),
+            process.pid_term(),
+            unused(),
+            name.encode().unwrap(),
+        ]);
+
+        self.pass_through(control, Some(message))
+    }
+
+    /// `pid ! message` where `pid` is an external pid on this connection's node.
+    pub fn send(&self, process: &Process, pid: Term, message: Term) -> io::Result<()> {
+        let control = process.tuple_from_slice(&[process.integer(SEND), unused(), pid]);
+
+        self.pass_through(control, Some(message))
+    }
+
+    /// `exit(pid, reason)` where `pid` is an external pid on this connection's node.
+    pub fn exit2(&self, process: &Process, pid: Term, reason: Term) -> io::Result<()> {
+        let control =
+            process.tuple_from_slice(&[process.integer(EXIT2), process.pid_term(), pid, reason]);
+
+        self.pass_through(control, None)
+    }
+
+    // Private
+
+    fn pass_through(&self, control: Term, message: Option<Term>) -> io::Result<()> {
+        // length placeholder
+        let mut byte_vec = vec![0, 0, 0, 0, PASS_THROUGH];
+        byte_vec.append(&mut encode_term(control)?);
+
+        if let Some(message) = message {
+            byte_vec.append(&mut encode_term(message)?);
+        }
+
+        let len: u32 = (byte_vec.len() - 4)
+            .try_into()
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "message is too large"))?;
+        byte_vec[0..4].copy_from_slice(&len.to_be_bytes());
+
+        self.stream.lock().write_all(&byte_vec)
+    }
+
+    fn tick(&self) -> io::Result<()> {
+        self.stream.lock().write_all(&[0, 0, 0, 0])
+    }
+}
+
+/// Connects to the node named `name`, which must be registered with EPMD on its host.
+pub fn connect(name: Atom) -> anyhow::Result<Arc<Connection>> {
+    ensure!(
+        node::is_alive(),
+        "local node is not alive, so it can't connect to node ({})",
+        name
+    );
+
+    let (alive, host) = split_name(name.name())?;
+    let port = epmd::port_please(alive, host)?;
+    let mut stream = TcpStream::connect((host, port))
+        .with_context(|| format!("could not connect to node ({})", name))?;
+    let peer = handshake::connect(&mut stream, &super::cookie())
+        .with_context(|| format!("handshake with node ({}) failed", name))?;
+    ensure!(
+        peer.name == name,
+        "connected to node ({}) instead of node ({})",
+        peer.name,
+        name
+    );
+
+    establish(stream, peer)
+}
+
+pub fn get(name: &Atom) -> Option<Arc<Connection>> {
+    RW_LOCK_CONNECTION_BY_NAME.read().get(name).cloned()
+}
+
+/// Gets the connection to the node named `name`, connecting to it first if there is none yet.
+pub fn get_or_connect(name: Atom) -> anyhow::Result<Arc<Connection>> {
+    match get(&name) {
+        Some(arc_connection) => Ok(arc_connection),
+        None => connect(name),
+    }
+}
+
+/// Accepts connections from other nodes until `listener` fails.
+pub(super) fn listen(listener: TcpListener) {
+    for result in listener.incoming() {
+        match result {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    if let Err(error) = accept(stream) {
+                        log::warn!("Rejected connection from other node: {:?}", error);
+                    }
+                });
+            }
+            Err(error) => {
+                log::error!("Distribution listener failed: {}", error);
+
+                break;
+            }
+        }
+    }
+}
+
+// Private
+
+fn accept(mut stream: TcpStream) -> anyhow::Result<Arc<Connection>> {
+    let peer = handshake::accept(&mut stream, &super::cookie())?;
+
+    establish(stream, peer)
+}
+
+fn decode<'a>(process: &Process, bytes: &'a [u8]) -> anyhow::Result<(Term, &'a [u8])> {
+    let after_version_bytes = version::check(bytes)?;
+    // Unlike `binary_to_term`, atoms are created as needed, as the peer is trusted
+    let decoded = term::decode_tagged(process, false, after_version_bytes)?;
+
+    Ok(decoded)
+}
+
+/// Returns whether the message was sent, as it is dropped if its destination doesn't exist.
+fn deliver(decoder: &Process, bytes: &[u8]) -> anyhow::Result<bool> {
+    let (tag, after_tag_bytes) = bytes.split_first().context("message is empty")?;
+    ensure!(*tag == PASS_THROUGH, "message has unexpected tag ({})", tag);
+
+    let (control, after_control_bytes) = decode(decoder, after_tag_bytes)?;
+    let control_tuple: Boxed<Tuple> = control
+        .try_into()
+        .with_context(|| format!("control message ({}) is not a tuple", control))?;
+    let operation: u8 = control_tuple
+        .get_element(0)
+        .ok()
+        .and_then(|operation| operation.try_into().ok())
+        .with_context(|| format!("control message ({}) has no operation", control))?;
+
+    let destination_arc_process = match (operation, control_tuple.len()) {
+        (SEND, 3) | (SEND_SENDER, 3) => {
+            let pid: Pid = control_tuple[2]
+                .try_into()
+                .with_context(|| format!("control message ({}) is not to a local pid", control))?;
+
+            registry::pid_to_process(&pid)
+        }
+        (REG_SEND, 4) => {
+            let name: Atom = control_tuple[3]
+                .try_into()
+                .with_context(|| format!("control message ({}) is not to a name", control))?;
+
+            registry::atom_to_process(&name)
+        }
+        // Links, monitors and exits from other nodes are not supported yet
+        _ => None,
+    };
+
+    // Like local sends, messages to processes that don't exist are dropped
+    match destination_arc_process {
+        Some(destination_arc_process) => {
+            let (message, _) = decode(decoder, after_control_bytes)?;
+            process::send_from_decoder(decoder, &destination_arc_process, message);
+
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+fn encode_term(term: Term) -> io::Result<Vec<u8>> {
+    encode::term_to_byte_vec(term)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error.to_string()))
+}
+
+fn establish(stream: TcpStream, peer: handshake::Peer) -> anyhow::Result<Arc<Connection>> {
+    let receive_stream = stream.try_clone()?;
+    let arc_connection = Arc::new(Connection {
+        arc_node: nodes::get_or_insert(peer.name, peer.creation),
+        stream: Mutex::new(stream),
+    });
+
+    RW_LOCK_CONNECTION_BY_NAME
+        .write()
+        .insert(peer.name, arc_connection.clone());
+
+    let name = peer.name;
+    thread::spawn(move || {
+        if let Err(error) = receive(receive_stream) {
+            log::warn!("Connection to node ({}) closed: {:?}", name, error);
+        }
+
+        RW_LOCK_CONNECTION_BY_NAME.write().remove(&name);
+    });
+
+    let weak_connection = Arc::downgrade(&arc_connection);
+    thread::spawn(move || tick(weak_connection));
+
+    Ok(arc_connection)
+}
+
+fn receive(mut stream: TcpStream) -> anyhow::Result<()> {
+    // Control messages and messages are decoded onto this process's heap before being copied to
+    // the destination process, as the destination is only known after decoding.
+    let decoder = Options::default()
+        .spawn(
+            None,
+            Atom::from_str("erlang"),
+            Atom::from_str("dist_receive"),
+            0,
+        )
+        .map_err(|alloc| anyhow!("could not allocate decoder heap: {}", alloc))?;
+
+    loop {
+        let mut len_bytes = [0; 4];
+        stream.read_exact(&mut len_bytes)?;
+        let len = u32::from_be_bytes(len_bytes) as usize;
+
+        // tick
+        if len == 0 {
+            continue;
+        }
+
+        let mut bytes = vec![0; len];
+        stream.read_exact(&mut bytes)?;
+
+        let sent = deliver(&decoder, &bytes).unwrap_or_else(|error| {
+            log::warn!("Dropped message from other node: {:?}", error);
+
+            false
+        });
+
+        // Sending empties the decoder heap, but a dropped message leaves whatever was decoded
+        if !sent {
+            let _ = decoder.garbage_collect(0, RootSet::default());
+        }
+    }
+}
+
+fn tick(weak_connection: Weak<Connection>) {
+    loop {
+        thread::sleep(TICK_INTERVAL);
+
+        match weak_connection.upgrade() {
+            Some(arc_connection) => {
+                if arc_connection.tick().is_err() {
+                    break;
+                }
+            }
+            None => break,
+        }
+    }
+}
+
+/// The `Unused` element of control messages
+fn unused() -> Term {
+    Atom::str_to_term("")
+}
+
+lazy_static! {
+    static ref RW_LOCK_CONNECTION_BY_NAME: RwLock<HashMap<Atom, Arc<Connection>>> =
+        Default::default();
+}
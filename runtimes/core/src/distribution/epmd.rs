@@ -0,0 +1,130 @@
+//! Client for the [Erlang Port Mapper Daemon](http://erlang.org/doc/apps/erts/erl_dist_protocol.html#epmd-protocol)
+//! that maps the alive part of node names to the port that the node's distribution listens on.
+
+use std::convert::TryInto;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use anyhow::*;
+
+use super::handshake::VERSION;
+
+const PORT: u16 = 4369;
+
+const ALIVE2_X_RESP: u8 = 118;
+const ALIVE2_REQ: u8 = 120;
+const ALIVE2_RESP: u8 = 121;
+const PORT2_RESP: u8 = 119;
+const PORT_PLEASE2_REQ: u8 = 122;
+
+/// Normal Erlang node, as opposed to a hidden node.
+const NODE_TYPE: u8 = 77;
+/// TCP/IPv4
+const PROTOCOL: u8 = 0;
+
+/// Registers the `alive` name as listening on `port`.  EPMD unregisters the name when the
+/// returned stream is closed, so it must be kept open for as long as the node is alive.
+pub fn register(alive: &str, port: u16) -> anyhow::Result<(TcpStream, u32)> {
+    let mut request = vec![ALIVE2_REQ];
+    request.extend_from_slice(&port.to_be_bytes());
+    request.push(NODE_TYPE);
+    request.push(PROTOCOL);
+    // highest version
+    request.extend_from_slice(&VERSION.to_be_bytes());
+    // lowest version
+    request.extend_from_slice(&VERSION.to_be_bytes());
+    append_u16_len_bytes(&mut request, alive.as_bytes())?;
+    // no extra
+    request.extend_from_slice(&0_u16.to_be_bytes());
+
+    let mut stream = connect("localhost")?;
+    write_request(&mut stream, &request)?;
+
+    let mut tag_result = [0; 2];
+    stream
+        .read_exact(&mut tag_result)
+        .context("EPMD closed connection before responding to ALIVE2_REQ")?;
+
+    let creation = match tag_result {
+        [ALIVE2_X_RESP, 0] => {
+            let mut creation = [0; 4];
+            stream.read_exact(&mut creation)?;
+
+            u32::from_be_bytes(creation)
+        }
+        [ALIVE2_RESP, 0] => {
+            let mut creation = [0; 2];
+            stream.read_exact(&mut creation)?;
+
+            u16::from_be_bytes(creation) as u32
+        }
+        [ALIVE2_X_RESP, result] | [ALIVE2_RESP, result] => {
+            bail!(
+                "EPMD refused to register name ({}) with result ({})",
+                alive,
+                result
+            )
+        }
+        [tag, _] => bail!("EPMD responded to ALIVE2_REQ with unexpected tag ({})", tag),
+    };
+
+    Ok((stream, creation))
+}
+
+/// The port that the node named `alive` on `host` listens on for distribution connections.
+pub fn port_please(alive: &str, host: &str) -> anyhow::Result<u16> {
+    let mut request = vec![PORT_PLEASE2_REQ];
+    request.extend_from_slice(alive.as_bytes());
+
+    let mut stream = connect(host)?;
+    write_request(&mut stream, &request)?;
+
+    let mut tag_result = [0; 2];
+    stream
+        .read_exact(&mut tag_result)
+        .context("EPMD closed connection before responding to PORT_PLEASE2_REQ")?;
+
+    match tag_result {
+        [PORT2_RESP, 0] => {
+            let mut port = [0; 2];
+            stream.read_exact(&mut port)?;
+
+            // The rest of the response describes the node type, protocol and versions, but
+            // anything registered with the same versions as `VERSION` speaks the handshake.
+            Ok(u16::from_be_bytes(port))
+        }
+        [PORT2_RESP, _] => Err(anyhow!(
+            "EPMD on host ({}) has no node registered with name ({})",
+            host,
+            alive
+        )),
+        [tag, _] => Err(anyhow!(
+            "EPMD responded to PORT_PLEASE2_REQ with unexpected tag ({})",
+            tag
+        )),
+    }
+}
+
+// Private
+
+fn append_u16_len_bytes(byte_vec: &mut Vec<u8>, bytes: &[u8]) -> anyhow::Result<()> {
+    let len: u16 = bytes.len().try_into().context("name is too long")?;
+    byte_vec.extend_from_slice(&len.to_be_bytes());
+    byte_vec.extend_from_slice(bytes);
+
+    Ok(())
+}
+
+fn connect(host: &str) -> anyhow::Result<TcpStream> {
+    TcpStream::connect((host, PORT))
+        .with_context(|| format!("could not connect to EPMD on host ({})", host))
+}
+
+/// EPMD requests are prefixed with their 2 byte length
+fn write_request(stream: &mut TcpStream, request: &[u8]) -> anyhow::Result<()> {
+    let len = request.len() as u16;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(request)?;
+
+    Ok(())
+}
@@ -4,6 +4,8 @@ mod atom_utf8;
 mod big;
 mod binary;
 mod bit_binary;
+pub mod compressed;
+pub mod encode;
 mod export;
 mod f64;
 mod i32;
@@ -87,6 +89,7 @@ impl From<DecodeError> for InternalException {
 #[repr(u8)]
 pub enum Tag {
     NewFloat = 70,
+    Compressed = 80,
     BitBinary = 77,
     AtomCacheReference = 82,
     NewPID = 88,
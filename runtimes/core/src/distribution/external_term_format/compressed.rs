@@ -0,0 +1,62 @@
+use std::io::{Read, Write};
+
+use anyhow::*;
+use flate2::bufread::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use liblumen_alloc::erts::exception::InternalResult;
+
+use super::Tag;
+
+// Tag + UncompressedSize
+const HEADER_LEN: usize = 1 + 4;
+
+/// Compresses the tagged term `bytes` that follow the version number into a `COMPRESSED` term.
+///
+/// Like the BEAM, returns `None` when compressing would not make the encoding any smaller.
+pub fn encode(bytes: &[u8], level: u8) -> Option<Vec<u8>> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::new(level as u32));
+    encoder.write_all(bytes).ok()?;
+    let zlib_byte_vec = encoder.finish().ok()?;
+
+    if HEADER_LEN + zlib_byte_vec.len() < bytes.len() {
+        let mut byte_vec = Vec::with_capacity(HEADER_LEN + zlib_byte_vec.len());
+        byte_vec.push(Tag::Compressed.into());
+        byte_vec.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+        byte_vec.extend_from_slice(&zlib_byte_vec);
+
+        Some(byte_vec)
+    } else {
+        None
+    }
+}
+
+/// Inflates the bytes after the `COMPRESSED` tag back into tagged term bytes.
+///
+/// Returns the uncompressed bytes and the bytes after the zlib stream.
+pub fn decode<'a>(bytes: &'a [u8]) -> InternalResult<(Vec<u8>, &'a [u8])> {
+    let (uncompressed_size, after_uncompressed_size_bytes) = super::u32::decode(bytes)?;
+    let uncompressed_size = uncompressed_size as usize;
+
+    let mut decoder = ZlibDecoder::new(after_uncompressed_size_bytes);
+    let mut byte_vec = Vec::with_capacity(uncompressed_size);
+    decoder
+        .by_ref()
+        .take(uncompressed_size as u64 + 1)
+        .read_to_end(&mut byte_vec)
+        .context("compressed term could not be inflated")?;
+
+    if byte_vec.len() == uncompressed_size {
+        let zlib_len = decoder.total_in() as usize;
+
+        Ok((byte_vec, &after_uncompressed_size_bytes[zlib_len..]))
+    } else {
+        Err(anyhow!(
+            "compressed term inflated to {} bytes instead of {} bytes",
+            byte_vec.len(),
+            uncompressed_size
+        )
+        .into())
+    }
+}
@@ -13,6 +13,12 @@ pub fn decode_tagged<'a>(
         Tag::AtomUTF8 => atom_utf8::decode_term(safe, after_tag_bytes),
         Tag::Binary => binary::decode(process, after_tag_bytes),
         Tag::BitBinary => bit_binary::decode(process, after_tag_bytes),
+        // Only valid directly after the version number, so it can't be nested in another term
+        Tag::Compressed => Err(DecodeError::UnexpectedTag {
+            tag,
+            backtrace: Backtrace::capture(),
+        }
+        .into()),
         Tag::Export => export::decode(process, safe, after_tag_bytes),
         Tag::Float => unimplemented!("{:?}", tag),
         Tag::Function => unimplemented!("{:?}", tag),
@@ -0,0 +1,297 @@
+//! The [distribution handshake](http://erlang.org/doc/apps/erts/erl_dist_protocol.html#distribution-handshake)
+//! that authenticates two nodes to each other with their shared cookie.
+//!
+//! Only the version 6 (OTP 23+) handshake is supported, which is what is registered with EPMD, so
+//! peers will never try the version 5 handshake.
+
+use std::convert::TryInto;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::distribution::nodes::node;
+use crate::sys::entropy;
+
+pub const VERSION: u16 = 6;
+
+const EXTENDED_REFERENCES: u64 = 0x4;
+const FUN_TAGS: u64 = 0x10;
+const NEW_FUN_TAGS: u64 = 0x80;
+const EXTENDED_PIDS_PORTS: u64 = 0x100;
+const EXPORT_PTR_TAG: u64 = 0x200;
+const BIT_BINARIES: u64 = 0x400;
+const NEW_FLOATS: u64 = 0x800;
+const UTF8_ATOMS: u64 = 0x1_0000;
+const MAP_TAG: u64 = 0x2_0000;
+const BIG_CREATION: u64 = 0x4_0000;
+const HANDSHAKE_23: u64 = 0x100_0000;
+const UNLINK_ID: u64 = 0x200_0000;
+const MANDATORY_25_DIGEST: u64 = 0x400_0000;
+const V4_NC: u64 = 0x4_0000_0000;
+
+/// The flags that newer nodes require of their peers.  Lumen does not support any optional
+/// features, such as the atom cache or fragments, so they are also all the flags that are sent.
+const FLAGS: u64 = EXTENDED_REFERENCES
+    | FUN_TAGS
+    | NEW_FUN_TAGS
+    | EXTENDED_PIDS_PORTS
+    | EXPORT_PTR_TAG
+    | BIT_BINARIES
+    | NEW_FLOATS
+    | UTF8_ATOMS
+    | MAP_TAG
+    | BIG_CREATION
+    | HANDSHAKE_23
+    | UNLINK_ID
+    | MANDATORY_25_DIGEST
+    | V4_NC;
+
+const SEND_NAME: u8 = b'N';
+const SEND_STATUS: u8 = b's';
+const SEND_CHALLENGE: u8 = b'N';
+const SEND_CHALLENGE_REPLY: u8 = b'r';
+const SEND_CHALLENGE_ACK: u8 = b'a';
+const OLD_SEND_NAME: u8 = b'n';
+
+/// The node on the other end of a handshake.
+pub struct Peer {
+    pub name: Atom,
+    pub creation: u32,
+}
+
+/// Performs the handshake as the node that initiated the connection.
+pub fn connect(stream: &mut TcpStream, cookie: &str) -> anyhow::Result<Peer> {
+    let local = node::arc_node();
+
+    // send_name
+    let mut send_name = vec![SEND_NAME];
+    send_name.extend_from_slice(&FLAGS.to_be_bytes());
+    send_name.extend_from_slice(&local.creation().to_be_bytes());
+    append_u16_len_bytes(&mut send_name, local.name().name().as_bytes())?;
+    write_message(stream, &send_name)?;
+
+    // recv_status
+    let status = read_message(stream)?;
+    match status.split_first() {
+        Some((&SEND_STATUS, b"ok")) | Some((&SEND_STATUS, b"ok_simultaneous")) => (),
+        Some((&SEND_STATUS, status)) => bail!(
+            "peer refused connection with status ({})",
+            String::from_utf8_lossy(status)
+        ),
+        _ => bail!("peer did not send status"),
+    }
+
+    // recv_challenge
+    let challenge = read_message(stream)?;
+    let (tag, bytes) = challenge
+        .split_first()
+        .context("peer did not send challenge")?;
+    ensure!(*tag == SEND_CHALLENGE, "peer sent unexpected tag ({})", tag);
+    let (flags, bytes) = split_u64(bytes)?;
+    let (peer_challenge, bytes) = split_u32(bytes)?;
+    let (creation, bytes) = split_u32(bytes)?;
+    let (name, _) = split_u16_len_bytes(bytes)?;
+    let peer = Peer {
+        name: try_name_to_atom(name)?,
+        creation,
+    };
+    check_flags(flags)?;
+
+    // send_challenge_reply
+    let challenge = new_challenge();
+    let mut challenge_reply = vec![SEND_CHALLENGE_REPLY];
+    challenge_reply.extend_from_slice(&challenge.to_be_bytes());
+    challenge_reply.extend_from_slice(&digest(peer_challenge, cookie));
+    write_message(stream, &challenge_reply)?;
+
+    // recv_challenge_ack
+    let challenge_ack = read_message(stream)?;
+    match challenge_ack.split_first() {
+        Some((&SEND_CHALLENGE_ACK, peer_digest)) if peer_digest == digest(challenge, cookie) => {
+            Ok(peer)
+        }
+        Some((&SEND_CHALLENGE_ACK, _)) => bail!(
+            "peer ({}) does not share the cookie of the local node",
+            peer.name
+        ),
+        _ => bail!("peer ({}) did not acknowledge challenge", peer.name),
+    }
+}
+
+/// Performs the handshake as the node that accepted the connection.
+pub fn accept(stream: &mut TcpStream, cookie: &str) -> anyhow::Result<Peer> {
+    let local = node::arc_node();
+
+    // recv_name
+    let send_name = read_message(stream)?;
+    let (tag, bytes) = send_name.split_first().context("peer did not send name")?;
+    match *tag {
+        SEND_NAME => (),
+        OLD_SEND_NAME => bail!("peer only supports distribution protocol version 5"),
+        tag => bail!("peer sent unexpected tag ({})", tag),
+    }
+    let (flags, bytes) = split_u64(bytes)?;
+    let (creation, bytes) = split_u32(bytes)?;
+    let (name, _) = split_u16_len_bytes(bytes)?;
+    let peer = Peer {
+        name: try_name_to_atom(name)?,
+        creation,
+    };
+    check_flags(flags)?;
+
+    // send_status
+    let mut status = vec![SEND_STATUS];
+    status.extend_from_slice(b"ok");
+    write_message(stream, &status)?;
+
+    // send_challenge
+    let challenge = new_challenge();
+    let mut send_challenge = vec![SEND_CHALLENGE];
+    send_challenge.extend_from_slice(&FLAGS.to_be_bytes());
+    send_challenge.extend_from_slice(&challenge.to_be_bytes());
+    send_challenge.extend_from_slice(&local.creation().to_be_bytes());
+    append_u16_len_bytes(&mut send_challenge, local.name().name().as_bytes())?;
+    write_message(stream, &send_challenge)?;
+
+    // recv_challenge_reply
+    let challenge_reply = read_message(stream)?;
+    let (tag, bytes) = challenge_reply
+        .split_first()
+        .context("peer did not reply to challenge")?;
+    ensure!(
+        *tag == SEND_CHALLENGE_REPLY,
+        "peer sent unexpected tag ({})",
+        tag
+    );
+    let (peer_challenge, peer_digest) = split_u32(bytes)?;
+    ensure!(
+        peer_digest == digest(challenge, cookie),
+        "peer ({}) does not share the cookie of the local node",
+        peer.name
+    );
+
+    // send_challenge_ack
+    let mut challenge_ack = vec![SEND_CHALLENGE_ACK];
+    challenge_ack.extend_from_slice(&digest(peer_challenge, cookie));
+    write_message(stream, &challenge_ack)?;
+
+    Ok(peer)
+}
+
+// Private
+
+fn append_u16_len_bytes(byte_vec: &mut Vec<u8>, bytes: &[u8]) -> anyhow::Result<()> {
+    let len: u16 = bytes.len().try_into().context("name is too long")?;
+    byte_vec.extend_from_slice(&len.to_be_bytes());
+    byte_vec.extend_from_slice(bytes);
+
+    Ok(())
+}
+
+fn check_flags(flags: u64) -> anyhow::Result<()> {
+    ensure!(
+        flags & HANDSHAKE_23 == HANDSHAKE_23,
+        "peer does not support the OTP 23 handshake"
+    );
+
+    Ok(())
+}
+
+/// > MD5 digest of the challenge (as text) concatenated with the cookie (as text).
+///
+/// The cookie comes first, despite the wording.
+fn digest(challenge: u32, cookie: &str) -> [u8; 16] {
+    let mut context = md5::Context::new();
+    context.consume(cookie.as_bytes());
+    context.consume(challenge.to_string().as_bytes());
+
+    context.compute().0
+}
+
+fn new_challenge() -> u32 {
+    entropy::u64() as u32
+}
+
+/// Handshake messages are prefixed with their 2 byte length
+fn read_message(stream: &mut TcpStream) -> anyhow::Result<Vec<u8>> {
+    let mut len = [0; 2];
+    stream
+        .read_exact(&mut len)
+        .context("peer closed connection during handshake")?;
+
+    let mut message = vec![0; u16::from_be_bytes(len) as usize];
+    stream
+        .read_exact(&mut message)
+        .context("peer closed connection during handshake")?;
+
+    Ok(message)
+}
+
+fn split_u16_len_bytes(bytes: &[u8]) -> anyhow::Result<(&[u8], &[u8])> {
+    let (len_bytes, after_len_bytes) = split(bytes, 2)?;
+    let len = u16::from_be_bytes(len_bytes.try_into().unwrap()) as usize;
+
+    split(after_len_bytes, len)
+}
+
+fn split_u32(bytes: &[u8]) -> anyhow::Result<(u32, &[u8])> {
+    let (u32_bytes, after_u32_bytes) = split(bytes, 4)?;
+
+    Ok((
+        u32::from_be_bytes(u32_bytes.try_into().unwrap()),
+        after_u32_bytes,
+    ))
+}
+
+fn split_u64(bytes: &[u8]) -> anyhow::Result<(u64, &[u8])> {
+    let (u64_bytes, after_u64_bytes) = split(bytes, 8)?;
+
+    Ok((
+        u64::from_be_bytes(u64_bytes.try_into().unwrap()),
+        after_u64_bytes,
+    ))
+}
+
+fn split(bytes: &[u8], mid: usize) -> anyhow::Result<(&[u8], &[u8])> {
+    ensure!(
+        mid <= bytes.len(),
+        "handshake message is truncated: needed {} bytes, but only {} available",
+        mid,
+        bytes.len()
+    );
+
+    Ok(bytes.split_at(mid))
+}
+
+fn try_name_to_atom(name: &[u8]) -> anyhow::Result<Atom> {
+    let name = std::str::from_utf8(name).context("peer name is not UTF-8")?;
+
+    Atom::try_from_str(name).map_err(From::from)
+}
+
+fn write_message(stream: &mut TcpStream, message: &[u8]) -> anyhow::Result<()> {
+    let len: u16 = message
+        .len()
+        .try_into()
+        .context("handshake message is too long")?;
+    stream.write_all(&len.to_be_bytes())?;
+    stream.write_all(message)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_is_md5_of_cookie_then_challenge_as_decimal() {
+        assert_eq!(
+            digest(0xDEADBEEF, "COOKIE"),
+            [194, 204, 88, 31, 203, 218, 46, 6, 65, 233, 216, 73, 81, 4, 37, 23]
+        );
+    }
+}
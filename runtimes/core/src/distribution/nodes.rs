@@ -1,6 +1,7 @@
 pub mod node;
 
 use std::backtrace::Backtrace;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use hashbrown::HashMap;
@@ -49,6 +50,20 @@ pub fn try_id_to_arc_node(id: &usize) -> Result<Arc<Node>, NodeNotFound> {
     }
 }
 
+/// The `Node` named `name` from incarnation `creation`.  A new incarnation of a known node keeps
+/// the old incarnation's id, while unknown nodes are assigned the next unused id.
+pub fn get_or_insert(name: Atom, creation: u32) -> Arc<Node> {
+    let id = match atom_to_arc_node(&name) {
+        Some(arc_node) if arc_node.creation() == creation => return arc_node,
+        Some(arc_node) => arc_node.id(),
+        None => NEXT_ID.fetch_add(1, Ordering::SeqCst),
+    };
+    let arc_node = Arc::new(Node::new(id, name, creation));
+    insert(arc_node.clone());
+
+    arc_node
+}
+
 pub fn insert(arc_node: Arc<Node>) {
     let id = arc_node.id();
     let name = arc_node.name();
@@ -100,6 +115,9 @@ impl From<NodeNotFound> for RuntimeException {
     }
 }
 
+// `node::id()` is `0`
+static NEXT_ID: AtomicUsize = AtomicUsize::new(1);
+
 lazy_static! {
     static ref RW_LOCK_ARC_NODE_BY_ID: RwLock<HashMap<usize, Arc<Node>>> = {
         let mut hash_map = HashMap::new();
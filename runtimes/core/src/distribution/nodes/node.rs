@@ -2,13 +2,16 @@ use std::sync::Arc;
 
 use lazy_static::lazy_static;
 
+use liblumen_core::locks::RwLock;
+
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::erts::Node;
 
 pub const DEAD_ATOM_NAME: &str = "nonode@nohost";
 
 lazy_static! {
-    pub(super) static ref ARC_NODE: Arc<Node> = Arc::new(Node::new(ID, dead_atom(), CREATION));
+    static ref RW_LOCK_ARC_NODE: RwLock<Arc<Node>> =
+        RwLock::new(Arc::new(Node::new(ID, dead_atom(), CREATION)));
 }
 
 pub fn dead_atom() -> Atom {
@@ -16,20 +19,34 @@ pub fn dead_atom() -> Atom {
 }
 
 pub fn arc_node() -> Arc<Node> {
-    ARC_NODE.clone()
+    RW_LOCK_ARC_NODE.read().clone()
 }
 
 pub fn atom() -> Atom {
-    ARC_NODE.name()
+    arc_node().name()
 }
 
 pub fn id() -> usize {
-    ARC_NODE.id()
+    ID
+}
+
+/// Whether the local node has been started with a name, so that it can talk to other nodes.
+pub fn is_alive() -> bool {
+    atom() != dead_atom()
 }
 
 pub fn term() -> Term {
     atom().encode().unwrap()
 }
 
+/// Renames the local node once it is registered with EPMD.  Local pids and references encoded
+/// after this use `name` and `creation`, so that other nodes can route them back.
+pub(in crate::distribution) fn set(name: Atom, creation: u32) {
+    let arc_node = Arc::new(Node::new(ID, name, creation));
+    *RW_LOCK_ARC_NODE.write() = arc_node.clone();
+
+    super::insert(arc_node);
+}
+
 const CREATION: u32 = 0;
 const ID: usize = 0;
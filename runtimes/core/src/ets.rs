@@ -0,0 +1,387 @@
+//! Erlang Term Storage
+//!
+//! Tables live outside of any process heap.  Each object is copied into its own `HeapFragment`
+//! when inserted, which is freed when the object is overwritten or deleted or when the table is
+//! deleted.  Objects are copied back onto the calling process's heap when read.
+//!
+//! Tables are deleted when their owner exits.
+
+mod options;
+mod pattern;
+
+use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::ptr::{self, NonNull};
+use std::sync::Arc;
+
+use anyhow::*;
+use dashmap::DashMap;
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::RwLock;
+
+use liblumen_alloc::borrow::CloneToProcess;
+use liblumen_alloc::erts::exception::InternalResult;
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::HeapFragment;
+
+use crate::scheduler::SchedulerDependentAlloc;
+
+pub use options::*;
+
+use self::pattern::Bindings;
+
+lazy_static! {
+    static ref TABLE_BY_REFERENCE: DashMap<Reference, Arc<Table>> = Default::default();
+    static ref REFERENCE_BY_NAME: DashMap<Atom, Reference> = Default::default();
+}
+
+/// `ets:delete/1`
+pub fn delete(process: &Process, table: Term) -> InternalResult<()> {
+    let arc_table = table_for_write(process, table)?;
+
+    remove(&arc_table);
+
+    Ok(())
+}
+
+/// `ets:delete/2`
+pub fn delete_key(process: &Process, table: Term, key: Term) -> InternalResult<()> {
+    let arc_table = table_for_write(process, table)?;
+    arc_table.objects.write().remove(key);
+
+    Ok(())
+}
+
+/// Deletes all tables owned by the exiting process with `pid`
+pub fn delete_owned_by(pid: Pid) {
+    let owned_table_vec: Vec<Arc<Table>> = TABLE_BY_REFERENCE
+        .iter()
+        .filter(|entry| entry.value().owner == pid)
+        .map(|entry| entry.value().clone())
+        .collect();
+
+    for arc_table in owned_table_vec {
+        remove(&arc_table);
+    }
+}
+
+/// `ets:insert/2`
+///
+/// `objects` is either a single tuple or a list of tuples.  If any object is invalid, no objects
+/// are inserted.
+pub fn insert(process: &Process, table: Term, objects: Term) -> InternalResult<()> {
+    let arc_table = table_for_write(process, table)?;
+    let key_position = arc_table.options.key_position;
+
+    let object_term_vec = match objects.decode()? {
+        TypedTerm::Tuple(_) => vec![objects],
+        TypedTerm::Nil => Vec::new(),
+        TypedTerm::List(cons) => {
+            let mut vec = Vec::new();
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(object) => vec.push(object),
+                    Err(_) => {
+                        return Err(ImproperListError)
+                            .context(format!("objects ({}) is improper", objects))
+                            .map_err(From::from)
+                    }
+                }
+            }
+
+            vec
+        }
+        _ => {
+            return Err(TypeError)
+                .context(format!(
+                    "objects ({}) is neither a tuple nor a list of tuples",
+                    objects
+                ))
+                .map_err(From::from)
+        }
+    };
+
+    let mut object_vec = Vec::with_capacity(object_term_vec.len());
+
+    for object_term in object_term_vec {
+        let tuple: Boxed<Tuple> = object_term
+            .try_into()
+            .with_context(|| format!("object ({}) is not a tuple", object_term))?;
+
+        if tuple.len() < key_position {
+            return Err(anyhow!(
+                "object ({}) does not have an element at keypos ({})",
+                object_term,
+                key_position
+            )
+            .into());
+        }
+
+        object_vec.push(Object::new(object_term, key_position)?);
+    }
+
+    let mut objects = arc_table.objects.write();
+
+    for object in object_vec {
+        objects.insert(object);
+    }
+
+    Ok(())
+}
+
+/// `ets:lookup/2`
+pub fn lookup(process: &Process, table: Term, key: Term) -> InternalResult<Term> {
+    let arc_table = table_for_read(process, table)?;
+    let objects = arc_table.objects.read();
+
+    let list = match objects.get(key) {
+        Some(object) => {
+            let process_object = object.term.clone_to_process(process);
+
+            process.list_from_slice(&[process_object])
+        }
+        None => Term::NIL,
+    };
+
+    Ok(list)
+}
+
+/// `ets:match_object/2`
+pub fn match_object(process: &Process, table: Term, pattern: Term) -> InternalResult<Term> {
+    let arc_table = table_for_read(process, table)?;
+    let objects = arc_table.objects.read();
+    let mut matched_vec = Vec::new();
+
+    for object in objects.iter() {
+        let mut bindings = Bindings::new();
+
+        if pattern::matches(pattern, object.term, &mut bindings) {
+            matched_vec.push(object.term.clone_to_process(process));
+        }
+    }
+
+    Ok(process.list_from_slice(&matched_vec))
+}
+
+/// `ets:new/2`
+///
+/// Returns `name` for `named_table`s; otherwise, a reference that identifies the table.
+pub fn new(process: &Process, name: Atom, options: Options) -> InternalResult<Term> {
+    let reference_term = process.next_reference();
+    let reference: Boxed<Reference> = reference_term.try_into().unwrap();
+    let reference = *reference.as_ref();
+
+    let arc_table = Arc::new(Table {
+        reference,
+        name,
+        owner: process.pid(),
+        options,
+        objects: RwLock::new(Objects::new(options.r#type)),
+    });
+
+    if options.named_table {
+        match REFERENCE_BY_NAME.entry(name) {
+            dashmap::mapref::entry::Entry::Occupied(_) => {
+                return Err(anyhow!("table named ({}) already exists", name).into())
+            }
+            dashmap::mapref::entry::Entry::Vacant(vacant) => {
+                vacant.insert(reference);
+            }
+        }
+    }
+
+    TABLE_BY_REFERENCE.insert(reference, arc_table);
+
+    if options.named_table {
+        Ok(name.encode()?)
+    } else {
+        Ok(reference_term)
+    }
+}
+
+/// `ets:select/2`
+pub fn select(process: &Process, table: Term, match_spec: Term) -> InternalResult<Term> {
+    let match_spec = pattern::match_spec(match_spec)?;
+    let arc_table = table_for_read(process, table)?;
+    let objects = arc_table.objects.read();
+    let mut selected_vec = Vec::new();
+
+    for object in objects.iter() {
+        for clause in match_spec.clauses() {
+            // match in the table first, so that only matching objects are copied
+            if pattern::matches(clause.head, object.term, &mut Bindings::new()) {
+                let process_object = object.term.clone_to_process(process);
+                let mut bindings = Bindings::new();
+                assert!(pattern::matches(clause.head, process_object, &mut bindings));
+
+                selected_vec.push(clause.evaluate(process, process_object, &bindings));
+
+                break;
+            }
+        }
+    }
+
+    Ok(process.list_from_slice(&selected_vec))
+}
+
+// Private
+
+fn remove(table: &Table) {
+    TABLE_BY_REFERENCE.remove(&table.reference);
+
+    if table.options.named_table {
+        REFERENCE_BY_NAME.remove(&table.name);
+    }
+}
+
+fn table(table: Term) -> InternalResult<Arc<Table>> {
+    let option_reference = match table.decode()? {
+        TypedTerm::Atom(name) => REFERENCE_BY_NAME.get(&name).map(|entry| *entry.value()),
+        TypedTerm::Reference(reference) => Some(*reference.as_ref()),
+        _ => {
+            return Err(TypeError)
+                .context(format!(
+                    "table ({}) is neither a table name (atom) nor a table identifier (reference)",
+                    table
+                ))
+                .map_err(From::from)
+        }
+    };
+
+    option_reference
+        .and_then(|reference| {
+            TABLE_BY_REFERENCE
+                .get(&reference)
+                .map(|entry| entry.value().clone())
+        })
+        .ok_or_else(|| anyhow!("table ({}) does not exist", table).into())
+}
+
+fn table_for_read(process: &Process, table_term: Term) -> InternalResult<Arc<Table>> {
+    let arc_table = table(table_term)?;
+
+    match arc_table.options.access {
+        Access::Private if arc_table.owner != process.pid() => Err(anyhow!(
+            "table ({}) is private and process ({}) is not the owner",
+            table_term,
+            process
+        )
+        .into()),
+        _ => Ok(arc_table),
+    }
+}
+
+fn table_for_write(process: &Process, table_term: Term) -> InternalResult<Arc<Table>> {
+    let arc_table = table(table_term)?;
+
+    match arc_table.options.access {
+        Access::Public => Ok(arc_table),
+        _ if arc_table.owner == process.pid() => Ok(arc_table),
+        _ => Err(anyhow!(
+            "table ({}) is not public and process ({}) is not the owner",
+            table_term,
+            process
+        )
+        .into()),
+    }
+}
+
+struct Table {
+    reference: Reference,
+    name: Atom,
+    owner: Pid,
+    options: Options,
+    objects: RwLock<Objects>,
+}
+
+// `Objects` are only accessed while holding the `RwLock`
+unsafe impl Send for Table {}
+unsafe impl Sync for Table {}
+
+enum Objects {
+    Set(HashMap<Term, Object>),
+    OrderedSet(BTreeMap<Term, Object>),
+}
+
+impl Objects {
+    fn new(r#type: Type) -> Self {
+        match r#type {
+            Type::Set => Self::Set(Default::default()),
+            Type::OrderedSet => Self::OrderedSet(Default::default()),
+        }
+    }
+
+    fn get(&self, key: Term) -> Option<&Object> {
+        match self {
+            Self::Set(hash_map) => hash_map.get(&key),
+            Self::OrderedSet(btree_map) => btree_map.get(&key),
+        }
+    }
+
+    fn insert(&mut self, object: Object) {
+        // drop any previous object before its key, so the key isn't dangling
+        let key = object.key;
+
+        match self {
+            Self::Set(hash_map) => {
+                hash_map.remove(&key);
+                hash_map.insert(key, object);
+            }
+            Self::OrderedSet(btree_map) => {
+                btree_map.remove(&key);
+                btree_map.insert(key, object);
+            }
+        }
+    }
+
+    /// `ordered_set` tables are iterated in term order of the keys
+    fn iter<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Object> + 'a> {
+        match self {
+            Self::Set(hash_map) => Box::new(hash_map.values()),
+            Self::OrderedSet(btree_map) => Box::new(btree_map.values()),
+        }
+    }
+
+    fn remove(&mut self, key: Term) {
+        match self {
+            Self::Set(hash_map) => {
+                hash_map.remove(&key);
+            }
+            Self::OrderedSet(btree_map) => {
+                btree_map.remove(&key);
+            }
+        }
+    }
+}
+
+struct Object {
+    heap_fragment: NonNull<HeapFragment>,
+    /// The tuple in `heap_fragment`
+    term: Term,
+    /// The element of `term` at the table's `keypos`
+    key: Term,
+}
+
+impl Object {
+    fn new(term: Term, key_position: usize) -> InternalResult<Self> {
+        let (term, heap_fragment) = term.clone_to_fragment()?;
+        let tuple: Boxed<Tuple> = term.try_into().unwrap();
+        let key = tuple[key_position - 1];
+
+        Ok(Self {
+            heap_fragment,
+            term,
+            key,
+        })
+    }
+}
+
+impl Drop for Object {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.heap_fragment.as_ptr()) };
+    }
+}
@@ -0,0 +1,138 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplist::TryPropListFromTermError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Type {
+    Set,
+    OrderedSet,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    /// Any process can read or write
+    Public,
+    /// Any process can read, but only the owner can write
+    Protected,
+    /// Only the owner can read or write
+    Private,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Options {
+    pub r#type: Type,
+    pub access: Access,
+    pub named_table: bool,
+    /// 1-based index of the key in each object tuple
+    pub key_position: usize,
+}
+
+const SUPPORTED_OPTIONS_CONTEXT: &str = "supported options are set, ordered_set, public, protected, private, named_table, or {keypos, pos_integer()}";
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> std::result::Result<&Options, anyhow::Error> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "set" => {
+                    self.r#type = Type::Set;
+
+                    Ok(self)
+                }
+                "ordered_set" => {
+                    self.r#type = Type::OrderedSet;
+
+                    Ok(self)
+                }
+                "public" => {
+                    self.access = Access::Public;
+
+                    Ok(self)
+                }
+                "protected" => {
+                    self.access = Access::Protected;
+
+                    Ok(self)
+                }
+                "private" => {
+                    self.access = Access::Private;
+
+                    Ok(self)
+                }
+                "named_table" => {
+                    self.named_table = true;
+
+                    Ok(self)
+                }
+                name => {
+                    Err(TryPropListFromTermError::AtomName(name)).context(SUPPORTED_OPTIONS_CONTEXT)
+                }
+            },
+            TypedTerm::Tuple(tuple) => {
+                if tuple.len() == 2 {
+                    let atom: Atom = tuple[0]
+                        .try_into()
+                        .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+                    match atom.name() {
+                        "keypos" => {
+                            let key_position: usize = tuple[1].try_into().with_context(|| {
+                                format!("keypos ({}) must be a positive integer", tuple[1])
+                            })?;
+
+                            if 0 < key_position {
+                                self.key_position = key_position;
+
+                                Ok(self)
+                            } else {
+                                Err(anyhow!("keypos ({}) must be a positive integer", tuple[1]))
+                            }
+                        }
+                        name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                            .context(SUPPORTED_OPTIONS_CONTEXT),
+                    }
+                } else {
+                    Err(TryPropListFromTermError::TupleNotPair).context(SUPPORTED_OPTIONS_CONTEXT)
+                }
+            }
+            _ => Err(TryPropListFromTermError::PropertyType).context(SUPPORTED_OPTIONS_CONTEXT),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            r#type: Type::Set,
+            access: Access::Protected,
+            named_table: false,
+            key_position: 1,
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> std::result::Result<Options, Self::Error> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            }
+        }
+    }
+}
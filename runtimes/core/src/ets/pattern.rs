@@ -0,0 +1,213 @@
+//! Matching of `ets:match_object/2` patterns and the heads and bodies of `ets:select/2` match
+//! specifications.
+//!
+//! Only the subset of match specifications without guards is supported: heads may use `'_'` and
+//! `'$N'` variables and bodies may use `'$_'`, `'$$'`, `'$N'`, `{{...}}` tuple construction, lists,
+//! and constants.
+
+use std::convert::TryInto;
+
+use anyhow::*;
+use hashbrown::HashMap;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+pub type Bindings = HashMap<usize, Term>;
+
+pub struct MatchSpec {
+    clauses: Vec<Clause>,
+}
+
+impl MatchSpec {
+    pub fn clauses(&self) -> &[Clause] {
+        &self.clauses
+    }
+}
+
+pub struct Clause {
+    pub head: Term,
+    pub body: Vec<Term>,
+}
+
+impl Clause {
+    /// Evaluates the body of the clause with `bindings` from matching `head` against `object`.
+    ///
+    /// `object` and `bindings` must already be on the heap of `process`.
+    pub fn evaluate(&self, process: &Process, object: Term, bindings: &Bindings) -> Term {
+        self.body.iter().fold(object, |_, expression| {
+            evaluate(process, *expression, object, bindings)
+        })
+    }
+}
+
+pub fn match_spec(match_spec: Term) -> anyhow::Result<MatchSpec> {
+    let mut clauses = Vec::new();
+
+    for clause_term in list_to_vec(match_spec)
+        .with_context(|| format!("match_spec ({}) is not a proper list", match_spec))?
+    {
+        let clause_tuple: Boxed<Tuple> = clause_term.try_into().with_context(|| {
+            format!(
+                "clause ({}) is not a {{head, guards, body}} tuple",
+                clause_term
+            )
+        })?;
+
+        if clause_tuple.len() != 3 {
+            return Err(anyhow!(
+                "clause ({}) is not a {{head, guards, body}} tuple",
+                clause_term
+            ));
+        }
+
+        let guards = clause_tuple[1];
+
+        if !guards.is_nil() {
+            return Err(anyhow!(
+                "guards ({}) in clause ({}) are not supported yet",
+                guards,
+                clause_term
+            ));
+        }
+
+        let body_term = clause_tuple[2];
+        let body = list_to_vec(body_term)
+            .with_context(|| format!("body ({}) is not a proper list", body_term))?;
+
+        if body.is_empty() {
+            return Err(anyhow!("body in clause ({}) is empty", clause_term));
+        }
+
+        clauses.push(Clause {
+            head: clause_tuple[0],
+            body,
+        });
+    }
+
+    Ok(MatchSpec { clauses })
+}
+
+/// Returns `true` if `term` matches `pattern`, binding any `'$N'` variables in `bindings`.
+pub fn matches(pattern: Term, term: Term, bindings: &mut Bindings) -> bool {
+    match pattern.decode().unwrap() {
+        TypedTerm::Atom(atom) => {
+            if atom.name() == "_" {
+                true
+            } else if let Some(variable) = variable(atom) {
+                match bindings.get(&variable) {
+                    Some(bound) => *bound == term,
+                    None => {
+                        bindings.insert(variable, term);
+
+                        true
+                    }
+                }
+            } else {
+                pattern == term
+            }
+        }
+        TypedTerm::Tuple(pattern_tuple) => match term.decode().unwrap() {
+            TypedTerm::Tuple(term_tuple) => {
+                pattern_tuple.len() == term_tuple.len()
+                    && pattern_tuple.iter().zip(term_tuple.iter()).all(
+                        |(pattern_element, term_element)| {
+                            matches(*pattern_element, *term_element, bindings)
+                        },
+                    )
+            }
+            _ => false,
+        },
+        TypedTerm::List(pattern_cons) => match term.decode().unwrap() {
+            TypedTerm::List(term_cons) => {
+                matches(pattern_cons.head, term_cons.head, bindings)
+                    && matches(pattern_cons.tail, term_cons.tail, bindings)
+            }
+            _ => false,
+        },
+        TypedTerm::Map(pattern_map) => match term.decode().unwrap() {
+            TypedTerm::Map(term_map) => {
+                pattern_map
+                    .iter()
+                    .all(|(key, pattern_value)| match term_map.get(*key) {
+                        Some(term_value) => matches(*pattern_value, term_value, bindings),
+                        None => false,
+                    })
+            }
+            _ => false,
+        },
+        _ => pattern == term,
+    }
+}
+
+// Private
+
+fn evaluate(process: &Process, expression: Term, object: Term, bindings: &Bindings) -> Term {
+    match expression.decode().unwrap() {
+        TypedTerm::Atom(atom) => match atom.name() {
+            "$_" => object,
+            "$$" => {
+                let mut variables: Vec<&usize> = bindings.keys().collect();
+                variables.sort_unstable();
+
+                let values: Vec<Term> = variables
+                    .into_iter()
+                    .map(|variable| bindings[variable])
+                    .collect();
+
+                process.list_from_slice(&values)
+            }
+            _ => match variable(atom) {
+                Some(variable) => bindings.get(&variable).copied().unwrap_or(expression),
+                None => expression,
+            },
+        },
+        // `{{...}}` constructs a tuple, while `{...}` would be ambiguous with a function call.
+        TypedTerm::Tuple(tuple) if tuple.len() == 1 && tuple[0].is_boxed_tuple() => {
+            let inner: Boxed<Tuple> = tuple[0].try_into().unwrap();
+            let elements: Vec<Term> = inner
+                .iter()
+                .map(|element| evaluate(process, *element, object, bindings))
+                .collect();
+
+            process.tuple_from_slice(&elements)
+        }
+        TypedTerm::List(cons) => {
+            let head = evaluate(process, cons.head, object, bindings);
+            let tail = evaluate(process, cons.tail, object, bindings);
+
+            process.cons(head, tail)
+        }
+        _ => expression,
+    }
+}
+
+fn list_to_vec(list: Term) -> anyhow::Result<Vec<Term>> {
+    match list.decode().unwrap() {
+        TypedTerm::Nil => Ok(Vec::new()),
+        TypedTerm::List(cons) => {
+            let mut vec = Vec::new();
+
+            for result in cons.into_iter() {
+                match result {
+                    Ok(element) => vec.push(element),
+                    Err(_) => return Err(ImproperListError.into()),
+                }
+            }
+
+            Ok(vec)
+        }
+        _ => Err(TypeError.into()),
+    }
+}
+
+/// `'$N'` variables are numbered with a non-negative integer `N`
+fn variable(atom: Atom) -> Option<usize> {
+    let name = atom.name();
+
+    if name.starts_with('$') && 1 < name.len() {
+        name[1..].parse().ok()
+    } else {
+        None
+    }
+}
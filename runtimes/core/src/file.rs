@@ -0,0 +1,352 @@
+//! Blocking file operations for the `file` module, which runs them on the `dirty_io` pool.
+//!
+//! Errors are reported the way `file` does, as the POSIX error code, such as `enoent`, instead of
+//! raising.
+
+mod modes;
+
+use std::fs::{self, File, Metadata};
+use std::io::{self, ErrorKind, Read, Write};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use chrono::prelude::*;
+
+use liblumen_core::locks::Mutex;
+
+pub use modes::*;
+
+/// What a file operation returns, before it is converted to a term by the process that asked for
+/// it.
+pub enum Reply {
+    /// `ok`
+    Ok,
+    /// `{ok, Data}`, where `Data` is a binary if `binary` and a list of bytes otherwise
+    Data { bytes: Vec<u8>, binary: bool },
+    /// `eof`
+    Eof,
+    /// `{ok, IoDevice}`
+    IoDevice(IoDevice),
+    /// `{ok, FileInfo}`
+    FileInfo(FileInfo),
+    /// `{ok, Filenames}`
+    Filenames(Vec<String>),
+    /// `{error, Reason}`, where `Reason` is the POSIX error code
+    Error(&'static str),
+}
+
+impl From<io::Error> for Reply {
+    fn from(error: io::Error) -> Self {
+        Reply::Error(posix(&error))
+    }
+}
+
+/// A file opened by `file:open/2`.
+///
+/// Unlike the BEAM, where the io device is a process, closing is explicit and the file is not
+/// closed when the process that opened it exits.
+#[derive(Clone)]
+pub struct IoDevice {
+    file: Arc<Mutex<Option<File>>>,
+    binary: bool,
+}
+
+impl IoDevice {
+    /// Reads up to `len` bytes, which are fewer only at end-of-file.
+    pub fn read(&self, len: usize) -> Reply {
+        match self.file.lock().as_mut() {
+            Some(file) => {
+                let mut bytes = Vec::with_capacity(len);
+
+                match file.take(len as u64).read_to_end(&mut bytes) {
+                    Ok(0) if 0 < len => Reply::Eof,
+                    Ok(_) => Reply::Data {
+                        bytes,
+                        binary: self.binary,
+                    },
+                    Err(error) => error.into(),
+                }
+            }
+            None => Reply::Error("einval"),
+        }
+    }
+
+    pub fn write(&self, bytes: &[u8]) -> Reply {
+        match self.file.lock().as_mut() {
+            Some(file) => match file.write_all(bytes) {
+                Ok(()) => Reply::Ok,
+                Err(error) => error.into(),
+            },
+            None => Reply::Error("einval"),
+        }
+    }
+
+    pub fn close(&self) -> Reply {
+        match self.file.lock().take() {
+            Some(file) => match file.sync_all() {
+                Ok(()) => Reply::Ok,
+                // Like `close(2)`, the file is closed even if flushing it failed
+                Err(error) => error.into(),
+            },
+            None => Reply::Error("einval"),
+        }
+    }
+}
+
+/// The fields of the `#file_info{}` record returned by `file:read_file_info/1`.
+pub struct FileInfo {
+    pub size: u64,
+    /// `device`, `directory`, `other`, or `regular`
+    pub r#type: &'static str,
+    /// `read`, `write`, `read_write`, or `none`
+    pub access: &'static str,
+    /// Local `[year, month, day, hour, minute, second]` of the last access
+    pub atime: [usize; 6],
+    /// Local `[year, month, day, hour, minute, second]` of the last modification
+    pub mtime: [usize; 6],
+    /// Local `[year, month, day, hour, minute, second]` of the last status change on unix and
+    /// the creation elsewhere
+    pub ctime: [usize; 6],
+    pub mode: u32,
+    pub links: u64,
+    pub major_device: u64,
+    pub minor_device: u64,
+    pub inode: u64,
+    pub uid: u32,
+    pub gid: u32,
+}
+
+pub fn delete(path: PathBuf) -> Reply {
+    match fs::remove_file(path) {
+        Ok(()) => Reply::Ok,
+        Err(error) => error.into(),
+    }
+}
+
+/// The names of the files in the directory at `path`, sorted, without `.` and `..`.
+pub fn list_dir(path: PathBuf) -> Reply {
+    let read_dir = match fs::read_dir(path) {
+        Ok(read_dir) => read_dir,
+        Err(error) => return error.into(),
+    };
+    let mut filenames = Vec::new();
+
+    for result in read_dir {
+        match result {
+            Ok(entry) => filenames.push(entry.file_name().to_string_lossy().into_owned()),
+            Err(error) => return error.into(),
+        }
+    }
+
+    filenames.sort();
+
+    Reply::Filenames(filenames)
+}
+
+pub fn open(path: PathBuf, modes: Modes) -> Reply {
+    match modes.open_options().open(path) {
+        Ok(file) => Reply::IoDevice(IoDevice {
+            file: Arc::new(Mutex::new(Some(file))),
+            binary: modes.binary,
+        }),
+        Err(error) => error.into(),
+    }
+}
+
+pub fn read_file(path: PathBuf) -> Reply {
+    match fs::read(path) {
+        Ok(bytes) => Reply::Data {
+            bytes,
+            binary: true,
+        },
+        Err(error) => error.into(),
+    }
+}
+
+pub fn read_file_info(path: PathBuf) -> Reply {
+    match fs::metadata(path) {
+        Ok(metadata) => Reply::FileInfo(file_info(&metadata)),
+        Err(error) => error.into(),
+    }
+}
+
+/// Writes `bytes` to the file at `path`, opened with `write` and `modes`, so it is truncated
+/// unless `modes` has `append`.
+pub fn write_file(path: PathBuf, bytes: Vec<u8>, mut modes: Modes) -> Reply {
+    modes.write = true;
+
+    let result = modes
+        .open_options()
+        .open(path)
+        .and_then(|mut file| file.write_all(&bytes));
+
+    match result {
+        Ok(()) => Reply::Ok,
+        Err(error) => error.into(),
+    }
+}
+
+/// The POSIX error code of `error`, as used for `Reason` in `{error, Reason}`.
+pub fn posix(error: &io::Error) -> &'static str {
+    #[cfg(unix)]
+    {
+        if let Some(code) = error.raw_os_error() {
+            let name = match code {
+                libc::EACCES => "eacces",
+                libc::EADDRINUSE => "eaddrinuse",
+                libc::EADDRNOTAVAIL => "eaddrnotavail",
+                libc::EAGAIN => "eagain",
+                libc::EBADF => "ebadf",
+                libc::EBUSY => "ebusy",
+                libc::ECONNABORTED => "econnaborted",
+                libc::ECONNREFUSED => "econnrefused",
+                libc::ECONNRESET => "econnreset",
+                libc::EEXIST => "eexist",
+                libc::EFBIG => "efbig",
+                libc::EHOSTUNREACH => "ehostunreach",
+                libc::EINTR => "eintr",
+                libc::EINVAL => "einval",
+                libc::EIO => "eio",
+                libc::EISDIR => "eisdir",
+                libc::ELOOP => "eloop",
+                libc::EMFILE => "emfile",
+                libc::ENAMETOOLONG => "enametoolong",
+                libc::ENETUNREACH => "enetunreach",
+                libc::ENFILE => "enfile",
+                libc::ENODEV => "enodev",
+                libc::ENOENT => "enoent",
+                libc::ENOMEM => "enomem",
+                libc::ENOSPC => "enospc",
+                libc::ENOTCONN => "enotconn",
+                libc::ENOTDIR => "enotdir",
+                libc::ENOTEMPTY => "enotempty",
+                libc::ENXIO => "enxio",
+                libc::EPERM => "eperm",
+                libc::EPIPE => "epipe",
+                libc::EROFS => "erofs",
+                libc::ESPIPE => "espipe",
+                libc::ETIMEDOUT => "etimedout",
+                libc::EXDEV => "exdev",
+                _ => "eio",
+            };
+
+            return name;
+        }
+    }
+
+    match error.kind() {
+        ErrorKind::NotFound => "enoent",
+        ErrorKind::PermissionDenied => "eacces",
+        ErrorKind::AlreadyExists => "eexist",
+        ErrorKind::InvalidInput => "einval",
+        ErrorKind::Interrupted => "eintr",
+        ErrorKind::BrokenPipe => "epipe",
+        ErrorKind::WouldBlock => "eagain",
+        ErrorKind::ConnectionRefused => "econnrefused",
+        ErrorKind::ConnectionReset => "econnreset",
+        ErrorKind::ConnectionAborted => "econnaborted",
+        ErrorKind::NotConnected => "enotconn",
+        ErrorKind::AddrInUse => "eaddrinuse",
+        ErrorKind::AddrNotAvailable => "eaddrnotavail",
+        ErrorKind::TimedOut => "etimedout",
+        _ => "eio",
+    }
+}
+
+// Private
+
+#[cfg(unix)]
+fn file_info(metadata: &Metadata) -> FileInfo {
+    use std::os::unix::fs::{FileTypeExt, MetadataExt};
+
+    let file_type = metadata.file_type();
+    let r#type = if file_type.is_dir() {
+        "directory"
+    } else if file_type.is_file() {
+        "regular"
+    } else if file_type.is_block_device() || file_type.is_char_device() {
+        "device"
+    } else {
+        "other"
+    };
+    let mode = metadata.mode();
+    // Like the BEAM, access is from the owner's permissions
+    let access = match (mode & 0o400 != 0, mode & 0o200 != 0) {
+        (true, true) => "read_write",
+        (true, false) => "read",
+        (false, true) => "write",
+        (false, false) => "none",
+    };
+
+    FileInfo {
+        size: metadata.size(),
+        r#type,
+        access,
+        atime: local_datetime(metadata.atime()),
+        mtime: local_datetime(metadata.mtime()),
+        ctime: local_datetime(metadata.ctime()),
+        mode,
+        links: metadata.nlink(),
+        major_device: metadata.dev(),
+        minor_device: metadata.rdev(),
+        inode: metadata.ino(),
+        uid: metadata.uid(),
+        gid: metadata.gid(),
+    }
+}
+
+#[cfg(not(unix))]
+fn file_info(metadata: &Metadata) -> FileInfo {
+    use std::time::SystemTime;
+
+    let file_type = metadata.file_type();
+    let r#type = if file_type.is_dir() {
+        "directory"
+    } else if file_type.is_file() {
+        "regular"
+    } else {
+        "other"
+    };
+    let (access, mode) = if metadata.permissions().readonly() {
+        ("read", 0o444)
+    } else {
+        ("read_write", 0o666)
+    };
+    let seconds = |result: io::Result<SystemTime>| {
+        result
+            .ok()
+            .and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs() as i64)
+            .unwrap_or(0)
+    };
+    let mtime = local_datetime(seconds(metadata.modified()));
+
+    FileInfo {
+        size: metadata.len(),
+        r#type,
+        access,
+        atime: local_datetime(seconds(metadata.accessed())),
+        mtime,
+        ctime: local_datetime(seconds(metadata.created())),
+        mode,
+        links: 1,
+        major_device: 0,
+        minor_device: 0,
+        inode: 0,
+        uid: 0,
+        gid: 0,
+    }
+}
+
+fn local_datetime(seconds_since_epoch: i64) -> [usize; 6] {
+    let datetime = Local.timestamp(seconds_since_epoch, 0);
+
+    [
+        datetime.year() as usize,
+        datetime.month() as usize,
+        datetime.day() as usize,
+        datetime.hour() as usize,
+        datetime.minute() as usize,
+        datetime.second() as usize,
+    ]
+}
@@ -0,0 +1,92 @@
+use std::convert::{TryFrom, TryInto};
+use std::fs::OpenOptions;
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplist::TryPropListFromTermError;
+
+/// The modes of `file:open/2` and `file:write_file/3`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Modes {
+    pub read: bool,
+    pub write: bool,
+    /// Writes go to the end of the file, which implies `write`.
+    pub append: bool,
+    /// The file must not exist already.  Only useful with `write`.
+    pub exclusive: bool,
+    /// Data is read as a binary instead of a list of bytes.
+    pub binary: bool,
+}
+
+const SUPPORTED_MODES_CONTEXT: &str =
+    "supported modes are append, binary, delayed_write, exclusive, raw, read, read_ahead, and write";
+
+impl Modes {
+    /// The `OpenOptions` for these modes, which, like the BEAM, are `read` without `write` or
+    /// `append` and truncate on `write` without `read` or `append`.
+    pub fn open_options(&self) -> OpenOptions {
+        let write = self.write || self.append;
+        let mut open_options = OpenOptions::new();
+
+        open_options
+            .read(self.read || !write)
+            .write(write)
+            .append(self.append)
+            .truncate(self.write && !self.read && !self.append);
+
+        if self.exclusive {
+            open_options.create_new(write);
+        } else {
+            open_options.create(write);
+        }
+
+        open_options
+    }
+
+    fn put_mode_term(&mut self, mode: Term) -> core::result::Result<&Modes, anyhow::Error> {
+        let atom: Atom = mode
+            .try_into()
+            .map_err(|_| TryPropListFromTermError::PropertyType)
+            .context(SUPPORTED_MODES_CONTEXT)?;
+
+        match atom.name() {
+            "append" => self.append = true,
+            "binary" => self.binary = true,
+            "exclusive" => self.exclusive = true,
+            "read" => self.read = true,
+            "write" => self.write = true,
+            // Reads and writes are not buffered, so these have no effect
+            "delayed_write" | "raw" | "read_ahead" => (),
+            name => {
+                return Err(TryPropListFromTermError::AtomName(name))
+                    .context(SUPPORTED_MODES_CONTEXT)
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<Term> for Modes {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> std::result::Result<Modes, Self::Error> {
+        let mut modes: Modes = Default::default();
+        let mut modes_term = term;
+
+        loop {
+            match modes_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(modes),
+                TypedTerm::List(cons) => {
+                    modes.put_mode_term(cons.head)?;
+                    modes_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            }
+        }
+    }
+}
@@ -0,0 +1,691 @@
+//! Sockets for `gen_tcp`, `gen_udp`, and `ssl`, and the name resolution and interfaces of `inet`.
+//!
+//! Like the BEAM, a socket is a port owned by the process that opened it.  The functions of `tcp`,
+//! `udp`, and `tls` block, so the native functions run them as futures on the `task` pool with
+//! `task::blocking`, while the calling process waits for their reply.
+//!
+//! An active socket has a thread, like a port, that sends what it reads to the owner: as
+//! `{tcp, Socket, Data}` and `{tcp_closed, Socket}` when the peer closes a TCP socket, as
+//! `{ssl, Socket, Data}` and `{ssl_closed, Socket}` for a TLS socket, and as
+//! `{udp, Socket, IP, InPortNo, Packet}` for a UDP socket.
+mod interfaces;
+mod options;
+pub mod tcp;
+#[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+pub mod tls;
+pub mod udp;
+
+use std::io::{self, ErrorKind, Read};
+use std::mem;
+use std::net::{IpAddr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::*;
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::{Mutex, RwLock};
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::Process;
+
+use crate::file::posix;
+use crate::process;
+use crate::process::spawn::options::Options as SpawnOptions;
+use crate::registry;
+
+pub use interfaces::*;
+pub use options::*;
+
+/// The most bytes delivered in one `{tcp, Socket, Data}` message or returned by
+/// `gen_tcp:recv(Socket, 0)`.
+const READ_SIZE: usize = 4096;
+
+/// The largest UDP datagram.
+const MAX_DATAGRAM_SIZE: usize = 65_535;
+
+/// How often threads waiting on a socket that closing it doesn't wake check whether it has been
+/// closed and whether they have timed out.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// What a socket or name resolution operation returns, before it is converted to a term by the
+/// process that asked for it.
+pub enum Reply {
+    /// `ok`
+    Ok,
+    /// `{ok, Socket}`
+    Socket(Port),
+    /// `{ok, Data}`, where `Data` is a binary if `binary` and a list of bytes otherwise
+    Data { bytes: Vec<u8>, binary: bool },
+    /// `{ok, {Address, Port, Packet}}`, where `Packet` is a binary if `binary` and a list of bytes
+    /// otherwise
+    Datagram {
+        ip_addr: IpAddr,
+        port: u16,
+        bytes: Vec<u8>,
+        binary: bool,
+    },
+    /// `{ok, IPAddress}`
+    Address(IpAddr),
+    /// `{ok, Hostent}`
+    HostEnt(HostEnt),
+    /// `{error, Reason}`, where `Reason` is `closed`, `timeout`, `nxdomain`, or the POSIX error
+    /// code
+    Error(&'static str),
+    /// `{error, {tls_alert, {Alert, Description}}}`
+    TlsAlert {
+        alert: &'static str,
+        description: String,
+    },
+}
+
+impl From<io::Error> for Reply {
+    fn from(error: io::Error) -> Self {
+        let reason = match error.kind() {
+            // Reads that time out return `WouldBlock` on unix
+            ErrorKind::TimedOut | ErrorKind::WouldBlock => "timeout",
+            ErrorKind::BrokenPipe | ErrorKind::UnexpectedEof => "closed",
+            _ => posix(&error),
+        };
+
+        Reply::Error(reason)
+    }
+}
+
+/// The host of `gen_tcp:connect`, `gen_udp:send`, or `inet:getaddr`.
+pub enum Address {
+    Ip(IpAddr),
+    Hostname(String),
+}
+
+impl Address {
+    /// The socket addresses of `port` on the address, with a hostname only resolving to addresses
+    /// of the socket's family.
+    fn to_socket_addrs(&self, port: u16, inet6: bool) -> io::Result<Vec<SocketAddr>> {
+        match self {
+            Address::Ip(ip_addr) => Ok(vec![SocketAddr::new(*ip_addr, port)]),
+            Address::Hostname(hostname) => {
+                (hostname.as_str(), port)
+                    .to_socket_addrs()
+                    .map(|socket_addrs| {
+                        socket_addrs
+                            .filter(|socket_addr| socket_addr.is_ipv6() == inet6)
+                            .collect()
+                    })
+            }
+        }
+    }
+}
+
+/// The `#hostent{}` record from `kernel/include/inet.hrl`, without aliases, which the standard
+/// library doesn't resolve.
+pub struct HostEnt {
+    pub name: String,
+    pub inet6: bool,
+    pub ip_addrs: Vec<IpAddr>,
+}
+
+/// The first address of the family of `address`, resolving it if it is a hostname, like
+/// `inet:getaddr/2`.  An IPv4 address is IPv4-mapped for `inet6`.
+pub fn getaddr(address: &Address, inet6: bool) -> Reply {
+    match address {
+        Address::Ip(ip_addr) => match (inet6, ip_addr) {
+            // Like `gen_udp:send` on an IPv6 socket, IPv4 is reached through IPv4-mapped IPv6
+            (true, IpAddr::V4(ipv4_addr)) => Reply::Address(IpAddr::V6(ipv4_addr.to_ipv6_mapped())),
+            (false, IpAddr::V6(_)) => Reply::Error("einval"),
+            _ => Reply::Address(*ip_addr),
+        },
+        Address::Hostname(hostname) => match resolve(hostname, inet6) {
+            Ok(ip_addrs) => Reply::Address(ip_addrs[0]),
+            Err(reason) => Reply::Error(reason),
+        },
+    }
+}
+
+/// The IPv4 addresses of `address`, resolving it if it is a hostname, like
+/// `inet:gethostbyname/1`.
+pub fn gethostbyname(address: &Address) -> Reply {
+    let result = match address {
+        Address::Ip(ip_addr) => Ok((ip_addr.to_string(), vec![*ip_addr])),
+        Address::Hostname(hostname) => {
+            resolve(hostname, false).map(|ip_addrs| (hostname.clone(), ip_addrs))
+        }
+    };
+
+    match result {
+        Ok((name, ip_addrs)) => Reply::HostEnt(HostEnt {
+            name,
+            inet6: false,
+            ip_addrs,
+        }),
+        Err(reason) => Reply::Error(reason),
+    }
+}
+
+/// `{A, B, C, D}` for IPv4 and `{A, B, C, D, E, F, G, H}` for IPv6, like `inet:ip_address()`.
+pub fn ip_addr_to_term(process: &Process, ip_addr: IpAddr) -> Term {
+    let elements: Vec<Term> = match ip_addr {
+        IpAddr::V4(ipv4_addr) => ipv4_addr
+            .octets()
+            .iter()
+            .map(|octet| process.integer(*octet))
+            .collect(),
+        IpAddr::V6(ipv6_addr) => ipv6_addr
+            .segments()
+            .iter()
+            .map(|segment| process.integer(*segment as u64))
+            .collect(),
+    };
+
+    process.tuple_from_slice(&elements)
+}
+
+/// `Data` or `Packet` of a reply or message, as a binary if `binary` and a list of bytes otherwise.
+pub fn bytes_to_term(process: &Process, bytes: &[u8], binary: bool) -> Term {
+    if binary {
+        process.binary_from_bytes(bytes)
+    } else {
+        let byte_terms: Vec<Term> = bytes.iter().map(|byte| process.integer(*byte)).collect();
+
+        process.list_from_slice(&byte_terms)
+    }
+}
+
+/// Closes the socket `port`, so that its owner receives no more messages from it.
+///
+/// Returns `false` if `port` was not open.
+pub fn close(port: Port) -> bool {
+    match RW_LOCK_SOCKET_BY_PORT.write().remove(&port) {
+        Some(arc_socket) => {
+            // Wakes any thread blocked reading the socket.  Threads reading other kinds of
+            // sockets poll whether they are still open.
+            match &arc_socket.kind {
+                Kind::TcpStream(stream) => {
+                    let _ = stream.shutdown(Shutdown::Both);
+                }
+                #[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+                Kind::Tls(stream) => stream.lock().close(),
+                _ => (),
+            }
+
+            true
+        }
+        None => false,
+    }
+}
+
+/// Closes all sockets owned by the exiting process with `pid`, so that listen, passive, and UDP
+/// sockets, which it may never read again, release their addresses.
+pub fn close_owned_by(pid: Pid) {
+    let owned_port_vec: Vec<Port> = RW_LOCK_SOCKET_BY_PORT
+        .read()
+        .iter()
+        .filter(|(_, arc_socket)| *arc_socket.owner.lock() == pid)
+        .map(|(port, _)| *port)
+        .collect();
+
+    for port in owned_port_vec {
+        close(port);
+    }
+}
+
+/// Makes `new_owner` the owner of the socket `port`, so that it receives the messages of an active
+/// socket, like `gen_tcp:controlling_process/2`.  Only the owner, `caller`, can do so.
+pub fn controlling_process(caller: Pid, port: Port, new_owner: Pid) -> Reply {
+    match get(port) {
+        Some(arc_socket) => {
+            let mut owner = arc_socket.owner.lock();
+
+            if *owner == caller {
+                *owner = new_owner;
+
+                Reply::Ok
+            } else {
+                Reply::Error("not_owner")
+            }
+        }
+        None => Reply::Error("closed"),
+    }
+}
+
+/// Changes the options of the socket `port`, like `inet:setopts/2`.  Making the socket active
+/// again, such as after an `{active, once}` message, starts reading for it.
+pub fn setopts(port: Port, set_options: SetOptions) -> Reply {
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("einval"),
+    };
+
+    {
+        let mut options = arc_socket.options.lock();
+
+        if let Some(active) = set_options.active {
+            options.active = active;
+        }
+
+        if let Some(binary) = set_options.binary {
+            options.binary = binary;
+        }
+    }
+
+    match activate(port, &arc_socket) {
+        Ok(()) => Reply::Ok,
+        Err(error) => Reply::Error(error),
+    }
+}
+
+pub fn is_open(port: Port) -> bool {
+    RW_LOCK_SOCKET_BY_PORT.read().contains_key(&port)
+}
+
+// Private
+
+struct Socket {
+    /// Changed by `controlling_process`.
+    owner: Mutex<Pid>,
+    kind: Kind,
+    options: Mutex<Options>,
+    /// Whether a thread is reading for an active socket.  Only changed while `options` is locked,
+    /// so that the thread can't stop just as the socket is made active again.
+    reading_actively: AtomicBool,
+    /// Held while reading, as the read timeout is shared by all reads of the socket.
+    reading: Mutex<()>,
+    /// Bytes a passive `recv` read from a stream socket but didn't return, as it timed out before
+    /// all the bytes it asked for arrived.  The next `recv` returns them first.
+    received: Mutex<Vec<u8>>,
+}
+
+enum Kind {
+    TcpListener(TcpListener),
+    TcpStream(TcpStream),
+    #[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+    Tls(Mutex<tls::Stream>),
+    Udp(UdpSocket),
+}
+
+impl Kind {
+    /// The tags of the `{Tag, Socket, Data}` and `{ClosedTag, Socket}` messages of an active
+    /// stream socket.
+    fn tags(&self) -> (&'static str, &'static str) {
+        match self {
+            #[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+            Kind::Tls(_) => ("ssl", "ssl_closed"),
+            _ => ("tcp", "tcp_closed"),
+        }
+    }
+}
+
+/// What the thread reading for an active socket read.
+enum Received {
+    Data(Vec<u8>),
+    Datagram(SocketAddr, Vec<u8>),
+    Closed,
+}
+
+fn open(owner: Pid, kind: Kind, options: Options) -> Reply {
+    let port = Port::next();
+    let arc_socket = Arc::new(Socket {
+        owner: Mutex::new(owner),
+        kind,
+        options: Mutex::new(options),
+        reading_actively: AtomicBool::new(false),
+        reading: Mutex::new(()),
+        received: Mutex::new(Vec::new()),
+    });
+
+    RW_LOCK_SOCKET_BY_PORT
+        .write()
+        .insert(port, arc_socket.clone());
+
+    if let Err(error) = activate(port, &arc_socket) {
+        close(port);
+
+        return Reply::Error(error);
+    }
+
+    Reply::Socket(port)
+}
+
+/// Starts the thread that reads for `socket` if it is active and doesn't have one.  Listen
+/// sockets are never read.
+fn activate(port: Port, arc_socket: &Arc<Socket>) -> std::result::Result<(), &'static str> {
+    if let Kind::TcpListener(_) = arc_socket.kind {
+        return Ok(());
+    }
+
+    let options = arc_socket.options.lock();
+
+    if options.active != Active::False && !arc_socket.reading_actively.load(Ordering::SeqCst) {
+        arc_socket.reading_actively.store(true, Ordering::SeqCst);
+
+        let reader_arc_socket = arc_socket.clone();
+
+        thread::Builder::new()
+            .name(format!("inet_{}", port.as_usize()))
+            .spawn(move || {
+                if let Err(error) = read_actively(port, &reader_arc_socket) {
+                    log::warn!("Socket ({}) failed: {:?}", port, error);
+
+                    let _options = reader_arc_socket.options.lock();
+                    reader_arc_socket
+                        .reading_actively
+                        .store(false, Ordering::SeqCst);
+                }
+            })
+            .map_err(|_| "emfile")?;
+    }
+
+    Ok(())
+}
+
+fn deliver(
+    decoder: &Process,
+    port: Port,
+    socket: &Socket,
+    data: impl FnOnce(&Process) -> Term,
+) -> bool {
+    // A closed socket sends nothing more to its owner
+    if !is_open(port) {
+        return false;
+    }
+
+    let owner = *socket.owner.lock();
+
+    match registry::pid_to_process(&owner) {
+        Some(owner_arc_process) => {
+            process::send_from_decoder(decoder, &owner_arc_process, data(decoder));
+
+            true
+        }
+        // The owner exited before `close_owned_by` closed the socket
+        None => {
+            close(port);
+
+            false
+        }
+    }
+}
+
+/// The addresses of `hostname` of the family, without duplicates, or `nxdomain` if there are
+/// none.
+fn resolve(hostname: &str, inet6: bool) -> std::result::Result<Vec<IpAddr>, &'static str> {
+    let mut ip_addrs: Vec<IpAddr> = Vec::new();
+
+    for socket_addr in (hostname, 0).to_socket_addrs().map_err(|_| "nxdomain")? {
+        let ip_addr = socket_addr.ip();
+
+        if ip_addr.is_ipv6() == inet6 && !ip_addrs.contains(&ip_addr) {
+            ip_addrs.push(ip_addr);
+        }
+    }
+
+    if ip_addrs.is_empty() {
+        Err("nxdomain")
+    } else {
+        Ok(ip_addrs)
+    }
+}
+
+fn get(port: Port) -> Option<Arc<Socket>> {
+    RW_LOCK_SOCKET_BY_PORT.read().get(&port).cloned()
+}
+
+fn is_timeout(error: &io::Error) -> bool {
+    match error.kind() {
+        ErrorKind::TimedOut | ErrorKind::WouldBlock => true,
+        _ => false,
+    }
+}
+
+/// Socket timeouts can't be zero, so a zero timeout is as short as possible instead.
+fn non_zero(timeout: Duration) -> Duration {
+    timeout.max(Duration::from_millis(1))
+}
+
+/// The bytes a passive `recv` of `len` bytes returns from `bytes`, or all of them if `len` is `0`,
+/// or `None` if it needs to read more.
+fn take_received(bytes: &mut Vec<u8>, len: usize) -> Option<Vec<u8>> {
+    if len == 0 && !bytes.is_empty() {
+        Some(mem::take(bytes))
+    } else if 0 < len && len <= bytes.len() {
+        Some(bytes.drain(..len).collect())
+    } else {
+        None
+    }
+}
+
+/// Reads whatever is available, or `None` at end-of-file.
+fn read_available(stream: &TcpStream) -> io::Result<Option<Vec<u8>>> {
+    let mut bytes = vec![0; READ_SIZE];
+    let len = (&*stream).read(&mut bytes)?;
+
+    if len == 0 {
+        Ok(None)
+    } else {
+        bytes.truncate(len);
+
+        Ok(Some(bytes))
+    }
+}
+
+fn read_actively(port: Port, socket: &Socket) -> anyhow::Result<()> {
+    // Messages are built on this process's heap before being copied to the owner, as only the
+    // owner's scheduler may allocate on the owner's heap.
+    let decoder = SpawnOptions::default()
+        .spawn(
+            None,
+            Atom::from_str("inet"),
+            Atom::from_str("socket_receive"),
+            0,
+        )
+        .map_err(|alloc| anyhow!("could not allocate decoder heap: {}", alloc))?;
+
+    let _reading = socket.reading.lock();
+
+    match &socket.kind {
+        Kind::TcpStream(stream) => stream.set_read_timeout(None)?,
+        Kind::Udp(udp_socket) => udp_socket.set_read_timeout(Some(POLL_INTERVAL))?,
+        // Always polls, so that the owner can send between reads
+        #[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+        Kind::Tls(_) => (),
+        Kind::TcpListener(_) => unreachable!("listen socket ({}) is read", port),
+    }
+
+    let (data_tag, closed_tag) = socket.kind.tags();
+
+    loop {
+        let binary = {
+            let options = socket.options.lock();
+
+            if options.active == Active::False {
+                socket.reading_actively.store(false, Ordering::SeqCst);
+
+                return Ok(());
+            }
+
+            options.binary
+        };
+
+        // Bytes left by a passive `recv` that timed out arrive before any read after them
+        let left = mem::take(&mut *socket.received.lock());
+
+        let received = match &socket.kind {
+            _ if !left.is_empty() => Received::Data(left),
+            // Like the BEAM, read errors close the socket the same as the peer closing it
+            Kind::TcpStream(stream) => match read_available(stream) {
+                Ok(Some(bytes)) => Received::Data(bytes),
+                Ok(None) | Err(_) => Received::Closed,
+            },
+            #[cfg(all(feature = "ssl", not(target_arch = "wasm32")))]
+            Kind::Tls(stream) => {
+                let mut bytes = vec![0; READ_SIZE];
+                let result = stream.lock().read(&mut bytes);
+
+                match result {
+                    Ok(Some(len)) => {
+                        bytes.truncate(len);
+
+                        Received::Data(bytes)
+                    }
+                    Err(ref error) if is_timeout(error) => {
+                        if is_open(port) {
+                            continue;
+                        } else {
+                            return Ok(());
+                        }
+                    }
+                    Ok(None) | Err(_) => Received::Closed,
+                }
+            }
+            Kind::Udp(udp_socket) => {
+                let mut bytes = vec![0; MAX_DATAGRAM_SIZE];
+
+                match udp_socket.recv_from(&mut bytes) {
+                    Ok((len, socket_addr)) => {
+                        bytes.truncate(len);
+
+                        Received::Datagram(socket_addr, bytes)
+                    }
+                    Err(ref error) if is_timeout(error) => {
+                        if is_open(port) {
+                            continue;
+                        } else {
+                            return Ok(());
+                        }
+                    }
+                    Err(error) => return Err(error.into()),
+                }
+            }
+            Kind::TcpListener(_) => unreachable!(),
+        };
+
+        if let Received::Closed = received {
+            deliver(&decoder, port, socket, |process| {
+                process.tuple_from_slice(&[Atom::str_to_term(closed_tag), port.encode().unwrap()])
+            });
+
+            return Ok(());
+        }
+
+        {
+            let mut options = socket.options.lock();
+
+            if options.active == Active::Once {
+                options.active = Active::False;
+            }
+        }
+
+        let delivered = deliver(&decoder, port, socket, |process| match received {
+            Received::Data(bytes) => process.tuple_from_slice(&[
+                Atom::str_to_term(data_tag),
+                port.encode().unwrap(),
+                bytes_to_term(process, &bytes, binary),
+            ]),
+            Received::Datagram(socket_addr, bytes) => process.tuple_from_slice(&[
+                Atom::str_to_term("udp"),
+                port.encode().unwrap(),
+                ip_addr_to_term(process, socket_addr.ip()),
+                process.integer(socket_addr.port() as u64),
+                bytes_to_term(process, &bytes, binary),
+            ]),
+            Received::Closed => unreachable!(),
+        });
+
+        if !delivered {
+            return Ok(());
+        }
+    }
+}
+
+lazy_static! {
+    static ref RW_LOCK_SOCKET_BY_PORT: RwLock<HashMap<Port, Arc<Socket>>> = Default::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+
+    #[test]
+    fn close_owned_by_closes_only_sockets_of_owner() {
+        let owner = Pid::next();
+        let other_owner = Pid::next();
+        let owned_port = listen_socket(owner);
+        let other_owned_port = listen_socket(other_owner);
+
+        close_owned_by(owner);
+
+        assert!(!is_open(owned_port));
+        assert!(is_open(other_owned_port));
+
+        close(other_owned_port);
+    }
+
+    #[test]
+    fn close_owned_by_releases_address() {
+        let owner = Pid::next();
+        let port = listen_socket(owner);
+        let local_port = match &get(port).unwrap().kind {
+            Kind::TcpListener(listener) => listener.local_addr().unwrap().port(),
+            _ => unreachable!(),
+        };
+
+        close_owned_by(owner);
+
+        match tcp::listen(Pid::next(), local_port, Options::default()) {
+            Reply::Socket(relistened_port) => {
+                close(relistened_port);
+            }
+            _ => panic!("port ({}) is still in use", local_port),
+        }
+    }
+
+    #[test]
+    fn tcp_recv_that_times_out_keeps_bytes_for_next_recv() {
+        let owner = Pid::next();
+        let passive_options = Options {
+            active: Active::False,
+            ..Default::default()
+        };
+        let listen_port = match tcp::listen(owner, 0, passive_options) {
+            Reply::Socket(port) => port,
+            _ => panic!("could not listen"),
+        };
+        let local_port = match &get(listen_port).unwrap().kind {
+            Kind::TcpListener(listener) => listener.local_addr().unwrap().port(),
+            _ => unreachable!(),
+        };
+        let mut peer = TcpStream::connect(("127.0.0.1", local_port)).unwrap();
+        let port = match tcp::accept(owner, listen_port, Some(Duration::from_secs(5))) {
+            Reply::Socket(port) => port,
+            _ => panic!("could not accept"),
+        };
+
+        peer.write_all(&[1, 2]).unwrap();
+
+        match tcp::recv(port, 4, Some(Duration::from_millis(100))) {
+            Reply::Error("timeout") => (),
+            _ => panic!("recv did not time out"),
+        }
+
+        peer.write_all(&[3, 4]).unwrap();
+
+        match tcp::recv(port, 4, Some(Duration::from_secs(5))) {
+            Reply::Data { bytes, .. } => assert_eq!(bytes, vec![1, 2, 3, 4]),
+            _ => panic!("recv did not return the bytes"),
+        }
+
+        close_owned_by(owner);
+    }
+
+    fn listen_socket(owner: Pid) -> Port {
+        match tcp::listen(owner, 0, Options::default()) {
+            Reply::Socket(port) => port,
+            _ => panic!("could not listen"),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::net::IpAddr;
+
+/// A network interface of `inet:getifaddrs/0`.
+pub struct Interface {
+    pub name: String,
+    /// The names of its flags, such as `up` and `loopback`.
+    pub flags: Vec<&'static str>,
+    /// Each address with its netmask, if it has one.
+    pub addresses: Vec<(IpAddr, Option<IpAddr>)>,
+}
+
+cfg_if::cfg_if! {
+  if #[cfg(unix)] {
+     use std::ffi::CStr;
+     use std::io;
+     use std::net::{Ipv4Addr, Ipv6Addr};
+     use std::ptr;
+
+     use crate::file::posix;
+
+     /// The network interfaces in the order the OS lists them, or `{error, Reason}`'s POSIX error
+     /// code.
+     pub fn interfaces() -> Result<Vec<Interface>, &'static str> {
+         let mut ifaddrs: *mut libc::ifaddrs = ptr::null_mut();
+
+         if unsafe { libc::getifaddrs(&mut ifaddrs) } != 0 {
+             return Err(posix(&io::Error::last_os_error()));
+         }
+
+         let mut interface_vec: Vec<Interface> = Vec::new();
+         let mut current = ifaddrs;
+
+         // The OS lists each interface once for each of its addresses
+         while !current.is_null() {
+             let ifaddr = unsafe { &*current };
+             let name = unsafe { CStr::from_ptr(ifaddr.ifa_name) }
+                 .to_string_lossy()
+                 .into_owned();
+
+             let index = match interface_vec
+                 .iter()
+                 .position(|interface| interface.name == name)
+             {
+                 Some(index) => index,
+                 None => {
+                     interface_vec.push(Interface {
+                         name,
+                         flags: flags(ifaddr.ifa_flags as libc::c_int),
+                         addresses: Vec::new(),
+                     });
+
+                     interface_vec.len() - 1
+                 }
+             };
+
+             if let Some(ip_addr) = unsafe { sockaddr_to_ip_addr(ifaddr.ifa_addr) } {
+                 let netmask = unsafe { sockaddr_to_ip_addr(ifaddr.ifa_netmask) };
+                 interface_vec[index].addresses.push((ip_addr, netmask));
+             }
+
+             current = ifaddr.ifa_next;
+         }
+
+         unsafe { libc::freeifaddrs(ifaddrs) };
+
+         Ok(interface_vec)
+     }
+
+     fn flags(ifa_flags: libc::c_int) -> Vec<&'static str> {
+         [
+             (libc::IFF_UP, "up"),
+             (libc::IFF_BROADCAST, "broadcast"),
+             (libc::IFF_LOOPBACK, "loopback"),
+             (libc::IFF_POINTOPOINT, "pointtopoint"),
+             (libc::IFF_RUNNING, "running"),
+             (libc::IFF_MULTICAST, "multicast"),
+         ]
+         .iter()
+         .filter(|(flag, _)| ifa_flags & flag != 0)
+         .map(|(_, name)| *name)
+         .collect()
+     }
+
+     /// `None` for a null `sockaddr` or one that isn't IPv4 or IPv6, such as a link-layer address.
+     unsafe fn sockaddr_to_ip_addr(sockaddr: *const libc::sockaddr) -> Option<IpAddr> {
+         if sockaddr.is_null() {
+             return None;
+         }
+
+         match (*sockaddr).sa_family as libc::c_int {
+             libc::AF_INET => {
+                 let sockaddr_in = &*(sockaddr as *const libc::sockaddr_in);
+
+                 Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+                     sockaddr_in.sin_addr.s_addr,
+                 ))))
+             }
+             libc::AF_INET6 => {
+                 let sockaddr_in6 = &*(sockaddr as *const libc::sockaddr_in6);
+
+                 Some(IpAddr::V6(Ipv6Addr::from(sockaddr_in6.sin6_addr.s6_addr)))
+             }
+             _ => None,
+         }
+     }
+  } else {
+     /// Listing network interfaces is only supported on unix.
+     pub fn interfaces() -> Result<Vec<Interface>, &'static str> {
+         Err("enotsup")
+     }
+  }
+}
@@ -0,0 +1,213 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplist::TryPropListFromTermError;
+
+/// How data received by a socket gets to its owner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Active {
+    /// The owner calls `gen_tcp:recv` or `gen_udp:recv`.
+    False,
+    /// Data is sent to the owner as `{tcp, Socket, Data}` or `{udp, Socket, IP, InPortNo, Packet}`
+    /// messages.
+    True,
+    /// Like `True` for the next message, after which the socket is passive.
+    Once,
+}
+
+impl TryFrom<Term> for Active {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> std::result::Result<Active, Self::Error> {
+        let atom: Atom = term
+            .try_into()
+            .with_context(|| format!("active ({}) must be true, false, or once", term))?;
+
+        match atom.name() {
+            "false" => Ok(Active::False),
+            "true" => Ok(Active::True),
+            "once" => Ok(Active::Once),
+            _ => Err(anyhow!("active ({}) must be true, false, or once", term)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Options {
+    pub active: Active,
+    /// Whether data is delivered as a binary instead of a list of bytes.
+    pub binary: bool,
+    /// Whether the socket is IPv6 instead of IPv4.
+    pub inet6: bool,
+}
+
+const SUPPORTED_OPTIONS_CONTEXT: &str = "supported options are binary, list, inet, inet6, \
+     {active, true | false | once}, {backlog, Backlog}, or {reuseaddr, Boolean}";
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> core::result::Result<&Options, anyhow::Error> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "binary" => {
+                    self.binary = true;
+
+                    Ok(self)
+                }
+                "list" => {
+                    self.binary = false;
+
+                    Ok(self)
+                }
+                "inet" => {
+                    self.inet6 = false;
+
+                    Ok(self)
+                }
+                "inet6" => {
+                    self.inet6 = true;
+
+                    Ok(self)
+                }
+                name => {
+                    Err(TryPropListFromTermError::AtomName(name)).context(SUPPORTED_OPTIONS_CONTEXT)
+                }
+            },
+            TypedTerm::Tuple(tuple) => {
+                if tuple.len() == 2 {
+                    let name: Atom = tuple[0]
+                        .try_into()
+                        .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+                    match name.name() {
+                        "active" => {
+                            self.active = tuple[1].try_into()?;
+
+                            Ok(self)
+                        }
+                        // The standard library picks the backlog and always reuses addresses
+                        "backlog" | "reuseaddr" => Ok(self),
+                        name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                            .context(SUPPORTED_OPTIONS_CONTEXT),
+                    }
+                } else {
+                    Err(TryPropListFromTermError::TupleNotPair).context(SUPPORTED_OPTIONS_CONTEXT)
+                }
+            }
+            _ => Err(TryPropListFromTermError::PropertyType).context(SUPPORTED_OPTIONS_CONTEXT),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            active: Active::True,
+            binary: false,
+            inet6: false,
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> std::result::Result<Options, Self::Error> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            }
+        }
+    }
+}
+
+/// The options of an open socket that `inet:setopts/2` changes, leaving the others as they are.
+#[derive(Clone, Default)]
+pub struct SetOptions {
+    pub active: Option<Active>,
+    pub binary: Option<bool>,
+}
+
+const SUPPORTED_SET_OPTIONS_CONTEXT: &str =
+    "supported options are binary, list, or {active, true | false | once}";
+
+impl SetOptions {
+    fn put_option_term(
+        &mut self,
+        option: Term,
+    ) -> core::result::Result<&SetOptions, anyhow::Error> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "binary" => {
+                    self.binary = Some(true);
+
+                    Ok(self)
+                }
+                "list" => {
+                    self.binary = Some(false);
+
+                    Ok(self)
+                }
+                name => Err(TryPropListFromTermError::AtomName(name))
+                    .context(SUPPORTED_SET_OPTIONS_CONTEXT),
+            },
+            TypedTerm::Tuple(tuple) => {
+                if tuple.len() == 2 {
+                    let name: Atom = tuple[0]
+                        .try_into()
+                        .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                        .context(SUPPORTED_SET_OPTIONS_CONTEXT)?;
+
+                    match name.name() {
+                        "active" => {
+                            self.active = Some(tuple[1].try_into()?);
+
+                            Ok(self)
+                        }
+                        name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                            .context(SUPPORTED_SET_OPTIONS_CONTEXT),
+                    }
+                } else {
+                    Err(TryPropListFromTermError::TupleNotPair)
+                        .context(SUPPORTED_SET_OPTIONS_CONTEXT)
+                }
+            }
+            _ => Err(TryPropListFromTermError::PropertyType).context(SUPPORTED_SET_OPTIONS_CONTEXT),
+        }
+    }
+}
+
+impl TryFrom<Term> for SetOptions {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> std::result::Result<SetOptions, Self::Error> {
+        let mut options: SetOptions = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+//! TCP sockets for `gen_tcp`, owned by the process that connected or accepted them.
+
+use std::io::{ErrorKind, Read, Write};
+use std::mem;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener, TcpStream};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::*;
+
+/// Connects to `port` on `address`, trying each of its addresses in turn, and returns a socket
+/// owned by `owner`.  `timeout` of `None` waits as long as the OS does.
+pub fn connect(
+    owner: Pid,
+    address: &Address,
+    port: u16,
+    options: Options,
+    timeout: Option<Duration>,
+) -> Reply {
+    match connect_stream(address, port, options.inet6, timeout) {
+        Ok(stream) => open(owner, Kind::TcpStream(stream), options),
+        Err(reply) => reply,
+    }
+}
+
+/// The stream of `connect`, before it becomes a socket, so that `tls` can connect the same way.
+pub(super) fn connect_stream(
+    address: &Address,
+    port: u16,
+    inet6: bool,
+    timeout: Option<Duration>,
+) -> std::result::Result<TcpStream, Reply> {
+    let socket_addrs = match address.to_socket_addrs(port, inet6) {
+        Ok(socket_addrs) if !socket_addrs.is_empty() => socket_addrs,
+        _ => return Err(Reply::Error("nxdomain")),
+    };
+    let mut last_error = None;
+
+    for socket_addr in socket_addrs {
+        let result = match timeout {
+            Some(timeout) => TcpStream::connect_timeout(&socket_addr, non_zero(timeout)),
+            None => TcpStream::connect(socket_addr),
+        };
+
+        match result {
+            Ok(stream) => return Ok(stream),
+            Err(error) => last_error = Some(error),
+        }
+    }
+
+    Err(last_error.unwrap().into())
+}
+
+/// Listens on `port` on all interfaces, or an ephemeral port if `port` is `0`.  Sockets accepted
+/// from it get its `options`.
+pub fn listen(owner: Pid, port: u16, options: Options) -> Reply {
+    let ip_addr = if options.inet6 {
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED)
+    } else {
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+    };
+
+    // Non-blocking, so that `accept` can time out and notice the listen socket being closed
+    match TcpListener::bind(SocketAddr::new(ip_addr, port)).and_then(|listener| {
+        listener.set_nonblocking(true)?;
+
+        Ok(listener)
+    }) {
+        Ok(listener) => open(owner, Kind::TcpListener(listener), options),
+        Err(error) => error.into(),
+    }
+}
+
+/// Waits for a connection to the listen socket `port` and returns a socket for it owned by
+/// `owner`.  `timeout` of `None` waits forever.
+pub fn accept(owner: Pid, port: Port, timeout: Option<Duration>) -> Reply {
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("closed"),
+    };
+    let listener = match &arc_socket.kind {
+        Kind::TcpListener(listener) => listener,
+        _ => return Reply::Error("einval"),
+    };
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                // Whether accepted streams inherit non-blocking differs between OSes
+                if let Err(error) = stream.set_nonblocking(false) {
+                    return error.into();
+                }
+
+                let options = arc_socket.options.lock().clone();
+
+                return open(owner, Kind::TcpStream(stream), options);
+            }
+            Err(error) if error.kind() == ErrorKind::WouldBlock => {
+                if !is_open(port) {
+                    return Reply::Error("closed");
+                }
+
+                if deadline.map_or(false, |deadline| deadline <= Instant::now()) {
+                    return Reply::Error("timeout");
+                }
+
+                thread::sleep(POLL_INTERVAL);
+            }
+            Err(error) => return error.into(),
+        }
+    }
+}
+
+/// Writes all of `bytes` to the socket `port`.
+pub fn send(port: Port, bytes: &[u8]) -> Reply {
+    match get(port) {
+        Some(arc_socket) => match &arc_socket.kind {
+            Kind::TcpStream(stream) => match (&*stream).write_all(bytes) {
+                Ok(()) => Reply::Ok,
+                Err(error) => error.into(),
+            },
+            _ => Reply::Error("enotconn"),
+        },
+        None => Reply::Error("closed"),
+    }
+}
+
+/// Reads exactly `len` bytes from the passive socket `port`, or whatever is available if `len` is
+/// `0`.  `timeout` of `None` waits forever.  If it times out, the bytes that did arrive are
+/// returned by the next `recv`.
+pub fn recv(port: Port, len: usize, timeout: Option<Duration>) -> Reply {
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("closed"),
+    };
+    let stream = match &arc_socket.kind {
+        Kind::TcpStream(stream) => stream,
+        _ => return Reply::Error("enotconn"),
+    };
+    let binary = {
+        let options = arc_socket.options.lock();
+
+        // Data goes to the owner in messages instead
+        if options.active != Active::False {
+            return Reply::Error("einval");
+        }
+
+        options.binary
+    };
+
+    let _reading = arc_socket.reading.lock();
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut received = mem::take(&mut *arc_socket.received.lock());
+    let mut buffer = vec![0; READ_SIZE];
+
+    let reply = loop {
+        if let Some(bytes) = take_received(&mut received, len) {
+            break Reply::Data { bytes, binary };
+        }
+
+        // One deadline for all the reads, instead of the whole timeout for each of them
+        let read_timeout =
+            deadline.map(|deadline| non_zero(deadline.saturating_duration_since(Instant::now())));
+
+        if let Err(error) = stream.set_read_timeout(read_timeout) {
+            break error.into();
+        }
+
+        let wanted_len = if len == 0 {
+            READ_SIZE
+        } else {
+            (len - received.len()).min(READ_SIZE)
+        };
+
+        match (&*stream).read(&mut buffer[..wanted_len]) {
+            Ok(0) => break Reply::Error("closed"),
+            Ok(read_len) => received.extend_from_slice(&buffer[..read_len]),
+            Err(ref error) if is_timeout(error) => {
+                if deadline.map_or(false, |deadline| deadline <= Instant::now()) {
+                    break Reply::Error("timeout");
+                }
+            }
+            Err(error) => break error.into(),
+        }
+    };
+
+    // Bytes read before a timeout are kept for the next `recv` instead of being lost
+    *arc_socket.received.lock() = received;
+
+    reply
+}
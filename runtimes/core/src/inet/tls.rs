@@ -0,0 +1,399 @@
+//! TLS sockets for `ssl`, layered with rustls on the TCP streams of `gen_tcp`.
+//!
+//! Reads and writes share the TLS session, so reads hold it for at most `POLL_INTERVAL` at a time,
+//! which lets the owner send while a read waits for data.
+
+use std::fs::File;
+use std::io::{self, BufReader, ErrorKind, Read, Write};
+use std::mem;
+use std::net::{Shutdown, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use rustls::internal::pemfile;
+use rustls::{ClientConfig, ClientSession, NoClientAuth, ServerConfig, ServerSession, TLSError};
+use webpki::DNSNameRef;
+
+use liblumen_core::locks::Mutex;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::*;
+
+/// The TLS options of `ssl:connect` and `ssl:handshake`, besides the socket's `Options`.
+#[derive(Clone, Default)]
+pub struct Config {
+    /// PEM file of the CA certificates that the server's certificate is verified against, instead
+    /// of the bundled Mozilla roots.
+    pub cacertfile: Option<PathBuf>,
+    /// PEM file of a server's certificate chain.
+    pub certfile: Option<PathBuf>,
+    /// PEM file of a server's private key.
+    pub keyfile: Option<PathBuf>,
+    /// The hostname that the server's certificate is verified for, instead of the hostname
+    /// connected to.
+    pub server_name_indication: Option<String>,
+}
+
+/// Connects to `port` on `address`, verifies the server's certificate, and returns a TLS socket
+/// owned by `owner`.  `timeout` of `None` waits forever.
+pub fn connect(
+    owner: Pid,
+    address: &Address,
+    port: u16,
+    options: Options,
+    config: Config,
+    timeout: Option<Duration>,
+) -> Reply {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let server_name = match (config.server_name_indication.as_ref(), address) {
+        (Some(server_name), _) => server_name.clone(),
+        (None, Address::Hostname(hostname)) => hostname.clone(),
+        // Certificates are only verified for hostnames
+        (None, Address::Ip(_)) => return Reply::Error("einval"),
+    };
+    let client_config = match client_config(&config) {
+        Ok(client_config) => client_config,
+        Err(reply) => return reply,
+    };
+    let session = match DNSNameRef::try_from_ascii_str(&server_name) {
+        Ok(dns_name) => ClientSession::new(&Arc::new(client_config), dns_name),
+        Err(_) => return Reply::Error("einval"),
+    };
+
+    match tcp::connect_stream(address, port, options.inet6, timeout) {
+        Ok(tcp_stream) => handshake(
+            owner,
+            Session::Client(session),
+            tcp_stream,
+            options,
+            deadline,
+        ),
+        Err(reply) => reply,
+    }
+}
+
+/// Upgrades the passive `gen_tcp` socket `port` to a TLS socket owned by `owner`, as the server.
+/// The `gen_tcp` socket is gone afterwards, even if the handshake fails.
+pub fn accept(owner: Pid, port: Port, options: Options, config: Config) -> Reply {
+    let server_config = match server_config(&config) {
+        Ok(server_config) => server_config,
+        Err(reply) => return reply,
+    };
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("closed"),
+    };
+    let tcp_stream = match &arc_socket.kind {
+        Kind::TcpStream(tcp_stream) => tcp_stream,
+        _ => return Reply::Error("enotconn"),
+    };
+
+    // A thread reading for an active socket would take the peer's handshake
+    if arc_socket.options.lock().active != Active::False {
+        return Reply::Error("einval");
+    }
+
+    let tcp_stream = match tcp_stream.try_clone() {
+        Ok(tcp_stream) => tcp_stream,
+        Err(error) => return error.into(),
+    };
+
+    // Not `close`, as that would shut down the stream that the TLS socket now uses
+    RW_LOCK_SOCKET_BY_PORT.write().remove(&port);
+
+    let session = ServerSession::new(&Arc::new(server_config));
+
+    handshake(owner, Session::Server(session), tcp_stream, options, None)
+}
+
+/// Encrypts all of `bytes` and writes them to the TLS socket `port`.
+pub fn send(port: Port, bytes: &[u8]) -> Reply {
+    match get(port) {
+        Some(arc_socket) => match &arc_socket.kind {
+            Kind::Tls(stream) => match stream.lock().write(bytes) {
+                Ok(()) => Reply::Ok,
+                Err(error) => error_to_reply(error),
+            },
+            _ => Reply::Error("enotconn"),
+        },
+        None => Reply::Error("closed"),
+    }
+}
+
+/// Reads exactly `len` bytes from the passive TLS socket `port`, or whatever is available if
+/// `len` is `0`.  `timeout` of `None` waits forever.  If it times out, the bytes that did arrive
+/// are returned by the next `recv`.
+pub fn recv(port: Port, len: usize, timeout: Option<Duration>) -> Reply {
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("closed"),
+    };
+    let stream = match &arc_socket.kind {
+        Kind::Tls(stream) => stream,
+        _ => return Reply::Error("enotconn"),
+    };
+    let binary = {
+        let options = arc_socket.options.lock();
+
+        // Data goes to the owner in messages instead
+        if options.active != Active::False {
+            return Reply::Error("einval");
+        }
+
+        options.binary
+    };
+
+    let _reading = arc_socket.reading.lock();
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut buffer = vec![0; READ_SIZE];
+    let mut received = mem::take(&mut *arc_socket.received.lock());
+
+    let reply = loop {
+        if let Some(bytes) = take_received(&mut received, len) {
+            break Reply::Data { bytes, binary };
+        }
+
+        let wanted_len = if len == 0 {
+            READ_SIZE
+        } else {
+            (len - received.len()).min(READ_SIZE)
+        };
+        let result = stream.lock().read(&mut buffer[..wanted_len]);
+
+        match result {
+            Ok(Some(read_len)) => received.extend_from_slice(&buffer[..read_len]),
+            Ok(None) => break Reply::Error("closed"),
+            Err(ref error) if is_timeout(error) => {
+                if !is_open(port) {
+                    break Reply::Error("closed");
+                }
+
+                if deadline.map_or(false, |deadline| deadline <= Instant::now()) {
+                    break Reply::Error("timeout");
+                }
+            }
+            Err(error) => break error_to_reply(error),
+        }
+    };
+
+    // Bytes read before a timeout are kept for the next `recv` instead of being lost
+    *arc_socket.received.lock() = received;
+
+    reply
+}
+
+/// The TLS session of a socket and the TCP stream under it.
+pub(super) struct Stream {
+    session: Session,
+    tcp_stream: TcpStream,
+}
+
+impl Stream {
+    /// Reads decrypted data into `buffer`, or returns `None` once the peer has closed the
+    /// connection.  Waiting longer than `POLL_INTERVAL` for data is a timeout error.
+    pub(super) fn read(&mut self, buffer: &mut [u8]) -> io::Result<Option<usize>> {
+        loop {
+            match self.session.as_dyn().read(buffer) {
+                Ok(0) => (),
+                Ok(len) => return Ok(Some(len)),
+                // How rustls reports the peer's close_notify alert
+                Err(ref error) if error.kind() == ErrorKind::ConnectionAborted => return Ok(None),
+                Err(error) => return Err(error),
+            }
+
+            if self.session.as_dyn().read_tls(&mut self.tcp_stream)? == 0 {
+                return Ok(None);
+            }
+
+            if let Err(tls_error) = self.session.as_dyn().process_new_packets() {
+                // Sends the peer the alert for the error
+                let _ = self.flush();
+
+                return Err(io::Error::new(ErrorKind::InvalidData, tls_error));
+            }
+
+            // Answers any handshake messages, such as key updates
+            self.flush()?;
+        }
+    }
+
+    fn write(&mut self, mut bytes: &[u8]) -> io::Result<()> {
+        while !bytes.is_empty() {
+            let len = self.session.as_dyn().write(bytes)?;
+            bytes = &bytes[len..];
+
+            self.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the close_notify alert, so that the peer knows that no data was cut off, before
+    /// shutting down the stream.
+    pub(super) fn close(&mut self) {
+        self.session.as_dyn().send_close_notify();
+        let _ = self.flush();
+        let _ = self.tcp_stream.shutdown(Shutdown::Both);
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        while self.session.as_dyn().wants_write() {
+            self.session.as_dyn().write_tls(&mut self.tcp_stream)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Private
+
+enum Session {
+    Client(ClientSession),
+    Server(ServerSession),
+}
+
+impl Session {
+    fn as_dyn(&mut self) -> &mut dyn rustls::Session {
+        match self {
+            Session::Client(client_session) => client_session,
+            Session::Server(server_session) => server_session,
+        }
+    }
+
+    // Only implemented for sized sessions
+    fn complete_io(&mut self, tcp_stream: &mut TcpStream) -> io::Result<(usize, usize)> {
+        match self {
+            Session::Client(client_session) => client_session.complete_io(tcp_stream),
+            Session::Server(server_session) => server_session.complete_io(tcp_stream),
+        }
+    }
+}
+
+fn client_config(config: &Config) -> std::result::Result<ClientConfig, Reply> {
+    let mut client_config = ClientConfig::new();
+
+    match &config.cacertfile {
+        Some(cacertfile) => {
+            let mut reader = open_pem_file(cacertfile)?;
+
+            match client_config.root_store.add_pem_file(&mut reader) {
+                Ok((valid_count, _)) if valid_count > 0 => (),
+                _ => return Err(Reply::Error("einval")),
+            }
+        }
+        None => client_config
+            .root_store
+            .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS),
+    }
+
+    Ok(client_config)
+}
+
+fn server_config(config: &Config) -> std::result::Result<ServerConfig, Reply> {
+    let (certfile, keyfile) = match (&config.certfile, &config.keyfile) {
+        (Some(certfile), Some(keyfile)) => (certfile, keyfile),
+        _ => return Err(Reply::Error("einval")),
+    };
+
+    let certificates =
+        pemfile::certs(&mut open_pem_file(certfile)?).map_err(|()| Reply::Error("einval"))?;
+
+    // PKCS #8 from `openssl genpkey` or PKCS #1 from `openssl genrsa`
+    let mut private_keys = pemfile::pkcs8_private_keys(&mut open_pem_file(keyfile)?)
+        .map_err(|()| Reply::Error("einval"))?;
+
+    if private_keys.is_empty() {
+        private_keys = pemfile::rsa_private_keys(&mut open_pem_file(keyfile)?)
+            .map_err(|()| Reply::Error("einval"))?;
+    }
+
+    let private_key = match private_keys.into_iter().next() {
+        Some(private_key) => private_key,
+        None => return Err(Reply::Error("einval")),
+    };
+
+    let mut server_config = ServerConfig::new(NoClientAuth::new());
+    server_config
+        .set_single_cert(certificates, private_key)
+        .map_err(|_| Reply::Error("einval"))?;
+
+    Ok(server_config)
+}
+
+fn open_pem_file(path: &Path) -> std::result::Result<BufReader<File>, Reply> {
+    File::open(path).map(BufReader::new).map_err(From::from)
+}
+
+/// Finishes the handshake on `tcp_stream` and opens the TLS socket.
+fn handshake(
+    owner: Pid,
+    mut session: Session,
+    mut tcp_stream: TcpStream,
+    options: Options,
+    deadline: Option<Instant>,
+) -> Reply {
+    while session.as_dyn().is_handshaking() {
+        let read_timeout = match deadline {
+            Some(deadline) => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                if remaining == Duration::from_secs(0) {
+                    return Reply::Error("timeout");
+                }
+
+                Some(remaining)
+            }
+            None => None,
+        };
+
+        if let Err(error) = tcp_stream.set_read_timeout(read_timeout) {
+            return error.into();
+        }
+
+        if let Err(error) = session.complete_io(&mut tcp_stream) {
+            return error_to_reply(error);
+        }
+    }
+
+    // Reads poll, so that they don't hold the session while waiting
+    if let Err(error) = tcp_stream.set_read_timeout(Some(POLL_INTERVAL)) {
+        return error.into();
+    }
+
+    let stream = Stream {
+        session,
+        tcp_stream,
+    };
+
+    open(owner, Kind::Tls(Mutex::new(stream)), options)
+}
+
+/// TLS errors are `{error, {tls_alert, {Alert, Description}}}` like in OTP.
+fn error_to_reply(error: io::Error) -> Reply {
+    let option_tls_error = error
+        .get_ref()
+        .and_then(|inner| inner.downcast_ref::<TLSError>());
+
+    match option_tls_error {
+        Some(tls_error) => {
+            let alert = match tls_error {
+                TLSError::WebPKIError(webpki::Error::UnknownIssuer) => "unknown_ca",
+                TLSError::WebPKIError(webpki::Error::CertExpired)
+                | TLSError::WebPKIError(webpki::Error::CertNotValidYet) => "certificate_expired",
+                TLSError::WebPKIError(_) => "bad_certificate",
+                TLSError::DecryptError => "decrypt_error",
+                _ => "handshake_failure",
+            };
+
+            Reply::TlsAlert {
+                alert,
+                description: tls_error.to_string(),
+            }
+        }
+        None if is_timeout(&error) => Reply::Error("timeout"),
+        None if error.kind() == ErrorKind::UnexpectedEof => Reply::Error("closed"),
+        None => error.into(),
+    }
+}
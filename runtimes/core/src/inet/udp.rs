@@ -0,0 +1,122 @@
+//! UDP sockets for `gen_udp`, owned by the process that opened them.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use super::*;
+
+/// Opens a socket on `port` on all interfaces, or an ephemeral port if `port` is `0`, owned by
+/// `owner`.
+pub fn open(owner: Pid, port: u16, options: Options) -> Reply {
+    let ip_addr = if options.inet6 {
+        IpAddr::V6(Ipv6Addr::UNSPECIFIED)
+    } else {
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED)
+    };
+
+    match UdpSocket::bind(SocketAddr::new(ip_addr, port)) {
+        Ok(udp_socket) => super::open(owner, Kind::Udp(udp_socket), options),
+        Err(error) => error.into(),
+    }
+}
+
+/// Sends `bytes` as one datagram from the socket `port` to `destination_port` on `address`.
+pub fn send(port: Port, address: &Address, destination_port: u16, bytes: &[u8]) -> Reply {
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("closed"),
+    };
+    let udp_socket = match &arc_socket.kind {
+        Kind::Udp(udp_socket) => udp_socket,
+        _ => return Reply::Error("einval"),
+    };
+    let inet6 = arc_socket.options.lock().inet6;
+
+    let socket_addr = match address.to_socket_addrs(destination_port, inet6) {
+        Ok(socket_addrs) => match socket_addrs.into_iter().next() {
+            Some(socket_addr) => socket_addr,
+            None => return Reply::Error("nxdomain"),
+        },
+        Err(_) => return Reply::Error("nxdomain"),
+    };
+
+    // An IPv6 socket reaches IPv4 addresses through their IPv4-mapped IPv6 addresses
+    let socket_addr = match (inet6, socket_addr.ip()) {
+        (true, IpAddr::V4(ipv4_addr)) => {
+            SocketAddr::new(IpAddr::V6(ipv4_addr.to_ipv6_mapped()), socket_addr.port())
+        }
+        (false, IpAddr::V6(_)) => return Reply::Error("eafnosupport"),
+        _ => socket_addr,
+    };
+
+    match udp_socket.send_to(bytes, socket_addr) {
+        Ok(_) => Reply::Ok,
+        Err(error) => error.into(),
+    }
+}
+
+/// Receives the next datagram, or up to `len` bytes of it if `len` isn't `0`, from the passive
+/// socket `port`.  `timeout` of `None` waits forever.
+pub fn recv(port: Port, len: usize, timeout: Option<Duration>) -> Reply {
+    let arc_socket = match get(port) {
+        Some(arc_socket) => arc_socket,
+        None => return Reply::Error("closed"),
+    };
+    let udp_socket = match &arc_socket.kind {
+        Kind::Udp(udp_socket) => udp_socket,
+        _ => return Reply::Error("einval"),
+    };
+    let binary = {
+        let options = arc_socket.options.lock();
+
+        // Datagrams go to the owner in messages instead
+        if options.active != Active::False {
+            return Reply::Error("einval");
+        }
+
+        options.binary
+    };
+
+    let _reading = arc_socket.reading.lock();
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut bytes = vec![0; if len == 0 { MAX_DATAGRAM_SIZE } else { len }];
+
+    loop {
+        // Closing a UDP socket doesn't wake a thread receiving from it, so it polls
+        let poll_interval = match deadline {
+            Some(deadline) => deadline
+                .saturating_duration_since(Instant::now())
+                .min(POLL_INTERVAL),
+            None => POLL_INTERVAL,
+        };
+
+        if let Err(error) = udp_socket.set_read_timeout(Some(non_zero(poll_interval))) {
+            return error.into();
+        }
+
+        match udp_socket.recv_from(&mut bytes) {
+            Ok((received_len, socket_addr)) => {
+                bytes.truncate(received_len);
+
+                return Reply::Datagram {
+                    ip_addr: socket_addr.ip(),
+                    port: socket_addr.port(),
+                    bytes,
+                    binary,
+                };
+            }
+            Err(ref error) if is_timeout(error) => {
+                if !is_open(port) {
+                    return Reply::Error("closed");
+                }
+
+                if deadline.map_or(false, |deadline| deadline <= Instant::now()) {
+                    return Reply::Error("timeout");
+                }
+            }
+            Err(error) => return error.into(),
+        }
+    }
+}
@@ -7,14 +7,24 @@
 
 pub mod binary_to_string;
 pub mod builtins;
+pub mod code;
 pub mod context;
+pub mod dirty_io;
 pub mod distribution;
+pub mod ets;
+pub mod file;
+pub mod inet;
+pub mod logger;
+pub mod port;
 pub mod process;
+pub mod profile;
 pub mod proplist;
 pub mod registry;
 pub mod scheduler;
 pub mod send;
 pub mod sys;
+pub mod task;
 pub mod test;
 pub mod time;
 pub mod timer;
+pub mod trace;
@@ -0,0 +1,152 @@
+//! The primary level of `logger`, which decides which events reach the handlers.
+//!
+//! The level is set when the runtime starts and can be changed later with
+//! `logger:set_primary_config/2`.  Like OTP, it defaults to `notice`.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use thiserror::Error;
+
+/// The severity of a log event, from most to least severe
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Emergency,
+    Alert,
+    Critical,
+    Error,
+    Warning,
+    Notice,
+    Info,
+    Debug,
+}
+
+impl Level {
+    const ALL: [Level; 8] = [
+        Level::Emergency,
+        Level::Alert,
+        Level::Critical,
+        Level::Error,
+        Level::Warning,
+        Level::Notice,
+        Level::Info,
+        Level::Debug,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Emergency => "emergency",
+            Level::Alert => "alert",
+            Level::Critical => "critical",
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Notice => "notice",
+            Level::Info => "info",
+            Level::Debug => "debug",
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Level {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|level| level.as_str() == s)
+            .copied()
+            .ok_or_else(|| ParseLevelError(s.to_string()))
+    }
+}
+
+/// The primary level, which may also let through every event, or none of them
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrimaryLevel {
+    None,
+    Level(Level),
+    All,
+}
+
+impl PrimaryLevel {
+    /// Whether events at `level` are logged
+    pub fn allows(&self, level: Level) -> bool {
+        match self {
+            PrimaryLevel::None => false,
+            PrimaryLevel::Level(primary_level) => level <= *primary_level,
+            PrimaryLevel::All => true,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrimaryLevel::None => "none",
+            PrimaryLevel::Level(level) => level.as_str(),
+            PrimaryLevel::All => "all",
+        }
+    }
+
+    fn to_u8(self) -> u8 {
+        match self {
+            PrimaryLevel::None => 0,
+            PrimaryLevel::Level(level) => level as u8 + 1,
+            PrimaryLevel::All => Level::ALL.len() as u8 + 1,
+        }
+    }
+
+    fn from_u8(u: u8) -> Self {
+        match u {
+            0 => PrimaryLevel::None,
+            u if (u as usize) <= Level::ALL.len() => {
+                PrimaryLevel::Level(Level::ALL[u as usize - 1])
+            }
+            _ => PrimaryLevel::All,
+        }
+    }
+}
+
+impl Default for PrimaryLevel {
+    fn default() -> Self {
+        PrimaryLevel::Level(Level::Notice)
+    }
+}
+
+impl Display for PrimaryLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PrimaryLevel {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(PrimaryLevel::None),
+            "all" => Ok(PrimaryLevel::All),
+            _ => s.parse().map(PrimaryLevel::Level),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a level, which is emergency, alert, critical, error, warning, notice, info, debug, all, or none")]
+pub struct ParseLevelError(String);
+
+pub fn primary_level() -> PrimaryLevel {
+    PrimaryLevel::from_u8(PRIMARY_LEVEL.load(Ordering::SeqCst))
+}
+
+pub fn set_primary_level(primary_level: PrimaryLevel) {
+    PRIMARY_LEVEL.store(primary_level.to_u8(), Ordering::SeqCst);
+}
+
+// Private
+
+static PRIMARY_LEVEL: AtomicU8 = AtomicU8::new(Level::Notice as u8 + 1);
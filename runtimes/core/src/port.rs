@@ -0,0 +1,372 @@
+//! Ports connect processes to OS processes.
+//!
+//! `open_port({spawn, Command}, Options)` runs `Command` with the shell.  Data written with
+//! `port_command/2` goes to the OS process's stdin and whatever it writes to stdout is sent to the
+//! port's owner as `{Port, {data, Data}}`.
+//!
+//! Writes block until the OS process reads its stdin, so `port_command/2` writes on the `dirty_io`
+//! pool.  Ports are closed when their owner exits.
+//!
+//! `cmd` runs a command the same way for `os:cmd/1`, but waits for it to exit.
+mod options;
+
+use std::convert::TryInto;
+use std::io::{self, Read, Write};
+use std::process::{Child, ChildStdin, ChildStdout, Command, ExitStatus, Stdio};
+use std::sync::Arc;
+use std::thread;
+
+use anyhow::*;
+use hashbrown::HashMap;
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::{Mutex, RwLock};
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::Process;
+
+use crate::process;
+use crate::process::spawn::options::Options as SpawnOptions;
+use crate::registry;
+
+pub use options::*;
+
+/// The most bytes delivered in one `{data, Data}` message when there is no packet header.
+const STREAM_READ_SIZE: usize = 4096;
+
+struct Control {
+    owner: Pid,
+    options: Options,
+    // `None` once the port is closed, so that the OS process sees end-of-file.
+    stdin: Mutex<Option<ChildStdin>>,
+}
+
+/// Spawns `command` with the shell and returns the port owned by `owner` that communicates with
+/// it.
+pub fn open(owner: Pid, command: &str, options: Options) -> anyhow::Result<Port> {
+    let mut child = shell(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("could not spawn command ({:?})", command))?;
+    let stdin = child.stdin.take();
+    let stdout = child.stdout.take().unwrap();
+
+    let port = Port::next();
+    let arc_control = Arc::new(Control {
+        owner,
+        options,
+        stdin: Mutex::new(stdin),
+    });
+
+    RW_LOCK_CONTROL_BY_PORT
+        .write()
+        .insert(port, arc_control.clone());
+
+    thread::spawn(move || {
+        if let Err(error) = receive(port, &arc_control, child, stdout) {
+            log::warn!("Port ({}) failed: {:?}", port, error);
+        }
+
+        RW_LOCK_CONTROL_BY_PORT.write().remove(&port);
+    });
+
+    Ok(port)
+}
+
+/// Runs `command` with the shell, like a port, and returns what it writes to stdout and stderr
+/// once it exits, like `os:cmd/1`.
+pub fn cmd(command: &str) -> anyhow::Result<Vec<u8>> {
+    // Like `os:cmd/1`, stdin is closed and stderr goes to stdout.  The newline before `)` ends any
+    // comment at the end of `command`.
+    let output = shell(&format!("({}\n) 2>&1", command))
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("could not spawn command ({:?})", command))?;
+
+    Ok(output.stdout)
+}
+
+/// The bytes of a `port_command/2`, prefixed with the packet header if the port has one, ready to
+/// be written to the OS process's stdin.
+pub struct Packet {
+    port: Port,
+    control: Arc<Control>,
+    bytes: Vec<u8>,
+}
+
+impl Packet {
+    /// Writes the packet to the OS process's stdin.
+    ///
+    /// The write blocks until the OS process reads enough of its stdin, so it must run on the
+    /// `dirty_io` pool and not on a scheduler.
+    pub fn write(self) -> anyhow::Result<()> {
+        let result = match self.control.stdin.lock().as_mut() {
+            Some(stdin) => stdin
+                .write_all(&self.bytes)
+                .with_context(|| format!("could not write to port ({})", self.port)),
+            None => Err(anyhow!("port ({}) is closed", self.port)),
+        };
+
+        // `close` leaves stdin to the write in progress, so that it does not block its scheduler
+        if !is_open(self.port) {
+            self.control.stdin.lock().take();
+        }
+
+        result
+    }
+}
+
+/// Prefixes `bytes` with the packet header if `port` has one, for `Packet::write`.
+pub fn command(port: Port, bytes: &[u8]) -> anyhow::Result<Packet> {
+    let control = get(port).with_context(|| format!("port ({}) is not open", port))?;
+    let mut byte_vec = match control.options.packet {
+        Some(size) => {
+            let len: u32 = bytes
+                .len()
+                .try_into()
+                .ok()
+                .filter(|len| *len <= max_packet_len(size))
+                .with_context(|| {
+                    format!(
+                        "data ({} bytes) does not fit in a {} byte packet header",
+                        bytes.len(),
+                        size
+                    )
+                })?;
+
+            len.to_be_bytes()[(4 - size as usize)..].to_vec()
+        }
+        None => Vec::with_capacity(bytes.len()),
+    };
+    byte_vec.extend_from_slice(bytes);
+
+    Ok(Packet {
+        port,
+        control,
+        bytes: byte_vec,
+    })
+}
+
+/// Closes `port`, so that its owner receives no more messages from it.  The OS process sees
+/// end-of-file on its stdin.
+///
+/// Returns `false` if `port` was not open.
+pub fn close(port: Port) -> bool {
+    match RW_LOCK_CONTROL_BY_PORT.write().remove(&port) {
+        Some(arc_control) => {
+            // A write in progress holds stdin and closes it once done
+            if let Some(mut stdin) = arc_control.stdin.try_lock() {
+                stdin.take();
+            }
+
+            true
+        }
+        None => false,
+    }
+}
+
+/// Closes all ports owned by the exiting process with `pid`, so that their OS processes see
+/// end-of-file on their stdin.
+pub fn close_owned_by(pid: Pid) {
+    let owned_port_vec: Vec<Port> = RW_LOCK_CONTROL_BY_PORT
+        .read()
+        .iter()
+        .filter(|(_, arc_control)| arc_control.owner == pid)
+        .map(|(port, _)| *port)
+        .collect();
+
+    for port in owned_port_vec {
+        close(port);
+    }
+}
+
+pub fn is_open(port: Port) -> bool {
+    RW_LOCK_CONTROL_BY_PORT.read().contains_key(&port)
+}
+
+// Private
+
+fn deliver(
+    decoder: &Process,
+    port: Port,
+    control: &Control,
+    data: impl FnOnce(&Process) -> Term,
+) -> bool {
+    // A closed port sends nothing more to its owner
+    if !is_open(port) {
+        return false;
+    }
+
+    match registry::pid_to_process(&control.owner) {
+        Some(owner_arc_process) => {
+            let message = decoder.tuple_from_slice(&[port.encode().unwrap(), data(decoder)]);
+            process::send_from_decoder(decoder, &owner_arc_process, message);
+
+            true
+        }
+        // Ports are closed when their owner exits
+        None => {
+            close(port);
+
+            false
+        }
+    }
+}
+
+fn exit_status_code(exit_status: ExitStatus) -> i32 {
+    match exit_status.code() {
+        Some(code) => code,
+        None => {
+            // Like the shell, report death by signal as 128 + the signal number
+            #[cfg(unix)]
+            {
+                use std::os::unix::process::ExitStatusExt;
+
+                128 + exit_status.signal().unwrap_or(0)
+            }
+
+            #[cfg(not(unix))]
+            {
+                -1
+            }
+        }
+    }
+}
+
+fn get(port: Port) -> Option<Arc<Control>> {
+    RW_LOCK_CONTROL_BY_PORT.read().get(&port).cloned()
+}
+
+fn max_packet_len(size: u8) -> u32 {
+    match size {
+        4 => u32::MAX,
+        _ => (1 << (8 * size as u32)) - 1,
+    }
+}
+
+/// Reads the next packet, or `None` at end-of-file.
+fn read_packet(stdout: &mut ChildStdout, options: &Options) -> io::Result<Option<Vec<u8>>> {
+    match options.packet {
+        Some(size) => {
+            let mut len_bytes = [0; 4];
+
+            match stdout.read_exact(&mut len_bytes[(4 - size as usize)..]) {
+                Ok(()) => {
+                    let mut bytes = vec![0; u32::from_be_bytes(len_bytes) as usize];
+                    stdout.read_exact(&mut bytes)?;
+
+                    Ok(Some(bytes))
+                }
+                Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+                Err(error) => Err(error),
+            }
+        }
+        None => {
+            let mut bytes = vec![0; STREAM_READ_SIZE];
+            let len = stdout.read(&mut bytes)?;
+
+            if len == 0 {
+                Ok(None)
+            } else {
+                bytes.truncate(len);
+
+                Ok(Some(bytes))
+            }
+        }
+    }
+}
+
+fn receive(
+    port: Port,
+    control: &Control,
+    mut child: Child,
+    mut stdout: ChildStdout,
+) -> anyhow::Result<()> {
+    // Messages are built on this process's heap before being copied to the owner, as only the
+    // owner's scheduler may allocate on the owner's heap.
+    let decoder = SpawnOptions::default()
+        .spawn(
+            None,
+            Atom::from_str("erlang"),
+            Atom::from_str("port_receive"),
+            0,
+        )
+        .map_err(|alloc| anyhow!("could not allocate decoder heap: {}", alloc))?;
+
+    while let Some(bytes) = read_packet(&mut stdout, &control.options)? {
+        let delivered = deliver(&decoder, port, control, |process| {
+            let data = if control.options.binary {
+                process.binary_from_bytes(&bytes)
+            } else {
+                let byte_terms: Vec<Term> =
+                    bytes.iter().map(|byte| process.integer(*byte)).collect();
+
+                process.list_from_slice(&byte_terms)
+            };
+
+            process.tuple_from_slice(&[Atom::str_to_term("data"), data])
+        });
+
+        if !delivered {
+            break;
+        }
+    }
+
+    // Without its stdout, the OS process can't do anything more that the owner would see
+    drop(stdout);
+    let exit_status = child.wait()?;
+
+    if control.options.exit_status {
+        deliver(&decoder, port, control, |process| {
+            process.tuple_from_slice(&[
+                Atom::str_to_term("exit_status"),
+                process.integer(exit_status_code(exit_status)),
+            ])
+        });
+    }
+
+    Ok(())
+}
+
+fn shell(command: &str) -> Command {
+    let mut shell = Command::new("/bin/sh");
+    shell.arg("-c").arg(command);
+
+    shell
+}
+
+lazy_static! {
+    static ref RW_LOCK_CONTROL_BY_PORT: RwLock<HashMap<Port, Arc<Control>>> = Default::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn close_owned_by_closes_only_ports_of_owner() {
+        let owner = Pid::next();
+        let other_owner = Pid::next();
+        let owned_port = open(owner, "cat", Options::default()).unwrap();
+        let other_owned_port = open(other_owner, "cat", Options::default()).unwrap();
+
+        close_owned_by(owner);
+
+        assert!(!is_open(owned_port));
+        assert!(is_open(other_owned_port));
+
+        close(other_owned_port);
+    }
+
+    #[test]
+    fn packet_write_after_close_errors() {
+        let port = open(Pid::next(), "cat", Options::default()).unwrap();
+        let packet = command(port, b"data").unwrap();
+
+        close(port);
+
+        assert!(packet.write().is_err());
+        assert!(command(port, b"data").is_err());
+    }
+}
@@ -0,0 +1,110 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::proplist::TryPropListFromTermError;
+
+pub struct Options {
+    /// Whether data is delivered to the owner as a binary instead of a list of bytes.
+    pub binary: bool,
+    /// Whether the owner is sent `{Port, {exit_status, Status}}` when the OS process exits.
+    pub exit_status: bool,
+    /// The size in bytes of the big-endian length header that frames each packet, if any.  Without
+    /// a header, data is delivered in whatever chunks it is read.
+    pub packet: Option<u8>,
+}
+
+const SUPPORTED_OPTIONS_CONTEXT: &str =
+    "supported options are binary, exit_status, stream, use_stdio, or {packet, 1 | 2 | 4}";
+
+impl Options {
+    fn put_option_term(&mut self, option: Term) -> core::result::Result<&Options, anyhow::Error> {
+        match option.decode().unwrap() {
+            TypedTerm::Atom(atom) => match atom.name() {
+                "binary" => {
+                    self.binary = true;
+
+                    Ok(self)
+                }
+                "exit_status" => {
+                    self.exit_status = true;
+
+                    Ok(self)
+                }
+                "stream" => {
+                    self.packet = None;
+
+                    Ok(self)
+                }
+                // stdin and stdout are the only way to communicate with the OS process
+                "use_stdio" => Ok(self),
+                name => {
+                    Err(TryPropListFromTermError::AtomName(name)).context(SUPPORTED_OPTIONS_CONTEXT)
+                }
+            },
+            TypedTerm::Tuple(tuple) => {
+                if tuple.len() == 2 {
+                    let name: Atom = tuple[0]
+                        .try_into()
+                        .map_err(|_| TryPropListFromTermError::KeywordKeyType)
+                        .context(SUPPORTED_OPTIONS_CONTEXT)?;
+
+                    match name.name() {
+                        "packet" => {
+                            let value = tuple[1];
+                            let size: u8 = value
+                                .try_into()
+                                .ok()
+                                .filter(|size| [1, 2, 4].contains(size))
+                                .with_context(|| {
+                                    format!("packet size ({}) must be 1, 2, or 4", value)
+                                })?;
+                            self.packet = Some(size);
+
+                            Ok(self)
+                        }
+                        name => Err(TryPropListFromTermError::KeywordKeyName(name))
+                            .context(SUPPORTED_OPTIONS_CONTEXT),
+                    }
+                } else {
+                    Err(TryPropListFromTermError::TupleNotPair).context(SUPPORTED_OPTIONS_CONTEXT)
+                }
+            }
+            _ => Err(TryPropListFromTermError::PropertyType).context(SUPPORTED_OPTIONS_CONTEXT),
+        }
+    }
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            binary: false,
+            exit_status: false,
+            packet: None,
+        }
+    }
+}
+
+impl TryFrom<Term> for Options {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> std::result::Result<Options, Self::Error> {
+        let mut options: Options = Default::default();
+        let mut options_term = term;
+
+        loop {
+            match options_term.decode().unwrap() {
+                TypedTerm::Nil => return Ok(options),
+                TypedTerm::List(cons) => {
+                    options.put_option_term(cons.head)?;
+                    options_term = cons.tail;
+
+                    continue;
+                }
+                _ => return Err(ImproperListError.into()),
+            }
+        }
+    }
+}
@@ -5,14 +5,20 @@ use std::cell::{Cell, RefCell};
 use std::convert::TryInto;
 use std::sync::Arc;
 
-use liblumen_alloc::erts::exception::{self, RuntimeException};
-use liblumen_alloc::erts::process::alloc::{Heap, TermAlloc};
-use liblumen_alloc::erts::process::{Process, ProcessHeap};
+use liblumen_alloc::erts::exception::{self, ArcError, RuntimeException};
+use liblumen_alloc::erts::process::gc::RootSet;
+use liblumen_alloc::erts::process::trace::Trace;
+use liblumen_alloc::erts::process::{ExitSignal, ExitSignalKind, Process};
 use liblumen_alloc::erts::term::prelude::*;
-use liblumen_alloc::{atom, CloneToProcess, HeapFragment, Monitor};
+use liblumen_alloc::{atom, Monitor};
 
+use crate::distribution::connection;
+use crate::ets;
+use crate::inet;
+use crate::port;
 use crate::registry::*;
 use crate::scheduler::{Scheduled, SchedulerDependentAlloc};
+use crate::trace;
 
 thread_local! {
   pub static CURRENT_PROCESS: RefCell<Option<Arc<Process>>> = RefCell::new(None);
@@ -88,130 +94,144 @@ pub fn monitor(process: &Process, monitored_process: &Process) -> Term {
 }
 
 pub fn propagate_exit(process: &Process, exception: Option<&RuntimeException>) {
+    let reason = exception
+        .map(|exception| exception.reason())
+        .unwrap_or_else(|| atom!("normal"));
+    trace::exit(process, reason);
+
+    // Like the BEAM, the name, tables, sockets, and ports are released before any exit signals are
+    // sent, so that monitoring and linked processes can immediately reuse the name and addresses and
+    // never see the tables.
+    remove_process(process);
+    ets::delete_owned_by(process.pid());
+    inet::close_owned_by(process.pid());
+    port::close_owned_by(process.pid());
     monitor::propagate_exit(process, exception);
     propagate_exit_to_links(process, exception);
 }
 
 pub fn propagate_exit_to_links(process: &Process, exception: Option<&RuntimeException>) {
-    // TODO: Shouldn't normal exits propagate?
-    if exception.is_none() {
-        return;
-    }
-    let exception = exception.unwrap();
-    if !is_expected_exception(exception) {
-        let tag = atom!("EXIT");
-        let from = process.pid_term();
-        let reason = exception.reason();
-        let reason_word_size = reason.size_in_words();
-        let exit_message_elements: &[Term] = &[tag, from, reason];
-        let exit_message_word_size = Tuple::need_in_words_from_elements(exit_message_elements);
-
-        for linked_pid in process.linked_pid_set.iter() {
-            if let Some(linked_pid_arc_process) = pid_to_process(linked_pid.key()) {
-                if linked_pid_arc_process.traps_exit() {
-                    match linked_pid_arc_process.try_acquire_heap() {
-                        Some(ref mut linked_pid_heap) => {
-                            if exit_message_word_size <= linked_pid_heap.heap_available() {
-                                send_self_exit_message(
-                                    &linked_pid_arc_process,
-                                    linked_pid_heap,
-                                    exit_message_elements,
-                                );
-                            } else {
-                                send_heap_exit_message(
-                                    &linked_pid_arc_process,
-                                    exit_message_elements,
-                                );
-                            }
-                        }
-                        None => {
-                            send_heap_exit_message(&linked_pid_arc_process, exit_message_elements);
-                        }
-                    }
-                } else {
-                    // only tell the linked process to exit.  When it is run by its scheduler, it
-                    // will go through propagating its own exit.
-                    match linked_pid_arc_process.try_acquire_heap() {
-                        Some(ref mut linked_pid_heap) => {
-                            if reason_word_size <= linked_pid_heap.heap_available() {
-                                exit_in_heap(
-                                    &linked_pid_arc_process,
-                                    linked_pid_heap,
-                                    reason,
-                                    exception.clone(),
-                                );
-                            } else {
-                                exit_in_heap_fragment(
-                                    &linked_pid_arc_process,
-                                    reason,
-                                    exception.clone(),
-                                );
-                            }
-                        }
-                        None => {
-                            exit_in_heap_fragment(
-                                &linked_pid_arc_process,
-                                reason,
-                                exception.clone(),
-                            );
-                        }
-                    }
-                }
-
-                linked_pid_arc_process
-                    .scheduler()
-                    .unwrap()
-                    .stop_waiting(&linked_pid_arc_process);
-            }
+    let pid = process.pid();
+    // A process that returns normally exits with `normal`, which only processes trapping exits
+    // observe.
+    let reason = exception
+        .map(|exception| exception.reason())
+        .unwrap_or_else(|| atom!("normal"));
+    let (trace, source) = match exception {
+        Some(exception) => (exception.stacktrace(), exception.source()),
+        None => (Trace::capture(), None),
+    };
+
+    // Collect first, so that the shard locks on `linked_pid_set` aren't held while the linked
+    // processes are unlinked.
+    let linked_pid_vec: Vec<Pid> = process
+        .linked_pid_set
+        .iter()
+        .map(|linked_pid| *linked_pid.key())
+        .collect();
+
+    for linked_pid in linked_pid_vec {
+        process.linked_pid_set.remove(&linked_pid);
+
+        if let Some(linked_pid_arc_process) = pid_to_process(&linked_pid) {
+            // The link is gone once the exit signal is delivered
+            linked_pid_arc_process.linked_pid_set.remove(&pid);
+
+            send_exit_signal(
+                &linked_pid_arc_process,
+                ExitSignalKind::Link,
+                pid,
+                reason,
+                trace.clone(),
+                source.clone(),
+            );
         }
     }
 }
 
-fn send_self_exit_message(
+/// Queues an exit signal for `process` and wakes it, so that the signal is delivered even when
+/// `process` is waiting in a `receive`.
+pub fn send_exit_signal(
     process: &Process,
-    heap: &mut ProcessHeap,
-    exit_message_elements: &[Term],
+    kind: ExitSignalKind,
+    from: Pid,
+    reason: Term,
+    trace: Arc<Trace>,
+    source: Option<ArcError>,
 ) {
-    let data = heap
-        .tuple_from_slice(exit_message_elements)
-        .unwrap()
-        .encode()
-        .unwrap();
+    let exit_signal = ExitSignal::new(kind, from, reason, trace, source).unwrap();
+    process.send_exit_signal(exit_signal);
 
-    process.send_from_self(data);
+    if let Some(scheduler) = process.scheduler() {
+        scheduler.stop_waiting(process);
+    }
 }
 
-fn send_heap_exit_message(process: &Process, exit_message_elements: &[Term]) {
-    let (layout, _) = Tuple::layout_for(exit_message_elements);
-    let mut heap_fragment = HeapFragment::new(layout).unwrap();
-    let heap_fragment_ref = unsafe { heap_fragment.as_mut() };
+/// Sends `message`, which a thread other than the schedulers built on `decoder`'s heap, to
+/// `destination` and wakes it.  `decoder`'s heap is then emptied, as nothing on it is live once the
+/// message is copied to `destination`.
+pub fn send_from_decoder(decoder: &Process, destination: &Process, message: Term) {
+    destination.send_from_other(message);
+    destination.scheduler().unwrap().stop_waiting(destination);
 
-    let ptr = heap_fragment_ref
-        .tuple_from_slice(exit_message_elements)
-        .unwrap();
-    process.send_heap_message(heap_fragment, ptr.into());
+    let _ = decoder.garbage_collect(0, RootSet::default());
 }
 
-fn exit_in_heap(
-    process: &Process,
-    heap: &mut ProcessHeap,
-    reason: Term,
-    exception: RuntimeException,
-) {
-    let data = reason.clone_to_heap(heap).unwrap();
+/// Sends an exit signal from `process` to `pid` on another node.  Like messages, exit signals to
+/// nodes that can't be reached are dropped.
+pub fn send_exit_signal_to_external_pid(process: &Process, pid: Boxed<ExternalPid>, reason: Term) {
+    let node = pid.arc_node().name();
+    let result = connection::get_or_connect(node).and_then(|arc_connection| {
+        arc_connection
+            .exit2(process, pid.encode().unwrap(), reason)
+            .map_err(From::from)
+    });
+
+    if let Err(error) = result {
+        log::warn!("Dropped exit signal to node ({}): {:?}", node, error);
+    }
+}
+
+/// Delivers the exit signals queued for `process`.
+///
+/// Schedulers call this before running `process`, so that it is never exited while running, and
+/// then don't run `process` if it is exiting.
+pub fn deliver_exit_signals(process: &Process) {
+    for exit_signal in process.take_exit_signals() {
+        // Any remaining signals are dropped like on the BEAM, as the process is already exiting
+        if process.is_exiting() {
+            break;
+        }
 
-    process.exit(data, exception.stacktrace(), exception.source());
+        deliver_exit_signal(process, exit_signal);
+    }
 }
 
-fn exit_in_heap_fragment(process: &Process, reason: Term, exception: RuntimeException) {
-    let (heap_fragment_data, mut heap_fragment) = reason.clone_to_fragment().unwrap();
+fn deliver_exit_signal(process: &Process, exit_signal: ExitSignal) {
+    let kind = exit_signal.kind;
+    let from = exit_signal.from;
+    let trace = exit_signal.trace.clone();
+    let source = exit_signal.source.clone();
+    let reason = exit_signal.attach_reason(process);
+
+    if kind == ExitSignalKind::Exit && is_kill_exit_reason(reason) {
+        // `kill` from `exit/2` can't be trapped
+        process.exit(atom!("killed"), trace, source);
+    } else if process.traps_exit() {
+        let exit_message =
+            process.tuple_from_slice(&[atom!("EXIT"), from.encode().unwrap(), reason]);
+        process.send_from_self(exit_message);
+    } else if !is_expected_exit_reason(reason) {
+        process.exit(reason, trace, source);
+    }
+    // `normal` exit signals are ignored by processes that don't trap exits
+}
 
-    process.attach_fragment(unsafe { heap_fragment.as_mut() });
-    process.exit(
-        heap_fragment_data,
-        exception.stacktrace(),
-        exception.source(),
-    );
+fn is_kill_exit_reason(reason: Term) -> bool {
+    match reason.decode().unwrap() {
+        TypedTerm::Atom(atom) => atom == "kill",
+        _ => false,
+    }
 }
 
 thread_local! {
@@ -1,3 +1,4 @@
+mod max_heap_size;
 mod message_queue_data;
 
 use std::convert::{TryFrom, TryInto};
@@ -5,7 +6,8 @@ use std::convert::{TryFrom, TryInto};
 use anyhow::*;
 
 use liblumen_alloc::erts::exception::Alloc;
-use liblumen_alloc::erts::process::alloc::{default_heap_size, heap, next_heap_size};
+use liblumen_alloc::erts::process::alloc::{heap, next_heap_size};
+use liblumen_alloc::erts::process::gc_defaults;
 use liblumen_alloc::erts::process::priority::Priority;
 use liblumen_alloc::erts::process::Process;
 use liblumen_alloc::erts::term::prelude::*;
@@ -13,7 +15,9 @@ use liblumen_alloc::ModuleFunctionArity;
 
 use crate::process;
 use crate::proplist::TryPropListFromTermError;
+use crate::trace;
 
+pub use max_heap_size::MaxHeapSize;
 use message_queue_data::*;
 
 #[must_use]
@@ -23,13 +27,6 @@ pub struct Connection {
     pub monitor_reference: Option<Term>,
 }
 
-#[derive(Clone, Copy, Debug)]
-pub struct MaxHeapSize {
-    size: Option<usize>,
-    kill: Option<bool>,
-    error_logger: Option<bool>,
-}
-
 #[derive(Clone, Copy, Debug)]
 pub struct Options {
     pub link: bool,
@@ -78,6 +75,24 @@ impl Options {
         }
     }
 
+    /// Applies the garbage collection options to `process`, which must not be scheduled yet, and
+    /// starts tracing it if new processes are traced.
+    pub fn configure(&self, process: &mut Process) {
+        trace::new_process(process);
+
+        if let Some(fullsweep_after) = self.fullsweep_after {
+            process.set_fullsweep_after(fullsweep_after);
+        }
+
+        if let Some(min_bin_vheap_size) = self.min_bin_vheap_size {
+            process.set_min_vheap_size(min_bin_vheap_size);
+        }
+
+        if let Some(max_heap_size) = self.max_heap_size {
+            process.set_max_heap_size(max_heap_size.cascaded(gc_defaults::max_heap_size()));
+        }
+    }
+
     pub fn sized_heap(&self) -> Result<(*mut Term, usize), Alloc> {
         let heap_size = self.heap_size();
         let heap = heap(self.heap_size())?;
@@ -104,13 +119,14 @@ impl Options {
         };
         let (heap, heap_size) = self.sized_heap()?;
 
-        let process = Process::new(
+        let mut process = Process::new(
             priority,
             parent_process,
             module_function_arity,
             heap,
             heap_size,
         );
+        self.configure(&mut process);
 
         Ok(process)
     }
@@ -121,7 +137,7 @@ impl Options {
     fn heap_size(&self) -> usize {
         match self.min_heap_size {
             Some(min_heap_size) => next_heap_size(min_heap_size),
-            None => default_heap_size(),
+            None => gc_defaults::min_heap_size(),
         }
     }
 
@@ -162,7 +178,12 @@ impl Options {
 
                     Ok(self)
                 }
-                "max_heap_size" => unimplemented!(),
+                "max_heap_size" => {
+                    let max_heap_size = tuple[1].try_into().context("max_heap_size")?;
+                    self.max_heap_size = Some(max_heap_size);
+
+                    Ok(self)
+                }
                 "message_queue_data" => {
                     let message_queue_data = tuple[1].try_into().context("message_queue_data")?;
                     self.message_queue_data = message_queue_data;
@@ -212,7 +233,8 @@ impl Default for Options {
 
 const SUPPORTED_OPTIONS_CONTEXT: &str = "supported options are :link, :monitor, \
      {:fullsweep_after, generational_collections :: pos_integer()}, \
-     {:max_heap_size, words :: pos_integer()}, \
+     {:max_heap_size, words :: non_neg_integer() | \
+     %{size: words, kill: boolean(), error_logger: boolean()}}, \
      {:message_queue_data, :off_heap | :on_heap}, \
      {:min_bin_vheap_size, words :: pos_integer()}, \
      {:min_heap_size, words :: pos_integer()}, and \
@@ -0,0 +1,69 @@
+use std::convert::{TryFrom, TryInto};
+
+use anyhow::*;
+
+use liblumen_alloc::erts::process;
+use liblumen_alloc::erts::term::prelude::*;
+
+const SUPPORTED_CONTEXT: &str = "max_heap_size must be words :: non_neg_integer() or \
+     #{size => words :: non_neg_integer(), kill => boolean(), error_logger => boolean()}";
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MaxHeapSize {
+    /// `0` means the heap size is unbounded
+    pub size: Option<usize>,
+    pub kill: Option<bool>,
+    pub error_logger: Option<bool>,
+}
+
+impl MaxHeapSize {
+    /// Uses `default` for any option that isn't set, like the BEAM does with the runtime-wide
+    /// `max_heap_size`
+    pub fn cascaded(&self, default: process::MaxHeapSize) -> process::MaxHeapSize {
+        process::MaxHeapSize {
+            size: self.size.unwrap_or(default.size),
+            kill: self.kill.unwrap_or(default.kill),
+            error_logger: self.error_logger.unwrap_or(default.error_logger),
+        }
+    }
+}
+
+impl TryFrom<Term> for MaxHeapSize {
+    type Error = anyhow::Error;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        match term.decode().unwrap() {
+            TypedTerm::Map(map) => {
+                let size = match map.get(Atom::str_to_term(process::MaxHeapSize::SIZE_KEY)) {
+                    Some(size_term) => Some(size_term.try_into().context(SUPPORTED_CONTEXT)?),
+                    None => None,
+                };
+                let kill = match map.get(Atom::str_to_term(process::MaxHeapSize::KILL_KEY)) {
+                    Some(kill_term) => Some(kill_term.try_into().context(SUPPORTED_CONTEXT)?),
+                    None => None,
+                };
+                let error_logger =
+                    match map.get(Atom::str_to_term(process::MaxHeapSize::ERROR_LOGGER_KEY)) {
+                        Some(error_logger_term) => {
+                            Some(error_logger_term.try_into().context(SUPPORTED_CONTEXT)?)
+                        }
+                        None => None,
+                    };
+
+                Ok(Self {
+                    size,
+                    kill,
+                    error_logger,
+                })
+            }
+            _ => {
+                let size: usize = term.try_into().context(SUPPORTED_CONTEXT)?;
+
+                Ok(Self {
+                    size: Some(size),
+                    ..Default::default()
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Opt-in profiling of processes, enabled with `--profile=processes`.
+//!
+//! While enabled, schedulers record the reductions, garbage collection time and scheduled time of
+//! each process they run, and their own time running processes and collecting garbage for
+//! `erlang:statistics(microstate_accounting)`.  Processes that have exited are kept, so the report
+//! written when the runtime shuts down covers every process that ran.
+//!
+//! Only collections done by the scheduler when a process runs out of heap are timed as garbage
+//! collection; those done by `erlang:garbage_collect` are part of the process's scheduled time.
+
+use std::fmt::Write;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use dashmap::DashMap;
+use lazy_static::lazy_static;
+use thiserror::Error;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+
+use crate::sys::io::put_error_chars;
+
+/// What `--profile` profiles
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Processes,
+}
+
+impl FromStr for Mode {
+    type Err = ParseModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "processes" => Ok(Mode::Processes),
+            _ => Err(ParseModeError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+#[error("{0} is not a profile mode, which is only processes")]
+pub struct ParseModeError(String);
+
+/// The cumulative counters of a process
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProcessProfile {
+    /// The registered name of the process, or its initial call if it was never registered
+    pub name: String,
+    pub reductions: u64,
+    pub garbage_collection_microseconds: u64,
+    pub scheduled_microseconds: u64,
+}
+
+pub fn enable(mode: Mode) {
+    match mode {
+        Mode::Processes => ENABLED.store(true, Ordering::SeqCst),
+    }
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// The time since the runtime started, in microseconds, for timing runs and collections
+pub fn microseconds() -> u64 {
+    elapsed_microseconds()
+}
+
+pub fn garbage_collection_microseconds(pid: &Pid) -> u64 {
+    PROCESS_PROFILE_BY_PID
+        .get(pid)
+        .map_or(0, |process_profile| {
+            process_profile.garbage_collection_microseconds
+        })
+}
+
+/// Runs `garbage_collect` for `process`, timing it if profiling is enabled
+pub fn time_garbage_collection<T>(process: &Process, garbage_collect: impl FnOnce() -> T) -> T {
+    if is_enabled() {
+        let start = microseconds();
+        let t = garbage_collect();
+        let elapsed = microseconds().saturating_sub(start);
+
+        with_process_profile(process, |process_profile| {
+            process_profile.garbage_collection_microseconds += elapsed
+        });
+
+        t
+    } else {
+        garbage_collect()
+    }
+}
+
+/// Counts a run of `process` that took `scheduled_microseconds`, including any garbage collection
+pub fn record_run(process: &Process, reductions: u64, scheduled_microseconds: u64) {
+    with_process_profile(process, |process_profile| {
+        process_profile.reductions += reductions;
+        process_profile.scheduled_microseconds += scheduled_microseconds;
+    });
+}
+
+/// The profiles of all processes that have run, with the most reductions first
+pub fn process_profiles() -> Vec<(Pid, ProcessProfile)> {
+    let mut process_profile_vec: Vec<(Pid, ProcessProfile)> = PROCESS_PROFILE_BY_PID
+        .iter()
+        .map(|entry| (*entry.key(), entry.value().clone()))
+        .collect();
+    process_profile_vec.sort_by(|(left_pid, left), (right_pid, right)| {
+        right
+            .reductions
+            .cmp(&left.reductions)
+            .then(left_pid.cmp(right_pid))
+    });
+
+    process_profile_vec
+}
+
+/// The table of `process_profiles`
+pub fn report() -> String {
+    let mut report = String::new();
+    writeln!(
+        &mut report,
+        "{:<16} {:<32} {:>12} {:>12} {:>16}",
+        "Pid", "Name", "Reductions", "GC (us)", "Scheduled (us)"
+    )
+    .unwrap();
+
+    for (pid, process_profile) in process_profiles() {
+        writeln!(
+            &mut report,
+            "{:<16} {:<32} {:>12} {:>12} {:>16}",
+            pid.to_string(),
+            process_profile.name,
+            process_profile.reductions,
+            process_profile.garbage_collection_microseconds,
+            process_profile.scheduled_microseconds
+        )
+        .unwrap();
+    }
+
+    report
+}
+
+/// Writes the `report` to stderr if profiling is enabled, such as when the runtime shuts down
+pub fn write_report() {
+    if is_enabled() {
+        put_error_chars(&report());
+    }
+}
+
+// Private
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref PROCESS_PROFILE_BY_PID: DashMap<Pid, ProcessProfile> = Default::default();
+}
+
+cfg_if::cfg_if! {
+  if #[cfg(target_arch = "wasm32")] {
+     /// Only milliseconds are available from the host
+     fn elapsed_microseconds() -> u64 {
+         crate::time::monotonic::time().0 * 1_000
+     }
+  } else {
+     use std::time::Instant;
+
+     fn elapsed_microseconds() -> u64 {
+         START.elapsed().as_micros() as u64
+     }
+
+     lazy_static! {
+         static ref START: Instant = Instant::now();
+     }
+  }
+}
+
+fn name(process: &Process) -> Option<String> {
+    (*process.registered_name.read()).map(|registered_name| registered_name.name().to_string())
+}
+
+fn with_process_profile(process: &Process, f: impl FnOnce(&mut ProcessProfile)) {
+    let mut process_profile = PROCESS_PROFILE_BY_PID
+        .entry(process.pid())
+        .or_insert_with(|| ProcessProfile {
+            name: process.initial_module_function_arity.to_string(),
+            ..Default::default()
+        });
+
+    // Processes are often registered after they start running
+    if let Some(name) = name(process) {
+        process_profile.name = name;
+    }
+
+    f(&mut process_profile);
+}
@@ -1,6 +1,7 @@
 /// Maps registered names (`Atom`) to `LocalPid` or `Port`
 use std::sync::{Arc, Weak};
 
+use dashmap::mapref::entry::Entry;
 use dashmap::DashMap;
 use lazy_static::lazy_static;
 
@@ -51,21 +52,37 @@ pub fn pid_to_self_or_process(pid: Pid, process_arc: &Arc<Process>) -> Option<Ar
     }
 }
 
+/// The processes that currently exist.
+pub fn processes() -> Vec<Arc<Process>> {
+    WEAK_PROCESS_CONTROL_BLOCK_BY_PID
+        .iter()
+        .filter_map(|entry| entry.value().upgrade())
+        .collect()
+}
+
+/// The number of processes that currently exist.
+pub fn process_count() -> usize {
+    WEAK_PROCESS_CONTROL_BLOCK_BY_PID.len()
+}
+
 pub fn put_atom_to_process(name: Atom, arc_process: Arc<Process>) -> bool {
-    if !REGISTERED_BY_NAME.contains_key(&name) {
-        register_in(arc_process, name)
-    } else {
-        false
-    }
+    register_in(arc_process, name)
 }
 
 pub fn register_in(arc_process: Arc<Process>, name: Atom) -> bool {
     let mut writable_registered_name = arc_process.registered_name.write();
 
     if let None = *writable_registered_name {
-        REGISTERED_BY_NAME.insert(name, Registered::Process(Arc::downgrade(&arc_process)));
-        *writable_registered_name = Some(name);
-        true
+        // `entry` holds the shard lock, so two processes can't both claim `name`
+        match REGISTERED_BY_NAME.entry(name) {
+            Entry::Occupied(_) => false,
+            Entry::Vacant(vacant) => {
+                vacant.insert(Registered::Process(Arc::downgrade(&arc_process)));
+                *writable_registered_name = Some(name);
+
+                true
+            }
+        }
     } else {
         false
     }
@@ -94,6 +111,16 @@ pub fn unregister(name: &Atom) -> bool {
     }
 }
 
+/// Removes the exiting `process` from the registry, so that its registered name can be reused
+/// and its pid no longer resolves to it.
+pub fn remove_process(process: &Process) {
+    if let Some(name) = process.registered_name.write().take() {
+        REGISTERED_BY_NAME.remove(&name);
+    }
+
+    WEAK_PROCESS_CONTROL_BLOCK_BY_PID.remove(&process.pid());
+}
+
 #[cfg_attr(test, derive(Debug))]
 pub enum Registered {
     Process(Weak<Process>),
@@ -1,8 +1,10 @@
+pub mod idle;
 pub mod run_queue;
+pub mod statistics;
 
 use std::any::Any;
 use std::fmt::Debug;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Weak};
 
 use hashbrown::HashMap;
@@ -19,6 +21,9 @@ use liblumen_alloc::Priority;
 use crate::process::spawn::options::{Connection, Options};
 use crate::timer::Hierarchy;
 
+use self::idle::Idle;
+use self::statistics::Statistics;
+
 extern "Rust" {
     /// Creates an concrete `impl` of `dyn Scheduler` that hasn't yet been registered with an ID.
     #[link_name = "lumen_rt_scheduler_unregistered"]
@@ -29,6 +34,51 @@ pub fn current() -> Arc<dyn Scheduler> {
     SCHEDULER.with(|thread_local_scheduler| thread_local_scheduler.clone())
 }
 
+/// The number of schedulers the runtime was started with.
+pub fn count() -> usize {
+    COUNT.load(Ordering::Relaxed)
+}
+
+/// Sets the number of schedulers the runtime was started with.  Should only be called during
+/// runtime startup.
+pub fn set_count(count: usize) {
+    assert!(0 < count, "There must be at least 1 scheduler");
+
+    COUNT.store(count, Ordering::Relaxed)
+}
+
+/// Returns the schedulers registered on threads other than the current thread, so that an idle
+/// scheduler can steal processes from them.
+pub fn others() -> Vec<Arc<dyn Scheduler>> {
+    // Get the current ID before locking, as getting the current scheduler for the first time
+    // registers it
+    let current_id = current().id();
+
+    SCHEDULER_BY_ID
+        .lock()
+        .iter()
+        .filter(|(id, _)| **id != current_id)
+        .filter_map(|(_, weak_scheduler)| weak_scheduler.upgrade())
+        .collect()
+}
+
+/// Where scheduler threads with nothing to run park until a process may be runnable on any
+/// scheduler.
+pub fn idle() -> &'static Idle {
+    &IDLE
+}
+
+/// Returns all registered schedulers, including the current one, in ID order.
+pub fn all() -> Vec<Arc<dyn Scheduler>> {
+    // Getting the current scheduler for the first time registers it
+    let current_arc_scheduler = current();
+    let mut arc_schedulers = others();
+    arc_schedulers.push(current_arc_scheduler);
+    arc_schedulers.sort_by_key(|arc_scheduler| arc_scheduler.id());
+
+    arc_schedulers
+}
+
 fn current_from_id(id: &ID) -> Option<Arc<dyn Scheduler>> {
     SCHEDULER.with(|thread_local_scheduler| {
         if &thread_local_scheduler.id() == id {
@@ -195,6 +245,13 @@ pub trait Scheduler: Debug + Send + Sync {
         options: Options,
     ) -> anyhow::Result<Spawned>;
     fn shutdown(&self) -> anyhow::Result<()>;
+    /// Counters of the work this scheduler has done, for `erlang:statistics/1`
+    fn statistics(&self) -> &Statistics;
+    /// Removes a runnable process from this scheduler's run queues, so that it can be migrated to
+    /// an idle scheduler.  Returns `None` if there is no process that can be stolen.
+    fn steal(&self) -> Option<Arc<Process>> {
+        None
+    }
     fn stop_waiting(&self, process: &Process);
 }
 
@@ -203,12 +260,15 @@ pub trait SchedulerDependentAlloc {
 }
 
 impl SchedulerDependentAlloc for Process {
+    /// References are unique for the lifetime of the node because scheduler IDs are never reused
+    /// and each scheduler only hands out each of its reference numbers once.  If the process's
+    /// scheduler has stopped, such as after the process was stolen by another scheduler, the
+    /// current scheduler's numbers are used instead.
     fn next_reference(&self) -> Term {
-        let scheduler_id = self.scheduler_id().unwrap();
-        let arc_scheduler = from_id(&scheduler_id).unwrap();
+        let arc_scheduler = self.scheduler().unwrap_or_else(current);
         let number = arc_scheduler.next_reference_number();
 
-        self.reference_from_scheduler(scheduler_id, number)
+        self.reference_from_scheduler(arc_scheduler.id(), number)
     }
 }
 
@@ -233,7 +293,10 @@ thread_local! {
   static SCHEDULER: Arc<dyn Scheduler> = registered();
 }
 
+static COUNT: AtomicUsize = AtomicUsize::new(1);
+
 lazy_static! {
+    static ref IDLE: Idle = Default::default();
     static ref RW_LOCK_OPTION_UNREGISTERED: RwLock<Option<Box<dyn Fn() -> Arc<dyn Scheduler> + 'static + Sync + Send>>> =
         RwLock::new(None);
     static ref SCHEDULER_BY_ID: Mutex<HashMap<ID, Weak<dyn Scheduler>>> =
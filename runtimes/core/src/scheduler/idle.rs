@@ -0,0 +1,116 @@
+//! Lets scheduler threads with nothing to run sleep until a process may be runnable, instead of
+//! spinning.
+//!
+//! A scheduler takes a `Generation` before it looks for a process to run and parks with it only if
+//! it found none, so that a `wake` in between makes `park` return at once instead of being lost.
+
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Generation(u64);
+
+#[derive(Debug, Default)]
+pub struct Idle {
+    generation: AtomicU64,
+    /// Lets `wake` skip locking `mutex` when no scheduler is parked, as it is called for every
+    /// process that becomes runnable.
+    parked: AtomicUsize,
+    mutex: Mutex<()>,
+    condvar: Condvar,
+}
+
+impl Idle {
+    pub fn generation(&self) -> Generation {
+        Generation(self.generation.load(Ordering::SeqCst))
+    }
+
+    /// Parks the current thread until `wake` is called or `timeout` passes.  Returns at once if
+    /// `wake` was called after `generation` was taken.  Returns `true` if woken, `false` if timed
+    /// out.
+    pub fn park(&self, generation: Generation, timeout: Option<Duration>) -> bool {
+        self.parked.fetch_add(1, Ordering::SeqCst);
+
+        let is_unchanged = |_: &mut ()| self.generation.load(Ordering::SeqCst) == generation.0;
+        let guard = self.mutex.lock().unwrap();
+
+        let woken = match timeout {
+            Some(timeout) => {
+                let (_guard, wait_timeout_result) = self
+                    .condvar
+                    .wait_timeout_while(guard, timeout, is_unchanged)
+                    .unwrap();
+
+                !wait_timeout_result.timed_out()
+            }
+            None => {
+                let _guard = self.condvar.wait_while(guard, is_unchanged).unwrap();
+
+                true
+            }
+        };
+
+        self.parked.fetch_sub(1, Ordering::SeqCst);
+
+        woken
+    }
+
+    /// Wakes all parked schedulers, as a process became runnable on one of them and the others
+    /// may be able to steal it.
+    pub fn wake(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+
+        // A scheduler that incremented `parked` after this load will see the new generation
+        // before it waits
+        if 0 < self.parked.load(Ordering::SeqCst) {
+            let _guard = self.mutex.lock().unwrap();
+            self.condvar.notify_all();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Instant;
+
+    #[test]
+    fn park_after_wake_returns_at_once() {
+        let idle = Idle::default();
+        let generation = idle.generation();
+
+        idle.wake();
+
+        assert!(idle.park(generation, None));
+    }
+
+    #[test]
+    fn park_without_wake_times_out() {
+        let idle = Idle::default();
+        let timeout = Duration::from_millis(10);
+        let start = Instant::now();
+
+        assert!(!idle.park(idle.generation(), Some(timeout)));
+        assert!(timeout <= start.elapsed());
+    }
+
+    #[test]
+    fn wake_from_other_thread_unparks() {
+        let idle = Arc::new(Idle::default());
+        let generation = idle.generation();
+        let parking_idle = idle.clone();
+        let parked = thread::spawn(move || parking_idle.park(generation, None));
+
+        while idle.parked.load(Ordering::SeqCst) == 0 {
+            thread::yield_now();
+        }
+
+        idle.wake();
+
+        assert!(parked.join().unwrap());
+    }
+}
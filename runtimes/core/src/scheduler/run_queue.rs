@@ -77,6 +77,15 @@ impl Queues {
         }
     }
 
+    /// Removes the runnable process that would otherwise be run last, so that it can be migrated
+    /// to another scheduler.  Waiting processes are never stolen.
+    pub fn steal(&mut self) -> Option<Arc<Process>> {
+        self.max
+            .steal()
+            .or_else(|| self.high.steal())
+            .or_else(|| self.normal_low.steal())
+    }
+
     pub fn stop_waiting(&mut self, process: &Process) {
         match self.waiting.get(process) {
             Some(arc_process) => {
@@ -182,6 +191,10 @@ impl Immediate {
     pub fn enqueue(&mut self, process: Arc<Process>) {
         self.0.push_back(process);
     }
+
+    pub fn steal(&mut self) -> Option<Arc<Process>> {
+        self.0.pop_back()
+    }
 }
 
 /// A run queue where the `Arc<Process` is run only when its delay is `0`.  This allows
@@ -221,6 +234,12 @@ impl Delayed {
         let delayed_process = DelayedProcess::new(arc_process);
         self.0.push_back(delayed_process);
     }
+
+    pub fn steal(&mut self) -> Option<Arc<Process>> {
+        self.0
+            .pop_back()
+            .map(|delayed_process| delayed_process.arc_process)
+    }
 }
 
 type Delay = u8;
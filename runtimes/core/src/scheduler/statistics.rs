@@ -0,0 +1,154 @@
+//! Counters of the work each scheduler has done, for `erlang:statistics/1`.
+//!
+//! Each run of a process is counted by the scheduler that ran it, so work stays with that
+//! scheduler even if the process is later stolen by another.  When profiling is enabled, runs are
+//! also timed, for microstate accounting and the profile of each process.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use liblumen_alloc::erts::process::Process;
+
+use crate::profile;
+
+use super::all;
+
+#[derive(Debug, Default)]
+pub struct Statistics {
+    reductions: AtomicU64,
+    context_switches: AtomicU64,
+    garbage_collections: AtomicU64,
+    words_reclaimed: AtomicU64,
+    emulator_microseconds: AtomicU64,
+    garbage_collection_microseconds: AtomicU64,
+}
+
+impl Statistics {
+    /// Counts a run of `process`, given its counts from before it was switched in
+    pub fn record_run(&self, process: &Process, before: ProcessCounts) {
+        let after = ProcessCounts::of(process);
+
+        self.context_switches.fetch_add(1, Ordering::Relaxed);
+        self.reductions.fetch_add(
+            after.reductions.saturating_sub(before.reductions),
+            Ordering::Relaxed,
+        );
+        self.garbage_collections.fetch_add(
+            after
+                .garbage_collections
+                .saturating_sub(before.garbage_collections),
+            Ordering::Relaxed,
+        );
+        self.words_reclaimed.fetch_add(
+            after.words_reclaimed.saturating_sub(before.words_reclaimed),
+            Ordering::Relaxed,
+        );
+
+        if let Some(before_microseconds) = before.microseconds {
+            let scheduled_microseconds =
+                profile::microseconds().saturating_sub(before_microseconds);
+            let garbage_collection_microseconds =
+                profile::garbage_collection_microseconds(&process.pid())
+                    .saturating_sub(before.garbage_collection_microseconds);
+
+            self.emulator_microseconds.fetch_add(
+                scheduled_microseconds.saturating_sub(garbage_collection_microseconds),
+                Ordering::Relaxed,
+            );
+            self.garbage_collection_microseconds
+                .fetch_add(garbage_collection_microseconds, Ordering::Relaxed);
+
+            profile::record_run(
+                process,
+                after.reductions.saturating_sub(before.reductions),
+                scheduled_microseconds,
+            );
+        }
+    }
+
+    /// The time spent in each state, which is only counted while profiling is enabled
+    pub fn microstates(&self) -> Microstates {
+        Microstates {
+            emulator_microseconds: self.emulator_microseconds.load(Ordering::Relaxed),
+            garbage_collection_microseconds: self
+                .garbage_collection_microseconds
+                .load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn totals(&self) -> Totals {
+        Totals {
+            reductions: self.reductions.load(Ordering::Relaxed),
+            context_switches: self.context_switches.load(Ordering::Relaxed),
+            garbage_collections: self.garbage_collections.load(Ordering::Relaxed),
+            words_reclaimed: self.words_reclaimed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// The time a scheduler has spent running processes (`emulator`) and collecting their garbage
+/// (`gc`), in microseconds
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Microstates {
+    pub emulator_microseconds: u64,
+    pub garbage_collection_microseconds: u64,
+}
+
+/// The counters of a process when a scheduler switches it in
+#[derive(Clone, Copy, Debug)]
+pub struct ProcessCounts {
+    reductions: u64,
+    garbage_collections: u64,
+    words_reclaimed: u64,
+    /// When the process was switched in, if profiling is enabled
+    microseconds: Option<u64>,
+    garbage_collection_microseconds: u64,
+}
+
+impl ProcessCounts {
+    pub fn of(process: &Process) -> Self {
+        let (microseconds, garbage_collection_microseconds) = if profile::is_enabled() {
+            (
+                Some(profile::microseconds()),
+                profile::garbage_collection_microseconds(&process.pid()),
+            )
+        } else {
+            (None, 0)
+        };
+
+        Self {
+            reductions: process.total_reductions.load(Ordering::SeqCst),
+            garbage_collections: process.total_garbage_collections.load(Ordering::SeqCst),
+            words_reclaimed: process.total_words_reclaimed.load(Ordering::SeqCst),
+            microseconds,
+            garbage_collection_microseconds,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Totals {
+    pub reductions: u64,
+    pub context_switches: u64,
+    pub garbage_collections: u64,
+    pub words_reclaimed: u64,
+}
+
+impl std::ops::Add for Totals {
+    type Output = Totals;
+
+    fn add(self, other: Totals) -> Totals {
+        Totals {
+            reductions: self.reductions + other.reductions,
+            context_switches: self.context_switches + other.context_switches,
+            garbage_collections: self.garbage_collections + other.garbage_collections,
+            words_reclaimed: self.words_reclaimed + other.words_reclaimed,
+        }
+    }
+}
+
+/// The totals of all schedulers
+pub fn totals() -> Totals {
+    all().iter().fold(Default::default(), |acc, scheduler| {
+        acc + scheduler.statistics().totals()
+    })
+}
@@ -1,6 +1,7 @@
 mod options;
 
 use std::convert::TryInto;
+use std::io;
 
 use anyhow::*;
 
@@ -8,9 +9,11 @@ use liblumen_alloc::erts::exception::InternalResult;
 use liblumen_alloc::term::prelude::*;
 use liblumen_alloc::Process;
 
+use crate::distribution::connection::{self, Connection};
 use crate::distribution::nodes::node;
 use crate::registry::{self, pid_to_process};
 use crate::scheduler::Scheduled;
+use crate::trace;
 
 pub use options::*;
 
@@ -20,6 +23,8 @@ pub fn send(
     options: Options,
     process: &Process,
 ) -> InternalResult<Sent> {
+    trace::send(process, message, destination);
+
     match destination.decode()? {
         TypedTerm::Atom(destination_atom) => {
             send_to_name(destination_atom, message, options, process)
@@ -37,17 +42,12 @@ pub fn send(
                     )
                 })?;
 
-                match node_atom.name() {
-                    node::DEAD_ATOM_NAME => send_to_name(name_atom, message, options, process),
-                    _ => {
-                        if !options.connect {
-                            Ok(Sent::ConnectRequired)
-                        } else if !options.suspend {
-                            Ok(Sent::SuspendRequired)
-                        } else {
-                            unimplemented!("distribution")
-                        }
-                    }
+                if node_atom == node::atom() {
+                    send_to_name(name_atom, message, options, process)
+                } else {
+                    Ok(send_to_node(node_atom, options, |connection| {
+                        connection.reg_send(process, name_atom, message)
+                    }))
                 }
             } else {
                 Err(anyhow!("destination ({}) is a tuple, but not 2-arity", destination).into())
@@ -55,12 +55,14 @@ pub fn send(
         }
         TypedTerm::Pid(destination_pid) => {
             if destination_pid == process.pid() {
+                trace::receive(process, process, message);
                 process.send_from_self(message);
 
                 Ok(Sent::Sent)
             } else {
                 match pid_to_process(&destination_pid) {
                     Some(destination_arc_process) => {
+                        trace::receive(process, &destination_arc_process, message);
                         destination_arc_process.send_from_other(message);
                         destination_arc_process
                             .scheduler()
@@ -73,6 +75,11 @@ pub fn send(
                 }
             }
         }
+        TypedTerm::ExternalPid(external_pid) => Ok(send_to_node(
+            external_pid.arc_node().name(),
+            options,
+            |connection| connection.send(process, destination, message),
+        )),
         _ => Err(TypeError)
             .context(format!(
                 "destination ({}) is not registered_name (atom), {{registered_name, node}}, or pid",
@@ -90,6 +97,27 @@ pub enum Sent {
 
 // Private
 
+/// Like BEAM, messages to nodes that can't be reached are dropped instead of raising an error.
+fn send_to_node<F>(node: Atom, options: Options, send: F) -> Sent
+where
+    F: FnOnce(&Connection) -> io::Result<()>,
+{
+    let result = match connection::get(&node) {
+        Some(arc_connection) => Ok(arc_connection),
+        None if !options.connect => return Sent::ConnectRequired,
+        // Connecting blocks the sender until the handshake completes
+        None if !options.suspend => return Sent::SuspendRequired,
+        None => connection::connect(node),
+    };
+
+    if let Err(error) = result.and_then(|arc_connection| send(&arc_connection).map_err(From::from))
+    {
+        log::warn!("Dropped message to node ({}): {:?}", node, error);
+    }
+
+    Sent::Sent
+}
+
 // `options` will only be used once ports are supported
 fn send_to_name(
     destination: Atom,
@@ -98,12 +126,14 @@ fn send_to_name(
     process: &Process,
 ) -> InternalResult<Sent> {
     if *process.registered_name.read() == Some(destination) {
+        trace::receive(process, process, message);
         process.send_from_self(message);
 
         Ok(Sent::Sent)
     } else {
         match registry::atom_to_process(&destination) {
             Some(destination_arc_process) => {
+                trace::receive(process, &destination_arc_process, message);
                 destination_arc_process.send_from_other(message);
                 destination_arc_process
                     .scheduler()
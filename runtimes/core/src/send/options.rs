@@ -8,9 +8,9 @@ use crate::proplist::TryPropListFromTermError;
 
 pub struct Options {
     // Send only suspends for some sends to ports and for remote (`ExternalPid` or
-    // `{name, remote_node}`) sends, so it does not apply at this time.
+    // `{name, remote_node}`) sends.  Remote sends only suspend while connecting to the node.
     pub suspend: bool,
-    // Connect only applies when there is distribution, which isn't implemented yet.
+    // Whether remote sends may connect to a node that isn't connected yet.
     pub connect: bool,
 }
 
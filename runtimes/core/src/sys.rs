@@ -1 +1,4 @@
+pub mod cpu_time;
+pub mod cpus;
+pub mod entropy;
 pub mod io;
@@ -0,0 +1,27 @@
+//! The CPU time used by the runtime, for `erlang:statistics(runtime)`.
+
+cfg_if::cfg_if! {
+  if #[cfg(unix)] {
+     use std::mem::MaybeUninit;
+
+     /// The user and system CPU time used by all threads, in milliseconds.
+     pub fn milliseconds() -> u64 {
+         let mut usage = MaybeUninit::<libc::rusage>::uninit();
+         let result = unsafe { libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) };
+         assert_eq!(result, 0, "getrusage(RUSAGE_SELF) should not fail");
+         let usage = unsafe { usage.assume_init() };
+
+         timeval_to_milliseconds(usage.ru_utime) + timeval_to_milliseconds(usage.ru_stime)
+     }
+
+     fn timeval_to_milliseconds(timeval: libc::timeval) -> u64 {
+         (timeval.tv_sec as u64) * 1_000 + (timeval.tv_usec as u64) / 1_000
+     }
+  } else {
+     /// There is no CPU time from the host, so the time since the runtime started is used, which
+     /// is close for wasm32, where there is only the one thread.
+     pub fn milliseconds() -> u64 {
+         crate::time::monotonic::time().0
+     }
+  }
+}
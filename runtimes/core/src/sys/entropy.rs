@@ -0,0 +1,24 @@
+//! Entropy from the host for seeding pseudo-random number generators.
+
+cfg_if::cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", target_os = "wasi"))] {
+     /// Fills `bytes` with entropy from the WASI host's `random_get` import.
+     pub fn fill_bytes(bytes: &mut [u8]) {
+         unsafe { wasi::random_get(bytes.as_mut_ptr(), bytes.len()) }
+             .expect("WASI host should provide random_get");
+     }
+  } else {
+     /// Fills `bytes` with entropy from the host.
+     pub fn fill_bytes(bytes: &mut [u8]) {
+         getrandom::getrandom(bytes).expect("host should provide entropy");
+     }
+  }
+}
+
+/// A `u64` of entropy from the host.
+pub fn u64() -> u64 {
+    let mut bytes = [0; 8];
+    fill_bytes(&mut bytes);
+
+    u64::from_ne_bytes(bytes)
+}
@@ -1,19 +1,15 @@
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen::prelude::*;
+cfg_if::cfg_if! {
+  if #[cfg(all(target_arch = "wasm32", target_os = "wasi"))] {
+     mod wasi;
 
-#[cfg(target_arch = "wasm32")]
-#[wasm_bindgen]
-extern "C" {
-    #[wasm_bindgen(js_namespace = console, js_name = log)]
-    pub fn console_log(s: &str);
-}
+     pub use self::wasi::*;
+  } else if #[cfg(target_arch = "wasm32")] {
+     mod web_sys;
 
-#[cfg(not(target_arch = "wasm32"))]
-pub fn puts(s: &str) {
-    println!("{}", s);
-}
+     pub use self::web_sys::*;
+  } else {
+     mod std;
 
-#[cfg(target_arch = "wasm32")]
-pub fn puts(s: &str) {
-    console_log(s);
+     pub use self::std::*;
+  }
 }
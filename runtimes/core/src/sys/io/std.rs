@@ -0,0 +1,22 @@
+use std::io::{self, Write};
+
+pub fn puts(s: &str) {
+    println!("{}", s);
+}
+
+/// Writes `s` to stdout as is, so unlike `puts` no newline is added.
+pub fn put_chars(s: &str) {
+    let stdout = io::stdout();
+    let mut handle = stdout.lock();
+    // Nowhere left to report the failure
+    let _ = handle.write_all(s.as_bytes());
+    let _ = handle.flush();
+}
+
+/// Like `put_chars`, but to stderr.
+pub fn put_error_chars(s: &str) {
+    let stderr = io::stderr();
+    let mut handle = stderr.lock();
+    let _ = handle.write_all(s.as_bytes());
+    let _ = handle.flush();
+}
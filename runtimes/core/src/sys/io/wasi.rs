@@ -0,0 +1,37 @@
+use wasi::{Ciovec, Fd};
+
+const STDOUT: Fd = 1;
+const STDERR: Fd = 2;
+
+pub fn puts(s: &str) {
+    fd_write_all(STDOUT, s.as_bytes());
+    fd_write_all(STDOUT, b"\n");
+}
+
+/// Writes `s` to stdout as is, so unlike `puts` no newline is added.
+pub fn put_chars(s: &str) {
+    fd_write_all(STDOUT, s.as_bytes());
+}
+
+/// Like `put_chars`, but to stderr.
+pub fn put_error_chars(s: &str) {
+    fd_write_all(STDERR, s.as_bytes());
+}
+
+/// `fd_write` may write fewer bytes than requested, so keep writing until the host has accepted
+/// all of `bytes`.
+fn fd_write_all(fd: Fd, mut bytes: &[u8]) {
+    while !bytes.is_empty() {
+        let ciovec = Ciovec {
+            buf: bytes.as_ptr(),
+            buf_len: bytes.len(),
+        };
+
+        match unsafe { wasi::fd_write(fd, &[ciovec]) } {
+            Ok(0) => break,
+            Ok(written) => bytes = &bytes[written..],
+            // Nowhere left to report the failure, so drop the output like `console.log` would
+            Err(_) => break,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::cell::RefCell;
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_namespace = console, js_name = log)]
+    pub fn console_log(s: &str);
+
+    #[wasm_bindgen(js_namespace = console, js_name = error)]
+    pub fn console_error(s: &str);
+}
+
+thread_local! {
+    // The console only logs whole lines, so partial lines are held until their newline is written
+    static STDOUT_LINE: RefCell<String> = RefCell::new(String::new());
+    static STDERR_LINE: RefCell<String> = RefCell::new(String::new());
+}
+
+pub fn puts(s: &str) {
+    console_log(s);
+}
+
+/// Writes `s` to the console log as is, so unlike `puts` no newline is added.
+pub fn put_chars(s: &str) {
+    STDOUT_LINE.with(|line| log_lines(&mut line.borrow_mut(), s, console_log));
+}
+
+/// Like `put_chars`, but to the console error log.
+pub fn put_error_chars(s: &str) {
+    STDERR_LINE.with(|line| log_lines(&mut line.borrow_mut(), s, console_error));
+}
+
+fn log_lines(line: &mut String, s: &str, log: fn(&str)) {
+    line.push_str(s);
+
+    while let Some(index) = line.find('\n') {
+        log(&line[..index]);
+        line.drain(..=index);
+    }
+}
@@ -0,0 +1,311 @@
+//! Runs Rust futures for native code and sends their outputs to processes as messages, so that
+//! file, network and timer native functions can wait without blocking a scheduler.
+//!
+//! A native function spawns a future with `spawn`, naming the process that should receive its
+//! output, and returns.  The process then receives the message like any other, so it can wait for
+//! it with a `receive` that has a timeout or match it along with other messages.
+//!
+//! Native functions that return the output instead, like those of `file` do with `dirty_io`, spawn
+//! the future with `spawn_output`.
+//!
+//! Futures are polled on a small pool of threads, so they must not block: blocking calls are
+//! wrapped in `blocking`.  WebAssembly hosts can't spawn threads, so there, futures are polled on
+//! the thread that spawns or wakes them, and `blocking` runs its job before returning.
+
+#[cfg(target_arch = "wasm32")]
+use std::cell::RefCell;
+#[cfg(target_arch = "wasm32")]
+use std::collections::VecDeque;
+use std::future::Future;
+use std::mem;
+use std::pin::Pin;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+#[cfg(not(target_arch = "wasm32"))]
+use std::thread;
+
+#[cfg(not(target_arch = "wasm32"))]
+use lazy_static::lazy_static;
+
+use liblumen_core::locks::Mutex;
+
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::Process;
+
+use crate::dirty_io::Output;
+use crate::process;
+use crate::process::spawn::options::Options as SpawnOptions;
+use crate::registry;
+
+/// The number of threads polling futures.  Futures only run on them until they are pending, so
+/// few are needed.
+pub const THREADS: usize = 2;
+
+/// Polls `future` on the pool and, once it is ready, sends the term `message` makes from its
+/// output to `destination`.
+///
+/// `message` makes the term on a heap that belongs to the pool, from which it is copied to
+/// `destination`, as only `destination`'s scheduler may allocate on `destination`'s heap.  The
+/// output is dropped without calling `message` if `destination` has exited by then.
+pub fn spawn<T, F, M>(destination: Pid, future: F, message: M)
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+    M: FnOnce(T, &Process) -> Term + Send + 'static,
+{
+    let delivery_future = async move {
+        let output = future.await;
+
+        let deliver: Deliver = Box::new(move |builder: &Process| {
+            if let Some(destination_arc_process) = registry::pid_to_process(&destination) {
+                let message = message(output, builder);
+                process::send_from_decoder(builder, &destination_arc_process, message);
+            }
+        });
+
+        deliver
+    };
+
+    spawn_delivery(delivery_future);
+}
+
+/// Polls `future` on the pool and, once it is ready, stores its output and wakes `process`, like
+/// `dirty_io::spawn`, for native functions that return the output instead of it being sent.
+pub fn spawn_output<T, F>(process: &Process, future: F) -> Output<T>
+where
+    T: Send + 'static,
+    F: Future<Output = T> + Send + 'static,
+{
+    let output = Output::new();
+    let future_output = output.clone();
+    let pid = process.pid();
+
+    let delivery_future = async move {
+        let value = future.await;
+
+        let deliver: Deliver = Box::new(move |_: &Process| future_output.put(&pid, value));
+
+        deliver
+    };
+
+    spawn_delivery(delivery_future);
+
+    output
+}
+
+/// A future of the output of `job`, which runs on its own thread.
+///
+/// Unlike the threads of `dirty_io`, which are shared by all processes, the thread may block for
+/// as long as `job` likes, such as while waiting for a peer to connect or send.
+pub fn blocking<T, F>(job: F) -> Blocking<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let arc_blocked = Arc::new(Mutex::new(Blocked {
+        output: None,
+        waker: None,
+    }));
+    let job_arc_blocked = arc_blocked.clone();
+    let run = move || {
+        let output = job();
+        let mut blocked = job_arc_blocked.lock();
+        blocked.output = Some(output);
+
+        if let Some(waker) = blocked.waker.take() {
+            waker.wake();
+        }
+    };
+
+    #[cfg(target_arch = "wasm32")]
+    run();
+
+    #[cfg(not(target_arch = "wasm32"))]
+    thread::Builder::new()
+        .name("task_blocking".to_string())
+        .spawn(run)
+        .unwrap();
+
+    Blocking(arc_blocked)
+}
+
+pub struct Blocking<T>(Arc<Mutex<Blocked<T>>>);
+
+impl<T> Future for Blocking<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<T> {
+        let mut blocked = self.0.lock();
+
+        match blocked.output.take() {
+            Some(output) => Poll::Ready(output),
+            None => {
+                blocked.waker = Some(context.waker().clone());
+
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// Private
+
+/// Sends the output of a ready future, using the heap of the polling thread's builder process.
+type Deliver = Box<dyn FnOnce(&Process) + Send>;
+
+struct Blocked<T> {
+    output: Option<T>,
+    /// The waker of the task that last polled the `Blocking` before `output` was ready
+    waker: Option<Waker>,
+}
+
+struct Task {
+    /// `None` once the future is ready, so that wakes after that are ignored.
+    future: Mutex<Option<Pin<Box<dyn Future<Output = Deliver> + Send>>>>,
+}
+
+impl Task {
+    fn poll(self: Arc<Self>, builder: &Process) {
+        let waker = waker(self.clone());
+        let mut context = Context::from_waker(&waker);
+        // A task woken while it is polled is polled again by the next free thread, which waits
+        // here for this thread to finish.
+        let mut guard = self.future.lock();
+
+        let ready = match guard.as_mut() {
+            Some(future) => match future.as_mut().poll(&mut context) {
+                Poll::Ready(deliver) => Some(deliver),
+                Poll::Pending => None,
+            },
+            None => None,
+        };
+
+        if let Some(deliver) = ready {
+            *guard = None;
+            drop(guard);
+
+            deliver(builder);
+        }
+    }
+}
+
+fn spawn_delivery<F>(delivery_future: F)
+where
+    F: Future<Output = Deliver> + Send + 'static,
+{
+    schedule(Arc::new(Task {
+        future: Mutex::new(Some(Box::pin(delivery_future))),
+    }));
+}
+
+fn builder() -> Process {
+    SpawnOptions::default()
+        .spawn(
+            None,
+            Atom::from_str("erlang"),
+            Atom::from_str("task_message"),
+            0,
+        )
+        .unwrap_or_else(|alloc| panic!("could not allocate task message heap: {}", alloc))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn schedule(task: Arc<Task>) {
+    thread_local! {
+        /// The task being polled, if any, followed by the tasks scheduled while it is polled
+        static QUEUE: RefCell<VecDeque<Arc<Task>>> = RefCell::new(VecDeque::new());
+        static BUILDER: Process = builder();
+    }
+
+    let polling = QUEUE.with(|queue| {
+        let mut queue = queue.borrow_mut();
+        queue.push_back(task);
+
+        1 < queue.len()
+    });
+
+    // A task scheduled while another is polled, such as by waking itself, waits its turn instead
+    // of being polled reentrantly.
+    if !polling {
+        while let Some(task) = QUEUE.with(|queue| queue.borrow().front().cloned()) {
+            BUILDER.with(|builder| task.poll(builder));
+            QUEUE.with(|queue| queue.borrow_mut().pop_front());
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn schedule(task: Arc<Task>) {
+    SENDER.lock().send(task).unwrap();
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+lazy_static! {
+    static ref SENDER: Mutex<mpsc::Sender<Arc<Task>>> = Mutex::new(start());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn start() -> mpsc::Sender<Arc<Task>> {
+    let (sender, receiver) = mpsc::channel::<Arc<Task>>();
+    let arc_receiver = Arc::new(Mutex::new(receiver));
+
+    for index in 0..THREADS {
+        let arc_receiver = arc_receiver.clone();
+
+        thread::Builder::new()
+            .name(format!("task_{}", index))
+            .spawn(move || {
+                let builder = builder();
+
+                loop {
+                    // The lock is only held while waiting for a task, so the other threads can
+                    // take the next task while this one polls.
+                    let received = arc_receiver.lock().recv();
+
+                    match received {
+                        Ok(task) => task.poll(&builder),
+                        Err(_) => break,
+                    }
+                }
+            })
+            .unwrap();
+    }
+
+    sender
+}
+
+// The waker of a task is an `Arc<Task>` whose strong count it owns, so waking reschedules it.
+
+fn waker(task: Arc<Task>) -> Waker {
+    unsafe { Waker::from_raw(raw_waker(task)) }
+}
+
+fn raw_waker(task: Arc<Task>) -> RawWaker {
+    RawWaker::new(Arc::into_raw(task) as *const (), &VTABLE)
+}
+
+static VTABLE: RawWakerVTable = RawWakerVTable::new(clone_waker, wake, wake_by_ref, drop_waker);
+
+unsafe fn clone_waker(data: *const ()) -> RawWaker {
+    let task = Arc::from_raw(data as *const Task);
+    let cloned = task.clone();
+    mem::forget(task);
+
+    raw_waker(cloned)
+}
+
+unsafe fn wake(data: *const ()) {
+    schedule(Arc::from_raw(data as *const Task));
+}
+
+unsafe fn wake_by_ref(data: *const ()) {
+    let task = Arc::from_raw(data as *const Task);
+    schedule(task.clone());
+    mem::forget(task);
+}
+
+unsafe fn drop_waker(data: *const ()) {
+    drop(Arc::from_raw(data as *const Task));
+}
@@ -43,18 +43,18 @@ pub fn convert(time: BigInt, from_unit: Unit, to_unit: Unit) -> BigInt {
         let from_hertz = from_unit.hertz();
         let to_hertz = to_unit.hertz();
 
-        if from_hertz <= to_hertz {
-            time * ((to_hertz / from_hertz) as i32)
+        // Multiply before dividing, so that hertz that aren't multiples of each other, such as
+        // 2 to 5, convert exactly
+        let numerator = time * to_hertz;
+        let denominator: BigInt = from_hertz.into();
+        let zero: BigInt = Zero::zero();
+
+        // mimic behavior of erts_napi_convert_time_unit, so that rounding is the same: towards
+        // negative infinity
+        if zero <= numerator {
+            numerator / denominator
         } else {
-            // mimic behavior of erts_napi_convert_time_unit, so that rounding is the same
-            let denominator: BigInt = (from_hertz / to_hertz).into();
-            let zero: BigInt = Zero::zero();
-
-            if zero <= time {
-                time / denominator
-            } else {
-                (time - (denominator.clone() - 1)) / denominator
-            }
+            (numerator - (denominator.clone() - 1)) / denominator
         }
     }
 }
@@ -4,7 +4,10 @@ use crate::time::{convert_milliseconds, Unit};
 use liblumen_alloc::erts::time::Monotonic;
 
 cfg_if::cfg_if! {
-  if #[cfg(all(target_arch = "wasm32", feature = "time_web_sys"))] {
+  if #[cfg(all(target_arch = "wasm32", target_os = "wasi"))] {
+     mod wasi;
+     pub use self::wasi::*;
+  } else if #[cfg(all(target_arch = "wasm32", feature = "time_web_sys"))] {
      mod web_sys;
      pub use self::web_sys::*;
   } else {
@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+
+use super::Monotonic;
+
+const NANOSECONDS_PER_MILLISECOND: u64 = 1_000_000;
+
+pub fn freeze() -> Monotonic {
+    FROZEN.with(|frozen| *frozen.borrow_mut().get_or_insert_with(|| now()))
+}
+
+pub fn freeze_at(monotonic: Monotonic) {
+    FROZEN.with(|frozen| *frozen.borrow_mut() = Some(monotonic));
+}
+
+pub fn time() -> Monotonic {
+    FROZEN.with(|frozen| frozen.borrow().unwrap_or_else(|| now()))
+}
+
+fn now() -> Monotonic {
+    let nanoseconds = unsafe {
+        wasi::clock_time_get(wasi::CLOCKID_MONOTONIC, NANOSECONDS_PER_MILLISECOND)
+            .expect("WASI host should provide a monotonic clock")
+    };
+
+    Monotonic::from_millis(nanoseconds / NANOSECONDS_PER_MILLISECOND)
+}
+
+// The time frozen at a specific time for testing
+thread_local! {
+    static FROZEN: RefCell<Option<Monotonic>> = RefCell::new(None);
+}
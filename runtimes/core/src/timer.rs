@@ -1,7 +1,8 @@
 use core::cmp::Ordering::{self, *};
 use core::fmt::{self, Debug};
+use core::mem;
 use core::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, RangeBounds, Rem};
-use core::ptr::NonNull;
+use core::ptr::{self, NonNull};
 
 use std::sync::{Arc, Weak};
 use std::vec::Drain;
@@ -52,6 +53,12 @@ pub fn start(
     result
 }
 
+/// The time that the thread's next timer times out, so that a scheduler with nothing to run knows
+/// how long it can sleep.
+pub fn next_monotonic() -> Option<Monotonic> {
+    scheduler::current().hierarchy().read().next_monotonic()
+}
+
 /// Times out the timers for the thread that have timed out since the last time `timeout` was
 /// called.
 pub fn timeout() {
@@ -70,6 +77,14 @@ pub struct HeapFragment {
     pub term: Term,
 }
 
+impl Drop for HeapFragment {
+    // Only reached when the message is never sent, because the timer was canceled or the
+    // destination no longer exists; once sent, the fragment is owned by the destination process.
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.heap_fragment.as_ptr()) };
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Destination {
     Name(Atom),
@@ -111,6 +126,20 @@ impl Hierarchy {
             })
     }
 
+    /// The soonest timer of each slot or wheel, as a timer can be in `later` when it times out
+    /// before the last timer in `soon`.
+    pub fn next_monotonic(&self) -> Option<Monotonic> {
+        [
+            self.at_once.next_monotonic(),
+            self.soon.next_monotonic(),
+            self.later.next_monotonic(),
+            self.long_term.next_monotonic(),
+        ]
+        .iter()
+        .filter_map(|next_monotonic| *next_monotonic)
+        .min()
+    }
+
     fn position(&self, monotonic: Monotonic) -> Position {
         if monotonic < self.soon.slot_monotonic {
             Position::AtOnce
@@ -146,6 +175,29 @@ impl Hierarchy {
             arc_process.reference_from_scheduler(arc_scheduler.id(), reference_number);
 
         let destination_event = match source_event {
+            SourceEvent::Interval {
+                destination,
+                interval,
+                term,
+            } => {
+                let (heap_fragment_message, heap_fragment) = term.clone_to_fragment()?;
+                let heap_fragment = Mutex::new(HeapFragment {
+                    heap_fragment,
+                    term: heap_fragment_message,
+                });
+                // Sending to a name stops when the process that started the timer exits, as the
+                // name may be reregistered by another process
+                let owner = match &destination {
+                    Destination::Name(_) => Arc::downgrade(&arc_process),
+                    Destination::Process(weak_process) => weak_process.clone(),
+                };
+
+                DestinationEvent::Message {
+                    destination,
+                    heap_fragment,
+                    repeat: Some(Repeat { interval, owner }),
+                }
+            }
             SourceEvent::Message {
                 destination,
                 format,
@@ -168,6 +220,24 @@ impl Hierarchy {
                 DestinationEvent::Message {
                     destination,
                     heap_fragment,
+                    repeat: None,
+                }
+            }
+            SourceEvent::Spawn {
+                module,
+                function,
+                arguments,
+            } => {
+                let (heap_fragment_arguments, heap_fragment) = arguments.clone_to_fragment()?;
+                let heap_fragment = Mutex::new(HeapFragment {
+                    heap_fragment,
+                    term: heap_fragment_arguments,
+                });
+
+                DestinationEvent::Spawn {
+                    module,
+                    function,
+                    heap_fragment,
                 }
             }
             SourceEvent::StopWaiting => DestinationEvent::StopWaiting {
@@ -175,14 +245,20 @@ impl Hierarchy {
             },
         };
 
-        let position = self.position(monotonic);
-
-        let timer = Timer {
+        self.insert(Timer {
             reference_number,
             monotonic,
             event: destination_event,
-            position: Mutex::new(position),
-        };
+            position: Mutex::new(Position::AtOnce),
+        });
+
+        Ok(process_reference)
+    }
+
+    fn insert(&mut self, timer: Timer) {
+        let reference_number = timer.reference_number;
+        let position = self.position(timer.monotonic);
+        *timer.position.lock() = position;
 
         let arc_timer = Arc::new(timer);
         let timeoutable = Arc::clone(&arc_timer);
@@ -197,8 +273,6 @@ impl Hierarchy {
 
         self.timer_by_reference_number
             .insert(reference_number, cancellable);
-
-        Ok(process_reference)
     }
 
     pub fn timeout(&mut self) {
@@ -233,30 +307,46 @@ impl Hierarchy {
     }
 
     fn timeout_at_once(&mut self) {
+        let mut repeated_timers = Vec::new();
+
         for arc_timer in self.at_once.drain(..) {
             self.timer_by_reference_number
                 .remove(&arc_timer.reference_number);
 
-            Self::timeout_arc_timer(arc_timer);
+            repeated_timers.extend(Self::timeout_arc_timer(arc_timer));
         }
+
+        self.insert_repeated(repeated_timers);
     }
 
     fn timeout_soon_slot(&mut self) {
-        for arc_timer in self.soon.drain(..) {
+        let mut repeated_timers = Vec::new();
+
+        for arc_timer in self.soon.drain() {
             self.timer_by_reference_number
                 .remove(&arc_timer.reference_number);
 
-            Self::timeout_arc_timer(arc_timer);
+            repeated_timers.extend(Self::timeout_arc_timer(arc_timer));
         }
+
+        self.insert_repeated(repeated_timers);
     }
 
-    fn timeout_arc_timer(arc_timer: Arc<Timer>) {
+    fn timeout_arc_timer(arc_timer: Arc<Timer>) -> Option<Timer> {
         match Arc::try_unwrap(arc_timer) {
             Ok(timer) => timer.timeout(),
             Err(_) => panic!("Timer Dropped"),
         }
     }
 
+    /// Timers are only inserted again after their slot is drained, so that an interval shorter
+    /// than a slot can't time out more than once per call to `timeout`.
+    fn insert_repeated(&mut self, repeated_timers: Vec<Timer>) {
+        for timer in repeated_timers {
+            self.insert(timer);
+        }
+    }
+
     fn transfer(&mut self, mut transferable_arc_timer: Arc<Timer>, wheel_name: WheelName) {
         let wheel = match wheel_name {
             WheelName::Soon => &mut self.soon,
@@ -420,6 +510,20 @@ pub enum SourceEvent {
         format: Format,
         term: Term,
     },
+    /// Sends `term` to `destination` every `interval` until the timer is canceled, or
+    /// `destination` exits.  For a registered name, it stops when the process starting the timer
+    /// exits instead.
+    Interval {
+        destination: Destination,
+        interval: Milliseconds,
+        term: Term,
+    },
+    /// Spawns a process calling `module:function` with the elements of the `arguments` list
+    Spawn {
+        module: Atom,
+        function: Atom,
+        arguments: Term,
+    },
     StopWaiting,
 }
 
@@ -452,11 +556,13 @@ impl Timer {
         }
     }
 
-    fn timeout(self) {
+    /// Returns the timer for the next time out of a repeating timer.
+    fn timeout(self) -> Option<Timer> {
         match self.event {
             DestinationEvent::Message {
                 destination,
                 heap_fragment,
+                repeat,
             } => {
                 let option_destination_arc_process = match &destination {
                     Destination::Name(ref name) => registry::atom_to_process(name),
@@ -465,20 +571,75 @@ impl Timer {
                     }
                 };
 
-                if let Some(destination_arc_process) = option_destination_arc_process {
-                    let HeapFragment {
-                        heap_fragment,
-                        term,
-                    } = heap_fragment.into_inner();
-
-                    destination_arc_process.send_heap_message(heap_fragment, term);
-
-                    destination_arc_process
-                        .scheduler()
-                        .unwrap()
-                        .stop_waiting(&destination_arc_process);
+                match repeat {
+                    Some(repeat) => {
+                        if let Some(destination_arc_process) = option_destination_arc_process {
+                            // Each message needs a fragment of its own, as the original is kept
+                            // for the next time out
+                            let term = heap_fragment.lock().term;
+
+                            if let Ok((message, message_heap_fragment)) = term.clone_to_fragment() {
+                                destination_arc_process
+                                    .send_heap_message(message_heap_fragment, message);
+                                destination_arc_process
+                                    .scheduler()
+                                    .unwrap()
+                                    .stop_waiting(&destination_arc_process);
+                            }
+                        }
+
+                        if repeat.owner.upgrade().is_some() {
+                            return Some(Timer {
+                                reference_number: self.reference_number,
+                                monotonic: self.monotonic + repeat.interval,
+                                event: DestinationEvent::Message {
+                                    destination,
+                                    heap_fragment,
+                                    repeat: Some(repeat),
+                                },
+                                position: self.position,
+                            });
+                        }
+                    }
+                    None => {
+                        if let Some(destination_arc_process) = option_destination_arc_process {
+                            let heap_fragment = heap_fragment.into_inner();
+
+                            destination_arc_process
+                                .send_heap_message(heap_fragment.heap_fragment, heap_fragment.term);
+                            // ownership of the fragment moved to `destination_arc_process`
+                            mem::forget(heap_fragment);
+
+                            destination_arc_process
+                                .scheduler()
+                                .unwrap()
+                                .stop_waiting(&destination_arc_process);
+                        }
+                    }
                 }
             }
+            DestinationEvent::Spawn {
+                module,
+                function,
+                heap_fragment,
+            } => {
+                let arguments = heap_fragment.lock().term;
+                let argument_vec: Vec<Term> = match arguments.decode().unwrap() {
+                    TypedTerm::List(cons) => cons.into_iter().map(Result::unwrap).collect(),
+                    _ => Vec::new(),
+                };
+
+                // Like the BEAM's timer server, the spawned process has no parent, and a process
+                // that fails to spawn is not reported to anyone.  The arguments are copied to
+                // the spawned process, so the fragment can be dropped afterwards.
+                let _ = scheduler::current().spawn_module_function_arguments(
+                    None,
+                    module,
+                    function,
+                    argument_vec,
+                    Default::default(),
+                );
+            }
             DestinationEvent::StopWaiting { process } => {
                 if let Some(destination_arc_process) = process.upgrade() {
                     // `__lumen_builtin_receive_wait` will notice it has timed out, so only need to
@@ -491,6 +652,8 @@ impl Timer {
                 }
             }
         }
+
+        None
     }
 }
 
@@ -502,6 +665,7 @@ impl Debug for Timer {
             DestinationEvent::Message {
                 destination,
                 heap_fragment,
+                repeat,
             } => {
                 let HeapFragment { term, .. } = *heap_fragment.lock();
                 write!(f, "{} -> ", term)?;
@@ -510,6 +674,18 @@ impl Debug for Timer {
                     Destination::Process(weak_process) => fmt_weak_process(weak_process, f),
                     Destination::Name(name) => write!(f, "{}", name),
                 }?;
+
+                if let Some(Repeat { interval, .. }) = repeat {
+                    write!(f, " every {}ms", u64::from(*interval))?;
+                }
+            }
+            DestinationEvent::Spawn {
+                module,
+                function,
+                heap_fragment,
+            } => {
+                let HeapFragment { term, .. } = *heap_fragment.lock();
+                write!(f, "spawn {}:{} with {}", module, function, term)?;
             }
             DestinationEvent::StopWaiting { process } => {
                 fmt_weak_process(process, f)?;
@@ -563,11 +739,25 @@ enum DestinationEvent {
     Message {
         destination: Destination,
         heap_fragment: Mutex<HeapFragment>,
+        repeat: Option<Repeat>,
+    },
+    /// Spawn a process calling `module:function` with the arguments list in `heap_fragment`
+    Spawn {
+        module: Atom,
+        function: Atom,
+        heap_fragment: Mutex<HeapFragment>,
     },
     /// Stop `process` from waiting
     StopWaiting { process: Weak<Process> },
 }
 
+/// How a `DestinationEvent::Message` repeats
+struct Repeat {
+    interval: Milliseconds,
+    /// The timer stops repeating when `owner` exits
+    owner: Weak<Process>,
+}
+
 #[derive(Clone, Copy)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 enum Position {
@@ -613,6 +803,11 @@ impl Slot {
         self.0.is_empty()
     }
 
+    /// Timers are sorted, so the first times out soonest.
+    fn next_monotonic(&self) -> Option<Monotonic> {
+        self.0.first().map(|arc_timer| arc_timer.monotonic)
+    }
+
     fn start(&mut self, arc_timer: Arc<Timer>) {
         let index = self
             .0
@@ -692,6 +887,9 @@ struct Wheel {
     slots: Vec<Slot>,
     slot_index: SlotIndex,
     slot_monotonic: Monotonic,
+    /// The number of timers in all `slots`, so that `next_monotonic` doesn't look through every
+    /// slot of an empty wheel.
+    len: usize,
 }
 
 impl Wheel {
@@ -709,6 +907,7 @@ impl Wheel {
             slots: vec![Default::default(); Self::SLOTS.0 as usize],
             slot_index,
             slot_monotonic,
+            len: 0,
         }
     }
 
@@ -717,18 +916,28 @@ impl Wheel {
         slot_index: SlotIndex,
         reference_number: ReferenceNumber,
     ) -> Option<Arc<Timer>> {
-        self.slots[slot_index.0 as usize].cancel(reference_number)
+        let canceled = self.slots[slot_index.0 as usize].cancel(reference_number);
+
+        if canceled.is_some() {
+            self.len -= 1;
+        }
+
+        canceled
     }
 
-    fn drain<R>(&mut self, range: R) -> Drain<Arc<Timer>>
-    where
-        R: RangeBounds<usize>,
-    {
-        self.slots[self.slot_index.0 as usize].drain(range)
+    /// Drains the current slot.
+    fn drain(&mut self) -> Drain<Arc<Timer>> {
+        let drain = self.slots[self.slot_index.0 as usize].drain(..);
+        self.len -= drain.len();
+
+        drain
     }
 
     fn drain_before_or_at(&mut self, max_monotonic: Monotonic) -> Drain<Arc<Timer>> {
-        self.slots[self.slot_index.0 as usize].drain_before_or_at(max_monotonic)
+        let drain = self.slots[self.slot_index.0 as usize].drain_before_or_at(max_monotonic);
+        self.len -= drain.len();
+
+        drain
     }
 
     fn is_empty(&self) -> bool {
@@ -739,6 +948,18 @@ impl Wheel {
         self.slot_monotonic + self.total_milliseconds - Milliseconds(1)
     }
 
+    /// Slots time out in order from `slot_index`, so the first timer of the first slot with any
+    /// times out soonest.
+    fn next_monotonic(&self) -> Option<Monotonic> {
+        if self.len == 0 {
+            return None;
+        }
+
+        (0..Self::SLOTS.0)
+            .map(|offset| &self.slots[((self.slot_index + offset) % Self::SLOTS).0 as usize])
+            .find_map(Slot::next_monotonic)
+    }
+
     fn next_slot(&mut self) {
         self.slot_index = (self.slot_index + 1) % Self::SLOTS;
         self.slot_monotonic += self.milliseconds_per_slot;
@@ -758,7 +979,8 @@ impl Wheel {
     }
 
     fn start(&mut self, slot_index: SlotIndex, arc_timer: Arc<Timer>) {
-        self.slots[slot_index.0 as usize].start(arc_timer)
+        self.slots[slot_index.0 as usize].start(arc_timer);
+        self.len += 1;
     }
 }
 
@@ -849,3 +1071,82 @@ pub fn long_term_milliseconds() -> Milliseconds {
 
     milliseconds
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_monotonic_is_soonest_timer_in_any_wheel() {
+        let now = monotonic::freeze();
+        let mut hierarchy = Hierarchy::default();
+
+        assert_eq!(hierarchy.next_monotonic(), None);
+
+        for (reference_number, milliseconds) in [
+            long_term_milliseconds(),
+            later_milliseconds(),
+            soon_milliseconds(),
+        ]
+        .iter()
+        .enumerate()
+        {
+            hierarchy.insert(timer(
+                reference_number as ReferenceNumber,
+                now + *milliseconds,
+            ));
+
+            assert_eq!(hierarchy.next_monotonic(), Some(now + *milliseconds));
+        }
+    }
+
+    #[test]
+    fn next_monotonic_skips_canceled_timers() {
+        let now = monotonic::freeze();
+        let mut hierarchy = Hierarchy::default();
+        hierarchy.insert(timer(1, now + soon_milliseconds()));
+        hierarchy.insert(timer(2, now + later_milliseconds()));
+
+        hierarchy.cancel(1);
+
+        assert_eq!(hierarchy.next_monotonic(), Some(now + later_milliseconds()));
+
+        hierarchy.cancel(2);
+
+        assert_eq!(hierarchy.next_monotonic(), None);
+    }
+
+    #[test]
+    fn next_monotonic_wraps_around_wheel() {
+        let total_milliseconds = Hierarchy::SOON_TOTAL_MILLISECONDS.as_u64();
+        // 4 slots before the end of the soon wheel
+        monotonic::freeze_at(Monotonic::from_millis(3 * total_milliseconds - 4));
+        let mut hierarchy = Hierarchy::default();
+        let slot_monotonic = hierarchy.soon.slot_monotonic;
+        let wrapped = slot_monotonic + Milliseconds(10);
+        let not_wrapped = slot_monotonic + Milliseconds(2);
+
+        hierarchy.insert(timer(1, wrapped));
+
+        assert_eq!(hierarchy.next_monotonic(), Some(wrapped));
+
+        hierarchy.insert(timer(2, not_wrapped));
+
+        assert_eq!(hierarchy.next_monotonic(), Some(not_wrapped));
+
+        hierarchy.cancel(2);
+
+        assert_eq!(hierarchy.next_monotonic(), Some(wrapped));
+    }
+
+    fn timer(reference_number: ReferenceNumber, monotonic: Monotonic) -> Timer {
+        Timer {
+            reference_number,
+            monotonic,
+            event: DestinationEvent::StopWaiting {
+                process: Weak::new(),
+            },
+            position: Mutex::new(Position::AtOnce),
+        }
+    }
+}
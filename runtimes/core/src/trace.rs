@@ -0,0 +1,472 @@
+//! Tracing of processes, for `erlang:trace/3` and `erlang:trace_pattern/2`.
+//!
+//! Each traced process, the tracee, has one tracer, which is sent a trace message for each event
+//! the tracee's flags ask for.  Trace messages are built on the heap of the process that causes
+//! the event and then sent to the tracer like any other message, so they are never themselves
+//! traced.
+//!
+//! The hooks are called by the code causing the event:
+//!
+//! * `send` and `receive` by `send::send`, so messages sent by the runtime, such as timeouts,
+//!   `'DOWN'` and `'EXIT'` messages, are not traced as received.
+//! * `call` by `erlang:apply/3`, so only calls made through it are traced, as compiled code calls
+//!   other functions directly.
+//! * `spawn`, `link` and `unlink` by the BIFs that spawn, link and unlink.
+//! * `exit` by `process::propagate_exit`, which also stops tracing the exiting process and any
+//!   processes traced by it.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use dashmap::DashMap;
+use hashbrown::HashSet;
+use lazy_static::lazy_static;
+
+use liblumen_alloc::erts::process::Process;
+use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::{atom, ModuleFunctionArity};
+use liblumen_core::locks::Mutex;
+
+use crate::registry::pid_to_process;
+use crate::scheduler::Scheduled;
+
+/// The kinds of events that can be traced
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Flag {
+    Send,
+    Receive,
+    Call,
+    Procs,
+    Exiting,
+}
+
+impl Flag {
+    pub const ALL: [Flag; 5] = [
+        Flag::Send,
+        Flag::Receive,
+        Flag::Call,
+        Flag::Procs,
+        Flag::Exiting,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Flag::Send => "send",
+            Flag::Receive => "receive",
+            Flag::Call => "call",
+            Flag::Procs => "procs",
+            Flag::Exiting => "exiting",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+impl Display for Flag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Flag {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|flag| flag.as_str() == s)
+            .copied()
+            .ok_or(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flags(u8);
+
+impl Flags {
+    pub fn contains(&self, flag: Flag) -> bool {
+        (self.0 & flag.bit()) != 0
+    }
+
+    pub fn insert(&mut self, flags: Flags) {
+        self.0 |= flags.0;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Flag> + '_ {
+        Flag::ALL
+            .iter()
+            .copied()
+            .filter(move |flag| self.contains(*flag))
+    }
+
+    pub fn remove(&mut self, flags: Flags) {
+        self.0 &= !flags.0;
+    }
+}
+
+impl From<Flag> for Flags {
+    fn from(flag: Flag) -> Self {
+        Flags(flag.bit())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Tracee {
+    pub flags: Flags,
+    pub tracer: Pid,
+}
+
+/// Which processes `set` applies to, like the `PidSpec` of `erlang:trace/3`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tracees {
+    Pid(Pid),
+    /// Processes that currently exist
+    Existing,
+    /// Processes that will be spawned
+    New,
+    /// Both `Existing` and `New`
+    All,
+}
+
+/// Turns `flags` on (`how` is `true`) or off for `tracees`, which are traced by `tracer`.
+///
+/// Like the BEAM, a process that is already traced by another tracer is left alone when turning
+/// flags on.  Returns the number of existing processes whose flags were changed, or `Err` if
+/// `tracees` is a single process that is traced by another tracer.
+pub fn set(tracees: Tracees, how: bool, flags: Flags, tracer: Pid) -> Result<usize, ()> {
+    match tracees {
+        Tracees::Pid(pid) => {
+            if set_tracee(pid, how, flags, tracer) {
+                Ok(1)
+            } else {
+                Err(())
+            }
+        }
+        Tracees::Existing => Ok(set_existing(how, flags, tracer)),
+        Tracees::New => {
+            set_new(how, flags, tracer);
+
+            Ok(0)
+        }
+        Tracees::All => {
+            set_new(how, flags, tracer);
+
+            Ok(set_existing(how, flags, tracer))
+        }
+    }
+}
+
+/// The flags and tracer of `pid`, if it is traced
+pub fn tracee(pid: &Pid) -> Option<Tracee> {
+    TRACEE_BY_PID.get(pid).map(|tracee| *tracee.value())
+}
+
+/// The flags and tracer that processes will have when they are spawned
+pub fn new_tracee() -> Option<Tracee> {
+    *NEW_TRACEE.lock()
+}
+
+/// Turns call tracing of the functions matching `module`, `function` and `arity` on (`enable` is
+/// `true`) or off.  `None` matches any function or arity.  Returns the number of functions
+/// matched.
+pub fn set_call_pattern(
+    module: Atom,
+    function: Option<Atom>,
+    arity: Option<u8>,
+    enable: bool,
+) -> usize {
+    let matching_vec: Vec<ModuleFunctionArity> =
+        liblumen_alloc::erts::apply::module_function_arities()
+            .into_iter()
+            .filter(|mfa| {
+                mfa.module == module
+                    && function.map_or(true, |function| mfa.function == function)
+                    && arity.map_or(true, |arity| mfa.arity == arity)
+            })
+            .collect();
+
+    let mut call_patterns = CALL_PATTERNS.lock();
+
+    for mfa in &matching_vec {
+        if enable {
+            call_patterns.insert(*mfa);
+        } else {
+            call_patterns.remove(mfa);
+        }
+    }
+
+    matching_vec.len()
+}
+
+/// Whether calls to `module_function_arity` are traced by processes with the `call` flag
+pub fn is_call_traced(module_function_arity: &ModuleFunctionArity) -> bool {
+    CALL_PATTERNS.lock().contains(module_function_arity)
+}
+
+// Hooks
+
+/// `{trace, Pid, send, Msg, To}`
+pub fn send(process: &Process, message: Term, destination: Term) {
+    if let Some(tracer) = tracer_for(process.pid(), Flag::Send) {
+        let trace_message = process.tuple_from_slice(&[
+            atom!("trace"),
+            process.pid_term(),
+            atom!("send"),
+            message,
+            destination,
+        ]);
+
+        deliver(process, tracer, trace_message);
+    }
+}
+
+/// `{trace, Pid, 'receive', Msg}`, built on the heap of `sender`, which may be `receiver`
+pub fn receive(sender: &Process, receiver: &Process, message: Term) {
+    if let Some(tracer) = tracer_for(receiver.pid(), Flag::Receive) {
+        let trace_message = sender.tuple_from_slice(&[
+            atom!("trace"),
+            receiver.pid_term(),
+            atom!("receive"),
+            message,
+        ]);
+
+        deliver(sender, tracer, trace_message);
+    }
+}
+
+/// `{trace, Pid, call, {M, F, Args}}`
+pub fn call(process: &Process, module_function_arity: &ModuleFunctionArity, arguments: Term) {
+    if let Some(tracer) = tracer_for(process.pid(), Flag::Call) {
+        if is_call_traced(module_function_arity) {
+            let mfa = process.tuple_from_slice(&[
+                module_function_arity.module.encode().unwrap(),
+                module_function_arity.function.encode().unwrap(),
+                arguments,
+            ]);
+            let trace_message =
+                process.tuple_from_slice(&[atom!("trace"), process.pid_term(), atom!("call"), mfa]);
+
+            deliver(process, tracer, trace_message);
+        }
+    }
+}
+
+/// Starts tracing `process` if processes are traced when spawned.  Called before `process` is
+/// scheduled, so that none of its events are missed.
+pub fn new_process(process: &Process) {
+    if let Some(new_tracee) = new_tracee() {
+        TRACEE_BY_PID.insert(process.pid(), new_tracee);
+    }
+}
+
+/// Sends `{trace, Parent, spawn, Child, {M, F, Args}}` and
+/// `{trace, Child, spawned, Parent, {M, F, Args}}`.  `arguments` is only called if either is
+/// traced.
+pub fn spawn(
+    parent: &Process,
+    child: &Process,
+    module: Atom,
+    function: Atom,
+    arguments: impl FnOnce() -> Term,
+) {
+    let option_parent_tracer = tracer_for(parent.pid(), Flag::Procs);
+    let option_child_tracer = tracer_for(child.pid(), Flag::Procs);
+
+    if option_parent_tracer.is_none() && option_child_tracer.is_none() {
+        return;
+    }
+
+    let mfa = parent.tuple_from_slice(&[
+        module.encode().unwrap(),
+        function.encode().unwrap(),
+        arguments(),
+    ]);
+
+    if let Some(tracer) = option_parent_tracer {
+        let trace_message = parent.tuple_from_slice(&[
+            atom!("trace"),
+            parent.pid_term(),
+            atom!("spawn"),
+            child.pid_term(),
+            mfa,
+        ]);
+
+        deliver(parent, tracer, trace_message);
+    }
+
+    if let Some(tracer) = option_child_tracer {
+        let trace_message = parent.tuple_from_slice(&[
+            atom!("trace"),
+            child.pid_term(),
+            atom!("spawned"),
+            parent.pid_term(),
+            mfa,
+        ]);
+
+        deliver(parent, tracer, trace_message);
+    }
+}
+
+/// `{trace, Pid, link, Pid2}`
+pub fn link(process: &Process, other: Pid) {
+    procs_event(process, "link", other);
+}
+
+/// `{trace, Pid, unlink, Pid2}`
+pub fn unlink(process: &Process, other: Pid) {
+    procs_event(process, "unlink", other);
+}
+
+/// Sends `{trace, Pid, exit, Reason}` and `{trace, Pid, out_exited, 0}`, then stops tracing
+/// `process` and, if it is a tracer, the processes it traces.
+pub fn exit(process: &Process, reason: Term) {
+    let pid = process.pid();
+
+    if let Some((_, tracee)) = TRACEE_BY_PID.remove(&pid) {
+        if tracee.flags.contains(Flag::Procs) {
+            let trace_message = process.tuple_from_slice(&[
+                atom!("trace"),
+                process.pid_term(),
+                atom!("exit"),
+                reason,
+            ]);
+
+            deliver(process, tracee.tracer, trace_message);
+        }
+
+        if tracee.flags.contains(Flag::Exiting) {
+            let trace_message = process.tuple_from_slice(&[
+                atom!("trace"),
+                process.pid_term(),
+                atom!("out_exited"),
+                process.integer(0),
+            ]);
+
+            deliver(process, tracee.tracer, trace_message);
+        }
+    }
+
+    // Collect first, so that the shard locks aren't held while removing
+    let traced_pid_vec: Vec<Pid> = TRACEE_BY_PID
+        .iter()
+        .filter(|entry| entry.value().tracer == pid)
+        .map(|entry| *entry.key())
+        .collect();
+
+    for traced_pid in traced_pid_vec {
+        TRACEE_BY_PID.remove(&traced_pid);
+    }
+
+    let mut new_tracee = NEW_TRACEE.lock();
+
+    if new_tracee.map_or(false, |tracee| tracee.tracer == pid) {
+        *new_tracee = None;
+    }
+}
+
+// Private
+
+lazy_static! {
+    static ref TRACEE_BY_PID: DashMap<Pid, Tracee> = Default::default();
+    static ref NEW_TRACEE: Mutex<Option<Tracee>> = Mutex::new(None);
+    static ref CALL_PATTERNS: Mutex<HashSet<ModuleFunctionArity>> = Default::default();
+}
+
+/// Sends `trace_message`, which is on the heap of `process`, to `tracer`
+fn deliver(process: &Process, tracer: Pid, trace_message: Term) {
+    if tracer == process.pid() {
+        process.send_from_self(trace_message);
+    } else if let Some(tracer_arc_process) = pid_to_process(&tracer) {
+        tracer_arc_process.send_from_other(trace_message);
+        tracer_arc_process
+            .scheduler()
+            .unwrap()
+            .stop_waiting(&tracer_arc_process);
+    }
+}
+
+fn procs_event(process: &Process, event: &str, other: Pid) {
+    if let Some(tracer) = tracer_for(process.pid(), Flag::Procs) {
+        let trace_message = process.tuple_from_slice(&[
+            atom!("trace"),
+            process.pid_term(),
+            Atom::str_to_term(event),
+            other.encode().unwrap(),
+        ]);
+
+        deliver(process, tracer, trace_message);
+    }
+}
+
+fn set_existing(how: bool, flags: Flags, tracer: Pid) -> usize {
+    crate::registry::processes()
+        .iter()
+        .filter(|arc_process| {
+            let pid = arc_process.pid();
+
+            pid != tracer && set_tracee(pid, how, flags, tracer)
+        })
+        .count()
+}
+
+fn set_new(how: bool, flags: Flags, tracer: Pid) {
+    let mut new_tracee = NEW_TRACEE.lock();
+    *new_tracee = updated(*new_tracee, how, flags, tracer);
+}
+
+/// Returns `false` if `pid` is traced by another tracer
+fn set_tracee(pid: Pid, how: bool, flags: Flags, tracer: Pid) -> bool {
+    let option_tracee = tracee(&pid);
+
+    match option_tracee {
+        Some(tracee) if how && tracee.tracer != tracer => false,
+        _ => {
+            match updated(option_tracee, how, flags, tracer) {
+                Some(tracee) => {
+                    TRACEE_BY_PID.insert(pid, tracee);
+                }
+                None => {
+                    TRACEE_BY_PID.remove(&pid);
+                }
+            }
+
+            true
+        }
+    }
+}
+
+fn tracer_for(pid: Pid, flag: Flag) -> Option<Pid> {
+    TRACEE_BY_PID.get(&pid).and_then(|tracee| {
+        if tracee.flags.contains(flag) {
+            Some(tracee.tracer)
+        } else {
+            None
+        }
+    })
+}
+
+/// `None` once no flags are left
+fn updated(option_tracee: Option<Tracee>, how: bool, flags: Flags, tracer: Pid) -> Option<Tracee> {
+    let mut tracee = option_tracee.unwrap_or(Tracee {
+        flags: Default::default(),
+        tracer,
+    });
+
+    if how {
+        tracee.flags.insert(flags);
+    } else {
+        tracee.flags.remove(flags);
+    }
+
+    if tracee.flags.is_empty() {
+        None
+    } else {
+        Some(tracee)
+    }
+}
@@ -6,6 +6,9 @@ use std::path::Path;
 
 use clap::{App, AppSettings, Arg, SubCommand};
 
+use lumen_rt_core::logger::PrimaryLevel;
+use lumen_rt_core::profile;
+
 pub type ConfigResult<T> = std::result::Result<T, ConfigError>;
 //TODO: Needs to be HashMap<Atom, HashMap<Atom, Term>>
 pub type AppConfig = HashMap<String, HashMap<String, String>>;
@@ -49,8 +52,10 @@ pub struct Config {
     pub config: AppConfig,
     pub boot: Option<BootScript>,
     pub debug: bool,
+    pub logger_level: PrimaryLevel,
     pub name: Option<String>,
     pub cookie: Option<String>,
+    pub profile: Option<profile::Mode>,
     pub command: Command,
     pub extra: Vec<String>,
 }
@@ -79,6 +84,12 @@ impl Config {
             .arg(Arg::with_name("debug")
                      .long("debug")
                      .help("Enable debug output from the runtime"))
+            .arg(Arg::with_name("logger_level")
+                     .long("logger_level")
+                     .help("The primary level of logger, below which events are dropped (default: notice)")
+                     .takes_value(true)
+                     .possible_values(&["emergency", "alert", "critical", "error", "warning", "notice", "info", "debug", "all", "none"])
+                     .env("LOGGER_LEVEL"))
             .arg(Arg::with_name("name")
                      .long("name")
                      .global(true)
@@ -92,6 +103,12 @@ impl Config {
                      .help("The secret cookie to use in distributed mode")
                      .takes_value(true)
                      .env("COOKIE"))
+            .arg(Arg::with_name("profile")
+                     .long("profile")
+                     .help("Profile the reductions, garbage collection and scheduled time of each process, \
+                            which is reported on shutdown")
+                     .takes_value(true)
+                     .possible_values(&["processes"]))
             .arg(Arg::with_name("extra")
                      .last(true)
                      .multiple(true)
@@ -130,8 +147,12 @@ impl Config {
             )?,
             boot: with_file(matches.value_of_os("boot"), None, load_boot_script)?,
             debug: matches.is_present("debug"),
+            logger_level: matches
+                .value_of("logger_level")
+                .map_or_else(PrimaryLevel::default, |level| level.parse().unwrap()),
             name: matches.value_of("name").map(|v| v.to_string()),
             cookie: matches.value_of("cookie").map(|v| v.to_string()),
+            profile: matches.value_of("profile").map(|v| v.parse().unwrap()),
             command,
             extra: extra.iter().map(|v| v.to_string()).collect(),
         })
@@ -39,7 +39,8 @@ extern crate cfg_if;
 extern crate chrono;
 
 pub use lumen_rt_core::{
-    binary_to_string, context, distribution, proplist, registry, send, test, time, timer,
+    binary_to_string, code, context, dirty_io, distribution, file, inet, logger, port, profile,
+    proplist, registry, send, task, test, time, timer, trace,
 };
 
 #[cfg(not(any(test, target_arch = "wasm32")))]
@@ -78,7 +79,7 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
     use std::thread;
 
     // Load system configuration
-    let _config = match Config::from_argv(name.to_string(), version.to_string(), argv) {
+    let config = match Config::from_argv(name.to_string(), version.to_string(), argv) {
         Ok(config) => config,
         Err(err) => {
             eprintln!("Config error: {}", err);
@@ -95,6 +96,12 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
 
     // Start logger
     Logger::init(Level::Info).expect("Unexpected failure initializing logger");
+    // Filter `logger` events, like the `kernel` `logger_level` parameter
+    logger::set_primary_level(config.logger_level);
+
+    if let Some(profile_mode) = config.profile {
+        profile::enable(profile_mode);
+    }
 
     let scheduler = scheduler::current();
     loop {
@@ -105,6 +112,8 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
             match sig {
                 // For now, SIGINT initiates a controlled shutdown
                 Signal::INT => {
+                    profile::write_report();
+
                     // If an error occurs, report it before shutdown
                     if let Err(err) = scheduler.shutdown() {
                         eprintln!("System error: {}", err);
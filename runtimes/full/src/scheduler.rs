@@ -7,22 +7,29 @@ use std::sync::Arc;
 
 use liblumen_core::locks::RwLock;
 
+use liblumen_alloc::atom;
 use liblumen_alloc::borrow::clone_to_process::CloneToProcess;
 use liblumen_alloc::erts::exception::SystemException;
+use liblumen_alloc::erts::process::gc::GcError;
+use liblumen_alloc::erts::process::trace::Trace;
 use liblumen_alloc::erts::process::{Frame, FrameWithArguments, Native, Priority, Process, Status};
 pub use liblumen_alloc::erts::scheduler::{id, ID};
 use liblumen_alloc::erts::term::prelude::*;
 use liblumen_alloc::{Arity, ModuleFunctionArity, Ran};
 
 use lumen_rt_core::process::spawn::options::Options;
-use lumen_rt_core::process::{log_exit, propagate_exit, CURRENT_PROCESS};
+use lumen_rt_core::process::{deliver_exit_signals, log_exit, propagate_exit, CURRENT_PROCESS};
+use lumen_rt_core::profile;
 use lumen_rt_core::registry::put_pid_to_process;
 pub use lumen_rt_core::scheduler::{
-    current, from_id, run_through, Scheduled, SchedulerDependentAlloc, Spawned,
+    all, count, current, from_id, run_through, statistics, Scheduled, SchedulerDependentAlloc,
+    Spawned,
 };
 use lumen_rt_core::scheduler::{run_queue, unregister, Run, Scheduler as SchedulerTrait};
 use lumen_rt_core::timer::Hierarchy;
 
+use self::statistics::{ProcessCounts, Statistics};
+
 use crate::process::out_of_code;
 
 // External functions defined in OTP
@@ -43,6 +50,7 @@ fn unregistered() -> Arc<dyn lumen_rt_core::scheduler::Scheduler> {
         hierarchy: Default::default(),
         reference_count: AtomicU64::new(0),
         run_queues: Default::default(),
+        statistics: Default::default(),
         unique_integer: AtomicU64::new(0),
     })
 }
@@ -53,6 +61,7 @@ pub struct Scheduler {
     // References are always 64-bits even on 32-bit platforms
     reference_count: AtomicU64,
     run_queues: RwLock<run_queue::Queues>,
+    statistics: Statistics,
     // Non-monotonic unique integers are scoped to the scheduler ID and then use this per-scheduler
     // `u64`.
     unique_integer: AtomicU64,
@@ -193,11 +202,17 @@ impl SchedulerTrait for Scheduler {
                     CURRENT_PROCESS
                         .with(|current_process| current_process.replace(Some(arc_process.clone())));
 
+                    // Exit signals are only delivered between runs, so that a running process
+                    // is never exited from outside itself.
+                    deliver_exit_signals(&arc_process);
+
                     // Don't allow exiting processes to run again.
                     //
                     // Without this check, a process.exit() from outside the process during WAITING
                     // will return to the Frame that called `process.wait()`
                     if !arc_process.is_exiting() {
+                        let process_counts = ProcessCounts::of(&arc_process);
+
                         match arc_process.run() {
                             Ran::Waiting | Ran::Reduced | Ran::Exited | Ran::RuntimeException => (),
                             Ran::SystemException => {
@@ -206,8 +221,13 @@ impl SchedulerTrait for Scheduler {
                                         match system_exception {
                                             SystemException::Alloc(_) => {
                                                 let mut roots = [];
-                                                match arc_process.garbage_collect(0, &mut roots[..])
-                                                {
+                                                match profile::time_garbage_collection(
+                                                    &arc_process,
+                                                    || {
+                                                        arc_process
+                                                            .garbage_collect(0, &mut roots[..])
+                                                    },
+                                                ) {
                                                     Ok(reductions) => {
                                                         arc_process.total_reductions.fetch_add(
                                                             reductions.try_into().unwrap(),
@@ -218,6 +238,9 @@ impl SchedulerTrait for Scheduler {
                                                         // successful `garbage_collect`
                                                         true
                                                     }
+                                                    // `kill` is set in `max_heap_size`, so the
+                                                    // process is killed below
+                                                    Err(GcError::MaxHeapSizeExceeded) => false,
                                                     Err(gc_err) => panic!(
                                                         "fatal garbage collection error: {:?}",
                                                         gc_err
@@ -230,12 +253,20 @@ impl SchedulerTrait for Scheduler {
                                     _ => unreachable!(),
                                 };
 
+                                // Have to set after `match` where `ReadGuard` is held
                                 if runnable {
-                                    // Have to set after `match` where `ReadGuard` is held
                                     *arc_process.status.write() = Status::Runnable;
+                                } else {
+                                    arc_process.exit(
+                                        atom!("killed"),
+                                        Trace::capture(),
+                                        Some(anyhow::anyhow!(GcError::MaxHeapSizeExceeded).into()),
+                                    );
                                 }
                             }
                         }
+
+                        self.statistics.record_run(&arc_process, process_counts);
                     } else {
                         arc_process.reduce()
                     }
@@ -308,6 +339,10 @@ impl SchedulerTrait for Scheduler {
         Ok(())
     }
 
+    fn statistics(&self) -> &Statistics {
+        &self.statistics
+    }
+
     fn spawn_init(&self, minimum_heap_size: usize) -> anyhow::Result<Arc<Process>> {
         let mut options: Options = Default::default();
         options.min_heap_size = Some(minimum_heap_size);
@@ -332,13 +367,14 @@ impl SchedulerTrait for Scheduler {
         let (heap, heap_size) = options.sized_heap()?;
         let priority = options.cascaded_priority(parent);
         let initial_module_function_arity = closure.module_function_arity();
-        let process = Process::new(
+        let mut process = Process::new(
             priority,
             parent,
             initial_module_function_arity,
             heap,
             heap_size,
         );
+        options.configure(&mut process);
 
         let frame_with_arguments = Self::spawn_closure_frame_with_arguments(&process, closure);
         Self::runnable(&process, frame_with_arguments);
@@ -371,13 +407,14 @@ impl SchedulerTrait for Scheduler {
             function,
             arity: arguments.len() as Arity,
         };
-        let process = Process::new(
+        let mut process = Process::new(
             priority,
             parent,
             initial_module_function_arity,
             heap,
             heap_size,
         );
+        options.configure(&mut process);
 
         let frame_with_arguments = Self::spawn_module_function_arguments_frame_with_arguments(
             &process, module, function, arguments,
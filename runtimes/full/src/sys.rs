@@ -2,3 +2,5 @@ pub mod break_handler;
 pub mod host;
 pub mod io;
 pub mod random;
+
+pub use lumen_rt_core::sys::{cpu_time, cpus, entropy};
@@ -2,8 +2,14 @@ use core::ptr;
 
 use stackmaps::{FrameInfo, StackMap};
 
+use liblumen_alloc::atom;
+use liblumen_alloc::erts::exception;
+use liblumen_alloc::erts::process::ffi::process_raise;
+use liblumen_alloc::erts::process::gc::GcError;
+use liblumen_alloc::erts::process::trace::Trace;
 use liblumen_alloc::erts::term::prelude::{Boxed, Encoded, Term};
 use lumen_rt_core::process::current_process;
+use lumen_rt_core::profile;
 
 /// On x86_64, calling this function with no arguments will result
 /// in effectively calling __lumen_builtin_gc.run with the return address
@@ -58,8 +64,15 @@ pub unsafe extern "C" fn builtin_gc_run(
 ) -> bool {
     let iter = RootsIter::new(StackMap::get(), return_address, base_pointer);
     let roots = iter.collect::<Vec<_>>();
-    match current_process().garbage_collect(1, roots) {
+    let process = current_process();
+    match profile::time_garbage_collection(&process, || process.garbage_collect(1, roots)) {
         Ok(_) => true,
+        // `kill` is set in `max_heap_size`
+        Err(err @ GcError::MaxHeapSizeExceeded) => process_raise(exception::exit(
+            atom!("killed"),
+            Trace::capture(),
+            Some(anyhow::anyhow!(err).into()),
+        )),
         Err(err) => panic!("garbage collection failed: {}", err),
     }
 }
@@ -41,6 +41,9 @@ pub struct ReceiveContext {
     message: Term,
     timer_reference: Term,
     state: ReceiveState,
+    // Index in the mailbox of `message`.  Messages before it were not matched by any clause and
+    // stay in the mailbox.
+    cursor: usize,
 }
 impl ReceiveContext {
     #[inline]
@@ -58,6 +61,7 @@ impl ReceiveContext {
             message: Term::NONE,
             timer_reference,
             timeout,
+            cursor: 0,
         }
     }
 
@@ -67,6 +71,14 @@ impl ReceiveContext {
         self.message = message;
     }
 
+    /// Leaves the current message in the mailbox and moves on to the next one
+    #[inline]
+    fn skip_message(&mut self) {
+        self.state = ReceiveState::Ready;
+        self.message = Term::NONE;
+        self.cursor += 1;
+    }
+
     #[inline]
     fn with_timeout(&mut self) {
         self.cancel_timer();
@@ -100,9 +112,7 @@ pub extern "C" fn builtin_receive_start(timeout: Term) -> *mut ReceiveContext {
     // TODO: It would be best if ReceiveContext was repr(C) so we
     // could keep it on the stack rather than heap allocate here
     let p = current_process();
-    let context = Box::new(ReceiveContext::new(p.clone(), to));
-    let mbox = p.mailbox.lock();
-    mbox.borrow().recv_start();
+    let context = Box::new(ReceiveContext::new(p, to));
     Box::into_raw(context)
 }
 
@@ -110,17 +120,24 @@ pub extern "C" fn builtin_receive_start(timeout: Term) -> *mut ReceiveContext {
 #[export_name = "__lumen_builtin_receive_wait"]
 pub extern "C" fn builtin_receive_wait(ctx: *mut ReceiveContext) -> ReceiveState {
     let context = unsafe { &mut *ctx };
+    // Generated code branches back to `receive_wait` when none of the clauses match the received
+    // message, so waiting again after a message was received leaves it in the mailbox and checks
+    // the next message instead.
+    if context.state == ReceiveState::Received {
+        context.skip_message();
+    }
     loop {
         {
             let p = current_process();
             let mbox_lock = p.mailbox.lock();
             let mut mbox = mbox_lock.borrow_mut();
-            if let Some(msg) = mbox.recv_peek() {
-                mbox.recv_increment();
+            if let Some(msg) = mbox.recv_peek(context.cursor) {
+                // Messages sent while the heap was locked live in heap fragments, so copy them
+                // onto the heap before the clauses bind any of their terms.
+                let msg = mbox.recv_move_to_heap(context.cursor, &p).unwrap_or(msg);
                 context.with_message(msg);
                 break ReceiveState::Received;
             } else if context.should_time_out() {
-                mbox.recv_timeout();
                 context.with_timeout();
                 break ReceiveState::Timeout;
             } else {
@@ -156,7 +173,7 @@ pub extern "C" fn builtin_receive_done(ctx: *mut ReceiveContext) -> bool {
 
         match context.state {
             ReceiveState::Received => {
-                mbox.recv_received();
+                mbox.recv_received(context.cursor);
             }
             receive_state => {
                 unreachable!(
@@ -6,6 +6,11 @@ use std::path::Path;
 
 use clap::{App, AppSettings, Arg, SubCommand};
 
+use liblumen_alloc::erts::term::atom;
+
+use lumen_rt_core::logger::PrimaryLevel;
+use lumen_rt_core::profile;
+
 pub type ConfigResult<T> = std::result::Result<T, ConfigError>;
 //TODO: Needs to be HashMap<Atom, HashMap<Atom, Term>>
 pub type AppConfig = HashMap<String, HashMap<String, String>>;
@@ -49,10 +54,15 @@ pub struct Config {
     pub config: AppConfig,
     pub boot: Option<BootScript>,
     pub debug: bool,
+    pub logger_level: PrimaryLevel,
     pub name: Option<String>,
+    pub sname: Option<String>,
     pub cookie: Option<String>,
     pub command: Command,
     pub extra: Vec<String>,
+    pub schedulers: Option<usize>,
+    pub max_atoms: Option<usize>,
+    pub profile: Option<profile::Mode>,
 }
 
 impl Config {
@@ -79,19 +89,54 @@ impl Config {
             .arg(Arg::with_name("debug")
                      .long("debug")
                      .help("Enable debug output from the runtime"))
+            .arg(Arg::with_name("logger_level")
+                     .long("logger_level")
+                     .help("The primary level of logger, below which events are dropped (default: notice)")
+                     .takes_value(true)
+                     .possible_values(&["emergency", "alert", "critical", "error", "warning", "notice", "info", "debug", "all", "none"])
+                     .env("LOGGER_LEVEL"))
             .arg(Arg::with_name("name")
                      .long("name")
                      .global(true)
-                     .help("The secret cookie to use in distributed mode\n\
-                            If one is not provided, one will be generated for you in ~/.erlang.cookie")
+                     .help("The long name of this node in distributed mode, either name@host or only name for the full local host name\n\
+                            May also be given as -name Name like erl")
+                     .takes_value(true)
+                     .conflicts_with("sname")
+                     .validator(is_valid_node_name))
+            .arg(Arg::with_name("sname")
+                     .long("sname")
+                     .global(true)
+                     .help("The short name of this node in distributed mode, either name@host or only name for the first part of the local host name\n\
+                            May also be given as -sname Name like erl")
                      .takes_value(true)
                      .validator(is_valid_node_name))
             .arg(Arg::with_name("cookie")
                      .long("cookie")
                      .global(true)
-                     .help("The secret cookie to use in distributed mode")
+                     .help("The secret cookie to use in distributed mode\n\
+                            If one is not provided, the one in ~/.erlang.cookie is used\n\
+                            May also be given as -setcookie Cookie like erl")
                      .takes_value(true)
                      .env("COOKIE"))
+            .arg(Arg::with_name("schedulers")
+                     .long("schedulers")
+                     .short("S")
+                     .help("The number of scheduler threads to run, defaults to the number of logical cores\n\
+                            May also be given as +S Schedulers[:SchedulersOnline] like erl")
+                     .takes_value(true)
+                     .validator(is_valid_scheduler_count))
+            .arg(Arg::with_name("max_atoms")
+                     .long("max-atoms")
+                     .help("The maximum number of atoms, beyond which creating an atom raises system_limit\n\
+                            May also be given as +t Size like erl")
+                     .takes_value(true)
+                     .validator(is_valid_max_atoms))
+            .arg(Arg::with_name("profile")
+                     .long("profile")
+                     .help("Profile the reductions, garbage collection and scheduled time of each process, \
+                            which is reported on shutdown")
+                     .takes_value(true)
+                     .possible_values(&["processes"]))
             .arg(Arg::with_name("extra")
                      .last(true)
                      .multiple(true)
@@ -104,7 +149,7 @@ impl Config {
                             .help("Connects a remote shell to the specified host")
                             .takes_value(true)
                             .validator(is_valid_node_name)))
-            .get_matches_from(argv);
+            .get_matches_from(runtime_arguments(normalize_emulator_flags(argv)));
 
         let command: Command;
         let extra: Vec<&str>;
@@ -130,17 +175,190 @@ impl Config {
             )?,
             boot: with_file(matches.value_of_os("boot"), None, load_boot_script)?,
             debug: matches.is_present("debug"),
+            logger_level: matches
+                .value_of("logger_level")
+                .map_or_else(PrimaryLevel::default, |level| level.parse().unwrap()),
             name: matches.value_of("name").map(|v| v.to_string()),
+            sname: matches.value_of("sname").map(|v| v.to_string()),
             cookie: matches.value_of("cookie").map(|v| v.to_string()),
             command,
             extra: extra.iter().map(|v| v.to_string()).collect(),
+            schedulers: matches.value_of("schedulers").map(|v| v.parse().unwrap()),
+            max_atoms: matches.value_of("max_atoms").map(|v| v.parse().unwrap()),
+            profile: matches.value_of("profile").map(|v| v.parse().unwrap()),
         })
     }
 }
 
-fn is_valid_node_name(_f: String) -> Result<(), String> {
-    //TODO: Validate name
-    Ok(())
+/// Node names are `alive@host` or only `alive`, where `alive` may only contain the characters that
+/// `erl` allows.
+fn is_valid_node_name(name: String) -> Result<(), String> {
+    let mut parts = name.splitn(2, '@');
+    let alive = parts.next().unwrap();
+    let alive_is_valid = !alive.is_empty()
+        && alive
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    let host_is_valid = parts.next().map_or(true, |host| !host.is_empty());
+
+    if alive_is_valid && host_is_valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "node name ({}) must be name@host or only name, where name only contains letters, digits, _, or -",
+            name
+        ))
+    }
+}
+
+// The same limit as `+S` for `erl`
+const MAX_SCHEDULERS: usize = 1024;
+
+fn is_valid_scheduler_count(count: String) -> Result<(), String> {
+    match count.parse::<usize>() {
+        Ok(count) if 1 <= count && count <= MAX_SCHEDULERS => Ok(()),
+        _ => Err(format!(
+            "scheduler count ({}) must be an integer between 1 and {}",
+            count, MAX_SCHEDULERS
+        )),
+    }
+}
+
+// The same minimum as `+t` for `erl`
+const MIN_MAX_ATOMS: usize = 8192;
+
+fn is_valid_max_atoms(max_atoms: String) -> Result<(), String> {
+    match max_atoms.parse::<usize>() {
+        Ok(max_atoms) if MIN_MAX_ATOMS <= max_atoms && max_atoms <= atom::MAX_ATOMS => Ok(()),
+        _ => Err(format!(
+            "maximum number of atoms ({}) must be an integer between {} and {}",
+            max_atoms,
+            MIN_MAX_ATOMS,
+            atom::MAX_ATOMS
+        )),
+    }
+}
+
+/// `erl`-style emulator flags, their `--` equivalents, and how to convert their values
+const EMULATOR_FLAGS: &[(&str, &str, fn(&str) -> String)] = &[
+    ("+S", "--schedulers", schedulers_value),
+    ("+t", "--max-atoms", str::to_string),
+    ("-name", "--name", str::to_string),
+    ("-setcookie", "--cookie", str::to_string),
+    ("-sname", "--sname", str::to_string),
+];
+
+/// Rewrites the `erl`-style emulator flags in `EMULATOR_FLAGS` to their `--` equivalents, as `clap`
+/// only supports `-` prefixed short flags and `--` prefixed long flags.
+///
+/// Like `erl`, `+` flags may have their value attached, as in `+S4`, but `-` flags must be whole
+/// arguments, so that `-namespace` is not `-name space`.  Arguments after `--` are for the program,
+/// so they are passed through untouched.
+fn normalize_emulator_flags(argv: Vec<String>) -> Vec<String> {
+    let mut normalized = Vec::with_capacity(argv.len());
+    let mut iter = argv.into_iter();
+
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            normalized.push(arg);
+            normalized.extend(iter);
+
+            break;
+        }
+
+        match EMULATOR_FLAGS
+            .iter()
+            .find(|(flag, _, _)| arg == *flag || (flag.starts_with('+') && arg.starts_with(flag)))
+        {
+            Some((flag, long, value)) if arg.len() == flag.len() => {
+                normalized.push(long.to_string());
+
+                if let Some(next) = iter.next() {
+                    normalized.push(value(&next));
+                }
+            }
+            Some((flag, long, value)) => {
+                normalized.push(format!("{}={}", long, value(&arg[flag.len()..])));
+            }
+            None => normalized.push(arg),
+        }
+    }
+
+    normalized
+}
+
+/// The flags defined in `Config::from_argv` and whether they take a value
+const FLAGS: &[(&str, bool)] = &[
+    ("--args_file", true),
+    ("--boot", true),
+    ("--config", true),
+    ("--cookie", true),
+    ("--debug", false),
+    ("--help", false),
+    ("-h", false),
+    ("--logger_level", true),
+    ("--max-atoms", true),
+    ("--name", true),
+    ("--profile", true),
+    ("--remote", true),
+    ("--schedulers", true),
+    ("-S", true),
+    ("--sname", true),
+    ("--version", false),
+    ("-V", false),
+];
+
+/// Keeps only the flags in `FLAGS`, the `shell` subcommand, and everything after `--`, so that
+/// arguments meant for the program, which it gets from `init:get_plain_arguments/0`, don't stop
+/// the runtime from starting.
+fn runtime_arguments(argv: Vec<String>) -> Vec<String> {
+    let mut runtime_argv = Vec::with_capacity(argv.len());
+    let mut iter = argv.into_iter();
+    // The path of the executable
+    runtime_argv.extend(iter.next());
+
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            runtime_argv.push(arg);
+            runtime_argv.extend(iter);
+
+            break;
+        }
+
+        if arg == "shell" {
+            runtime_argv.push(arg);
+
+            continue;
+        }
+
+        // Long flags may be given as `--flag=value` and short flags as `-Svalue`
+        let option_flag = FLAGS.iter().find(|(flag, takes_value)| {
+            if flag.starts_with("--") {
+                arg.splitn(2, '=').next().unwrap() == *flag
+            } else if *takes_value {
+                arg.starts_with(flag)
+            } else {
+                arg == *flag
+            }
+        });
+
+        if let Some((flag, takes_value)) = option_flag {
+            let has_value = flag.len() < arg.len();
+            runtime_argv.push(arg);
+
+            if *takes_value && !has_value {
+                runtime_argv.extend(iter.next());
+            }
+        }
+    }
+
+    runtime_argv
+}
+
+/// `+S Schedulers[:SchedulersOnline]` becomes `--schedulers Schedulers`.  All schedulers are
+/// always online, so `SchedulersOnline` is ignored.
+fn schedulers_value(value: &str) -> String {
+    value.split(':').next().unwrap().to_string()
 }
 
 fn with_file<T>(v: Option<&OsStr>, default: T, fun: fn(String) -> T) -> ConfigResult<T> {
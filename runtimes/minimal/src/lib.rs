@@ -23,35 +23,68 @@ pub mod process;
 pub mod scheduler;
 pub mod sys;
 
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
 use liblumen_alloc::erts::process::alloc::default_heap_size;
+use liblumen_alloc::erts::term::atom;
 
 pub use lumen_rt_core::{
-    binary_to_string, context, distribution, proplist, registry, send, time, timer,
+    binary_to_string, code, context, dirty_io, distribution, file, inet, logger, port, profile,
+    proplist, registry, send, task, time, timer,
 };
 
 use bus::Bus;
 use log::Level;
 
 use self::config::Config;
+use self::distribution::NameType;
 use self::sys::break_handler::{self, Signal};
 
+extern "C" {
+    /// This symbol is defined in the compiled executable,
+    /// and is non-zero if it was built with `--bin`, in which
+    /// case the command-line arguments are passed to `main/1`,
+    /// and are not parsed as runtime configuration.
+    #[link_name = "__LUMEN_ESCRIPT"]
+    static ESCRIPT: u8;
+}
+
 #[liblumen_core::entry]
 fn main() -> impl ::std::process::Termination + 'static {
     let name = env!("CARGO_PKG_NAME");
     let version = env!("CARGO_PKG_VERSION");
-    main_internal(name, version, Vec::new())
+    main_internal(name, version, std::env::args().collect())
 }
 
 fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()> {
     self::env::init_argv_from_slice(std::env::args_os()).unwrap();
+    // Executables built with `--bin` leave all of their arguments to `main/1`,
+    // which receives them via `init:get_plain_arguments/0`
+    let argv = if unsafe { ESCRIPT } != 0 {
+        argv.into_iter().take(1).collect()
+    } else {
+        argv
+    };
     // Load system configuration
-    let _config = match Config::from_argv(name.to_string(), version.to_string(), argv) {
+    let config = match Config::from_argv(name.to_string(), version.to_string(), argv) {
         Ok(config) => config,
         Err(err) => {
             panic!("Config error: {}", err);
         }
     };
 
+    if let Some(profile_mode) = config.profile {
+        profile::enable(profile_mode);
+    }
+
+    if let Some(max_atoms) = config.max_atoms {
+        if let Err(err) = atom::set_limit(max_atoms) {
+            panic!("Config error: {}", err);
+        }
+    }
+
     // This bus is used to receive signals across threads in the system
     let mut bus: Bus<break_handler::Signal> = Bus::new(1);
     // Each thread needs a reader
@@ -62,10 +95,34 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
     // Start logger
     let level_filter = Level::Info.to_level_filter();
     logging::init(level_filter).expect("Unexpected failure initializing logger");
+    // Filter `logger` events, like the `kernel` `logger_level` parameter
+    logger::set_primary_level(config.logger_level);
+
+    let name_and_type = match (config.name, config.sname) {
+        (Some(name), _) => Some((name, NameType::Long)),
+        (None, Some(sname)) => Some((sname, NameType::Short)),
+        (None, None) => None,
+    };
+
+    if let Some((name, name_type)) = name_and_type {
+        if let Err(err) = distribution::start(&name, name_type, config.cookie) {
+            panic!("Distribution error: {:?}", err);
+        }
+    }
+
+    // The main thread runs the first scheduler, so only the additional schedulers need threads
+    let scheduler_count = scheduler_count(&config);
+    scheduler::set_count(scheduler_count);
+    let shutdown = Arc::new(AtomicBool::new(false));
+    let mut scheduler_threads: Vec<JoinHandle<()>> = (1..scheduler_count)
+        .map(|index| spawn_scheduler_thread(index, shutdown.clone()))
+        .collect();
 
     let scheduler = scheduler::current();
     scheduler.spawn_init(default_heap_size()).unwrap();
     loop {
+        // Taken before running, so that a process that becomes runnable afterwards isn't missed
+        let generation = scheduler::idle().generation();
         // Run the scheduler for a cycle
         let scheduled = scheduler.run_once();
         // Check for system signals, and terminate if needed
@@ -73,6 +130,9 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
             match sig {
                 // For now, SIGINT initiates a controlled shutdown
                 Signal::INT => {
+                    stop_scheduler_threads(&shutdown, &mut scheduler_threads);
+                    profile::write_report();
+
                     // If an error occurs, report it before shutdown
                     if let Err(err) = scheduler.shutdown() {
                         eprintln!("System error: {}", err);
@@ -93,15 +153,25 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
                 _ => (),
             }
         }
-        // If the scheduler scheduled a process this cycle, then we're busy
+        // If the scheduler has a process to run, then we're busy
         // and should keep working until we have an idle period
-        if scheduled {
+        if scheduler::has_runnable_processes(&*scheduler) {
+            continue;
+        }
+
+        // Processes that are waiting, or are running on other schedulers, may become runnable
+        // here or be stolen once they yield
+        if scheduled || scheduler::has_scheduled_processes() {
+            scheduler::park(generation);
             continue;
         }
 
         break;
     }
 
+    stop_scheduler_threads(&shutdown, &mut scheduler_threads);
+    profile::write_report();
+
     match scheduler.shutdown() {
         Ok(_) => Ok(()),
         Err(err) => {
@@ -110,3 +180,47 @@ fn main_internal(name: &str, version: &str, argv: Vec<String>) -> Result<(), ()>
         }
     }
 }
+
+// WebAssembly hosts can't spawn threads, so all processes run on the main thread's scheduler
+#[cfg(target_arch = "wasm32")]
+fn scheduler_count(_config: &Config) -> usize {
+    1
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn scheduler_count(config: &Config) -> usize {
+    config.schedulers.unwrap_or_else(sys::cpus::num_logical)
+}
+
+fn spawn_scheduler_thread(index: usize, shutdown: Arc<AtomicBool>) -> JoinHandle<()> {
+    thread::Builder::new()
+        .name(format!("scheduler-{}", index))
+        .spawn(move || {
+            // Getting the current scheduler registers a new scheduler for this thread, which then
+            // runs processes stolen from the other schedulers
+            let scheduler = scheduler::current();
+
+            while !shutdown.load(Ordering::Acquire) {
+                let generation = scheduler::idle().generation();
+                scheduler.run_once();
+
+                if !scheduler::has_runnable_processes(&*scheduler) {
+                    scheduler::park(generation);
+                }
+            }
+
+            if let Err(err) = scheduler.shutdown() {
+                eprintln!("System error: {}", err);
+            }
+        })
+        .expect("Unexpected failure spawning scheduler thread")
+}
+
+fn stop_scheduler_threads(shutdown: &AtomicBool, scheduler_threads: &mut Vec<JoinHandle<()>>) {
+    shutdown.store(true, Ordering::Release);
+    scheduler::idle().wake();
+
+    for scheduler_thread in scheduler_threads.drain(..) {
+        let _ = scheduler_thread.join();
+    }
+}
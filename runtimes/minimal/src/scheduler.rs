@@ -4,8 +4,9 @@ use std::ffi::c_void;
 use std::fmt::{self, Debug};
 use std::mem;
 use std::ptr::{self, NonNull};
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
 use log::info;
 
@@ -17,18 +18,23 @@ use liblumen_alloc::erts::exception::ErlangException;
 use liblumen_alloc::erts::process::{CalleeSavedRegisters, Priority, Process, Status};
 use liblumen_alloc::erts::scheduler::{id, ID};
 use liblumen_alloc::erts::term::prelude::*;
+use liblumen_alloc::erts::time::Milliseconds;
 use liblumen_alloc::erts::ModuleFunctionArity;
 use liblumen_alloc::{Arity, CloneToProcess};
 
 use lumen_rt_core::process::spawn::options::Options;
-use lumen_rt_core::process::{log_exit, propagate_exit, CURRENT_PROCESS};
+use lumen_rt_core::process::{deliver_exit_signals, log_exit, propagate_exit, CURRENT_PROCESS};
 use lumen_rt_core::registry::put_pid_to_process;
+use lumen_rt_core::scheduler::idle::Generation;
+use lumen_rt_core::scheduler::statistics::{ProcessCounts, Statistics};
 use lumen_rt_core::scheduler::Scheduler as SchedulerTrait;
 use lumen_rt_core::scheduler::{self, run_queue, unregister, Run};
 pub use lumen_rt_core::scheduler::{
-    current, from_id, run_through, Scheduled, SchedulerDependentAlloc, Spawned,
+    count, current, from_id, idle, run_through, set_count, Scheduled, SchedulerDependentAlloc,
+    Spawned,
 };
-use lumen_rt_core::timer::Hierarchy;
+use lumen_rt_core::time::monotonic;
+use lumen_rt_core::timer::{self, Hierarchy};
 
 // External thread locals owned by the generated code
 extern "C" {
@@ -51,6 +57,39 @@ extern "C" {
     fn apply_apply_3() -> usize;
 }
 
+/// The number of processes scheduled across all schedulers that have not exited yet.  Migrating a
+/// process between schedulers does not change the count, so the runtime can use it to tell that
+/// all schedulers are out of work.
+static SCHEDULED_PROCESS_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns `true` if any scheduler still has a process that has not exited.
+crate fn has_scheduled_processes() -> bool {
+    0 < SCHEDULED_PROCESS_COUNT.load(Ordering::Acquire)
+}
+
+/// Returns `true` if `scheduler` has a process it can run now.  Waiting processes are kept in the
+/// run queues too, but are not counted by `run_queue_len`.
+crate fn has_runnable_processes(scheduler: &dyn SchedulerTrait) -> bool {
+    [Priority::Normal, Priority::High, Priority::Max]
+        .iter()
+        .any(|priority| 0 < scheduler.run_queue_len(*priority))
+}
+
+/// Parks the current thread's scheduler, which has no runnable processes, until a process may be
+/// runnable on any scheduler or until the scheduler's next timer times out.  `generation` must be
+/// taken before looking for a process to run.
+crate fn park(generation: Generation) {
+    let timeout = timer::next_monotonic().map(|next_monotonic| {
+        let milliseconds = next_monotonic
+            .checked_sub(monotonic::time())
+            .unwrap_or(Milliseconds(0));
+
+        Duration::from_millis(milliseconds.as_u64())
+    });
+
+    scheduler::idle().park(generation, timeout);
+}
+
 crate fn stop_waiting(process: &Process) {
     if let Some(scheduler) = from_id(&process.scheduler_id().unwrap()) {
         scheduler.stop_waiting(process)
@@ -141,6 +180,7 @@ pub struct Scheduler {
     // References are always 64-bits even on 32-bit platforms
     reference_count: AtomicU64,
     run_queues: RwLock<run_queue::Queues>,
+    statistics: Statistics,
     // Non-monotonic unique integers are scoped to the scheduler ID and then use this per-scheduler
     // `u64`.
     unique_integer: AtomicU64,
@@ -197,6 +237,7 @@ impl Scheduler {
             current,
             hierarchy: Default::default(),
             reference_count: AtomicU64::new(0),
+            statistics: Default::default(),
             unique_integer: AtomicU64::new(0),
         })
     }
@@ -275,10 +316,16 @@ impl SchedulerTrait for Scheduler {
 
         self.run_queues.write().enqueue(arc_process.clone());
         put_pid_to_process(&arc_process);
+        SCHEDULED_PROCESS_COUNT.fetch_add(1, Ordering::AcqRel);
+        scheduler::idle().wake();
 
         arc_process
     }
 
+    fn statistics(&self) -> &Statistics {
+        &self.statistics
+    }
+
     fn spawn_init(&self, minimum_heap_size: usize) -> anyhow::Result<Arc<Process>> {
         // The init process is the actual "root" Erlang process, it acts
         // as the entry point for the program from Erlang's perspective,
@@ -313,13 +360,14 @@ impl SchedulerTrait for Scheduler {
         let (heap, heap_size) = options.sized_heap()?;
         let priority = options.cascaded_priority(parent);
         let initial_module_function_arity = closure.module_function_arity();
-        let process = Process::new_with_stack(
+        let mut process = Process::new_with_stack(
             priority,
             parent,
             initial_module_function_arity,
             heap,
             heap_size,
         )?;
+        options.configure(&mut process);
 
         let (init_fn, env) = Self::spawn_closure_init_env(&process, closure);
         Self::runnable(&process, init_fn, env);
@@ -353,13 +401,14 @@ impl SchedulerTrait for Scheduler {
             function,
             arity: arguments.len() as Arity,
         };
-        let process = Process::new_with_stack(
+        let mut process = Process::new_with_stack(
             priority,
             parent,
             initial_module_function_arity,
             heap,
             heap_size,
         )?;
+        options.configure(&mut process);
         let (init_fn, env) =
             Self::spawn_module_function_arguments_init_env(&process, module, function, arguments);
         Self::runnable(&process, init_fn, env);
@@ -390,9 +439,14 @@ impl SchedulerTrait for Scheduler {
         Ok(())
     }
 
+    fn steal(&self) -> Option<Arc<Process>> {
+        self.run_queues.write().steal()
+    }
+
     fn stop_waiting(&self, process: &Process) {
         process.stop_waiting();
         self.run_queues.write().stop_waiting(process);
+        scheduler::idle().wake();
     }
 }
 
@@ -436,12 +490,17 @@ impl Scheduler {
             match next {
                 Run::Now(process) => {
                     info!("found process to schedule");
+                    // Exit signals are only delivered between runs, so that a running process
+                    // is never exited from outside itself.
+                    deliver_exit_signals(&process);
+
                     // Don't allow exiting processes to run again.
                     //
                     // Without this check, a process.exit() from outside the process during WAITING
                     // will return to code that called `process.wait()`
                     let requeue_arc_process = if !process.is_exiting() {
                         info!("swapping into process {:?}", process.pid());
+                        let process_counts = ProcessCounts::of(&process);
                         // The swap takes care of setting up the to-be-scheduled process
                         // as the current process, and swaps to its stack. The code below
                         // is executed when that process has yielded and we're resetting
@@ -463,6 +522,7 @@ impl Scheduler {
                         let prev_reductions = reset_reduction_counter();
                         prev.total_reductions
                             .fetch_add(prev_reductions as u64, Ordering::Relaxed);
+                        self.statistics.record_run(&prev, process_counts);
 
                         // Change the previous process status to Runnable
                         {
@@ -490,6 +550,12 @@ impl Scheduler {
 
                     // If the process is exiting, then handle the exit
                     if let Some(exiting_arc_process) = option_exiting_arc_process {
+                        // Parked schedulers need to notice that there is nothing left to run, so
+                        // that the runtime can stop
+                        if SCHEDULED_PROCESS_COUNT.fetch_sub(1, Ordering::AcqRel) == 1 {
+                            scheduler::idle().wake();
+                        }
+
                         match *exiting_arc_process.status.read() {
                             Status::Exited => {
                                 propagate_exit(&exiting_arc_process, None);
@@ -512,6 +578,10 @@ impl Scheduler {
                     info!("found process, but it is delayed");
                     continue;
                 }
+                Run::Waiting | Run::None if self.steal_from_others() => {
+                    info!("stole process from another scheduler");
+                    continue;
+                }
                 Run::Waiting => {
                     info!("exiting scheduler loop because waiting");
                     // Return to main scheduler loop to check for signals and to re-enter from
@@ -520,13 +590,10 @@ impl Scheduler {
                 }
                 Run::None if self.current.pid() == self.root.pid() => {
                     info!("no processes remaining to schedule, exiting loop");
-                    // If no processes are available, then the scheduler should steal,
-                    // but if it can't/doesn't, then it must terminate, as there is
-                    // nothing we can swap to. When we break here, we're returning
-                    // to the core scheduler loop, which _must_ terminate, if it does
-                    // not, we'll just end up right back here again.
-                    //
-                    // TODO: stealing
+                    // If no processes are available and none could be stolen, then there is
+                    // nothing we can swap to. When we break here, we're returning to the core
+                    // scheduler loop, which _must_ terminate or wait for other schedulers to have
+                    // work to steal, if it does not, we'll just end up right back here again.
                     break false;
                 }
                 Run::None => unreachable!(),
@@ -534,6 +601,27 @@ impl Scheduler {
         }
     }
 
+    /// Steals a runnable process from another scheduler and migrates it to this scheduler's run
+    /// queues.  Returns `true` if a process was migrated.
+    fn steal_from_others(&self) -> bool {
+        for other in scheduler::others() {
+            if let Some(arc_process) = other.steal() {
+                info!(
+                    "migrating process {:?} from scheduler {} to scheduler {}",
+                    arc_process.pid(),
+                    other.id(),
+                    self.id
+                );
+                arc_process.schedule_with(self.id);
+                self.run_queues.write().enqueue(arc_process);
+
+                return true;
+            }
+        }
+
+        false
+    }
+
     /// This function takes care of coordinating the scheduling of a new
     /// process/descheduling of the current process.
     ///
@@ -1,3 +1,4 @@
 pub mod break_handler;
-pub mod cpus;
 pub mod io;
+
+pub use lumen_rt_core::sys::cpus;
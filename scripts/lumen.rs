@@ -374,7 +374,7 @@ fn main() {
 
     println!("Installing runtime libraries..");
 
-    let lumenlibs = &["lumen_rt_minimal", "liblumen_otp"];
+    let lumenlibs = &["lumen_rt_minimal", "liblumen_otp", "lumen_nif"];
     for lib in lumenlibs.iter().copied() {
         if let Some(files) = deps.get(lib) {
             for file in files.iter() {